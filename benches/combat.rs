@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::combat_system::{collision_system, tower_targeting_system, EnemyDamagedEvent, EnemyKilledEvent, Target, WaveStatus};
+
+const ENEMY_COUNTS: [usize; 3] = [100, 500, 1000];
+const TOWER_COUNT: usize = 20;
+const PROJECTILE_COUNT: usize = 50;
+
+/// World with towers along the middle of the map and `enemy_count` enemies spread over it
+fn create_combat_world(enemy_count: usize) -> World {
+    let mut world = World::new();
+    world.insert_resource(Economy::default());
+    world.insert_resource(Score::default());
+    world.insert_resource(WaveStatus::default());
+    world.init_resource::<Events<EnemyKilledEvent>>();
+    world.init_resource::<Events<EnemyDamagedEvent>>();
+
+    for i in 0..TOWER_COUNT {
+        world.spawn((
+            TowerStats::new(TowerType::Basic),
+            Transform::from_xyz(-600.0 + i as f32 * 60.0, 0.0, 0.0),
+            Target::default(),
+        ));
+    }
+
+    for i in 0..enemy_count {
+        let x = -620.0 + (i % 64) as f32 * 20.0;
+        let y = -340.0 + (i / 64) as f32 * 20.0;
+        world.spawn((
+            Enemy::default(),
+            Health::new(1_000_000.0),
+            PathProgress { current: i as f32 / enemy_count as f32 },
+            Transform::from_xyz(x, y, 0.0),
+        ));
+    }
+
+    world
+}
+
+fn targeting_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tower_targeting");
+
+    for enemy_count in ENEMY_COUNTS {
+        let mut world = create_combat_world(enemy_count);
+        let mut schedule = Schedule::default();
+        schedule.add_systems(tower_targeting_system);
+
+        group.bench_with_input(BenchmarkId::from_parameter(enemy_count), &enemy_count, |b, _| {
+            b.iter(|| schedule.run(&mut world))
+        });
+    }
+
+    group.finish();
+}
+
+fn collision_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("collision");
+
+    for enemy_count in ENEMY_COUNTS {
+        // Worst case: projectiles in flight that miss every enemy, so each one scans all enemies
+        let mut miss_world = create_combat_world(enemy_count);
+        let target = miss_world.query_filtered::<Entity, With<Enemy>>().iter(&miss_world).next().unwrap();
+        for i in 0..PROJECTILE_COUNT {
+            miss_world.spawn((
+                Transform::from_xyz(-600.0 + i as f32 * 25.0, 1000.0, 0.0),
+                Projectile::new(10.0, 300.0, target, Vec2::ZERO, TowerType::Basic),
+            ));
+        }
+        let mut schedule = Schedule::default();
+        schedule.add_systems(collision_system);
+
+        group.bench_with_input(BenchmarkId::new("scan", enemy_count), &enemy_count, |b, _| {
+            b.iter(|| schedule.run(&mut miss_world))
+        });
+
+        // Every projectile lands on an enemy (fresh world per batch, since hits despawn projectiles)
+        group.bench_with_input(BenchmarkId::new("hits", enemy_count), &enemy_count, |b, &enemy_count| {
+            b.iter_batched(
+                || {
+                    let mut world = create_combat_world(enemy_count);
+                    let enemies: Vec<(Entity, Vec3)> = world
+                        .query_filtered::<(Entity, &Transform), With<Enemy>>()
+                        .iter(&world)
+                        .map(|(entity, transform)| (entity, transform.translation))
+                        .collect();
+                    for (entity, position) in enemies.into_iter().rev().take(PROJECTILE_COUNT) {
+                        world.spawn((
+                            Transform::from_translation(position),
+                            Projectile::new(10.0, 300.0, entity, position.truncate(), TowerType::Basic),
+                        ));
+                    }
+                    let mut schedule = Schedule::default();
+                    schedule.add_systems(collision_system);
+                    (world, schedule)
+                },
+                |(mut world, mut schedule)| schedule.run(&mut world),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, targeting_benchmark, collision_benchmark);
+criterion_main!(benches);
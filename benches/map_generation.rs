@@ -0,0 +1,53 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::hint::black_box;
+use tower_defense_bevy::systems::path_generation::*;
+
+/// Full procedural map generation, including the A* validation and
+/// obstacle-reduction retries, across the difficulty range
+fn procedural_map_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("procedural_map");
+
+    for difficulty in [0.0_f32, 0.5, 1.0] {
+        group.bench_with_input(BenchmarkId::from_parameter(difficulty), &difficulty, |b, &difficulty| {
+            let mut seed = 0_u64;
+            b.iter(|| {
+                seed = seed.wrapping_add(1);
+                generate_procedural_map_with_random_sides(black_box(seed), difficulty)
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn archetype_map_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("archetype_map");
+
+    for archetype in MapArchetype::ALL {
+        group.bench_with_input(BenchmarkId::from_parameter(archetype.get_name()), &archetype, |b, &archetype| {
+            let mut seed = 0_u64;
+            b.iter(|| {
+                seed = seed.wrapping_add(1);
+                generate_procedural_map_with_archetype(black_box(seed), 0.5, archetype)
+            })
+        });
+    }
+
+    group.finish();
+}
+
+/// Map generation followed by strategic path generation, as done for each new level
+fn level_pipeline_benchmark(c: &mut Criterion) {
+    c.bench_function("level_pipeline", |b| {
+        let mut seed = 0_u64;
+        b.iter(|| {
+            seed = seed.wrapping_add(1);
+            let grid = generate_procedural_map_with_random_sides(black_box(seed), 0.5);
+            let path = generate_random_strategic_path(seed.wrapping_add(1000), &grid);
+            grid.to_enemy_path(path)
+        })
+    });
+}
+
+criterion_group!(benches, procedural_map_benchmark, archetype_map_benchmark, level_pipeline_benchmark);
+criterion_main!(benches);
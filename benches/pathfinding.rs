@@ -0,0 +1,44 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::hint::black_box;
+use tower_defense_bevy::systems::path_generation::*;
+
+/// Dense 32x18 grids with increasing obstacle coverage
+fn bench_grids() -> Vec<(&'static str, PathGrid)> {
+    vec![
+        ("open", PathGrid::new_unified()),
+        ("classic", generate_procedural_map_with_random_sides(42, 0.5)),
+        ("maze", generate_procedural_map_with_archetype(42, 1.0, MapArchetype::Maze)),
+    ]
+}
+
+fn find_path_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("find_path");
+
+    for (name, grid) in bench_grids() {
+        group.bench_with_input(BenchmarkId::from_parameter(name), &grid, |b, grid| {
+            b.iter(|| find_path(black_box(grid), grid.entry_point, grid.exit_point))
+        });
+    }
+
+    group.finish();
+}
+
+fn strategic_analysis_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("strategic_analysis");
+
+    for (name, grid) in bench_grids() {
+        let path = generate_random_strategic_path(1042, &grid);
+
+        group.bench_with_input(BenchmarkId::new("strategic_positions", name), &path, |b, path| {
+            b.iter(|| analyze_strategic_positions(black_box(&grid), black_box(path)))
+        });
+        group.bench_with_input(BenchmarkId::new("path_strategic_value", name), &path, |b, path| {
+            b.iter(|| evaluate_path_strategic_value(black_box(&grid), black_box(path)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, find_path_benchmark, strategic_analysis_benchmark);
+criterion_main!(benches);
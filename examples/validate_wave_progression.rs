@@ -6,7 +6,7 @@
 
 use tower_defense_bevy::resources::*;
 use tower_defense_bevy::components::*;
-use tower_defense_bevy::systems::enemy_system::calculate_enemies_for_wave;
+use tower_defense_bevy::systems::enemy_system::{calculate_enemies_for_wave, compose_wave};
 
 fn main() {
     println!("=== Tower Defense Wave Progression Validation ===\n");
@@ -14,8 +14,8 @@ fn main() {
     // Test wave enemy count scaling
     println!("📊 Wave Enemy Count Scaling:");
     for wave in 1..=10 {
-        let enemy_count = calculate_enemies_for_wave(wave);
-        println!("  Wave {:2}: {:2} enemies", wave, enemy_count);
+        let composition = compose_wave(wave, None, None);
+        println!("  Wave {:2}: {}", wave, composition.summary());
     }
     
     // Test enemy stat scaling  
@@ -39,19 +39,20 @@ fn main() {
     
     // Test spawn rate scaling
     println!("\n⏱️ Spawn Rate Scaling:");
-    let mut wave_manager = WaveManager::new();
+    let (mut wave_plan, mut wave_runtime) = (WavePlan::default(), WaveRuntime::new());
     for wave in [1, 2, 3, 5, 10] {
         // Simulate starting each wave to get proper wave number
         for _ in 0..wave {
-            wave_manager.start_wave(calculate_enemies_for_wave(wave_manager.current_wave + 1));
+            let enemy_count = calculate_enemies_for_wave(wave_plan.next_wave());
+            start_wave(&mut wave_plan, &mut wave_runtime, enemy_count);
         }
-        let spawn_interval = wave_manager.spawn_timer.duration().as_secs_f32();
+        let spawn_interval = wave_runtime.spawn_timer.duration().as_secs_f32();
         let spawn_rate = 1.0 / spawn_interval;
         println!("  Wave {:2}: {:.2} enemies/sec (interval: {:.2}s)", 
                  wave, spawn_rate, spawn_interval);
         
         // Reset for next test
-        wave_manager = WaveManager::new();
+        (wave_plan, wave_runtime) = (WavePlan::default(), WaveRuntime::new());
     }
     
     println!("\n✅ Validation Complete!");
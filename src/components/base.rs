@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+/// Starting health of the base
+pub const BASE_MAX_HEALTH: f32 = 100.0;
+/// Damage an enemy deals when it reaches the base. Ten escapes destroy a full-health base.
+pub const ENEMY_BASE_DAMAGE: f32 = 10.0;
+/// Number of segments in the health ring around the base
+pub const BASE_RING_SEGMENTS: usize = 20;
+
+/// The base at the path exit. Enemies that reach it damage its `Health`,
+/// and the game is lost once it is destroyed.
+#[derive(Component, Debug, Default)]
+pub struct Base;
+
+/// One segment of the base's health ring, lit while the base has enough health
+#[derive(Component, Debug, Clone, Copy)]
+pub struct BaseHealthSegment {
+    pub index: usize,
+}
+
+impl Base {
+    /// Number of ring segments lit at this health fraction. Rounds up so a base
+    /// with any health left keeps at least one segment.
+    pub fn lit_segments(health_fraction: f32) -> usize {
+        let fraction = health_fraction.clamp(0.0, 1.0);
+        (fraction * BASE_RING_SEGMENTS as f32).ceil() as usize
+    }
+}
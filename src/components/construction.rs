@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+use crate::resources::ResourceCost;
+
+/// Tower that is still being built. Until the build timer finishes the tower
+/// cannot fire or be upgraded, and cancelling refunds `paid_cost` in full.
+#[derive(Component, Debug)]
+pub struct Constructing {
+    pub build_timer: Timer,
+    pub paid_cost: ResourceCost,
+}
+
+impl Constructing {
+    pub fn new(build_time: f32, paid_cost: ResourceCost) -> Self {
+        Self {
+            build_timer: Timer::from_seconds(build_time, TimerMode::Once),
+            paid_cost,
+        }
+    }
+
+    /// Build progress from 0.0 (just placed) to 1.0 (finished)
+    pub fn progress(&self) -> f32 {
+        self.build_timer.fraction()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.build_timer.finished()
+    }
+}
+
+/// Seconds after a tower finishes building during which selling it is refunded in full
+pub const PLACEMENT_GRACE_PERIOD: f32 = 3.0;
+
+/// Grace window on a freshly built tower to forgive misplacements. Until the
+/// timer runs out or the tower fires, selling it refunds `paid_cost` in full
+/// instead of the usual partial sell value.
+#[derive(Component, Debug)]
+pub struct PlacementGrace {
+    pub timer: Timer,
+    pub paid_cost: ResourceCost,
+}
+
+impl PlacementGrace {
+    pub fn new(paid_cost: ResourceCost) -> Self {
+        Self {
+            timer: Timer::from_seconds(PLACEMENT_GRACE_PERIOD, TimerMode::Once),
+            paid_cost,
+        }
+    }
+
+    /// Share of the grace window left, from 1.0 (just built) to 0.0
+    pub fn remaining(&self) -> f32 {
+        self.timer.fraction_remaining()
+    }
+}
@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+
+/// Kinds of purely cosmetic effect kept in check by the effect budget
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EffectCategory {
+    Particle,
+    DamageNumber,
+    Decal,
+    Trail,
+    ScreenFlash,
+}
+
+impl EffectCategory {
+    pub const ALL: [EffectCategory; 5] = [
+        EffectCategory::Particle,
+        EffectCategory::DamageNumber,
+        EffectCategory::Decal,
+        EffectCategory::Trail,
+        EffectCategory::ScreenFlash,
+    ];
+
+    /// Position in `ALL`, for per-category tables
+    pub fn index(&self) -> usize {
+        match self {
+            EffectCategory::Particle => 0,
+            EffectCategory::DamageNumber => 1,
+            EffectCategory::Decal => 2,
+            EffectCategory::Trail => 3,
+            EffectCategory::ScreenFlash => 4,
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            EffectCategory::Particle => "Particles",
+            EffectCategory::DamageNumber => "Damage Numbers",
+            EffectCategory::Decal => "Decals",
+            EffectCategory::Trail => "Trails",
+            EffectCategory::ScreenFlash => "Screen Flashes",
+        }
+    }
+}
+
+/// Marker for a cosmetic entity counted against the effect budget
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CosmeticEffect(pub EffectCategory);
@@ -1,5 +1,11 @@
 use bevy::prelude::*;
 
+/// Hit radius of an enemy (half its 20px sprite)
+pub const ENEMY_COLLISION_RADIUS: f32 = 10.0;
+/// Furthest a swarm enemy strays sideways from the path centre line. A 40px path
+/// cell leaves 10px either side of an enemy; the rest is margin for spline curves.
+pub const SWARM_MAX_LATERAL_OFFSET: f32 = 8.0;
+
 /// Enemy component that defines enemy properties
 #[derive(Component)]
 pub struct Enemy {
@@ -38,6 +44,86 @@ impl Enemy {
     }
 }
 
+/// Type of an enemy, setting its stats relative to the wave's baseline and its color
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Serialize, serde::Deserialize)]
+pub enum EnemyType {
+    /// Wave-scaled stats, drawn in its kind's color
+    #[default]
+    Basic,
+    Fast,
+    Tank,
+    Flying,
+    Shielded,
+    Boss,
+}
+
+impl EnemyType {
+    pub const ALL: [EnemyType; 6] = [
+        EnemyType::Basic,
+        EnemyType::Fast,
+        EnemyType::Tank,
+        EnemyType::Flying,
+        EnemyType::Shielded,
+        EnemyType::Boss,
+    ];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            EnemyType::Basic => "Basic",
+            EnemyType::Fast => "Fast",
+            EnemyType::Tank => "Tank",
+            EnemyType::Flying => "Flying",
+            EnemyType::Shielded => "Shielded",
+            EnemyType::Boss => "Boss",
+        }
+    }
+
+    /// Health as a multiple of the wave's baseline
+    pub fn health_multiplier(&self) -> f32 {
+        match self {
+            EnemyType::Basic => 1.0,
+            EnemyType::Fast => 0.6,
+            EnemyType::Tank => 2.5,
+            EnemyType::Flying => 0.8,
+            EnemyType::Shielded => 1.6,
+            EnemyType::Boss => 12.0,
+        }
+    }
+
+    /// Speed as a multiple of the wave's baseline
+    pub fn speed_multiplier(&self) -> f32 {
+        match self {
+            EnemyType::Basic => 1.0,
+            EnemyType::Fast => 1.6,
+            EnemyType::Tank => 0.6,
+            EnemyType::Flying => 1.2,
+            EnemyType::Shielded => 0.9,
+            EnemyType::Boss => 0.5,
+        }
+    }
+
+    /// Kill reward as a multiple of the killing tower's payout
+    pub fn reward_multiplier(&self) -> u32 {
+        match self {
+            EnemyType::Basic | EnemyType::Fast => 1,
+            EnemyType::Flying | EnemyType::Shielded => 2,
+            EnemyType::Tank => 3,
+            EnemyType::Boss => 10,
+        }
+    }
+
+    pub const fn color(&self) -> Color {
+        match self {
+            EnemyType::Basic => Color::srgb(1.0, 0.2, 0.2),
+            EnemyType::Fast => Color::srgb(1.0, 0.8, 0.2),
+            EnemyType::Tank => Color::srgb(0.55, 0.35, 0.2),
+            EnemyType::Flying => Color::srgb(0.4, 0.85, 1.0),
+            EnemyType::Shielded => Color::srgb(0.7, 0.75, 0.8),
+            EnemyType::Boss => Color::srgb(0.9, 0.1, 0.45),
+        }
+    }
+}
+
 /// Component that tracks an enemy's progress along the path (0.0 to 1.0)
 #[derive(Component)]
 pub struct PathProgress {
@@ -66,4 +152,83 @@ impl Default for PathProgress {
     fn default() -> Self {
         Self::new()
     }
-}
\ No newline at end of file
+}
+/// Stable sideways offset from the path centre line, so a swarm spreads across
+/// the path instead of walking in single file
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct SwarmOffset {
+    /// Signed distance from the centre line (positive = left of travel direction)
+    pub lateral: f32,
+}
+
+impl SwarmOffset {
+    /// Offset for the n-th enemy of a wave. Golden-ratio spacing keeps enemies
+    /// spawned one after another on different lanes.
+    pub fn for_spawn_index(index: u32) -> Self {
+        let fraction = (index as f32 * 0.618_034).fract();
+        Self {
+            lateral: (fraction * 2.0 - 1.0) * SWARM_MAX_LATERAL_OFFSET,
+        }
+    }
+}
+
+/// Caps how far hits may push an enemy back along its path within one second
+#[derive(Component, Debug, Clone, Copy, PartialEq, Default)]
+pub struct KnockbackLimiter {
+    /// Simulation time the current one-second window began
+    pub window_start: f32,
+    /// Distance already pushed back in the current window, in pixels
+    pub pushed: f32,
+}
+
+impl KnockbackLimiter {
+    /// Share of `distance` still allowed at simulation time `now` under a cap of
+    /// `max_per_second` pixels, recording it as pushed
+    pub fn allow(&mut self, distance: f32, max_per_second: f32, now: f32) -> f32 {
+        if now - self.window_start >= 1.0 {
+            self.window_start = now;
+            self.pushed = 0.0;
+        }
+        let allowed = distance.min(max_per_second - self.pushed).max(0.0);
+        self.pushed += allowed;
+        allowed
+    }
+}
+
+/// Seconds a killed enemy takes to shrink and fade away
+pub const ENEMY_DEATH_DURATION: f32 = 0.3;
+
+/// Component for a killed enemy playing its death animation. Only its visuals
+/// are kept, so nothing targets, hits or counts it; it is despawned once faded.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct DyingEnemy {
+    pub elapsed: f32,
+    /// Whether the start scale and alpha have been taken from the enemy yet
+    pub started: bool,
+    /// Scale when it died, shrunk down to nothing
+    pub start_scale: Vec3,
+    /// Sprite alpha when it died, faded down to nothing
+    pub start_alpha: f32,
+}
+
+impl Default for DyingEnemy {
+    fn default() -> Self {
+        Self {
+            elapsed: 0.0,
+            started: false,
+            start_scale: Vec3::ONE,
+            start_alpha: 1.0,
+        }
+    }
+}
+
+impl DyingEnemy {
+    /// Share of the animation played, from 0.0 to 1.0
+    pub fn fraction(&self) -> f32 {
+        (self.elapsed / ENEMY_DEATH_DURATION).clamp(0.0, 1.0)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= ENEMY_DEATH_DURATION
+    }
+}
@@ -0,0 +1,141 @@
+use bevy::prelude::*;
+use crate::resources::TowerType;
+
+/// Rounds in one Advanced tower burst
+pub const BURST_ROUNDS: u32 = 3;
+/// Seconds between the rounds of a burst
+pub const BURST_ROUND_DELAY: f32 = 0.1;
+/// Seconds a Laser charges before it fires
+pub const CHARGE_UP_SECONDS: f32 = 1.0;
+/// Damage multiplier of a fully charged Laser shot
+pub const CHARGED_SHOT_DAMAGE_MULTIPLIER: f32 = 3.0;
+/// Fire-rate multiplier a Tesla gains with each shot at the same target
+pub const RAMP_PER_SHOT: f32 = 0.25;
+/// Highest fire-rate multiplier a Tesla ramps up to
+pub const MAX_RAMP_MULTIPLIER: f32 = 2.5;
+
+/// How a tower spaces its shots. Holds the pattern's state between frames.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub enum FiringPattern {
+    /// One shot per cooldown
+    Uniform,
+    /// Rounds fired `BURST_ROUND_DELAY` apart; the pause after a burst keeps
+    /// the average at one round per cooldown
+    Burst { rounds_fired: u32 },
+    /// Charge for `CHARGE_UP_SECONDS` once the cooldown is over, then fire one heavy shot
+    ChargeUp { charge: f32 },
+    /// Fire faster with every shot at the same target; a new target starts over
+    Ramping { target: Option<Entity>, multiplier: f32 },
+}
+
+impl FiringPattern {
+    pub fn for_tower(tower_type: TowerType) -> Self {
+        match tower_type {
+            TowerType::Advanced => FiringPattern::Burst { rounds_fired: 0 },
+            TowerType::Laser => FiringPattern::ChargeUp { charge: 0.0 },
+            TowerType::Tesla => FiringPattern::Ramping { target: None, multiplier: 1.0 },
+            TowerType::Basic | TowerType::Missile => FiringPattern::Uniform,
+        }
+    }
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            FiringPattern::Uniform => "Single Shot",
+            FiringPattern::Burst { .. } => "Burst",
+            FiringPattern::ChargeUp { .. } => "Charge-Up",
+            FiringPattern::Ramping { .. } => "Ramping",
+        }
+    }
+
+    pub fn get_description(&self) -> String {
+        match self {
+            FiringPattern::Uniform => "One shot per cooldown".to_string(),
+            FiringPattern::Burst { .. } => {
+                format!("{} rounds {:.1}s apart", BURST_ROUNDS, BURST_ROUND_DELAY)
+            }
+            FiringPattern::ChargeUp { .. } => {
+                format!("Charges {:.0}s, then hits for x{:.0} damage", CHARGE_UP_SECONDS, CHARGED_SHOT_DAMAGE_MULTIPLIER)
+            }
+            FiringPattern::Ramping { .. } => {
+                format!("Fires faster on one target, up to x{:.1}", MAX_RAMP_MULTIPLIER)
+            }
+        }
+    }
+
+    /// Live state worth showing, e.g. "Charging 40%", or `None` when idle
+    pub fn status_text(&self) -> Option<String> {
+        match self {
+            FiringPattern::ChargeUp { charge } if *charge > 0.0 => {
+                Some(format!("Charging {:.0}%", (charge / CHARGE_UP_SECONDS).min(1.0) * 100.0))
+            }
+            FiringPattern::Ramping { multiplier, .. } if *multiplier > 1.0 => {
+                Some(format!("Ramped x{:.2}", multiplier))
+            }
+            _ => None,
+        }
+    }
+
+    /// Decide whether the tower fires at `target` this frame
+    ///
+    /// # Arguments
+    /// * `since_last_shot` - Seconds since the tower last fired
+    /// * `cooldown` - Seconds between shots at the tower's current fire rate
+    /// * `delta_secs` - Length of this frame
+    ///
+    /// # Returns
+    /// * `Some(multiplier)` - Fire, with the shot's damage multiplied by `multiplier`
+    /// * `None` - Hold fire
+    pub fn try_fire(&mut self, target: Entity, since_last_shot: f32, cooldown: f32, delta_secs: f32) -> Option<f32> {
+        match self {
+            FiringPattern::Uniform => (since_last_shot >= cooldown).then_some(1.0),
+            FiringPattern::Burst { rounds_fired } => {
+                let wait = if *rounds_fired == 0 {
+                    let burst_delays = (BURST_ROUNDS - 1) as f32 * BURST_ROUND_DELAY;
+                    (cooldown * BURST_ROUNDS as f32 - burst_delays).max(BURST_ROUND_DELAY)
+                } else {
+                    BURST_ROUND_DELAY
+                };
+                if since_last_shot < wait {
+                    return None;
+                }
+                *rounds_fired = (*rounds_fired + 1) % BURST_ROUNDS;
+                Some(1.0)
+            }
+            FiringPattern::ChargeUp { charge } => {
+                if since_last_shot < cooldown {
+                    return None;
+                }
+                *charge += delta_secs;
+                if *charge < CHARGE_UP_SECONDS {
+                    return None;
+                }
+                *charge = 0.0;
+                Some(CHARGED_SHOT_DAMAGE_MULTIPLIER)
+            }
+            FiringPattern::Ramping { target: current, multiplier } => {
+                if *current != Some(target) {
+                    *current = Some(target);
+                    *multiplier = 1.0;
+                }
+                if since_last_shot < cooldown / *multiplier {
+                    return None;
+                }
+                *multiplier = (*multiplier + RAMP_PER_SHOT).min(MAX_RAMP_MULTIPLIER);
+                Some(1.0)
+            }
+        }
+    }
+
+    /// The tower has nothing to shoot at: drop any charge, ramp or half-fired burst
+    pub fn lose_target(&mut self) {
+        match self {
+            FiringPattern::Uniform => {}
+            FiringPattern::Burst { rounds_fired } => *rounds_fired = 0,
+            FiringPattern::ChargeUp { charge } => *charge = 0.0,
+            FiringPattern::Ramping { target, multiplier } => {
+                *target = None;
+                *multiplier = 1.0;
+            }
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use bevy::prelude::*;
+
+/// Fire-rate multiplier while a tower is overclocked
+pub const OVERCLOCK_FIRE_RATE_MULTIPLIER: f32 = 1.5;
+/// Heat at which an overclocked tower overheats and shuts down
+pub const MAX_HEAT: f32 = 100.0;
+/// Heat gained per second while overclocked (overheats after 6 seconds)
+pub const OVERCLOCK_HEAT_PER_SECOND: f32 = MAX_HEAT / 6.0;
+/// Heat lost per second while not overclocked
+pub const HEAT_DISSIPATION_PER_SECOND: f32 = 15.0;
+/// How long an overheated tower stays offline
+pub const OVERHEAT_SHUTDOWN_SECONDS: f32 = 4.0;
+
+/// Overclock and heat state of a tower. Overclocking boosts fire rate but
+/// builds heat; reaching `MAX_HEAT` shuts the tower down until it cools off.
+#[derive(Component, Debug, Clone)]
+pub struct Heat {
+    pub current: f32,
+    pub overclocked: bool,
+    /// Running while the tower is offline after overheating
+    pub shutdown_timer: Option<Timer>,
+}
+
+impl Default for Heat {
+    fn default() -> Self {
+        Self {
+            current: 0.0,
+            overclocked: false,
+            shutdown_timer: None,
+        }
+    }
+}
+
+impl Heat {
+    /// Toggle overclock. Returns false if the tower is shut down and can't overclock.
+    pub fn toggle_overclock(&mut self) -> bool {
+        if self.is_shut_down() {
+            return false;
+        }
+        self.overclocked = !self.overclocked;
+        true
+    }
+
+    /// Advance heat by `delta_secs`. Returns true on the tick the tower overheats.
+    pub fn tick(&mut self, delta_secs: f32) -> bool {
+        if let Some(timer) = self.shutdown_timer.as_mut() {
+            timer.tick(std::time::Duration::from_secs_f32(delta_secs));
+            self.current = (self.current - HEAT_DISSIPATION_PER_SECOND * delta_secs).max(0.0);
+            if timer.finished() {
+                self.shutdown_timer = None;
+            }
+            return false;
+        }
+
+        if self.overclocked {
+            self.current = (self.current + OVERCLOCK_HEAT_PER_SECOND * delta_secs).min(MAX_HEAT);
+            if self.current >= MAX_HEAT {
+                self.overclocked = false;
+                self.shutdown_timer = Some(Timer::from_seconds(OVERHEAT_SHUTDOWN_SECONDS, TimerMode::Once));
+                return true;
+            }
+        } else {
+            self.current = (self.current - HEAT_DISSIPATION_PER_SECOND * delta_secs).max(0.0);
+        }
+        false
+    }
+
+    /// Drop all heat and bring a shut-down tower back online
+    pub fn vent(&mut self) {
+        self.current = 0.0;
+        self.shutdown_timer = None;
+    }
+
+    pub fn is_shut_down(&self) -> bool {
+        self.shutdown_timer.is_some()
+    }
+
+    /// Heat level from 0.0 (cold) to 1.0 (overheated)
+    pub fn fraction(&self) -> f32 {
+        (self.current / MAX_HEAT).clamp(0.0, 1.0)
+    }
+
+    pub fn fire_rate_multiplier(&self) -> f32 {
+        if self.overclocked {
+            OVERCLOCK_FIRE_RATE_MULTIPLIER
+        } else {
+            1.0
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+use super::EnemyType;
+
+/// Kinds of pickups an enemy can drop on death
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PickupKind {
+    /// Instant money
+    CashBundle,
+    /// Temporary fire-rate boost for every tower
+    FireRateBoost,
+    /// Every tower's fire cooldown is reset and its overclock heat vented
+    CooldownReset,
+}
+
+impl PickupKind {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            PickupKind::CashBundle => "Cash Bundle",
+            PickupKind::FireRateBoost => "Fire Rate Boost",
+            PickupKind::CooldownReset => "Cooldown Reset",
+        }
+    }
+
+    pub fn get_color(&self) -> Color {
+        match self {
+            PickupKind::CashBundle => Color::srgb(1.0, 0.85, 0.2),
+            PickupKind::FireRateBoost => Color::srgb(1.0, 0.45, 0.2),
+            PickupKind::CooldownReset => Color::srgb(0.3, 0.8, 1.0),
+        }
+    }
+}
+
+/// One weighted entry of a loot table
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LootEntry {
+    pub kind: PickupKind,
+    pub weight: f32,
+}
+
+/// Loot table attached to an enemy when it spawns. Each enemy type carries
+/// its own table; enemies without one never drop anything.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct LootTable {
+    /// Chance (0.0-1.0) that the enemy drops anything at all
+    pub drop_chance: f32,
+    pub entries: Vec<LootEntry>,
+    /// Money granted by a cash bundle from this enemy
+    pub cash_amount: u32,
+}
+
+impl LootTable {
+    /// Loot table for an enemy of the given type and wave. Tougher types drop
+    /// more often, and their cash bundles scale with their kill reward.
+    pub fn for_enemy(enemy_type: EnemyType, wave_number: u32) -> Self {
+        let wave = wave_number.max(1);
+        let (drop_chance, [cash, fire_rate, cooldown]) = match enemy_type {
+            EnemyType::Basic => (0.08, [6.0, 3.0, 1.0]),
+            EnemyType::Fast => (0.06, [4.0, 5.0, 1.0]),
+            EnemyType::Tank => (0.15, [8.0, 1.0, 1.0]),
+            EnemyType::Flying => (0.10, [3.0, 2.0, 5.0]),
+            EnemyType::Shielded => (0.12, [5.0, 3.0, 2.0]),
+            EnemyType::Boss => (1.0, [5.0, 3.0, 2.0]),
+        };
+        Self {
+            drop_chance,
+            entries: vec![
+                LootEntry { kind: PickupKind::CashBundle, weight: cash },
+                LootEntry { kind: PickupKind::FireRateBoost, weight: fire_rate },
+                LootEntry { kind: PickupKind::CooldownReset, weight: cooldown },
+            ],
+            cash_amount: (15 + wave * 3) * enemy_type.reward_multiplier(),
+        }
+    }
+
+    /// Decide the drop from two uniform random numbers in 0.0..1.0:
+    /// `drop_roll` against the drop chance, `pick_roll` across the entry weights
+    pub fn roll(&self, drop_roll: f32, pick_roll: f32) -> Option<PickupKind> {
+        if drop_roll >= self.drop_chance {
+            return None;
+        }
+
+        let total_weight: f32 = self.entries.iter().map(|entry| entry.weight.max(0.0)).sum();
+        if total_weight <= 0.0 {
+            return None;
+        }
+
+        let mut remaining = pick_roll.clamp(0.0, 1.0) * total_weight;
+        for entry in &self.entries {
+            let weight = entry.weight.max(0.0);
+            if remaining < weight {
+                return Some(entry.kind);
+            }
+            remaining -= weight;
+        }
+
+        // pick_roll of exactly 1.0 lands on the last weighted entry
+        self.entries.iter().rev().find(|entry| entry.weight > 0.0).map(|entry| entry.kind)
+    }
+}
+
+/// Pickup lying on the battlefield, waiting to be clicked before it expires
+#[derive(Component, Debug)]
+pub struct LootPickup {
+    pub kind: PickupKind,
+    /// Money for cash bundles (unused by other kinds)
+    pub cash_amount: u32,
+    pub lifetime: Timer,
+}
+
+impl LootPickup {
+    pub fn new(kind: PickupKind, cash_amount: u32, lifetime: f32) -> Self {
+        Self {
+            kind,
+            cash_amount,
+            lifetime: Timer::from_seconds(lifetime, TimerMode::Once),
+        }
+    }
+
+    /// Seconds left before the pickup disappears
+    pub fn remaining_secs(&self) -> f32 {
+        self.lifetime.remaining_secs()
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.lifetime.finished()
+    }
+}
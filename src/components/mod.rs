@@ -3,15 +3,53 @@ pub mod enemy;
 pub mod projectile;
 pub mod health;
 pub mod position;
+pub mod construction;
+pub mod loot;
+pub mod heat;
+pub mod base;
+pub mod effect;
+pub mod firing;
+pub mod status_effects;
 
 pub use tower::*;
 pub use enemy::*;
 pub use projectile::*;
 pub use health::*;
 pub use position::*;
+pub use construction::*;
+pub use loot::*;
+pub use heat::*;
+pub use base::*;
+pub use effect::*;
+pub use firing::*;
+pub use status_effects::*;
 
-use bevy::prelude::Component;
+use bevy::prelude::{Component, Vec2};
 
 /// Marker component for path visualization entities that need to be updated when path changes
 #[derive(Component)]
-pub struct PathVisualization;
\ No newline at end of file
+pub struct PathVisualization;
+
+/// Ends of the path stretch a path visualization sprite covers, for coverage tinting
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct PathSegment {
+    pub start: Vec2,
+    pub end: Vec2,
+}
+/// A dot, chevron arm or dash drawn as a child of a path segment by the selected path style
+#[derive(Component)]
+pub struct PathMark;
+
+/// Stripe in its route's color drawn beside a path segment on maps with several routes
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct PathRouteStripe {
+    pub route: usize,
+}
+
+/// A flowing dash that slides along its segment in the direction of travel, wrapping
+/// back by `cycle` once it passes `start + cycle` in the segment's local x axis
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct PathFlowDash {
+    pub start: f32,
+    pub cycle: f32,
+}
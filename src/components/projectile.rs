@@ -1,6 +1,23 @@
 use bevy::prelude::*;
 use crate::resources::TowerType;
 
+/// Hit radius of a projectile; a hit lands within this plus the enemy's radius
+pub const PROJECTILE_HIT_RADIUS: f32 = 6.0;
+/// Fraction of a projectile's damage dealt at the very edge of its blast
+pub const SPLASH_EDGE_DAMAGE: f32 = 0.25;
+/// How long an explosion visual lingers before it is gone
+pub const EXPLOSION_DURATION: f32 = 0.3;
+/// How long a laser beam stays on screen after it strikes
+pub const BEAM_DURATION: f32 = 0.1;
+/// How fast homing missiles turn, in radians per second
+pub const MISSILE_TURN_RATE: f32 = 5.0;
+/// Angle off the line to their target that missiles launch at, so they curve in
+pub const MISSILE_LAUNCH_ANGLE: f32 = 0.6;
+/// Distance from the target within which missiles fly straight at it, so they can't orbit it
+pub const MISSILE_TERMINAL_DISTANCE: f32 = 40.0;
+/// Furthest a Tesla bolt bows out from the straight line to its target
+pub const ARC_HEIGHT: f32 = 30.0;
+
 #[derive(Component)]
 pub struct Projectile {
     pub damage: f32,
@@ -8,6 +25,16 @@ pub struct Projectile {
     pub target_entity: Entity,
     pub target_position: Vec2,   // Where the target was when fired
     pub tower_type: TowerType,   // For different projectile behaviors
+    pub splash_radius: f32,      // Blast radius on impact, 0 for single-target shots
+    pub chain_targets: u32,      // Extra enemies chain lightning jumps to on impact
+    pub burn_dps: f32,           // Damage per second of the burn lit on the enemies hit
+    pub slow: f32,               // Share of speed taken from the enemies hit
+}
+
+/// Brief visual left where a splash projectile detonated
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Explosion {
+    pub radius: f32,
 }
 
 impl Projectile {
@@ -18,8 +45,95 @@ impl Projectile {
             target_entity,
             target_position,
             tower_type,
+            splash_radius: 0.0,
+            chain_targets: 0,
+            burn_dps: 0.0,
+            slow: 0.0,
+        }
+    }
+
+    /// Carry the status effects a tower's hits apply: chain lightning, burn and slow
+    pub fn with_status_effects(mut self, chain_targets: u32, burn_dps: f32, slow: f32) -> Self {
+        self.chain_targets = chain_targets;
+        self.burn_dps = burn_dps.max(0.0);
+        self.slow = slow.max(0.0);
+        self
+    }
+
+    /// Detonate on impact, damaging every enemy within `radius`
+    pub fn with_splash(mut self, radius: f32) -> Self {
+        self.splash_radius = radius.max(0.0);
+        self
+    }
+
+    pub fn has_splash(&self) -> bool {
+        self.splash_radius > 0.0
+    }
+
+    /// Damage dealt to an enemy `distance` away from the impact point, falling off
+    /// linearly to `SPLASH_EDGE_DAMAGE` at the edge. None outside the blast.
+    pub fn splash_damage_at(&self, distance: f32) -> Option<f32> {
+        if !self.has_splash() || distance > self.splash_radius {
+            return None;
+        }
+        let falloff = 1.0 - (1.0 - SPLASH_EDGE_DAMAGE) * (distance / self.splash_radius);
+        Some(self.damage * falloff)
+    }
+}
+
+/// How a projectile travels to its target, by the tower that fired it.
+/// Projectiles without one fly straight like bullets.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub enum ProjectileKind {
+    /// Flies straight at the target
+    Bullet,
+    /// Laser: strikes the target the frame it fires, drawn as a line from the tower
+    Beam { fired: bool },
+    /// Missile: turns toward the target at `MISSILE_TURN_RATE`
+    Homing { heading: Vec2 },
+    /// Tesla: bows out to one side on its way to the target
+    Arc { origin: Vec2, travelled: f32 },
+}
+
+impl ProjectileKind {
+    /// The projectile a tower type fires from `origin` at a target at `target`
+    pub fn for_tower(tower_type: TowerType, origin: Vec2, target: Vec2) -> Self {
+        match tower_type {
+            TowerType::Basic | TowerType::Advanced => ProjectileKind::Bullet,
+            TowerType::Laser => ProjectileKind::Beam { fired: false },
+            TowerType::Missile => ProjectileKind::Homing {
+                heading: Vec2::from_angle(MISSILE_LAUNCH_ANGLE).rotate((target - origin).normalize_or(Vec2::X)),
+            },
+            TowerType::Tesla => ProjectileKind::Arc { origin, travelled: 0.0 },
         }
     }
+
+    /// Size of the projectile's sprite; beams have none and are drawn as a line instead
+    pub fn sprite_size(&self) -> Option<Vec2> {
+        match self {
+            ProjectileKind::Bullet => Some(Vec2::splat(6.0)),
+            ProjectileKind::Beam { .. } => None,
+            ProjectileKind::Homing { .. } => Some(Vec2::new(10.0, 4.0)),
+            ProjectileKind::Arc { .. } => Some(Vec2::splat(5.0)),
+        }
+    }
+}
+
+/// Turn `heading` toward `desired` by at most `max_turn` radians
+pub fn steer(heading: Vec2, desired: Vec2, max_turn: f32) -> Vec2 {
+    if desired == Vec2::ZERO {
+        return heading;
+    }
+    let turn = heading.angle_to(desired).clamp(-max_turn, max_turn);
+    Vec2::from_angle(turn).rotate(heading)
+}
+
+/// Point `t` of the way along a Tesla bolt's arc from `origin` to `target`,
+/// bowing out furthest halfway. Shorter shots bow out less.
+pub fn arc_position(origin: Vec2, target: Vec2, t: f32) -> Vec2 {
+    let span = target - origin;
+    let height = ARC_HEIGHT.min(span.length() * 0.25);
+    origin + span * t + span.perp().normalize_or_zero() * height * (std::f32::consts::PI * t).sin()
 }
 
 // Removed Default implementation to prevent Entity::PLACEHOLDER usage
@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+use crate::resources::TowerType;
+
+/// Seconds a slow lasts after the last hit that applied it
+pub const SLOW_DURATION: f32 = 2.0;
+/// Largest share of speed a slow can take away
+pub const MAX_SLOW: f32 = 0.8;
+/// Seconds a burn keeps dealing damage after the last hit that lit it
+pub const BURN_DURATION: f32 = 3.0;
+/// Seconds between burn damage ticks
+pub const BURN_TICK_INTERVAL: f32 = 0.5;
+/// Furthest chain lightning jumps from one enemy to the next
+pub const CHAIN_JUMP_RADIUS: f32 = 70.0;
+/// Share of the previous jump's damage each chain jump deals
+pub const CHAIN_DAMAGE_FALLOFF: f32 = 0.7;
+/// Seconds an enemy struck by chain lightning can't be chained to again
+pub const CHAINED_DURATION: f32 = 0.25;
+
+/// Slows an enemy's movement until it wears off. Reapplying refreshes it.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Slow {
+    /// Share of speed taken away, from 0 to `MAX_SLOW`
+    pub amount: f32,
+    pub remaining: f32,
+}
+
+impl Slow {
+    pub fn new(amount: f32, duration: f32) -> Self {
+        Self {
+            amount: amount.clamp(0.0, MAX_SLOW),
+            remaining: duration,
+        }
+    }
+
+    /// Multiplier applied to the enemy's speed
+    pub fn speed_multiplier(&self) -> f32 {
+        1.0 - self.amount
+    }
+
+    /// Count down; returns true once the slow has worn off
+    pub fn tick(&mut self, delta_secs: f32) -> bool {
+        self.remaining -= delta_secs;
+        self.remaining <= 0.0
+    }
+}
+
+/// Damage over time, dealt in ticks every `BURN_TICK_INTERVAL`. Reapplying refreshes it.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Burn {
+    pub damage_per_second: f32,
+    pub remaining: f32,
+    /// Tower type credited with the burn's damage and kills
+    pub tower_type: TowerType,
+    since_tick: f32,
+}
+
+impl Burn {
+    pub fn new(damage_per_second: f32, duration: f32, tower_type: TowerType) -> Self {
+        Self {
+            damage_per_second,
+            remaining: duration,
+            tower_type,
+            since_tick: 0.0,
+        }
+    }
+
+    /// Count down, returning the damage due this frame: a tick's worth each
+    /// time a tick completes, and whatever is left of a partial tick when the
+    /// burn runs out
+    pub fn tick(&mut self, delta_secs: f32) -> f32 {
+        let elapsed = delta_secs.min(self.remaining.max(0.0));
+        self.remaining -= delta_secs;
+        self.since_tick += elapsed;
+
+        let mut damage = 0.0;
+        while self.since_tick >= BURN_TICK_INTERVAL {
+            self.since_tick -= BURN_TICK_INTERVAL;
+            damage += self.damage_per_second * BURN_TICK_INTERVAL;
+        }
+        if self.is_finished() && self.since_tick > 0.0 {
+            damage += self.damage_per_second * self.since_tick;
+            self.since_tick = 0.0;
+        }
+        damage
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.remaining <= 0.0
+    }
+}
+
+/// Marks an enemy chain lightning just struck, so the next jumps look elsewhere
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Chained {
+    pub remaining: f32,
+}
+
+impl Default for Chained {
+    fn default() -> Self {
+        Self {
+            remaining: CHAINED_DURATION,
+        }
+    }
+}
+
+impl Chained {
+    /// Count down; returns true once the enemy can be chained to again
+    pub fn tick(&mut self, delta_secs: f32) -> bool {
+        self.remaining -= delta_secs;
+        self.remaining <= 0.0
+    }
+}
+
+/// Pick the enemies chain lightning jumps to after striking the enemy at `start`:
+/// each jump goes to the nearest candidate within `CHAIN_JUMP_RADIUS` of the last
+/// enemy struck, up to `max_jumps`. Returns indices into `candidates` with the
+/// damage each takes, starting from `damage` and falling off per jump.
+pub fn chain_jumps(start: Vec2, candidates: &[Vec2], max_jumps: u32, damage: f32) -> Vec<(usize, f32)> {
+    let mut jumps = Vec::new();
+    let mut struck = vec![false; candidates.len()];
+    let mut from = start;
+    let mut jump_damage = damage;
+    for _ in 0..max_jumps {
+        let next = candidates
+            .iter()
+            .enumerate()
+            .filter(|(index, position)| !struck[*index] && from.distance(**position) <= CHAIN_JUMP_RADIUS)
+            .min_by(|(_, a), (_, b)| from.distance_squared(**a).total_cmp(&from.distance_squared(**b)))
+            .map(|(index, _)| index);
+        let Some(index) = next else {
+            break;
+        };
+        struck[index] = true;
+        jump_damage *= CHAIN_DAMAGE_FALLOFF;
+        jumps.push((index, jump_damage));
+        from = candidates[index];
+    }
+    jumps
+}
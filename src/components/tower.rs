@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use crate::resources::TowerType;
 
 #[derive(Component)]
 pub struct Tower {
@@ -17,4 +18,79 @@ impl Default for Tower {
             last_shot: 0.0,
         }
     }
-}
\ No newline at end of file
+}
+
+/// Upgrade level from which a tower can specialize down one of its branches
+pub const BRANCH_LEVEL: u32 = 3;
+
+/// A specialization a tower commits to once it reaches `BRANCH_LEVEL`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum UpgradeBranch {
+    Sniper,
+    Rapid,
+    Heavy,
+    Gatling,
+    Scorch,
+    Lens,
+    Cluster,
+    Hunter,
+    Storm,
+    Overload,
+}
+
+impl UpgradeBranch {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            UpgradeBranch::Sniper => "Sniper",
+            UpgradeBranch::Rapid => "Rapid",
+            UpgradeBranch::Heavy => "Heavy",
+            UpgradeBranch::Gatling => "Gatling",
+            UpgradeBranch::Scorch => "Scorch",
+            UpgradeBranch::Lens => "Lens",
+            UpgradeBranch::Cluster => "Cluster",
+            UpgradeBranch::Hunter => "Hunter",
+            UpgradeBranch::Storm => "Storm",
+            UpgradeBranch::Overload => "Overload",
+        }
+    }
+
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            UpgradeBranch::Sniper => "Long range, heavy shots, slow reload",
+            UpgradeBranch::Rapid => "Fires much faster for less damage",
+            UpgradeBranch::Heavy => "Big hits at a slower pace",
+            UpgradeBranch::Gatling => "Doubles fire rate, lighter rounds",
+            UpgradeBranch::Scorch => "Burns far hotter on every hit",
+            UpgradeBranch::Lens => "Longer, harder beam that no longer burns",
+            UpgradeBranch::Cluster => "Much wider blasts, lighter warheads",
+            UpgradeBranch::Hunter => "Single-target warheads with long reach",
+            UpgradeBranch::Storm => "Lightning jumps to two more enemies",
+            UpgradeBranch::Overload => "No chaining; hard hits that slow",
+        }
+    }
+}
+
+/// The branches a tower type can specialize into at `BRANCH_LEVEL`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UpgradePath {
+    pub tower_type: TowerType,
+    pub branches: [UpgradeBranch; 2],
+}
+
+impl UpgradePath {
+    pub fn for_tower(tower_type: TowerType) -> Self {
+        let branches = match tower_type {
+            TowerType::Basic => [UpgradeBranch::Sniper, UpgradeBranch::Rapid],
+            TowerType::Advanced => [UpgradeBranch::Heavy, UpgradeBranch::Gatling],
+            TowerType::Laser => [UpgradeBranch::Scorch, UpgradeBranch::Lens],
+            TowerType::Missile => [UpgradeBranch::Cluster, UpgradeBranch::Hunter],
+            TowerType::Tesla => [UpgradeBranch::Storm, UpgradeBranch::Overload],
+        };
+        Self { tower_type, branches }
+    }
+
+    /// Whether `branch` is one of this tower type's branches
+    pub fn offers(&self, branch: UpgradeBranch) -> bool {
+        self.branches.contains(&branch)
+    }
+}
@@ -1 +1,435 @@
-use bevy::prelude::*;
\ No newline at end of file
+use bevy::prelude::*;
+
+use crate::resources::{BalanceConfig, Economy, GameConstants, GameRng, GameState, Score, WavePlan, WaveRuntime, EnemyPath, AppState, GameSystemSet, CombatSet, EnemySet};
+use crate::systems::enemy_system::{enemy_spawning_system, enemy_movement_system, enemy_cleanup_system};
+use crate::systems::input_system::{mouse_input_system, tower_placement_system, tower_placement_preview_system, MouseInputState, auto_grid_mode_system};
+use crate::systems::ui_system::update_ui_system;
+use crate::systems::combat_system::{tower_targeting_system, projectile_spawning_system, projectile_movement_system, collision_system, game_state_system, WaveStatus, EnemyKilledEvent, EnemyDamagedEvent, TowerFiredEvent};
+use crate::systems::debug_visualization::{DebugVisualizationState, debug_visualization_system};
+use crate::systems::debug_ui::DebugUIPlugin;
+use crate::systems::debug_ui::cheat_menu::CheatMenuState;
+use crate::systems::input::InputRegistryPluginBuilder;
+use crate::systems::enemy_system::{auto_start_wave_system, manual_wave_system, path_generation_system, path_visualization_system, path_coverage_tint_system, path_flow_system, EnemyEscapedEvent, StartWaveEvent};
+use crate::systems::tower_ui::{
+    TowerSelectionState,
+    TowerStatPopupState,
+    setup_tower_placement_panel,
+    setup_tower_upgrade_panel,
+    setup_tower_stat_popup,
+    tower_selection_system,
+    tower_type_button_system,
+    upgrade_button_system,
+    branch_button_system,
+    branch_button_label_system,
+    targeting_button_system,
+    targeting_button_label_system,
+    update_upgrade_panel_system,
+    selected_tower_indicator_system,
+    range_indicator_system,
+    update_resource_status_system,
+    tower_cost_label_system,
+    tower_tooltip_system,
+    tower_affordability_system,
+    tower_stat_popup_system,
+    hover_stat_popup_system,
+    popup_close_button_system,
+    popup_outside_click_system,
+    start_wave_button_system,
+    update_start_wave_button_system,
+};
+use crate::systems::unified_grid::{
+    UnifiedGridSystem,
+    setup_unified_grid,
+    update_grid_visualization,
+};
+use crate::systems::obstacle_rendering::{ObstacleGrid, ObstacleRenderingPlugin};
+use crate::systems::tower_rendering::TowerRenderingPlugin;
+use crate::systems::construction_system::ConstructionPlugin;
+use crate::systems::results_screen::ResultsScreenPlugin;
+use crate::systems::tween::TweenPlugin;
+use crate::systems::system_order::SystemOrderPlugin;
+use crate::systems::loot_system::LootPlugin;
+use crate::systems::overclock_system::OverclockPlugin;
+use crate::systems::multi_select_system::MultiSelectPlugin;
+use crate::systems::checkpoint_system::CheckpointPlugin;
+use crate::systems::action_camera::ActionCameraPlugin;
+use crate::systems::ui_feedback::{UiFeedbackEvent, UiFeedbackPlugin};
+use crate::systems::advisor_system::AdvisorPlugin;
+use crate::systems::spawn_preview::SpawnPreviewPlugin;
+use crate::systems::wave_preview_panel::WavePreviewPanelPlugin;
+use crate::systems::smart_enemy_system::SmartEnemyPlugin;
+use crate::systems::base_system::BasePlugin;
+use crate::systems::shop_system::ShopPlugin;
+use crate::systems::threat_alert_system::ThreatAlertPlugin;
+use crate::systems::balance_system::BalancePlugin;
+use crate::systems::effect_budget_system::EffectBudgetPlugin;
+use crate::systems::help_overlay::HelpOverlayPlugin;
+use crate::systems::enemy_count_hud::EnemyCountHudPlugin;
+use crate::systems::stress_test_system::StressTestPlugin;
+use crate::systems::map_share_system::MapSharePlugin;
+use crate::systems::placement_assist::PlacementAssistPlugin;
+use crate::systems::boss_arena_system::BossArenaPlugin;
+use crate::systems::game_snapshot::GameSnapshotPlugin;
+use crate::systems::focus_zone_system::FocusZonePlugin;
+use crate::systems::simulation_clock_system::SimulationClockPlugin;
+use crate::systems::reward_chest_system::RewardChestPlugin;
+use crate::systems::codex_system::CodexPlugin;
+use crate::systems::hit_feedback_system::HitFeedbackPlugin;
+use crate::systems::floating_text::FloatingTextPlugin;
+use crate::systems::tower_animation::TowerAnimationPlugin;
+use crate::systems::enemy_death_system::EnemyDeathPlugin;
+use crate::systems::particles::ParticlePlugin;
+use crate::systems::map_select::MapSelectPlugin;
+use crate::systems::seed_entry::SeedEntryPlugin;
+use crate::systems::map_editor::MapEditorPlugin;
+use crate::systems::void_terrain_system::VoidTerrainPlugin;
+use crate::systems::suspend_system::SuspendPlugin;
+use crate::systems::bounty_system::BountyPlugin;
+use crate::systems::frame_pacing_system::FramePacingPlugin;
+use crate::systems::save_load::SaveLoadPlugin;
+use crate::systems::occupancy::OccupancyPlugin;
+use crate::systems::main_menu::MainMenuPlugin;
+use crate::systems::seasonal_event_system::SeasonalEventPlugin;
+use crate::systems::remote_commands::RemoteCommandPlugin;
+use crate::systems::wave_config_system::WaveConfigPlugin;
+use crate::systems::privacy_system::PrivacyPlugin;
+use crate::systems::crowding_system::CrowdingPlugin;
+use crate::systems::screen_shake::ScreenShakePlugin;
+use crate::systems::first_breach_system::FirstBreachPlugin;
+use crate::systems::market_system::MarketPlugin;
+use crate::systems::security::RunIntegrityPlugin;
+use crate::systems::whats_new::WhatsNewPlugin;
+use crate::systems::leaderboard_page::LeaderboardPagePlugin;
+use crate::systems::controls_menu::ControlsMenuPlugin;
+use crate::systems::status_effect_system::status_effect_system;
+use crate::systems::virtual_cursor::VirtualCursorPlugin;
+use crate::systems::input::GamepadInputPlugin;
+use crate::systems::path_generation::{current_level_seed, generate_level_path, FixedLevelPath, LevelConfig};
+use crate::systems::pause_system::PauseSystemPlugin;
+use crate::systems::settings_menu::{SettingsSystemPlugin, GameSettings, SettingsLoadError};
+use crate::systems::debug_toggle::DebugTogglePlugin;
+
+/// The whole game as one plugin, on top of bevy's `DefaultPlugins`.
+///
+/// Every subsystem is on by default; embedders can opt out of the optional
+/// ones through `TowerDefensePluginBuilder`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TowerDefensePlugin {
+    /// Debug UI, cheat menu, debug visualization, stress test and the F-key handlers
+    pub debug_tools: bool,
+    /// UI sound cues and gamepad rumble
+    pub audio: bool,
+    /// Generated paths and obstacles. When off, the app's own `EnemyPath`
+    /// resource is used for every run.
+    pub procedural_generation: bool,
+    /// The backtick gate in front of the debug tools. When off, the debug tools
+    /// are reachable without unlocking them first.
+    pub security: bool,
+}
+
+impl TowerDefensePlugin {
+    /// Everything enabled, as the game ships
+    pub const DEFAULT: Self = TowerDefensePluginBuilder::new().build();
+}
+
+// Defaults are checked when the crate compiles
+const _: () = assert!(
+    TowerDefensePlugin::DEFAULT.debug_tools
+        && TowerDefensePlugin::DEFAULT.audio
+        && TowerDefensePlugin::DEFAULT.procedural_generation
+        && TowerDefensePlugin::DEFAULT.security
+);
+
+impl Default for TowerDefensePlugin {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Plugin for TowerDefensePlugin {
+    fn build(&self, app: &mut App) {
+        // Settings supplied by the embedding app win over settings.json
+        if !app.world().contains_resource::<GameSettings>() {
+            let (game_settings, settings_load_error) = GameSettings::load();
+            app.insert_resource(game_settings)
+                .insert_resource(SettingsLoadError(settings_load_error));
+        }
+
+        // ORDER MATTERS: SettingsSystemPlugin must come before DebugTogglePlugin
+        app.add_plugins(SettingsSystemPlugin);
+        if self.security {
+            app.add_plugins(DebugTogglePlugin); // Simple debug feature toggle
+        }
+
+        let mut input_registry = InputRegistryPluginBuilder::new();
+        if !self.debug_tools {
+            input_registry = input_registry.without_auto_registration();
+        }
+        app.add_plugins(input_registry.build()); // Centralized input handling
+
+        if self.debug_tools {
+            app.add_plugins((DebugUIPlugin, StressTestPlugin))
+                .init_resource::<DebugVisualizationState>()
+                .add_systems(
+                    Update,
+                    debug_visualization_system
+                        .in_set(GameSystemSet::Gameplay)
+                        .run_if(in_state(AppState::Playing)),
+                );
+        }
+
+        if self.audio {
+            app.add_plugins(UiFeedbackPlugin);
+        } else {
+            // Gameplay and UI still send feedback cues; nothing plays them
+            app.add_event::<UiFeedbackEvent>();
+        }
+
+        if self.procedural_generation {
+            app.add_plugins(ObstacleRenderingPlugin)
+                .insert_resource(generate_level_path(1)); // Start with wave 1 generated path
+        } else {
+            if !app.world().contains_resource::<EnemyPath>() {
+                warn!("Procedural generation is off but no EnemyPath was supplied, using a straight path");
+                app.insert_resource(create_default_path());
+            }
+            app.init_resource::<ObstacleGrid>()
+                .insert_resource(FixedLevelPath);
+        }
+
+        app
+            .add_plugins(TowerRenderingPlugin)
+            .add_plugins(ConstructionPlugin)
+            .add_plugins(PauseSystemPlugin)
+            .add_plugins(ResultsScreenPlugin)
+            .add_plugins(CheckpointPlugin)
+            .add_plugins(TweenPlugin)
+            .add_plugins(LootPlugin)
+            .add_plugins(OverclockPlugin)
+            .add_plugins(MultiSelectPlugin)
+            .add_plugins(ActionCameraPlugin)
+            .add_plugins(AdvisorPlugin)
+            .add_plugins(SpawnPreviewPlugin)
+            .add_plugins(WavePreviewPanelPlugin)
+            .add_plugins(SmartEnemyPlugin)
+            .add_plugins(BasePlugin)
+            .add_plugins(ShopPlugin)
+            .add_plugins(ThreatAlertPlugin)
+            .add_plugins(BalancePlugin)
+            .add_plugins(EffectBudgetPlugin)
+            .add_plugins(HelpOverlayPlugin)
+            .add_plugins(EnemyCountHudPlugin)
+            .add_plugins(MapSharePlugin)
+            .add_plugins(PlacementAssistPlugin)
+            .add_plugins(BossArenaPlugin)
+            .add_plugins(GameSnapshotPlugin)
+            .add_plugins(FocusZonePlugin)
+            .add_plugins(SimulationClockPlugin)
+            .add_plugins(RewardChestPlugin)
+            .add_plugins(CodexPlugin)
+            .add_plugins(VirtualCursorPlugin)
+            .add_plugins(GamepadInputPlugin)
+            .add_plugins(HitFeedbackPlugin)
+            .add_plugins(FloatingTextPlugin)
+            .add_plugins(TowerAnimationPlugin)
+            .add_plugins(EnemyDeathPlugin)
+            .add_plugins(ParticlePlugin)
+            .add_plugins(VoidTerrainPlugin)
+            .add_plugins(SuspendPlugin)
+            .add_plugins(BountyPlugin)
+            .add_plugins(FramePacingPlugin)
+            .add_plugins(SaveLoadPlugin)
+            .add_plugins(OccupancyPlugin)
+            .add_plugins(MainMenuPlugin)
+            .add_plugins(MapSelectPlugin)
+            .add_plugins(SeedEntryPlugin)
+            .add_plugins(MapEditorPlugin)
+            .add_plugins(SeasonalEventPlugin)
+            .add_plugins(RemoteCommandPlugin)
+            .add_plugins(WaveConfigPlugin)
+            .add_plugins(PrivacyPlugin)
+            .add_plugins(CrowdingPlugin)
+            .add_plugins(ScreenShakePlugin)
+            .add_plugins(FirstBreachPlugin)
+            .add_plugins(MarketPlugin)
+            .add_plugins(RunIntegrityPlugin)
+            .add_plugins(WhatsNewPlugin)
+            .add_plugins(LeaderboardPagePlugin)
+            .add_plugins(ControlsMenuPlugin)
+            .add_plugins(SystemOrderPlugin) // Gameplay ordering sets + debug schedule validation
+            // Add events
+            .add_event::<StartWaveEvent>()
+            .add_event::<EnemyEscapedEvent>()
+            .add_event::<EnemyKilledEvent>()
+            .add_event::<EnemyDamagedEvent>()
+            .add_event::<TowerFiredEvent>()
+            // Initialize state and resources
+            .init_state::<AppState>()
+            .insert_resource(GameConstants::load())
+            .insert_resource(BalanceConfig::load())
+            .insert_resource(GameRng::from_seed(current_level_seed()))
+            .init_resource::<Score>()
+            .init_resource::<WavePlan>()
+            .init_resource::<WaveRuntime>()
+            .init_resource::<GameState>()
+            .init_resource::<LevelConfig>()
+            .init_resource::<Economy>()
+            .init_resource::<MouseInputState>()
+            .init_resource::<WaveStatus>()
+            .init_resource::<CheatMenuState>()
+            .init_resource::<TowerSelectionState>()
+            .init_resource::<TowerStatPopupState>()
+            .init_resource::<UnifiedGridSystem>()
+            // Configure system sets
+            .configure_sets(Update, (
+                GameSystemSet::Input,
+                GameSystemSet::UI,
+                GameSystemSet::Gameplay,
+            ).chain())
+            // Setup systems - Stat popup last for proper Z-order (renders on top)
+            .add_systems(Startup, (setup, setup_unified_grid, setup_tower_placement_panel, setup_tower_upgrade_panel, setup_tower_stat_popup).chain())
+            // Input systems - run in all states
+            .add_systems(Update, (
+                mouse_input_system,
+            ).in_set(GameSystemSet::Input))
+            // UI systems - run in all states
+            .add_systems(Update, (
+                // UI interaction systems (consume UI clicks)
+                tower_type_button_system,
+                upgrade_button_system,
+                branch_button_system,
+                targeting_button_system,
+                tower_selection_system,
+                popup_close_button_system,
+                popup_outside_click_system,
+                start_wave_button_system,
+
+                // UI update systems
+                update_upgrade_panel_system,
+                branch_button_label_system,
+                targeting_button_label_system,
+                selected_tower_indicator_system,
+                update_resource_status_system,
+                tower_cost_label_system,
+                tower_tooltip_system,
+                tower_affordability_system,
+                tower_stat_popup_system,
+                hover_stat_popup_system,
+                update_start_wave_button_system,
+                update_ui_system,
+            ).chain().in_set(GameSystemSet::UI))
+            .add_systems(Update, range_indicator_system.after(selected_tower_indicator_system).in_set(GameSystemSet::UI))
+            // Gameplay systems - only run in Playing state
+            .add_systems(Update, (
+                // Tower placement systems
+                tower_placement_system,
+                tower_placement_preview_system,
+
+                // Grid visualization systems
+                auto_grid_mode_system,
+                update_grid_visualization,
+
+                // Enemy and wave management (ordering declared by EnemySet)
+                auto_start_wave_system.in_set(EnemySet::WaveControl).before(manual_wave_system),
+                manual_wave_system.in_set(EnemySet::WaveControl),
+                (
+                    // Generates the path once the game starts, unless the app supplies its own
+                    path_generation_system.run_if(not(resource_exists::<FixedLevelPath>)),
+                    path_visualization_system, // Updates visual path representation
+                    path_coverage_tint_system, // Tints path segments by tower coverage
+                ).chain().in_set(EnemySet::PathGeneration),
+                path_flow_system.after(EnemySet::PathGeneration),
+                enemy_spawning_system.in_set(EnemySet::Spawning),
+                enemy_movement_system.in_set(EnemySet::Movement),
+                enemy_cleanup_system.in_set(EnemySet::Cleanup),
+
+                // Combat systems (ordering declared by CombatSet)
+                tower_targeting_system.in_set(CombatSet::Targeting),
+                projectile_spawning_system.in_set(CombatSet::Firing),
+                projectile_movement_system.in_set(CombatSet::ProjectileMovement),
+                collision_system.in_set(CombatSet::Collision),
+                status_effect_system.in_set(CombatSet::Collision).after(collision_system),
+
+                // Game state management (runs last)
+                game_state_system.after(CombatSet::Collision),
+            ).in_set(GameSystemSet::Gameplay).run_if(in_state(AppState::Playing)));
+    }
+}
+
+/// Builder for choosing which optional subsystems `TowerDefensePlugin` adds
+#[derive(Debug, Clone, Copy)]
+pub struct TowerDefensePluginBuilder {
+    debug_tools: bool,
+    audio: bool,
+    procedural_generation: bool,
+    security: bool,
+}
+
+impl TowerDefensePluginBuilder {
+    /// Create a new builder with every subsystem enabled
+    pub const fn new() -> Self {
+        Self {
+            debug_tools: true,
+            audio: true,
+            procedural_generation: true,
+            security: true,
+        }
+    }
+
+    /// Leave out the debug UI, cheat menu, debug visualization and stress test
+    pub const fn without_debug_tools(mut self) -> Self {
+        self.debug_tools = false;
+        self
+    }
+
+    /// Leave out UI sound cues and gamepad rumble
+    pub const fn without_audio(mut self) -> Self {
+        self.audio = false;
+        self
+    }
+
+    /// Use the `EnemyPath` resource inserted by the app instead of generated levels
+    pub const fn without_procedural_generation(mut self) -> Self {
+        self.procedural_generation = false;
+        self
+    }
+
+    /// Leave out the backtick gate in front of the debug tools
+    pub const fn without_security(mut self) -> Self {
+        self.security = false;
+        self
+    }
+
+    /// Build the plugin with current configuration
+    pub const fn build(self) -> TowerDefensePlugin {
+        TowerDefensePlugin {
+            debug_tools: self.debug_tools,
+            audio: self.audio,
+            procedural_generation: self.procedural_generation,
+            security: self.security,
+        }
+    }
+}
+
+impl Default for TowerDefensePluginBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d::default());
+    // Controls are explained by the help overlay (H)
+
+    // Initial path visualization - will be updated dynamically by path_visualization_system
+    // This creates placeholder entities that will be updated when the path changes
+}
+
+/// Straight path across the screen, for apps that turn procedural generation
+/// off without supplying a path
+fn create_default_path() -> EnemyPath {
+    EnemyPath::new(vec![
+        Vec2::new(-600.0, 0.0),  // Start left side of screen
+        Vec2::new(600.0, 0.0),   // End right side of screen
+    ])
+}
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+/// Fire-rate multiplier while a fire-rate boost pickup is active
+pub const FIRE_RATE_BOOST_MULTIPLIER: f32 = 1.5;
+/// Seconds a fire-rate boost lasts (collecting another one restarts it)
+pub const FIRE_RATE_BOOST_DURATION: f32 = 8.0;
+
+/// Temporary global buffs granted by loot pickups
+#[derive(Resource, Debug, Default)]
+pub struct ActiveBuffs {
+    /// Remaining seconds of the fire-rate boost (0.0 when inactive)
+    pub fire_rate_boost_remaining: f32,
+}
+
+impl ActiveBuffs {
+    /// Start or refresh the fire-rate boost
+    pub fn activate_fire_rate_boost(&mut self) {
+        self.fire_rate_boost_remaining = FIRE_RATE_BOOST_DURATION;
+    }
+
+    /// Count buff durations down
+    pub fn tick(&mut self, delta_secs: f32) {
+        self.fire_rate_boost_remaining = (self.fire_rate_boost_remaining - delta_secs).max(0.0);
+    }
+
+    pub fn is_fire_rate_boosted(&self) -> bool {
+        self.fire_rate_boost_remaining > 0.0
+    }
+
+    /// Multiplier applied to every tower's fire rate
+    pub fn fire_rate_multiplier(&self) -> f32 {
+        if self.is_fire_rate_boosted() {
+            FIRE_RATE_BOOST_MULTIPLIER
+        } else {
+            1.0
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::components::Enemy;
+use crate::resources::{EndlessScaling, TowerStats, TowerType};
+
+/// Config file the tuned stats are read from and saved back to
+pub const BALANCE_FILE: &str = "balance.ron";
+
+/// Level 1 stats of one tower type
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TowerBalance {
+    pub tower_type: TowerType,
+    pub damage: f32,
+    pub range: f32,
+    pub fire_rate: f32,
+}
+
+impl TowerBalance {
+    /// The stats a tower type is built with
+    pub fn built_in(tower_type: TowerType) -> Self {
+        let stats = TowerStats::new(tower_type);
+        Self {
+            tower_type,
+            damage: stats.damage,
+            range: stats.range,
+            fire_rate: stats.fire_rate,
+        }
+    }
+}
+
+/// How far a tower's stats are scaled from the built-in ones
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TowerFactors {
+    pub damage: f32,
+    pub range: f32,
+    pub fire_rate: f32,
+}
+
+impl TowerFactors {
+    pub const IDENTITY: TowerFactors = TowerFactors {
+        damage: 1.0,
+        range: 1.0,
+        fire_rate: 1.0,
+    };
+}
+
+/// Per-wave enemy scaling: a stat is `base + per_wave * wave`
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EnemyBalance {
+    pub base_health: f32,
+    pub health_per_wave: f32,
+    pub base_speed: f32,
+    pub speed_per_wave: f32,
+}
+
+impl Default for EnemyBalance {
+    /// Matches `Enemy::for_wave` and `Enemy::health_for_wave`
+    fn default() -> Self {
+        Self {
+            base_health: 50.0,
+            health_per_wave: 25.0,
+            base_speed: 50.0,
+            speed_per_wave: 5.0,
+        }
+    }
+}
+
+impl EnemyBalance {
+    pub fn health_for_wave(&self, wave: u32) -> f32 {
+        self.base_health + self.health_per_wave * wave.max(1) as f32
+    }
+
+    pub fn speed_for_wave(&self, wave: u32) -> f32 {
+        self.base_speed + self.speed_per_wave * wave.max(1) as f32
+    }
+
+    /// Health and speed factors turning a built-in enemy of `wave` into a tuned one
+    pub fn factors_for_wave(&self, wave: u32) -> (f32, f32) {
+        (
+            self.health_for_wave(wave) / Enemy::health_for_wave(wave),
+            self.speed_for_wave(wave) / Enemy::for_wave(wave).speed,
+        )
+    }
+}
+
+/// Resource holding the tower and enemy stats tuned in the balance config.
+/// Lives in `balance.ron`; stats the file leaves out keep their built-in values.
+#[derive(Resource, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BalanceConfig {
+    pub towers: Vec<TowerBalance>,
+    pub enemies: EnemyBalance,
+    pub endless: EndlessScaling,
+}
+
+impl Default for BalanceConfig {
+    fn default() -> Self {
+        Self {
+            towers: TowerType::ALL.iter().map(|tower_type| TowerBalance::built_in(*tower_type)).collect(),
+            enemies: EnemyBalance::default(),
+            endless: EndlessScaling::default(),
+        }
+    }
+}
+
+impl BalanceConfig {
+    /// Tuned stats of a tower type, or the built-in ones if the config leaves it out
+    pub fn tower(&self, tower_type: TowerType) -> TowerBalance {
+        self.towers
+            .iter()
+            .find(|tower| tower.tower_type == tower_type)
+            .copied()
+            .unwrap_or_else(|| TowerBalance::built_in(tower_type))
+    }
+
+    pub fn tower_mut(&mut self, tower_type: TowerType) -> &mut TowerBalance {
+        let index = match self.towers.iter().position(|tower| tower.tower_type == tower_type) {
+            Some(index) => index,
+            None => {
+                self.towers.push(TowerBalance::built_in(tower_type));
+                self.towers.len() - 1
+            }
+        };
+        &mut self.towers[index]
+    }
+
+    /// Factors turning the built-in stats of a tower type into the tuned ones
+    pub fn tower_factors(&self, tower_type: TowerType) -> TowerFactors {
+        let built_in = TowerBalance::built_in(tower_type);
+        let tuned = self.tower(tower_type);
+        TowerFactors {
+            damage: tuned.damage / built_in.damage,
+            range: tuned.range / built_in.range,
+            fire_rate: tuned.fire_rate / built_in.fire_rate,
+        }
+    }
+
+    pub fn from_ron(text: &str) -> Result<Self, String> {
+        ron::from_str(text).map_err(|e| e.to_string())
+    }
+
+    pub fn to_ron(&self) -> Result<String, String> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()).map_err(|e| e.to_string())
+    }
+
+    /// Load the tuned stats from the balance file, or the built-in ones if it's missing or broken
+    pub fn load() -> Self {
+        let Ok(contents) = std::fs::read_to_string(BALANCE_FILE) else {
+            return Self::default();
+        };
+        Self::from_ron(&contents).unwrap_or_else(|error| {
+            warn!("Ignoring balance stats in {}: {}", BALANCE_FILE, error);
+            Self::default()
+        })
+    }
+
+    /// Save the tuned stats to the balance file
+    pub fn save(&self) -> Result<(), String> {
+        std::fs::write(BALANCE_FILE, self.to_ron()?).map_err(|e| e.to_string())
+    }
+}
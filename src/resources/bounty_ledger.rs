@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+use crate::resources::NumberFormatter;
+
+/// Bounty paid out while one wave was running
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WaveBounty {
+    pub wave: u32,
+    /// Lowest bounty a kill pays; values leaks in a wave without kills to average over
+    pub min_per_kill: u32,
+    pub kills: u32,
+    pub leaks: u32,
+    /// Bounty money earned from kills
+    pub earned: u32,
+}
+
+impl WaveBounty {
+    /// Average bounty a kill paid this wave
+    pub fn per_kill(&self) -> f32 {
+        if self.kills == 0 {
+            return self.min_per_kill as f32;
+        }
+        self.earned as f32 / self.kills as f32
+    }
+
+    /// Bounty lost to enemies that reached the base, at the wave's average per kill
+    pub fn missed(&self) -> u32 {
+        (self.leaks as f32 * self.per_kill()).round() as u32
+    }
+
+    /// Bounty the wave would have paid had no enemy leaked
+    pub fn expected(&self) -> u32 {
+        self.earned + self.missed()
+    }
+
+    /// Expected against actual income, e.g. "Wave 3 bounty: $96 of $120 ($24 missed to 2 leaks)"
+    pub fn summary(&self, formatter: &NumberFormatter) -> String {
+        let mut summary = format!(
+            "Wave {} bounty: {} of {}",
+            self.wave,
+            formatter.money(self.earned),
+            formatter.money(self.expected())
+        );
+        if self.leaks > 0 {
+            let noun = if self.leaks == 1 { "leak" } else { "leaks" };
+            summary.push_str(&format!(" ({} missed to {} {})", formatter.money(self.missed()), self.leaks, noun));
+        }
+        summary
+    }
+}
+
+/// Resource tagging bounty income with the wave it was earned in
+#[derive(Resource, Debug, Clone, Default)]
+pub struct BountyLedger {
+    /// One entry per wave, oldest first
+    pub waves: Vec<WaveBounty>,
+}
+
+impl BountyLedger {
+    /// Open the entry for `wave`. Entries for this wave or later ones are dropped
+    /// first, as after a restart or a checkpoint retry.
+    pub fn start_wave(&mut self, wave: u32, min_per_kill: u32) {
+        self.waves.retain(|entry| entry.wave < wave);
+        self.waves.push(WaveBounty {
+            wave,
+            min_per_kill,
+            ..default()
+        });
+    }
+
+    /// Book a kill's bounty to the running wave
+    pub fn record_kill(&mut self, bounty: u32) {
+        if let Some(entry) = self.waves.last_mut() {
+            entry.kills += 1;
+            entry.earned += bounty;
+        }
+    }
+
+    /// Book an enemy reaching the base to the running wave
+    pub fn record_leak(&mut self) {
+        if let Some(entry) = self.waves.last_mut() {
+            entry.leaks += 1;
+        }
+    }
+
+    /// Entry of the running or most recent wave
+    pub fn latest(&self) -> Option<&WaveBounty> {
+        self.waves.last()
+    }
+
+    pub fn wave(&self, wave: u32) -> Option<&WaveBounty> {
+        self.waves.iter().find(|entry| entry.wave == wave)
+    }
+}
@@ -0,0 +1,73 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Version of the running build
+pub const GAME_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Changelog shipped inside the binary, newest release first
+pub const EMBEDDED_CHANGELOG: &str = include_str!("../../assets/changelog.json");
+
+/// Release notes of one version
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChangelogEntry {
+    pub version: String,
+    pub notes: Vec<String>,
+}
+
+/// Parse a "major.minor.patch" version; missing parts count as 0
+pub fn parse_version(version: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = version.trim().split('.').map(|part| part.parse::<u32>().ok());
+    let major = parts.next()??;
+    let minor = parts.next().unwrap_or(Some(0))?;
+    let patch = parts.next().unwrap_or(Some(0))?;
+    Some((major, minor, patch))
+}
+
+/// Whether `current` is a later release than `last_seen`. A missing or
+/// unreadable last-seen version counts as older than any release.
+pub fn is_newer_version(current: &str, last_seen: &str) -> bool {
+    match (parse_version(current), parse_version(last_seen)) {
+        (Some(current), Some(last_seen)) => current > last_seen,
+        (Some(_), None) => true,
+        _ => false,
+    }
+}
+
+/// Resource holding the release notes, newest first
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct Changelog {
+    pub entries: Vec<ChangelogEntry>,
+}
+
+impl Changelog {
+    pub fn from_json(contents: &str) -> Result<Self, String> {
+        let entries = serde_json::from_str(contents).map_err(|e| e.to_string())?;
+        Ok(Self { entries })
+    }
+
+    /// The changelog built into the game, empty if it can't be read
+    pub fn embedded() -> Self {
+        Self::from_json(EMBEDDED_CHANGELOG).unwrap_or_else(|error| {
+            warn!("Ignoring embedded changelog: {}", error);
+            Self::default()
+        })
+    }
+
+    /// Releases newer than `last_seen`, up to and including `current`. With no
+    /// readable last-seen version only the current release is returned.
+    pub fn since(&self, last_seen: &str, current: &str) -> Vec<&ChangelogEntry> {
+        let seen = parse_version(last_seen);
+        let current = parse_version(current);
+        self.entries
+            .iter()
+            .filter(|entry| {
+                let Some(version) = parse_version(&entry.version) else {
+                    return false;
+                };
+                match seen {
+                    Some(seen) => version > seen && current.is_none_or(|current| version <= current),
+                    None => Some(version) == current,
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+use crate::components::UpgradeBranch;
+use crate::resources::{Economy, RunPerks, Score, TowerStats, TowerType};
+
+/// A checkpoint is recorded at the start of every wave that is a multiple of this
+pub const CHECKPOINT_INTERVAL: u32 = 5;
+
+/// Whether the start of `wave` records a checkpoint
+pub fn is_checkpoint_wave(wave: u32) -> bool {
+    wave > 0 && wave.is_multiple_of(CHECKPOINT_INTERVAL)
+}
+
+/// Run difficulty, chosen in the settings menu
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Hard => "Hard",
+        }
+    }
+
+    /// How many times per run the player may restart from a checkpoint
+    pub fn checkpoint_retries(&self) -> u32 {
+        match self {
+            Difficulty::Easy => 3,
+            Difficulty::Normal => 1,
+            Difficulty::Hard => 0,
+        }
+    }
+
+    /// The next difficulty in the cycle, wrapping around
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|difficulty| *difficulty == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Enough of a tower to rebuild it when a checkpoint is restored
+#[derive(Clone, Debug, PartialEq)]
+pub struct TowerSnapshot {
+    pub tower_type: TowerType,
+    pub position: Vec2,
+    pub upgrade_level: u32,
+    pub branch: Option<UpgradeBranch>,
+}
+
+impl TowerSnapshot {
+    pub fn capture(stats: &TowerStats, position: Vec2) -> Self {
+        Self {
+            tower_type: stats.tower_type,
+            position,
+            upgrade_level: stats.upgrade_level,
+            branch: stats.branch,
+        }
+    }
+
+    /// Tower stats at the recorded upgrade level and specialization
+    pub fn restored_stats(&self) -> TowerStats {
+        let mut stats = TowerStats::new(self.tower_type);
+        while stats.upgrade_level < self.upgrade_level && stats.can_upgrade() {
+            stats.upgrade();
+        }
+        if let Some(branch) = self.branch {
+            stats.specialize(branch);
+        }
+        stats
+    }
+}
+
+/// Lightweight snapshot of a run taken at the start of a checkpoint wave
+#[derive(Clone, Debug)]
+pub struct WaveCheckpoint {
+    pub wave: u32,
+    /// Money, research, materials and energy when the wave started
+    pub economy: Economy,
+    pub score: Score,
+    pub towers: Vec<TowerSnapshot>,
+    /// Shop perks owned when the wave started
+    pub perks: RunPerks,
+}
+
+/// Resource tracking the latest checkpoint and the retries left this run
+#[derive(Resource, Debug)]
+pub struct CheckpointState {
+    pub latest: Option<WaveCheckpoint>,
+    pub retries_used: u32,
+    pub max_retries: u32,
+}
+
+impl Default for CheckpointState {
+    fn default() -> Self {
+        Self::new(Difficulty::default().checkpoint_retries())
+    }
+}
+
+impl CheckpointState {
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            latest: None,
+            retries_used: 0,
+            max_retries,
+        }
+    }
+
+    pub fn record(&mut self, checkpoint: WaveCheckpoint) {
+        self.latest = Some(checkpoint);
+    }
+
+    pub fn retries_remaining(&self) -> u32 {
+        self.max_retries.saturating_sub(self.retries_used)
+    }
+
+    /// Checkpoint that can be restored right now, if any retries are left
+    pub fn available(&self) -> Option<&WaveCheckpoint> {
+        if self.retries_remaining() == 0 {
+            return None;
+        }
+        self.latest.as_ref()
+    }
+
+    /// Spend a retry and hand out the checkpoint to restore
+    pub fn consume_retry(&mut self) -> Option<WaveCheckpoint> {
+        let checkpoint = self.available()?.clone();
+        self.retries_used += 1;
+        Some(checkpoint)
+    }
+}
@@ -1,4 +1,16 @@
 use bevy::prelude::*;
+use crate::components::{UpgradeBranch, UpgradePath, BRANCH_LEVEL};
+
+/// Fraction of a tower's total investment returned when it is sold
+pub const SELL_REFUND_RATIO: f32 = 0.7;
+
+/// Upgrade level from which towers switch to their heavy turret's muzzles
+pub const HEAVY_TURRET_LEVEL: u32 = 3;
+
+/// Upgrade level from which Basic towers fire slowing rounds
+pub const SLOW_UPGRADE_LEVEL: u32 = 3;
+/// Share of a Laser hit's damage its burn deals each second
+pub const LASER_BURN_SHARE: f32 = 0.25;
 
 #[derive(Resource, Debug, Clone)]
 pub struct Economy {
@@ -13,6 +25,14 @@ pub struct Economy {
     pub energy_generation: f32,
 }
 
+/// Passive income earned but not yet paid out, in fractions of a unit
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct IncomeRemainder {
+    pub money: f32,
+    pub research_points: f32,
+    pub energy: f32,
+}
+
 impl Default for Economy {
     fn default() -> Self {
         Self {
@@ -45,6 +65,15 @@ impl Economy {
             && self.energy >= cost.energy
     }
 
+    /// Spend `cost` if affordable, returning whether the transaction went through
+    pub fn try_spend(&mut self, cost: &ResourceCost) -> bool {
+        if !self.can_afford(cost) {
+            return false;
+        }
+        self.spend(cost);
+        true
+    }
+
     pub fn spend(&mut self, cost: &ResourceCost) {
         if self.can_afford(cost) {
             self.money -= cost.money;
@@ -61,12 +90,40 @@ impl Economy {
         self.energy += reward.energy;
     }
 
+    /// Return a previously spent cost in full (e.g. cancelled construction)
+    pub fn refund(&mut self, cost: &ResourceCost) {
+        self.money += cost.money;
+        self.research_points += cost.research_points;
+        self.materials += cost.materials;
+        self.energy += cost.energy;
+    }
+
     pub fn generate_passive_income(&mut self, delta_time: f32) {
         self.money += (self.money_generation * delta_time) as u32;
         self.research_points += (self.research_generation * delta_time) as u32;
         self.energy = (self.energy + (self.energy_generation * delta_time) as u32).min(100); // Cap energy at 100
     }
 
+    /// Pay passive income for `delta_time` seconds. Fractions of a unit are kept
+    /// in `remainder` and paid out once they add up, so short frames still earn.
+    pub fn accrue_passive_income(&mut self, delta_time: f32, remainder: &mut IncomeRemainder) {
+        let whole = |carry: &mut f32, rate: f32| {
+            *carry += rate * delta_time;
+            let paid = carry.floor();
+            *carry -= paid;
+            paid as u32
+        };
+        let money = whole(&mut remainder.money, self.money_generation);
+        let research_points = whole(&mut remainder.research_points, self.research_generation);
+        let energy = whole(&mut remainder.energy, self.energy_generation);
+
+        self.money = self.money.saturating_add(money);
+        self.research_points = self.research_points.saturating_add(research_points);
+        if self.energy < 100 {
+            self.energy = (self.energy + energy).min(100); // Cap energy at 100
+        }
+    }
+
     pub fn get_total_value(&self) -> f32 {
         // Weighted value calculation for scoring/difficulty scaling
         self.money as f32 + 
@@ -129,6 +186,29 @@ impl ResourceCost {
             energy: amount,
         }
     }
+
+    pub fn zero() -> Self {
+        Self::new(0, 0, 0, 0)
+    }
+
+    /// Each resource multiplied by `factor`, rounded down
+    pub fn scaled(&self, factor: f32) -> Self {
+        Self {
+            money: (self.money as f32 * factor) as u32,
+            research_points: (self.research_points as f32 * factor) as u32,
+            materials: (self.materials as f32 * factor) as u32,
+            energy: (self.energy as f32 * factor) as u32,
+        }
+    }
+}
+
+impl std::ops::AddAssign<&ResourceCost> for ResourceCost {
+    fn add_assign(&mut self, other: &ResourceCost) {
+        self.money += other.money;
+        self.research_points += other.research_points;
+        self.materials += other.materials;
+        self.energy += other.energy;
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -159,7 +239,7 @@ impl ResourceReward {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum TowerType {
     Basic,
     Advanced,
@@ -169,6 +249,14 @@ pub enum TowerType {
 }
 
 impl TowerType {
+    pub const ALL: [TowerType; 5] = [
+        TowerType::Basic,
+        TowerType::Advanced,
+        TowerType::Laser,
+        TowerType::Missile,
+        TowerType::Tesla,
+    ];
+
     pub fn get_cost(&self) -> ResourceCost {
         match self {
             TowerType::Basic => ResourceCost::money(40),      // Increased from 25
@@ -179,6 +267,17 @@ impl TowerType {
         }
     }
 
+    /// Seconds of construction before a newly placed tower becomes active
+    pub fn get_build_time(&self) -> f32 {
+        match self {
+            TowerType::Basic => 2.0,
+            TowerType::Advanced => 3.0,
+            TowerType::Laser => 3.5,
+            TowerType::Missile => 4.0,
+            TowerType::Tesla => 5.0,
+        }
+    }
+
     pub fn get_name(&self) -> &'static str {
         match self {
             TowerType::Basic => "Basic Tower",
@@ -198,6 +297,41 @@ impl TowerType {
             TowerType::Tesla => "Chain lightning, high energy cost",
         }
     }
+
+    /// Where shots leave the turret, relative to the tower centre with +X pointing
+    /// at the target. Multi-barrel towers fire from each in turn; upgraded towers
+    /// from `HEAVY_TURRET_LEVEL` on get their heavier turret's barrels.
+    pub fn get_muzzle_offsets(&self, upgrade_level: u32) -> &'static [Vec2] {
+        const BASIC_MUZZLES: &[Vec2] = &[Vec2::new(14.0, 0.0)];
+        const ADVANCED_MUZZLES: &[Vec2] = &[Vec2::new(18.0, 6.0), Vec2::new(18.0, -6.0)];
+        const HEAVY_ADVANCED_MUZZLES: &[Vec2] = &[
+            Vec2::new(18.0, 9.0),
+            Vec2::new(18.0, -9.0),
+            Vec2::new(18.0, 3.0),
+            Vec2::new(18.0, -3.0),
+        ];
+        // Beams start at the tip of the emitter bar
+        const LASER_MUZZLES: &[Vec2] = &[Vec2::new(18.0, 0.0)];
+        const MISSILE_MUZZLES: &[Vec2] = &[Vec2::new(10.0, 8.0), Vec2::new(10.0, -8.0)];
+        const HEAVY_MISSILE_MUZZLES: &[Vec2] = &[
+            Vec2::new(10.0, 10.0),
+            Vec2::new(10.0, -10.0),
+            Vec2::new(4.0, 14.0),
+            Vec2::new(4.0, -14.0),
+        ];
+        const TESLA_MUZZLES: &[Vec2] = &[Vec2::new(12.0, 0.0)];
+
+        let heavy = upgrade_level >= HEAVY_TURRET_LEVEL;
+        match self {
+            TowerType::Basic => BASIC_MUZZLES,
+            TowerType::Advanced if heavy => HEAVY_ADVANCED_MUZZLES,
+            TowerType::Advanced => ADVANCED_MUZZLES,
+            TowerType::Laser => LASER_MUZZLES,
+            TowerType::Missile if heavy => HEAVY_MISSILE_MUZZLES,
+            TowerType::Missile => MISSILE_MUZZLES,
+            TowerType::Tesla => TESLA_MUZZLES,
+        }
+    }
 }
 
 #[derive(Component, Debug, Clone)]
@@ -208,6 +342,11 @@ pub struct TowerStats {
     pub fire_rate: f32,
     pub last_shot: f32,
     pub upgrade_level: u32,
+    pub splash_radius: f32,     // Blast radius of each shot, 0 for single-target towers
+    pub chain_targets: u32,     // Extra enemies chain lightning jumps to
+    pub burn_dps: f32,          // Damage per second of the burn each hit lights
+    pub slow: f32,              // Share of speed each hit takes away, from upgrades
+    pub branch: Option<UpgradeBranch>, // Specialization chosen at BRANCH_LEVEL
 }
 
 impl TowerStats {
@@ -220,13 +359,116 @@ impl TowerStats {
             TowerType::Tesla => (14.0, 70.0, 0.6),       // Reduced damage and fire rate
         };
 
-        Self {
+        let mut stats = Self {
             tower_type,
             damage,
             range,
             fire_rate,
             last_shot: 0.0,
             upgrade_level: 1,
+            splash_radius: Self::base_splash_radius(tower_type),
+            chain_targets: 0,
+            burn_dps: 0.0,
+            slow: 0.0,
+            branch: None,
+        };
+        stats.apply_status_stats();
+        stats
+    }
+
+    /// Status effects the tower's hits carry at its level: Tesla lightning chains
+    /// further with each upgrade, Laser hits burn, and Basic towers learn to slow
+    fn apply_status_stats(&mut self) {
+        let level = self.upgrade_level;
+        self.chain_targets = match self.tower_type {
+            TowerType::Tesla => 2 + level / 2,
+            _ => 0,
+        };
+        self.burn_dps = match self.tower_type {
+            TowerType::Laser => self.damage * LASER_BURN_SHARE,
+            _ => 0.0,
+        };
+        self.slow = match self.tower_type {
+            TowerType::Basic if level >= SLOW_UPGRADE_LEVEL => 0.25 + 0.05 * (level - SLOW_UPGRADE_LEVEL) as f32,
+            _ => 0.0,
+        };
+    }
+
+    /// Stat and behavior changes of the chosen specialization, on top of the level's stats
+    fn apply_branch_stats(&mut self) {
+        let Some(branch) = self.branch else {
+            return;
+        };
+        match branch {
+            UpgradeBranch::Sniper => {
+                self.damage *= 1.6;
+                self.range *= 1.4;
+                self.fire_rate *= 0.6;
+            }
+            UpgradeBranch::Rapid => {
+                self.damage *= 0.7;
+                self.range *= 0.9;
+                self.fire_rate *= 1.8;
+            }
+            UpgradeBranch::Heavy => {
+                self.damage *= 1.7;
+                self.fire_rate *= 0.75;
+            }
+            UpgradeBranch::Gatling => {
+                self.damage *= 0.6;
+                self.fire_rate *= 2.0;
+            }
+            UpgradeBranch::Scorch => {
+                self.burn_dps *= 2.5;
+                self.range *= 0.9;
+            }
+            UpgradeBranch::Lens => {
+                self.damage *= 1.3;
+                self.range *= 1.3;
+                self.burn_dps = 0.0;
+            }
+            UpgradeBranch::Cluster => {
+                self.damage *= 0.85;
+                self.splash_radius *= 1.6;
+            }
+            UpgradeBranch::Hunter => {
+                self.damage *= 1.8;
+                self.range *= 1.2;
+                self.splash_radius = 0.0;
+            }
+            UpgradeBranch::Storm => {
+                self.damage *= 0.9;
+                self.chain_targets += 2;
+            }
+            UpgradeBranch::Overload => {
+                self.damage *= 1.5;
+                self.chain_targets = 0;
+                self.slow = 0.3;
+            }
+        }
+    }
+
+    /// Whether the tower is high enough to specialize and hasn't yet
+    pub fn can_specialize(&self) -> bool {
+        self.branch.is_none() && self.upgrade_level >= BRANCH_LEVEL
+    }
+
+    /// Commit to one of the tower type's branches. Returns false, changing
+    /// nothing, if the tower can't specialize or the branch isn't on its path.
+    pub fn specialize(&mut self, branch: UpgradeBranch) -> bool {
+        if !self.can_specialize() || !UpgradePath::for_tower(self.tower_type).offers(branch) {
+            return false;
+        }
+        self.branch = Some(branch);
+        self.apply_upgrade_stats();
+        true
+    }
+
+    /// Blast radius at level 1; only missiles explode
+    fn base_splash_radius(tower_type: TowerType) -> f32 {
+        match tower_type {
+            TowerType::Missile => 40.0,
+            _ => 0.0,
         }
     }
 
@@ -246,6 +488,22 @@ impl TowerStats {
         )
     }
 
+    /// Everything paid for this tower: its build cost plus every upgrade so far
+    pub fn total_investment(&self) -> ResourceCost {
+        let mut total = self.tower_type.get_cost();
+        let mut level_stats = TowerStats::new(self.tower_type);
+        while level_stats.upgrade_level < self.upgrade_level {
+            total += &level_stats.get_upgrade_cost();
+            level_stats.upgrade_level += 1;
+        }
+        total
+    }
+
+    /// Resources returned when this tower is sold
+    pub fn sell_value(&self) -> ResourceCost {
+        self.total_investment().scaled(SELL_REFUND_RATIO)
+    }
+
     pub fn can_upgrade(&self) -> bool {
         self.upgrade_level < 5
     }
@@ -296,6 +554,7 @@ impl TowerStats {
                 self.damage = base_damage * (1.0 + (level_multiplier - 1.0) * 0.20);     // Reduced from 0.45
                 self.range = base_range * (1.0 + (level_multiplier - 1.0) * 0.08);       // Reduced from 0.10
                 self.fire_rate = base_fire_rate * (1.0 + (level_multiplier - 1.0) * 0.08); // Reduced from 0.10
+                self.splash_radius = Self::base_splash_radius(self.tower_type) * (1.0 + (level_multiplier - 1.0) * 0.10);
             },
             TowerType::Tesla => {
                 // Focus on range (chain lightning, area coverage) - REBALANCED
@@ -304,6 +563,8 @@ impl TowerStats {
                 self.fire_rate = base_fire_rate * (1.0 + (level_multiplier - 1.0) * 0.12); // Reduced from 0.15
             },
         }
+        self.apply_status_stats();
+        self.apply_branch_stats();
     }
 }
 
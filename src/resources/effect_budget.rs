@@ -0,0 +1,134 @@
+use std::collections::VecDeque;
+use bevy::prelude::*;
+use crate::components::EffectCategory;
+
+/// Number of effect categories, for per-category tables
+pub const EFFECT_CATEGORY_COUNT: usize = EffectCategory::ALL.len();
+/// Frames averaged when judging frame time
+pub const FRAME_TIME_WINDOW: usize = 60;
+/// Average frame time above which cosmetic effects are thinned out (40 FPS)
+pub const DEFAULT_FRAME_TIME_BUDGET: f32 = 1.0 / 40.0;
+/// Lowest share of cosmetic effects kept under load
+pub const MIN_EFFECT_DENSITY: f32 = 0.25;
+/// How much effect density changes in one adjustment
+pub const EFFECT_DENSITY_STEP: f32 = 0.25;
+/// Seconds between density adjustments, so it doesn't flicker at the threshold
+pub const DENSITY_ADJUST_INTERVAL: f32 = 1.0;
+/// Share of the caps and frame time budget that must be free before density is restored
+pub const RECOVERY_HEADROOM: f32 = 0.75;
+
+/// Resource tracking live cosmetic effects against their caps and thinning
+/// new ones out while over budget or running slow
+#[derive(Resource, Debug, Clone)]
+pub struct EffectBudget {
+    /// Most live effects allowed per category, indexed by `EffectCategory::index`
+    pub caps: [usize; EFFECT_CATEGORY_COUNT],
+    /// Average frame time, in seconds, to stay under
+    pub frame_time_budget: f32,
+    /// Share of requested effects that get spawned, from `MIN_EFFECT_DENSITY` to 1.0
+    pub density: f32,
+    live: [usize; EFFECT_CATEGORY_COUNT],
+    frame_times: VecDeque<f32>,
+    since_adjust: f32,
+    /// Spawn credit per category; density adds to it and each admitted effect spends one
+    credit: [f32; EFFECT_CATEGORY_COUNT],
+}
+
+impl Default for EffectBudget {
+    fn default() -> Self {
+        Self {
+            caps: [400, 60, 150, 120, 4],
+            frame_time_budget: DEFAULT_FRAME_TIME_BUDGET,
+            density: 1.0,
+            live: [0; EFFECT_CATEGORY_COUNT],
+            frame_times: VecDeque::with_capacity(FRAME_TIME_WINDOW),
+            since_adjust: 0.0,
+            credit: [0.0; EFFECT_CATEGORY_COUNT],
+        }
+    }
+}
+
+impl EffectBudget {
+    pub fn cap(&self, category: EffectCategory) -> usize {
+        self.caps[category.index()]
+    }
+
+    pub fn set_cap(&mut self, category: EffectCategory, cap: usize) {
+        self.caps[category.index()] = cap;
+    }
+
+    /// Live effects of a category, as last counted plus any admitted since
+    pub fn live(&self, category: EffectCategory) -> usize {
+        self.live[category.index()]
+    }
+
+    pub fn total_live(&self) -> usize {
+        self.live.iter().sum()
+    }
+
+    /// Rolling average of recent frame times, in seconds
+    pub fn average_frame_time(&self) -> f32 {
+        if self.frame_times.is_empty() {
+            return 0.0;
+        }
+        self.frame_times.iter().sum::<f32>() / self.frame_times.len() as f32
+    }
+
+    /// Whether a category is full or frames are running slow
+    pub fn is_over_budget(&self) -> bool {
+        self.average_frame_time() > self.frame_time_budget
+            || EffectCategory::ALL
+                .iter()
+                .any(|category| self.live(*category) >= self.cap(*category))
+    }
+
+    /// Whether there's enough room below every cap and the frame budget to add effects back
+    pub fn has_headroom(&self) -> bool {
+        self.average_frame_time() <= self.frame_time_budget * RECOVERY_HEADROOM
+            && EffectCategory::ALL
+                .iter()
+                .all(|category| self.live(*category) as f32 <= self.cap(*category) as f32 * RECOVERY_HEADROOM)
+    }
+
+    /// Record one frame's time and live effect counts, stepping density down
+    /// when over budget and back up once there's headroom
+    pub fn record_frame(&mut self, frame_time: f32, live: [usize; EFFECT_CATEGORY_COUNT]) {
+        self.live = live;
+        self.frame_times.push_back(frame_time);
+        if self.frame_times.len() > FRAME_TIME_WINDOW {
+            self.frame_times.pop_front();
+        }
+
+        self.since_adjust += frame_time;
+        if self.since_adjust < DENSITY_ADJUST_INTERVAL {
+            return;
+        }
+        if self.is_over_budget() && self.density > MIN_EFFECT_DENSITY {
+            self.density = (self.density - EFFECT_DENSITY_STEP).max(MIN_EFFECT_DENSITY);
+            self.since_adjust = 0.0;
+            info!("Effect density lowered to {:.0}%", self.density * 100.0);
+        } else if self.has_headroom() && self.density < 1.0 {
+            self.density = (self.density + EFFECT_DENSITY_STEP).min(1.0);
+            self.since_adjust = 0.0;
+            info!("Effect density raised to {:.0}%", self.density * 100.0);
+        }
+    }
+
+    /// Whether a new cosmetic effect may spawn. Never past its category's cap;
+    /// below it, an even share of requests set by the density gets through.
+    pub fn admit(&mut self, category: EffectCategory) -> bool {
+        let index = category.index();
+        if self.live[index] >= self.caps[index] {
+            return false;
+        }
+
+        self.credit[index] += self.density;
+        if self.credit[index] < 1.0 {
+            return false;
+        }
+        self.credit[index] -= 1.0;
+        // Count it now so a burst within one frame still respects the cap
+        self.live[index] += 1;
+        true
+    }
+}
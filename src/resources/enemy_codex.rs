@@ -0,0 +1,159 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use crate::components::EnemyType;
+
+/// Profile file the lifetime enemy statistics are kept in
+pub const ENEMY_CODEX_FILE: &str = "enemy_codex.json";
+/// Lifetime kills of one enemy type that unlock each of its lore entries
+pub const CODEX_MILESTONES: [u64; 4] = [25, 100, 500, 1000];
+
+/// Lifetime statistics for one enemy type
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct EnemyTypeStats {
+    pub kills: u64,
+    pub leaks: u64,
+    pub damage_taken: f64,
+}
+
+/// Resource holding per-enemy-type statistics across every run in the profile
+#[derive(Resource, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct EnemyCodex {
+    pub stats: HashMap<EnemyType, EnemyTypeStats>,
+    /// Statistics collection is switched off in the privacy settings; nothing is recorded
+    #[serde(skip)]
+    pub opted_out: bool,
+}
+
+impl EnemyCodex {
+    pub fn stats(&self, enemy_type: EnemyType) -> EnemyTypeStats {
+        self.stats.get(&enemy_type).copied().unwrap_or_default()
+    }
+
+    /// Count a kill, returning the milestone it reached, if any
+    pub fn record_kill(&mut self, enemy_type: EnemyType) -> Option<u64> {
+        if self.opted_out {
+            return None;
+        }
+        let stats = self.stats.entry(enemy_type).or_default();
+        stats.kills += 1;
+        CODEX_MILESTONES.contains(&stats.kills).then_some(stats.kills)
+    }
+
+    pub fn record_leak(&mut self, enemy_type: EnemyType) {
+        if self.opted_out {
+            return;
+        }
+        self.stats.entry(enemy_type).or_default().leaks += 1;
+    }
+
+    pub fn record_damage(&mut self, enemy_type: EnemyType, amount: f32) {
+        if self.opted_out {
+            return;
+        }
+        self.stats.entry(enemy_type).or_default().damage_taken += amount as f64;
+    }
+
+    /// Lore entries unlocked for a type, in milestone order
+    pub fn unlocked_lore(&self, enemy_type: EnemyType) -> Vec<&'static str> {
+        let kills = self.stats(enemy_type).kills;
+        CODEX_MILESTONES
+            .iter()
+            .zip(codex_lore(enemy_type))
+            .filter(|(milestone, _)| kills >= **milestone)
+            .map(|(_, lore)| *lore)
+            .collect()
+    }
+
+    /// Kills needed for the next lore entry, `None` once all are unlocked
+    pub fn next_milestone(&self, enemy_type: EnemyType) -> Option<u64> {
+        let kills = self.stats(enemy_type).kills;
+        CODEX_MILESTONES.iter().copied().find(|milestone| *milestone > kills)
+    }
+
+    /// A type's share of the most-killed type's kills, 0.0 to 1.0
+    pub fn kill_heat(&self, enemy_type: EnemyType) -> f32 {
+        let most = self.stats.values().map(|stats| stats.kills).max().unwrap_or(0);
+        if most == 0 {
+            return 0.0;
+        }
+        self.stats(enemy_type).kills as f32 / most as f32
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+
+    pub fn from_json(contents: &str) -> Result<Self, String> {
+        serde_json::from_str(contents).map_err(|e| e.to_string())
+    }
+
+    /// Load the profile's statistics, starting fresh if the file is missing or broken
+    pub fn load() -> Self {
+        let Ok(contents) = std::fs::read_to_string(ENEMY_CODEX_FILE) else {
+            return Self::default();
+        };
+        Self::from_json(&contents).unwrap_or_else(|error| {
+            warn!("Ignoring enemy statistics in {}: {}", ENEMY_CODEX_FILE, error);
+            Self::default()
+        })
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        std::fs::write(ENEMY_CODEX_FILE, self.to_json()?).map_err(|e| e.to_string())
+    }
+}
+
+/// Short description shown at the top of a type's codex entry
+pub fn codex_description(enemy_type: EnemyType) -> &'static str {
+    match enemy_type {
+        EnemyType::Basic => "Walks the path at the wave's pace, in numbers.",
+        EnemyType::Fast => "Lightly built and quick; gone before slow towers turn.",
+        EnemyType::Tank => "Slow and heavily armoured, worth three times the bounty.",
+        EnemyType::Flying => "Crosses the void on its own shortcut where the path can't.",
+        EnemyType::Shielded => "Carries a shield that soaks up the first volleys.",
+        EnemyType::Boss => "Leads the hardest waves. Takes everything you have.",
+    }
+}
+
+/// Lore unlocked at each of `CODEX_MILESTONES`
+pub fn codex_lore(enemy_type: EnemyType) -> &'static [&'static str; 4] {
+    match enemy_type {
+        EnemyType::Basic => &[
+            "Basic enemies never stop to count their losses. Neither should you.",
+            "Scouts report they follow whoever walked the path first.",
+            "Survivors of a broken wave regroup at the spawn and try again.",
+            "They have learned your name. They are not afraid of it.",
+        ],
+        EnemyType::Fast => &[
+            "Fast enemies shed their armour to outrun the first tower.",
+            "They time their sprints to the gaps between volleys.",
+            "Some have been clocked crossing the map before the wave horn ends.",
+            "They no longer run from your towers. They run past them.",
+        ],
+        EnemyType::Tank => &[
+            "Tanks carry plating salvaged from towers they have broken.",
+            "A tank's crew is rotated at every leak. None ask for leave.",
+            "Their treads are tuned to the path's exact width.",
+            "The last tank you stopped was painted with your colours.",
+        ],
+        EnemyType::Flying => &[
+            "Flyers were first sighted over the void, never over land.",
+            "They ride the updraft rising from the edge of the map.",
+            "Flocks split to draw fire, then close up past the towers.",
+            "They have started nesting in the void between your waves.",
+        ],
+        EnemyType::Shielded => &[
+            "Each shield is forged to stop exactly one kind of shot.",
+            "Shielded enemies walk at the back, letting others test your range.",
+            "Broken shields are carried home and melted into new ones.",
+            "The newest shields hum at the pitch of your lasers.",
+        ],
+        EnemyType::Boss => &[
+            "Bosses only take the field once the path is worn smooth.",
+            "Every boss you stop is replaced by one that watched it fall.",
+            "Their bounty was raised after your hundredth kill.",
+            "The bosses have stopped coming alone.",
+        ],
+    }
+}
@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::resources::GRID_CELL_SIZE;
+
+/// Enemies bucketed by grid cell, rebuilt every frame after movement.
+/// Cells are `GRID_CELL_SIZE` squares and extend past the play area, so
+/// enemies still entering or leaving the map are indexed too.
+#[derive(Resource, Debug, Default)]
+pub struct EnemySpatialIndex {
+    cells: HashMap<IVec2, Vec<Entity>>,
+}
+
+impl EnemySpatialIndex {
+    /// Cell containing a world position
+    pub fn cell_of(position: Vec2) -> IVec2 {
+        (position / GRID_CELL_SIZE).floor().as_ivec2()
+    }
+
+    /// World position of a cell's centre
+    pub fn cell_center(cell: IVec2) -> Vec2 {
+        (cell.as_vec2() + Vec2::splat(0.5)) * GRID_CELL_SIZE
+    }
+
+    pub fn clear(&mut self) {
+        // Keep the buckets' allocations for the next rebuild
+        for enemies in self.cells.values_mut() {
+            enemies.clear();
+        }
+    }
+
+    pub fn insert(&mut self, entity: Entity, position: Vec2) {
+        self.cells.entry(Self::cell_of(position)).or_default().push(entity);
+    }
+
+    /// Enemies in a cell, in a stable order (oldest entity first)
+    pub fn enemies_in(&self, cell: IVec2) -> &[Entity] {
+        self.cells.get(&cell).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn count_in(&self, cell: IVec2) -> usize {
+        self.enemies_in(cell).len()
+    }
+
+    /// Every occupied cell with its enemies
+    pub fn occupied_cells(&self) -> impl Iterator<Item = (IVec2, &[Entity])> {
+        self.cells
+            .iter()
+            .filter(|(_, enemies)| !enemies.is_empty())
+            .map(|(cell, enemies)| (*cell, enemies.as_slice()))
+    }
+
+    /// Sort each bucket so layering doesn't depend on query order
+    pub fn sort(&mut self) {
+        for enemies in self.cells.values_mut() {
+            enemies.sort();
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+
+/// Frames per second the game updates at while power saving and out of focus
+pub const POWER_SAVING_FPS: f64 = 10.0;
+
+/// Highest frame rate the game renders at, chosen in the settings menu
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FrameLimit {
+    Fps30,
+    Fps60,
+    Fps120,
+    /// Left to VSync
+    #[default]
+    Unlimited,
+}
+
+impl FrameLimit {
+    pub const ALL: [FrameLimit; 4] = [FrameLimit::Fps30, FrameLimit::Fps60, FrameLimit::Fps120, FrameLimit::Unlimited];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            FrameLimit::Fps30 => "30 FPS",
+            FrameLimit::Fps60 => "60 FPS",
+            FrameLimit::Fps120 => "120 FPS",
+            FrameLimit::Unlimited => "Unlimited",
+        }
+    }
+
+    /// Shortest time a frame may take, or `None` when uncapped
+    pub fn frame_duration(&self) -> Option<Duration> {
+        let fps = match self {
+            FrameLimit::Fps30 => 30.0,
+            FrameLimit::Fps60 => 60.0,
+            FrameLimit::Fps120 => 120.0,
+            FrameLimit::Unlimited => return None,
+        };
+        Some(Duration::from_secs_f64(1.0 / fps))
+    }
+
+    /// How long to wait before starting the next frame, given how long this one took
+    pub fn remaining_wait(&self, frame_time: Duration) -> Duration {
+        self.frame_duration()
+            .map_or(Duration::ZERO, |target| target.saturating_sub(frame_time))
+    }
+
+    /// The next limit in the cycle, wrapping around
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|limit| limit == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
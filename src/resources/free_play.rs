@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use super::run_results::RunResults;
+use super::score::Score;
+
+/// Resource for the endless continuation of a won campaign. While active, the
+/// board, towers and economy carry over and waves keep scaling with no victory.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct FreePlayRun {
+    pub active: bool,
+    /// Wave the campaign was won on; free play starts after it
+    pub start_wave: u32,
+    /// Score when free play began, so free play is ranked on what it added
+    pub start_score: u32,
+    /// Campaign result, kept apart from the free play one
+    pub campaign: Option<RunResults>,
+}
+
+impl FreePlayRun {
+    /// Continue a won campaign from `wave`
+    pub fn start(&mut self, wave: u32, score: &Score, campaign: Option<RunResults>) {
+        *self = Self {
+            active: true,
+            start_wave: wave,
+            start_score: score.current,
+            campaign,
+        };
+    }
+
+    /// Free play waves fully cleared while `current_wave` is being fought
+    pub fn waves_survived(&self, current_wave: u32) -> u32 {
+        current_wave.saturating_sub(self.start_wave + 1)
+    }
+
+    /// Score earned since free play began
+    pub fn score_gained(&self, score: &Score) -> u32 {
+        score.current.saturating_sub(self.start_score)
+    }
+}
@@ -0,0 +1,147 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use crate::resources::TowerType;
+
+// ============================================================================
+// LAYOUT CONSTANTS
+// ============================================================================
+
+/// Width of the play area covered by the grid, in world pixels
+pub const PLAY_AREA_WIDTH: f32 = 1280.0;
+
+/// Height of the play area covered by the grid, in world pixels
+pub const PLAY_AREA_HEIGHT: f32 = 720.0;
+
+/// Side length of one square of the dense placement grid, in world pixels.
+/// The grid dimensions below follow from it.
+pub const GRID_CELL_SIZE: f32 = 40.0;
+
+/// Grid columns needed to cover the play area (32 at the default cell size)
+pub const GRID_WIDTH: usize = (PLAY_AREA_WIDTH / GRID_CELL_SIZE) as usize;
+
+/// Grid rows needed to cover the play area (18 at the default cell size)
+pub const GRID_HEIGHT: usize = (PLAY_AREA_HEIGHT / GRID_CELL_SIZE) as usize;
+
+/// Side length of the area a tower occupies when placed - exactly one grid cell
+pub const TOWER_FOOTPRINT: f32 = GRID_CELL_SIZE;
+
+/// How close a click must land to a tower's center to select it
+pub const TOWER_CLICK_RADIUS: f32 = 40.0;
+
+/// Side length of the ring drawn under the tower selected for upgrades
+pub const SELECTION_RING_SIZE: f32 = 50.0;
+
+/// Side length of the ring drawn under each tower of a multi-selection
+pub const GROUP_SELECTION_RING_SIZE: f32 = 46.0;
+
+/// Left edge of the tower tooltip until the tower buttons are laid out, in UI pixels
+pub const TOOLTIP_LEFT: f32 = 50.0;
+
+/// Top edge of the tooltip for the first tower type, in UI pixels
+pub const TOOLTIP_TOP: f32 = 200.0;
+
+/// Vertical step between the tooltips of consecutive tower types
+pub const TOOLTIP_STAGGER: f32 = 100.0;
+
+/// Size of the tower stat popup, assumed for placement until it's first laid out
+pub const STAT_POPUP_SIZE: Vec2 = Vec2::new(340.0, 400.0);
+
+/// Config files layered over the defaults at startup, later files winning
+pub const CONSTANTS_OVERRIDE_FILES: [&str; 2] = ["balance.json", "theme.json"];
+
+// ============================================================================
+// OVERRIDABLE CONSTANTS
+// ============================================================================
+
+/// Runtime copy of the tunable layout constants. Starts from the values above;
+/// each config layer only needs to name the fields it changes.
+///
+/// The grid cell size stays a compile-time constant: the path and obstacle
+/// grids are laid out from it before any config is read.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameConstants {
+    pub tower_footprint: f32,
+    pub tower_click_radius: f32,
+    pub selection_ring_size: f32,
+    pub group_selection_ring_size: f32,
+    pub tooltip_left: f32,
+    pub tooltip_top: f32,
+    pub tooltip_stagger: f32,
+    pub stat_popup_width: f32,
+    pub stat_popup_height: f32,
+}
+
+impl Default for GameConstants {
+    fn default() -> Self {
+        Self {
+            tower_footprint: TOWER_FOOTPRINT,
+            tower_click_radius: TOWER_CLICK_RADIUS,
+            selection_ring_size: SELECTION_RING_SIZE,
+            group_selection_ring_size: GROUP_SELECTION_RING_SIZE,
+            tooltip_left: TOOLTIP_LEFT,
+            tooltip_top: TOOLTIP_TOP,
+            tooltip_stagger: TOOLTIP_STAGGER,
+            stat_popup_width: STAT_POPUP_SIZE.x,
+            stat_popup_height: STAT_POPUP_SIZE.y,
+        }
+    }
+}
+
+impl GameConstants {
+    /// Apply one JSON config layer on top of the current values.
+    /// Keys this struct doesn't know about are ignored, so balance and theme
+    /// files can carry their own settings alongside these.
+    pub fn apply_layer(&mut self, contents: &str) -> Result<(), String> {
+        let layer: Value = serde_json::from_str(contents).map_err(|e| e.to_string())?;
+        let overrides = layer.as_object().ok_or("expected a JSON object")?;
+
+        let mut merged = serde_json::to_value(&*self).map_err(|e| e.to_string())?;
+        let fields = merged.as_object_mut().ok_or("expected a JSON object")?;
+        for (key, value) in overrides {
+            if fields.contains_key(key) {
+                fields.insert(key.clone(), value.clone());
+            }
+        }
+
+        *self = serde_json::from_value(merged).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    /// Defaults with each layer applied in order. A broken layer is skipped
+    /// so one bad file doesn't throw away the others.
+    pub fn layered<'a>(layers: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut constants = Self::default();
+        for contents in layers {
+            if let Err(error) = constants.apply_layer(contents) {
+                warn!("Ignoring constants override layer: {}", error);
+            }
+        }
+        constants
+    }
+
+    /// Load the defaults with any override files present next to the game
+    pub fn load() -> Self {
+        let layers: Vec<String> = CONSTANTS_OVERRIDE_FILES
+            .iter()
+            .filter_map(|path| std::fs::read_to_string(path).ok())
+            .collect();
+        if !layers.is_empty() {
+            info!("Applying {} constants override file(s)", layers.len());
+        }
+        Self::layered(layers.iter().map(String::as_str))
+    }
+
+    pub fn stat_popup_size(&self) -> Vec2 {
+        Vec2::new(self.stat_popup_width, self.stat_popup_height)
+    }
+
+    /// Where the tooltip for a tower type's button is shown
+    pub fn tooltip_position(&self, tower_type: TowerType) -> Vec2 {
+        Vec2::new(
+            self.tooltip_left,
+            self.tooltip_top + tower_type as u8 as f32 * self.tooltip_stagger,
+        )
+    }
+}
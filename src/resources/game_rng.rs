@@ -0,0 +1,26 @@
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Seeded random source for gameplay rolls such as loot drops. Simulation code
+/// draws from this instead of `rand::random`, so the same seed and inputs
+/// always replay the same run.
+#[derive(Resource, Debug, Clone)]
+pub struct GameRng(StdRng);
+
+impl GameRng {
+    pub fn from_seed(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
+
+    /// Uniform roll in `0.0..1.0`
+    pub fn roll(&mut self) -> f32 {
+        self.0.random()
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::from_seed(0)
+    }
+}
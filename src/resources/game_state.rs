@@ -10,6 +10,22 @@ pub enum AppState {
     Paused,
     /// Settings menu is open
     Settings,
+    /// Title screen shown on launch, before any gameplay runs
+    MainMenu,
+    /// Run has ended - results screen visible until the run is restarted or continued
+    GameOver,
+    /// Map editor open - painting a custom map, no gameplay runs
+    Editor,
+}
+
+/// Resource holding the state the settings menu goes back to when closed
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SettingsReturnState(pub AppState);
+
+impl Default for SettingsReturnState {
+    fn default() -> Self {
+        Self(AppState::Paused)
+    }
 }
 
 /// Game state for tracking win/loss conditions (separate from UI state)
@@ -37,4 +53,31 @@ pub enum GameSystemSet {
     Settings,
     /// Input systems - run in all states but handle differently
     Input,
-}
\ No newline at end of file
+}
+/// Ordered stages of the wave and enemy lifecycle within `GameSystemSet::Gameplay`
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum EnemySet {
+    /// Wave start requests (button, keyboard)
+    WaveControl,
+    /// Path regeneration and path visuals - must run before spawning
+    PathGeneration,
+    /// Spawning queued enemies at the path start
+    Spawning,
+    /// Moving enemies along the path
+    Movement,
+    /// Removing enemies that reached the end
+    Cleanup,
+}
+
+/// Ordered stages of combat within `GameSystemSet::Gameplay`, after `EnemySet`
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub enum CombatSet {
+    /// Towers pick targets from current enemy positions
+    Targeting,
+    /// Towers fire projectiles at their targets
+    Firing,
+    /// Projectiles move toward their targets
+    ProjectileMovement,
+    /// Projectile hits, damage and kill rewards
+    Collision,
+}
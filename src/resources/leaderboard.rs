@@ -0,0 +1,160 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+use super::prestige::PrestigeSet;
+use super::run_mode::RunMode;
+
+/// Profile file the run leaderboard is kept in, next to settings.json
+pub const LEADERBOARD_FILE: &str = "leaderboard.json";
+/// Runs the leaderboard keeps for each mode
+pub const LEADERBOARD_SIZE: usize = 10;
+
+/// Which ranking a run competes in. Runs are only ranked against runs of the
+/// same mode.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum LeaderboardMode {
+    #[default]
+    Campaign,
+    Endless,
+    /// Continuation of a won campaign, ranked on what it added
+    FreePlay,
+}
+
+impl LeaderboardMode {
+    pub const ALL: [LeaderboardMode; 3] = [
+        LeaderboardMode::Campaign,
+        LeaderboardMode::Endless,
+        LeaderboardMode::FreePlay,
+    ];
+}
+
+impl From<RunMode> for LeaderboardMode {
+    fn from(run_mode: RunMode) -> Self {
+        match run_mode {
+            RunMode::Campaign => LeaderboardMode::Campaign,
+            RunMode::Endless => LeaderboardMode::Endless,
+        }
+    }
+}
+
+/// One finished run
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    /// Waves reached, or for free play the waves survived past the campaign
+    #[serde(alias = "waves_reached")]
+    pub waves: u32,
+    pub score: u32,
+    /// Day the run ended, as "YYYY-MM-DD" (UTC)
+    pub date: String,
+    pub seed: u64,
+    #[serde(default, alias = "run_mode")]
+    pub mode: LeaderboardMode,
+    /// Prestige modifiers the run was played with
+    #[serde(default)]
+    pub prestige: PrestigeSet,
+}
+
+/// Calendar date of a Unix timestamp, as "YYYY-MM-DD" (UTC)
+pub fn format_date(unix_secs: u64) -> String {
+    // Days since 1970-01-01 to a civil date, counting in 400-year eras from 0000-03-01
+    let days = (unix_secs / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Today's date for a new leaderboard entry
+pub fn today() -> String {
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs());
+    format_date(secs)
+}
+
+/// Resource holding the profile's best runs, best first within each mode
+#[derive(Resource, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RunLeaderboard {
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+impl RunLeaderboard {
+    /// Insert a run, returning its 1-based rank within its mode if it made
+    /// the board. More waves rank higher, then more score; ties keep the
+    /// earlier run ahead.
+    pub fn record(&mut self, entry: LeaderboardEntry) -> Option<usize> {
+        let mode = entry.mode;
+        let rank = self
+            .entries_for(mode)
+            .position(|other| (entry.waves, entry.score) > (other.waves, other.score))
+            .unwrap_or_else(|| self.entries_for(mode).count());
+        if rank >= LEADERBOARD_SIZE {
+            return None;
+        }
+        let index = self.index_in_mode(mode, rank).unwrap_or(self.entries.len());
+        self.entries.insert(index, entry);
+        if let Some(dropped) = self.index_in_mode(mode, LEADERBOARD_SIZE) {
+            self.entries.remove(dropped);
+        }
+        Some(rank + 1)
+    }
+
+    /// Runs of one mode, best first
+    pub fn entries_for(&self, mode: LeaderboardMode) -> impl Iterator<Item = &LeaderboardEntry> + '_ {
+        self.entries.iter().filter(move |entry| entry.mode == mode)
+    }
+
+    pub fn best(&self, mode: LeaderboardMode) -> Option<&LeaderboardEntry> {
+        self.entries_for(mode).next()
+    }
+
+    /// Position in `entries` of the run ranked `rank` (0-based) in `mode`
+    fn index_in_mode(&self, mode: LeaderboardMode, rank: usize) -> Option<usize> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| entry.mode == mode)
+            .nth(rank)
+            .map(|(index, _)| index)
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+
+    pub fn from_json(contents: &str) -> Result<Self, String> {
+        serde_json::from_str(contents).map_err(|e| e.to_string())
+    }
+
+    /// Load the profile's leaderboard, starting fresh if the file is missing or broken
+    pub fn load() -> Self {
+        let Ok(contents) = std::fs::read_to_string(LEADERBOARD_FILE) else {
+            return Self::default();
+        };
+        Self::from_json(&contents).unwrap_or_else(|error| {
+            warn!("Ignoring leaderboard in {}: {}", LEADERBOARD_FILE, error);
+            Self::default()
+        })
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        std::fs::write(LEADERBOARD_FILE, self.to_json()?).map_err(|e| e.to_string())
+    }
+}
+
+/// Leaderboard row, e.g. "#1  Wave 12  4,500 pts  2026-10-16  seed 42"
+pub fn leaderboard_row(rank: usize, entry: &LeaderboardEntry, score: &str) -> String {
+    let (waves, mode) = match entry.mode {
+        LeaderboardMode::Campaign => (format!("Wave {}", entry.waves), ""),
+        LeaderboardMode::Endless => (format!("Wave {}", entry.waves), "  endless"),
+        LeaderboardMode::FreePlay => (format!("+{} waves", entry.waves), "  free play"),
+    };
+    let mut row = format!("#{}  {}  {} pts  {}  seed {}{}", rank, waves, score, entry.date, entry.seed, mode);
+    if !entry.prestige.is_empty() {
+        row.push_str(&format!("  prestige: {}", entry.prestige.label()));
+    }
+    row
+}
@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use crate::resources::{ResourceCost, TowerType};
+
+/// Furthest a tower's price can drift from its list price, as a fraction
+pub const MARKET_MAX_SWING: f32 = 0.2;
+/// Largest move a price makes between two waves, as a fraction of list price
+pub const MARKET_STEP: f32 = 0.08;
+/// Moves smaller than this show as steady
+const TREND_THRESHOLD: f32 = 0.005;
+
+/// Which way a tower's price moved at the last wave
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriceTrend {
+    Rising,
+    Falling,
+    Steady,
+}
+
+impl PriceTrend {
+    /// Arrow shown beside the price
+    pub fn arrow(&self) -> &'static str {
+        match self {
+            PriceTrend::Rising => "^",
+            PriceTrend::Falling => "v",
+            PriceTrend::Steady => "",
+        }
+    }
+}
+
+/// Optional market mode: each wave every tower's price takes a seeded random
+/// step, staying within `MARKET_MAX_SWING` of its list price. The same seed
+/// always walks the same prices.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct MarketState {
+    pub enabled: bool,
+    /// Seed the walk is drawn from, normally the level seed
+    pub seed: u64,
+    /// Wave the prices were last moved for
+    pub wave: u32,
+    multipliers: [f32; TowerType::ALL.len()],
+    previous: [f32; TowerType::ALL.len()],
+}
+
+impl Default for MarketState {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl MarketState {
+    /// Flat prices at the start of a run
+    pub fn new(seed: u64) -> Self {
+        Self {
+            enabled: false,
+            seed,
+            wave: 0,
+            multipliers: [1.0; TowerType::ALL.len()],
+            previous: [1.0; TowerType::ALL.len()],
+        }
+    }
+
+    fn index(tower_type: TowerType) -> usize {
+        TowerType::ALL.iter().position(|other| *other == tower_type).unwrap_or(0)
+    }
+
+    /// Current price relative to list price (1.0 while the market is off)
+    pub fn multiplier(&self, tower_type: TowerType) -> f32 {
+        if self.enabled {
+            self.multipliers[Self::index(tower_type)]
+        } else {
+            1.0
+        }
+    }
+
+    /// What building a tower costs right now
+    pub fn tower_cost(&self, tower_type: TowerType) -> ResourceCost {
+        let list_price = tower_type.get_cost();
+        if self.enabled {
+            list_price.scaled(self.multiplier(tower_type))
+        } else {
+            list_price
+        }
+    }
+
+    pub fn trend(&self, tower_type: TowerType) -> PriceTrend {
+        if !self.enabled {
+            return PriceTrend::Steady;
+        }
+        let index = Self::index(tower_type);
+        let change = self.multipliers[index] - self.previous[index];
+        if change > TREND_THRESHOLD {
+            PriceTrend::Rising
+        } else if change < -TREND_THRESHOLD {
+            PriceTrend::Falling
+        } else {
+            PriceTrend::Steady
+        }
+    }
+
+    /// Walk the prices forward to a wave, one step per wave passed
+    pub fn advance_to(&mut self, wave: u32) {
+        while self.wave < wave {
+            self.wave += 1;
+            self.step();
+        }
+    }
+
+    fn step(&mut self) {
+        // Each wave draws from its own stream, so prices depend only on seed and wave
+        let mut rng = StdRng::seed_from_u64(self.seed ^ (self.wave as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        self.previous = self.multipliers;
+        for multiplier in &mut self.multipliers {
+            let step = rng.random_range(-MARKET_STEP..=MARKET_STEP);
+            *multiplier = (*multiplier + step).clamp(1.0 - MARKET_MAX_SWING, 1.0 + MARKET_MAX_SWING);
+        }
+    }
+}
+
+/// What building a tower costs, through the market when there is one
+pub fn resolve_tower_cost(market: Option<&MarketState>, tower_type: TowerType) -> ResourceCost {
+    market.map_or_else(|| tower_type.get_cost(), |market| market.tower_cost(tower_type))
+}
@@ -1,12 +1,70 @@
 pub mod game_state;
 pub mod wave_manager;
+pub mod wave_composition;
 pub mod score;
 pub mod economy;
 pub mod path_generation;
+pub mod run_results;
+pub mod active_buffs;
+pub mod changelog;
+pub mod checkpoint;
+pub mod save_version;
+pub mod game_constants;
+pub mod game_rng;
+pub mod run_perks;
+pub mod balance_config;
+pub mod effect_budget;
+pub mod stress_test;
+pub mod simulation_clock;
+pub mod reward_chest;
+pub mod enemy_codex;
+pub mod number_format;
+pub mod free_play;
+pub mod prestige;
+pub mod bounty_ledger;
+pub mod frame_pacing;
+pub mod seasonal_event;
+pub mod wave_config;
+pub mod enemy_spatial_index;
+pub mod path_style;
+pub mod player_base;
+pub mod leaderboard;
+pub mod market;
+pub mod run_integrity;
+pub mod run_mode;
 
 pub use game_state::*;
 pub use wave_manager::*;
+pub use wave_composition::*;
 pub use score::*;
 pub use economy::*;
+pub use run_results::*;
+pub use active_buffs::*;
+pub use changelog::*;
+pub use checkpoint::*;
+pub use save_version::*;
+pub use game_constants::*;
+pub use game_rng::*;
+pub use run_perks::*;
+pub use balance_config::*;
+pub use effect_budget::*;
+pub use stress_test::*;
+pub use simulation_clock::*;
+pub use reward_chest::*;
+pub use enemy_codex::*;
+pub use number_format::*;
+pub use free_play::*;
+pub use prestige::*;
+pub use bounty_ledger::*;
+pub use frame_pacing::*;
+pub use seasonal_event::*;
+pub use wave_config::*;
+pub use enemy_spatial_index::*;
+pub use path_style::*;
+pub use player_base::*;
+pub use leaderboard::*;
+pub use market::*;
+pub use run_integrity::*;
+pub use run_mode::*;
 // Re-export only specific types from path_generation to avoid namespace conflicts
 pub use path_generation::{PathGenerationConfig, PathGenerationState};
\ No newline at end of file
@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Smallest value compact notation shortens, so everyday prices stay exact
+pub const COMPACT_THRESHOLD: u64 = 10_000;
+/// Compact notation suffixes, largest first
+const COMPACT_UNITS: [(u64, &str); 3] = [(1_000_000_000, "B"), (1_000_000, "M"), (1_000, "k")];
+
+/// Conventions for writing numbers and money
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum NumberLocale {
+    /// 1,234.5 and $1,234
+    #[default]
+    English,
+    /// 1.234,5 and 1.234 $
+    German,
+    /// 1 234,5 and 1 234 $
+    French,
+}
+
+impl NumberLocale {
+    pub const ALL: [NumberLocale; 3] = [NumberLocale::English, NumberLocale::German, NumberLocale::French];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            NumberLocale::English => "English (1,234.5)",
+            NumberLocale::German => "German (1.234,5)",
+            NumberLocale::French => "French (1 234,5)",
+        }
+    }
+
+    pub fn group_separator(&self) -> char {
+        match self {
+            NumberLocale::English => ',',
+            NumberLocale::German => '.',
+            NumberLocale::French => ' ',
+        }
+    }
+
+    pub fn decimal_separator(&self) -> char {
+        match self {
+            NumberLocale::English => '.',
+            NumberLocale::German | NumberLocale::French => ',',
+        }
+    }
+
+    /// The locale after this one, wrapping around
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|locale| locale == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Resource every UI system formats money and counts through, so the player's
+/// number settings apply everywhere
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NumberFormatter {
+    pub locale: NumberLocale,
+    /// Shorten large values to e.g. "12.5k"
+    pub compact: bool,
+}
+
+impl NumberFormatter {
+    pub fn new(locale: NumberLocale, compact: bool) -> Self {
+        Self { locale, compact }
+    }
+
+    /// A count such as "1,204", or "12.5k" in compact notation
+    pub fn count(&self, value: impl Into<u64>) -> String {
+        let value = value.into();
+        self.compact_count(value).unwrap_or_else(|| self.grouped(value))
+    }
+
+    /// A money amount such as "$1,204", with the symbol where the locale puts it
+    pub fn money(&self, amount: impl Into<u64>) -> String {
+        let number = self.count(amount);
+        match self.locale {
+            NumberLocale::English => format!("${}", number),
+            NumberLocale::German | NumberLocale::French => format!("{} $", number),
+        }
+    }
+
+    /// Digits with the locale's thousands separators
+    fn grouped(&self, value: u64) -> String {
+        let digits = value.to_string();
+        let mut formatted = String::with_capacity(digits.len() + digits.len() / 3);
+        for (index, digit) in digits.chars().enumerate() {
+            if index > 0 && (digits.len() - index).is_multiple_of(3) {
+                formatted.push(self.locale.group_separator());
+            }
+            formatted.push(digit);
+        }
+        formatted
+    }
+
+    /// Compact form of a large value. Tenths are rounded down so a shortened
+    /// amount never overstates what the player has.
+    fn compact_count(&self, value: u64) -> Option<String> {
+        if !self.compact || value < COMPACT_THRESHOLD {
+            return None;
+        }
+        let (unit, suffix) = COMPACT_UNITS.into_iter().find(|(unit, _)| value >= *unit)?;
+        let whole = value / unit;
+        let tenths = value % unit * 10 / unit;
+        if whole >= 100 || tenths == 0 {
+            Some(format!("{}{}", self.grouped(whole), suffix))
+        } else {
+            Some(format!("{}{}{}{}", whole, self.locale.decimal_separator(), tenths, suffix))
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+
+/// How the enemy path is drawn, chosen in the settings menu
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum PathStyle {
+    /// A plain line along each segment
+    Line,
+    /// A dotted trail
+    Dots,
+    /// Chevrons pointing the way enemies travel
+    Chevrons,
+    /// Dashes flowing along the path at enemy speed
+    #[default]
+    Flow,
+}
+
+impl PathStyle {
+    pub const ALL: [PathStyle; 4] = [PathStyle::Line, PathStyle::Dots, PathStyle::Chevrons, PathStyle::Flow];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            PathStyle::Line => "Line",
+            PathStyle::Dots => "Dots",
+            PathStyle::Chevrons => "Chevrons",
+            PathStyle::Flow => "Flow",
+        }
+    }
+
+    /// Distance between the marks drawn along a segment, or `None` for the plain line
+    pub fn mark_spacing(&self) -> Option<f32> {
+        match self {
+            PathStyle::Line => None,
+            PathStyle::Dots => Some(14.0),
+            PathStyle::Chevrons => Some(30.0),
+            PathStyle::Flow => Some(24.0),
+        }
+    }
+
+    /// The next style in the cycle, wrapping around
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|style| style == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
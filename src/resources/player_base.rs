@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+use crate::components::{Health, BASE_MAX_HEALTH, ENEMY_BASE_DAMAGE};
+
+/// Escapes the base can take before it falls, for a given amount of base health
+pub fn lives_for_health(health: f32) -> u32 {
+    (health.max(0.0) / ENEMY_BASE_DAMAGE).ceil() as u32
+}
+
+/// Lives the player has left: one per escape the base can still absorb.
+/// Always read off the base's `Health` by `player_base_sync_system`, its only
+/// writer, so escapes, repairs, perks and restarts all count through the health.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerBase {
+    pub lives: u32,
+    pub max_lives: u32,
+}
+
+impl Default for PlayerBase {
+    fn default() -> Self {
+        let lives = lives_for_health(BASE_MAX_HEALTH);
+        Self { lives, max_lives: lives }
+    }
+}
+
+impl PlayerBase {
+    /// Lives matching the base's current and maximum health
+    pub fn from_health(health: &Health) -> Self {
+        Self {
+            lives: lives_for_health(health.current),
+            max_lives: lives_for_health(health.max),
+        }
+    }
+
+    pub fn is_destroyed(&self) -> bool {
+        self.lives == 0
+    }
+}
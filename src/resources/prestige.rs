@@ -0,0 +1,161 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Profile file prestige progress is kept in
+pub const PRESTIGE_PROFILE_FILE: &str = "prestige_profile.json";
+
+/// Enemy health multiplier of the Tough Enemies modifier
+pub const TOUGH_ENEMIES_HEALTH_MULTIPLIER: f32 = 1.2;
+/// Starting money multiplier of the Lean Start modifier
+pub const LEAN_START_MONEY_MULTIPLIER: f32 = 0.75;
+
+/// Optional run modifier unlocked by winning a campaign. Each one makes the
+/// run harder in exchange for a score multiplier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PrestigeModifier {
+    ToughEnemies,
+    LeanStart,
+}
+
+impl PrestigeModifier {
+    pub const ALL: [PrestigeModifier; 2] = [PrestigeModifier::ToughEnemies, PrestigeModifier::LeanStart];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            PrestigeModifier::ToughEnemies => "Tough Enemies",
+            PrestigeModifier::LeanStart => "Lean Start",
+        }
+    }
+
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            PrestigeModifier::ToughEnemies => "+20% enemy HP",
+            PrestigeModifier::LeanStart => "-25% starting money",
+        }
+    }
+
+    /// Multiplier applied to score while this modifier is active
+    pub fn score_multiplier(&self) -> f32 {
+        match self {
+            PrestigeModifier::ToughEnemies => 1.25,
+            PrestigeModifier::LeanStart => 1.2,
+        }
+    }
+
+    fn bit(&self) -> u8 {
+        1 << (*self as u8)
+    }
+}
+
+/// Set of prestige modifiers. Kept as bits so leaderboard entries stay `Copy`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PrestigeSet(u8);
+
+impl PrestigeSet {
+    pub fn contains(&self, modifier: PrestigeModifier) -> bool {
+        self.0 & modifier.bit() != 0
+    }
+
+    pub fn insert(&mut self, modifier: PrestigeModifier) {
+        self.0 |= modifier.bit();
+    }
+
+    /// Add or remove a modifier, returning whether it is now in the set
+    pub fn toggle(&mut self, modifier: PrestigeModifier) -> bool {
+        self.0 ^= modifier.bit();
+        self.contains(modifier)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = PrestigeModifier> + '_ {
+        PrestigeModifier::ALL.into_iter().filter(|modifier| self.contains(*modifier))
+    }
+
+    /// Score multiplier of the whole set; stacked modifiers multiply
+    pub fn score_multiplier(&self) -> f32 {
+        self.iter().map(|modifier| modifier.score_multiplier()).product()
+    }
+
+    pub fn enemy_health_multiplier(&self) -> f32 {
+        if self.contains(PrestigeModifier::ToughEnemies) {
+            TOUGH_ENEMIES_HEALTH_MULTIPLIER
+        } else {
+            1.0
+        }
+    }
+
+    /// Money a run starts with given the usual starting money
+    pub fn starting_money(&self, base: u32) -> u32 {
+        if self.contains(PrestigeModifier::LeanStart) {
+            (base as f32 * LEAN_START_MONEY_MULTIPLIER).round() as u32
+        } else {
+            base
+        }
+    }
+
+    /// Points awarded for a kill worth `points` without prestige
+    pub fn scale_points(&self, points: u32) -> u32 {
+        (points as f32 * self.score_multiplier()).round() as u32
+    }
+
+    /// Modifier names joined for display, e.g. "Tough Enemies + Lean Start"
+    pub fn label(&self) -> String {
+        if self.is_empty() {
+            return "None".to_string();
+        }
+        self.iter().map(|modifier| modifier.get_name()).collect::<Vec<_>>().join(" + ")
+    }
+}
+
+/// Resource holding the profile's prestige progress: whether modifiers are
+/// unlocked and which ones the player picked for their next run
+#[derive(Resource, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct PrestigeProfile {
+    pub unlocked: bool,
+    pub selected: PrestigeSet,
+}
+
+impl PrestigeProfile {
+    /// Unlock prestige modifiers, returning whether this was the first unlock
+    pub fn unlock(&mut self) -> bool {
+        !std::mem::replace(&mut self.unlocked, true)
+    }
+
+    /// Toggle a modifier for the next run. Locked profiles can't select any.
+    pub fn toggle(&mut self, modifier: PrestigeModifier) -> bool {
+        self.unlocked && self.selected.toggle(modifier)
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+
+    pub fn from_json(contents: &str) -> Result<Self, String> {
+        serde_json::from_str(contents).map_err(|e| e.to_string())
+    }
+
+    /// Load the profile's prestige progress, starting fresh if the file is missing or broken
+    pub fn load() -> Self {
+        let Ok(contents) = std::fs::read_to_string(PRESTIGE_PROFILE_FILE) else {
+            return Self::default();
+        };
+        Self::from_json(&contents).unwrap_or_else(|error| {
+            warn!("Ignoring prestige profile in {}: {}", PRESTIGE_PROFILE_FILE, error);
+            Self::default()
+        })
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        std::fs::write(PRESTIGE_PROFILE_FILE, self.to_json()?).map_err(|e| e.to_string())
+    }
+}
+
+/// Resource holding the prestige modifiers the current run was started with
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq)]
+pub struct RunPrestige {
+    pub modifiers: PrestigeSet,
+}
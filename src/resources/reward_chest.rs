@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+use crate::resources::{Economy, GameRng, NumberFormatter, Perk, RunPerks};
+
+/// Money in every chest, plus `CHEST_CASH_PER_WAVE` for each wave cleared
+pub const CHEST_CASH_BASE: u32 = 60;
+pub const CHEST_CASH_PER_WAVE: u32 = 8;
+/// Research points in every chest, plus `CHEST_RESEARCH_PER_WAVE` for each wave cleared
+pub const CHEST_RESEARCH_BASE: u32 = 6;
+pub const CHEST_RESEARCH_PER_WAVE: u32 = 1;
+
+/// One of the rewards a chest offers; the player keeps exactly one
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChestReward {
+    Cash(u32),
+    Research(u32),
+    Perk(Perk),
+}
+
+impl ChestReward {
+    pub fn get_name(&self, formatter: &NumberFormatter) -> String {
+        match self {
+            ChestReward::Cash(amount) => formatter.money(*amount),
+            ChestReward::Research(amount) => format!("{} Research", formatter.count(*amount)),
+            ChestReward::Perk(perk) => perk.get_name().to_string(),
+        }
+    }
+
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            ChestReward::Cash(_) => "Money to spend right away",
+            ChestReward::Research(_) => "Research points for perks and upgrades",
+            ChestReward::Perk(perk) => perk.get_description(),
+        }
+    }
+}
+
+/// The cash, research and random perk rewards of a chest found after `wave`
+pub fn roll_chest_rewards(wave: u32, rng: &mut GameRng) -> Vec<ChestReward> {
+    let index = ((rng.roll() * Perk::ALL.len() as f32) as usize).min(Perk::ALL.len() - 1);
+    vec![
+        ChestReward::Cash(CHEST_CASH_BASE + CHEST_CASH_PER_WAVE * wave),
+        ChestReward::Research(CHEST_RESEARCH_BASE + CHEST_RESEARCH_PER_WAVE * wave),
+        ChestReward::Perk(Perk::ALL[index]),
+    ]
+}
+
+/// Resource for the reward chest offered after a boss wave
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct RewardChest {
+    pub open: bool,
+    /// Last wave a chest was offered for
+    pub wave: u32,
+    /// Rewards to choose from while the chest is open
+    pub rewards: Vec<ChestReward>,
+}
+
+impl RewardChest {
+    /// Offer a chest for clearing `wave`
+    pub fn open_after(&mut self, wave: u32, rng: &mut GameRng) {
+        self.open = true;
+        self.wave = wave;
+        self.rewards = roll_chest_rewards(wave, rng);
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+        self.rewards.clear();
+    }
+
+    /// Take the reward at `index` and close the chest, returning the reward.
+    /// Reinforcements act on the base and are applied by the caller.
+    pub fn try_claim(&mut self, index: usize, economy: &mut Economy, perks: &mut RunPerks) -> Option<ChestReward> {
+        let reward = *self.rewards.get(index).filter(|_| self.open)?;
+        match reward {
+            ChestReward::Cash(amount) => economy.money += amount,
+            ChestReward::Research(amount) => economy.research_points += amount,
+            ChestReward::Perk(perk) => perks.grant(perk, economy),
+        }
+        self.close();
+        Some(reward)
+    }
+}
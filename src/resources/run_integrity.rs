@@ -0,0 +1,102 @@
+use std::collections::VecDeque;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Real seconds over which tower placements are counted
+pub const PLACEMENT_RATE_WINDOW_SECS: f64 = 1.0;
+/// Placements allowed inside one window; more than a player can click
+pub const MAX_PLACEMENTS_PER_WINDOW: usize = 5;
+/// Slack for the rounding in summed frame times, so a placement exactly one
+/// window old has left it
+const WINDOW_TOLERANCE_SECS: f64 = 1e-9;
+
+/// Why a run stopped counting as cheat-free
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TamperReason {
+    /// The cheat menu was opened or its cheats were in effect
+    CheatMenu,
+    /// Admin or debug tools were switched on
+    AdminMode,
+    /// Towers were placed faster than the placement rate allows
+    PlacementRate,
+}
+
+impl TamperReason {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            TamperReason::CheatMenu => "cheat menu",
+            TamperReason::AdminMode => "admin tools",
+            TamperReason::PlacementRate => "placement rate",
+        }
+    }
+}
+
+/// Run metadata recording whether every change in the run came from legal
+/// player actions. Once tampered, a run stays tampered until the next one starts.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RunIntegrity {
+    reasons: Vec<TamperReason>,
+    /// Real seconds since the run started
+    elapsed: f64,
+    /// When recent placements happened, oldest first
+    recent_placements: VecDeque<f64>,
+}
+
+impl RunIntegrity {
+    pub fn is_clean(&self) -> bool {
+        self.reasons.is_empty()
+    }
+
+    pub fn reasons(&self) -> &[TamperReason] {
+        &self.reasons
+    }
+
+    /// Mark the run as tampered, once per reason
+    pub fn flag(&mut self, reason: TamperReason) {
+        if !self.reasons.contains(&reason) {
+            warn!("Run no longer leaderboard-eligible: {}", reason.get_name());
+            self.reasons.push(reason);
+        }
+    }
+
+    pub fn tick(&mut self, delta_secs: f64) {
+        self.elapsed += delta_secs;
+    }
+
+    /// Whether one more tower may be placed under the rate limit. Returns false,
+    /// and flags the run, when the placement would go over it and must be refused.
+    pub fn allows_placement(&mut self) -> bool {
+        while self
+            .recent_placements
+            .front()
+            .is_some_and(|placed| self.elapsed - placed >= PLACEMENT_RATE_WINDOW_SECS - WINDOW_TOLERANCE_SECS)
+        {
+            self.recent_placements.pop_front();
+        }
+        if self.recent_placements.len() >= MAX_PLACEMENTS_PER_WINDOW {
+            self.flag(TamperReason::PlacementRate);
+            return false;
+        }
+        true
+    }
+
+    /// Count a tower that was placed against the rate limit
+    pub fn record_placement(&mut self) {
+        self.recent_placements.push_back(self.elapsed);
+    }
+
+    /// Check a placement against the rate limit and count it if allowed, for
+    /// callers that have already made sure nothing else can refuse it
+    pub fn try_record_placement(&mut self) -> bool {
+        let allowed = self.allows_placement();
+        if allowed {
+            self.record_placement();
+        }
+        allowed
+    }
+
+    /// Start a new run with a clean record
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+/// Waves to survive for a campaign victory unless the settings say otherwise
+pub const DEFAULT_VICTORY_WAVES: u32 = 3;
+/// Victory wave counts the settings cycle through
+pub const VICTORY_WAVE_CHOICES: [u32; 5] = [3, 5, 10, 20, 30];
+
+/// How a run ends, picked on the main menu before the game starts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RunMode {
+    /// Survive the victory wave count to win
+    #[default]
+    Campaign,
+    /// No victory: enemies keep getting tougher until the base falls
+    Endless,
+}
+
+impl RunMode {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            RunMode::Campaign => "Campaign",
+            RunMode::Endless => "Endless",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            RunMode::Campaign => RunMode::Endless,
+            RunMode::Endless => RunMode::Campaign,
+        }
+    }
+}
+
+/// Extra enemy scaling in endless mode, on top of the usual per-wave stats.
+/// Health compounds every wave; speed grows linearly up to a cap so enemies
+/// stay catchable. Tuned under `endless` in the balance config.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EndlessScaling {
+    /// Fraction health grows by each wave, compounded
+    pub health_growth: f32,
+    /// Fraction of base speed added each wave
+    pub speed_growth: f32,
+    /// Highest speed multiplier endless scaling reaches
+    pub max_speed_multiplier: f32,
+}
+
+impl Default for EndlessScaling {
+    fn default() -> Self {
+        Self {
+            health_growth: 0.08,
+            speed_growth: 0.02,
+            max_speed_multiplier: 2.0,
+        }
+    }
+}
+
+impl EndlessScaling {
+    /// `(1 + health_growth)^(wave - 1)`
+    pub fn health_multiplier(&self, wave: u32) -> f32 {
+        (1.0 + self.health_growth).powi(wave.saturating_sub(1) as i32)
+    }
+
+    /// `1 + speed_growth * (wave - 1)`, capped at `max_speed_multiplier`
+    pub fn speed_multiplier(&self, wave: u32) -> f32 {
+        (1.0 + self.speed_growth * wave.saturating_sub(1) as f32).min(self.max_speed_multiplier)
+    }
+}
@@ -0,0 +1,189 @@
+use bevy::prelude::*;
+use crate::resources::{Economy, GameRng, ResourceCost};
+
+/// The shop opens after every wave that is a multiple of this
+pub const SHOP_INTERVAL: u32 = 5;
+/// Perks offered per shop visit
+pub const SHOP_OFFER_COUNT: usize = 3;
+/// Money cost of the first reroll in a visit; each further reroll costs this much more
+pub const SHOP_REROLL_COST: u32 = 15;
+
+/// Global damage bonus per Sharpened Rounds perk
+pub const DAMAGE_PERK_BONUS: f32 = 0.05;
+/// Global fire-rate bonus per Rapid Loaders perk
+pub const FIRE_RATE_PERK_BONUS: f32 = 0.05;
+/// Base escapes absorbed by one Reinforcements perk
+pub const LIVES_PER_PERK: u32 = 2;
+/// Research generated per second by one Field Research perk
+pub const RESEARCH_PERK_GENERATION: f32 = 0.2;
+
+/// Whether finishing `wave` opens the shop
+pub fn is_shop_wave(wave: u32) -> bool {
+    wave > 0 && wave.is_multiple_of(SHOP_INTERVAL)
+}
+
+/// Upgrade bought in the between-wave shop, lasting for the rest of the run
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum Perk {
+    SharpenedRounds,
+    RapidLoaders,
+    FreeTower,
+    Reinforcements,
+    FieldResearch,
+}
+
+impl Perk {
+    /// Every perk the shop can offer
+    pub const ALL: [Perk; 5] = [
+        Perk::SharpenedRounds,
+        Perk::RapidLoaders,
+        Perk::FreeTower,
+        Perk::Reinforcements,
+        Perk::FieldResearch,
+    ];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Perk::SharpenedRounds => "Sharpened Rounds",
+            Perk::RapidLoaders => "Rapid Loaders",
+            Perk::FreeTower => "Free Tower",
+            Perk::Reinforcements => "Reinforcements",
+            Perk::FieldResearch => "Field Research",
+        }
+    }
+
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            Perk::SharpenedRounds => "+5% damage for all towers",
+            Perk::RapidLoaders => "+5% fire rate for all towers",
+            Perk::FreeTower => "Your next tower costs nothing",
+            Perk::Reinforcements => "+2 lives for the base",
+            Perk::FieldResearch => "+0.2 research per second",
+        }
+    }
+
+    pub fn get_cost(&self) -> ResourceCost {
+        match self {
+            Perk::SharpenedRounds => ResourceCost::money(60),
+            Perk::RapidLoaders => ResourceCost::money(60),
+            Perk::FreeTower => ResourceCost::research(10),
+            Perk::Reinforcements => ResourceCost::money(40),
+            Perk::FieldResearch => ResourceCost::money(50),
+        }
+    }
+}
+
+/// Resource holding the perks bought this run
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct RunPerks {
+    /// Every perk bought, in purchase order; repeat purchases stack
+    pub purchased: Vec<Perk>,
+    /// Free tower placements not used yet
+    pub free_towers: u32,
+}
+
+impl RunPerks {
+    /// How many times a perk has been bought
+    pub fn stacks(&self, perk: Perk) -> u32 {
+        self.purchased.iter().filter(|bought| **bought == perk).count() as u32
+    }
+
+    /// Record a purchase. Perks acting on the economy or the base are applied by the caller.
+    pub fn add(&mut self, perk: Perk) {
+        self.purchased.push(perk);
+        if perk == Perk::FreeTower {
+            self.free_towers += 1;
+        }
+    }
+
+    /// Record a perk gained for free or bought, applying its effect on the economy.
+    /// Reinforcements act on the base and are applied by the caller.
+    pub fn grant(&mut self, perk: Perk, economy: &mut Economy) {
+        self.add(perk);
+        if perk == Perk::FieldResearch {
+            economy.research_generation += RESEARCH_PERK_GENERATION;
+        }
+    }
+
+    /// Multiplier applied to every tower's damage
+    pub fn damage_multiplier(&self) -> f32 {
+        1.0 + DAMAGE_PERK_BONUS * self.stacks(Perk::SharpenedRounds) as f32
+    }
+
+    /// Multiplier applied to every tower's fire rate
+    pub fn fire_rate_multiplier(&self) -> f32 {
+        1.0 + FIRE_RATE_PERK_BONUS * self.stacks(Perk::RapidLoaders) as f32
+    }
+
+    /// Use up a free tower placement, returning whether one was left
+    pub fn take_free_tower(&mut self) -> bool {
+        if self.free_towers == 0 {
+            return false;
+        }
+        self.free_towers -= 1;
+        true
+    }
+}
+
+/// Resource for the between-wave perk shop
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct PerkShop {
+    pub open: bool,
+    /// Last wave after which the shop was opened
+    pub visited_wave: u32,
+    /// Perks on offer; bought ones are removed
+    pub offers: Vec<Perk>,
+    /// Rerolls used this visit
+    pub rerolls: u32,
+}
+
+impl PerkShop {
+    /// Open the shop after `wave` with a fresh set of offers
+    pub fn open_after(&mut self, wave: u32, rng: &mut GameRng) {
+        self.open = true;
+        self.visited_wave = wave;
+        self.rerolls = 0;
+        self.roll_offers(rng);
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+        self.offers.clear();
+    }
+
+    /// Replace the offers with distinct perks drawn at random
+    pub fn roll_offers(&mut self, rng: &mut GameRng) {
+        let mut pool = Perk::ALL.to_vec();
+        self.offers.clear();
+        while self.offers.len() < SHOP_OFFER_COUNT && !pool.is_empty() {
+            let index = ((rng.roll() * pool.len() as f32) as usize).min(pool.len() - 1);
+            self.offers.push(pool.swap_remove(index));
+        }
+    }
+
+    /// Money the next reroll costs
+    pub fn reroll_cost(&self) -> ResourceCost {
+        ResourceCost::money(SHOP_REROLL_COST * (self.rerolls + 1))
+    }
+
+    /// Pay for and roll a new set of offers, returning whether it went through
+    pub fn try_reroll(&mut self, economy: &mut Economy, rng: &mut GameRng) -> bool {
+        if !self.open || !economy.try_spend(&self.reroll_cost()) {
+            return false;
+        }
+        self.rerolls += 1;
+        self.roll_offers(rng);
+        true
+    }
+
+    /// Pay for the offer at `index` and record it, returning the perk bought
+    pub fn try_buy(&mut self, index: usize, economy: &mut Economy, perks: &mut RunPerks) -> Option<Perk> {
+        let perk = *self.offers.get(index).filter(|_| self.open)?;
+        if !economy.try_spend(&perk.get_cost()) {
+            return None;
+        }
+        self.offers.remove(index);
+        perks.grant(perk, economy);
+        Some(perk)
+    }
+}
@@ -0,0 +1,70 @@
+use bevy::prelude::*;
+use super::game_state::GameState;
+use super::prestige::PrestigeSet;
+use super::score::Score;
+
+/// How a finished run ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunOutcome {
+    Victory,
+    Defeat,
+    /// Defeat while continuing a won campaign in free play
+    FreePlayEnded,
+}
+
+impl RunOutcome {
+    /// Outcome for a terminal game state, `None` while the game is still running
+    pub fn from_game_state(game_state: &GameState) -> Option<Self> {
+        match game_state {
+            GameState::Victory => Some(RunOutcome::Victory),
+            GameState::GameOver => Some(RunOutcome::Defeat),
+            GameState::Playing => None,
+        }
+    }
+}
+
+/// Snapshot of run statistics taken the moment the game ends.
+/// Inserted as a resource for the results screen and removed when a new run starts.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct RunResults {
+    pub outcome: RunOutcome,
+    pub waves_reached: u32,
+    pub enemies_killed: u32,
+    pub enemies_escaped: u32,
+    pub damage_dealt: f32,
+    pub money_earned: u32,
+    pub score: u32,
+    pub seed: u64,
+    /// Prestige modifiers the run was played with
+    pub prestige: PrestigeSet,
+    /// Cheats, admin tools or illegal actions were used, so the run is unranked
+    pub tampered: bool,
+}
+
+impl RunResults {
+    /// Capture the results of a run from the live score
+    pub fn capture(outcome: RunOutcome, score: &Score, waves_reached: u32, enemies_escaped: u32, seed: u64) -> Self {
+        Self {
+            outcome,
+            waves_reached,
+            enemies_killed: score.enemies_killed,
+            enemies_escaped,
+            damage_dealt: score.damage_dealt,
+            money_earned: score.money_earned,
+            score: score.current,
+            seed,
+            prestige: PrestigeSet::default(),
+            tampered: false,
+        }
+    }
+
+    pub fn with_prestige(mut self, prestige: PrestigeSet) -> Self {
+        self.prestige = prestige;
+        self
+    }
+
+    pub fn with_tampered(mut self, tampered: bool) -> Self {
+        self.tampered = tampered;
+        self
+    }
+}
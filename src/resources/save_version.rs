@@ -0,0 +1,258 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{Read, Write};
+use std::path::Path;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// JSON field holding the format version of a saved file
+pub const VERSION_FIELD: &str = "version";
+/// Version assumed for files written before saves were versioned
+pub const UNVERSIONED_SAVE_VERSION: u32 = 1;
+
+/// Leading bytes of a file written in `SaveFormat::Binary`
+pub const BINARY_SAVE_MAGIC: [u8; 4] = *b"TDSB";
+
+/// On-disk encoding of a save. Binary saves hold the same versioned document as
+/// JSON ones, deflate-compressed behind `BINARY_SAVE_MAGIC`, so both formats
+/// share one migration path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SaveFormat {
+    /// Pretty-printed JSON, readable and hand-editable
+    #[default]
+    Json,
+    /// Compact and compressed, for large saves
+    Binary,
+}
+
+impl SaveFormat {
+    pub const ALL: [SaveFormat; 2] = [SaveFormat::Json, SaveFormat::Binary];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            SaveFormat::Json => "JSON",
+            SaveFormat::Binary => "Binary",
+        }
+    }
+
+    /// Extension of files saved in this format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            SaveFormat::Json => "json",
+            SaveFormat::Binary => "sav",
+        }
+    }
+
+    /// File the save named `stem` is written to in this format
+    pub fn file_name(&self, stem: &str) -> String {
+        format!("{}.{}", stem, self.extension())
+    }
+
+    /// Format of a saved file, told apart by its magic bytes
+    pub fn detect(bytes: &[u8]) -> Self {
+        if bytes.starts_with(&BINARY_SAVE_MAGIC) {
+            SaveFormat::Binary
+        } else {
+            SaveFormat::Json
+        }
+    }
+}
+
+/// File the save named `stem` was last written to, in whichever format, if any
+pub fn existing_save_file(stem: &str) -> Option<String> {
+    SaveFormat::ALL
+        .iter()
+        .map(|format| format.file_name(stem))
+        .find(|file| Path::new(file).exists())
+}
+
+/// Errors from loading or writing a versioned save
+#[derive(Debug, Clone, PartialEq)]
+pub enum SaveError {
+    /// The file couldn't be read or written
+    Io(String),
+    /// The file isn't valid JSON or a valid binary save, or doesn't match the expected format
+    Parse(String),
+    /// The file was written by a newer build than this one
+    TooNew { found: u32, supported: u32 },
+    /// No migration is registered to take the file past this version
+    MissingMigration { from: u32 },
+    /// A migration rejected the file
+    Migration { from: u32, reason: String },
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::Io(reason) => write!(f, "save file error: {}", reason),
+            SaveError::Parse(reason) => write!(f, "save file could not be read: {}", reason),
+            SaveError::TooNew { found, supported } => write!(
+                f,
+                "save file is version {} but this build only understands up to version {}",
+                found, supported
+            ),
+            SaveError::MissingMigration { from } => write!(f, "no migration from save version {}", from),
+            SaveError::Migration { from, reason } => {
+                write!(f, "migrating save version {} failed: {}", from, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+/// Upgrades a saved JSON object from one version to the next, in place
+pub type Migration = fn(&mut serde_json::Map<String, Value>) -> Result<(), String>;
+
+/// Migrations for one save format, keyed by the version they upgrade from.
+/// Loading runs every migration between the file's version and the current one.
+#[derive(Debug, Clone)]
+pub struct MigrationRegistry {
+    current_version: u32,
+    migrations: BTreeMap<u32, Migration>,
+}
+
+impl MigrationRegistry {
+    pub fn new(current_version: u32) -> Self {
+        Self {
+            current_version,
+            migrations: BTreeMap::new(),
+        }
+    }
+
+    /// Register the migration from `from` to `from + 1`
+    pub fn register(mut self, from: u32, migration: Migration) -> Self {
+        self.migrations.insert(from, migration);
+        self
+    }
+
+    pub fn current_version(&self) -> u32 {
+        self.current_version
+    }
+
+    /// Version a saved file was written with. Files without a version field predate versioning.
+    pub fn version_of(value: &Value) -> Result<u32, SaveError> {
+        match value.get(VERSION_FIELD) {
+            None => Ok(UNVERSIONED_SAVE_VERSION),
+            Some(version) => version
+                .as_u64()
+                .and_then(|version| u32::try_from(version).ok())
+                .ok_or_else(|| SaveError::Parse(format!("invalid {} field: {}", VERSION_FIELD, version))),
+        }
+    }
+
+    /// Bring a saved JSON object up to the current version
+    pub fn migrate(&self, mut value: Value) -> Result<Value, SaveError> {
+        let mut version = Self::version_of(&value)?;
+        if version > self.current_version {
+            return Err(SaveError::TooNew {
+                found: version,
+                supported: self.current_version,
+            });
+        }
+
+        let object = value
+            .as_object_mut()
+            .ok_or_else(|| SaveError::Parse("expected a JSON object".to_string()))?;
+        while version < self.current_version {
+            let migration = self
+                .migrations
+                .get(&version)
+                .ok_or(SaveError::MissingMigration { from: version })?;
+            migration(object).map_err(|reason| SaveError::Migration { from: version, reason })?;
+            version += 1;
+            object.insert(VERSION_FIELD.to_string(), Value::from(version));
+        }
+        Ok(value)
+    }
+
+    /// Parse a saved file of any supported version
+    pub fn load<T: DeserializeOwned>(&self, contents: &str) -> Result<T, SaveError> {
+        let value: Value = serde_json::from_str(contents).map_err(|e| SaveError::Parse(e.to_string()))?;
+        self.load_value(value)
+    }
+
+    /// Parse a saved file of any supported version in either format
+    pub fn load_bytes<T: DeserializeOwned>(&self, bytes: &[u8]) -> Result<T, SaveError> {
+        let value: Value = match SaveFormat::detect(bytes) {
+            SaveFormat::Json => serde_json::from_slice(bytes),
+            SaveFormat::Binary => {
+                let mut json = Vec::new();
+                DeflateDecoder::new(&bytes[BINARY_SAVE_MAGIC.len()..])
+                    .read_to_end(&mut json)
+                    .map_err(|e| SaveError::Parse(format!("corrupt binary save: {}", e)))?;
+                serde_json::from_slice(&json)
+            }
+        }
+        .map_err(|e| SaveError::Parse(e.to_string()))?;
+        self.load_value(value)
+    }
+
+    fn load_value<T: DeserializeOwned>(&self, value: Value) -> Result<T, SaveError> {
+        let migrated = self.migrate(value)?;
+        serde_json::from_value(migrated).map_err(|e| SaveError::Parse(e.to_string()))
+    }
+
+    /// Serialize a value stamped with the current version
+    pub fn save<T: Serialize>(&self, data: &T) -> Result<String, SaveError> {
+        let value = self.stamped(data)?;
+        serde_json::to_string_pretty(&value).map_err(|e| SaveError::Parse(e.to_string()))
+    }
+
+    /// Serialize a value stamped with the current version in the given format
+    pub fn save_bytes<T: Serialize>(&self, data: &T, format: SaveFormat) -> Result<Vec<u8>, SaveError> {
+        match format {
+            SaveFormat::Json => self.save(data).map(String::into_bytes),
+            SaveFormat::Binary => {
+                let json = serde_json::to_vec(&self.stamped(data)?).map_err(|e| SaveError::Parse(e.to_string()))?;
+                let mut encoder = DeflateEncoder::new(BINARY_SAVE_MAGIC.to_vec(), Compression::default());
+                encoder.write_all(&json).map_err(|e| SaveError::Parse(e.to_string()))?;
+                encoder.finish().map_err(|e| SaveError::Parse(e.to_string()))
+            }
+        }
+    }
+
+    /// Write `data` as the save named `stem` in `format`, to the file with that
+    /// format's extension. A copy left in the other format is removed, so the
+    /// save is only ever read back from the file written last. Returns the file.
+    pub fn write_file<T: Serialize>(&self, data: &T, stem: &str, format: SaveFormat) -> Result<String, SaveError> {
+        let file = format.file_name(stem);
+        std::fs::write(&file, self.save_bytes(data, format)?).map_err(|e| SaveError::Io(e.to_string()))?;
+        for stale in SaveFormat::ALL.iter().filter(|other| **other != format) {
+            remove_save_file(&stale.file_name(stem))?;
+        }
+        Ok(file)
+    }
+
+    /// Read the save named `stem` in whichever format it was written
+    pub fn read_file<T: DeserializeOwned>(&self, stem: &str) -> Result<T, SaveError> {
+        let file = existing_save_file(stem).ok_or_else(|| SaveError::Io(format!("no save named {}", stem)))?;
+        let bytes = std::fs::read(&file).map_err(|e| SaveError::Io(e.to_string()))?;
+        self.load_bytes(&bytes)
+    }
+
+    fn stamped<T: Serialize>(&self, data: &T) -> Result<Value, SaveError> {
+        let mut value = serde_json::to_value(data).map_err(|e| SaveError::Parse(e.to_string()))?;
+        let object = value
+            .as_object_mut()
+            .ok_or_else(|| SaveError::Parse("expected a JSON object".to_string()))?;
+        object.insert(VERSION_FIELD.to_string(), Value::from(self.current_version));
+        Ok(value)
+    }
+}
+
+/// Delete the save named `stem` in every format
+pub fn remove_save(stem: &str) -> Result<(), SaveError> {
+    SaveFormat::ALL.iter().try_for_each(|format| remove_save_file(&format.file_name(stem)))
+}
+
+fn remove_save_file(file: &str) -> Result<(), SaveError> {
+    match std::fs::remove_file(file) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(SaveError::Io(e.to_string())),
+        _ => Ok(()),
+    }
+}
@@ -9,6 +9,10 @@ pub struct Score {
     pub enemies_killed: u32,
     /// Number of enemies that escaped
     pub enemies_escaped: u32,
+    /// Total damage dealt to enemies
+    pub damage_dealt: f32,
+    /// Total money earned from kills
+    pub money_earned: u32,
 }
 
 impl Score {
@@ -18,6 +22,8 @@ impl Score {
             current: 0,
             enemies_killed: 0,
             enemies_escaped: 0,
+            damage_dealt: 0.0,
+            money_earned: 0,
         }
     }
 
@@ -32,6 +38,16 @@ impl Score {
         self.enemies_escaped += 1;
     }
 
+    /// Record damage dealt to an enemy
+    pub fn record_damage(&mut self, amount: f32) {
+        self.damage_dealt += amount;
+    }
+
+    /// Record money earned from a kill
+    pub fn record_money_earned(&mut self, amount: u32) {
+        self.money_earned += amount;
+    }
+
     /// Get the total number of enemies that have appeared
     pub fn total_enemies(&self) -> u32 {
         self.enemies_killed + self.enemies_escaped
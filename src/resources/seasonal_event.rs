@@ -0,0 +1,195 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Optional file replacing the built-in seasonal event definitions
+pub const SEASONAL_EVENTS_FILE: &str = "seasonal_events.json";
+
+/// Day of the year an event window starts or ends on
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct MonthDay {
+    pub month: u32,
+    pub day: u32,
+}
+
+impl MonthDay {
+    pub const fn new(month: u32, day: u32) -> Self {
+        Self { month, day }
+    }
+
+    /// Date of a time in UTC
+    pub fn of(time: SystemTime) -> Self {
+        let days = time.duration_since(UNIX_EPOCH).map_or(0, |since| since.as_secs() / 86_400);
+        Self::from_days_since_epoch(days as i64)
+    }
+
+    /// Today's date in UTC
+    pub fn today() -> Self {
+        Self::of(SystemTime::now())
+    }
+
+    /// Month and day of a day count since 1970-01-01, in the proleptic Gregorian calendar
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        // Days since 0000-03-01, so leap days fall at the end of each year
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+        Self::new(month as u32, day as u32)
+    }
+}
+
+/// Extra-valuable enemy an event mixes into waves
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GoldenEnemyDefinition {
+    /// Every Nth enemy spawned while the event runs is golden
+    pub every: u32,
+    /// Money paid on top of the usual bounty for killing one
+    pub bonus_bounty: u32,
+    pub color: [f32; 3],
+}
+
+/// Themed content switched on for part of the year
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SeasonalEventDefinition {
+    pub id: String,
+    pub name: String,
+    /// First day of the event
+    pub start: MonthDay,
+    /// Last day of the event. Before `start`, the window runs over New Year.
+    pub end: MonthDay,
+    /// Background color of the map while the event runs
+    #[serde(default)]
+    pub biome_tint: Option<[f32; 3]>,
+    #[serde(default)]
+    pub golden_enemy: Option<GoldenEnemyDefinition>,
+}
+
+impl SeasonalEventDefinition {
+    pub fn is_running_on(&self, date: MonthDay) -> bool {
+        if self.start <= self.end {
+            self.start <= date && date <= self.end
+        } else {
+            date >= self.start || date <= self.end
+        }
+    }
+
+    pub fn tint(&self) -> Option<Color> {
+        self.biome_tint.map(|[red, green, blue]| Color::srgb(red, green, blue))
+    }
+}
+
+/// Which seasonal event runs, chosen in the settings menu
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SeasonalEventOverride {
+    /// Whichever event the date falls in
+    #[default]
+    Auto,
+    /// No event, whatever the date
+    Off,
+    /// The event with this id, whatever the date
+    Force(String),
+}
+
+/// Resource holding every seasonal event definition
+#[derive(Resource, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SeasonalEvents {
+    pub definitions: Vec<SeasonalEventDefinition>,
+}
+
+impl Default for SeasonalEvents {
+    fn default() -> Self {
+        Self {
+            definitions: vec![SeasonalEventDefinition {
+                id: "winter".to_string(),
+                name: "Winter Festival".to_string(),
+                start: MonthDay::new(12, 1),
+                end: MonthDay::new(12, 31),
+                biome_tint: Some([0.82, 0.88, 0.95]),
+                golden_enemy: Some(GoldenEnemyDefinition {
+                    every: 25,
+                    bonus_bounty: 50,
+                    color: [1.0, 0.84, 0.0],
+                }),
+            }],
+        }
+    }
+}
+
+impl SeasonalEvents {
+    pub fn from_json(contents: &str) -> Result<Self, String> {
+        serde_json::from_str(contents).map_err(|e| e.to_string())
+    }
+
+    /// Load the event definitions, keeping the built-in ones if the file is missing or broken
+    pub fn load() -> Self {
+        let Ok(contents) = std::fs::read_to_string(SEASONAL_EVENTS_FILE) else {
+            return Self::default();
+        };
+        Self::from_json(&contents).unwrap_or_else(|error| {
+            warn!("Ignoring seasonal events in {}: {}", SEASONAL_EVENTS_FILE, error);
+            Self::default()
+        })
+    }
+
+    pub fn get(&self, id: &str) -> Option<&SeasonalEventDefinition> {
+        self.definitions.iter().find(|definition| definition.id == id)
+    }
+
+    /// Event that runs on `date` under the settings override
+    pub fn active(&self, date: MonthDay, event_override: &SeasonalEventOverride) -> Option<&SeasonalEventDefinition> {
+        match event_override {
+            SeasonalEventOverride::Auto => self.definitions.iter().find(|definition| definition.is_running_on(date)),
+            SeasonalEventOverride::Off => None,
+            SeasonalEventOverride::Force(id) => self.get(id),
+        }
+    }
+
+    /// The override after `current` in the settings cycle: Auto, Off, then each event
+    pub fn next_override(&self, current: &SeasonalEventOverride) -> SeasonalEventOverride {
+        let forced = |index: usize| {
+            self.definitions
+                .get(index)
+                .map_or(SeasonalEventOverride::Auto, |definition| SeasonalEventOverride::Force(definition.id.clone()))
+        };
+        match current {
+            SeasonalEventOverride::Auto => SeasonalEventOverride::Off,
+            SeasonalEventOverride::Off => forced(0),
+            SeasonalEventOverride::Force(id) => {
+                let index = self.definitions.iter().position(|definition| &definition.id == id);
+                index.map_or(SeasonalEventOverride::Auto, |index| forced(index + 1))
+            }
+        }
+    }
+
+    /// Label of an override for the settings menu
+    pub fn override_label(&self, event_override: &SeasonalEventOverride) -> String {
+        match event_override {
+            SeasonalEventOverride::Auto => "Auto".to_string(),
+            SeasonalEventOverride::Off => "Off".to_string(),
+            SeasonalEventOverride::Force(id) => self.get(id).map_or_else(|| id.clone(), |definition| definition.name.clone()),
+        }
+    }
+}
+
+/// Resource holding the seasonal event in effect, if any
+#[derive(Resource, Clone, Debug, Default, PartialEq)]
+pub struct ActiveSeasonalEvent {
+    pub event: Option<SeasonalEventDefinition>,
+    /// Enemies spawned since the event started, for picking golden ones
+    pub enemies_spawned: u32,
+}
+
+impl ActiveSeasonalEvent {
+    /// Count a spawned enemy, returning the golden enemy definition if it is to be golden
+    pub fn next_spawn_golden(&mut self) -> Option<&GoldenEnemyDefinition> {
+        let golden = self.event.as_ref()?.golden_enemy.as_ref()?;
+        self.enemies_spawned += 1;
+        (golden.every > 0 && self.enemies_spawned.is_multiple_of(golden.every)).then_some(golden)
+    }
+}
@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Game speeds the player can cycle through
+pub const GAME_SPEEDS: [f32; 3] = [1.0, 2.0, 4.0];
+/// Slowest and fastest speeds the clock accepts
+pub const MIN_GAME_SPEED: f32 = 0.25;
+pub const MAX_GAME_SPEED: f32 = 4.0;
+/// Longest real frame the clock advances by, so a hitch doesn't burst timers
+pub const MAX_CLOCK_STEP: Duration = Duration::from_millis(250);
+
+/// Simulation time read by economy, wave and status timers.
+///
+/// Only advances while the game is being played: it stands still in the pause
+/// and settings menus, once the run has ended and while held by the speed
+/// controls' pause, and runs at the game speed otherwise.
+#[derive(Resource, Debug, Clone)]
+pub struct SimulationClock {
+    speed: f32,
+    /// Temporary multiplier on top of the game speed, for slow-motion moments
+    time_scale: f32,
+    /// Stopped from the speed controls, separate from the pause menu
+    held: bool,
+    running: bool,
+    delta: Duration,
+    elapsed: Duration,
+}
+
+impl Default for SimulationClock {
+    fn default() -> Self {
+        Self {
+            speed: 1.0,
+            time_scale: 1.0,
+            held: false,
+            running: true,
+            delta: Duration::ZERO,
+            elapsed: Duration::ZERO,
+        }
+    }
+}
+
+impl SimulationClock {
+    /// Step the clock by a real frame of `real_delta`; a frozen step takes no time
+    pub fn advance(&mut self, real_delta: Duration, running: bool) {
+        self.running = running;
+        self.delta = if running && !self.held {
+            real_delta.min(MAX_CLOCK_STEP).mul_f64(self.effective_speed() as f64)
+        } else {
+            Duration::ZERO
+        };
+        self.elapsed += self.delta;
+    }
+
+    /// Simulation time that passed during the current frame
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    pub fn delta_secs(&self) -> f32 {
+        self.delta.as_secs_f32()
+    }
+
+    /// Simulation time since the game started
+    pub fn elapsed_secs(&self) -> f32 {
+        self.elapsed.as_secs_f32()
+    }
+
+    /// Whether the last step was frozen by a menu, the speed controls or the end of the run
+    pub fn is_frozen(&self) -> bool {
+        !self.running || self.held
+    }
+
+    pub fn is_held(&self) -> bool {
+        self.held
+    }
+
+    /// Stop or restart the game from the speed controls
+    pub fn set_held(&mut self, held: bool) {
+        self.held = held;
+    }
+
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = speed.clamp(MIN_GAME_SPEED, MAX_GAME_SPEED);
+    }
+
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    /// Slow the game (below 1.0) for a moment without touching the player's game speed
+    pub fn set_time_scale(&mut self, time_scale: f32) {
+        self.time_scale = time_scale.clamp(0.0, 1.0);
+    }
+
+    /// Rate the simulation runs at: the game speed with any slow motion applied,
+    /// or nothing while held
+    pub fn effective_speed(&self) -> f32 {
+        if self.held {
+            0.0
+        } else {
+            self.speed * self.time_scale
+        }
+    }
+
+    /// Move on to the next of `GAME_SPEEDS`, wrapping back to normal speed
+    pub fn cycle_speed(&mut self) {
+        let next = GAME_SPEEDS
+            .iter()
+            .copied()
+            .find(|speed| *speed > self.speed)
+            .unwrap_or(GAME_SPEEDS[0]);
+        self.set_speed(next);
+    }
+}
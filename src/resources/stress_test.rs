@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+
+/// File the stress test samples are exported to
+pub const STRESS_TEST_CSV: &str = "stress_test.csv";
+
+/// Size and length of a synthetic load run
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct StressTestConfig {
+    pub enemies: u32,
+    pub towers: u32,
+    /// Projectiles kept in flight between dummy targets
+    pub projectiles: u32,
+    /// Seconds of frames sampled before the run ends
+    pub duration: f32,
+}
+
+impl Default for StressTestConfig {
+    fn default() -> Self {
+        Self {
+            enemies: 200,
+            towers: 40,
+            projectiles: 300,
+            duration: 10.0,
+        }
+    }
+}
+
+/// One frame of a stress test run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StressTestSample {
+    /// Seconds since the run started
+    pub time: f32,
+    pub frame_time_ms: f32,
+    pub enemies: u32,
+    pub towers: u32,
+    pub projectiles: u32,
+}
+
+/// Frame time statistics of a finished run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StressTestSummary {
+    pub frames: usize,
+    pub average_ms: f32,
+    pub p95_ms: f32,
+    pub max_ms: f32,
+}
+
+/// Resource tracking the running stress test and its samples
+#[derive(Resource, Debug, Default)]
+pub struct StressTestRun {
+    /// Config of the current or last run
+    pub config: StressTestConfig,
+    pub active: bool,
+    pub elapsed: f32,
+    pub samples: Vec<StressTestSample>,
+}
+
+impl StressTestRun {
+    /// Start a new run, dropping the samples of the last one
+    pub fn start(&mut self, config: StressTestConfig) {
+        self.config = config;
+        self.active = true;
+        self.elapsed = 0.0;
+        self.samples.clear();
+    }
+
+    /// Record a frame; returns true once the run has lasted its configured duration
+    pub fn record(&mut self, frame_time: f32, enemies: u32, towers: u32, projectiles: u32) -> bool {
+        if !self.active {
+            return false;
+        }
+        self.elapsed += frame_time;
+        self.samples.push(StressTestSample {
+            time: self.elapsed,
+            frame_time_ms: frame_time * 1000.0,
+            enemies,
+            towers,
+            projectiles,
+        });
+        self.elapsed >= self.config.duration
+    }
+
+    pub fn summary(&self) -> StressTestSummary {
+        if self.samples.is_empty() {
+            return StressTestSummary { frames: 0, average_ms: 0.0, p95_ms: 0.0, max_ms: 0.0 };
+        }
+        let mut frame_times: Vec<f32> = self.samples.iter().map(|sample| sample.frame_time_ms).collect();
+        frame_times.sort_by(|a, b| a.total_cmp(b));
+        let p95_index = ((frame_times.len() as f32 * 0.95).ceil() as usize).clamp(1, frame_times.len()) - 1;
+        StressTestSummary {
+            frames: frame_times.len(),
+            average_ms: frame_times.iter().sum::<f32>() / frame_times.len() as f32,
+            p95_ms: frame_times[p95_index],
+            max_ms: frame_times[frame_times.len() - 1],
+        }
+    }
+
+    /// Samples as CSV, one row per frame after a header naming the config
+    pub fn to_csv(&self) -> String {
+        let config = self.config;
+        let mut csv = format!(
+            "# enemies={} towers={} projectiles={} duration={}\ntime_s,frame_time_ms,enemies,towers,projectiles\n",
+            config.enemies, config.towers, config.projectiles, config.duration
+        );
+        for sample in &self.samples {
+            csv.push_str(&format!(
+                "{:.4},{:.3},{},{},{}\n",
+                sample.time, sample.frame_time_ms, sample.enemies, sample.towers, sample.projectiles
+            ));
+        }
+        csv
+    }
+
+    /// Export the samples to `STRESS_TEST_CSV`
+    pub fn write_csv(&self) -> Result<(), String> {
+        std::fs::write(STRESS_TEST_CSV, self.to_csv()).map_err(|e| e.to_string())
+    }
+}
@@ -0,0 +1,391 @@
+use crate::components::{Enemy, EnemyType};
+
+/// Waves with at least this many enemies are tagged as a swarm
+const SWARM_TAG_MIN_ENEMIES: u32 = 10;
+/// Enemy speed from which a wave is tagged as fast
+const FAST_TAG_MIN_SPEED: f32 = 75.0;
+/// Average enemy health from which a wave is tagged as tanky
+const TANKY_TAG_MIN_HEALTH: f32 = 175.0;
+/// Share of smart enemies from which a wave is tagged as smart-heavy
+const SMART_TAG_MIN_SHARE: f32 = 0.2;
+/// First wave paced with the tension curve; earlier waves spawn steadily
+const TENSION_PACING_FROM_WAVE: u32 = 3;
+/// Slowest a pacing profile may make spawns, as a share of the wave's rate
+const MIN_PACE: f32 = 0.1;
+/// Every Nth wave is a boss wave, closed out by a boss enemy
+pub const BOSS_WAVE_INTERVAL: u32 = 5;
+/// Specialist enemy types mixed into standard waves: the first wave each joins,
+/// its share of the wave's stream then, and the most that share grows to
+const SPECIALISTS: [(EnemyType, u32, f32, f32); 4] = [
+    (EnemyType::Fast, 2, 0.15, 0.25),
+    (EnemyType::Tank, 4, 0.10, 0.20),
+    (EnemyType::Shielded, 6, 0.10, 0.20),
+    (EnemyType::Flying, 8, 0.10, 0.15),
+];
+/// Growth of a specialist's share for every wave after it joins
+const SPECIALIST_SHARE_PER_WAVE: f32 = 0.01;
+
+/// Kind of enemy a wave group is made of
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub enum EnemyKind {
+    /// Follows the shared path, spread across its width
+    Swarm,
+    /// Routes around tower coverage on its own
+    Smart,
+}
+
+impl EnemyKind {
+    pub const ALL: [EnemyKind; 2] = [EnemyKind::Swarm, EnemyKind::Smart];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            EnemyKind::Swarm => "Swarm",
+            EnemyKind::Smart => "Smart",
+        }
+    }
+
+    /// Name of enemies of this kind and type: the type for specialists, the kind for basic enemies
+    pub fn name_with(&self, enemy_type: EnemyType) -> &'static str {
+        match enemy_type {
+            EnemyType::Basic => self.get_name(),
+            specialist => specialist.get_name(),
+        }
+    }
+
+    /// Kind of a spawned enemy, told apart by its smart-enemy marker
+    pub fn of_enemy(is_smart: bool) -> Self {
+        if is_smart {
+            EnemyKind::Smart
+        } else {
+            EnemyKind::Swarm
+        }
+    }
+}
+
+/// Where a group's enemies fall in the wave's spawn order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpawnPattern {
+    /// Fills every spawn slot not claimed by an interleaved group
+    Stream,
+    /// Every Nth enemy of the wave belongs to this group. Interleaved groups in
+    /// one wave should not share slots.
+    Interleaved { every: u32 },
+}
+
+/// A batch of identical enemies within a wave
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnemyGroup {
+    pub kind: EnemyKind,
+    pub enemy_type: EnemyType,
+    pub count: u32,
+    /// Health of each enemy
+    pub health: f32,
+    pub speed: f32,
+    pub pattern: SpawnPattern,
+}
+
+impl EnemyGroup {
+    /// Group of `count` basic enemies with the stats of the given wave
+    pub fn for_wave(kind: EnemyKind, wave: u32, count: u32, pattern: SpawnPattern) -> Self {
+        Self::of_type(kind, EnemyType::Basic, wave, count, pattern)
+    }
+
+    /// Group of `count` enemies of a type, with the wave's stats scaled for that type
+    pub fn of_type(kind: EnemyKind, enemy_type: EnemyType, wave: u32, count: u32, pattern: SpawnPattern) -> Self {
+        Self {
+            kind,
+            enemy_type,
+            count,
+            health: Enemy::health_for_wave(wave) * enemy_type.health_multiplier(),
+            speed: Enemy::for_wave(wave).speed * enemy_type.speed_multiplier(),
+            pattern,
+        }
+    }
+}
+
+/// Notable traits of a wave, shown to the player ahead of it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WaveTag {
+    Swarm,
+    Fast,
+    Tanky,
+    SmartHeavy,
+    Boss,
+}
+
+impl WaveTag {
+    pub fn label(&self) -> &'static str {
+        match self {
+            WaveTag::Swarm => "swarm",
+            WaveTag::Fast => "fast",
+            WaveTag::Tanky => "tanky",
+            WaveTag::SmartHeavy => "smart-heavy",
+            WaveTag::Boss => "boss",
+        }
+    }
+}
+
+/// How a wave's spawn rate rises and falls as the wave plays out
+#[derive(Debug, Clone, PartialEq)]
+pub struct PacingProfile {
+    pub name: &'static str,
+    /// (share of the wave's enemies due, spawn rate multiplier) keys, sorted by
+    /// share. The multiplier is interpolated linearly between keys.
+    pub keys: Vec<(f32, f32)>,
+}
+
+impl Default for PacingProfile {
+    fn default() -> Self {
+        Self::steady()
+    }
+}
+
+impl PacingProfile {
+    /// Every enemy at the wave's own spawn rate
+    pub fn steady() -> Self {
+        Self {
+            name: "steady",
+            keys: Vec::new(),
+        }
+    }
+
+    /// Calm start, a spike mid-wave, a breather, then a final rush
+    pub fn tension() -> Self {
+        Self {
+            name: "tension",
+            keys: vec![(0.0, 0.6), (0.3, 0.8), (0.5, 1.6), (0.65, 0.9), (0.85, 1.8), (1.0, 2.0)],
+        }
+    }
+
+    /// Profile the standard waves use: steady while the player settles in
+    pub fn for_wave(wave: u32) -> Self {
+        if wave >= TENSION_PACING_FROM_WAVE {
+            Self::tension()
+        } else {
+            Self::steady()
+        }
+    }
+
+    pub fn is_steady(&self) -> bool {
+        self.keys.iter().all(|(_, multiplier)| *multiplier == 1.0)
+    }
+
+    /// Spawn rate multiplier once `progress` (0..1) of the wave's enemies are due
+    pub fn rate_at(&self, progress: f32) -> f32 {
+        let progress = progress.clamp(0.0, 1.0);
+        let (Some(first), Some(last)) = (self.keys.first(), self.keys.last()) else {
+            return 1.0;
+        };
+
+        let multiplier = if progress <= first.0 {
+            first.1
+        } else if progress >= last.0 {
+            last.1
+        } else {
+            self.keys
+                .windows(2)
+                .find(|pair| progress <= pair[1].0)
+                .map_or(last.1, |pair| {
+                    let (start, end) = (pair[0], pair[1]);
+                    let span = end.0 - start.0;
+                    let t = if span > 0.0 { (progress - start.0) / span } else { 1.0 };
+                    start.1 + (end.1 - start.1) * t
+                })
+        };
+        multiplier.max(MIN_PACE)
+    }
+
+    /// Highest multiplier the profile reaches
+    pub fn peak_rate(&self) -> f32 {
+        if self.keys.is_empty() {
+            return 1.0;
+        }
+        self.keys.iter().map(|(_, multiplier)| multiplier.max(MIN_PACE)).fold(MIN_PACE, f32::max)
+    }
+}
+
+/// What a wave is made of: its enemy groups, their spawn order and the spawn rate
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct WaveComposition {
+    pub wave: u32,
+    pub groups: Vec<EnemyGroup>,
+    /// Enemies released per second
+    pub spawn_rate: f32,
+    /// How the spawn rate varies across the wave
+    pub pacing: PacingProfile,
+    /// Seconds before each enemy falls due, in spawn order, for waves laid out
+    /// by a `WaveDefinition`. Empty for waves timed by the spawn rate and pacing.
+    pub spawn_gaps: Vec<f32>,
+}
+
+impl WaveComposition {
+    /// Wave of `count` swarm enemies
+    pub fn swarm(wave: u32, count: u32) -> Self {
+        Self::standard(wave, count, None)
+    }
+
+    /// Swarm wave in which every `smart_every`-th enemy is smart instead
+    pub fn standard(wave: u32, count: u32, smart_every: Option<u32>) -> Self {
+        let smart_count = smart_every.filter(|every| *every > 0).map_or(0, |every| count / every);
+        let mut groups = vec![EnemyGroup::for_wave(EnemyKind::Swarm, wave, count - smart_count, SpawnPattern::Stream)];
+        if let Some(every) = smart_every.filter(|_| smart_count > 0) {
+            groups.push(EnemyGroup::for_wave(EnemyKind::Smart, wave, smart_count, SpawnPattern::Interleaved { every }));
+        }
+
+        Self {
+            wave,
+            groups,
+            spawn_rate: spawn_rate_for_wave(wave),
+            pacing: PacingProfile::for_wave(wave),
+            spawn_gaps: Vec::new(),
+        }
+    }
+
+    /// Standard wave with specialist enemy types mixed into the swarm stream.
+    /// Types join as waves go on and their share grows; every `BOSS_WAVE_INTERVAL`th
+    /// wave ends with a boss.
+    pub fn mixed(wave: u32, count: u32, smart_every: Option<u32>) -> Self {
+        let mut composition = Self::standard(wave, count, smart_every);
+        let stream = composition.groups[0].count;
+        let mut basic = stream;
+        let mut specialists = Vec::new();
+        for (enemy_type, from_wave, share, max_share) in SPECIALISTS {
+            if wave < from_wave {
+                continue;
+            }
+            let share = (share + (wave - from_wave) as f32 * SPECIALIST_SHARE_PER_WAVE).min(max_share);
+            let type_count = ((stream as f32 * share) as u32).min(basic);
+            basic -= type_count;
+            specialists.push((enemy_type, type_count));
+        }
+        if wave.is_multiple_of(BOSS_WAVE_INTERVAL) && basic > 0 {
+            basic -= 1;
+            specialists.push((EnemyType::Boss, 1));
+        }
+
+        composition.groups[0].count = basic;
+        let typed_groups = specialists
+            .into_iter()
+            .filter(|(_, type_count)| *type_count > 0)
+            .map(|(enemy_type, type_count)| EnemyGroup::of_type(EnemyKind::Swarm, enemy_type, wave, type_count, SpawnPattern::Stream));
+        composition.groups.extend(typed_groups);
+        composition
+    }
+
+    /// Whether every enemy follows the last at the wave's own spawn rate
+    pub fn is_steady(&self) -> bool {
+        self.pacing.is_steady() && self.spawn_gaps.is_empty()
+    }
+
+    pub fn total_enemies(&self) -> u32 {
+        self.groups.iter().map(|group| group.count).sum()
+    }
+
+    /// Money the wave pays out if every enemy is killed by towers paying `per_kill`,
+    /// scaled by each enemy type's reward
+    pub fn bounty(&self, per_kill: u32) -> u32 {
+        self.groups
+            .iter()
+            .map(|group| group.count * group.enemy_type.reward_multiplier() * per_kill)
+            .sum()
+    }
+
+    /// Health of every enemy in the wave added up
+    pub fn estimated_total_health(&self) -> f32 {
+        self.groups.iter().map(|group| group.health * group.count as f32).sum()
+    }
+
+    /// Number of enemies of one kind
+    pub fn count_of(&self, kind: EnemyKind) -> u32 {
+        self.groups.iter().filter(|group| group.kind == kind).map(|group| group.count).sum()
+    }
+
+    /// Number of enemies of one type
+    pub fn count_of_type(&self, enemy_type: EnemyType) -> u32 {
+        self.groups.iter().filter(|group| group.enemy_type == enemy_type).map(|group| group.count).sum()
+    }
+
+    /// Group the enemy with this spawn index belongs to. Interleaved groups claim
+    /// their slots first; stream groups share out the rest in order.
+    pub fn group_at(&self, spawn_index: u32) -> Option<&EnemyGroup> {
+        if spawn_index >= self.total_enemies() {
+            return None;
+        }
+
+        let mut claimed_before = 0;
+        for group in &self.groups {
+            if let SpawnPattern::Interleaved { every } = group.pattern {
+                let slot = spawn_index + 1;
+                if every > 0 && slot.is_multiple_of(every) && slot / every <= group.count {
+                    return Some(group);
+                }
+                claimed_before += interleaved_slots_before(spawn_index, every, group.count);
+            }
+        }
+
+        let mut stream_index = spawn_index - claimed_before;
+        for group in self.groups.iter().filter(|group| group.pattern == SpawnPattern::Stream) {
+            if stream_index < group.count {
+                return Some(group);
+            }
+            stream_index -= group.count;
+        }
+        None
+    }
+
+    /// Tags describing the wave, in a stable order
+    pub fn tags(&self) -> Vec<WaveTag> {
+        let total = self.total_enemies();
+        if total == 0 {
+            return Vec::new();
+        }
+
+        let mut tags = Vec::new();
+        if total >= SWARM_TAG_MIN_ENEMIES {
+            tags.push(WaveTag::Swarm);
+        }
+        if self.groups.iter().any(|group| group.count > 0 && group.speed >= FAST_TAG_MIN_SPEED) {
+            tags.push(WaveTag::Fast);
+        }
+        if self.estimated_total_health() / total as f32 >= TANKY_TAG_MIN_HEALTH {
+            tags.push(WaveTag::Tanky);
+        }
+        if self.count_of(EnemyKind::Smart) as f32 / total as f32 >= SMART_TAG_MIN_SHARE {
+            tags.push(WaveTag::SmartHeavy);
+        }
+        if self.count_of_type(EnemyType::Boss) > 0 {
+            tags.push(WaveTag::Boss);
+        }
+        tags
+    }
+
+    /// One-line summary for previews, e.g. "12 enemies, 1200 HP [swarm, fast]"
+    pub fn summary(&self) -> String {
+        let tags: Vec<&str> = self.tags().iter().map(WaveTag::label).collect();
+        let mut summary = format!("{} enemies, {:.0} HP", self.total_enemies(), self.estimated_total_health());
+        if !tags.is_empty() {
+            summary.push_str(&format!(" [{}]", tags.join(", ")));
+        }
+        summary
+    }
+}
+
+/// Spawn slots of an interleaved group that come before `spawn_index`
+fn interleaved_slots_before(spawn_index: u32, every: u32, count: u32) -> u32 {
+    if every == 0 {
+        return 0;
+    }
+    (spawn_index / every).min(count)
+}
+
+/// Enemies released per second in a wave. Higher waves spawn faster for
+/// increased pressure: 1.0 + (wave - 1) * 0.2, capped at 3.0
+/// (wave 1: one per second, wave 3: one per 0.71s, wave 10: one per 0.36s).
+pub fn spawn_rate_for_wave(wave: u32) -> f32 {
+    let wave = wave.max(1) as f32;
+    let base_rate = 1.0;
+    let scaling_factor = 0.2;
+    let max_rate = 3.0;
+
+    (base_rate + (wave - 1.0) * scaling_factor).min(max_rate)
+}
@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::components::EnemyType;
+use crate::resources::{EnemyGroup, EnemyKind, PacingProfile, SpawnPattern, WaveComposition};
+
+/// Directory holding one JSON `WaveDefinition` per file
+pub const WAVE_DEFINITIONS_DIR: &str = "assets/waves";
+/// Shortest gap a definition may put between two enemies of a group
+pub const MIN_DEFINED_SPAWN_INTERVAL: f32 = 0.05;
+
+fn swarm_kind() -> EnemyKind {
+    EnemyKind::Swarm
+}
+
+/// A run of identical enemies within a defined wave
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WaveGroupDefinition {
+    #[serde(default)]
+    pub enemy_type: EnemyType,
+    #[serde(default = "swarm_kind")]
+    pub kind: EnemyKind,
+    pub count: u32,
+    /// Seconds between two enemies of the group
+    pub spawn_interval: f32,
+    /// Extra seconds before the group's first enemy, after the group before it
+    #[serde(default)]
+    pub delay: f32,
+}
+
+/// Hand-authored layout of one wave: its groups in spawn order and their timing.
+/// Enemy health and speed still follow the wave number and enemy type.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WaveDefinition {
+    pub wave: u32,
+    pub groups: Vec<WaveGroupDefinition>,
+}
+
+impl WaveDefinition {
+    pub fn from_json(contents: &str) -> Result<Self, String> {
+        let definition: Self = serde_json::from_str(contents).map_err(|e| e.to_string())?;
+        definition.validate()?;
+        Ok(definition)
+    }
+
+    /// Check the definition describes a wave that can be played
+    pub fn validate(&self) -> Result<(), String> {
+        if self.wave == 0 {
+            return Err("waves are numbered from 1".to_string());
+        }
+        if self.total_enemies() == 0 {
+            return Err(format!("wave {} has no enemies", self.wave));
+        }
+        for (index, group) in self.groups.iter().enumerate() {
+            if !group.spawn_interval.is_finite() || group.spawn_interval < MIN_DEFINED_SPAWN_INTERVAL {
+                return Err(format!(
+                    "wave {} group {}: spawn_interval must be at least {}s",
+                    self.wave, index, MIN_DEFINED_SPAWN_INTERVAL
+                ));
+            }
+            if !group.delay.is_finite() || group.delay < 0.0 {
+                return Err(format!("wave {} group {}: delay must not be negative", self.wave, index));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn total_enemies(&self) -> u32 {
+        self.groups.iter().map(|group| group.count).sum()
+    }
+
+    /// The wave as the wave manager plays it, with each enemy's gap laid out
+    pub fn composition(&self) -> WaveComposition {
+        let groups: Vec<&WaveGroupDefinition> = self.groups.iter().filter(|group| group.count > 0).collect();
+        let spawn_gaps = groups
+            .iter()
+            .flat_map(|group| {
+                (0..group.count).map(|index| if index == 0 { group.delay + group.spawn_interval } else { group.spawn_interval })
+            })
+            .collect();
+        WaveComposition {
+            wave: self.wave,
+            groups: groups
+                .iter()
+                .map(|group| EnemyGroup::of_type(group.kind, group.enemy_type, self.wave, group.count, SpawnPattern::Stream))
+                .collect(),
+            spawn_rate: groups.first().map_or(1.0, |group| 1.0 / group.spawn_interval),
+            pacing: PacingProfile::steady(),
+            spawn_gaps,
+        }
+    }
+}
+
+/// Resource holding the wave definitions loaded from data files, by wave number.
+/// Waves without a definition are composed procedurally.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct WaveConfig {
+    definitions: BTreeMap<u32, WaveDefinition>,
+}
+
+impl WaveConfig {
+    /// Config from definitions, refusing two for the same wave
+    pub fn from_definitions(definitions: impl IntoIterator<Item = WaveDefinition>) -> Result<Self, String> {
+        let mut config = Self::default();
+        for definition in definitions {
+            config.insert(definition)?;
+        }
+        Ok(config)
+    }
+
+    fn insert(&mut self, definition: WaveDefinition) -> Result<(), String> {
+        definition.validate()?;
+        if self.definitions.contains_key(&definition.wave) {
+            return Err(format!("wave {} is defined twice", definition.wave));
+        }
+        self.definitions.insert(definition.wave, definition);
+        Ok(())
+    }
+
+    /// Load every `.json` file in `dir` in name order, skipping broken ones with a
+    /// warning. A missing directory leaves every wave procedural.
+    pub fn load_from(dir: &Path) -> Self {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return Self::default();
+        };
+        let mut paths: Vec<_> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|extension| extension == "json"))
+            .collect();
+        paths.sort();
+
+        let mut config = Self::default();
+        for path in paths {
+            let loaded = std::fs::read_to_string(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|contents| WaveDefinition::from_json(&contents))
+                .and_then(|definition| config.insert(definition));
+            if let Err(error) = loaded {
+                warn!("Ignoring wave definition {}: {}", path.display(), error);
+            }
+        }
+        config
+    }
+
+    pub fn load() -> Self {
+        Self::load_from(Path::new(WAVE_DEFINITIONS_DIR))
+    }
+
+    pub fn get(&self, wave: u32) -> Option<&WaveDefinition> {
+        self.definitions.get(&wave)
+    }
+
+    /// Numbers of the defined waves, in order
+    pub fn waves(&self) -> impl Iterator<Item = u32> + '_ {
+        self.definitions.keys().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.definitions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.definitions.is_empty()
+    }
+}
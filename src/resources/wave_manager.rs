@@ -1,17 +1,49 @@
 use bevy::prelude::*;
+use crate::components::EnemyType;
+use crate::resources::{EnemyKind, WaveComposition};
 
 /// Defines the path that enemies follow from spawn to goal
-#[derive(Debug, Clone, Resource)]
+#[derive(Debug, Clone, PartialEq, Resource)]
 pub struct EnemyPath {
     /// Waypoints that define the path enemies follow
     pub waypoints: Vec<Vec2>,
+    /// Waypoints of further routes from other entry points to the same exit,
+    /// on maps enemies enter from several sides. Empty for a single route.
+    pub extra_routes: Vec<Vec<Vec2>>,
 }
 
 impl EnemyPath {
     /// Create a new enemy path with the given waypoints
     pub fn new(waypoints: Vec<Vec2>) -> Self {
         assert!(!waypoints.is_empty(), "Enemy path must have at least one waypoint");
-        Self { waypoints }
+        Self {
+            waypoints,
+            extra_routes: Vec::new(),
+        }
+    }
+
+    /// Add routes from further entry points, skipping any without waypoints
+    pub fn with_extra_routes(mut self, routes: Vec<Vec<Vec2>>) -> Self {
+        self.extra_routes = routes.into_iter().filter(|route| !route.is_empty()).collect();
+        self
+    }
+
+    /// Number of routes enemies can take, the main one included
+    pub fn route_count(&self) -> usize {
+        1 + self.extra_routes.len()
+    }
+
+    /// Waypoints of every route, the main one first
+    pub fn routes(&self) -> impl Iterator<Item = &[Vec2]> {
+        std::iter::once(self.waypoints.as_slice()).chain(self.extra_routes.iter().map(Vec::as_slice))
+    }
+
+    /// Path along a single route, 0 being the main one. `None` past the last route.
+    pub fn route(&self, index: usize) -> Option<EnemyPath> {
+        self.routes().nth(index).map(|waypoints| EnemyPath {
+            waypoints: waypoints.to_vec(),
+            extra_routes: Vec::new(),
+        })
     }
 
     /// Get the position along the path at the given progress (0.0 to 1.0)
@@ -43,6 +75,30 @@ impl EnemyPath {
         start.lerp(end, local_progress)
     }
 
+    /// Progress at the point of the path nearest `point`, on the scale
+    /// `get_position_at_progress` uses. The first of equally near points wins.
+    pub fn progress_nearest(&self, point: Vec2) -> f32 {
+        let total_segments = self.waypoints.len().saturating_sub(1);
+        if total_segments == 0 {
+            return 0.0;
+        }
+        let (segment_index, local_progress, _) = self
+            .waypoints
+            .windows(2)
+            .enumerate()
+            .map(|(index, segment)| {
+                let along = segment[1] - segment[0];
+                let local = if along.length_squared() > 0.0 {
+                    ((point - segment[0]).dot(along) / along.length_squared()).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                (index, local, point.distance_squared(segment[0] + along * local))
+            })
+            .fold((0, 0.0, f32::INFINITY), |nearest, candidate| if candidate.2 < nearest.2 { candidate } else { nearest });
+        (segment_index as f32 + local_progress) / total_segments as f32
+    }
+
     /// Get the total length of the path (sum of distances between waypoints)
     pub fn total_length(&self) -> f32 {
         let mut total = 0.0;
@@ -91,6 +147,16 @@ impl EnemyPath {
         self.catmull_rom_interpolation(p0, p1, p2, p3, local_progress)
     }
 
+    /// Unit direction of travel along the smooth path at the given progress.
+    /// Zero for a path with a single waypoint.
+    pub fn get_smooth_direction_at_progress(&self, progress: f32) -> Vec2 {
+        // Central difference, shifted inwards at the ends of the path
+        const STEP: f32 = 0.001;
+        let before = (progress - STEP).clamp(0.0, 1.0 - 2.0 * STEP);
+        let after = before + 2.0 * STEP;
+        (self.get_smooth_position_at_progress(after) - self.get_smooth_position_at_progress(before)).normalize_or_zero()
+    }
+
     /// Performs Catmull-Rom spline interpolation between four control points
     /// p1 and p2 are the actual waypoints, p0 and p3 are control points
     /// t is the interpolation parameter (0.0 to 1.0)
@@ -133,72 +199,308 @@ impl EnemyPath {
     }
 }
 
-/// Simple wave manager for Phase 1 - manual wave spawning
-#[derive(Debug, Resource)]
-pub struct WaveManager {
+/// Which of the level's routes the shared `EnemyPath` holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PathVariant {
+    #[default]
+    Normal,
+    /// Longer route boss waves take
+    Arena,
+}
+
+/// The level's normal route alongside the arena route worked out for it.
+/// `EnemyPath` always holds a copy of the `active` one.
+#[derive(Debug, Clone, Default, Resource)]
+pub struct PathVariants {
+    pub normal: Option<EnemyPath>,
+    /// `None` when the map leaves no room for a longer route
+    pub arena: Option<EnemyPath>,
+    pub active: PathVariant,
+}
+
+impl PathVariants {
+    pub fn get(&self, variant: PathVariant) -> Option<&EnemyPath> {
+        match variant {
+            PathVariant::Normal => self.normal.as_ref(),
+            PathVariant::Arena => self.arena.as_ref(),
+        }
+    }
+
+    /// The route `EnemyPath` currently holds
+    pub fn active_path(&self) -> Option<&EnemyPath> {
+        self.get(self.active)
+    }
+}
+
+/// Default ceiling on simultaneously alive enemies before spawning is held back
+pub const DEFAULT_MAX_LIVE_ENEMIES: u32 = 150;
+/// Default number of queued spawns released per frame
+pub const DEFAULT_MAX_SPAWNS_PER_FRAME: u32 = 2;
+/// Shortest gap between two spawns a wave's spawn gaps are held to
+pub const MIN_SPAWN_GAP: f32 = 0.01;
+
+/// One enemy's slot in the current wave's spawn schedule
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScheduledSpawn {
+    /// Spawn order within the wave
+    pub index: u32,
+    /// Seconds after the wave starts that the enemy becomes due
+    pub time: f32,
+    pub kind: EnemyKind,
+    pub enemy_type: EnemyType,
+    /// Pacing multiplier of the gap leading up to this enemy
+    pub pace: f32,
+}
+
+/// Resource describing what the current wave contains. Only replaced when a
+/// wave starts; nothing that ticks during the wave writes to it.
+#[derive(Debug, Clone, Default, PartialEq, Resource)]
+pub struct WavePlan {
     /// Current wave number
     pub current_wave: u32,
-    /// Number of enemies in current wave
-    pub enemies_in_wave: u32,
+    /// Enemy groups, spawn order and spawn rate of the current wave
+    pub composition: WaveComposition,
+}
+
+impl WavePlan {
+    /// Number of the wave that starts next
+    pub fn next_wave(&self) -> u32 {
+        self.current_wave + 1
+    }
+
+    /// Number of enemies in the current wave
+    pub fn enemies_in_wave(&self) -> u32 {
+        self.composition.total_enemies()
+    }
+
+    /// Number of enemies of a kind from spawn `from` to the end of the wave
+    pub fn count_of_kind_from(&self, from: u32, kind: EnemyKind) -> u32 {
+        (from..self.enemies_in_wave())
+            .filter(|index| self.composition.group_at(*index).is_some_and(|group| group.kind == kind))
+            .count() as u32
+    }
+
+    /// Number of enemies of a type from spawn `from` to the end of the wave
+    pub fn count_of_type_from(&self, from: u32, enemy_type: EnemyType) -> u32 {
+        (from..self.enemies_in_wave())
+            .filter(|index| self.composition.group_at(*index).is_some_and(|group| group.enemy_type == enemy_type))
+            .count() as u32
+    }
+
+    /// Make `composition` the next wave's plan
+    fn advance(&mut self, composition: WaveComposition) {
+        self.current_wave += 1;
+        self.composition = WaveComposition {
+            wave: self.current_wave,
+            ..composition
+        };
+    }
+}
+
+/// Resource tracking where spawning of the current wave stands: timers,
+/// counters and the spawn caps. Anything that depends on the wave's contents
+/// takes the `WavePlan` it is spawning.
+#[derive(Debug, Clone, Resource)]
+pub struct WaveRuntime {
     /// Number of enemies spawned so far in current wave
     pub enemies_spawned: u32,
     /// Timer for spawning enemies
     pub spawn_timer: Timer,
+    /// Spawns that are due but have not been released yet (bounded by enemies left in the wave)
+    pub pending_spawns: u32,
+    /// Spawning is held back while this many enemies are alive
+    pub max_live_enemies: u32,
+    /// Maximum number of queued spawns released in a single frame
+    pub max_spawns_per_frame: u32,
+    /// Holds the spawn schedule where it is while the rest of the game runs on
+    pub spawning_paused: bool,
 }
 
-impl WaveManager {
-    /// Create a new wave manager
+impl WaveRuntime {
     pub fn new() -> Self {
         Self {
-            current_wave: 0,
-            enemies_in_wave: 0,
             enemies_spawned: 0,
             spawn_timer: Timer::from_seconds(1.0, TimerMode::Repeating),
+            pending_spawns: 0,
+            max_live_enemies: DEFAULT_MAX_LIVE_ENEMIES,
+            max_spawns_per_frame: DEFAULT_MAX_SPAWNS_PER_FRAME,
+            spawning_paused: false,
         }
     }
 
-    /// Start a new wave with the given number of enemies
-    pub fn start_wave(&mut self, enemy_count: u32) {
-        self.current_wave += 1;
-        self.enemies_in_wave = enemy_count;
+    /// Clear the counters for a fresh wave; the caps and the pause stay as they are
+    fn restart(&mut self, spawn_rate: f32) {
         self.enemies_spawned = 0;
-        
-        // Scale spawn rate based on wave number for increased intensity
-        let spawn_rate = self.calculate_spawn_rate_for_wave();
+        self.pending_spawns = 0;
         self.set_spawn_rate(spawn_rate);
     }
-    
-    /// Calculate appropriate spawn rate for current wave
-    /// Higher waves spawn enemies faster for increased pressure
-    fn calculate_spawn_rate_for_wave(&self) -> f32 {
-        let wave = self.current_wave.max(1) as f32;
-        
-        // Progressive spawn rate scaling:
-        // Wave 1: 1.0 (1 enemy per second)
-        // Wave 2: 1.2 (1 enemy per 0.83 seconds)  
-        // Wave 3: 1.4 (1 enemy per 0.71 seconds)
-        // Wave 10: 2.8 (1 enemy per 0.36 seconds)
-        // Formula: 1.0 + (wave - 1) * 0.2, capped at 3.0
-        let base_rate = 1.0;
-        let scaling_factor = 0.2;
-        let max_rate = 3.0;
-        
-        (base_rate + (wave - 1.0) * scaling_factor).min(max_rate)
+
+    /// Spawns that have fallen due so far, released or queued
+    pub fn spawns_due(&self) -> u32 {
+        self.enemies_spawned + self.pending_spawns
+    }
+
+    /// Record that an enemy was spawned
+    pub fn enemy_spawned(&mut self) {
+        self.enemies_spawned += 1;
+        self.pending_spawns = self.pending_spawns.saturating_sub(1);
     }
 
     /// Check if all enemies in the current wave have been spawned
-    pub fn wave_complete(&self) -> bool {
-        self.enemies_spawned >= self.enemies_in_wave
+    pub fn wave_complete(&self, plan: &WavePlan) -> bool {
+        self.enemies_spawned >= plan.enemies_in_wave()
     }
 
     /// Check if it's time to spawn the next enemy
-    pub fn should_spawn_enemy(&self) -> bool {
-        !self.wave_complete() && self.spawn_timer.finished()
+    pub fn should_spawn_enemy(&self, plan: &WavePlan) -> bool {
+        !self.wave_complete(plan) && self.spawn_timer.finished()
     }
 
-    /// Record that an enemy was spawned
-    pub fn enemy_spawned(&mut self) {
-        self.enemies_spawned += 1;
+    /// Number of enemies in the current wave that have not been spawned yet
+    pub fn enemies_remaining_to_spawn(&self, plan: &WavePlan) -> u32 {
+        plan.enemies_in_wave().saturating_sub(self.enemies_spawned)
+    }
+
+    /// Number of enemies of a kind in the current wave that have not been spawned yet
+    pub fn remaining_to_spawn_of(&self, plan: &WavePlan, kind: EnemyKind) -> u32 {
+        plan.count_of_kind_from(self.enemies_spawned, kind)
+    }
+
+    /// Number of enemies of a type in the current wave that have not been spawned yet
+    pub fn remaining_to_spawn_of_type(&self, plan: &WavePlan, enemy_type: EnemyType) -> u32 {
+        plan.count_of_type_from(self.enemies_spawned, enemy_type)
+    }
+
+    /// Advance the spawn timer and queue every interval that elapsed during this tick.
+    /// Extreme spawn rates finish the timer several times per frame; those spawns are
+    /// queued instead of being dropped or released all at once. Does nothing while
+    /// spawning is paused.
+    pub fn tick_spawn_timer(&mut self, plan: &WavePlan, delta: std::time::Duration) {
+        if !self.spawning_paused {
+            self.advance_spawn_schedule(plan, delta);
+        }
+    }
+
+    /// Move the spawn schedule forward, queueing every spawn that falls due.
+    /// The wave's pacing profile speeds the timer up or slows it down spawn by
+    /// spawn. Works while spawning is paused, for jumping ahead on the admin timeline.
+    pub fn advance_spawn_schedule(&mut self, plan: &WavePlan, delta: std::time::Duration) {
+        if plan.composition.is_steady() {
+            self.queue_due_spawns(plan, delta);
+            return;
+        }
+
+        let mut delta = delta;
+        while self.spawns_due() < plan.enemies_in_wave() {
+            let pace = self.current_pace(plan);
+            let until_next = self.spawn_timer.remaining().div_f32(pace);
+            if delta < until_next {
+                self.queue_due_spawns(plan, delta.mul_f32(pace));
+                return;
+            }
+            let remaining = self.spawn_timer.remaining();
+            self.queue_due_spawns(plan, remaining);
+            delta = delta.saturating_sub(until_next);
+        }
+        // Everything is due; keep the timer running as before
+        self.queue_due_spawns(plan, delta);
+    }
+
+    /// Tick the spawn timer by `delta` of unpaced schedule time and queue what finished
+    fn queue_due_spawns(&mut self, plan: &WavePlan, delta: std::time::Duration) {
+        let remaining = self.enemies_remaining_to_spawn(plan);
+        self.spawn_timer.tick(delta);
+
+        let due = self.spawn_timer.times_finished_this_tick();
+        self.pending_spawns = self.pending_spawns.saturating_add(due).min(remaining);
+    }
+
+    /// Pacing multiplier for the gap before the enemy at `index`. Waves with
+    /// explicit spawn gaps are paced to match them.
+    pub fn pace_at_spawn(&self, plan: &WavePlan, index: u32) -> f32 {
+        if let Some(gap) = plan.composition.spawn_gaps.get(index as usize) {
+            return self.spawn_interval() / gap.max(MIN_SPAWN_GAP);
+        }
+        let total = plan.enemies_in_wave().max(1);
+        plan.composition.pacing.rate_at(index as f32 / total as f32)
+    }
+
+    /// Pacing multiplier for the gap the schedule is currently in
+    pub fn current_pace(&self, plan: &WavePlan) -> f32 {
+        self.pace_at_spawn(plan, self.spawns_due())
+    }
+
+    /// Seconds from the wave start until `count` enemies have fallen due
+    fn time_until_due(&self, plan: &WavePlan, count: u32) -> f32 {
+        let interval = self.spawn_interval();
+        if plan.composition.is_steady() {
+            return count as f32 * interval;
+        }
+        (0..count).map(|index| interval / self.pace_at_spawn(plan, index)).sum()
+    }
+
+    /// How many queued enemies may be spawned this frame given the current live count
+    pub fn spawn_budget(&self, plan: &WavePlan, live_enemies: u32) -> u32 {
+        let cap_headroom = self.max_live_enemies.saturating_sub(live_enemies);
+
+        self.pending_spawns
+            .min(self.enemies_remaining_to_spawn(plan))
+            .min(self.max_spawns_per_frame)
+            .min(cap_headroom)
+    }
+
+    /// Whether queued spawns are currently held back by the live-enemy cap
+    pub fn is_spawn_capped(&self, live_enemies: u32) -> bool {
+        self.pending_spawns > 0 && live_enemies >= self.max_live_enemies
+    }
+
+    /// Seconds between two spawns of the current wave
+    pub fn spawn_interval(&self) -> f32 {
+        self.spawn_timer.duration().as_secs_f32()
+    }
+
+    /// When each enemy of the current wave falls due, in spawn order
+    pub fn spawn_schedule(&self, plan: &WavePlan) -> Vec<ScheduledSpawn> {
+        (0..plan.enemies_in_wave())
+            .filter_map(|index| {
+                let group = plan.composition.group_at(index)?;
+                Some(ScheduledSpawn {
+                    index,
+                    time: self.time_until_due(plan, index + 1),
+                    kind: group.kind,
+                    enemy_type: group.enemy_type,
+                    pace: self.pace_at_spawn(plan, index),
+                })
+            })
+            .collect()
+    }
+
+    /// Seconds from the wave start until its last enemy falls due
+    pub fn schedule_length(&self, plan: &WavePlan) -> f32 {
+        self.time_until_due(plan, plan.enemies_in_wave())
+    }
+
+    /// Seconds of the spawn schedule played so far, counting queued spawns as due
+    pub fn schedule_elapsed(&self, plan: &WavePlan) -> f32 {
+        let due = self.spawns_due();
+        if due >= plan.enemies_in_wave() {
+            return self.schedule_length(plan);
+        }
+        self.time_until_due(plan, due) + self.spawn_timer.elapsed_secs() / self.current_pace(plan)
+    }
+
+    /// Jump the spawn schedule forward to the moment the next enemy falls due
+    pub fn jump_to_next_spawn(&mut self, plan: &WavePlan) {
+        let remaining = self.spawn_timer.remaining();
+        self.queue_due_spawns(plan, remaining);
+    }
+
+    /// Jump the spawn schedule forward until the enemy at `index` is due; it never runs backwards
+    pub fn jump_to_spawn(&mut self, plan: &WavePlan, index: u32) {
+        let last = plan.enemies_in_wave();
+        while index < last && self.spawns_due() <= index {
+            self.jump_to_next_spawn(plan);
+        }
     }
 
     /// Update the spawn rate (higher values = faster spawning)
@@ -209,12 +511,25 @@ impl WaveManager {
     }
 }
 
-impl Default for WaveManager {
+impl Default for WaveRuntime {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Start the next wave with the given composition: the plan moves on and the
+/// runtime's counters start over
+pub fn start_composed_wave(plan: &mut WavePlan, runtime: &mut WaveRuntime, composition: WaveComposition) {
+    runtime.restart(composition.spawn_rate);
+    plan.advance(composition);
+}
+
+/// Start a new wave of the given number of swarm enemies
+pub fn start_wave(plan: &mut WavePlan, runtime: &mut WaveRuntime, enemy_count: u32) {
+    let composition = WaveComposition::swarm(plan.next_wave(), enemy_count);
+    start_composed_wave(plan, runtime, composition);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -319,4 +634,67 @@ mod tests {
         assert_eq!(linear_end, waypoints[waypoints.len() - 1]);
         assert_eq!(smooth_end, waypoints[waypoints.len() - 1]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_extreme_spawn_rate_queues_instead_of_dropping() {
+        let mut plan = WavePlan::default();
+        let mut runtime = WaveRuntime::new();
+        start_wave(&mut plan, &mut runtime, 50);
+        runtime.set_spawn_rate(100.0);
+
+        // One second at 100/s finishes the timer far more often than the wave has enemies
+        runtime.tick_spawn_timer(&plan, std::time::Duration::from_secs(1));
+        assert_eq!(runtime.pending_spawns, 50, "Due spawns should be queued up to the wave size");
+
+        // Only a few are released per frame
+        assert_eq!(runtime.spawn_budget(&plan, 0), DEFAULT_MAX_SPAWNS_PER_FRAME);
+    }
+
+    #[test]
+    fn test_spawn_queue_bounded_by_wave_size() {
+        let mut plan = WavePlan::default();
+        let mut runtime = WaveRuntime::new();
+        start_wave(&mut plan, &mut runtime, 3);
+        runtime.set_spawn_rate(100.0);
+
+        runtime.tick_spawn_timer(&plan, std::time::Duration::from_secs(2));
+        assert_eq!(runtime.pending_spawns, 3);
+
+        for _ in 0..3 {
+            runtime.enemy_spawned();
+        }
+        assert_eq!(runtime.pending_spawns, 0);
+        assert_eq!(runtime.spawn_budget(&plan, 0), 0);
+    }
+
+    #[test]
+    fn test_live_enemy_cap_holds_back_spawns() {
+        let mut plan = WavePlan::default();
+        let mut runtime = WaveRuntime::new();
+        start_wave(&mut plan, &mut runtime, 10);
+        runtime.max_live_enemies = 5;
+        runtime.tick_spawn_timer(&plan, std::time::Duration::from_secs(3));
+
+        assert_eq!(runtime.spawn_budget(&plan, 4), 1, "Only headroom below the cap may spawn");
+        assert_eq!(runtime.spawn_budget(&plan, 5), 0);
+        assert!(runtime.is_spawn_capped(5));
+        assert!(!runtime.is_spawn_capped(4));
+    }
+
+    #[test]
+    fn test_starting_a_wave_replaces_the_plan_and_resets_the_runtime() {
+        let mut plan = WavePlan::default();
+        let mut runtime = WaveRuntime::new();
+        runtime.max_live_enemies = 5;
+        start_wave(&mut plan, &mut runtime, 4);
+        runtime.tick_spawn_timer(&plan, std::time::Duration::from_secs(2));
+        runtime.enemy_spawned();
+
+        start_wave(&mut plan, &mut runtime, 6);
+        assert_eq!(plan.current_wave, 2);
+        assert_eq!(plan.composition.wave, 2);
+        assert_eq!(plan.next_wave(), 3);
+        assert_eq!(runtime.spawns_due(), 0, "Counters start over with the new plan");
+        assert_eq!(runtime.max_live_enemies, 5, "Spawn caps outlive the wave");
+    }
+}
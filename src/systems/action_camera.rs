@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+use bevy::input::mouse::AccumulatedMouseScroll;
+use bevy::prelude::*;
+use crate::components::Enemy;
+use crate::resources::{AppState, CombatSet, GameState, GameSystemSet};
+use crate::systems::combat_system::EnemyKilledEvent;
+use crate::systems::first_breach_system::BreachMoment;
+use crate::systems::input::{is_playing, InputHandler, InputRegistryAppExt};
+use crate::systems::results_screen::{capture_run_results_system, EndCinematic};
+
+/// Key switching the action cam on and off
+pub const ACTION_CAMERA_KEY: KeyCode = KeyCode::KeyC;
+
+/// Side length of the square regions kills are binned into
+pub const ACTIVITY_REGION_SIZE: f32 = 160.0;
+/// Seconds over which kill activity is averaged
+pub const ACTIVITY_WINDOW_SECONDS: f32 = 3.0;
+/// Kills per second a region needs before the camera moves in on it
+pub const MIN_FOCUS_KILLS_PER_SECOND: f32 = 0.3;
+/// Kills per second at which the camera is fully zoomed in
+pub const FULL_ZOOM_KILLS_PER_SECOND: f32 = 3.0;
+/// Orthographic scale at full zoom, relative to the home scale (smaller = closer)
+pub const ACTION_CAMERA_MAX_ZOOM: f32 = 0.65;
+/// How quickly the camera eases towards its target (higher = snappier)
+const CAMERA_FOLLOW_RATE: f32 = 2.5;
+
+/// Keys that count as manual camera input and hand control back to the player
+const MANUAL_CAMERA_KEYS: [KeyCode; 4] = [
+    KeyCode::ArrowUp,
+    KeyCode::ArrowDown,
+    KeyCode::ArrowLeft,
+    KeyCode::ArrowRight,
+];
+
+/// Recent kills binned into map regions, decaying over `ACTIVITY_WINDOW_SECONDS`
+#[derive(Resource, Debug, Default)]
+pub struct CombatActivity {
+    regions: HashMap<IVec2, f32>,
+}
+
+impl CombatActivity {
+    pub fn region_of(position: Vec2) -> IVec2 {
+        (position / ACTIVITY_REGION_SIZE).floor().as_ivec2()
+    }
+
+    pub fn region_center(region: IVec2) -> Vec2 {
+        (region.as_vec2() + Vec2::splat(0.5)) * ACTIVITY_REGION_SIZE
+    }
+
+    pub fn record_kill(&mut self, position: Vec2) {
+        *self.regions.entry(Self::region_of(position)).or_default() += 1.0;
+    }
+
+    /// Fade out old kills so the activity tracks the recent kill rate
+    pub fn decay(&mut self, delta_secs: f32) {
+        let factor = (-delta_secs / ACTIVITY_WINDOW_SECONDS).exp();
+        self.regions.retain(|_, activity| {
+            *activity *= factor;
+            *activity > 0.01
+        });
+    }
+
+    pub fn kills_per_second(&self, region: IVec2) -> f32 {
+        self.regions.get(&region).copied().unwrap_or(0.0) / ACTIVITY_WINDOW_SECONDS
+    }
+
+    /// Busiest region and its kill rate
+    pub fn hottest(&self) -> Option<(IVec2, f32)> {
+        self.regions
+            .iter()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .map(|(region, activity)| (*region, activity / ACTIVITY_WINDOW_SECONDS))
+    }
+
+    pub fn clear(&mut self) {
+        self.regions.clear();
+    }
+}
+
+/// Event sent when the player asks to switch the action cam on or off
+#[derive(Event)]
+pub struct ToggleActionCameraEvent;
+
+/// Input handler switching the action cam on and off
+pub struct ActionCameraHandler;
+
+impl InputHandler for ActionCameraHandler {
+    fn handle_input(&self, world: &mut World, key: KeyCode) -> bool {
+        if key != ACTION_CAMERA_KEY || !is_playing(world) {
+            return false;
+        }
+        world.send_event(ToggleActionCameraEvent);
+        true
+    }
+
+    fn get_description(&self) -> &str {
+        "Action camera (arrow keys or mouse wheel take back control)"
+    }
+
+    fn get_priority(&self) -> u8 {
+        20
+    }
+
+    fn get_id(&self) -> &str {
+        "action_camera"
+    }
+
+    fn handles_key(&self, key: KeyCode) -> bool {
+        key == ACTION_CAMERA_KEY
+    }
+
+    fn get_handled_keys(&self) -> Vec<KeyCode> {
+        vec![ACTION_CAMERA_KEY]
+    }
+}
+
+/// Optional camera mode that follows the fighting during waves
+#[derive(Resource, Debug)]
+pub struct ActionCamera {
+    pub enabled: bool,
+    /// Camera position to return to when the action cam lets go
+    pub home_position: Vec2,
+    /// Orthographic scale to return to when the action cam lets go
+    pub home_scale: f32,
+}
+
+impl Default for ActionCamera {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            home_position: Vec2::ZERO,
+            home_scale: 1.0,
+        }
+    }
+}
+
+/// Where the action cam wants to be: over the busiest region, zoomed in by its kill rate.
+/// Falls back to the home view while nothing is happening.
+pub fn action_camera_target(activity: &CombatActivity, home_position: Vec2, home_scale: f32) -> (Vec2, f32) {
+    match activity.hottest() {
+        Some((region, kills_per_second)) if kills_per_second >= MIN_FOCUS_KILLS_PER_SECOND => {
+            let intensity = (kills_per_second / FULL_ZOOM_KILLS_PER_SECOND).clamp(0.0, 1.0);
+            let scale = home_scale * (1.0 + (ACTION_CAMERA_MAX_ZOOM - 1.0) * intensity);
+            (CombatActivity::region_center(region), scale)
+        }
+        _ => (home_position, home_scale),
+    }
+}
+
+/// Switch the action cam off and put the camera straight back on the home view
+fn release_camera(
+    action_camera: &mut ActionCamera,
+    camera_query: &mut Query<(&mut Transform, &mut Projection), With<Camera2d>>,
+) {
+    action_camera.enabled = false;
+    if let Ok((mut transform, mut projection)) = camera_query.single_mut() {
+        transform.translation.x = action_camera.home_position.x;
+        transform.translation.y = action_camera.home_position.y;
+        if let Projection::Orthographic(orthographic) = projection.as_mut() {
+            orthographic.scale = action_camera.home_scale;
+        }
+    }
+}
+
+/// System to record where enemies are being killed
+pub fn record_combat_activity_system(
+    time: Res<Time>,
+    mut kill_events: EventReader<EnemyKilledEvent>,
+    mut activity: ResMut<CombatActivity>,
+) {
+    activity.decay(time.delta_secs());
+    for kill in kill_events.read() {
+        activity.record_kill(kill.position);
+    }
+}
+
+/// System to toggle the action cam when asked to. Any manual camera input
+/// (arrow keys, mouse wheel, middle mouse) or the end of the run switches it off.
+pub fn action_camera_toggle_system(
+    mut toggle_events: EventReader<ToggleActionCameraEvent>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_button_input: Res<ButtonInput<MouseButton>>,
+    mouse_scroll: Res<AccumulatedMouseScroll>,
+    game_state: Res<GameState>,
+    mut action_camera: ResMut<ActionCamera>,
+    mut activity: ResMut<CombatActivity>,
+    mut camera_query: Query<(&mut Transform, &mut Projection), With<Camera2d>>,
+) {
+    let toggle_requested = toggle_events.read().count() > 0;
+    if action_camera.enabled {
+        let manual_input = keyboard_input.any_pressed(MANUAL_CAMERA_KEYS)
+            || mouse_button_input.pressed(MouseButton::Middle)
+            || mouse_scroll.delta != Vec2::ZERO;
+        let run_over = *game_state != GameState::Playing;
+
+        if toggle_requested || manual_input || run_over {
+            release_camera(&mut action_camera, &mut camera_query);
+            println!("Action cam OFF");
+        }
+        return;
+    }
+
+    if toggle_requested && *game_state == GameState::Playing {
+        // Remember the current view so switching off returns to it
+        if let Ok((transform, projection)) = camera_query.single() {
+            action_camera.home_position = transform.translation.truncate();
+            action_camera.home_scale = match projection {
+                Projection::Orthographic(orthographic) => orthographic.scale,
+                _ => 1.0,
+            };
+        }
+        action_camera.enabled = true;
+        activity.clear();
+        println!("Action cam ON");
+    }
+}
+
+/// System to ease the camera towards the busiest fighting while a wave is running
+pub fn action_camera_system(
+    time: Res<Time>,
+    action_camera: Res<ActionCamera>,
+    activity: Res<CombatActivity>,
+    cinematic: Option<Res<EndCinematic>>,
+    breach: Option<Res<BreachMoment>>,
+    enemies: Query<(), With<Enemy>>,
+    mut camera_query: Query<(&mut Transform, &mut Projection), With<Camera2d>>,
+) {
+    // The first breach moment has the camera on the base until it ends
+    if !action_camera.enabled || cinematic.is_some() || breach.is_some() {
+        return;
+    }
+
+    // Drift back to the home view between waves
+    let (target_position, target_scale) = if enemies.is_empty() {
+        (action_camera.home_position, action_camera.home_scale)
+    } else {
+        action_camera_target(&activity, action_camera.home_position, action_camera.home_scale)
+    };
+
+    let blend = 1.0 - (-CAMERA_FOLLOW_RATE * time.delta_secs()).exp();
+    if let Ok((mut transform, mut projection)) = camera_query.single_mut() {
+        let position = transform.translation.truncate().lerp(target_position, blend);
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+
+        if let Projection::Orthographic(orthographic) = projection.as_mut() {
+            orthographic.scale += (target_scale - orthographic.scale) * blend;
+        }
+    }
+}
+
+/// Plugin to add the optional action cam that follows combat during waves
+pub struct ActionCameraPlugin;
+
+impl Plugin for ActionCameraPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<ActionCamera>()
+            .init_resource::<CombatActivity>()
+            .add_event::<ToggleActionCameraEvent>()
+            .register_input_handler(ActionCameraHandler)
+            .add_systems(
+                Update,
+                action_camera_toggle_system
+                    .in_set(GameSystemSet::UI)
+                    .before(capture_run_results_system) // Results cinematic starts from the home view
+                    .run_if(in_state(AppState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (record_combat_activity_system, action_camera_system)
+                    .chain()
+                    .in_set(GameSystemSet::Gameplay)
+                    .after(CombatSet::Collision)
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
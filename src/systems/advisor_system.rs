@@ -0,0 +1,583 @@
+use bevy::prelude::*;
+use crate::components::{Constructing, Enemy, PlacementGrace};
+use crate::resources::{resolve_tower_cost, AppState, CombatSet, Economy, EnemyPath, GameConstants, GameSystemSet, MarketState, NumberFormatter, ResourceCost, RunIntegrity, TowerStats, TowerType, WavePlan, WaveRuntime};
+use crate::systems::combat_system::Target;
+use crate::systems::construction_system::sell_refund;
+use crate::systems::input_system::{spawn_tower, MouseInputState};
+use crate::systems::placement_validator::{PlacementContext, PlacementValidator};
+use crate::systems::obstacle_rendering::ObstacleGrid;
+use crate::systems::occupancy::OccupancyMap;
+use crate::systems::path_generation::{calculate_exposure_tower_zones, GridPos, TowerZone, ZONE_TOWER_RANGE};
+use crate::systems::settings_menu::GameSettings;
+use crate::systems::tower_ui::{tower_selection_system, try_upgrade_tower, TowerSelectionState};
+use crate::systems::ui_feedback::UiFeedback;
+use crate::systems::unified_grid::{grid_to_world, world_to_grid, UnifiedGridSystem};
+
+/// Most suggestions shown at once
+pub const MAX_ADVISOR_SUGGESTIONS: usize = 3;
+/// Waves in a row without firing before a tower counts as idle
+pub const IDLE_WAVES_BEFORE_SELL: u32 = 2;
+
+// ============================================================================
+// TOWER ACTIVITY
+// ============================================================================
+
+/// Per-tower shot tally the advisor uses to spot idle towers
+#[derive(Component, Debug, Clone, Default)]
+pub struct TowerActivity {
+    /// Shots fired during the current wave
+    pub shots_this_wave: u32,
+    /// Completed waves in a row in which the tower never fired
+    pub idle_waves: u32,
+    /// `Target::last_shot_time` when the last shot was counted
+    pub last_counted_shot: f32,
+}
+
+impl TowerActivity {
+    /// Fold the current wave's shots into the idle streak and start a new tally
+    pub fn end_wave(&mut self) {
+        if self.shots_this_wave == 0 {
+            self.idle_waves += 1;
+        } else {
+            self.idle_waves = 0;
+        }
+        self.shots_this_wave = 0;
+    }
+}
+
+// ============================================================================
+// SUGGESTIONS
+// ============================================================================
+
+/// One actionable piece of advice, applied with a single click
+#[derive(Debug, Clone, PartialEq)]
+pub enum AdvisorSuggestion {
+    /// Sell a tower that hasn't fired for a while
+    SellIdle {
+        tower: Entity,
+        tower_type: TowerType,
+        idle_waves: u32,
+        refund: ResourceCost,
+    },
+    /// The affordable upgrade with the most extra DPS per dollar
+    Upgrade {
+        tower: Entity,
+        tower_type: TowerType,
+        next_level: u32,
+        dps_gain: f32,
+        cost: ResourceCost,
+    },
+    /// Build in the most valuable placement zone that is still empty
+    BuildInZone {
+        position: Vec2,
+        tower_type: TowerType,
+        strategic_value: f32,
+    },
+}
+
+impl AdvisorSuggestion {
+    pub fn label(&self, formatter: &NumberFormatter) -> String {
+        match self {
+            AdvisorSuggestion::SellIdle { tower_type, idle_waves, refund, .. } => format!(
+                "Sell idle {} (no shots for {} waves): +{}",
+                tower_type.get_name(),
+                idle_waves,
+                formatter.money(refund.money)
+            ),
+            AdvisorSuggestion::Upgrade { tower_type, next_level, dps_gain, cost, .. } => format!(
+                "Upgrade {} to Lv {}: +{:.1} DPS for {}",
+                tower_type.get_name(),
+                next_level,
+                dps_gain,
+                formatter.money(cost.money)
+            ),
+            AdvisorSuggestion::BuildInZone { tower_type, strategic_value, .. } => format!(
+                "Build {} in strategic zone (value {:.2}): {}",
+                tower_type.get_name(),
+                strategic_value,
+                formatter.money(tower_type.get_cost().money)
+            ),
+        }
+    }
+}
+
+/// A finished tower as the advisor sees it
+#[derive(Debug, Clone)]
+pub struct AdvisedTower {
+    pub entity: Entity,
+    pub stats: TowerStats,
+    pub idle_waves: u32,
+}
+
+/// An empty, valid build spot inside a placement zone
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ZoneSpot {
+    pub position: Vec2,
+    pub strategic_value: f32,
+}
+
+fn dps(stats: &TowerStats) -> f32 {
+    stats.damage * stats.fire_rate
+}
+
+/// Work out the advice for the current towers and money: at most one sale, one
+/// upgrade and one build, in that order
+pub fn advisor_suggestions(
+    towers: &[AdvisedTower],
+    build_spot: Option<ZoneSpot>,
+    economy: &Economy,
+) -> Vec<AdvisorSuggestion> {
+    let mut suggestions = Vec::new();
+
+    // Longest-idle tower first; ties go to the bigger refund
+    let idle_tower = towers
+        .iter()
+        .filter(|tower| tower.idle_waves >= IDLE_WAVES_BEFORE_SELL)
+        .max_by_key(|tower| (tower.idle_waves, tower.stats.sell_value().money));
+    if let Some(tower) = idle_tower {
+        suggestions.push(AdvisorSuggestion::SellIdle {
+            tower: tower.entity,
+            tower_type: tower.stats.tower_type,
+            idle_waves: tower.idle_waves,
+            refund: tower.stats.sell_value(),
+        });
+    }
+
+    let best_upgrade = towers
+        .iter()
+        .filter(|tower| tower.idle_waves < IDLE_WAVES_BEFORE_SELL && tower.stats.can_upgrade())
+        .filter_map(|tower| {
+            let cost = tower.stats.get_upgrade_cost();
+            if !economy.can_afford(&cost) {
+                return None;
+            }
+            let mut upgraded = tower.stats.clone();
+            upgraded.upgrade();
+            let dps_gain = dps(&upgraded) - dps(&tower.stats);
+            let value = dps_gain / cost.money.max(1) as f32;
+            Some((value, tower, upgraded.upgrade_level, dps_gain, cost))
+        })
+        .max_by(|a, b| a.0.total_cmp(&b.0));
+    if let Some((_, tower, next_level, dps_gain, cost)) = best_upgrade {
+        suggestions.push(AdvisorSuggestion::Upgrade {
+            tower: tower.entity,
+            tower_type: tower.stats.tower_type,
+            next_level,
+            dps_gain,
+            cost,
+        });
+    }
+
+    // Most DPS per dollar among the towers the player can afford
+    let build_type = TowerType::ALL
+        .into_iter()
+        .filter(|tower_type| economy.can_afford(&tower_type.get_cost()))
+        .max_by(|a, b| {
+            let value = |tower_type: &TowerType| dps(&TowerStats::new(*tower_type)) / tower_type.get_cost().money.max(1) as f32;
+            value(a).total_cmp(&value(b))
+        });
+    if let (Some(spot), Some(tower_type)) = (build_spot, build_type) {
+        suggestions.push(AdvisorSuggestion::BuildInZone {
+            position: spot.position,
+            tower_type,
+            strategic_value: spot.strategic_value,
+        });
+    }
+
+    suggestions.truncate(MAX_ADVISOR_SUGGESTIONS);
+    suggestions
+}
+
+/// Grid cells each route of `enemy_path` passes through, the main route first
+pub fn route_cells(enemy_path: &EnemyPath, unified_grid: &UnifiedGridSystem) -> Vec<Vec<GridPos>> {
+    enemy_path.routes().map(|route| path_cells(route, unified_grid)).collect()
+}
+
+/// Grid cells the enemy route passes through, in order
+pub fn path_cells(waypoints: &[Vec2], unified_grid: &UnifiedGridSystem) -> Vec<GridPos> {
+    let mut cells: Vec<GridPos> = Vec::new();
+    let step = unified_grid.cell_size / 2.0;
+    for segment in waypoints.windows(2) {
+        let samples = (segment[0].distance(segment[1]) / step).ceil().max(1.0) as usize;
+        for sample in 0..=samples {
+            let point = segment[0].lerp(segment[1], sample as f32 / samples as f32);
+            if let Some(cell) = world_to_grid(point, unified_grid) {
+                if cells.last() != Some(&cell) {
+                    cells.push(cell);
+                }
+            }
+        }
+    }
+    cells
+}
+
+// ============================================================================
+// STATE
+// ============================================================================
+
+/// Current advice, refreshed every frame between waves
+#[derive(Resource, Debug, Default)]
+pub struct Advisor {
+    pub suggestions: Vec<AdvisorSuggestion>,
+    /// Last wave whose tower activity has been folded into the idle streaks
+    pub tallied_wave: u32,
+    /// Placement zones for the current map, longest enemy exposure first. Rebuilt when the map or path changes.
+    pub zones: Option<Vec<TowerZone>>,
+}
+
+/// Request to carry out a suggestion
+#[derive(Event, Debug, Clone, PartialEq)]
+pub struct ApplySuggestionEvent(pub AdvisorSuggestion);
+
+// ============================================================================
+// COMPONENTS
+// ============================================================================
+
+#[derive(Component)]
+pub struct AdvisorPanel;
+
+/// One suggestion row in the advisor panel
+#[derive(Component)]
+pub struct AdvisorRow(pub usize);
+
+#[derive(Component)]
+pub struct AdvisorSuggestionText(pub usize);
+
+/// Button applying the suggestion in the same row
+#[derive(Component)]
+pub struct AdvisorApplyButton(pub usize);
+
+// ============================================================================
+// ADVISOR SYSTEMS
+// ============================================================================
+
+/// System to count the shots each tower fires this wave
+pub fn track_tower_activity_system(
+    mut towers: Query<(&Target, &mut TowerActivity)>,
+) {
+    for (target, mut activity) in towers.iter_mut() {
+        if target.last_shot_time > activity.last_counted_shot {
+            activity.last_counted_shot = target.last_shot_time;
+            activity.shots_this_wave += 1;
+        }
+    }
+}
+
+/// System to tally idle towers when a wave ends and rebuild the advice between waves
+pub fn advisor_system(
+    settings: Option<Res<GameSettings>>,
+    wave_plan: Res<WavePlan>,
+    wave_runtime: Res<WaveRuntime>,
+    economy: Res<Economy>,
+    enemy_path: Res<EnemyPath>,
+    unified_grid: Res<UnifiedGridSystem>,
+    obstacle_grid: Res<ObstacleGrid>,
+    constants: Res<GameConstants>,
+    mut advisor: ResMut<Advisor>,
+    enemies: Query<(), With<Enemy>>,
+    mut towers: Query<(Entity, &TowerStats, &mut TowerActivity, Has<Constructing>)>,
+    occupancy: Res<OccupancyMap>,
+) {
+    let between_waves = (wave_plan.current_wave == 0 || wave_runtime.wave_complete(&wave_plan)) && enemies.is_empty();
+    if !between_waves {
+        if !advisor.suggestions.is_empty() {
+            advisor.suggestions.clear();
+        }
+        return;
+    }
+
+    // A restarted run or restored checkpoint goes back to an earlier wave
+    if wave_plan.current_wave < advisor.tallied_wave {
+        advisor.tallied_wave = wave_plan.current_wave;
+    }
+    if wave_plan.current_wave > advisor.tallied_wave {
+        for (_, _, mut activity, _) in towers.iter_mut() {
+            activity.end_wave();
+        }
+        advisor.tallied_wave = wave_plan.current_wave;
+    }
+
+    if !settings.is_none_or(|settings| settings.advisor_enabled) {
+        if !advisor.suggestions.is_empty() {
+            advisor.suggestions.clear();
+        }
+        return;
+    }
+
+    if enemy_path.is_changed() || obstacle_grid.is_changed() {
+        advisor.zones = None;
+    }
+    let zones = advisor.zones.get_or_insert_with(|| {
+        let routes = route_cells(&enemy_path, &unified_grid);
+        calculate_exposure_tower_zones(&obstacle_grid.grid, &routes, ZONE_TOWER_RANGE, Enemy::default().speed)
+    });
+
+    // First free, buildable cell of the most valuable zone (zones come sorted best first)
+    let validator = PlacementValidator::new(
+        &unified_grid,
+        Some(&obstacle_grid.grid),
+        &enemy_path.waypoints,
+        &occupancy,
+        constants.tower_footprint,
+    )
+    .with_extra_routes(&enemy_path.extra_routes);
+    let build_spot = zones.iter().find_map(|zone| {
+        let (start, end) = zone.grid_bounds;
+        let mut cells = (start.y.min(end.y)..=start.y.max(end.y))
+            .flat_map(|y| (start.x.min(end.x)..=start.x.max(end.x)).map(move |x| GridPos::new(x, y)));
+        cells
+            .find(|cell| validator.is_cell_buildable(*cell).is_buildable())
+            .map(|cell| ZoneSpot {
+                position: grid_to_world(cell, &unified_grid),
+                strategic_value: zone.strategic_value,
+            })
+    });
+
+    let advised: Vec<AdvisedTower> = towers
+        .iter()
+        .filter(|(_, _, _, constructing)| !constructing)
+        .map(|(entity, stats, activity, _)| AdvisedTower {
+            entity,
+            stats: stats.clone(),
+            idle_waves: activity.idle_waves,
+        })
+        .collect();
+
+    let suggestions = advisor_suggestions(&advised, build_spot, &economy);
+    if advisor.suggestions != suggestions {
+        advisor.suggestions = suggestions;
+    }
+}
+
+/// System to turn apply-button clicks into suggestion requests
+pub fn advisor_button_system(
+    advisor: Res<Advisor>,
+    mut mouse_input_state: ResMut<MouseInputState>,
+    mut apply_events: EventWriter<ApplySuggestionEvent>,
+    interaction_query: Query<(&Interaction, &AdvisorApplyButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        // Consume the mouse click to prevent tower placement
+        mouse_input_state.left_clicked = false;
+
+        if let Some(suggestion) = advisor.suggestions.get(button.0) {
+            apply_events.write(ApplySuggestionEvent(suggestion.clone()));
+        }
+    }
+}
+
+/// System to carry out applied suggestions, re-checking that each still makes sense
+pub fn apply_suggestion_system(
+    mut commands: Commands,
+    mut apply_events: EventReader<ApplySuggestionEvent>,
+    mut economy: ResMut<Economy>,
+    market: Option<Res<MarketState>>,
+    mut integrity: Option<ResMut<RunIntegrity>>,
+    mut selection_state: ResMut<TowerSelectionState>,
+    mut placement: PlacementContext,
+    mut towers: Query<(&mut TowerStats, Option<&PlacementGrace>), Without<Constructing>>,
+    mut feedback: UiFeedback,
+) {
+    for ApplySuggestionEvent(suggestion) in apply_events.read() {
+        let applied = match suggestion {
+            AdvisorSuggestion::SellIdle { tower, .. } => match towers.get(*tower) {
+                Ok((stats, grace)) => {
+                    let refund = sell_refund(stats, None, grace);
+                    economy.refund(&refund);
+                    commands.entity(*tower).despawn();
+                    placement.vacate(*tower);
+                    if selection_state.selected_tower_entity == Some(*tower) {
+                        selection_state.clear_selection();
+                    }
+                    println!("Advisor: sold idle {}, refunded {:?}", stats.tower_type.get_name(), refund);
+                    true
+                }
+                Err(_) => false,
+            },
+            AdvisorSuggestion::Upgrade { tower, .. } => match towers.get_mut(*tower) {
+                Ok((mut stats, _)) => match try_upgrade_tower(&mut stats, false, &mut economy) {
+                    Ok(()) => {
+                        println!("Advisor: upgraded {} to level {}", stats.tower_type.get_name(), stats.upgrade_level);
+                        true
+                    }
+                    Err(_) => false,
+                },
+                Err(_) => false,
+            },
+            AdvisorSuggestion::BuildInZone { position, tower_type, .. } => {
+                let cost = resolve_tower_cost(market.as_deref(), *tower_type);
+                let verdict = placement
+                    .validator()
+                    .with_funds(&economy, &cost)
+                    .check_position(*position);
+                // Refused suggestions don't count against the rate limit
+                if verdict.is_buildable() && integrity.as_mut().is_none_or(|integrity| integrity.try_record_placement()) {
+                    economy.spend(&cost);
+                    spawn_tower(&mut commands, *position, *tower_type, cost);
+                    println!("Advisor: building {:?} at {:?}", tower_type, position);
+                    true
+                } else {
+                    false
+                }
+            }
+        };
+
+        if applied {
+            feedback.confirm();
+        } else {
+            println!("Advisor: suggestion no longer applies");
+            feedback.error();
+        }
+    }
+}
+
+/// System to show the advisor panel between waves and keep its rows current
+pub fn advisor_panel_system(
+    advisor: Res<Advisor>,
+    formatter: Res<NumberFormatter>,
+    mut panel_query: Query<&mut Node, (With<AdvisorPanel>, Without<AdvisorRow>)>,
+    mut row_query: Query<(&mut Node, &AdvisorRow), Without<AdvisorPanel>>,
+    mut text_query: Query<(&mut Text, &AdvisorSuggestionText)>,
+) {
+    if !advisor.is_changed() && !formatter.is_changed() {
+        return;
+    }
+
+    if let Ok(mut panel_node) = panel_query.single_mut() {
+        panel_node.display = if advisor.suggestions.is_empty() { Display::None } else { Display::Flex };
+    }
+    for (mut row_node, row) in row_query.iter_mut() {
+        row_node.display = if row.0 < advisor.suggestions.len() { Display::Flex } else { Display::None };
+    }
+    for (mut text, row) in text_query.iter_mut() {
+        if let Some(label) = advisor.suggestions.get(row.0).map(|suggestion| suggestion.label(&formatter)) {
+            if **text != label {
+                **text = label;
+            }
+        }
+    }
+}
+
+// ============================================================================
+// SETUP
+// ============================================================================
+
+/// Spawn the (hidden) advisor panel with one row per possible suggestion
+pub fn setup_advisor_panel(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(20.0),
+                top: Val::Px(120.0),
+                width: Val::Px(300.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(10.0)),
+                row_gap: Val::Px(8.0),
+                display: Display::None,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.15, 0.2, 0.15, 0.9)),
+            AdvisorPanel,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Advisor"),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            for index in 0..MAX_ADVISOR_SUGGESTIONS {
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Row,
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(8.0),
+                            ..default()
+                        },
+                        AdvisorRow(index),
+                    ))
+                    .with_children(|row| {
+                        row.spawn((
+                            Text::new(""),
+                            TextFont {
+                                font_size: 12.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                            Node {
+                                flex_grow: 1.0,
+                                ..default()
+                            },
+                            AdvisorSuggestionText(index),
+                        ));
+                        row.spawn((
+                            Button,
+                            Node {
+                                width: Val::Px(64.0),
+                                height: Val::Px(28.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(0.4, 0.7, 0.4)),
+                            AdvisorApplyButton(index),
+                        ))
+                        .with_children(|button| {
+                            button.spawn((
+                                Text::new("APPLY"),
+                                TextFont {
+                                    font_size: 12.0,
+                                    ..default()
+                                },
+                                TextColor(Color::WHITE),
+                            ));
+                        });
+                    });
+            }
+        });
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin to add the between-wave advisor and its one-click suggestions
+pub struct AdvisorPlugin;
+
+impl Plugin for AdvisorPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<Advisor>()
+            .add_event::<ApplySuggestionEvent>()
+            .add_systems(Startup, setup_advisor_panel)
+            .add_systems(
+                Update,
+                (
+                    advisor_button_system.before(tower_selection_system),
+                    advisor_panel_system,
+                )
+                    .in_set(GameSystemSet::UI)
+                    .run_if(in_state(AppState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (
+                    track_tower_activity_system.after(CombatSet::Firing),
+                    apply_suggestion_system,
+                    advisor_system.after(apply_suggestion_system),
+                )
+                    .in_set(GameSystemSet::Gameplay)
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
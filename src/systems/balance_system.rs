@@ -0,0 +1,114 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::resources::*;
+
+/// Tuning last applied to a tower, so it can be swapped for new values
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct TowerBalanceApplied {
+    /// Upgrades and specializing rebuild stats from the built-in tables, dropping earlier tuning
+    pub upgrade_level: u32,
+    pub branch: Option<UpgradeBranch>,
+    pub factors: TowerFactors,
+}
+
+/// Tuning last applied to an enemy, along with the wave it was scaled for
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct EnemyBalanceApplied {
+    pub wave: u32,
+    pub health: f32,
+    pub speed: f32,
+}
+
+/// System to scale tower stats to the balance config: new and upgraded towers
+/// pick it up, and edits apply to every tower straight away
+pub fn apply_tower_balance_system(
+    mut commands: Commands,
+    balance: Res<BalanceConfig>,
+    mut towers: Query<(Entity, &mut TowerStats, Option<&mut TowerBalanceApplied>)>,
+) {
+    for (entity, mut stats, applied) in towers.iter_mut() {
+        let current = applied
+            .as_ref()
+            .filter(|applied| applied.upgrade_level == stats.upgrade_level && applied.branch == stats.branch)
+            .map(|applied| applied.factors);
+        if current.is_some() && !balance.is_changed() {
+            continue;
+        }
+
+        let current = current.unwrap_or(TowerFactors::IDENTITY);
+        let target = balance.tower_factors(stats.tower_type);
+        if current != target {
+            stats.damage *= target.damage / current.damage;
+            stats.range *= target.range / current.range;
+            stats.fire_rate *= target.fire_rate / current.fire_rate;
+        }
+
+        let record = TowerBalanceApplied {
+            upgrade_level: stats.upgrade_level,
+            branch: stats.branch,
+            factors: target,
+        };
+        match applied {
+            Some(mut applied) => *applied = record,
+            None => {
+                commands.entity(entity).insert(record);
+            }
+        }
+    }
+}
+
+/// System to scale enemy health and speed to the balance config, keeping each
+/// enemy's share of health when the config is edited mid-wave
+pub fn apply_enemy_balance_system(
+    mut commands: Commands,
+    balance: Res<BalanceConfig>,
+    wave_plan: Res<WavePlan>,
+    mut enemies: Query<(Entity, &mut Enemy, &mut Health, Option<&mut EnemyBalanceApplied>)>,
+) {
+    for (entity, mut enemy, mut health, applied) in enemies.iter_mut() {
+        if applied.is_some() && !balance.is_changed() {
+            continue;
+        }
+
+        let (wave, current_health, current_speed) = applied
+            .as_ref()
+            .map_or((wave_plan.current_wave, 1.0, 1.0), |applied| (applied.wave, applied.health, applied.speed));
+        let (target_health, target_speed) = balance.enemies.factors_for_wave(wave);
+        if (target_health, target_speed) != (current_health, current_speed) {
+            let health_scale = target_health / current_health;
+            health.max *= health_scale;
+            health.current *= health_scale;
+            enemy.speed *= target_speed / current_speed;
+        }
+
+        let record = EnemyBalanceApplied {
+            wave,
+            health: target_health,
+            speed: target_speed,
+        };
+        match applied {
+            Some(mut applied) => *applied = record,
+            None => {
+                commands.entity(entity).insert(record);
+            }
+        }
+    }
+}
+
+/// Plugin applying the tuned tower and enemy stats from the balance config
+pub struct BalancePlugin;
+
+impl Plugin for BalancePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<BalanceConfig>()
+            .add_systems(
+                Update,
+                (apply_tower_balance_system, apply_enemy_balance_system)
+                    .in_set(GameSystemSet::Gameplay)
+                    .before(EnemySet::Movement)
+                    .before(CombatSet::Targeting)
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
@@ -0,0 +1,213 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::resources::*;
+use crate::systems::tween::{ColorTween, Easing, TweenProgress};
+
+/// Size of the base sprite
+const BASE_SIZE: f32 = 30.0;
+/// Distance of the health ring segments from the base centre
+const RING_RADIUS: f32 = 26.0;
+/// Size of a single health ring segment
+const RING_SEGMENT_SIZE: f32 = 6.0;
+/// Seconds the hit flash takes to fade back to the base colour
+const HIT_FLASH_DURATION: f32 = 0.3;
+
+const BASE_COLOR: Color = Color::srgb(0.3, 0.45, 0.85);
+const HIT_FLASH_COLOR: Color = Color::srgb(1.0, 0.3, 0.3);
+const RING_HEALTHY_COLOR: Color = Color::srgb(0.3, 0.9, 0.4);
+const RING_DAMAGED_COLOR: Color = Color::srgb(0.95, 0.8, 0.2);
+const RING_CRITICAL_COLOR: Color = Color::srgb(0.95, 0.25, 0.2);
+const RING_EMPTY_COLOR: Color = Color::srgba(0.2, 0.2, 0.2, 0.5);
+
+/// Text showing the lives the player has left
+#[derive(Component)]
+pub struct LivesText;
+
+/// Spawn the base with a full health ring at the given position
+pub fn spawn_base(commands: &mut Commands, position: Vec2) -> Entity {
+    commands
+        .spawn((
+            Sprite {
+                color: BASE_COLOR,
+                custom_size: Some(Vec2::splat(BASE_SIZE)),
+                ..default()
+            },
+            Transform::from_translation(position.extend(1.5)),
+            Base,
+            Health::new(BASE_MAX_HEALTH),
+        ))
+        .with_children(|base| {
+            for index in 0..BASE_RING_SEGMENTS {
+                let angle = index as f32 / BASE_RING_SEGMENTS as f32 * std::f32::consts::TAU;
+                base.spawn((
+                    Sprite {
+                        color: RING_HEALTHY_COLOR,
+                        custom_size: Some(Vec2::splat(RING_SEGMENT_SIZE)),
+                        ..default()
+                    },
+                    Transform::from_translation((Vec2::from_angle(angle) * RING_RADIUS).extend(0.1))
+                        .with_rotation(Quat::from_rotation_z(angle)),
+                    BaseHealthSegment { index },
+                ));
+            }
+        })
+        .id()
+}
+
+/// Damage the base and flash it red
+pub fn damage_base(commands: &mut Commands, base_entity: Entity, health: &mut Health, amount: f32) {
+    health.take_damage(amount);
+    // A fresh tween replaces any flash still fading out
+    commands.entity(base_entity).insert(ColorTween::new(
+        HIT_FLASH_COLOR,
+        BASE_COLOR,
+        TweenProgress::new(HIT_FLASH_DURATION, Easing::QuadOut),
+    ));
+    println!("Base hit for {} damage ({}/{} left)", amount, health.current, health.max);
+}
+
+/// Ring colour for a health fraction
+pub fn base_ring_color(health_fraction: f32) -> Color {
+    if health_fraction > 0.5 {
+        RING_HEALTHY_COLOR
+    } else if health_fraction > 0.25 {
+        RING_DAMAGED_COLOR
+    } else {
+        RING_CRITICAL_COLOR
+    }
+}
+
+/// System to spawn the base at the exit of the enemy path
+pub fn setup_base(mut commands: Commands, enemy_path: Res<EnemyPath>) {
+    let exit = enemy_path.waypoints.last().copied().unwrap_or_default();
+    spawn_base(&mut commands, exit);
+}
+
+/// System to keep the base on the path exit when the path is regenerated
+pub fn base_position_system(
+    enemy_path: Res<EnemyPath>,
+    mut base_query: Query<&mut Transform, With<Base>>,
+) {
+    if !enemy_path.is_changed() {
+        return;
+    }
+    let Some(exit) = enemy_path.waypoints.last() else {
+        return;
+    };
+
+    for mut transform in base_query.iter_mut() {
+        transform.translation = exit.extend(transform.translation.z);
+    }
+}
+
+/// System to light the health ring segments to match the base's health
+pub fn base_health_ring_system(
+    base_query: Query<&Health, (With<Base>, Changed<Health>)>,
+    mut segment_query: Query<(&BaseHealthSegment, &mut Sprite)>,
+) {
+    let Ok(health) = base_query.single() else {
+        return;
+    };
+
+    let fraction = health.current / health.max;
+    let lit_segments = Base::lit_segments(fraction);
+    let lit_color = base_ring_color(fraction);
+    for (segment, mut sprite) in segment_query.iter_mut() {
+        sprite.color = if segment.index < lit_segments {
+            lit_color
+        } else {
+            RING_EMPTY_COLOR
+        };
+    }
+}
+
+/// System to keep the player's lives in step with the base's health, so
+/// repairs, perks and restarts that change the health change the lives too
+pub fn player_base_sync_system(
+    mut player_base: ResMut<PlayerBase>,
+    base_query: Query<&Health, (With<Base>, Changed<Health>)>,
+) {
+    if let Ok(health) = base_query.single() {
+        player_base.set_if_neq(PlayerBase::from_health(health));
+    }
+}
+
+/// HUD text for the lives left, e.g. "Lives: 7/10"
+pub fn lives_label(player_base: &PlayerBase) -> String {
+    format!("Lives: {}/{}", player_base.lives, player_base.max_lives)
+}
+
+/// System to spawn the lives counter in the top-left corner
+pub fn setup_lives_hud(mut commands: Commands, player_base: Res<PlayerBase>) {
+    commands.spawn((
+        Text::new(lives_label(&player_base)),
+        TextFont {
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(RING_HEALTHY_COLOR),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(20.0),
+            top: Val::Px(20.0),
+            ..default()
+        },
+        LivesText,
+    ));
+}
+
+/// System to refresh the lives counter, coloured like the health ring
+pub fn lives_hud_system(
+    player_base: Res<PlayerBase>,
+    mut text_query: Query<(&mut Text, &mut TextColor), With<LivesText>>,
+) {
+    if !player_base.is_changed() {
+        return;
+    }
+    let fraction = player_base.lives as f32 / player_base.max_lives.max(1) as f32;
+    for (mut text, mut color) in &mut text_query {
+        **text = lives_label(&player_base);
+        color.0 = base_ring_color(fraction);
+    }
+}
+
+/// System to end the run once the base is destroyed
+pub fn base_destroyed_system(
+    mut game_state: ResMut<GameState>,
+    base_query: Query<&Health, (With<Base>, Changed<Health>)>,
+) {
+    if *game_state != GameState::Playing {
+        return;
+    }
+
+    if base_query.iter().any(|health| health.is_dead()) {
+        *game_state = GameState::GameOver;
+        println!("💀 GAME OVER! The base was destroyed!");
+    }
+}
+
+/// Plugin to add the base the player defends at the end of the path
+pub struct BasePlugin;
+
+impl Plugin for BasePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<PlayerBase>()
+            .add_systems(Startup, (setup_base, setup_lives_hud))
+            .add_systems(
+                Update,
+                (
+                    base_position_system,
+                    base_health_ring_system,
+                    (player_base_sync_system, lives_hud_system).chain(),
+                ).in_set(GameSystemSet::UI),
+            )
+            .add_systems(
+                Update,
+                base_destroyed_system
+                    .in_set(GameSystemSet::Gameplay)
+                    .after(EnemySet::Cleanup)
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
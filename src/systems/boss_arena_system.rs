@@ -0,0 +1,166 @@
+use bevy::prelude::*;
+use crate::components::Enemy;
+use crate::resources::{
+    AppState, EnemyPath, EnemySet, GameConstants, GameSystemSet, PathVariant, PathVariants, TowerStats, WavePlan, WaveRuntime,
+    BOSS_WAVE_INTERVAL,
+};
+use crate::systems::advisor_system::path_cells;
+use crate::systems::enemy_system::EnemyRoutes;
+use crate::systems::obstacle_rendering::ObstacleGrid;
+use crate::systems::path_generation::{arena_route, GridPos};
+use crate::systems::unified_grid::UnifiedGridSystem;
+
+// ============================================================================
+// COMPONENTS & RESOURCES
+// ============================================================================
+
+/// Route an enemy keeps following after the shared path switched under it.
+/// Its `PathProgress` is measured along this route.
+#[derive(Component, Debug, Clone)]
+pub struct PinnedRoute {
+    pub path: EnemyPath,
+}
+
+/// Resource controlling which waves are boss waves and whether they take the arena route
+#[derive(Resource, Debug, Clone)]
+pub struct BossArenaSettings {
+    pub enabled: bool,
+    /// Every Nth wave is a boss wave
+    pub boss_every: u32,
+}
+
+impl Default for BossArenaSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            boss_every: BOSS_WAVE_INTERVAL,
+        }
+    }
+}
+
+impl BossArenaSettings {
+    pub fn is_boss_wave(&self, wave: u32) -> bool {
+        self.boss_every > 0 && wave > 0 && wave.is_multiple_of(self.boss_every)
+    }
+
+    /// Whether this wave is sent down the arena route
+    pub fn uses_arena(&self, wave: u32) -> bool {
+        self.enabled && self.is_boss_wave(wave)
+    }
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// System to record a path this module didn't put in `EnemyPath` as a new
+/// normal route, dropping the arena route worked out for the old one
+pub fn track_normal_path_system(enemy_path: Res<EnemyPath>, mut variants: ResMut<PathVariants>) {
+    if !enemy_path.is_changed() || variants.active_path() == Some(&*enemy_path) {
+        return;
+    }
+    *variants = PathVariants {
+        normal: Some(enemy_path.clone()),
+        ..default()
+    };
+}
+
+/// System to work out the arena route around the current towers whenever the
+/// map, the normal route or the number of towers changes. The arena route is
+/// left alone while a boss wave is walking it.
+pub fn update_arena_path_system(
+    mut variants: ResMut<PathVariants>,
+    obstacle_grid: Option<Res<ObstacleGrid>>,
+    unified_grid: Res<UnifiedGridSystem>,
+    constants: Res<GameConstants>,
+    towers: Query<&Transform, With<TowerStats>>,
+    mut tower_count: Local<Option<usize>>,
+) {
+    let Some(obstacle_grid) = obstacle_grid else {
+        return;
+    };
+    let count = towers.iter().count();
+    let stale = variants.is_changed() || obstacle_grid.is_changed() || *tower_count != Some(count);
+    if !stale || variants.active == PathVariant::Arena {
+        return;
+    }
+    let Some(normal) = variants.normal.as_ref() else {
+        return;
+    };
+    *tower_count = Some(count);
+
+    // Keep clear of every cell a tower's footprint covers
+    let grid = &obstacle_grid.grid;
+    let reach = (constants.tower_footprint + grid.cell_size) / 2.0;
+    let tower_positions: Vec<Vec2> = towers.iter().map(|transform| transform.translation.truncate()).collect();
+    let under_tower = |pos: GridPos| {
+        let center = grid.grid_to_world(pos);
+        tower_positions.iter().any(|tower| (center - *tower).abs().max_element() < reach)
+    };
+
+    let route = path_cells(&normal.waypoints, &unified_grid);
+    let arena = arena_route(grid, &route, under_tower).and_then(|cells| grid.try_to_enemy_path(&cells).ok());
+    if variants.arena != arena {
+        variants.arena = arena;
+    }
+}
+
+/// System to send boss waves down the arena route and bring the normal route
+/// back once the wave is over. Enemies already on the map finish the route they
+/// were walking, so nobody jumps when the path changes under them.
+pub fn boss_arena_switch_system(
+    mut commands: Commands,
+    settings: Res<BossArenaSettings>,
+    wave_plan: Res<WavePlan>,
+    wave_runtime: Res<WaveRuntime>,
+    mut enemy_path: ResMut<EnemyPath>,
+    mut variants: ResMut<PathVariants>,
+    enemies: Query<(Entity, EnemyRoutes), With<Enemy>>,
+) {
+    let wave_running = !wave_runtime.wave_complete(&wave_plan) || !enemies.is_empty();
+    let wanted = if wave_running && settings.uses_arena(wave_plan.current_wave) && variants.arena.is_some() {
+        PathVariant::Arena
+    } else {
+        PathVariant::Normal
+    };
+    if wanted == variants.active {
+        return;
+    }
+    let Some(path) = variants.get(wanted).cloned() else {
+        return;
+    };
+
+    // Smart enemies reroute on their own, flyers keep their shortcut and
+    // enemies sent down one of several routes keep to it
+    for (entity, _) in enemies.iter().filter(|(_, routes)| routes.own().is_none()) {
+        commands.entity(entity).insert(PinnedRoute {
+            path: enemy_path.clone(),
+        });
+    }
+    info!("Wave {} takes the {:?} route", wave_plan.current_wave, wanted);
+    *enemy_path = path;
+    variants.active = wanted;
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin sending boss waves down a longer arena route
+pub struct BossArenaPlugin;
+
+impl Plugin for BossArenaPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BossArenaSettings>()
+            .init_resource::<PathVariants>()
+            .add_systems(
+                Update,
+                (track_normal_path_system, update_arena_path_system, boss_arena_switch_system)
+                    .chain()
+                    .after(EnemySet::WaveControl)
+                    .before(EnemySet::PathGeneration)
+                    .in_set(GameSystemSet::Gameplay)
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
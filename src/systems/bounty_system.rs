@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+use crate::components::Enemy;
+use crate::resources::{AppState, BountyLedger, EnemySet, GameSystemSet, NumberFormatter, WavePlan, WaveRuntime};
+use crate::systems::combat_system::kill_reward_range;
+
+const SUMMARY_COLOR: Color = Color::srgb(0.88, 0.92, 0.62);
+
+/// Marker for the text summing up the last wave's bounty
+#[derive(Component)]
+pub struct WaveSummaryText;
+
+/// Summary of the wave just cleared, or `None` while a wave is running or before the first
+pub fn wave_summary_text(
+    ledger: &BountyLedger,
+    wave_plan: &WavePlan,
+    wave_runtime: &WaveRuntime,
+    live_enemies: usize,
+    formatter: &NumberFormatter,
+) -> Option<String> {
+    let between_waves = wave_runtime.wave_complete(wave_plan) && live_enemies == 0;
+    if !between_waves {
+        return None;
+    }
+    ledger
+        .latest()
+        .filter(|entry| entry.wave == wave_plan.current_wave)
+        .map(|entry| entry.summary(formatter))
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// System to open a ledger entry for each wave as it starts
+pub fn record_wave_bounty_system(
+    mut last_wave: Local<u32>,
+    wave_plan: Res<WavePlan>,
+    mut ledger: ResMut<BountyLedger>,
+) {
+    if wave_plan.current_wave == *last_wave {
+        return;
+    }
+    *last_wave = wave_plan.current_wave;
+    if wave_plan.current_wave > 0 {
+        ledger.start_wave(wave_plan.current_wave, kill_reward_range().0);
+    }
+}
+
+/// System to spawn the wave summary line under the enemy counters
+pub fn setup_wave_summary_hud(mut commands: Commands) {
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(SUMMARY_COLOR),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(62.0),
+            left: Val::Percent(50.0),
+            margin: UiRect::left(Val::Px(-150.0)),
+            ..default()
+        },
+        Visibility::Hidden,
+        WaveSummaryText,
+    ));
+}
+
+/// System to show expected against actual bounty once a wave is cleared
+pub fn wave_summary_hud_system(
+    ledger: Res<BountyLedger>,
+    wave_plan: Res<WavePlan>,
+    wave_runtime: Res<WaveRuntime>,
+    formatter: Res<NumberFormatter>,
+    enemies: Query<(), With<Enemy>>,
+    mut text_query: Query<(&mut Text, &mut Visibility), With<WaveSummaryText>>,
+) {
+    let summary = wave_summary_text(&ledger, &wave_plan, &wave_runtime, enemies.iter().count(), &formatter);
+    for (mut text, mut visibility) in &mut text_query {
+        match &summary {
+            Some(summary) => {
+                if **text != *summary {
+                    **text = summary.clone();
+                }
+                visibility.set_if_neq(Visibility::Inherited);
+            }
+            None => {
+                visibility.set_if_neq(Visibility::Hidden);
+            }
+        }
+    }
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin booking bounty income per wave and summing it up after each wave
+pub struct BountyPlugin;
+
+impl Plugin for BountyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BountyLedger>()
+            .add_systems(Startup, setup_wave_summary_hud)
+            .add_systems(
+                Update,
+                record_wave_bounty_system
+                    .in_set(GameSystemSet::Gameplay)
+                    .after(EnemySet::WaveControl)
+                    .before(EnemySet::Spawning)
+                    .run_if(in_state(AppState::Playing)),
+            )
+            .add_systems(Update, wave_summary_hud_system.in_set(GameSystemSet::UI));
+    }
+}
@@ -0,0 +1,153 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::resources::*;
+use crate::systems::combat_system::WaveStatus;
+use crate::systems::results_screen::{dismiss_results_screen, results_button_system, EndCinematic, ResultsOverlay, RestoreCheckpointEvent};
+use crate::systems::settings_menu::GameSettings;
+use crate::systems::tower_rendering::spawn_tower_with_pattern;
+use crate::systems::tower_ui::TowerSelectionState;
+
+/// System to record a checkpoint when a checkpoint wave starts
+pub fn record_checkpoint_system(
+    mut last_wave: Local<u32>,
+    wave_plan: Res<WavePlan>,
+    economy: Res<Economy>,
+    score: Res<Score>,
+    perks: Option<Res<RunPerks>>,
+    towers: Query<(&TowerStats, &Transform)>,
+    mut checkpoints: ResMut<CheckpointState>,
+) {
+    if wave_plan.current_wave == *last_wave {
+        return;
+    }
+    *last_wave = wave_plan.current_wave;
+
+    if !is_checkpoint_wave(wave_plan.current_wave) {
+        return;
+    }
+
+    let towers = towers
+        .iter()
+        .map(|(stats, transform)| TowerSnapshot::capture(stats, transform.translation.truncate()))
+        .collect::<Vec<_>>();
+    println!(
+        "Checkpoint recorded at wave {} ({} towers, ${})",
+        wave_plan.current_wave,
+        towers.len(),
+        economy.money
+    );
+
+    checkpoints.record(WaveCheckpoint {
+        wave: wave_plan.current_wave,
+        economy: economy.clone(),
+        score: score.clone(),
+        towers,
+        perks: perks.map(|perks| perks.clone()).unwrap_or_default(),
+    });
+}
+
+/// System to rewind the run to the latest checkpoint, spending one retry.
+/// The map and path are kept, the base is repaired, perks bought since are taken back,
+/// and the checkpoint wave has to be started again.
+pub fn restore_checkpoint_system(
+    mut commands: Commands,
+    mut restore_events: EventReader<RestoreCheckpointEvent>,
+    mut checkpoints: ResMut<CheckpointState>,
+    (mut wave_plan, mut wave_runtime): (ResMut<WavePlan>, ResMut<WaveRuntime>),
+    mut wave_status: ResMut<WaveStatus>,
+    mut score: ResMut<Score>,
+    mut economy: ResMut<Economy>,
+    mut game_state: ResMut<GameState>,
+    mut buffs: ResMut<ActiveBuffs>,
+    mut selection_state: ResMut<TowerSelectionState>,
+    mut shop: Option<ResMut<PerkShop>>,
+    mut perks: Option<ResMut<RunPerks>>,
+    run_entities: Query<Entity, Or<(With<Enemy>, With<DyingEnemy>, With<Projectile>, With<TowerStats>, With<ResultsOverlay>, With<LootPickup>)>>,
+    mut camera_query: Query<(&mut Transform, &mut Projection), With<Camera2d>>,
+    mut base_query: Query<&mut Health, With<Base>>,
+    cinematic: Option<Res<EndCinematic>>,
+) {
+    if restore_events.read().last().is_none() {
+        return;
+    }
+    let Some(checkpoint) = checkpoints.consume_retry() else {
+        warn!("No checkpoint retries left");
+        return;
+    };
+
+    for entity in run_entities.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    for snapshot in &checkpoint.towers {
+        let tower_entity = spawn_tower_with_pattern(&mut commands, snapshot.position, snapshot.tower_type);
+        commands.entity(tower_entity).insert(snapshot.restored_stats());
+    }
+
+    // Stop just before the checkpoint wave so the player can start it again
+    *wave_plan = WavePlan::default();
+    *wave_runtime = WaveRuntime::new();
+    wave_plan.current_wave = checkpoint.wave - 1;
+    *wave_status = WaveStatus::default();
+    *economy = checkpoint.economy.clone();
+    *score = checkpoint.score.clone();
+    *buffs = ActiveBuffs::default();
+    *game_state = GameState::Playing;
+    if let Some(perks) = perks.as_mut() {
+        **perks = checkpoint.perks.clone();
+    }
+    // Replaying the checkpoint wave earns its shop visit again
+    if let Some(shop) = shop.as_mut() {
+        shop.close();
+        shop.visited_wave = shop.visited_wave.min(checkpoint.wave - 1);
+    }
+    // The retry starts with a repaired base, keeping the lives bought before the checkpoint
+    let base_max_health = BASE_MAX_HEALTH
+        + (checkpoint.perks.stacks(Perk::Reinforcements) * LIVES_PER_PERK) as f32 * ENEMY_BASE_DAMAGE;
+    for mut base_health in base_query.iter_mut() {
+        base_health.max = base_max_health;
+        base_health.current = base_health.max;
+    }
+    selection_state.clear_selection();
+    dismiss_results_screen(&mut commands, &mut camera_query, cinematic);
+
+    info!(
+        "Restored checkpoint at wave {} ({} retries left)",
+        checkpoint.wave,
+        checkpoints.retries_remaining()
+    );
+}
+
+/// System to size the first run's retry budget from the configured difficulty
+pub fn init_checkpoint_retries(
+    settings: Option<Res<GameSettings>>,
+    mut checkpoints: ResMut<CheckpointState>,
+) {
+    let difficulty = settings.map(|settings| settings.difficulty).unwrap_or_default();
+    *checkpoints = CheckpointState::new(difficulty.checkpoint_retries());
+}
+
+/// Plugin to record wave checkpoints and restore them after a defeat
+pub struct CheckpointPlugin;
+
+impl Plugin for CheckpointPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<CheckpointState>()
+            .add_event::<RestoreCheckpointEvent>()
+            .add_systems(Startup, init_checkpoint_retries)
+            .add_systems(
+                Update,
+                restore_checkpoint_system
+                    .in_set(GameSystemSet::UI)
+                    .after(results_button_system),
+            )
+            .add_systems(
+                Update,
+                record_checkpoint_system
+                    .in_set(GameSystemSet::Gameplay)
+                    .after(EnemySet::WaveControl)
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
@@ -0,0 +1,212 @@
+use bevy::prelude::*;
+use crate::components::EnemyType;
+use crate::resources::*;
+use crate::systems::input::{InputContext, InputHandler, InputRegistryAppExt};
+use crate::systems::settings_menu::GameSettings;
+use crate::systems::tween::blend_colors;
+
+/// Key opening and closing the enemy codex
+pub const CODEX_KEY: KeyCode = KeyCode::KeyK;
+
+const PANEL_BG: Color = Color::srgba(0.06, 0.09, 0.14, 0.95);
+const PANEL_BORDER: Color = Color::srgb(0.22, 0.28, 0.38);
+/// Entry backgrounds run from cold for rarely killed types to hot for the most killed
+const COLD_ENTRY_BG: Color = Color::srgb(0.10, 0.16, 0.26);
+const HOT_ENTRY_BG: Color = Color::srgb(0.45, 0.16, 0.08);
+const TEXT_PRIMARY: Color = Color::srgb(0.96, 0.96, 0.98);
+const TEXT_SECONDARY: Color = Color::srgb(0.78, 0.82, 0.88);
+const TEXT_LORE: Color = Color::srgb(0.88, 0.92, 0.62);
+
+/// Resource for whether the codex panel is showing
+#[derive(Resource, Debug, Default)]
+pub struct CodexPanel {
+    pub open: bool,
+}
+
+/// Marker for the codex panel root
+#[derive(Component)]
+pub struct CodexPanelRoot;
+
+/// Kill summary of a codex entry, e.g. "You've killed 1,204 Tank enemies; 37 leaked"
+pub fn codex_summary(enemy_type: EnemyType, stats: &EnemyTypeStats, formatter: &NumberFormatter) -> String {
+    format!(
+        "You've killed {} {} enemies; {} leaked",
+        formatter.count(stats.kills),
+        enemy_type.get_name(),
+        formatter.count(stats.leaks)
+    )
+}
+
+/// Input handler opening and closing the codex
+pub struct CodexHandler;
+
+impl InputHandler for CodexHandler {
+    fn handle_input(&self, world: &mut World, key: KeyCode) -> bool {
+        if key != CODEX_KEY {
+            return false;
+        }
+        let Some(mut panel) = world.get_resource_mut::<CodexPanel>() else {
+            warn!("Codex handler: CodexPanel resource not found");
+            return false;
+        };
+        panel.open = !panel.open;
+        true
+    }
+
+    fn get_description(&self) -> &str {
+        "Enemy codex"
+    }
+
+    fn get_priority(&self) -> u8 {
+        50
+    }
+
+    fn get_id(&self) -> &str {
+        "codex"
+    }
+
+    fn handles_key(&self, key: KeyCode) -> bool {
+        key == CODEX_KEY
+    }
+
+    fn get_handled_keys(&self) -> Vec<KeyCode> {
+        vec![CODEX_KEY]
+    }
+
+    fn get_context(&self) -> InputContext {
+        InputContext::UI
+    }
+}
+
+/// System to rebuild the codex panel when it opens or its statistics change
+pub fn codex_panel_system(
+    mut commands: Commands,
+    panel: Res<CodexPanel>,
+    codex: Res<EnemyCodex>,
+    formatter: Res<NumberFormatter>,
+    roots: Query<Entity, With<CodexPanelRoot>>,
+) {
+    if !panel.is_changed() && !(panel.open && (codex.is_changed() || formatter.is_changed())) {
+        return;
+    }
+
+    for entity in roots.iter() {
+        commands.entity(entity).despawn();
+    }
+    if panel.open {
+        spawn_codex_panel(&mut commands, &codex, &formatter);
+    }
+}
+
+fn spawn_codex_panel(commands: &mut Commands, codex: &EnemyCodex, formatter: &NumberFormatter) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(20.0),
+            top: Val::Px(120.0),
+            width: Val::Px(380.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(15.0)),
+            row_gap: Val::Px(10.0),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(PANEL_BG),
+        BorderColor(PANEL_BORDER),
+        BorderRadius::all(Val::Px(10.0)),
+        ZIndex(750), // Below the shop, results screen and pause menu
+        CodexPanelRoot,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new("ENEMY CODEX (K)"),
+            TextFont {
+                font_size: 22.0,
+                ..default()
+            },
+            TextColor(TEXT_PRIMARY),
+        ));
+
+        for enemy_type in EnemyType::ALL {
+            let stats = codex.stats(enemy_type);
+            let mut lines = vec![
+                (codex_summary(enemy_type, &stats, formatter), TEXT_PRIMARY),
+                (codex_description(enemy_type).to_string(), TEXT_SECONDARY),
+                (format!("Damage taken: {}", formatter.count(stats.damage_taken as u64)), TEXT_SECONDARY),
+            ];
+            lines.extend(
+                codex
+                    .unlocked_lore(enemy_type)
+                    .into_iter()
+                    .map(|lore| (format!("\"{}\"", lore), TEXT_LORE)),
+            );
+            if let Some(milestone) = codex.next_milestone(enemy_type) {
+                lines.push((format!("Next lore at {} kills", formatter.count(milestone)), TEXT_SECONDARY));
+            }
+
+            parent.spawn((
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(8.0)),
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                },
+                BackgroundColor(blend_colors(COLD_ENTRY_BG, HOT_ENTRY_BG, codex.kill_heat(enemy_type))),
+                BorderRadius::all(Val::Px(6.0)),
+            )).with_children(|entry| {
+                entry.spawn((
+                    Text::new(enemy_type.get_name().to_uppercase()),
+                    TextFont {
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(TEXT_PRIMARY),
+                ));
+                for (line, color) in lines {
+                    entry.spawn((
+                        Text::new(line),
+                        TextFont {
+                            font_size: 13.0,
+                            ..default()
+                        },
+                        TextColor(color),
+                    ));
+                }
+            });
+        }
+    });
+}
+
+/// System to write the profile's enemy statistics out when a run ends or the game closes
+pub fn save_codex_system(
+    codex: Res<EnemyCodex>,
+    run_results: Option<Res<RunResults>>,
+    mut exit_events: EventReader<AppExit>,
+    settings: Option<Res<GameSettings>>,
+) {
+    let run_ended = run_results.is_some_and(|results| results.is_added());
+    let exiting = exit_events.read().last().is_some();
+    if !run_ended && !exiting {
+        return;
+    }
+    // Records stay in memory only while saving them is switched off
+    if settings.is_some_and(|settings| !settings.save_records) {
+        return;
+    }
+    if let Err(error) = codex.save() {
+        warn!("Failed to save enemy statistics to {}: {}", ENEMY_CODEX_FILE, error);
+    }
+}
+
+/// Plugin tracking lifetime enemy statistics and showing them in the codex
+pub struct CodexPlugin;
+
+impl Plugin for CodexPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(EnemyCodex::load())
+            .init_resource::<CodexPanel>()
+            .register_input_handler(CodexHandler)
+            .add_systems(Update, codex_panel_system.in_set(GameSystemSet::UI))
+            // Last, so exit requests sent during Update are seen before the app closes
+            .add_systems(Last, save_codex_system);
+    }
+}
@@ -1,6 +1,12 @@
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 use crate::components::*;
 use crate::resources::*;
+use crate::systems::loot_system::spawn_loot_drop;
+use crate::systems::particles::{spawn_particles, ParticleEmitter};
+use crate::systems::settings_menu::GameSettings;
+use crate::systems::tween::{AlphaTween, Easing, ScaleTween, TweenProgress};
 
 // ============================================================================
 // COMPONENTS
@@ -13,8 +19,174 @@ pub struct Target {
     pub last_shot_time: f32,     // For fire rate control
 }
 
+/// Component for multi-barrel towers to fire from each muzzle in turn
+#[derive(Component, Debug, Default)]
+pub struct BarrelCycle {
+    pub next: usize,
+}
+
+impl BarrelCycle {
+    /// Muzzle offset for the next shot, advancing to the following barrel
+    pub fn fire(&mut self, muzzles: &[Vec2]) -> Vec2 {
+        if muzzles.is_empty() {
+            return Vec2::ZERO;
+        }
+        // Upgrades can swap in a turret with fewer barrels
+        let offset = muzzles[self.next % muzzles.len()];
+        self.next = (self.next + 1) % muzzles.len();
+        offset
+    }
+}
+
+/// World position of a muzzle, with the tower-local offset turned to face the target
+pub fn muzzle_position(tower_position: Vec2, target_position: Vec2, offset: Vec2) -> Vec2 {
+    let aim = (target_position - tower_position).normalize_or_zero();
+    if aim == Vec2::ZERO {
+        return tower_position + offset;
+    }
+    tower_position + aim.rotate(offset)
+}
+
+/// Which enemy in range a tower prefers to shoot
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TargetingMode {
+    /// Enemy furthest along the path
+    #[default]
+    First,
+    /// Enemy least far along the path
+    Last,
+    /// Enemy with the most health remaining
+    Strongest,
+    /// Enemy with the least health remaining
+    Weakest,
+    /// Enemy nearest to the tower
+    Closest,
+}
+
+impl TargetingMode {
+    pub const ALL: [TargetingMode; 5] = [
+        TargetingMode::First,
+        TargetingMode::Last,
+        TargetingMode::Strongest,
+        TargetingMode::Weakest,
+        TargetingMode::Closest,
+    ];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            TargetingMode::First => "First",
+            TargetingMode::Last => "Last",
+            TargetingMode::Strongest => "Strongest",
+            TargetingMode::Weakest => "Weakest",
+            TargetingMode::Closest => "Closest",
+        }
+    }
+
+    /// The next mode in the cycle, wrapping around
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|mode| *mode == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Mode every tower of a group uses, or `None` when they differ or there are none
+    pub fn shared(modes: impl IntoIterator<Item = TargetingMode>) -> Option<TargetingMode> {
+        let mut modes = modes.into_iter();
+        let first = modes.next()?;
+        modes.all(|mode| mode == first).then_some(first)
+    }
+
+    /// Score an enemy candidate; the tower picks the highest score
+    fn score(self, progress: f32, health: f32, distance: f32) -> f32 {
+        match self {
+            TargetingMode::First => progress,
+            TargetingMode::Last => -progress,
+            TargetingMode::Strongest => health,
+            TargetingMode::Weakest => -health,
+            TargetingMode::Closest => -distance,
+        }
+    }
+}
+
+/// Part of a tower's range the player has told it to watch. Enemies in range
+/// but outside the zone are ignored.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct FocusZone {
+    /// World-space rectangle, clipped to the square around the tower's range
+    pub rect: Rect,
+}
+
+impl FocusZone {
+    /// Zone from a dragged rectangle, or `None` if the drag misses the tower's range
+    pub fn from_drag(tower_position: Vec2, range: f32, drag: Rect) -> Option<Self> {
+        let rect = drag.intersect(Rect::from_center_half_size(tower_position, Vec2::splat(range)));
+        if rect.is_empty() {
+            return None;
+        }
+        let nearest = tower_position.clamp(rect.min, rect.max);
+        (nearest.distance(tower_position) <= range).then_some(Self { rect })
+    }
+
+    pub fn contains(&self, point: Vec2) -> bool {
+        self.rect.contains(point)
+    }
+}
+
 // Projectile component is now defined in components/projectile.rs
 
+// ============================================================================
+// EVENTS
+// ============================================================================
+
+/// Event sent when a projectile kills an enemy
+#[derive(Event, Debug, Clone, Copy)]
+pub struct EnemyKilledEvent {
+    /// Where the enemy died
+    pub position: Vec2,
+    /// Type of the tower that fired the killing shot
+    pub tower_type: TowerType,
+}
+
+/// Event sent whenever a tower fires a shot
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TowerFiredEvent {
+    pub tower: Entity,
+    /// Where the shot left the barrel
+    pub muzzle: Vec2,
+    /// Where the target was when fired at
+    pub target: Vec2,
+}
+
+/// Share of an enemy's maximum health a single direct hit must take to count as critical
+pub const CRITICAL_HIT_SHARE: f32 = 0.25;
+
+/// How damage reached an enemy
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DamageKind {
+    /// A projectile striking the enemy
+    Direct,
+    /// A direct hit taking at least `CRITICAL_HIT_SHARE` of the enemy's maximum health
+    Critical,
+    /// Caught in the blast of a splash shot
+    Splash,
+    /// Struck by a chain lightning jump
+    Chain,
+    /// A burn tick
+    Burn,
+}
+
+/// Event sent for every hit on an enemy, lethal or not
+#[derive(Event, Debug, Clone, Copy)]
+pub struct EnemyDamagedEvent {
+    pub enemy: Entity,
+    /// Where the enemy was hit
+    pub position: Vec2,
+    /// Health actually removed
+    pub amount: f32,
+    /// Type of the tower that fired the shot
+    pub tower_type: TowerType,
+    pub kind: DamageKind,
+}
+
 // ============================================================================
 // RESOURCES  
 // ============================================================================
@@ -37,29 +209,56 @@ impl WaveStatus {
     }
 }
 
+/// Money awarded for a kill, by the type of tower that landed the killing shot
+pub fn kill_reward(tower_type: TowerType) -> u32 {
+    match tower_type {
+        TowerType::Basic => 5,
+        TowerType::Advanced => 8,
+        TowerType::Laser => 10,
+        TowerType::Missile => 12,
+        TowerType::Tesla => 15,
+    }
+}
+
+/// Money a kill pays out, from the cheapest to the best-paying tower
+pub fn kill_reward_range() -> (u32, u32) {
+    let rewards = TowerType::ALL.iter().map(|tower_type| kill_reward(*tower_type));
+    (rewards.clone().min().unwrap_or(0), rewards.max().unwrap_or(0))
+}
+
 // ============================================================================
 // SYSTEMS
 // ============================================================================
 
-/// System 1: Tower Targeting - Find enemies closest to end within range
+/// System 1: Tower Targeting - Pick an enemy within range, and within the
+/// tower's focus zone if it has one, according to each tower's targeting mode
+/// (closest to the end by default)
 pub fn tower_targeting_system(
-    mut towers: Query<(&mut Target, &TowerStats, &Transform), With<TowerStats>>,
-    enemies: Query<(Entity, &Transform, &PathProgress), (With<Enemy>, Without<TowerStats>)>,
+    mut towers: Query<
+        (&mut Target, &TowerStats, &Transform, Option<&TargetingMode>, Option<&FocusZone>),
+        (With<TowerStats>, Without<Constructing>),
+    >,
+    enemies: Query<(Entity, &Transform, &PathProgress, Option<&Health>), (With<Enemy>, Without<TowerStats>)>,
 ) {
-    for (mut target, stats, tower_transform) in towers.iter_mut() {
+    for (mut target, stats, tower_transform, targeting_mode, focus_zone) in towers.iter_mut() {
         let tower_pos = tower_transform.translation.truncate();
+        let targeting_mode = targeting_mode.copied().unwrap_or_default();
         
-        // Find enemy closest to end (highest progress) within range
+        // Find the best-scoring enemy within range
         let mut best_target = None;
-        let mut highest_progress = -1.0;
+        let mut best_score = f32::NEG_INFINITY;
         
-        for (enemy_entity, enemy_transform, path_progress) in enemies.iter() {
+        for (enemy_entity, enemy_transform, path_progress, health) in enemies.iter() {
             let enemy_pos = enemy_transform.translation.truncate();
             let distance = tower_pos.distance(enemy_pos);
+            if distance > stats.range || focus_zone.is_some_and(|zone| !zone.contains(enemy_pos)) {
+                continue;
+            }
             
-            // Check if enemy is in range and closer to end than current best
-            if distance <= stats.range && path_progress.current > highest_progress {
-                highest_progress = path_progress.current;
+            let health = health.map_or(0.0, |health| health.current);
+            let score = targeting_mode.score(path_progress.current, health, distance);
+            if score > best_score {
+                best_score = score;
                 best_target = Some(enemy_entity);
             }
         }
@@ -68,51 +267,110 @@ pub fn tower_targeting_system(
     }
 }
 
-/// System 2: Projectile Spawning - Fire at targeted enemies
+/// Colour of a tower type's projectiles, and of the sparks where they strike
+pub fn projectile_color(tower_type: TowerType) -> Color {
+    match tower_type {
+        TowerType::Basic => Color::srgb(1.0, 1.0, 0.0), // Yellow
+        TowerType::Advanced => Color::srgb(0.0, 0.8, 1.0), // Cyan
+        TowerType::Laser => Color::srgb(1.0, 0.2, 0.2), // Red
+        TowerType::Missile => Color::srgb(1.0, 0.5, 0.0), // Orange
+        TowerType::Tesla => Color::srgb(0.8, 0.0, 1.0), // Purple
+    }
+}
+
+/// System 2: Projectile Spawning - Fire at targeted enemies.
+/// Towers with a `FiringPattern` space their shots by it instead of a uniform cooldown.
 pub fn projectile_spawning_system(
     mut commands: Commands,
     time: Res<Time>,
-    mut towers: Query<(&mut Target, &TowerStats, &Transform)>,
+    mut towers: Query<(
+        Entity,
+        &mut Target,
+        &TowerStats,
+        &Transform,
+        Option<&Heat>,
+        Option<&mut BarrelCycle>,
+        Option<&mut FiringPattern>,
+    ), Without<Constructing>>,
     enemies: Query<&Transform, (With<Enemy>, Without<TowerStats>)>,
+    buffs: Option<Res<ActiveBuffs>>,
+    perks: Option<Res<RunPerks>>,
+    mut fired_events: EventWriter<TowerFiredEvent>,
 ) {
     let current_time = time.elapsed_secs();
+    let perk_fire_rate = perks.as_ref().map_or(1.0, |perks| perks.fire_rate_multiplier());
+    let damage_multiplier = perks.as_ref().map_or(1.0, |perks| perks.damage_multiplier());
+    let buff_multiplier = buffs.map_or(1.0, |buffs| buffs.fire_rate_multiplier()) * perk_fire_rate;
     
-    for (mut target, stats, tower_transform) in towers.iter_mut() {
-        // Check if we can shoot (fire rate control)
-        if current_time - target.last_shot_time < (1.0 / stats.fire_rate) {
+    for (tower_entity, mut target, stats, tower_transform, heat, barrels, mut pattern) in towers.iter_mut() {
+        // Overheated towers stay offline until they cool down
+        if heat.is_some_and(|heat| heat.is_shut_down()) {
             continue;
         }
+
+        // Fire rate control, including loot buffs, run perks and overclock
+        let fire_rate_multiplier = buff_multiplier * heat.map_or(1.0, |heat| heat.fire_rate_multiplier());
+        let cooldown = 1.0 / (stats.fire_rate * fire_rate_multiplier);
+        let since_last_shot = current_time - target.last_shot_time;
         
         // Check if we have a valid target
         // HOTFIX: Validate entity exists before accessing to prevent crashes
         if let Some(target_entity) = target.entity {
             // Double-check the entity still exists before accessing
             if let Ok(target_transform) = enemies.get(target_entity) {
+                // Check if we can shoot, following the tower's firing pattern
+                let shot_multiplier = match pattern.as_deref_mut() {
+                    Some(pattern) => pattern.try_fire(target_entity, since_last_shot, cooldown, time.delta_secs()),
+                    None => (since_last_shot >= cooldown).then_some(1.0),
+                };
+                let Some(shot_multiplier) = shot_multiplier else {
+                    continue;
+                };
+                
                 // Get projectile properties based on tower type
-                let (projectile_speed, projectile_color) = match stats.tower_type {
-                    TowerType::Basic => (300.0, Color::srgb(1.0, 1.0, 0.0)), // Yellow
-                    TowerType::Advanced => (400.0, Color::srgb(0.0, 0.8, 1.0)), // Cyan
-                    TowerType::Laser => (800.0, Color::srgb(1.0, 0.2, 0.2)), // Red
-                    TowerType::Missile => (200.0, Color::srgb(1.0, 0.5, 0.0)), // Orange
-                    TowerType::Tesla => (600.0, Color::srgb(0.8, 0.0, 1.0)), // Purple
+                let projectile_speed = match stats.tower_type {
+                    TowerType::Basic => 300.0,
+                    TowerType::Advanced => 400.0,
+                    TowerType::Laser => 800.0,
+                    TowerType::Missile => 200.0,
+                    TowerType::Tesla => 600.0,
                 };
                 
-                // Spawn projectile
-                commands.spawn((
-                    Sprite {
-                        color: projectile_color,
-                        custom_size: Some(Vec2::new(6.0, 6.0)),
-                        ..default()
-                    },
-                    Transform::from_translation(tower_transform.translation),
+                // Spawn projectile from the next barrel's muzzle, turned toward the target
+                let muzzles = stats.tower_type.get_muzzle_offsets(stats.upgrade_level);
+                let offset = match barrels {
+                    Some(mut barrels) => barrels.fire(muzzles),
+                    None => muzzles.first().copied().unwrap_or(Vec2::ZERO),
+                };
+                let tower_position = tower_transform.translation.truncate();
+                let target_position = target_transform.translation.truncate();
+                let muzzle = muzzle_position(tower_position, target_position, offset);
+                let kind = ProjectileKind::for_tower(stats.tower_type, muzzle, target_position);
+                let mut projectile = commands.spawn((
+                    Transform::from_translation(muzzle.extend(tower_transform.translation.z)),
+                    kind,
                     Projectile::new(
-                        stats.damage,
+                        stats.damage * damage_multiplier * shot_multiplier,
                         projectile_speed,
                         target_entity,
-                        target_transform.translation.truncate(),
+                        target_position,
                         stats.tower_type,
-                    ),
+                    )
+                    .with_splash(stats.splash_radius)
+                    .with_status_effects(stats.chain_targets, stats.burn_dps, stats.slow),
                 ));
+                if let Some(size) = kind.sprite_size() {
+                    projectile.insert(Sprite {
+                        color: projectile_color(stats.tower_type),
+                        custom_size: Some(size),
+                        ..default()
+                    });
+                }
+                fired_events.write(TowerFiredEvent {
+                    tower: tower_entity,
+                    muzzle,
+                    target: target_position,
+                });
                 
                 target.last_shot_time = current_time;
             } else {
@@ -120,19 +378,27 @@ pub fn projectile_spawning_system(
                 target.entity = None;
             }
         }
+        
+        if target.entity.is_none() {
+            if let Some(pattern) = pattern.as_deref_mut() {
+                pattern.lose_target();
+            }
+        }
     }
 }
 
-/// System 3: Projectile Movement - Move projectiles toward targets
+/// System 3: Projectile Movement - Move projectiles toward targets, each the way
+/// its `ProjectileKind` flies
 pub fn projectile_movement_system(
     mut commands: Commands,
     time: Res<Time>,
-    mut projectiles: Query<(Entity, &mut Transform, &Projectile)>,
+    mut effect_budget: Option<ResMut<EffectBudget>>,
+    mut projectiles: Query<(Entity, &mut Transform, &Projectile, Option<&mut ProjectileKind>)>,
     enemies: Query<&Transform, (With<Enemy>, Without<Projectile>)>,
 ) {
     let delta_time = time.delta_secs();
     
-    for (projectile_entity, mut projectile_transform, projectile) in projectiles.iter_mut() {
+    for (projectile_entity, mut projectile_transform, projectile, mut kind) in projectiles.iter_mut() {
         // Determine target position (lead the target if it still exists)
         let target_position = if let Ok(enemy_transform) = enemies.get(projectile.target_entity) {
             // Target still exists - lead it (aim for current position)
@@ -144,10 +410,40 @@ pub fn projectile_movement_system(
         
         // Move projectile toward target
         let current_pos = projectile_transform.translation.truncate();
-        let direction = (target_position - current_pos).normalize_or_zero();
-        let movement = direction * projectile.speed * delta_time;
+        let step = projectile.speed * delta_time;
+        let next_pos = match kind.as_deref_mut() {
+            None | Some(ProjectileKind::Bullet) => {
+                current_pos + (target_position - current_pos).normalize_or_zero() * step
+            }
+            Some(ProjectileKind::Beam { fired }) => {
+                // A beam lands on its target the frame it fires; one still around after that missed
+                if *fired {
+                    commands.entity(projectile_entity).despawn();
+                    continue;
+                }
+                *fired = true;
+                spawn_beam(&mut commands, effect_budget.as_deref_mut(), current_pos, target_position);
+                target_position
+            }
+            Some(ProjectileKind::Homing { heading }) => {
+                let desired = (target_position - current_pos).normalize_or_zero();
+                *heading = if current_pos.distance(target_position) < MISSILE_TERMINAL_DISTANCE && desired != Vec2::ZERO {
+                    desired
+                } else {
+                    steer(*heading, desired, MISSILE_TURN_RATE * delta_time)
+                };
+                projectile_transform.rotation = Quat::from_rotation_z(heading.to_angle());
+                current_pos + *heading * step
+            }
+            Some(ProjectileKind::Arc { origin, travelled }) => {
+                *travelled += step;
+                let span = origin.distance(target_position);
+                let t = if span > 0.0 { (*travelled / span).min(1.0) } else { 1.0 };
+                arc_position(*origin, target_position, t)
+            }
+        };
         
-        projectile_transform.translation += movement.extend(0.0);
+        projectile_transform.translation = next_pos.extend(projectile_transform.translation.z);
         
         // Remove projectile if it has traveled too far (missed target)
         let travel_distance = current_pos.distance(projectile.target_position);
@@ -167,130 +463,323 @@ pub fn projectile_movement_system(
 /// System 4: Collision Detection - Handle projectile hits and enemy damage
 pub fn collision_system(
     mut commands: Commands,
-    mut economy: ResMut<Economy>,
-    mut wave_status: ResMut<WaveStatus>,
+    mut damage: EnemyDamage,
+    mut effect_budget: Option<ResMut<EffectBudget>>,
     // debug_ui_state: Option<Res<crate::systems::debug_ui::DebugUIState>>, // Disabled due to Bevy 0.16 Style issues
-    debug_state: Option<Res<crate::systems::debug_visualization::DebugVisualizationState>>,
     projectiles: Query<(Entity, &Transform, &Projectile)>,
-    mut enemies: Query<(Entity, &Transform, &mut Health), With<Enemy>>,
+    mut enemies: Query<(Entity, &Transform, &mut Health, Option<&LootTable>, Option<&EnemyType>, Has<Chained>), With<Enemy>>,
 ) {
     for (projectile_entity, projectile_transform, projectile_data) in projectiles.iter() {
-        for (enemy_entity, enemy_transform, mut enemy_health) in enemies.iter_mut() {
-            // Simple circle collision detection
-            let distance = projectile_transform.translation.truncate()
-                .distance(enemy_transform.translation.truncate());
-            
-            if distance < 16.0 { // Collision threshold
-                // Calculate effective damage with UI multiplier (UI disabled for now)
-                let damage_multiplier = 1.0; // Simplified since debug_ui is disabled
-                
-                let effective_damage = projectile_data.damage * damage_multiplier;
-                
-                // Debug output for damage multiplier (only when different from 1.0)
-                if damage_multiplier != 1.0 {
-                    println!("Applied damage multiplier {:.2}: {:.1} -> {:.1} damage", 
-                        damage_multiplier, projectile_data.damage, effective_damage);
+        let impact = projectile_transform.translation.truncate();
+
+        // Simple circle collision detection; enemies killed earlier this frame are skipped
+        let Some((primary, primary_position)) = enemies.iter()
+            .find(|(_, enemy_transform, health, ..)| {
+                !health.is_dead()
+                    && impact.distance(enemy_transform.translation.truncate()) < ENEMY_COLLISION_RADIUS + PROJECTILE_HIT_RADIUS
+            })
+            .map(|(entity, enemy_transform, ..)| (entity, enemy_transform.translation.truncate()))
+        else {
+            continue;
+        };
+
+        // Remove projectile (it hit something)
+        commands.entity(projectile_entity).despawn();
+        spawn_particles(&mut commands, impact, ParticleEmitter::impact(projectile_color(projectile_data.tower_type)));
+
+        // Calculate effective damage with UI multiplier (UI disabled for now)
+        let damage_multiplier = 1.0; // Simplified since debug_ui is disabled
+        
+        let effective_damage = projectile_data.damage * damage_multiplier;
+        
+        // Debug output for damage multiplier (only when different from 1.0)
+        if damage_multiplier != 1.0 {
+            println!("Applied damage multiplier {:.2}: {:.1} -> {:.1} damage", 
+                damage_multiplier, projectile_data.damage, effective_damage);
+        }
+
+        // The enemy struck takes full damage; splash shots also hurt everything in the blast
+        let mut hits = vec![(primary, effective_damage, DamageKind::Direct)];
+        if projectile_data.has_splash() {
+            for (enemy_entity, enemy_transform, health, ..) in enemies.iter() {
+                if enemy_entity == primary || health.is_dead() {
+                    continue;
                 }
-                
-                // Apply damage to enemy
-                enemy_health.take_damage(effective_damage);
-                
-                // Remove projectile (it hit something)
-                commands.entity(projectile_entity).despawn();
-                
-                // Check if enemy died from damage
-                if enemy_health.is_dead() {
-                    // Award resources based on tower type (different towers give different rewards)
-                    let money_reward = match projectile_data.tower_type {
-                        TowerType::Basic => 5,
-                        TowerType::Advanced => 8,
-                        TowerType::Laser => 10,
-                        TowerType::Missile => 12,
-                        TowerType::Tesla => 15,
-                    };
-                    
-                    economy.money += money_reward;
-                    economy.research_points += 1;
-                    
-                    // Remove dead enemy
-                    commands.entity(enemy_entity).despawn();
-                    
-                    // Update wave progress
-                    wave_status.enemies_killed += 1;
-                    wave_status.enemies_remaining = wave_status.enemies_remaining.saturating_sub(1);
-                    
-                    // Check if wave is complete
-                    if wave_status.enemies_remaining == 0 {
-                        wave_status.wave_complete = true;
-                        println!("Wave complete! {} enemies eliminated", wave_status.enemies_killed);
-                    }
+                let distance = impact.distance(enemy_transform.translation.truncate());
+                if let Some(splash_damage) = projectile_data.splash_damage_at(distance) {
+                    hits.push((enemy_entity, splash_damage * damage_multiplier, DamageKind::Splash));
                 }
-                
-                break; // Projectile can only hit one enemy
             }
+            spawn_explosion(&mut commands, effect_budget.as_deref_mut(), impact, projectile_data.splash_radius);
+            spawn_particles(&mut commands, impact, ParticleEmitter::explosion(projectile_data.splash_radius));
+        }
+
+        // Chain lightning jumps on to nearby enemies it hasn't just struck
+        if projectile_data.chain_targets > 0 {
+            let (candidates, positions): (Vec<Entity>, Vec<Vec2>) = enemies.iter()
+                .filter(|(entity, _, health, .., chained)| *entity != primary && !health.is_dead() && !*chained)
+                .map(|(entity, enemy_transform, ..)| (entity, enemy_transform.translation.truncate()))
+                .unzip();
+            let jumps = chain_jumps(primary_position, &positions, projectile_data.chain_targets, effective_damage);
+            let mut from = primary_position;
+            for (index, jump_damage) in jumps {
+                hits.push((candidates[index], jump_damage, DamageKind::Chain));
+                spawn_chain_bolt(&mut commands, effect_budget.as_deref_mut(), from, positions[index]);
+                from = positions[index];
+            }
+            for &(entity, ..) in &hits {
+                commands.entity(entity).try_insert(Chained::default());
+            }
+        }
+
+        for (enemy_entity, effective_damage, kind) in hits {
+            let Ok((_, enemy_transform, mut enemy_health, loot_table, enemy_type, _)) = enemies.get_mut(enemy_entity) else {
+                continue;
+            };
+            let killed = damage.apply(
+                &mut commands,
+                DamagedEnemy {
+                    entity: enemy_entity,
+                    position: enemy_transform.translation.truncate(),
+                    health: &mut *enemy_health,
+                    enemy_type,
+                    loot_table,
+                },
+                effective_damage,
+                projectile_data.tower_type,
+                kind,
+            );
+
+            // Survivors carry the shot's status effects; reapplying refreshes them
+            if !killed {
+                if projectile_data.slow > 0.0 {
+                    commands.entity(enemy_entity).try_insert(Slow::new(projectile_data.slow, SLOW_DURATION));
+                }
+                if projectile_data.burn_dps > 0.0 {
+                    commands.entity(enemy_entity).try_insert(Burn::new(projectile_data.burn_dps, BURN_DURATION, projectile_data.tower_type));
+                }
+            }
+        }
+    }
+}
+
+/// An enemy taking damage, as read from its components
+pub struct DamagedEnemy<'a> {
+    pub entity: Entity,
+    pub position: Vec2,
+    pub health: &'a mut Health,
+    pub enemy_type: Option<&'a EnemyType>,
+    pub loot_table: Option<&'a LootTable>,
+}
+
+/// Damage bookkeeping shared by projectile hits and damage over time: stats,
+/// events, kill rewards, loot and wave progress
+#[derive(SystemParam)]
+pub struct EnemyDamage<'w> {
+    economy: ResMut<'w, Economy>,
+    wave_status: ResMut<'w, WaveStatus>,
+    score: ResMut<'w, Score>,
+    kill_events: EventWriter<'w, EnemyKilledEvent>,
+    damage_events: EventWriter<'w, EnemyDamagedEvent>,
+    rng: Option<ResMut<'w, GameRng>>,
+    codex: Option<ResMut<'w, EnemyCodex>>,
+    ledger: Option<ResMut<'w, BountyLedger>>,
+    prestige: Option<Res<'w, RunPrestige>>,
+}
+
+impl EnemyDamage<'_> {
+    /// Damage an enemy, crediting the tower type. A kill pays out, may drop loot,
+    /// leaves the enemy dying and advances the wave. Returns whether it died.
+    pub fn apply(
+        &mut self,
+        commands: &mut Commands,
+        enemy: DamagedEnemy,
+        amount: f32,
+        tower_type: TowerType,
+        damage_kind: DamageKind,
+    ) -> bool {
+        // Apply damage to enemy (only the health actually removed counts towards stats)
+        let enemy_type = enemy.enemy_type.copied().unwrap_or_default();
+        let damage_dealt = amount.min(enemy.health.current);
+        let damage_kind = if damage_kind == DamageKind::Direct && damage_dealt >= enemy.health.max * CRITICAL_HIT_SHARE {
+            DamageKind::Critical
+        } else {
+            damage_kind
+        };
+        self.score.record_damage(damage_dealt);
+        if let Some(codex) = self.codex.as_deref_mut() {
+            codex.record_damage(enemy_type, damage_dealt);
+        }
+        enemy.health.take_damage(amount);
+        self.damage_events.write(EnemyDamagedEvent {
+            enemy: enemy.entity,
+            position: enemy.position,
+            amount: damage_dealt,
+            tower_type,
+            kind: damage_kind,
+        });
+
+        // Check if enemy died from damage
+        if !enemy.health.is_dead() {
+            return false;
+        }
+
+        // Award resources based on tower type (different towers give different rewards),
+        // scaled up for tougher enemy types
+        let reward_multiplier = enemy_type.reward_multiplier();
+        let money_reward = kill_reward(tower_type) * reward_multiplier;
+        
+        self.economy.money += money_reward;
+        self.economy.research_points += 1;
+        let points = self.prestige.as_ref().map_or(money_reward, |prestige| prestige.modifiers.scale_points(money_reward));
+        self.score.enemy_killed(points);
+        self.score.record_money_earned(money_reward);
+        if let Some(ledger) = self.ledger.as_deref_mut() {
+            ledger.record_kill(money_reward);
+        }
+        if let Some(milestone) = self.codex.as_deref_mut().and_then(|codex| codex.record_kill(enemy_type)) {
+            println!("Codex: {} {} kills, new lore unlocked", milestone, enemy_type.get_name());
+        }
+        
+        // Chance to drop a pickup where the enemy died
+        if let Some(loot_table) = enemy.loot_table {
+            let (drop_roll, pick_roll) = match self.rng.as_deref_mut() {
+                Some(rng) => (rng.roll(), rng.roll()),
+                None => (rand::random(), rand::random()),
+            };
+            spawn_loot_drop(commands, loot_table, enemy.position, drop_roll, pick_roll);
+        }
+        
+        self.kill_events.write(EnemyKilledEvent {
+            position: enemy.position,
+            tower_type,
+        });
+        
+        // Strip the dead enemy down to its visuals to play its death animation
+        commands
+            .entity(enemy.entity)
+            .retain::<(Sprite, Transform, GlobalTransform, Visibility, InheritedVisibility, ViewVisibility)>()
+            .insert(DyingEnemy::default());
+        
+        // Update wave progress
+        self.wave_status.enemies_killed += 1;
+        self.wave_status.enemies_remaining = self.wave_status.enemies_remaining.saturating_sub(1);
+        
+        // Check if wave is complete
+        if self.wave_status.enemies_remaining == 0 {
+            self.wave_status.wave_complete = true;
+            println!("Wave complete! {} enemies eliminated", self.wave_status.enemies_killed);
         }
+        true
     }
 }
 
-/// System 5: Game State Management - Handle win/lose conditions and wave progression
+/// Spawn a brief bolt of chain lightning between two enemies. Cosmetic, like explosions.
+fn spawn_chain_bolt(commands: &mut Commands, budget: Option<&mut EffectBudget>, from: Vec2, to: Vec2) {
+    if !budget.is_none_or(|budget| budget.admit(EffectCategory::Particle)) {
+        return;
+    }
+    let span = to - from;
+    commands.spawn((
+        Sprite {
+            color: Color::srgba(0.75, 0.5, 1.0, 0.9),
+            custom_size: Some(Vec2::new(span.length(), 2.0)),
+            ..default()
+        },
+        Transform::from_translation(((from + to) / 2.0).extend(2.0))
+            .with_rotation(Quat::from_rotation_z(span.to_angle())),
+        AlphaTween::new(
+            0.9,
+            0.0,
+            TweenProgress::new(CHAINED_DURATION, Easing::QuadIn).despawn_on_complete(),
+        ),
+        CosmeticEffect(EffectCategory::Particle),
+    ));
+}
+
+/// Draw a laser beam from the tower to where it struck, fading out over
+/// `BEAM_DURATION`. Purely cosmetic, so it is skipped when the effect budget is spent.
+fn spawn_beam(commands: &mut Commands, budget: Option<&mut EffectBudget>, from: Vec2, to: Vec2) {
+    if !budget.is_none_or(|budget| budget.admit(EffectCategory::Trail)) {
+        return;
+    }
+    let span = to - from;
+    commands.spawn((
+        Sprite {
+            color: Color::srgba(1.0, 0.2, 0.2, 0.9),
+            custom_size: Some(Vec2::new(span.length(), 3.0)),
+            ..default()
+        },
+        Transform::from_translation(((from + to) / 2.0).extend(2.0))
+            .with_rotation(Quat::from_rotation_z(span.to_angle())),
+        AlphaTween::new(
+            0.9,
+            0.0,
+            TweenProgress::new(BEAM_DURATION, Easing::QuadIn).despawn_on_complete(),
+        ),
+        CosmeticEffect(EffectCategory::Trail),
+    ));
+}
+
+/// Spawn a short-lived blast that grows to the splash radius and fades out.
+/// Purely cosmetic, so it is skipped when the effect budget is spent.
+fn spawn_explosion(commands: &mut Commands, budget: Option<&mut EffectBudget>, position: Vec2, radius: f32) {
+    if !budget.is_none_or(|budget| budget.admit(EffectCategory::Particle)) {
+        return;
+    }
+    commands.spawn((
+        Sprite {
+            color: Color::srgba(1.0, 0.6, 0.1, 0.6),
+            custom_size: Some(Vec2::splat(radius * 2.0)),
+            ..default()
+        },
+        Transform::from_translation(position.extend(2.0)).with_scale(Vec3::splat(0.3)),
+        ScaleTween::new(Vec3::splat(0.3), Vec3::ONE, TweenProgress::new(EXPLOSION_DURATION, Easing::QuadOut)),
+        AlphaTween::new(
+            0.6,
+            0.0,
+            TweenProgress::new(EXPLOSION_DURATION, Easing::QuadIn).despawn_on_complete(),
+        ),
+        CosmeticEffect(EffectCategory::Particle),
+        Explosion { radius },
+    ));
+}
+
+/// System 5: Game State Management - Handle win conditions and wave progression.
+/// Defeat comes from the base being destroyed (see `base_destroyed_system`).
 pub fn game_state_system(
-    mut commands: Commands,
     mut game_state: ResMut<GameState>,
     mut wave_status: ResMut<WaveStatus>,
-    mut wave_manager: ResMut<WaveManager>,
-    enemies: Query<(Entity, &Transform), With<Enemy>>,
-    enemy_path: Res<EnemyPath>,
+    mut wave_plan: ResMut<WavePlan>,
+    free_play: Option<Res<FreePlayRun>>,
+    settings: Option<Res<GameSettings>>,
 ) {
     // Skip all game logic if already in terminal state to prevent spam
     if matches!(*game_state, GameState::GameOver | GameState::Victory) {
         return;
     }
-    
-    // Check for enemies that have reached the end of the path
-    let mut enemies_to_remove = Vec::new();
-    let mut new_escapes = 0;
-    
-    for (enemy_entity, enemy_transform) in enemies.iter() {
-        let enemy_pos = enemy_transform.translation.truncate();
-        if let Some(path_end) = enemy_path.waypoints.last() {
-            if enemy_pos.distance(*path_end) < 32.0 {
-                enemies_to_remove.push(enemy_entity);
-                new_escapes += 1;
-            }
-        }
-    }
-    
-    // Remove enemies that reached the end
-    for enemy_entity in enemies_to_remove {
-        commands.entity(enemy_entity).despawn();
-    }
-    
-    // Update escape count
-    wave_status.enemies_escaped += new_escapes;
-    wave_status.enemies_remaining = wave_status.enemies_remaining.saturating_sub(new_escapes);
-    
-    if new_escapes > 0 {
-        println!("{} enemies escaped! Total escapes: {}", new_escapes, wave_status.enemies_escaped);
+
+    // Free play has no last wave; waves are started by the player until the base falls
+    if free_play.is_some_and(|run| run.active) {
+        return;
     }
     
+    // Endless runs never reach a last wave; they only end when the base falls
+    let (endless, victory_waves) = settings.map_or((false, DEFAULT_VICTORY_WAVES), |settings| {
+        (settings.run_mode == RunMode::Endless, settings.victory_waves)
+    });
+
     // Check win condition: Wave complete and no more waves
-    if wave_status.wave_complete && wave_manager.current_wave >= 3 { // 3 waves total
+    if !endless && wave_status.wave_complete && wave_plan.current_wave >= victory_waves {
         *game_state = GameState::Victory;
         println!("🎉 VICTORY! All waves defended successfully!");
         return;
     }
     
-    // Check lose condition: Too many enemies escaped  
-    if wave_status.enemies_escaped >= 10 {
-        *game_state = GameState::GameOver;
-        println!("💀 GAME OVER! {} enemies reached the end!", wave_status.enemies_escaped);
-        return;
-    }
-    
     // Auto-progress to next wave if current wave is complete
-    if wave_status.wave_complete && wave_manager.current_wave < 3 {
-        wave_manager.current_wave += 1;
-        wave_status.initialize_wave(wave_manager.enemies_in_wave);
-        println!("🚨 Wave {} incoming! Prepare your defenses!", wave_manager.current_wave);
+    if wave_status.wave_complete {
+        wave_plan.current_wave += 1;
+        wave_status.initialize_wave(wave_plan.enemies_in_wave());
+        println!("🚨 Wave {} incoming! Prepare your defenses!", wave_plan.current_wave);
     }
 }
\ No newline at end of file
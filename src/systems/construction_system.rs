@@ -0,0 +1,259 @@
+use bevy::prelude::*;
+use crate::components::{Constructing, PlacementGrace};
+use crate::resources::{AppState, CombatSet, Economy, GameSystemSet, ResourceCost, SimulationClock, TowerStats, TowerType};
+use crate::systems::combat_system::Target;
+use crate::systems::input_system::MouseInputState;
+use crate::systems::occupancy::OccupancyMap;
+use crate::systems::particles::{spawn_particles, ParticleEmitter};
+use crate::systems::tower_rendering::TowerVisualPart;
+use crate::systems::tower_ui::TowerSelectionState;
+
+/// Size of the scaffolding frame drawn over a tower under construction
+const SCAFFOLD_SIZE: f32 = 36.0;
+/// Width of the build-progress bar shown above the scaffolding
+const PROGRESS_BAR_WIDTH: f32 = 36.0;
+/// Right-click radius for cancelling a construction site
+const CANCEL_CLICK_RADIUS: f32 = 20.0;
+/// Radius of the countdown ring drawn around a tower in its placement grace window
+const GRACE_RING_RADIUS: f32 = 22.0;
+const GRACE_RING_COLOR: Color = Color::srgba(0.58, 0.88, 0.68, 0.8);
+
+/// Scaffolding sprite belonging to a tower under construction
+#[derive(Component)]
+pub struct ConstructionScaffold {
+    pub parent_tower: Entity,
+}
+
+/// Fill sprite of a tower's build-progress bar
+#[derive(Component)]
+pub struct BuildProgressBar {
+    pub parent_tower: Entity,
+}
+
+/// Put a freshly spawned tower into its construction phase: marks it with
+/// `Constructing` and spawns the scaffolding and build-progress bar.
+pub fn begin_tower_construction(
+    commands: &mut Commands,
+    tower_entity: Entity,
+    position: Vec2,
+    tower_type: TowerType,
+    paid_cost: ResourceCost,
+) {
+    commands
+        .entity(tower_entity)
+        .insert(Constructing::new(tower_type.get_build_time(), paid_cost));
+    spawn_particles(commands, position, ParticleEmitter::dust());
+
+    let beam_color = Color::srgb(0.55, 0.5, 0.4);
+    let half = SCAFFOLD_SIZE / 2.0;
+
+    // Outer frame beams (top, bottom, left, right)
+    let beams = [
+        (Vec2::new(0.0, half), Vec2::new(SCAFFOLD_SIZE, 3.0)),
+        (Vec2::new(0.0, -half), Vec2::new(SCAFFOLD_SIZE, 3.0)),
+        (Vec2::new(-half, 0.0), Vec2::new(3.0, SCAFFOLD_SIZE)),
+        (Vec2::new(half, 0.0), Vec2::new(3.0, SCAFFOLD_SIZE)),
+    ];
+    for (offset, size) in beams {
+        commands.spawn((
+            Sprite {
+                color: beam_color,
+                custom_size: Some(size),
+                ..default()
+            },
+            Transform::from_translation((position + offset).extend(0.4)),
+            ConstructionScaffold { parent_tower: tower_entity },
+        ));
+    }
+
+    // Diagonal cross braces
+    for angle in [std::f32::consts::FRAC_PI_4, -std::f32::consts::FRAC_PI_4] {
+        commands.spawn((
+            Sprite {
+                color: beam_color.with_alpha(0.7),
+                custom_size: Some(Vec2::new(SCAFFOLD_SIZE * std::f32::consts::SQRT_2, 2.0)),
+                ..default()
+            },
+            Transform::from_translation(position.extend(0.4))
+                .with_rotation(Quat::from_rotation_z(angle)),
+            ConstructionScaffold { parent_tower: tower_entity },
+        ));
+    }
+
+    // Build-progress bar background
+    let bar_position = position + Vec2::new(0.0, half + 8.0);
+    commands.spawn((
+        Sprite {
+            color: Color::srgb(0.15, 0.15, 0.15),
+            custom_size: Some(Vec2::new(PROGRESS_BAR_WIDTH, 5.0)),
+            ..default()
+        },
+        Transform::from_translation(bar_position.extend(0.5)),
+        ConstructionScaffold { parent_tower: tower_entity },
+    ));
+
+    // Build-progress bar fill (grows from the left edge)
+    commands.spawn((
+        Sprite {
+            color: Color::srgb(0.9, 0.75, 0.2),
+            custom_size: Some(Vec2::new(0.0, 5.0)),
+            ..default()
+        },
+        Transform::from_translation(
+            (bar_position - Vec2::new(PROGRESS_BAR_WIDTH / 2.0, 0.0)).extend(0.6)
+        ),
+        ConstructionScaffold { parent_tower: tower_entity },
+        BuildProgressBar { parent_tower: tower_entity },
+    ));
+}
+
+/// Resources returned for selling a tower: the full price while it is still
+/// being built or within its placement grace window, the sell value otherwise
+pub fn sell_refund(stats: &TowerStats, constructing: Option<&Constructing>, grace: Option<&PlacementGrace>) -> ResourceCost {
+    match (constructing, grace) {
+        (Some(constructing), _) => constructing.paid_cost.clone(),
+        (None, Some(grace)) => grace.paid_cost.clone(),
+        (None, None) => stats.sell_value(),
+    }
+}
+
+/// System to advance construction timers and activate finished towers.
+/// Finished towers start their placement grace window.
+pub fn construction_progress_system(
+    mut commands: Commands,
+    clock: Res<SimulationClock>,
+    mut constructing_query: Query<(Entity, &mut Constructing)>,
+) {
+    for (tower_entity, mut constructing) in constructing_query.iter_mut() {
+        constructing.build_timer.tick(clock.delta());
+
+        if constructing.is_complete() {
+            commands
+                .entity(tower_entity)
+                .remove::<Constructing>()
+                .insert(PlacementGrace::new(constructing.paid_cost.clone()));
+            println!("Tower {:?} construction complete", tower_entity);
+        }
+    }
+}
+
+/// System to end placement grace windows once they run out or the tower fires
+pub fn placement_grace_system(
+    mut commands: Commands,
+    clock: Res<SimulationClock>,
+    mut grace_query: Query<(Entity, &mut PlacementGrace, Option<&Target>)>,
+) {
+    for (tower_entity, mut grace, target) in grace_query.iter_mut() {
+        grace.timer.tick(clock.delta());
+
+        // Towers that haven't fired yet still have their initial shot time
+        let has_fired = target.is_some_and(|target| target.last_shot_time > 0.0);
+        if grace.timer.finished() || has_fired {
+            commands.entity(tower_entity).remove::<PlacementGrace>();
+        }
+    }
+}
+
+/// System to draw a shrinking countdown ring around towers in their grace window
+pub fn placement_grace_ring_system(
+    mut gizmos: Gizmos,
+    grace_query: Query<(&PlacementGrace, &Transform)>,
+) {
+    for (grace, transform) in grace_query.iter() {
+        gizmos.arc_2d(
+            Isometry2d::from_translation(transform.translation.truncate()),
+            std::f32::consts::TAU * grace.remaining(),
+            GRACE_RING_RADIUS,
+            GRACE_RING_COLOR,
+        );
+    }
+}
+
+/// System to update scaffolding, progress bars and tower pattern visibility
+pub fn construction_visual_system(
+    mut commands: Commands,
+    constructing_query: Query<&Constructing>,
+    scaffold_query: Query<(Entity, &ConstructionScaffold)>,
+    mut progress_bar_query: Query<(&BuildProgressBar, &mut Sprite, &mut Transform)>,
+    mut visual_parts: Query<(&TowerVisualPart, &mut Visibility)>,
+) {
+    // Fill progress bars from the left edge of the bar
+    for (bar, mut sprite, mut transform) in progress_bar_query.iter_mut() {
+        if let Ok(constructing) = constructing_query.get(bar.parent_tower) {
+            let previous_width = sprite.custom_size.map_or(0.0, |size| size.x);
+            let width = PROGRESS_BAR_WIDTH * constructing.progress();
+            sprite.custom_size = Some(Vec2::new(width, 5.0));
+            transform.translation.x += (width - previous_width) / 2.0;
+        }
+    }
+
+    // Remove scaffolding once its tower is finished, cancelled or destroyed
+    for (scaffold_entity, scaffold) in scaffold_query.iter() {
+        if !constructing_query.contains(scaffold.parent_tower) {
+            commands.entity(scaffold_entity).despawn();
+        }
+    }
+
+    // The tower pattern only shows once construction is done
+    for (visual_part, mut visibility) in visual_parts.iter_mut() {
+        let target = if constructing_query.contains(visual_part.parent_tower) {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+        visibility.set_if_neq(target);
+    }
+}
+
+/// System to cancel a construction site with right-click, refunding its full cost
+pub fn construction_cancel_system(
+    mut commands: Commands,
+    mouse_state: Res<MouseInputState>,
+    mut economy: ResMut<Economy>,
+    mut selection_state: ResMut<TowerSelectionState>,
+    mut occupancy: ResMut<OccupancyMap>,
+    constructing_query: Query<(Entity, &Constructing, &Transform)>,
+) {
+    if !mouse_state.right_clicked {
+        return;
+    }
+
+    let click_pos = mouse_state.world_position;
+    let clicked_site = constructing_query.iter().find(|(_, _, transform)| {
+        transform.translation.truncate().distance(click_pos) < CANCEL_CLICK_RADIUS
+    });
+
+    if let Some((tower_entity, constructing, _)) = clicked_site {
+        economy.refund(&constructing.paid_cost);
+        commands.entity(tower_entity).despawn();
+        occupancy.vacate(tower_entity);
+
+        if selection_state.selected_tower_entity == Some(tower_entity) {
+            selection_state.clear_selection();
+        }
+
+        println!("Cancelled construction of {:?}, refunded {:?}", tower_entity, constructing.paid_cost);
+    }
+}
+
+/// Plugin to add the tower construction phase
+pub struct ConstructionPlugin;
+
+impl Plugin for ConstructionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                construction_cancel_system,
+                construction_progress_system,
+                construction_visual_system,
+                placement_grace_system,
+                placement_grace_ring_system,
+            )
+                .chain()
+                .in_set(GameSystemSet::Gameplay)
+                .before(CombatSet::Targeting) // Finished towers can target this frame
+                .run_if(in_state(AppState::Playing)),
+        );
+    }
+}
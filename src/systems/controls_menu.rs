@@ -0,0 +1,231 @@
+use bevy::prelude::*;
+use crate::resources::{AppState, GameSystemSet};
+use crate::systems::input::{key_name, KeyBinding, KeyCapture, KeyRebind, InputMappingRegistry};
+use crate::systems::settings_menu::GameSettings;
+
+/// Key that cancels waiting for a new binding
+pub const CANCEL_REBIND_KEY: KeyCode = KeyCode::Escape;
+
+const ROW_BG: Color = Color::srgb(0.15, 0.20, 0.28);
+const ROW_CAPTURING_BG: Color = Color::srgb(0.25, 0.45, 0.65);
+const TEXT_PRIMARY: Color = Color::srgb(0.96, 0.96, 0.98);
+const TEXT_MUTED: Color = Color::srgb(0.58, 0.62, 0.68);
+const TEXT_CONFLICT: Color = Color::srgb(1.0, 0.72, 0.4);
+
+/// Container on the settings Controls tab that the binding rows are built into
+#[derive(Component)]
+pub struct ControlsList;
+
+/// Button rebinding one key of a handler
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct KeyBindingButton {
+    pub handler_id: String,
+    pub action_key: KeyCode,
+}
+
+/// Label of a binding row, e.g. "Toggle debug visualization: F1"
+pub fn binding_label(binding: &KeyBinding, capturing: bool) -> String {
+    if capturing {
+        format!("{}: press a key (Esc cancels)", binding.description)
+    } else {
+        format!("{}: {}", binding.description, key_name(binding.key))
+    }
+}
+
+/// Warning for a binding sharing its key with other handlers, e.g.
+/// "Also on F3: grid_mode". The higher priority handler gets the key first.
+pub fn conflict_note(registry: &InputMappingRegistry, binding: &KeyBinding) -> Option<String> {
+    let others: Vec<&str> = registry
+        .get_conflicts_for(&binding.handler_id, binding.key)
+        .into_iter()
+        .map(|conflict| {
+            if conflict.handler1 == binding.handler_id {
+                conflict.handler2.as_str()
+            } else {
+                conflict.handler1.as_str()
+            }
+        })
+        .filter(|other| *other != binding.handler_id)
+        .collect();
+    if others.is_empty() {
+        return None;
+    }
+    Some(format!("Also on {}: {}", key_name(binding.key), others.join(", ")))
+}
+
+/// Saved form of the registry's rebindings
+pub fn saved_rebinds(registry: &InputMappingRegistry) -> Vec<KeyRebind> {
+    registry
+        .get_rebinds()
+        .into_iter()
+        .map(|(handler_id, action_key, key)| KeyRebind::new(handler_id, action_key, key))
+        .collect()
+}
+
+/// System to move the registry's bindings to the ones in the settings whenever
+/// they change, including on load and on reset to defaults
+pub fn apply_key_rebinds_system(settings: Res<GameSettings>, registry: Option<ResMut<InputMappingRegistry>>) {
+    let Some(mut registry) = registry else {
+        return;
+    };
+    if !settings.is_changed() {
+        return;
+    }
+    if saved_rebinds(&registry) != settings.key_bindings {
+        registry.set_rebinds(settings.key_bindings.iter().filter_map(KeyRebind::parse));
+    }
+}
+
+/// System to start waiting for a new key when a binding row is clicked
+pub fn key_binding_button_system(
+    interaction_query: Query<(&Interaction, &KeyBindingButton), Changed<Interaction>>,
+    mut capture: ResMut<KeyCapture>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            capture.target = Some((button.handler_id.clone(), button.action_key));
+        }
+    }
+}
+
+/// System to bind the next key pressed while waiting for one, saving it to the settings
+pub fn key_capture_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut capture: ResMut<KeyCapture>,
+    registry: Option<ResMut<InputMappingRegistry>>,
+    mut settings: ResMut<GameSettings>,
+) {
+    let Some((handler_id, action_key)) = capture.target.clone() else {
+        return;
+    };
+    let Some(key) = keyboard_input.get_just_pressed().next().copied() else {
+        return;
+    };
+    capture.target = None;
+    if key == CANCEL_REBIND_KEY {
+        return;
+    }
+    let Some(mut registry) = registry else {
+        return;
+    };
+
+    match registry.rebind(&handler_id, action_key, key) {
+        Ok(()) => {
+            settings.key_bindings = saved_rebinds(&registry);
+            if let Some(binding) = registry
+                .get_key_bindings()
+                .into_iter()
+                .find(|binding| binding.handler_id == handler_id && binding.action_key == action_key)
+            {
+                if let Some(note) = conflict_note(&registry, &binding) {
+                    warn!("{} ({})", binding_label(&binding, false), note);
+                }
+            }
+        }
+        Err(error) => warn!("Could not rebind '{}': {}", handler_id, error),
+    }
+}
+
+/// System to rebuild the binding rows whenever the bindings or the key being waited for change
+pub fn controls_list_system(
+    mut commands: Commands,
+    registry: Option<Res<InputMappingRegistry>>,
+    capture: Res<KeyCapture>,
+    lists: Query<(Entity, Option<&Children>), With<ControlsList>>,
+    mut initialized: Local<bool>,
+) {
+    let Some(registry) = registry else {
+        return;
+    };
+    if *initialized && !registry.is_changed() && !capture.is_changed() {
+        return;
+    }
+    *initialized = true;
+
+    for (list, children) in lists.iter() {
+        if let Some(children) = children {
+            for &child in &children[..] {
+                commands.entity(child).despawn();
+            }
+        }
+        commands.entity(list).with_children(|parent| {
+            let bindings = registry.get_key_bindings();
+            if bindings.is_empty() {
+                parent.spawn((
+                    Text::new("No rebindable keys"),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(TEXT_MUTED),
+                ));
+            }
+            for binding in bindings {
+                let capturing = capture.target.as_ref() == Some(&(binding.handler_id.clone(), binding.action_key));
+                spawn_binding_row(parent, &binding, capturing, conflict_note(&registry, &binding));
+            }
+        });
+    }
+}
+
+fn spawn_binding_row(parent: &mut ChildSpawnerCommands, binding: &KeyBinding, capturing: bool, conflict: Option<String>) {
+    parent.spawn((
+        Button,
+        Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+            ..default()
+        },
+        BackgroundColor(if capturing { ROW_CAPTURING_BG } else { ROW_BG }),
+        BorderRadius::all(Val::Px(6.0)),
+        KeyBindingButton {
+            handler_id: binding.handler_id.clone(),
+            action_key: binding.action_key,
+        },
+    )).with_children(|row| {
+        row.spawn((
+            Text::new(binding_label(binding, capturing)),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(TEXT_PRIMARY),
+        ));
+        if let Some(conflict) = conflict {
+            row.spawn((
+                Text::new(conflict),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(TEXT_CONFLICT),
+            ));
+        }
+    });
+}
+
+/// System to stop waiting for a key when the settings menu closes
+pub fn cancel_key_capture_system(mut capture: ResMut<KeyCapture>) {
+    if capture.is_active() {
+        capture.target = None;
+    }
+}
+
+/// Plugin for the settings Controls tab, rebinding the input registry's keys
+pub struct ControlsMenuPlugin;
+
+impl Plugin for ControlsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<KeyCapture>()
+            .add_systems(Update, apply_key_rebinds_system.in_set(GameSystemSet::UI))
+            .add_systems(OnExit(AppState::Settings), cancel_key_capture_system)
+            .add_systems(
+                Update,
+                (key_capture_system, key_binding_button_system, controls_list_system)
+                    .chain()
+                    .in_set(GameSystemSet::Settings)
+                    .run_if(in_state(AppState::Settings)),
+            );
+    }
+}
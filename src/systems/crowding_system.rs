@@ -0,0 +1,172 @@
+use bevy::prelude::*;
+use crate::components::{Enemy, Health};
+use crate::resources::{AppState, CombatSet, EnemySpatialIndex, GameSystemSet};
+use crate::systems::input::{InputContext, InputRegistryAppExt};
+
+/// Scale lost per extra enemy sharing a cell
+pub const CROWD_SCALE_STEP: f32 = 0.05;
+/// Smallest scale a crowded enemy shrinks to
+pub const CROWD_MIN_SCALE: f32 = 0.7;
+/// Depth between enemies stacked in one cell
+pub const CROWD_Z_STEP: f32 = 0.01;
+/// Depth enemies are drawn at, matching the movement system
+const ENEMY_Z: f32 = 0.0;
+/// Count badges are drawn above enemies, health bars and projectiles
+const BADGE_Z: f32 = 8.0;
+/// Key toggling the count badges
+pub const CROWD_BADGE_KEY: KeyCode = KeyCode::KeyX;
+
+/// Settings for the crowd count badges
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrowdingConfig {
+    pub badges_enabled: bool,
+    /// A cell shows a badge once more than this many enemies occupy it
+    pub badge_threshold: usize,
+}
+
+impl Default for CrowdingConfig {
+    fn default() -> Self {
+        Self {
+            badges_enabled: true,
+            badge_threshold: 4,
+        }
+    }
+}
+
+impl CrowdingConfig {
+    pub fn shows_badge(&self, count: usize) -> bool {
+        self.badges_enabled && count > self.badge_threshold
+    }
+}
+
+/// "x8" badge over a crowded cell
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrowdBadge {
+    pub cell: IVec2,
+}
+
+/// Scale of each enemy in a cell holding `count` of them
+pub fn crowd_scale(count: usize) -> f32 {
+    (1.0 - CROWD_SCALE_STEP * count.saturating_sub(1) as f32).max(CROWD_MIN_SCALE)
+}
+
+/// Depth of the enemy at `rank` in its cell; the oldest enemy is drawn on top
+pub fn crowd_depth(rank: usize, count: usize) -> f32 {
+    ENEMY_Z + CROWD_Z_STEP * count.saturating_sub(rank + 1) as f32
+}
+
+pub fn crowd_badge_label(count: usize) -> String {
+    format!("x{}", count)
+}
+
+/// System to bucket living enemies by cell once they have moved and been hit
+pub fn rebuild_enemy_spatial_index_system(
+    mut index: ResMut<EnemySpatialIndex>,
+    enemies: Query<(Entity, &Transform, Option<&Health>), With<Enemy>>,
+) {
+    index.clear();
+    for (entity, transform, health) in enemies.iter() {
+        if health.is_some_and(Health::is_dead) {
+            continue;
+        }
+        index.insert(entity, transform.translation.truncate());
+    }
+    index.sort();
+}
+
+/// System to shrink and layer enemies stacked in one cell so the pile stays readable
+pub fn crowd_layout_system(index: Res<EnemySpatialIndex>, mut enemies: Query<&mut Transform, With<Enemy>>) {
+    for (_, cell_enemies) in index.occupied_cells() {
+        let count = cell_enemies.len();
+        let scale = Vec3::splat(crowd_scale(count));
+        for (rank, &entity) in cell_enemies.iter().enumerate() {
+            let Ok(mut transform) = enemies.get_mut(entity) else {
+                continue;
+            };
+            let depth = crowd_depth(rank, count);
+            if transform.scale != scale || transform.translation.z != depth {
+                transform.scale = scale;
+                transform.translation.z = depth;
+            }
+        }
+    }
+}
+
+/// System to keep one count badge over each crowded cell
+pub fn crowd_badge_system(
+    mut commands: Commands,
+    index: Res<EnemySpatialIndex>,
+    config: Res<CrowdingConfig>,
+    mut badges: Query<(Entity, &CrowdBadge, &mut Text2d)>,
+) {
+    let crowded: Vec<(IVec2, usize)> = index
+        .occupied_cells()
+        .map(|(cell, enemies)| (cell, enemies.len()))
+        .filter(|&(_, count)| config.shows_badge(count))
+        .collect();
+
+    // Update or remove existing badges
+    for (entity, badge, mut text) in badges.iter_mut() {
+        match crowded.iter().find(|(cell, _)| *cell == badge.cell) {
+            Some(&(_, count)) => {
+                let label = crowd_badge_label(count);
+                if text.0 != label {
+                    text.0 = label;
+                }
+            }
+            None => commands.entity(entity).despawn(),
+        }
+    }
+
+    // Spawn badges for newly crowded cells
+    for (cell, count) in crowded {
+        if badges.iter().any(|(_, badge, _)| badge.cell == cell) {
+            continue;
+        }
+        let position = EnemySpatialIndex::cell_center(cell) + Vec2::new(0.0, 16.0);
+        commands.spawn((
+            Text2d::new(crowd_badge_label(count)),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(Color::srgb(1.0, 0.85, 0.3)),
+            Transform::from_translation(position.extend(BADGE_Z)),
+            CrowdBadge { cell },
+        ));
+    }
+}
+
+/// System to toggle the count badges
+pub fn crowd_badge_toggle_system(keyboard_input: Res<ButtonInput<KeyCode>>, mut config: ResMut<CrowdingConfig>) {
+    if keyboard_input.just_pressed(CROWD_BADGE_KEY) {
+        config.badges_enabled = !config.badges_enabled;
+        info!("Crowd count badges: {}", if config.badges_enabled { "on" } else { "off" });
+    }
+}
+
+/// Plugin to keep piles of enemies at chokepoints readable
+pub struct CrowdingPlugin;
+
+impl Plugin for CrowdingPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<EnemySpatialIndex>()
+            .init_resource::<CrowdingConfig>()
+            .register_key_hint(CROWD_BADGE_KEY, "Toggle enemy count badges", InputContext::Game)
+            .add_systems(
+                Update,
+                crowd_badge_toggle_system
+                    .in_set(GameSystemSet::Input)
+                    .run_if(in_state(AppState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (rebuild_enemy_spatial_index_system, crowd_layout_system, crowd_badge_system)
+                    .chain()
+                    .in_set(GameSystemSet::Gameplay)
+                    .after(CombatSet::Collision)
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
@@ -0,0 +1,365 @@
+use bevy::prelude::*;
+use crate::resources::*;
+
+/// Resource to manage the balance tuning panel
+#[derive(Resource, Debug, Default)]
+pub struct BalancePanelState {
+    pub visible: bool,
+    /// Result of the last save, shown at the bottom of the panel
+    pub status: String,
+}
+
+/// Component marker for the balance tuning panel
+#[derive(Component)]
+pub struct BalancePanel;
+
+/// One editable stat of the balance config
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BalanceStat {
+    TowerDamage(TowerType),
+    TowerRange(TowerType),
+    TowerFireRate(TowerType),
+    EnemyBaseHealth,
+    EnemyHealthPerWave,
+    EnemyBaseSpeed,
+    EnemySpeedPerWave,
+}
+
+impl BalanceStat {
+    /// Every stat the panel lists, tower types first
+    pub fn all() -> Vec<BalanceStat> {
+        let mut stats: Vec<BalanceStat> = TowerType::ALL
+            .iter()
+            .flat_map(|tower_type| {
+                [
+                    BalanceStat::TowerDamage(*tower_type),
+                    BalanceStat::TowerRange(*tower_type),
+                    BalanceStat::TowerFireRate(*tower_type),
+                ]
+            })
+            .collect();
+        stats.extend([
+            BalanceStat::EnemyBaseHealth,
+            BalanceStat::EnemyHealthPerWave,
+            BalanceStat::EnemyBaseSpeed,
+            BalanceStat::EnemySpeedPerWave,
+        ]);
+        stats
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            BalanceStat::TowerDamage(tower_type) => format!("{} damage", tower_type.get_name()),
+            BalanceStat::TowerRange(tower_type) => format!("{} range", tower_type.get_name()),
+            BalanceStat::TowerFireRate(tower_type) => format!("{} fire rate", tower_type.get_name()),
+            BalanceStat::EnemyBaseHealth => "Enemy base health".to_string(),
+            BalanceStat::EnemyHealthPerWave => "Enemy health / wave".to_string(),
+            BalanceStat::EnemyBaseSpeed => "Enemy base speed".to_string(),
+            BalanceStat::EnemySpeedPerWave => "Enemy speed / wave".to_string(),
+        }
+    }
+
+    pub fn get(&self, config: &BalanceConfig) -> f32 {
+        match self {
+            BalanceStat::TowerDamage(tower_type) => config.tower(*tower_type).damage,
+            BalanceStat::TowerRange(tower_type) => config.tower(*tower_type).range,
+            BalanceStat::TowerFireRate(tower_type) => config.tower(*tower_type).fire_rate,
+            BalanceStat::EnemyBaseHealth => config.enemies.base_health,
+            BalanceStat::EnemyHealthPerWave => config.enemies.health_per_wave,
+            BalanceStat::EnemyBaseSpeed => config.enemies.base_speed,
+            BalanceStat::EnemySpeedPerWave => config.enemies.speed_per_wave,
+        }
+    }
+
+    /// Amount one press of the - or + button changes the stat by
+    pub fn step(&self) -> f32 {
+        match self {
+            BalanceStat::TowerDamage(_) => 1.0,
+            BalanceStat::TowerRange(_) => 5.0,
+            BalanceStat::TowerFireRate(_) => 0.1,
+            BalanceStat::EnemyBaseHealth | BalanceStat::EnemyHealthPerWave => 5.0,
+            BalanceStat::EnemyBaseSpeed => 5.0,
+            BalanceStat::EnemySpeedPerWave => 1.0,
+        }
+    }
+
+    /// Lowest value the panel allows; stats that scale others stay above zero
+    pub fn minimum(&self) -> f32 {
+        match self {
+            BalanceStat::TowerDamage(_) => 1.0,
+            BalanceStat::TowerRange(_) => 10.0,
+            BalanceStat::TowerFireRate(_) => 0.1,
+            BalanceStat::EnemyBaseHealth => 5.0,
+            BalanceStat::EnemyBaseSpeed => 5.0,
+            BalanceStat::EnemyHealthPerWave | BalanceStat::EnemySpeedPerWave => 0.0,
+        }
+    }
+
+    /// Move the stat by a number of steps, staying at or above its minimum
+    pub fn adjust(&self, config: &mut BalanceConfig, steps: f32) {
+        let value = (self.get(config) + self.step() * steps).max(self.minimum());
+        match self {
+            BalanceStat::TowerDamage(tower_type) => config.tower_mut(*tower_type).damage = value,
+            BalanceStat::TowerRange(tower_type) => config.tower_mut(*tower_type).range = value,
+            BalanceStat::TowerFireRate(tower_type) => config.tower_mut(*tower_type).fire_rate = value,
+            BalanceStat::EnemyBaseHealth => config.enemies.base_health = value,
+            BalanceStat::EnemyHealthPerWave => config.enemies.health_per_wave = value,
+            BalanceStat::EnemyBaseSpeed => config.enemies.base_speed = value,
+            BalanceStat::EnemySpeedPerWave => config.enemies.speed_per_wave = value,
+        }
+    }
+}
+
+/// Component for the - and + buttons next to a stat
+#[derive(Component)]
+pub struct BalanceStepButton {
+    pub stat: BalanceStat,
+    /// -1.0 or 1.0
+    pub steps: f32,
+}
+
+/// Component for the text showing a stat's current value
+#[derive(Component)]
+pub struct BalanceValueText(pub BalanceStat);
+
+/// Component for the text showing the last save result
+#[derive(Component)]
+pub struct BalanceStatusText;
+
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub enum BalanceActionButton {
+    Save,
+    /// Back to the built-in stats, without saving
+    ResetToBuiltIn,
+    Close,
+}
+
+/// Setup system for the balance tuning panel
+pub fn setup_balance_panel(mut commands: Commands, balance: Res<BalanceConfig>) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(10.0),
+                top: Val::Px(10.0),
+                width: Val::Px(340.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(10.0)),
+                display: Display::None, // Hidden by default
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.95)),
+            BorderColor(Color::srgb(0.3, 0.3, 0.3)),
+            BalancePanel,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("BALANCE TUNING ({})", BALANCE_FILE)),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.9, 1.0)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(8.0)),
+                    ..default()
+                },
+            ));
+
+            for stat in BalanceStat::all() {
+                create_stat_row(parent, stat, stat.get(&balance));
+            }
+
+            parent.spawn(Node {
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Row,
+                justify_content: JustifyContent::SpaceBetween,
+                margin: UiRect::top(Val::Px(8.0)),
+                ..default()
+            }).with_children(|row| {
+                create_action_button(row, BalanceActionButton::Save, "SAVE", Color::srgb(0.3, 0.6, 0.3));
+                create_action_button(row, BalanceActionButton::ResetToBuiltIn, "RESET", Color::srgb(0.6, 0.2, 0.2));
+                create_action_button(row, BalanceActionButton::Close, "CLOSE", Color::srgb(0.3, 0.3, 0.3));
+            });
+
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 10.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.6)),
+                Node {
+                    margin: UiRect::top(Val::Px(6.0)),
+                    ..default()
+                },
+                BalanceStatusText,
+            ));
+        });
+}
+
+/// Helper to create a stat row: label, value and step buttons
+fn create_stat_row(parent: &mut ChildSpawnerCommands, stat: BalanceStat, value: f32) {
+    parent.spawn(Node {
+        width: Val::Percent(100.0),
+        flex_direction: FlexDirection::Row,
+        align_items: AlignItems::Center,
+        margin: UiRect::bottom(Val::Px(2.0)),
+        ..default()
+    }).with_children(|row| {
+        row.spawn((
+            Text::new(stat.label()),
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.9, 0.9, 0.9)),
+            Node {
+                width: Val::Px(190.0),
+                ..default()
+            },
+        ));
+
+        create_step_button(row, stat, "-", -1.0);
+        row.spawn((
+            Text::new(format!("{:.2}", value)),
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            Node {
+                width: Val::Px(60.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BalanceValueText(stat),
+        ));
+        create_step_button(row, stat, "+", 1.0);
+    });
+}
+
+fn create_step_button(parent: &mut ChildSpawnerCommands, stat: BalanceStat, label: &str, steps: f32) {
+    parent.spawn((
+        Button,
+        Node {
+            width: Val::Px(24.0),
+            height: Val::Px(18.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.3, 0.3, 0.5)),
+        BalanceStepButton { stat, steps },
+    )).with_children(|button| {
+        button.spawn((
+            Text::new(label),
+            TextFont {
+                font_size: 11.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+/// Helper to create one of the save/reset/close buttons
+fn create_action_button(parent: &mut ChildSpawnerCommands, action: BalanceActionButton, label: &str, color: Color) {
+    parent.spawn((
+        Button,
+        Node {
+            width: Val::Percent(32.0),
+            height: Val::Px(26.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(color),
+        action,
+    )).with_children(|button| {
+        button.spawn((
+            Text::new(label),
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+/// System to show or hide the balance panel
+pub fn update_balance_panel_visibility(
+    panel_state: Res<BalancePanelState>,
+    mut panel_query: Query<&mut Node, With<BalancePanel>>,
+) {
+    if panel_state.is_changed() {
+        for mut node in &mut panel_query {
+            node.display = if panel_state.visible { Display::Flex } else { Display::None };
+        }
+    }
+}
+
+/// System to handle the - and + buttons; the balance systems apply the change live
+pub fn handle_balance_step_buttons(
+    interaction_query: Query<(&Interaction, &BalanceStepButton), (Changed<Interaction>, With<Button>)>,
+    mut balance: ResMut<BalanceConfig>,
+    mut mouse_input_state: ResMut<crate::systems::input_system::MouseInputState>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            // Consume the mouse click to prevent pass-through to game world
+            mouse_input_state.left_clicked = false;
+            button.stat.adjust(&mut balance, button.steps);
+        }
+    }
+}
+
+/// System to handle the save, reset and close buttons
+pub fn handle_balance_action_buttons(
+    interaction_query: Query<(&Interaction, &BalanceActionButton), (Changed<Interaction>, With<Button>)>,
+    mut balance: ResMut<BalanceConfig>,
+    mut panel_state: ResMut<BalancePanelState>,
+    mut mouse_input_state: ResMut<crate::systems::input_system::MouseInputState>,
+) {
+    for (interaction, action) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        mouse_input_state.left_clicked = false;
+
+        match action {
+            BalanceActionButton::Save => {
+                panel_state.status = match balance.save() {
+                    Ok(()) => format!("Saved to {}", BALANCE_FILE),
+                    Err(error) => format!("Save failed: {}", error),
+                };
+                println!("Balance: {}", panel_state.status);
+            }
+            BalanceActionButton::ResetToBuiltIn => {
+                *balance = BalanceConfig::default();
+                panel_state.status = "Reset to built-in stats (not saved)".to_string();
+            }
+            BalanceActionButton::Close => panel_state.visible = false,
+        }
+    }
+}
+
+/// System to refresh the value and status texts
+pub fn update_balance_panel_texts(
+    balance: Res<BalanceConfig>,
+    panel_state: Res<BalancePanelState>,
+    mut value_texts: Query<(&mut Text, &BalanceValueText), Without<BalanceStatusText>>,
+    mut status_texts: Query<&mut Text, With<BalanceStatusText>>,
+) {
+    if balance.is_changed() {
+        for (mut text, value_text) in &mut value_texts {
+            **text = format!("{:.2}", value_text.0.get(&balance));
+        }
+    }
+    if panel_state.is_changed() {
+        for mut text in &mut status_texts {
+            **text = panel_state.status.clone();
+        }
+    }
+}
@@ -3,6 +3,10 @@ use crate::resources::*;
 use crate::components::*;
 use crate::systems::combat_system::{WaveStatus, Target};
 use super::cheat_menu::*;
+use super::balance_panel::BalancePanelState;
+use super::spawn_timeline::SpawnTimelineState;
+use super::wave_jump::JumpToWaveEvent;
+use crate::systems::stress_test_system::StartStressTestEvent;
 
 /// System to handle cheat button interactions
 pub fn handle_cheat_button_interactions(
@@ -13,7 +17,11 @@ pub fn handle_cheat_button_interactions(
     >,
     mut economy: ResMut<Economy>,
     mut cheat_state: ResMut<CheatMenuState>,
-    mut wave_manager: ResMut<WaveManager>,
+    mut balance_panel: ResMut<BalancePanelState>,
+    mut spawn_timeline: ResMut<SpawnTimelineState>,
+    mut stress_tests: EventWriter<StartStressTestEvent>,
+    mut wave_jumps: EventWriter<JumpToWaveEvent>,
+    (mut wave_plan, mut wave_runtime): (ResMut<WavePlan>, ResMut<WaveRuntime>),
     mut wave_status: ResMut<WaveStatus>,
     mut game_state: ResMut<GameState>,
     enemy_query: Query<Entity, With<Enemy>>,
@@ -94,14 +102,10 @@ pub fn handle_cheat_button_interactions(
                     
                     // Game state cheats
                     CheatButtonType::NextWave => {
-                        // Clear all current enemies to force wave completion
-                        for entity in enemy_query.iter() {
-                            commands.entity(entity).despawn();
-                        }
-                        wave_status.enemies_remaining = 0;
-                        wave_status.wave_complete = true;
-                        wave_manager.current_wave += 1;
-                        println!("Cheat: Skipped to next wave: {}", wave_manager.current_wave);
+                        // Replace the current wave with the scaled composition of the next one
+                        let wave = wave_plan.next_wave();
+                        wave_jumps.write(JumpToWaveEvent { wave });
+                        println!("Cheat: Skipped to next wave: {}", wave);
                     }
                     CheatButtonType::InstantWin => {
                         // Clear all enemies and set game to victory
@@ -130,9 +134,9 @@ pub fn handle_cheat_button_interactions(
                         *economy = default_economy;
                         
                         // Reset wave manager
-                        wave_manager.current_wave = 0;
-                        wave_manager.enemies_in_wave = 0;
-                        wave_manager.enemies_spawned = 0;
+                        wave_plan.current_wave = 0;
+                        wave_plan.composition = WaveComposition::default();
+                        wave_runtime.enemies_spawned = 0;
                         
                         // Reset wave status
                         wave_status.enemies_remaining = 0;
@@ -154,6 +158,18 @@ pub fn handle_cheat_button_interactions(
                         
                         // Update button text - we'll handle this in a separate system for clarity
                     }
+                    CheatButtonType::OpenBalanceTuning => {
+                        balance_panel.visible = !balance_panel.visible;
+                        println!("Cheat: Balance tuning panel {}", if balance_panel.visible { "opened" } else { "closed" });
+                    }
+                    CheatButtonType::ToggleSpawnTimeline => {
+                        spawn_timeline.visible = !spawn_timeline.visible;
+                        println!("Cheat: Spawn timeline {}", if spawn_timeline.visible { "opened" } else { "closed" });
+                    }
+                    CheatButtonType::RunStressTest => {
+                        stress_tests.write(StartStressTestEvent);
+                        println!("Cheat: Stress test started, samples go to {}", STRESS_TEST_CSV);
+                    }
                 }
                 
                 // Visual feedback for button press
@@ -333,6 +349,9 @@ fn get_button_hover_color(button_type: CheatButtonType) -> Color {
         CheatButtonType::NextWave => Color::srgb(0.4, 0.4, 0.8),
         CheatButtonType::InstantWin => Color::srgb(0.4, 0.8, 0.4),
         CheatButtonType::ToggleGodMode => Color::srgb(0.8, 0.8, 0.4),
+        CheatButtonType::OpenBalanceTuning => Color::srgb(0.4, 0.7, 0.8),
+        CheatButtonType::ToggleSpawnTimeline => Color::srgb(0.7, 0.5, 0.8),
+        CheatButtonType::RunStressTest => Color::srgb(0.8, 0.6, 0.4),
     }
 }
 
@@ -353,5 +372,8 @@ fn get_button_normal_color(button_type: CheatButtonType) -> Color {
         CheatButtonType::NextWave => Color::srgb(0.3, 0.3, 0.7),
         CheatButtonType::InstantWin => Color::srgb(0.3, 0.7, 0.3),
         CheatButtonType::ToggleGodMode => Color::srgb(0.7, 0.7, 0.3),
+        CheatButtonType::OpenBalanceTuning => Color::srgb(0.3, 0.6, 0.7),
+        CheatButtonType::ToggleSpawnTimeline => Color::srgb(0.6, 0.4, 0.7),
+        CheatButtonType::RunStressTest => Color::srgb(0.7, 0.5, 0.3),
     }
 }
\ No newline at end of file
@@ -2,6 +2,7 @@ use bevy::prelude::*;
 use crate::resources::*;
 use crate::components::*;
 use super::components::DebugUIState;
+use super::wave_jump::create_wave_jump_row;
 use crate::systems::combat_system::{WaveStatus, Target};
 
 /// Resource to manage cheat menu state
@@ -88,6 +89,9 @@ pub enum CheatButtonType {
     InstantWin,
     ResetGame,
     ToggleGodMode,
+    OpenBalanceTuning,
+    ToggleSpawnTimeline,
+    RunStressTest,
 }
 
 /// Component for cheat sliders
@@ -183,13 +187,13 @@ pub fn setup_cheat_menu(mut commands: Commands) {
                 left: Val::Percent(50.0),
                 top: Val::Percent(50.0),
                 width: Val::Px(400.0),
-                height: Val::Px(600.0),
+                height: Val::Px(680.0),
                 flex_direction: FlexDirection::Column,
                 padding: UiRect::all(Val::Px(15.0)),
                 display: Display::None, // Hidden by default
                 margin: UiRect {
                     left: Val::Px(-200.0), // Center horizontally
-                    top: Val::Px(-300.0),  // Center vertically
+                    top: Val::Px(-340.0),  // Center vertically
                     ..default()
                 },
                 ..default()
@@ -483,8 +487,13 @@ fn create_game_state_section(parent: &mut ChildSpawnerCommands) {
         (CheatButtonType::InstantWin, "INSTANT WIN", Color::srgb(0.3, 0.7, 0.3)),
         (CheatButtonType::ResetGame, "RESET GAME", Color::srgb(0.7, 0.3, 0.3)),
         (CheatButtonType::ToggleGodMode, "GOD MODE: OFF", Color::srgb(0.7, 0.7, 0.3)),
+        (CheatButtonType::OpenBalanceTuning, "BALANCE TUNING", Color::srgb(0.3, 0.6, 0.7)),
+        (CheatButtonType::ToggleSpawnTimeline, "SPAWN TIMELINE", Color::srgb(0.6, 0.4, 0.7)),
+        (CheatButtonType::RunStressTest, "STRESS TEST", Color::srgb(0.7, 0.5, 0.3)),
     ];
 
+    create_wave_jump_row(parent);
+
     parent.spawn((
         Node {
             width: Val::Percent(100.0),
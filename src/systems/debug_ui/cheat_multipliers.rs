@@ -121,15 +121,16 @@ pub fn maintain_god_mode_system(
 /// System to handle instant enemy spawning when spawn rate is very high
 pub fn enhanced_enemy_spawn_system(
     multipliers: Res<CheatMultipliers>,
-    mut wave_manager: ResMut<WaveManager>,
+    wave_plan: Res<WavePlan>,
+    mut wave_runtime: ResMut<WaveRuntime>,
     cheat_state: Res<CheatMenuState>,
-    time: Res<Time>,
+    clock: Res<SimulationClock>,
 ) {
     // If enemy speed multiplier is very high, increase spawn rate proportionally
     if multipliers.enemy_speed > 3.0 && cheat_state.visible {
         let speed_boost = multipliers.enemy_speed - 1.0;
-        // Reset spawn timer to speed up enemy spawning
-        wave_manager.spawn_timer.tick(std::time::Duration::from_secs_f32(time.delta_secs() * speed_boost));
+        // Extra ticks feed the spawn queue, which the spawning system drains at a capped pace
+        wave_runtime.tick_spawn_timer(&wave_plan, std::time::Duration::from_secs_f32(clock.delta_secs() * speed_boost));
     }
 }
 
@@ -120,6 +120,16 @@ pub struct PerformanceMetrics {
     pub frame_time_ms: f32,
     pub entity_count: usize,
     pub path_generation_time_ms: f32,
+    pub live_enemies: u32,
+    pub max_live_enemies: u32,
+    pub spawn_queue_length: u32,
+    /// Map archetype, obstacle coverage and build-area summary of the current map
+    pub map_summary: String,
+    /// Share of cosmetic effects being spawned, from the effect budget
+    pub effect_density: f32,
+    pub live_effects: usize,
+    /// State of the timeline profiler capture
+    pub profiler_status: String,
     pub last_update_time: f32,
 }
 
@@ -130,6 +140,13 @@ impl Default for PerformanceMetrics {
             frame_time_ms: 16.67,
             entity_count: 0,
             path_generation_time_ms: 0.0,
+            live_enemies: 0,
+            max_live_enemies: 0,
+            spawn_queue_length: 0,
+            map_summary: String::new(),
+            effect_density: 1.0,
+            live_effects: 0,
+            profiler_status: String::new(),
             last_update_time: 0.0,
         }
     }
@@ -151,6 +168,11 @@ pub enum MetricType {
     FrameTime,
     EntityCount,
     PathGenTime,
+    EnemyCap,
+    SpawnQueue,
+    MapLayout,
+    EffectBudget,
+    Profiler,
 }
 
 /// Component marker for action buttons
@@ -163,6 +185,8 @@ pub struct ActionButton {
 pub enum ActionType {
     ResetGame,
     RandomizeMap,
+    CycleMapArchetype,
+    ToggleVoidLake,
     SaveState,
     LoadState,
 }
\ No newline at end of file
@@ -2,6 +2,9 @@ use bevy::prelude::*;
 use crate::components::*;
 use crate::resources::*;
 use crate::systems::debug_visualization::DebugVisualizationState;
+use crate::systems::path_generation::{current_level_archetype, set_level_archetype, LevelConfig};
+use crate::systems::results_screen::RestartRunEvent;
+use crate::systems::save_load::SaveLoadRequest;
 use crate::systems::unified_grid::UnifiedGridSystem;
 use super::components::*;
 
@@ -210,7 +213,7 @@ pub fn update_enemy_path_from_ui(
 pub fn update_spawn_rate_from_ui(
     mut ui_state: ResMut<DebugUIState>,
     debug_state: Res<crate::systems::debug_visualization::DebugVisualizationState>,
-    _wave_manager: ResMut<crate::resources::WaveManager>,
+    _wave_runtime: ResMut<crate::resources::WaveRuntime>,
 ) {
     // Only update if UI state has changed and debug is enabled
     if ui_state.is_changed() && debug_state.enabled {
@@ -223,7 +226,7 @@ pub fn update_spawn_rate_from_ui(
         }
         
         // Note: Wave manager spawn rate update temporarily disabled
-        // wave_manager.set_spawn_rate(ui_state.enemy_spawn_rate);
+        // wave_runtime.set_spawn_rate(ui_state.enemy_spawn_rate);
     }
 }
 
@@ -231,7 +234,8 @@ pub fn update_spawn_rate_from_ui(
 pub fn handle_debug_keyboard_shortcuts(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     mut ui_state: ResMut<DebugUIState>,
-    mut wave_manager: ResMut<WaveManager>,
+    mut wave_plan: ResMut<WavePlan>,
+    mut wave_runtime: ResMut<WaveRuntime>,
     mut economy: ResMut<Economy>,
     mut wave_status: ResMut<crate::systems::combat_system::WaveStatus>,
     mut game_state: ResMut<GameState>,
@@ -256,9 +260,9 @@ pub fn handle_debug_keyboard_shortcuts(
         }
         
         // Reset resources
-        wave_manager.current_wave = 0;
-        wave_manager.enemies_in_wave = 0;
-        wave_manager.enemies_spawned = 0;
+        wave_plan.current_wave = 0;
+        wave_plan.composition = WaveComposition::default();
+        wave_runtime.enemies_spawned = 0;
         
         economy.money = 100;
         economy.research_points = 0;
@@ -328,7 +332,8 @@ pub fn handle_action_buttons(
         (Changed<Interaction>, With<Button>),
     >,
     mut ui_state: ResMut<DebugUIState>,
-    mut wave_manager: ResMut<WaveManager>,
+    mut wave_plan: ResMut<WavePlan>,
+    mut wave_runtime: ResMut<WaveRuntime>,
     mut economy: ResMut<Economy>,
     mut wave_status: ResMut<crate::systems::combat_system::WaveStatus>,
     mut game_state: ResMut<GameState>,
@@ -337,6 +342,8 @@ pub fn handle_action_buttons(
     tower_query: Query<Entity, With<TowerStats>>,
     _path_line_query: Query<Entity, With<GamePathLine>>,
     _enemy_path: ResMut<EnemyPath>,
+    (mut restart_events, mut level_config): (EventWriter<RestartRunEvent>, ResMut<LevelConfig>),
+    mut save_load_events: EventWriter<SaveLoadRequest>,
     // CRITICAL FIX: Add mouse input state to consume clicks and prevent pass-through
     mut mouse_input_state: ResMut<crate::systems::input_system::MouseInputState>,
 ) {
@@ -366,9 +373,9 @@ pub fn handle_action_buttons(
                         }
                         
                         // Reset resources
-                        wave_manager.current_wave = 0;
-                        wave_manager.enemies_in_wave = 0;
-                        wave_manager.enemies_spawned = 0;
+                        wave_plan.current_wave = 0;
+                        wave_plan.composition = WaveComposition::default();
+                        wave_runtime.enemies_spawned = 0;
                         
                         economy.money = 100;
                         economy.research_points = 0;
@@ -395,15 +402,27 @@ pub fn handle_action_buttons(
                         
                         println!("Map randomized with obstacle density: {:.2}", ui_state.current_obstacle_density);
                     },
+                    ActionType::CycleMapArchetype => {
+                        // Switch obstacle layout and restart the run on the same seed
+                        let archetype = current_level_archetype().next();
+                        set_level_archetype(archetype);
+                        restart_events.write(RestartRunEvent { new_seed: false });
+                        
+                        println!("Map archetype: {} ({})", archetype.get_name(), archetype.get_description());
+                    },
+                    ActionType::ToggleVoidLake => {
+                        // Carve (or drop) a void lake and restart the run on the same seed
+                        let void_lake = !level_config.void_lake;
+                        level_config.void_lake = void_lake;
+                        restart_events.write(RestartRunEvent { new_seed: false });
+                        
+                        println!("Void lake: {}", if void_lake { "on" } else { "off" });
+                    },
                     ActionType::SaveState => {
-                        println!("Saving game state... (Feature not yet implemented)");
-                        // TODO: Implement save functionality
-                        // Could save to file: wave progress, economy, tower positions, etc.
+                        save_load_events.write(SaveLoadRequest::Save);
                     },
                     ActionType::LoadState => {
-                        println!("Loading game state... (Feature not yet implemented)");
-                        // TODO: Implement load functionality
-                        // Could load from file: restore game state
+                        save_load_events.write(SaveLoadRequest::Load);
                     },
                 }
             },
@@ -412,6 +431,8 @@ pub fn handle_action_buttons(
                 let hover_color = match action_button.action_type {
                     ActionType::ResetGame => Color::srgb(1.0, 0.4, 0.4),
                     ActionType::RandomizeMap => Color::srgb(0.4, 0.7, 1.0),
+                    ActionType::CycleMapArchetype => Color::srgb(0.7, 0.5, 1.0),
+                    ActionType::ToggleVoidLake => Color::srgb(0.3, 0.5, 0.9),
                     ActionType::SaveState => Color::srgb(0.4, 1.0, 0.4),
                     ActionType::LoadState => Color::srgb(1.0, 1.0, 0.4),
                 };
@@ -422,6 +443,8 @@ pub fn handle_action_buttons(
                 let normal_color = match action_button.action_type {
                     ActionType::ResetGame => Color::srgb(0.8, 0.3, 0.3),
                     ActionType::RandomizeMap => Color::srgb(0.3, 0.6, 0.8),
+                    ActionType::CycleMapArchetype => Color::srgb(0.5, 0.3, 0.8),
+                    ActionType::ToggleVoidLake => Color::srgb(0.1, 0.3, 0.7),
                     ActionType::SaveState => Color::srgb(0.3, 0.8, 0.3),
                     ActionType::LoadState => Color::srgb(0.8, 0.8, 0.3),
                 };
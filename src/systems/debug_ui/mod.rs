@@ -8,6 +8,10 @@ pub mod setup;
 pub mod cheat_menu;
 pub mod cheat_interactions;
 pub mod cheat_multipliers;
+pub mod balance_panel;
+pub mod spawn_timeline;
+pub mod wave_jump;
+pub mod profiler;
 
 // Re-export the main plugin for external use
 pub use plugin::DebugUIPlugin;
@@ -15,6 +19,10 @@ pub use plugin::DebugUIPlugin;
 // Re-export key components that other systems might need
 pub use components::{DebugUIState, DebugUIPanel};
 pub use cheat_menu::{CheatMenuState, CheatMultipliers, CheatMenuPanel};
+pub use balance_panel::{BalancePanelState, BalancePanel};
+pub use spawn_timeline::{SpawnTimelineState, SpawnTimelinePanel};
+pub use wave_jump::{WaveJumpState, JumpToWaveEvent};
+pub use profiler::{TimelineProfiler, TimelineProfilerPlugin};
 
 // Re-export key functions with standardized names
 pub use interactions::f2_debug_ui_panel_toggle;
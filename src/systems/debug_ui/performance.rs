@@ -1,11 +1,20 @@
 use bevy::prelude::*;
+use crate::components::Enemy;
+use crate::resources::{EffectBudget, WaveRuntime};
+use crate::systems::obstacle_rendering::ObstacleGrid;
 use super::components::*;
+use super::profiler::TimelineProfiler;
 
 /// System to update performance metrics
 pub fn update_performance_metrics(
     mut metrics: ResMut<PerformanceMetrics>,
     time: Res<Time>,
     entities: Query<Entity>,
+    enemies: Query<(), With<Enemy>>,
+    wave_runtime: Res<WaveRuntime>,
+    obstacle_grid: Res<ObstacleGrid>,
+    effect_budget: Option<Res<EffectBudget>>,
+    profiler: Option<Res<TimelineProfiler>>,
 ) {
     // Calculate FPS and frame time
     let delta_time = time.delta_secs();
@@ -19,6 +28,32 @@ pub fn update_performance_metrics(
     // Count entities
     metrics.entity_count = entities.iter().count();
     
+    // Spawn safeguard status
+    metrics.live_enemies = enemies.iter().count() as u32;
+    metrics.max_live_enemies = wave_runtime.max_live_enemies;
+    metrics.spawn_queue_length = wave_runtime.pending_spawns;
+    
+    // Map layout summary, rebuilt only when the map is regenerated
+    if obstacle_grid.is_changed() {
+        let analysis = &obstacle_grid.analysis;
+        metrics.map_summary = format!(
+            "{} | {:.0}% blocked | {} build areas",
+            analysis.archetype.get_name(),
+            analysis.obstacle_coverage * 100.0,
+            analysis.buildable_regions,
+        );
+    }
+    
+    // Cosmetic effect load
+    if let Some(effect_budget) = effect_budget {
+        metrics.effect_density = effect_budget.density;
+        metrics.live_effects = effect_budget.total_live();
+    }
+
+    if let Some(profiler) = profiler {
+        metrics.profiler_status = profiler.status_text();
+    }
+    
     // Update timestamp
     metrics.last_update_time = time.elapsed_secs();
 }
@@ -36,6 +71,11 @@ pub fn update_performance_display(
                 MetricType::FrameTime => format!("Frame Time: {:.1}ms", metrics.frame_time_ms),
                 MetricType::EntityCount => format!("Entities: {}", metrics.entity_count),
                 MetricType::PathGenTime => format!("Path Gen: {:.1}ms", metrics.path_generation_time_ms),
+                MetricType::EnemyCap => format!("Enemies: {}/{}", metrics.live_enemies, metrics.max_live_enemies),
+                MetricType::SpawnQueue => format!("Spawn Queue: {}", metrics.spawn_queue_length),
+                MetricType::MapLayout => format!("Map: {}", metrics.map_summary),
+                MetricType::EffectBudget => format!("Effects: {} at {:.0}% density", metrics.live_effects, metrics.effect_density * 100.0),
+                MetricType::Profiler => format!("Profiler: {}", metrics.profiler_status),
             };
             **text = display_text;
         }
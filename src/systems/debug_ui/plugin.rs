@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use crate::resources::BalanceConfig;
 use super::components::{DebugUIState, SliderDragState, PerformanceMetrics};
 use super::setup::setup_debug_ui;
 use super::interactions::{
@@ -10,6 +11,10 @@ use super::interactions::{
 use super::performance::{update_performance_metrics, update_performance_display};
 use super::cheat_menu::{CheatMenuState, CheatMultipliers, CheatSliderDragState, setup_cheat_menu, f9_cheat_menu_toggle, update_cheat_menu_visibility};
 use super::cheat_interactions::{handle_cheat_button_interactions, handle_cheat_slider_interactions, update_cheat_slider_values, update_god_mode_button_text};
+use super::balance_panel::{BalancePanelState, setup_balance_panel, update_balance_panel_visibility, handle_balance_step_buttons, handle_balance_action_buttons, update_balance_panel_texts};
+use super::spawn_timeline::{SpawnTimelineState, setup_spawn_timeline, update_spawn_timeline_visibility, rebuild_spawn_timeline_ticks, update_spawn_timeline_display, handle_spawn_timeline_buttons, handle_spawn_timeline_tick_clicks};
+use super::profiler::TimelineProfilerPlugin;
+use super::wave_jump::{WaveJumpState, JumpToWaveEvent, handle_wave_jump_buttons, wave_jump_keyboard_system, update_wave_jump_preview, apply_wave_jump_system};
 use super::cheat_multipliers::{apply_tower_multipliers_system, apply_enemy_multipliers_system, apply_god_mode_system, maintain_god_mode_system, validate_enemy_stats_system, validate_tower_stats_system, cheat_visual_feedback_system, reset_visual_effects_system, handle_extreme_fire_rates_system, handle_extreme_damage_system, enhanced_enemy_spawn_system};
 
 /// Plugin for interactive debug UI controls
@@ -28,8 +33,22 @@ impl Plugin for DebugUIPlugin {
             .init_resource::<CheatMultipliers>()
             .init_resource::<CheatSliderDragState>()
             
+            // Balance tuning resources
+            .init_resource::<BalancePanelState>()
+            .init_resource::<BalanceConfig>()
+            
+            // Spawn timeline resources
+            .init_resource::<SpawnTimelineState>()
+            
+            // Wave jump resources
+            .init_resource::<WaveJumpState>()
+            .add_event::<JumpToWaveEvent>()
+            
+            // Timeline profiler (F10)
+            .add_plugins(TimelineProfilerPlugin)
+            
             // Setup systems
-            .add_systems(Startup, (setup_debug_ui, setup_cheat_menu))
+            .add_systems(Startup, (setup_debug_ui, setup_cheat_menu, setup_balance_panel, setup_spawn_timeline))
             
             // Original debug UI systems
             .add_systems(Update, f2_debug_ui_panel_toggle)
@@ -53,6 +72,22 @@ impl Plugin for DebugUIPlugin {
             .add_systems(Update, update_cheat_slider_values)
             .add_systems(Update, update_god_mode_button_text)
             
+            // Balance tuning panel systems
+            .add_systems(Update, update_balance_panel_visibility)
+            .add_systems(Update, handle_balance_step_buttons)
+            .add_systems(Update, handle_balance_action_buttons)
+            .add_systems(Update, update_balance_panel_texts)
+            
+            // Spawn timeline systems
+            .add_systems(Update, update_spawn_timeline_visibility)
+            .add_systems(Update, (rebuild_spawn_timeline_ticks, update_spawn_timeline_display).chain())
+            .add_systems(Update, handle_spawn_timeline_buttons)
+            .add_systems(Update, handle_spawn_timeline_tick_clicks)
+            
+            // Wave jump systems
+            .add_systems(Update, (handle_wave_jump_buttons, wave_jump_keyboard_system, update_wave_jump_preview))
+            .add_systems(Update, apply_wave_jump_system.after(handle_cheat_button_interactions).after(handle_wave_jump_buttons))
+            
             // Cheat multiplier application systems
             .add_systems(Update, apply_tower_multipliers_system)
             .add_systems(Update, apply_enemy_multipliers_system)
@@ -0,0 +1,384 @@
+use bevy::prelude::*;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+use crate::resources::{CombatSet, EnemySet, GameSystemSet};
+use crate::systems::debug_toggle::DebugToggle;
+use crate::systems::input::{InputContext, InputHandler, InputRegistryAppExt};
+use super::components::PerformanceMetrics;
+
+/// Key that starts a timeline capture
+pub const PROFILER_KEY: KeyCode = KeyCode::F10;
+/// Seconds of frames one capture records
+pub const PROFILE_CAPTURE_SECONDS: f32 = 10.0;
+/// Directory captures are exported to
+pub const PROFILE_EXPORT_DIR: &str = "profiles";
+
+/// Span recorded by the timeline profiler. Gameplay stages hold the systems
+/// listed in `GAMEPLAY_SYSTEM_ORDER`, one or two per stage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProfiledSpan {
+    Frame,
+    Input,
+    WaveControl,
+    PathGeneration,
+    Spawning,
+    Movement,
+    Cleanup,
+    Targeting,
+    Firing,
+    ProjectileMovement,
+    Collision,
+    UI,
+}
+
+impl ProfiledSpan {
+    pub const ALL: [ProfiledSpan; 12] = [
+        ProfiledSpan::Frame,
+        ProfiledSpan::Input,
+        ProfiledSpan::WaveControl,
+        ProfiledSpan::PathGeneration,
+        ProfiledSpan::Spawning,
+        ProfiledSpan::Movement,
+        ProfiledSpan::Cleanup,
+        ProfiledSpan::Targeting,
+        ProfiledSpan::Firing,
+        ProfiledSpan::ProjectileMovement,
+        ProfiledSpan::Collision,
+        ProfiledSpan::UI,
+    ];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            ProfiledSpan::Frame => "frame",
+            ProfiledSpan::Input => "input",
+            ProfiledSpan::WaveControl => "wave_control",
+            ProfiledSpan::PathGeneration => "path_generation",
+            ProfiledSpan::Spawning => "enemy_spawning",
+            ProfiledSpan::Movement => "enemy_movement",
+            ProfiledSpan::Cleanup => "enemy_cleanup",
+            ProfiledSpan::Targeting => "tower_targeting",
+            ProfiledSpan::Firing => "projectile_spawning",
+            ProfiledSpan::ProjectileMovement => "projectile_movement",
+            ProfiledSpan::Collision => "collision",
+            ProfiledSpan::UI => "ui",
+        }
+    }
+
+    /// Trace category, used by chrome://tracing to filter spans
+    pub fn category(&self) -> &'static str {
+        match self {
+            ProfiledSpan::Frame => "frame",
+            ProfiledSpan::Input | ProfiledSpan::UI => "ui",
+            ProfiledSpan::WaveControl
+            | ProfiledSpan::PathGeneration
+            | ProfiledSpan::Spawning
+            | ProfiledSpan::Movement
+            | ProfiledSpan::Cleanup => "enemy",
+            _ => "combat",
+        }
+    }
+
+    /// Row the span is drawn on; every span gets its own so parallel stages don't overlap
+    pub fn track(&self) -> usize {
+        ProfiledSpan::ALL.iter().position(|span| span == self).unwrap_or(0)
+    }
+}
+
+/// One finished span, in microseconds since the capture started
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpanTiming {
+    pub span: ProfiledSpan,
+    pub start_us: f64,
+    pub duration_us: f64,
+}
+
+/// Performance metrics sampled once per captured frame
+#[derive(Clone, Debug, PartialEq)]
+pub struct MetricSample {
+    pub time_us: f64,
+    pub fps: f32,
+    pub entity_count: usize,
+    pub live_enemies: u32,
+}
+
+/// Capture in progress
+#[derive(Debug)]
+pub struct ProfileCapture {
+    pub started: Instant,
+    pub open: HashMap<ProfiledSpan, Instant>,
+    pub spans: Vec<SpanTiming>,
+    pub samples: Vec<MetricSample>,
+}
+
+impl ProfileCapture {
+    fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            open: HashMap::new(),
+            spans: Vec::new(),
+            samples: Vec::new(),
+        }
+    }
+
+    fn micros_since_start(&self, instant: Instant) -> f64 {
+        instant.saturating_duration_since(self.started).as_secs_f64() * 1_000_000.0
+    }
+
+    pub fn elapsed_secs(&self) -> f32 {
+        self.started.elapsed().as_secs_f32()
+    }
+}
+
+/// Resource for the admin timeline profiler
+#[derive(Resource, Debug, Default)]
+pub struct TimelineProfiler {
+    pub capture: Option<ProfileCapture>,
+    /// File the last capture was exported to
+    pub last_export: Option<PathBuf>,
+}
+
+impl TimelineProfiler {
+    pub fn is_recording(&self) -> bool {
+        self.capture.is_some()
+    }
+
+    /// Start a new capture, returning false if one is already running
+    pub fn start(&mut self) -> bool {
+        if self.is_recording() {
+            return false;
+        }
+        self.capture = Some(ProfileCapture::new());
+        true
+    }
+
+    pub fn begin(&mut self, span: ProfiledSpan) {
+        if let Some(capture) = self.capture.as_mut() {
+            capture.open.insert(span, Instant::now());
+        }
+    }
+
+    pub fn end(&mut self, span: ProfiledSpan) {
+        let Some(capture) = self.capture.as_mut() else {
+            return;
+        };
+        // Spans that began before the capture started are dropped
+        let Some(began) = capture.open.remove(&span) else {
+            return;
+        };
+        let start_us = capture.micros_since_start(began);
+        let duration_us = capture.micros_since_start(Instant::now()) - start_us;
+        capture.spans.push(SpanTiming { span, start_us, duration_us });
+    }
+
+    pub fn sample(&mut self, metrics: &PerformanceMetrics) {
+        if let Some(capture) = self.capture.as_mut() {
+            let time_us = capture.micros_since_start(Instant::now());
+            capture.samples.push(MetricSample {
+                time_us,
+                fps: metrics.fps,
+                entity_count: metrics.entity_count,
+                live_enemies: metrics.live_enemies,
+            });
+        }
+    }
+
+    /// Status line for the performance panel
+    pub fn status_text(&self) -> String {
+        match (&self.capture, &self.last_export) {
+            (Some(capture), _) => format!(
+                "recording {:.1}s / {:.0}s",
+                capture.elapsed_secs().min(PROFILE_CAPTURE_SECONDS),
+                PROFILE_CAPTURE_SECONDS
+            ),
+            (None, Some(path)) => format!("saved {}", path.display()),
+            (None, None) => "idle (F10 to record)".to_string(),
+        }
+    }
+}
+
+/// Chrome trace event JSON for a capture: one complete event per span, a
+/// counter event per metric sample and a name for every span's row
+pub fn chrome_trace(spans: &[SpanTiming], samples: &[MetricSample]) -> Value {
+    let mut events: Vec<Value> = ProfiledSpan::ALL
+        .iter()
+        .map(|span| {
+            json!({
+                "name": "thread_name",
+                "ph": "M",
+                "pid": 1,
+                "tid": span.track(),
+                "args": { "name": span.get_name() },
+            })
+        })
+        .collect();
+
+    events.extend(spans.iter().map(|timing| {
+        json!({
+            "name": timing.span.get_name(),
+            "cat": timing.span.category(),
+            "ph": "X",
+            "ts": timing.start_us,
+            "dur": timing.duration_us,
+            "pid": 1,
+            "tid": timing.span.track(),
+        })
+    }));
+
+    events.extend(samples.iter().map(|sample| {
+        json!({
+            "name": "performance",
+            "ph": "C",
+            "ts": sample.time_us,
+            "pid": 1,
+            "args": {
+                "fps": sample.fps,
+                "entities": sample.entity_count,
+                "enemies": sample.live_enemies,
+            },
+        })
+    }));
+
+    json!({ "traceEvents": events, "displayTimeUnit": "ms" })
+}
+
+/// Write a capture to a new file in `dir`, returning its path
+pub fn export_chrome_trace(dir: &Path, capture: &ProfileCapture) -> Result<PathBuf, String> {
+    std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    let stamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs());
+    let path = dir.join(format!("timeline-{}.json", stamp));
+    let trace = chrome_trace(&capture.spans, &capture.samples);
+    let contents = serde_json::to_string(&trace).map_err(|e| e.to_string())?;
+    std::fs::write(&path, contents).map_err(|e| e.to_string())?;
+    Ok(path)
+}
+
+/// Input handler starting a capture, gated behind debug features like the cheat menu
+pub struct ProfilerCaptureHandler;
+
+impl InputHandler for ProfilerCaptureHandler {
+    fn handle_input(&self, world: &mut World, key: KeyCode) -> bool {
+        if key != PROFILER_KEY {
+            return false;
+        }
+
+        if let Some(debug_toggle) = world.get_resource::<DebugToggle>() {
+            if !debug_toggle.is_enabled() {
+                info!("F10 (Timeline Profiler) blocked - Press ` (backtick) to enable debug features");
+                return true;
+            }
+        }
+
+        let Some(mut profiler) = world.get_resource_mut::<TimelineProfiler>() else {
+            warn!("F10 handler: TimelineProfiler resource not found");
+            return false;
+        };
+        if profiler.start() {
+            info!("Timeline profiler: recording {:.0}s of frames", PROFILE_CAPTURE_SECONDS);
+        } else {
+            info!("Timeline profiler: a capture is already running");
+        }
+        true
+    }
+
+    fn get_description(&self) -> &str {
+        "Record a 10s timeline profile"
+    }
+
+    fn get_priority(&self) -> u8 {
+        40
+    }
+
+    fn get_id(&self) -> &str {
+        "timeline_profiler"
+    }
+
+    fn handles_key(&self, key: KeyCode) -> bool {
+        key == PROFILER_KEY
+    }
+
+    fn get_handled_keys(&self) -> Vec<KeyCode> {
+        vec![PROFILER_KEY]
+    }
+
+    fn get_context(&self) -> InputContext {
+        InputContext::Admin
+    }
+}
+
+/// System opening the frame span
+pub fn profiler_frame_begin_system(mut profiler: ResMut<TimelineProfiler>) {
+    profiler.begin(ProfiledSpan::Frame);
+}
+
+/// System closing the frame span, sampling metrics and exporting finished captures
+pub fn profiler_frame_end_system(
+    mut profiler: ResMut<TimelineProfiler>,
+    metrics: Option<Res<PerformanceMetrics>>,
+) {
+    if !profiler.is_recording() {
+        return;
+    }
+    profiler.end(ProfiledSpan::Frame);
+    if let Some(metrics) = metrics {
+        profiler.sample(&metrics);
+    }
+
+    let finished = profiler
+        .capture
+        .as_ref()
+        .is_some_and(|capture| capture.elapsed_secs() >= PROFILE_CAPTURE_SECONDS);
+    if !finished {
+        return;
+    }
+    let Some(capture) = profiler.capture.take() else {
+        return;
+    };
+    match export_chrome_trace(Path::new(PROFILE_EXPORT_DIR), &capture) {
+        Ok(path) => {
+            info!("Timeline profiler: {} spans saved to {} (open in chrome://tracing)", capture.spans.len(), path.display());
+            profiler.last_export = Some(path);
+        }
+        Err(error) => error!("Timeline profiler: failed to export capture: {}", error),
+    }
+}
+
+/// Add systems marking the start and end of `span` around a system set
+fn add_span_markers<S: SystemSet + Clone>(app: &mut App, span: ProfiledSpan, set: S) {
+    app.add_systems(
+        Update,
+        (
+            (move |mut profiler: ResMut<TimelineProfiler>| profiler.begin(span)).before(set.clone()),
+            (move |mut profiler: ResMut<TimelineProfiler>| profiler.end(span)).after(set),
+        ),
+    );
+}
+
+/// Plugin for the admin timeline profiler. Spans are measured by marker
+/// systems placed around each system set, so they cover the set's systems
+/// plus any scheduling gaps between them.
+pub struct TimelineProfilerPlugin;
+
+impl Plugin for TimelineProfilerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TimelineProfiler>()
+            .register_input_handler(ProfilerCaptureHandler)
+            .add_systems(First, profiler_frame_begin_system)
+            .add_systems(Last, profiler_frame_end_system);
+
+        add_span_markers(app, ProfiledSpan::Input, GameSystemSet::Input);
+        add_span_markers(app, ProfiledSpan::WaveControl, EnemySet::WaveControl);
+        add_span_markers(app, ProfiledSpan::PathGeneration, EnemySet::PathGeneration);
+        add_span_markers(app, ProfiledSpan::Spawning, EnemySet::Spawning);
+        add_span_markers(app, ProfiledSpan::Movement, EnemySet::Movement);
+        add_span_markers(app, ProfiledSpan::Cleanup, EnemySet::Cleanup);
+        add_span_markers(app, ProfiledSpan::Targeting, CombatSet::Targeting);
+        add_span_markers(app, ProfiledSpan::Firing, CombatSet::Firing);
+        add_span_markers(app, ProfiledSpan::ProjectileMovement, CombatSet::ProjectileMovement);
+        add_span_markers(app, ProfiledSpan::Collision, CombatSet::Collision);
+        add_span_markers(app, ProfiledSpan::UI, GameSystemSet::UI);
+    }
+}
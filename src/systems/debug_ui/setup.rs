@@ -199,6 +199,11 @@ fn create_performance_metrics_display(parent: &mut ChildSpawnerCommands) {
         (MetricType::FrameTime, "Frame Time: 16.7ms"),
         (MetricType::EntityCount, "Entities: 0"),
         (MetricType::PathGenTime, "Path Gen: 0.0ms"),
+        (MetricType::EnemyCap, "Enemies: 0/0"),
+        (MetricType::SpawnQueue, "Spawn Queue: 0"),
+        (MetricType::MapLayout, "Map: -"),
+        (MetricType::EffectBudget, "Effects: 0 at 100% density"),
+        (MetricType::Profiler, "Profiler: idle (F10 to record)"),
     ];
 
     for (metric_type, default_text) in metrics {
@@ -242,6 +247,8 @@ fn create_action_buttons(parent: &mut ChildSpawnerCommands) {
     let actions = [
         (ActionType::ResetGame, "Reset Game"),
         (ActionType::RandomizeMap, "Randomize Map"),
+        (ActionType::CycleMapArchetype, "Map Type"),
+        (ActionType::ToggleVoidLake, "Void Lake"),
         (ActionType::SaveState, "Save State"),
         (ActionType::LoadState, "Load State"),
     ];
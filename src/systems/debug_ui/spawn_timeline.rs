@@ -0,0 +1,310 @@
+use bevy::prelude::*;
+use crate::resources::*;
+use crate::systems::enemy_system::enemy_color;
+
+/// Width of the timeline track in pixels
+const TRACK_WIDTH: f32 = 600.0;
+/// Opacity of ticks for enemies that have already spawned
+const SPAWNED_TICK_ALPHA: f32 = 0.25;
+/// Height of the tick for the calmest gap, as a share of the track; the
+/// busiest gap of the wave fills the track
+const MIN_TICK_HEIGHT: f32 = 25.0;
+
+/// Resource to manage the admin spawn timeline
+#[derive(Resource, Debug, Default)]
+pub struct SpawnTimelineState {
+    pub visible: bool,
+    /// Wave and spawn interval the ticks were laid out for
+    pub shown_wave: u32,
+    pub shown_interval: f32,
+}
+
+/// Component marker for the spawn timeline panel
+#[derive(Component)]
+pub struct SpawnTimelinePanel;
+
+/// Component marker for the track holding the ticks
+#[derive(Component)]
+pub struct SpawnTimelineTrack;
+
+/// One enemy on the timeline; clicking it jumps the schedule to its spawn
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SpawnTimelineTick(pub ScheduledSpawn);
+
+/// Component marker for the line showing how far the schedule has played
+#[derive(Component)]
+pub struct SpawnTimelinePlayhead;
+
+/// Component for the text summarising the schedule
+#[derive(Component)]
+pub struct SpawnTimelineText;
+
+/// Component for the label of the pause button
+#[derive(Component)]
+pub struct SpawnTimelinePauseLabel;
+
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub enum SpawnTimelineButton {
+    TogglePause,
+    /// Jump the schedule forward by this many seconds
+    Skip(f32),
+    NextEnemy,
+    Close,
+}
+
+/// Setup system for the spawn timeline, docked along the bottom of the screen
+pub fn setup_spawn_timeline(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(10.0),
+                bottom: Val::Px(10.0),
+                width: Val::Px(TRACK_WIDTH + 20.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(10.0)),
+                display: Display::None, // Hidden by default
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.9)),
+            SpawnTimelinePanel,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("SPAWN TIMELINE"),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.9, 1.0)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(6.0)),
+                    ..default()
+                },
+                SpawnTimelineText,
+            ));
+
+            parent.spawn((
+                Node {
+                    width: Val::Px(TRACK_WIDTH),
+                    height: Val::Px(28.0),
+                    margin: UiRect::bottom(Val::Px(6.0)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+                SpawnTimelineTrack,
+            )).with_children(|track| {
+                track.spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(0.0),
+                        width: Val::Px(2.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::WHITE),
+                    ZIndex(1),
+                    SpawnTimelinePlayhead,
+                ));
+            });
+
+            parent.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(6.0),
+                ..default()
+            }).with_children(|row| {
+                create_timeline_button(row, SpawnTimelineButton::TogglePause, "PAUSE SPAWNS", true);
+                create_timeline_button(row, SpawnTimelineButton::Skip(1.0), "+1s", false);
+                create_timeline_button(row, SpawnTimelineButton::Skip(5.0), "+5s", false);
+                create_timeline_button(row, SpawnTimelineButton::NextEnemy, "NEXT ENEMY", false);
+                create_timeline_button(row, SpawnTimelineButton::Close, "CLOSE", false);
+            });
+        });
+}
+
+/// Helper to create one of the timeline control buttons
+fn create_timeline_button(parent: &mut ChildSpawnerCommands, action: SpawnTimelineButton, label: &str, pause_label: bool) {
+    parent.spawn((
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.3, 0.3, 0.5)),
+        action,
+    )).with_children(|button| {
+        let mut text = button.spawn((
+            Text::new(label),
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+        if pause_label {
+            text.insert(SpawnTimelinePauseLabel);
+        }
+    });
+}
+
+/// Left edge of a point in the schedule, as a share of the track
+fn track_position(time: f32, wave_length: f32) -> Val {
+    if wave_length <= 0.0 {
+        return Val::Percent(0.0);
+    }
+    Val::Percent((time / wave_length).clamp(0.0, 1.0) * 100.0)
+}
+
+/// Height of a tick, tracing the wave's pacing curve along the track
+fn tick_height(pace: f32, peak_pace: f32) -> Val {
+    let share = if peak_pace > 0.0 { (pace / peak_pace).clamp(0.0, 1.0) } else { 1.0 };
+    Val::Percent(MIN_TICK_HEIGHT + (100.0 - MIN_TICK_HEIGHT) * share)
+}
+
+/// System to show or hide the spawn timeline
+pub fn update_spawn_timeline_visibility(
+    timeline_state: Res<SpawnTimelineState>,
+    mut panel_query: Query<&mut Node, With<SpawnTimelinePanel>>,
+) {
+    if timeline_state.is_changed() {
+        for mut node in &mut panel_query {
+            node.display = if timeline_state.visible { Display::Flex } else { Display::None };
+        }
+    }
+}
+
+/// System to lay the ticks out again when a new wave starts or the spawn rate changes
+pub fn rebuild_spawn_timeline_ticks(
+    mut commands: Commands,
+    wave_plan: Res<WavePlan>,
+    wave_runtime: Res<WaveRuntime>,
+    mut timeline_state: ResMut<SpawnTimelineState>,
+    track_query: Query<Entity, With<SpawnTimelineTrack>>,
+    tick_query: Query<Entity, With<SpawnTimelineTick>>,
+) {
+    let interval = wave_runtime.spawn_interval();
+    if timeline_state.shown_wave == wave_plan.current_wave && timeline_state.shown_interval == interval {
+        return;
+    }
+    timeline_state.shown_wave = wave_plan.current_wave;
+    timeline_state.shown_interval = interval;
+
+    for tick in &tick_query {
+        commands.entity(tick).despawn();
+    }
+    let Ok(track) = track_query.single() else {
+        return;
+    };
+
+    let schedule = wave_runtime.spawn_schedule(&wave_plan);
+    let wave_length = schedule.last().map_or(0.0, |spawn| spawn.time);
+    let peak_pace = wave_plan.composition.pacing.peak_rate();
+    commands.entity(track).with_children(|track| {
+        for spawn in schedule {
+            track.spawn((
+                Button,
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: track_position(spawn.time, wave_length),
+                    bottom: Val::Px(0.0),
+                    width: Val::Px(3.0),
+                    height: tick_height(spawn.pace, peak_pace),
+                    ..default()
+                },
+                BackgroundColor(enemy_color(spawn.kind, spawn.enemy_type)),
+                SpawnTimelineTick(spawn),
+            ));
+        }
+    });
+}
+
+/// System to move the playhead and dim the ticks of enemies already spawned
+pub fn update_spawn_timeline_display(
+    wave_plan: Res<WavePlan>,
+    wave_runtime: Res<WaveRuntime>,
+    timeline_state: Res<SpawnTimelineState>,
+    mut playhead_query: Query<&mut Node, With<SpawnTimelinePlayhead>>,
+    mut tick_query: Query<(&SpawnTimelineTick, &mut BackgroundColor)>,
+    mut text_query: Query<&mut Text, (With<SpawnTimelineText>, Without<SpawnTimelinePauseLabel>)>,
+    mut pause_label_query: Query<&mut Text, With<SpawnTimelinePauseLabel>>,
+) {
+    if !timeline_state.visible {
+        return;
+    }
+
+    let elapsed = wave_runtime.schedule_elapsed(&wave_plan);
+    let wave_length = wave_runtime.schedule_length(&wave_plan);
+    for mut node in &mut playhead_query {
+        node.left = track_position(elapsed, wave_length);
+    }
+
+    for (tick, mut color) in &mut tick_query {
+        let alpha = if tick.0.index < wave_runtime.enemies_spawned { SPAWNED_TICK_ALPHA } else { 1.0 };
+        *color = BackgroundColor(enemy_color(tick.0.kind, tick.0.enemy_type).with_alpha(alpha));
+    }
+
+    for mut text in &mut text_query {
+        **text = format!(
+            "SPAWN TIMELINE - wave {} ({} pacing, x{:.1}): {}/{} spawned, {} queued, {:.1}s / {:.1}s{}",
+            wave_plan.current_wave,
+            wave_plan.composition.pacing.name,
+            wave_runtime.current_pace(&wave_plan),
+            wave_runtime.enemies_spawned,
+            wave_plan.enemies_in_wave(),
+            wave_runtime.pending_spawns,
+            elapsed,
+            wave_length,
+            if wave_runtime.spawning_paused { " [PAUSED]" } else { "" },
+        );
+    }
+
+    for mut label in &mut pause_label_query {
+        **label = if wave_runtime.spawning_paused { "RESUME SPAWNS" } else { "PAUSE SPAWNS" }.to_string();
+    }
+}
+
+/// System to handle the pause, skip and close buttons
+pub fn handle_spawn_timeline_buttons(
+    interaction_query: Query<(&Interaction, &SpawnTimelineButton), (Changed<Interaction>, With<Button>)>,
+    wave_plan: Res<WavePlan>,
+    mut wave_runtime: ResMut<WaveRuntime>,
+    mut timeline_state: ResMut<SpawnTimelineState>,
+    mut mouse_input_state: ResMut<crate::systems::input_system::MouseInputState>,
+) {
+    for (interaction, action) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        // Consume the mouse click to prevent pass-through to game world
+        mouse_input_state.left_clicked = false;
+
+        match action {
+            SpawnTimelineButton::TogglePause => {
+                wave_runtime.spawning_paused = !wave_runtime.spawning_paused;
+                println!("Spawn timeline: spawning {}", if wave_runtime.spawning_paused { "paused" } else { "resumed" });
+            }
+            SpawnTimelineButton::Skip(seconds) => {
+                wave_runtime.advance_spawn_schedule(&wave_plan, std::time::Duration::from_secs_f32(*seconds));
+            }
+            SpawnTimelineButton::NextEnemy => wave_runtime.jump_to_next_spawn(&wave_plan),
+            SpawnTimelineButton::Close => timeline_state.visible = false,
+        }
+    }
+}
+
+/// System to jump the schedule forward to a clicked tick
+pub fn handle_spawn_timeline_tick_clicks(
+    interaction_query: Query<(&Interaction, &SpawnTimelineTick), (Changed<Interaction>, With<Button>)>,
+    wave_plan: Res<WavePlan>,
+    mut wave_runtime: ResMut<WaveRuntime>,
+    mut mouse_input_state: ResMut<crate::systems::input_system::MouseInputState>,
+) {
+    for (interaction, tick) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            mouse_input_state.left_clicked = false;
+            wave_runtime.jump_to_spawn(&wave_plan, tick.0.index);
+        }
+    }
+}
@@ -0,0 +1,284 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::resources::*;
+use crate::systems::combat_system::{kill_reward_range, WaveStatus};
+use crate::systems::enemy_system::compose_configured_wave;
+use crate::systems::obstacle_rendering::ObstacleGrid;
+use crate::systems::smart_enemy_system::SmartEnemySettings;
+use super::cheat_menu::{CheatMenuState, CheatMultipliers};
+
+/// Highest wave the cheat menu can jump to
+pub const MAX_JUMP_WAVE: u32 = 99;
+
+/// Resource holding the wave picked in the cheat menu's jump row
+#[derive(Resource, Debug)]
+pub struct WaveJumpState {
+    pub target: u32,
+}
+
+impl Default for WaveJumpState {
+    fn default() -> Self {
+        Self { target: 1 }
+    }
+}
+
+/// Event to replace the current wave with the scaled composition of another one
+#[derive(Event, Debug, Clone, Copy)]
+pub struct JumpToWaveEvent {
+    pub wave: u32,
+}
+
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub enum WaveJumpButton {
+    Previous,
+    Next,
+    Jump,
+}
+
+/// Component for the label showing the picked wave
+#[derive(Component)]
+pub struct WaveJumpLabel;
+
+/// Component for the text previewing the picked wave's enemies
+#[derive(Component)]
+pub struct WaveJumpPreviewText;
+
+/// Stats an enemy group will spawn with once balance tuning and cheat multipliers apply
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EnemyGroupPreview {
+    pub kind: EnemyKind,
+    pub enemy_type: EnemyType,
+    pub count: u32,
+    pub health: f32,
+    pub speed: f32,
+}
+
+/// Scaled stats of every group in a composition
+pub fn preview_wave_groups(
+    composition: &WaveComposition,
+    balance: &BalanceConfig,
+    multipliers: &CheatMultipliers,
+) -> Vec<EnemyGroupPreview> {
+    let (health_factor, speed_factor) = balance.enemies.factors_for_wave(composition.wave);
+    composition
+        .groups
+        .iter()
+        .map(|group| EnemyGroupPreview {
+            kind: group.kind,
+            enemy_type: group.enemy_type,
+            count: group.count,
+            health: group.health * health_factor * multipliers.enemy_health,
+            speed: group.speed * speed_factor * multipliers.enemy_speed,
+        })
+        .collect()
+}
+
+/// Preview text for a composition: one line per group, then the wave's bounty
+pub fn wave_preview_text(
+    composition: &WaveComposition,
+    balance: &BalanceConfig,
+    multipliers: &CheatMultipliers,
+    formatter: &NumberFormatter,
+) -> String {
+    let mut text = format!("Wave {}: {} enemies", composition.wave, composition.total_enemies());
+    for group in preview_wave_groups(composition, balance, multipliers) {
+        text.push_str(&format!(
+            "\n{} x{}  HP {:.0}  speed {:.0}",
+            group.kind.name_with(group.enemy_type),
+            group.count,
+            group.health,
+            group.speed
+        ));
+    }
+    let (min_reward, max_reward) = kill_reward_range();
+    text.push_str(&format!(
+        "\nBounty {}-{} per kill (by tower), {}-{} for the wave",
+        formatter.money(min_reward),
+        formatter.money(max_reward),
+        formatter.money(composition.bounty(min_reward)),
+        formatter.money(composition.bounty(max_reward))
+    ));
+    text
+}
+
+/// Make `composition` the running wave, as if the waves before it had been cleared
+pub fn apply_wave_jump(
+    wave_plan: &mut WavePlan,
+    wave_runtime: &mut WaveRuntime,
+    wave_status: &mut WaveStatus,
+    composition: WaveComposition,
+) {
+    wave_plan.current_wave = composition.wave.saturating_sub(1);
+    start_composed_wave(wave_plan, wave_runtime, composition);
+    wave_status.initialize_wave(wave_plan.enemies_in_wave());
+}
+
+/// Add the jump row and preview to the cheat menu's game state section
+pub fn create_wave_jump_row(parent: &mut ChildSpawnerCommands) {
+    parent.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(4.0),
+            margin: UiRect::bottom(Val::Px(4.0)),
+            ..default()
+        },
+    )).with_children(|row| {
+        for (button, label, width) in [
+            (WaveJumpButton::Previous, "-", 30.0),
+            (WaveJumpButton::Next, "+", 30.0),
+            (WaveJumpButton::Jump, "JUMP", 70.0),
+        ] {
+            row.spawn((
+                Button,
+                Node {
+                    width: Val::Px(width),
+                    height: Val::Px(25.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.3, 0.3, 0.7)),
+                button,
+            )).with_children(|button| {
+                button.spawn((
+                    Text::new(label),
+                    TextFont {
+                        font_size: 10.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                ));
+            });
+        }
+        row.spawn((
+            Text::new("Jump to wave 1 (Ctrl+1-9)"),
+            TextFont {
+                font_size: 10.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            WaveJumpLabel,
+        ));
+    });
+
+    parent.spawn((
+        Text::new(""),
+        TextFont {
+            font_size: 9.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.8, 0.8, 0.8)),
+        Node {
+            margin: UiRect::bottom(Val::Px(6.0)),
+            ..default()
+        },
+        WaveJumpPreviewText,
+    ));
+}
+
+/// System to handle the jump row buttons
+pub fn handle_wave_jump_buttons(
+    mut interaction_query: Query<(&Interaction, &WaveJumpButton, &mut BackgroundColor), (Changed<Interaction>, With<Button>)>,
+    mut jump_state: ResMut<WaveJumpState>,
+    mut jumps: EventWriter<JumpToWaveEvent>,
+    mut mouse_input_state: ResMut<crate::systems::input_system::MouseInputState>,
+) {
+    for (interaction, button, mut color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                mouse_input_state.left_clicked = false;
+                match button {
+                    WaveJumpButton::Previous => jump_state.target = jump_state.target.saturating_sub(1).max(1),
+                    WaveJumpButton::Next => jump_state.target = (jump_state.target + 1).min(MAX_JUMP_WAVE),
+                    WaveJumpButton::Jump => {
+                        jumps.write(JumpToWaveEvent { wave: jump_state.target });
+                    }
+                }
+                *color = Color::WHITE.into();
+            }
+            Interaction::Hovered => *color = Color::srgb(0.4, 0.4, 0.8).into(),
+            Interaction::None => *color = Color::srgb(0.3, 0.3, 0.7).into(),
+        }
+    }
+}
+
+/// System to pick the jump target with Ctrl+1-9 while the cheat menu is open
+pub fn wave_jump_keyboard_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    cheat_state: Res<CheatMenuState>,
+    mut jump_state: ResMut<WaveJumpState>,
+) {
+    if !cheat_state.visible
+        || !(keyboard_input.pressed(KeyCode::ControlLeft) || keyboard_input.pressed(KeyCode::ControlRight))
+    {
+        return;
+    }
+    for (key, wave) in [
+        (KeyCode::Digit1, 1),
+        (KeyCode::Digit2, 2),
+        (KeyCode::Digit3, 3),
+        (KeyCode::Digit4, 4),
+        (KeyCode::Digit5, 5),
+        (KeyCode::Digit6, 6),
+        (KeyCode::Digit7, 7),
+        (KeyCode::Digit8, 8),
+        (KeyCode::Digit9, 9),
+    ] {
+        if keyboard_input.just_pressed(key) {
+            jump_state.target = wave;
+        }
+    }
+}
+
+/// System to refresh the label and preview when the target or the scaling changes
+pub fn update_wave_jump_preview(
+    jump_state: Res<WaveJumpState>,
+    balance: Res<BalanceConfig>,
+    multipliers: Res<CheatMultipliers>,
+    formatter: Res<NumberFormatter>,
+    wave_config: Option<Res<WaveConfig>>,
+    smart_settings: Option<Res<SmartEnemySettings>>,
+    obstacle_grid: Option<Res<ObstacleGrid>>,
+    mut label_query: Query<&mut Text, (With<WaveJumpLabel>, Without<WaveJumpPreviewText>)>,
+    mut preview_query: Query<&mut Text, (With<WaveJumpPreviewText>, Without<WaveJumpLabel>)>,
+) {
+    let map_changed = obstacle_grid.as_ref().is_some_and(|grid| grid.is_changed());
+    if !jump_state.is_changed() && !balance.is_changed() && !multipliers.is_changed() && !formatter.is_changed() && !map_changed {
+        return;
+    }
+
+    for mut text in &mut label_query {
+        **text = format!("Jump to wave {} (Ctrl+1-9)", jump_state.target);
+    }
+    let composition = compose_configured_wave(jump_state.target, wave_config.as_deref(), smart_settings.as_deref(), obstacle_grid.as_deref());
+    let preview = wave_preview_text(&composition, &balance, &multipliers, &formatter);
+    for mut text in &mut preview_query {
+        **text = preview.clone();
+    }
+}
+
+/// System to clear the board and start the scaled composition of the requested wave
+pub fn apply_wave_jump_system(
+    mut commands: Commands,
+    mut jumps: EventReader<JumpToWaveEvent>,
+    mut wave_plan: ResMut<WavePlan>,
+    mut wave_runtime: ResMut<WaveRuntime>,
+    mut wave_status: ResMut<WaveStatus>,
+    wave_config: Option<Res<WaveConfig>>,
+    smart_settings: Option<Res<SmartEnemySettings>>,
+    obstacle_grid: Option<Res<ObstacleGrid>>,
+    enemy_query: Query<Entity, With<Enemy>>,
+) {
+    let Some(jump) = jumps.read().last().copied() else {
+        return;
+    };
+
+    for entity in &enemy_query {
+        commands.entity(entity).despawn();
+    }
+    let composition = compose_configured_wave(jump.wave.max(1), wave_config.as_deref(), smart_settings.as_deref(), obstacle_grid.as_deref());
+    info!("Cheat: Jumped to wave {}: {}", composition.wave, composition.summary());
+    apply_wave_jump(&mut wave_plan, &mut wave_runtime, &mut wave_status, composition);
+}
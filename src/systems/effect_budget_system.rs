@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::resources::*;
+
+/// System to count live cosmetic effects and feed the frame time to the effect budget
+pub fn effect_budget_monitor_system(
+    time: Res<Time<Real>>,
+    effects: Query<&CosmeticEffect>,
+    mut budget: ResMut<EffectBudget>,
+) {
+    let mut live = [0; EFFECT_CATEGORY_COUNT];
+    for effect in effects.iter() {
+        live[effect.0.index()] += 1;
+    }
+    budget.record_frame(time.delta_secs(), live);
+}
+
+/// Plugin keeping cosmetic effect density within the entity and frame time budget
+pub struct EffectBudgetPlugin;
+
+impl Plugin for EffectBudgetPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<EffectBudget>()
+            // Real time, so pausing doesn't count as fast frames
+            .add_systems(Update, effect_budget_monitor_system.before(GameSystemSet::Input));
+    }
+}
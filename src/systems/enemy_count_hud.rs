@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+use crate::components::{Enemy, EnemyType};
+use crate::resources::{GameSystemSet, WavePlan, WaveRuntime};
+
+/// Marker for the row of enemy counters
+#[derive(Component)]
+pub struct EnemyCountHud;
+
+/// One enemy type's icon and count
+#[derive(Component)]
+pub struct EnemyCountEntry(pub EnemyType);
+
+/// Text showing how many of a type are left
+#[derive(Component)]
+pub struct EnemyCountText(pub EnemyType);
+
+/// Enemies of each type left in the wave: alive now plus not yet spawned
+pub fn remaining_enemy_counts(
+    wave_plan: &WavePlan,
+    wave_runtime: &WaveRuntime,
+    live_types: impl IntoIterator<Item = EnemyType>,
+) -> Vec<(EnemyType, u32)> {
+    let mut counts: Vec<(EnemyType, u32)> = EnemyType::ALL
+        .iter()
+        .map(|enemy_type| (*enemy_type, wave_runtime.remaining_to_spawn_of_type(wave_plan, *enemy_type)))
+        .collect();
+    for live in live_types {
+        if let Some((_, count)) = counts.iter_mut().find(|(enemy_type, _)| *enemy_type == live) {
+            *count += 1;
+        }
+    }
+    counts
+}
+
+/// System to spawn the enemy counter row, centred along the top of the screen
+pub fn setup_enemy_count_hud(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(40.0),
+                left: Val::Percent(50.0),
+                margin: UiRect::left(Val::Px(-90.0)),
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(14.0),
+                ..default()
+            },
+            Visibility::Hidden,
+            EnemyCountHud,
+        ))
+        .with_children(|row| {
+            for enemy_type in EnemyType::ALL {
+                row.spawn((
+                    Node {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(5.0),
+                        ..default()
+                    },
+                    EnemyCountEntry(enemy_type),
+                ))
+                .with_children(|entry| {
+                    entry.spawn((
+                        Node {
+                            width: Val::Px(12.0),
+                            height: Val::Px(12.0),
+                            ..default()
+                        },
+                        BackgroundColor(enemy_type.color()),
+                    ));
+                    entry.spawn((
+                        Text::new(""),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        EnemyCountText(enemy_type),
+                    ));
+                });
+            }
+        });
+}
+
+/// System to refresh the counters whenever enemies spawn or leave play
+pub fn enemy_count_hud_system(
+    wave_plan: Res<WavePlan>,
+    wave_runtime: Res<WaveRuntime>,
+    enemies: Query<Option<&EnemyType>, With<Enemy>>,
+    added: Query<(), Added<Enemy>>,
+    mut removed: RemovedComponents<Enemy>,
+    mut hud_query: Query<&mut Visibility, With<EnemyCountHud>>,
+    mut entry_query: Query<(&mut Node, &EnemyCountEntry)>,
+    mut text_query: Query<(&mut Text, &EnemyCountText)>,
+) {
+    let enemies_left_play = removed.read().count() > 0;
+    if !wave_plan.is_changed() && !wave_runtime.is_changed() && added.is_empty() && !enemies_left_play {
+        return;
+    }
+
+    let live_types = enemies.iter().map(|enemy_type| enemy_type.copied().unwrap_or_default());
+    let counts = remaining_enemy_counts(&wave_plan, &wave_runtime, live_types);
+    let count_of = |enemy_type: EnemyType| counts.iter().find(|(t, _)| *t == enemy_type).map_or(0, |(_, count)| *count);
+
+    let total: u32 = counts.iter().map(|(_, count)| count).sum();
+    for mut visibility in &mut hud_query {
+        visibility.set_if_neq(if total > 0 { Visibility::Inherited } else { Visibility::Hidden });
+    }
+    for (mut node, entry) in &mut entry_query {
+        node.display = if count_of(entry.0) > 0 { Display::Flex } else { Display::None };
+    }
+    for (mut text, counter) in &mut text_query {
+        **text = format!("{} {}", count_of(counter.0), counter.0.get_name().to_lowercase());
+    }
+}
+
+/// Plugin showing live counts of each enemy type left in the wave
+pub struct EnemyCountHudPlugin;
+
+impl Plugin for EnemyCountHudPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(Startup, setup_enemy_count_hud)
+            .add_systems(Update, enemy_count_hud_system.in_set(GameSystemSet::UI));
+    }
+}
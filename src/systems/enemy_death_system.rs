@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::resources::*;
+use crate::systems::particles::{spawn_particles, ParticleEmitter};
+use crate::systems::tween::{AlphaTween, Easing, TranslationTween, TweenProgress};
+
+/// Seconds the coin popping out of a killed enemy takes to rise and fade
+pub const DEATH_COIN_DURATION: f32 = 0.5;
+/// How far the coin rises, in pixels
+const DEATH_COIN_RISE: f32 = 18.0;
+const DEATH_COIN_COLOR: Color = Color::srgb(1.0, 0.84, 0.0);
+const DEATH_COIN_SIZE: f32 = 6.0;
+
+/// Marker for the coin visual popping out of a killed enemy
+#[derive(Component)]
+pub struct DeathCoin;
+
+/// System to shrink and fade killed enemies, bursting each into fragments and
+/// popping a coin out of it as it dies, and despawn them once the animation ends
+pub fn enemy_death_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut effect_budget: Option<ResMut<EffectBudget>>,
+    mut dying: Query<(Entity, &mut DyingEnemy, &mut Transform, Option<&mut Sprite>)>,
+) {
+    let delta_secs = time.delta_secs();
+    for (entity, mut death, mut transform, sprite) in dying.iter_mut() {
+        if !death.started {
+            death.started = true;
+            death.start_scale = transform.scale;
+            death.start_alpha = sprite.as_ref().map_or(1.0, |sprite| sprite.color.alpha());
+            if let Some(sprite) = sprite.as_ref() {
+                spawn_particles(&mut commands, transform.translation.truncate(), ParticleEmitter::death(sprite.color));
+            }
+            if effect_budget.as_deref_mut().is_none_or(|budget| budget.admit(EffectCategory::Particle)) {
+                spawn_death_coin(&mut commands, transform.translation);
+            }
+        }
+
+        death.elapsed += delta_secs;
+        if death.is_finished() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        let fraction = death.fraction();
+        transform.scale = death.start_scale * (1.0 - Easing::QuadIn.apply(fraction));
+        if let Some(mut sprite) = sprite {
+            sprite.color.set_alpha(death.start_alpha * (1.0 - fraction));
+        }
+    }
+}
+
+/// Spawn a coin rising out of a killed enemy and fading as it goes
+fn spawn_death_coin(commands: &mut Commands, position: Vec3) {
+    let start = position.truncate().extend(position.z + 0.5);
+    commands.spawn((
+        Sprite {
+            color: DEATH_COIN_COLOR,
+            custom_size: Some(Vec2::splat(DEATH_COIN_SIZE)),
+            ..default()
+        },
+        Transform::from_translation(start).with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+        TranslationTween::new(start, start + Vec3::Y * DEATH_COIN_RISE, TweenProgress::new(DEATH_COIN_DURATION, Easing::QuadOut)),
+        AlphaTween::new(
+            1.0,
+            0.0,
+            TweenProgress::new(DEATH_COIN_DURATION, Easing::QuadIn).despawn_on_complete(),
+        ),
+        CosmeticEffect(EffectCategory::Particle),
+        DeathCoin,
+    ));
+}
+
+/// Plugin for killed enemies shrinking and fading out instead of vanishing
+pub struct EnemyDeathPlugin;
+
+impl Plugin for EnemyDeathPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            enemy_death_system
+                .after(CombatSet::Collision)
+                .in_set(GameSystemSet::Gameplay)
+                .run_if(in_state(AppState::Playing)),
+        );
+    }
+}
@@ -1,81 +1,301 @@
+use bevy::ecs::query::QueryData;
 use bevy::prelude::*;
 use crate::components::*;
 use crate::resources::*;
-use crate::systems::path_generation::generate_level_path;
+use crate::systems::base_system::damage_base;
+use crate::systems::boss_arena_system::PinnedRoute;
+use crate::systems::combat_system::WaveStatus;
+use crate::systems::obstacle_rendering::ObstacleGrid;
+use crate::systems::path_generation::{generate_level_path_with_config, LevelConfig, MAX_LEVEL_ROUTES};
+use crate::systems::settings_menu::GameSettings;
+use crate::systems::smart_enemy_system::{SmartEnemy, SmartEnemySettings, SmartRoute, SMART_ENEMY_COLOR};
+use crate::systems::tween::blend_colors;
+use crate::systems::unified_grid::{world_to_grid, UnifiedGridSystem};
+use crate::systems::void_terrain_system::{FlightPath, FlightRoute, FLYING_ENEMY_COLOR};
+
+/// Color of plain swarm enemies
+pub const SWARM_ENEMY_COLOR: Color = EnemyType::Basic.color();
+
+/// Color basic enemies of a kind are drawn with
+pub fn enemy_kind_color(kind: EnemyKind) -> Color {
+    match kind {
+        EnemyKind::Swarm => SWARM_ENEMY_COLOR,
+        EnemyKind::Smart => SMART_ENEMY_COLOR,
+    }
+}
+
+/// Color an enemy is drawn with: its type's, or its kind's for basic enemies
+pub fn enemy_color(kind: EnemyKind, enemy_type: EnemyType) -> Color {
+    match enemy_type {
+        EnemyType::Basic => enemy_kind_color(kind),
+        specialist => specialist.color(),
+    }
+}
+
+/// Seconds the board must stay clear before the next wave auto-starts
+pub const AUTO_START_DELAY: f32 = 3.0;
 
 /// Event sent when the player clicks the Start Wave button
 #[derive(Event)]
 pub struct StartWaveEvent;
 
-/// System that spawns enemies when the wave manager indicates it's time
+/// Event sent when an enemy reaches the base and escapes
+#[derive(Event, Debug, Clone, Copy)]
+pub struct EnemyEscapedEvent {
+    pub enemy: Entity,
+    pub is_smart: bool,
+}
+
+/// Route from one of the map's entry points an enemy was sent down at spawn,
+/// on maps with several routes. Its `PathProgress` is measured along this route.
+#[derive(Component, Debug, Clone)]
+pub struct SpawnRoute {
+    /// Index of the route in `EnemyPath::routes`, 0 being the main one
+    pub index: usize,
+    pub path: EnemyPath,
+}
+
+/// Route an enemy spawned as the `spawn_index`th of its wave takes: the routes
+/// take turns, so every entry sends a share of each wave
+pub fn route_for_spawn(spawn_index: u32, route_count: usize) -> usize {
+    spawn_index as usize % route_count.max(1)
+}
+
+/// Routes an enemy can carry in place of the shared path: a smart enemy's own
+/// route, the route it was pinned to when the path switched, a flyer's shortcut
+/// over the void, or the route it was sent down at spawn
+#[derive(QueryData)]
+pub struct EnemyRoutes {
+    smart: Option<&'static SmartRoute>,
+    pinned: Option<&'static PinnedRoute>,
+    flight: Option<&'static FlightRoute>,
+    spawn: Option<&'static SpawnRoute>,
+}
+
+impl EnemyRoutesItem<'_> {
+    /// Route the enemy follows and measures its `PathProgress` along
+    pub fn active<'a>(&'a self, shared: &'a EnemyPath) -> &'a EnemyPath {
+        self.own().unwrap_or(shared)
+    }
+
+    /// Route of its own the enemy follows, if it has one
+    pub fn own(&self) -> Option<&EnemyPath> {
+        own_route_of(self.smart, self.pinned, self.flight, self.spawn)
+    }
+}
+
+/// Route of its own `entity` follows, if it has one, for code holding the
+/// world rather than a query
+pub fn own_route(entity: EntityRef<'_>) -> Option<&EnemyPath> {
+    own_route_of(entity.get(), entity.get(), entity.get(), entity.get())
+}
+
+/// The one route out of those an enemy carries it follows, by precedence
+fn own_route_of<'a>(
+    smart: Option<&'a SmartRoute>,
+    pinned: Option<&'a PinnedRoute>,
+    flight: Option<&'a FlightRoute>,
+    spawn: Option<&'a SpawnRoute>,
+) -> Option<&'a EnemyPath> {
+    smart
+        .map(|route| &route.path)
+        .or(pinned.map(|route| &route.path))
+        .or(flight.map(|route| &route.path))
+        .or(spawn.map(|route| &route.path))
+}
+
+/// System that spawns enemies when the wave manager indicates it's time.
+/// Due spawns are queued and released a few per frame, and held back entirely
+/// while the live-enemy cap is reached. On maps with several routes, ground
+/// enemies are handed their route in turn.
 pub fn enemy_spawning_system(
     mut commands: Commands,
-    mut wave_manager: ResMut<WaveManager>,
+    wave_plan: Res<WavePlan>,
+    mut wave_runtime: ResMut<WaveRuntime>,
     enemy_path: Res<EnemyPath>,
-    time: Res<Time>,
+    enemy_query: Query<(), With<Enemy>>,
+    clock: Res<SimulationClock>,
+    prestige: Option<Res<RunPrestige>>,
+    flight_path: Option<Res<FlightPath>>,
+    settings: Option<Res<GameSettings>>,
+    balance: Option<Res<BalanceConfig>>,
 ) {
-    // Update the spawn timer
-    wave_manager.spawn_timer.tick(time.delta());
-
-    // Check if we should spawn an enemy
-    if wave_manager.should_spawn_enemy() {
-        // Get the starting position from the path using smooth interpolation
-        let start_pos = enemy_path.get_smooth_position_at_progress(0.0);
-
-        // Spawn a new enemy entity with wave-scaled stats for proper difficulty progression
-        let current_wave = wave_manager.current_wave;
-        commands.spawn((
-            Enemy::for_wave(current_wave),                    // Wave-scaled speed and reward
-            Health::new(Enemy::health_for_wave(current_wave)), // Wave-scaled health
+    // Update the spawn timer and queue any spawns that became due
+    wave_runtime.tick_spawn_timer(&wave_plan, clock.delta());
+
+    let live_enemies = enemy_query.iter().count() as u32;
+    let budget = wave_runtime.spawn_budget(&wave_plan, live_enemies);
+
+    // Each route on its own, so enemies can be sent down any of them
+    let routes: Vec<EnemyPath> = if enemy_path.route_count() > 1 {
+        (0..enemy_path.route_count()).filter_map(|index| enemy_path.route(index)).collect()
+    } else {
+        Vec::new()
+    };
+    let current_wave = wave_plan.current_wave;
+    let mut health_multiplier = prestige.map_or(1.0, |prestige| prestige.modifiers.enemy_health_multiplier());
+    let mut speed_multiplier = 1.0;
+    if settings.is_some_and(|settings| settings.run_mode == RunMode::Endless) {
+        let scaling = balance.map_or_else(EndlessScaling::default, |balance| balance.endless);
+        health_multiplier *= scaling.health_multiplier(current_wave);
+        speed_multiplier = scaling.speed_multiplier(current_wave);
+    }
+
+    for _ in 0..budget {
+        let Some(group) = wave_plan.composition.group_at(wave_runtime.enemies_spawned).cloned() else {
+            break;
+        };
+        let smart = group.kind == EnemyKind::Smart;
+        let flying_type = group.enemy_type == EnemyType::Flying;
+        let flight_route = flight_path
+            .as_deref()
+            .filter(|flight| !smart && (flying_type || flight.spawns_flying(wave_runtime.enemies_spawned)))
+            .and_then(|flight| flight.path.clone());
+        let wave_reward = Enemy::for_wave(current_wave).reward;
+        // Flyers set off from the main entry, where their shortcut starts
+        let route_index = route_for_spawn(wave_runtime.enemies_spawned, routes.len());
+        let spawn_route = routes.get(route_index).filter(|_| flight_route.is_none());
+
+        // Get the starting position from the route using smooth interpolation
+        let start_pos = spawn_route.unwrap_or(&*enemy_path).get_smooth_position_at_progress(0.0);
+
+        // Spawn a new enemy entity with the stats its wave group calls for
+        let mut enemy = commands.spawn((
+            Enemy {
+                speed: group.speed * speed_multiplier,
+                path_index: 0,
+                reward: wave_reward * group.enemy_type.reward_multiplier(),
+            },
+            group.enemy_type,
+            Health::new(group.health * health_multiplier),
             PathProgress::new(),
+            KnockbackLimiter::default(),
+            LootTable::for_enemy(group.enemy_type, current_wave),
             Sprite {
-                color: Color::srgb(1.0, 0.2, 0.2), // Red color for enemies
-                custom_size: Some(Vec2::new(20.0, 20.0)), // 20x20 pixel square
+                color: if flight_route.is_some() { FLYING_ENEMY_COLOR } else { enemy_color(group.kind, group.enemy_type) },
+                custom_size: Some(Vec2::splat(ENEMY_COLLISION_RADIUS * 2.0)), // 20x20 pixel square
                 ..default()
             },
             Transform::from_translation(start_pos.extend(1.0)),
         ));
+        if smart {
+            enemy.insert(SmartEnemy);
+        }
+        if let Some(path) = spawn_route {
+            enemy.insert(SpawnRoute { index: route_index, path: path.clone() });
+        }
+        if let Some(path) = flight_route {
+            // Flyers cross the void in single file
+            enemy.insert(FlightRoute { path });
+        } else if !flying_type {
+            // Ground enemies are swarm enemies: spread them across the path.
+            // Flyers without void to cross keep to its centre line.
+            enemy.insert(SwarmOffset::for_spawn_index(wave_runtime.enemies_spawned));
+        }
 
         // Record that we spawned an enemy
-        wave_manager.enemy_spawned();
+        wave_runtime.enemy_spawned();
+    }
+}
+
+/// Shrink a swarm enemy's sideways offset until its outer edge is on a traversable cell.
+/// Falls back to the path centre line when no part of the offset fits.
+pub fn bounded_lateral_offset(
+    center: Vec2,
+    normal: Vec2,
+    lateral: f32,
+    is_traversable: impl Fn(Vec2) -> bool,
+) -> f32 {
+    let mut offset = lateral.clamp(-SWARM_MAX_LATERAL_OFFSET, SWARM_MAX_LATERAL_OFFSET);
+    let edge = ENEMY_COLLISION_RADIUS * offset.signum();
+    for _ in 0..4 {
+        if is_traversable(center + normal * (offset + edge)) {
+            return offset;
+        }
+        offset *= 0.5;
     }
+    0.0
 }
 
-/// System that moves enemies along the path based on their speed
+/// System that moves enemies along the path based on their speed.
+/// Enemies with a route of their own (see `EnemyRoutes`) follow it instead of
+/// the shared path.
 pub fn enemy_movement_system(
-    mut enemy_query: Query<(&Enemy, &mut PathProgress, &mut Transform)>,
+    mut enemy_query: Query<(
+        &Enemy,
+        &mut PathProgress,
+        &mut Transform,
+        Option<&SwarmOffset>,
+        EnemyRoutes,
+        Option<&Slow>,
+    )>,
     enemy_path: Res<EnemyPath>,
     time: Res<Time>,
+    obstacle_grid: Option<Res<ObstacleGrid>>,
+    unified_grid: Option<Res<UnifiedGridSystem>>,
 ) {
-    let path_length = enemy_path.total_length();
+    // Without a map loaded every point counts as walkable
+    let is_traversable = |point: Vec2| match (&obstacle_grid, &unified_grid) {
+        (Some(obstacle_grid), Some(unified_grid)) => world_to_grid(point, unified_grid)
+            .is_some_and(|cell| obstacle_grid.grid.is_traversable(cell)),
+        _ => true,
+    };
+
+    for (enemy, mut path_progress, mut transform, swarm_offset, routes, slow) in enemy_query.iter_mut() {
+        let path = routes.active(&enemy_path);
 
-    for (enemy, mut path_progress, mut transform) in enemy_query.iter_mut() {
-        // Calculate how far the enemy should move this frame
-        let distance_this_frame = enemy.speed * time.delta_secs();
+        // Calculate how far the enemy should move this frame, held back by any slow
+        let speed = enemy.speed * slow.map_or(1.0, Slow::speed_multiplier);
+        let distance_this_frame = speed * time.delta_secs();
         
         // Convert distance to progress (0.0 to 1.0)
-        let progress_this_frame = distance_this_frame / path_length;
+        let progress_this_frame = distance_this_frame / path.total_length();
         
         // Advance the enemy's progress
         path_progress.advance(progress_this_frame);
         
         // Update the enemy's position based on current progress using smooth spline interpolation
-        let new_position = enemy_path.get_smooth_position_at_progress(path_progress.current);
+        let mut new_position = path.get_smooth_position_at_progress(path_progress.current);
+
+        // Swarm enemies walk their own lane, perpendicular to the direction of travel
+        if let Some(swarm_offset) = swarm_offset {
+            let normal = path.get_smooth_direction_at_progress(path_progress.current).perp();
+            new_position += normal * bounded_lateral_offset(new_position, normal, swarm_offset.lateral, is_traversable);
+        }
         transform.translation = new_position.extend(0.0);
     }
 }
 
-/// System that removes enemies that have reached the end of the path
+/// System that removes enemies that have reached the base at the end of the path.
+/// Each one counts as escaped, damages the base, costing a life, and sends an `EnemyEscapedEvent`.
 pub fn enemy_cleanup_system(
     mut commands: Commands,
+    mut escape_events: EventWriter<EnemyEscapedEvent>,
     mut score: ResMut<Score>,
-    enemy_query: Query<(Entity, &PathProgress), With<Enemy>>,
+    mut wave_status: ResMut<WaveStatus>,
+    mut codex: Option<ResMut<EnemyCodex>>,
+    mut ledger: Option<ResMut<BountyLedger>>,
+    enemy_query: Query<(Entity, &PathProgress, Has<SmartEnemy>, Option<&EnemyType>), With<Enemy>>,
+    mut base_query: Query<(Entity, &mut Health), (With<Base>, Without<Enemy>)>,
 ) {
-    for (entity, path_progress) in enemy_query.iter() {
+    for (entity, path_progress, is_smart, enemy_type) in enemy_query.iter() {
         if path_progress.is_complete() {
-            // Enemy reached the end - remove it and record as escaped
+            // Enemy reached the base - remove it, record the escape and damage the base
             commands.entity(entity).despawn();
             score.enemy_escaped();
+            wave_status.enemies_escaped += 1;
+            wave_status.enemies_remaining = wave_status.enemies_remaining.saturating_sub(1);
+            if let Some(codex) = codex.as_deref_mut() {
+                codex.record_leak(enemy_type.copied().unwrap_or_default());
+            }
+            if let Some(ledger) = ledger.as_deref_mut() {
+                ledger.record_leak();
+            }
+            escape_events.write(EnemyEscapedEvent { enemy: entity, is_smart });
+
+            if let Ok((base_entity, mut base_health)) = base_query.single_mut() {
+                damage_base(&mut commands, base_entity, &mut base_health, ENEMY_BASE_DAMAGE);
+            }
         }
     }
 }
@@ -83,22 +303,82 @@ pub fn enemy_cleanup_system(
 /// System that handles manual wave spawning (for Phase 1)
 /// Now controlled via UI button instead of keyboard
 pub fn manual_wave_system(
-    mut wave_manager: ResMut<WaveManager>,
+    mut wave_plan: ResMut<WavePlan>,
+    mut wave_runtime: ResMut<WaveRuntime>,
     mut wave_start_events: EventReader<StartWaveEvent>,
+    wave_config: Option<Res<WaveConfig>>,
+    smart_settings: Option<Res<SmartEnemySettings>>,
+    obstacle_grid: Option<Res<ObstacleGrid>>,
 ) {
     for _event in wave_start_events.read() {
-        if wave_manager.current_wave == 0 || wave_manager.wave_complete() {
-            // Calculate progressive enemy count based on wave number
-            let next_wave = wave_manager.current_wave + 1;
-            let enemy_count = calculate_enemies_for_wave(next_wave);
-            
-            // Start wave with progressive scaling
-            wave_manager.start_wave(enemy_count);
-            info!("Started wave {} with {} enemies", next_wave, enemy_count);
+        if wave_plan.current_wave == 0 || wave_runtime.wave_complete(&wave_plan) {
+            // Compose the next wave with progressive scaling
+            let next_wave = wave_plan.next_wave();
+            let composition = compose_configured_wave(next_wave, wave_config.as_deref(), smart_settings.as_deref(), obstacle_grid.as_deref());
+            info!("Started wave {}: {}", next_wave, composition.summary());
+            start_composed_wave(&mut wave_plan, &mut wave_runtime, composition);
         }
     }
 }
 
+/// System to start the next wave on its own once the board has been clear for
+/// `AUTO_START_DELAY`, when auto-start is turned on in the settings
+pub fn auto_start_wave_system(
+    time: Res<Time>,
+    settings: Option<Res<GameSettings>>,
+    game_state: Res<GameState>,
+    wave_plan: Res<WavePlan>,
+    wave_runtime: Res<WaveRuntime>,
+    enemies: Query<(), With<Enemy>>,
+    mut clear_for: Local<f32>,
+    mut wave_start_events: EventWriter<StartWaveEvent>,
+) {
+    let enabled = settings.is_some_and(|settings| settings.auto_start_waves);
+    let board_clear = *game_state == GameState::Playing
+        && wave_plan.current_wave > 0
+        && wave_runtime.wave_complete(&wave_plan)
+        && enemies.is_empty();
+    if !enabled || !board_clear {
+        *clear_for = 0.0;
+        return;
+    }
+
+    *clear_for += time.delta_secs();
+    if *clear_for >= AUTO_START_DELAY {
+        *clear_for = 0.0;
+        wave_start_events.write(StartWaveEvent);
+        info!("Auto-starting wave {}", wave_plan.next_wave());
+    }
+}
+
+/// Composition of a wave on the current map: the progressive enemy count with
+/// specialist types mixed in, and smart enemies where the map lets them reroute
+pub fn compose_wave(
+    wave_number: u32,
+    smart_settings: Option<&SmartEnemySettings>,
+    obstacle_grid: Option<&ObstacleGrid>,
+) -> WaveComposition {
+    let smart_every = match (smart_settings, obstacle_grid) {
+        (Some(settings), Some(obstacle_grid)) => settings.smart_every(obstacle_grid.analysis.archetype),
+        _ => None,
+    };
+    WaveComposition::mixed(wave_number, calculate_enemies_for_wave(wave_number), smart_every)
+}
+
+/// Composition of a wave as played: its `WaveDefinition` when the data files
+/// define one, otherwise the procedural wave from `compose_wave`
+pub fn compose_configured_wave(
+    wave_number: u32,
+    wave_config: Option<&WaveConfig>,
+    smart_settings: Option<&SmartEnemySettings>,
+    obstacle_grid: Option<&ObstacleGrid>,
+) -> WaveComposition {
+    match wave_config.and_then(|config| config.get(wave_number)) {
+        Some(definition) => definition.composition(),
+        None => compose_wave(wave_number, smart_settings, obstacle_grid),
+    }
+}
+
 /// Calculate the number of enemies for a given wave with progressive difficulty scaling
 pub fn calculate_enemies_for_wave(wave_number: u32) -> u32 {
     let wave = wave_number.max(1); // Ensure minimum wave 1
@@ -122,12 +402,14 @@ pub fn calculate_enemies_for_wave(wave_number: u32) -> u32 {
 /// Path persists across all waves for consistency
 pub fn path_generation_system(
     mut enemy_path: ResMut<EnemyPath>,
-    wave_manager: Res<WaveManager>,
+    level_config: Res<LevelConfig>,
+    wave_plan: Res<WavePlan>,
+    wave_runtime: Res<WaveRuntime>,
 ) {
     // Only generate path once when the game first starts
     // This ensures the path stays the same across all waves
-    if wave_manager.is_added() || (wave_manager.current_wave == 1 && wave_manager.enemies_spawned == 0 && enemy_path.waypoints.is_empty()) {
-        let new_path = generate_level_path(1); // Use wave 1 seed for consistent path
+    if wave_plan.is_added() || (wave_plan.current_wave == 1 && wave_runtime.enemies_spawned == 0 && enemy_path.waypoints.is_empty()) {
+        let new_path = generate_level_path_with_config(1, &level_config); // Use wave 1 seed for consistent path
         *enemy_path = new_path;
         info!(
             "Generated persistent path with {} waypoints (will be used for all waves)", 
@@ -136,68 +418,247 @@ pub fn path_generation_system(
     }
 }
 
-/// System that updates path visualization when the path changes
+/// System that updates path visualization when the path or the chosen path style changes
 /// This creates/updates visual path segments that show players where enemies will move
 pub fn path_visualization_system(
     mut commands: Commands,
     enemy_path: Res<EnemyPath>,
+    settings: Option<Res<GameSettings>>,
+    mut last_style: Local<Option<PathStyle>>,
     existing_path_viz: Query<Entity, With<crate::components::PathVisualization>>,
 ) {
-    // Only update visualization when the path resource changes
-    if enemy_path.is_changed() && !enemy_path.is_added() {
-        // Remove existing path visualization entities
-        for entity in existing_path_viz.iter() {
-            commands.entity(entity).despawn();
-        }
+    let style = settings.map_or_else(PathStyle::default, |settings| settings.path_style);
+    let style_changed = last_style.is_some_and(|last| last != style);
+    *last_style = Some(style);
+
+    // Only update visualization when the path resource or the style changes
+    if !enemy_path.is_changed() && !style_changed {
+        return;
+    }
+
+    // Remove existing path visualization entities; their marks go with them
+    for entity in existing_path_viz.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    // Create new path visualization based on current path, telling routes
+    // apart by color when there are several
+    let multi_route = enemy_path.route_count() > 1;
+    for (index, waypoints) in enemy_path.routes().enumerate() {
+        spawn_path_segments(&mut commands, waypoints, multi_route.then_some(index), style);
+    }
+
+    info!(
+        "Built {} path visualization with {} segments over {} route(s)",
+        style.get_name(),
+        enemy_path.routes().map(|route| route.len().saturating_sub(1)).sum::<usize>(),
+        enemy_path.route_count()
+    );
+}
+
+/// Colors routes are drawn in on maps with several, by route index
+pub const ROUTE_COLORS: [Color; MAX_LEVEL_ROUTES] = [
+    Color::srgb(0.3, 0.6, 1.0),
+    Color::srgb(0.85, 0.4, 0.95),
+    Color::srgb(1.0, 0.6, 0.2),
+];
+
+/// Color a route is drawn in, wrapping around past the last route color
+pub fn route_color(index: usize) -> Color {
+    ROUTE_COLORS[index % ROUTE_COLORS.len()]
+}
+
+const PATH_MARK_COLOR: Color = Color::srgb(0.5, 0.5, 0.5);
+/// Diameter of a dot in the dotted trail
+const PATH_DOT_SIZE: f32 = 5.0;
+/// Reach of each chevron arm back from its tip, along and across the path
+const CHEVRON_ARM_REACH: f32 = 6.0;
+const CHEVRON_ARM_WIDTH: f32 = 3.0;
+const FLOW_DASH_SIZE: Vec2 = Vec2::new(12.0, 4.0);
+const PATH_LINE_WIDTH: f32 = 5.0;
+/// Width of the route colored stripe beside each segment on multi-route maps
+const ROUTE_STRIPE_WIDTH: f32 = 2.0;
+
+/// Spawn one sprite per path segment; they start grey until coverage tinting picks them up.
+/// Styles other than the plain line hide that sprite and draw their marks as its children,
+/// laid out along the segment's local x axis so they point the way enemies travel.
+/// Segments of one of several routes also get a stripe in the route's color, offset by
+/// route so routes sharing a stretch show side by side.
+fn spawn_path_segments(commands: &mut Commands, waypoints: &[Vec2], route: Option<usize>, style: PathStyle) {
+    for segment in waypoints.windows(2) {
+        let (start, end) = (segment[0], segment[1]);
+        let midpoint = (start + end) / 2.0;
+        let length = start.distance(end);
         
-        // Create new path visualization based on current path
-        for i in 0..enemy_path.waypoints.len() - 1 {
-            let start = enemy_path.waypoints[i];
-            let end = enemy_path.waypoints[i + 1];
-            let midpoint = (start + end) / 2.0;
-            let length = start.distance(end);
-            
-            // Calculate rotation angle to align the rectangle with the path segment
-            let direction = end - start;
-            let angle = direction.y.atan2(direction.x);
-            
-            commands.spawn((
-                Sprite {
-                    color: Color::srgb(0.5, 0.5, 0.5),
-                    custom_size: Some(Vec2::new(length, 5.0)),
-                    ..default()
-                },
-                Transform::from_translation(midpoint.extend(-1.0))
-                    .with_rotation(Quat::from_rotation_z(angle)),
-                crate::components::PathVisualization,
-            ));
-        }
+        // Calculate rotation angle to align the rectangle with the path segment
+        let direction = end - start;
+        let angle = direction.y.atan2(direction.x);
         
-        info!("Updated path visualization with {} segments", enemy_path.waypoints.len() - 1);
-    } 
-    // On first run (when resource is added), create initial visualization
-    else if enemy_path.is_added() {
-        for i in 0..enemy_path.waypoints.len() - 1 {
-            let start = enemy_path.waypoints[i];
-            let end = enemy_path.waypoints[i + 1];
-            let midpoint = (start + end) / 2.0;
-            let length = start.distance(end);
-            
-            let direction = end - start;
-            let angle = direction.y.atan2(direction.x);
-            
-            commands.spawn((
-                Sprite {
-                    color: Color::srgb(0.5, 0.5, 0.5),
-                    custom_size: Some(Vec2::new(length, 5.0)),
-                    ..default()
-                },
-                Transform::from_translation(midpoint.extend(-1.0))
-                    .with_rotation(Quat::from_rotation_z(angle)),
-                crate::components::PathVisualization,
-            ));
+        let visibility = if style == PathStyle::Line { Visibility::Inherited } else { Visibility::Hidden };
+        let mut segment_entity = commands.spawn((
+            Sprite {
+                color: PATH_MARK_COLOR,
+                custom_size: Some(Vec2::new(length, PATH_LINE_WIDTH)),
+                ..default()
+            },
+            Transform::from_translation(midpoint.extend(-1.0))
+                .with_rotation(Quat::from_rotation_z(angle)),
+            visibility,
+            crate::components::PathVisualization,
+            PathSegment { start, end },
+        ));
+
+        if let Some(route) = route {
+            let offset = -(PATH_LINE_WIDTH / 2.0 + ROUTE_STRIPE_WIDTH * (route as f32 + 0.5));
+            segment_entity.with_children(|parent| {
+                // Shown whatever the style, which may hide the segment itself
+                parent.spawn((
+                    Sprite {
+                        color: route_color(route),
+                        custom_size: Some(Vec2::new(length, ROUTE_STRIPE_WIDTH)),
+                        ..default()
+                    },
+                    Transform::from_xyz(0.0, offset, 0.0),
+                    Visibility::Visible,
+                    PathRouteStripe { route },
+                ));
+            });
+        }
+
+        let Some(spacing) = style.mark_spacing() else { continue };
+        let count = (length / spacing).floor().max(1.0) as usize;
+        let half_length = length / 2.0;
+        segment_entity.with_children(|parent| {
+            for index in 0..count {
+                let x = -half_length + spacing * (index as f32 + 0.5);
+                match style {
+                    PathStyle::Line => {}
+                    PathStyle::Dots => {
+                        parent.spawn((
+                            path_mark_sprite(Vec2::splat(PATH_DOT_SIZE)),
+                            Transform::from_xyz(x, 0.0, 0.0),
+                            PathMark,
+                        ));
+                    }
+                    PathStyle::Chevrons => {
+                        // Two arms meeting at a tip on the centre line form a ">"
+                        let arm_length = CHEVRON_ARM_REACH * std::f32::consts::SQRT_2;
+                        for side in [1.0, -1.0] {
+                            parent.spawn((
+                                path_mark_sprite(Vec2::new(arm_length, CHEVRON_ARM_WIDTH)),
+                                Transform::from_xyz(x - CHEVRON_ARM_REACH / 2.0, side * CHEVRON_ARM_REACH / 2.0, 0.0)
+                                    .with_rotation(Quat::from_rotation_z(-side * std::f32::consts::FRAC_PI_4)),
+                                PathMark,
+                            ));
+                        }
+                    }
+                    PathStyle::Flow => {
+                        parent.spawn((
+                            path_mark_sprite(FLOW_DASH_SIZE),
+                            Transform::from_xyz(x, 0.0, 0.0),
+                            PathMark,
+                            PathFlowDash { start: -half_length, cycle: spacing * count as f32 },
+                        ));
+                    }
+                }
+            }
+        });
+    }
+}
+
+fn path_mark_sprite(size: Vec2) -> Sprite {
+    Sprite {
+        color: PATH_MARK_COLOR,
+        custom_size: Some(size),
+        ..default()
+    }
+}
+
+/// System to slide the flow style's dashes along their segments at the current
+/// wave's enemy speed, so the path shows both where and how fast enemies travel
+pub fn path_flow_system(
+    time: Res<Time>,
+    wave_plan: Option<Res<WavePlan>>,
+    mut dashes: Query<(&PathFlowDash, &mut Transform)>,
+) {
+    let wave = wave_plan.map_or(1, |plan| plan.current_wave);
+    let distance = Enemy::for_wave(wave).speed * time.delta_secs();
+    for (dash, mut transform) in dashes.iter_mut() {
+        transform.translation.x = flow_dash_position(transform.translation.x, distance, dash);
+    }
+}
+
+/// Where a flowing dash ends up after moving `distance` along its segment
+pub fn flow_dash_position(x: f32, distance: f32, dash: &PathFlowDash) -> f32 {
+    if dash.cycle <= 0.0 {
+        return x;
+    }
+    dash.start + (x + distance - dash.start).rem_euclid(dash.cycle)
+}
+
+/// Towers covering a point before it counts as fully covered
+pub const FULL_COVERAGE_TOWERS: f32 = 3.0;
+/// Distance between the points sampled along a segment when measuring coverage
+const COVERAGE_SAMPLE_SPACING: f32 = 20.0;
+
+const UNCOVERED_PATH_COLOR: Color = Color::srgb(0.9, 0.2, 0.2);
+const PARTLY_COVERED_PATH_COLOR: Color = Color::srgb(0.95, 0.8, 0.2);
+const COVERED_PATH_COLOR: Color = Color::srgb(0.2, 0.85, 0.3);
+
+/// How well towers cover a path segment, from 0.0 (out of every tower's range) to
+/// 1.0 (every sampled point in range of `FULL_COVERAGE_TOWERS` towers)
+pub fn segment_coverage(start: Vec2, end: Vec2, towers: &[(Vec2, f32)]) -> f32 {
+    let steps = ((start.distance(end) / COVERAGE_SAMPLE_SPACING).ceil() as usize).max(1);
+    let total: f32 = (0..=steps)
+        .map(|step| {
+            let point = start.lerp(end, step as f32 / steps as f32);
+            let covering = towers
+                .iter()
+                .filter(|(tower_pos, range)| tower_pos.distance(point) <= *range)
+                .count();
+            (covering as f32 / FULL_COVERAGE_TOWERS).min(1.0)
+        })
+        .sum();
+    total / (steps + 1) as f32
+}
+
+/// Threat tint for a coverage level: red when uncovered, through yellow, to green
+pub fn coverage_color(coverage: f32) -> Color {
+    let coverage = coverage.clamp(0.0, 1.0);
+    if coverage < 0.5 {
+        blend_colors(UNCOVERED_PATH_COLOR, PARTLY_COVERED_PATH_COLOR, coverage * 2.0)
+    } else {
+        blend_colors(PARTLY_COVERED_PATH_COLOR, COVERED_PATH_COLOR, (coverage - 0.5) * 2.0)
+    }
+}
+
+/// System to tint path segments by tower coverage, recomputed when towers are
+/// placed, finished, upgraded or removed, or when the path is rebuilt
+pub fn path_coverage_tint_system(
+    mut last_towers: Local<Vec<(Vec2, f32)>>,
+    towers: Query<(&Transform, &TowerStats), Without<Constructing>>,
+    new_segments: Query<(), Added<PathSegment>>,
+    mut segments: Query<(&PathSegment, &mut Sprite, Option<&Children>)>,
+    mut marks: Query<&mut Sprite, (With<PathMark>, Without<PathSegment>)>,
+) {
+    let current: Vec<(Vec2, f32)> = towers
+        .iter()
+        .map(|(transform, stats)| (transform.translation.truncate(), stats.range))
+        .collect();
+    if current == *last_towers && new_segments.is_empty() {
+        return;
+    }
+
+    for (segment, mut sprite, children) in segments.iter_mut() {
+        let color = coverage_color(segment_coverage(segment.start, segment.end, &current));
+        sprite.color = color;
+        // Marks drawn by the path style take their segment's tint
+        let Some(children) = children else { continue };
+        for &child in &children[..] {
+            if let Ok(mut mark) = marks.get_mut(child) {
+                mark.color = color;
+            }
         }
-        
-        info!("Created initial path visualization with {} segments", enemy_path.waypoints.len() - 1);
     }
+    *last_towers = current;
 }
\ No newline at end of file
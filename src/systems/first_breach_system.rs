@@ -0,0 +1,199 @@
+use bevy::prelude::*;
+use crate::components::Base;
+use crate::resources::*;
+use crate::systems::enemy_system::EnemyEscapedEvent;
+use crate::systems::input::{InputContext, InputRegistryAppExt};
+use crate::systems::results_screen::RestartRunEvent;
+use crate::systems::settings_menu::GameSettings;
+use crate::systems::ui_feedback::UiFeedback;
+
+/// Game speed multiplier while the first breach plays out
+pub const BREACH_SLOW_MOTION: f32 = 0.5;
+/// Real seconds the first breach moment lasts unless skipped
+pub const BREACH_MOMENT_SECONDS: f32 = 2.5;
+/// Orthographic scale the camera closes in to, relative to the view it started from
+pub const BREACH_ZOOM: f32 = 0.75;
+/// Key that skips the moment
+pub const BREACH_SKIP_KEY: KeyCode = KeyCode::Space;
+/// How quickly the camera eases onto the base (higher = snappier)
+const BREACH_CAMERA_RATE: f32 = 4.0;
+
+/// The first-leak moment in progress: slow motion with the camera on the base.
+/// Only exists while the moment is playing.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct BreachMoment {
+    /// Real seconds left before the game speeds back up
+    pub remaining: f32,
+    /// Where the camera closes in on
+    pub focus: Vec2,
+    /// Camera position and scale to return to afterwards
+    pub home_position: Vec2,
+    pub home_scale: f32,
+}
+
+/// Marker for the "First breach!" banner
+#[derive(Component)]
+pub struct BreachBanner;
+
+/// Whether this frame's escapes include the first of the run: every escape the
+/// score has counted happened this frame
+pub fn is_first_breach(escapes_this_frame: usize, escapes_this_run: u32) -> bool {
+    escapes_this_frame > 0 && escapes_this_run as usize == escapes_this_frame
+}
+
+/// System to start the breach moment when the first enemy of a run leaks
+pub fn first_breach_trigger_system(
+    mut commands: Commands,
+    mut escape_events: EventReader<EnemyEscapedEvent>,
+    score: Res<Score>,
+    settings: Option<Res<GameSettings>>,
+    moment: Option<Res<BreachMoment>>,
+    mut clock: ResMut<SimulationClock>,
+    enemy_path: Res<EnemyPath>,
+    base_query: Query<&Transform, With<Base>>,
+    camera_query: Query<(&Transform, &Projection), With<Camera2d>>,
+    mut feedback: UiFeedback,
+) {
+    let escapes = escape_events.read().count();
+    if !is_first_breach(escapes, score.enemies_escaped) || moment.is_some() {
+        return;
+    }
+    if settings.is_some_and(|settings| !settings.first_breach_moment_enabled) {
+        return;
+    }
+
+    let focus = base_query
+        .iter()
+        .next()
+        .map(|transform| transform.translation.truncate())
+        .or_else(|| enemy_path.waypoints.last().copied())
+        .unwrap_or(Vec2::ZERO);
+    let (home_position, home_scale) = camera_query
+        .single()
+        .map(|(transform, projection)| {
+            let scale = match projection {
+                Projection::Orthographic(orthographic) => orthographic.scale,
+                _ => 1.0,
+            };
+            (transform.translation.truncate(), scale)
+        })
+        .unwrap_or((Vec2::ZERO, 1.0));
+
+    commands.insert_resource(BreachMoment {
+        remaining: BREACH_MOMENT_SECONDS,
+        focus,
+        home_position,
+        home_scale,
+    });
+    clock.set_time_scale(BREACH_SLOW_MOTION);
+    feedback.alert();
+
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                top: Val::Px(90.0),
+                width: Val::Vw(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(6.0),
+                ..default()
+            },
+            ZIndex(702), // Above the threat alerts, below the results screen
+            BreachBanner,
+        ))
+        .with_children(|banner| {
+            banner.spawn((
+                Text::new("First breach!"),
+                TextFont {
+                    font_size: 40.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.3, 0.25)),
+            ));
+            banner.spawn((
+                Text::new("Every enemy that reaches the base damages it. Press Space to continue."),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+            ));
+        });
+    info!("First breach of the run; slowing to {}x", BREACH_SLOW_MOTION);
+}
+
+/// System to play out the breach moment: the camera eases onto the base until
+/// the moment runs out, is skipped, or the run ends, then everything is put back
+pub fn first_breach_moment_system(
+    mut commands: Commands,
+    real_time: Res<Time<Real>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    game_state: Res<GameState>,
+    mut restart_events: EventReader<RestartRunEvent>,
+    moment: Option<ResMut<BreachMoment>>,
+    mut clock: ResMut<SimulationClock>,
+    mut camera_query: Query<(&mut Transform, &mut Projection), With<Camera2d>>,
+    banners: Query<Entity, With<BreachBanner>>,
+) {
+    let Some(mut moment) = moment else {
+        return;
+    };
+
+    let skipped = keyboard_input.just_pressed(BREACH_SKIP_KEY);
+    let restarted = restart_events.read().count() > 0;
+    if skipped || restarted || *game_state != GameState::Playing || moment.remaining <= 0.0 {
+        clock.set_time_scale(1.0);
+        // The results screen takes the camera over itself when the run has ended
+        if *game_state == GameState::Playing {
+            if let Ok((mut transform, mut projection)) = camera_query.single_mut() {
+                transform.translation.x = moment.home_position.x;
+                transform.translation.y = moment.home_position.y;
+                if let Projection::Orthographic(orthographic) = projection.as_mut() {
+                    orthographic.scale = moment.home_scale;
+                }
+            }
+        }
+        for entity in banners.iter() {
+            commands.entity(entity).despawn();
+        }
+        commands.remove_resource::<BreachMoment>();
+        return;
+    }
+
+    // Menus freeze the moment along with the game
+    if clock.is_frozen() {
+        return;
+    }
+    let delta_secs = real_time.delta_secs();
+    moment.remaining -= delta_secs;
+
+    let blend = 1.0 - (-BREACH_CAMERA_RATE * delta_secs).exp();
+    let target_scale = moment.home_scale * BREACH_ZOOM;
+    if let Ok((mut transform, mut projection)) = camera_query.single_mut() {
+        let position = transform.translation.truncate().lerp(moment.focus, blend);
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+        if let Projection::Orthographic(orthographic) = projection.as_mut() {
+            orthographic.scale += (target_scale - orthographic.scale) * blend;
+        }
+    }
+}
+
+/// Plugin for the one-off slow-motion moment when the first enemy of a run leaks
+pub struct FirstBreachPlugin;
+
+impl Plugin for FirstBreachPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .register_key_hint(BREACH_SKIP_KEY, "Skip the first breach moment", InputContext::Game)
+            .add_systems(
+                Update,
+                (first_breach_trigger_system, first_breach_moment_system)
+                    .chain()
+                    .in_set(GameSystemSet::Gameplay)
+                    .after(EnemySet::Cleanup)
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
@@ -0,0 +1,177 @@
+use bevy::prelude::*;
+use crate::components::{CosmeticEffect, EffectCategory};
+use crate::resources::*;
+use crate::systems::combat_system::{DamageKind, EnemyDamagedEvent};
+use crate::systems::settings_menu::GameSettings;
+
+/// Seconds a damage number takes to rise and fade out
+pub const FLOATING_TEXT_LIFETIME: f32 = 0.8;
+/// How fast a damage number rises, in pixels per second
+pub const FLOATING_TEXT_RISE_SPEED: f32 = 40.0;
+/// Most faded-out text entities kept hidden for reuse; any beyond are despawned
+pub const FLOATING_TEXT_POOL_SIZE: usize = 64;
+/// Height above the enemy's centre a number starts at
+const FLOATING_TEXT_OFFSET: f32 = 14.0;
+const FLOATING_TEXT_FONT_SIZE: f32 = 12.0;
+const CRITICAL_FONT_SIZE: f32 = 16.0;
+/// Above enemies, health bars and projectiles
+const FLOATING_TEXT_Z: f32 = 15.0;
+
+/// Component for a damage number rising and fading above a hit enemy
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct FloatingText {
+    pub age: f32,
+    pub lifetime: f32,
+}
+
+impl FloatingText {
+    pub fn new() -> Self {
+        Self {
+            age: 0.0,
+            lifetime: FLOATING_TEXT_LIFETIME,
+        }
+    }
+
+    /// Remaining opacity, from 1.0 when spawned to 0.0 at the end of its lifetime
+    pub fn alpha(&self) -> f32 {
+        (1.0 - self.age / self.lifetime).clamp(0.0, 1.0)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.age >= self.lifetime
+    }
+}
+
+impl Default for FloatingText {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Marker for text entities that stay alive once their number fades, so the
+/// next hit reuses them instead of spawning a new entity. A slot without
+/// `FloatingText` is free.
+#[derive(Component, Debug)]
+pub struct FloatingTextSlot;
+
+/// Colour of a damage number, by how the damage was dealt
+pub fn damage_number_color(kind: DamageKind) -> Color {
+    match kind {
+        DamageKind::Direct => Color::srgb(0.95, 0.95, 0.95),
+        DamageKind::Critical => Color::srgb(1.0, 0.85, 0.1),
+        DamageKind::Splash => Color::srgb(1.0, 0.55, 0.15),
+        DamageKind::Chain => Color::srgb(0.5, 0.8, 1.0),
+        DamageKind::Burn => Color::srgb(1.0, 0.3, 0.2),
+    }
+}
+
+/// Text of a damage number: whole points, never below 1, with crits marked
+pub fn damage_number_label(amount: f32, kind: DamageKind) -> String {
+    let points = amount.round().max(1.0) as u32;
+    if kind == DamageKind::Critical {
+        format!("{}!", points)
+    } else {
+        points.to_string()
+    }
+}
+
+/// Components of a live damage number, for a fresh entity or a reused slot
+fn damage_number(event: &EnemyDamagedEvent) -> impl Bundle {
+    let font_size = if event.kind == DamageKind::Critical {
+        CRITICAL_FONT_SIZE
+    } else {
+        FLOATING_TEXT_FONT_SIZE
+    };
+    (
+        Text2d::new(damage_number_label(event.amount, event.kind)),
+        TextFont {
+            font_size,
+            ..default()
+        },
+        TextColor(damage_number_color(event.kind)),
+        Transform::from_translation((event.position + Vec2::Y * FLOATING_TEXT_OFFSET).extend(FLOATING_TEXT_Z)),
+        Visibility::Visible,
+        FloatingText::new(),
+        CosmeticEffect(EffectCategory::DamageNumber),
+    )
+}
+
+/// System to show a damage number over every enemy hit, reusing faded-out
+/// slots before spawning new text. Numbers are skipped when turned off in
+/// the settings or when the effect budget is full.
+pub fn damage_number_system(
+    mut commands: Commands,
+    mut damage_events: EventReader<EnemyDamagedEvent>,
+    settings: Option<Res<GameSettings>>,
+    mut effect_budget: Option<ResMut<EffectBudget>>,
+    free_slots: Query<Entity, (With<FloatingTextSlot>, Without<FloatingText>)>,
+) {
+    if settings.is_some_and(|settings| !settings.damage_numbers_enabled) {
+        damage_events.clear();
+        return;
+    }
+
+    let mut free_slots = free_slots.iter();
+    for event in damage_events.read() {
+        if event.amount <= 0.0 {
+            continue;
+        }
+        if !effect_budget.as_deref_mut().is_none_or(|budget| budget.admit(EffectCategory::DamageNumber)) {
+            continue;
+        }
+        match free_slots.next() {
+            Some(slot) => {
+                commands.entity(slot).insert(damage_number(event));
+            }
+            None => {
+                commands.spawn((damage_number(event), FloatingTextSlot));
+            }
+        }
+    }
+}
+
+/// System to float damage numbers upwards while fading them out. Finished
+/// numbers are hidden and handed back to the pool, up to its size.
+pub fn floating_text_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    free_slots: Query<(), (With<FloatingTextSlot>, Without<FloatingText>)>,
+    mut texts: Query<(Entity, &mut FloatingText, &mut Transform, &mut TextColor)>,
+) {
+    let delta_secs = time.delta_secs();
+    let mut pooled = free_slots.iter().count();
+    for (entity, mut text, mut transform, mut color) in texts.iter_mut() {
+        text.age += delta_secs;
+        if !text.is_finished() {
+            transform.translation.y += FLOATING_TEXT_RISE_SPEED * delta_secs;
+            color.0.set_alpha(text.alpha());
+            continue;
+        }
+
+        if pooled < FLOATING_TEXT_POOL_SIZE {
+            commands
+                .entity(entity)
+                .insert(Visibility::Hidden)
+                .remove::<(FloatingText, CosmeticEffect)>();
+            pooled += 1;
+        } else {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Plugin for the damage numbers floating above hit enemies
+pub struct FloatingTextPlugin;
+
+impl Plugin for FloatingTextPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (damage_number_system, floating_text_system)
+                .chain()
+                .after(CombatSet::Collision)
+                .in_set(GameSystemSet::Gameplay)
+                .run_if(in_state(AppState::Playing)),
+        );
+    }
+}
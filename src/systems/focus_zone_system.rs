@@ -0,0 +1,229 @@
+use bevy::prelude::*;
+use crate::resources::{AppState, GameSystemSet, TowerStats};
+use crate::systems::combat_system::FocusZone;
+use crate::systems::input::{InputContext, InputRegistryAppExt};
+use crate::systems::input_system::MouseInputState;
+use crate::systems::multi_select_system::multi_select_click_system;
+use crate::systems::tower_ui::{tower_selection_system, FocusZoneButton, FocusZoneButtonText, TowerSelectionState};
+
+/// Minimum drag distance (world units) for a gesture to draw a zone rather than clear it
+const MIN_FOCUS_DRAG: f32 = 8.0;
+const FOCUS_ZONE_COLOR: Color = Color::srgba(1.0, 0.85, 0.2, 0.18);
+const FOCUS_DRAG_COLOR: Color = Color::srgba(1.0, 0.85, 0.2, 0.3);
+
+// ============================================================================
+// STATE
+// ============================================================================
+
+/// Resource tracking a focus zone being drawn for the selected tower
+#[derive(Resource, Debug, Default)]
+pub struct FocusZoneDrawing {
+    /// Tower the zone is being drawn for; `None` when not drawing
+    pub tower: Option<Entity>,
+    /// The left button went down on the map since drawing started
+    pub dragging: bool,
+}
+
+impl FocusZoneDrawing {
+    pub fn is_active(&self) -> bool {
+        self.tower.is_some()
+    }
+
+    pub fn start(&mut self, tower: Entity) {
+        self.tower = Some(tower);
+        self.dragging = false;
+    }
+
+    pub fn cancel(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Marker for the sprites showing the selected tower's zone and the drag in progress
+#[derive(Component)]
+pub struct FocusZoneSprite;
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// System to start or cancel drawing a focus zone for the selected tower from
+/// its panel button or the F key
+pub fn focus_zone_button_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut mouse_input_state: ResMut<MouseInputState>,
+    selection_state: Res<TowerSelectionState>,
+    mut drawing: ResMut<FocusZoneDrawing>,
+    button_query: Query<&Interaction, (Changed<Interaction>, With<FocusZoneButton>)>,
+) {
+    let mut toggle_requested = keyboard_input.just_pressed(KeyCode::KeyF);
+    for interaction in button_query.iter() {
+        if *interaction == Interaction::Pressed {
+            // Consume the mouse click so the press doesn't start the drag
+            mouse_input_state.left_clicked = false;
+            toggle_requested = true;
+        }
+    }
+    if !toggle_requested {
+        return;
+    }
+
+    match selection_state.selected_tower_entity {
+        Some(tower) if drawing.tower != Some(tower) => {
+            drawing.start(tower);
+            println!("Drag over the map to set tower {:?}'s focus zone, click to clear it", tower);
+        }
+        _ => drawing.cancel(),
+    }
+}
+
+/// System to turn the drag made while drawing into the tower's focus zone. A
+/// click without a drag clears the zone, and a right-click cancels drawing.
+pub fn focus_zone_draw_system(
+    mut commands: Commands,
+    mut mouse_input_state: ResMut<MouseInputState>,
+    selection_state: Res<TowerSelectionState>,
+    mut drawing: ResMut<FocusZoneDrawing>,
+    towers: Query<(&Transform, &TowerStats)>,
+    ui_interaction_query: Query<&Interaction, With<Button>>,
+) {
+    let Some(tower) = drawing.tower else {
+        return;
+    };
+    let Ok((transform, stats)) = towers.get(tower) else {
+        drawing.cancel();
+        return;
+    };
+    if mouse_input_state.right_clicked || selection_state.selected_tower_entity != Some(tower) {
+        drawing.cancel();
+        return;
+    }
+
+    // Presses on the panels are button clicks, not the start of a zone
+    let ui_is_active = ui_interaction_query
+        .iter()
+        .any(|interaction| matches!(*interaction, Interaction::Pressed | Interaction::Hovered));
+
+    // Keep the gesture from selecting or deselecting towers
+    if mouse_input_state.left_clicked && !ui_is_active {
+        mouse_input_state.left_clicked = false;
+        drawing.dragging = true;
+    }
+    if !drawing.dragging || !mouse_input_state.left_released {
+        return;
+    }
+    mouse_input_state.left_released = false;
+
+    let drag = mouse_input_state
+        .drag_rect()
+        .filter(|drag| drag.size().max_element() >= MIN_FOCUS_DRAG);
+    match drag {
+        Some(drag) => match FocusZone::from_drag(transform.translation.truncate(), stats.range, drag) {
+            Some(zone) => {
+                commands.entity(tower).insert(zone);
+                println!("Tower {:?} now only targets enemies inside its focus zone", tower);
+            }
+            None => println!("Focus zone must overlap the tower's range"),
+        },
+        None => {
+            commands.entity(tower).remove::<FocusZone>();
+            println!("Tower {:?} targets its full range again", tower);
+        }
+    }
+    drawing.cancel();
+}
+
+/// System to show the selected tower's focus zone and the zone being dragged out
+pub fn focus_zone_visual_system(
+    mut commands: Commands,
+    mouse_input_state: Res<MouseInputState>,
+    selection_state: Res<TowerSelectionState>,
+    drawing: Res<FocusZoneDrawing>,
+    zones: Query<&FocusZone>,
+    sprites: Query<Entity, With<FocusZoneSprite>>,
+) {
+    for entity in sprites.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let dragged = mouse_input_state
+        .drag_rect()
+        .filter(|_| drawing.dragging && mouse_input_state.left_held);
+    let current = selection_state
+        .selected_tower_entity
+        .and_then(|tower| zones.get(tower).ok())
+        .map(|zone| zone.rect);
+
+    for (rect, color) in [(current, FOCUS_ZONE_COLOR), (dragged, FOCUS_DRAG_COLOR)] {
+        let Some(rect) = rect else {
+            continue;
+        };
+        commands.spawn((
+            Sprite {
+                color,
+                custom_size: Some(rect.size()),
+                ..default()
+            },
+            Transform::from_translation(rect.center().extend(5.0)),
+            FocusZoneSprite,
+        ));
+    }
+}
+
+/// System to show the selected tower's focus state on its panel button
+pub fn focus_zone_button_label_system(
+    selection_state: Res<TowerSelectionState>,
+    drawing: Res<FocusZoneDrawing>,
+    zones: Query<(), With<FocusZone>>,
+    mut text_query: Query<&mut Text, With<FocusZoneButtonText>>,
+    mut button_query: Query<&mut BackgroundColor, With<FocusZoneButton>>,
+) {
+    let has_zone = selection_state
+        .selected_tower_entity
+        .is_some_and(|tower| zones.contains(tower));
+    let (label, color) = if drawing.is_active() {
+        ("DRAG ZONE - CLICK TO CLEAR", Color::srgb(0.75, 0.6, 0.15))
+    } else if has_zone {
+        ("FOCUS: ZONE (F)", Color::srgb(0.55, 0.5, 0.2))
+    } else {
+        ("FOCUS: FULL RANGE (F)", Color::srgb(0.3, 0.4, 0.5))
+    };
+
+    if let Ok(mut text) = text_query.single_mut() {
+        if **text != label {
+            **text = label.to_string();
+        }
+    }
+    if let Ok(mut background) = button_query.single_mut() {
+        background.0 = color;
+    }
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin to let the player restrict a tower's targeting to part of its range
+pub struct FocusZonePlugin;
+
+impl Plugin for FocusZonePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FocusZoneDrawing>()
+            .register_key_hint(KeyCode::KeyF, "Draw a focus zone for the selected tower", InputContext::Game)
+            .add_systems(
+                Update,
+                (focus_zone_button_system, focus_zone_draw_system)
+                    .chain()
+                    .in_set(GameSystemSet::UI)
+                    .before(multi_select_click_system)
+                    .before(tower_selection_system)
+                    .run_if(in_state(AppState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (focus_zone_visual_system, focus_zone_button_label_system)
+                    .in_set(GameSystemSet::UI)
+                    .after(tower_selection_system),
+            );
+    }
+}
@@ -0,0 +1,64 @@
+use std::time::{Duration, Instant};
+use bevy::prelude::*;
+use bevy::winit::{UpdateMode, WinitSettings};
+use crate::resources::{GameSystemSet, POWER_SAVING_FPS};
+use crate::systems::settings_menu::GameSettings;
+
+/// Resource remembering when the previous frame finished, to time the frame cap from
+#[derive(Resource, Default)]
+pub struct FramePacer {
+    pub last_frame_end: Option<Instant>,
+}
+
+/// How the game updates while the window is unfocused or minimized
+pub fn unfocused_update_mode(power_saving: bool) -> UpdateMode {
+    if power_saving {
+        UpdateMode::reactive_low_power(Duration::from_secs_f64(1.0 / POWER_SAVING_FPS))
+    } else {
+        UpdateMode::Continuous
+    }
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// System to hold each frame back until the chosen frame limit allows the next
+pub fn frame_limiter_system(mut pacer: ResMut<FramePacer>, game_settings: Res<GameSettings>) {
+    if let Some(last_frame_end) = pacer.last_frame_end {
+        let wait = game_settings.frame_limit.remaining_wait(last_frame_end.elapsed());
+        if !wait.is_zero() {
+            std::thread::sleep(wait);
+        }
+    }
+    pacer.last_frame_end = Some(Instant::now());
+}
+
+/// System to slow updates down out of focus while power saving is on
+pub fn apply_power_saving_system(
+    game_settings: Res<GameSettings>,
+    winit_settings: Option<ResMut<WinitSettings>>,
+) {
+    if !game_settings.is_changed() {
+        return;
+    }
+    if let Some(mut winit_settings) = winit_settings {
+        winit_settings.unfocused_mode = unfocused_update_mode(game_settings.power_saving);
+    }
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin capping the frame rate and throttling the game in the background
+pub struct FramePacingPlugin;
+
+impl Plugin for FramePacingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FramePacer>()
+            .add_systems(Update, apply_power_saving_system.in_set(GameSystemSet::UI))
+            // Last, so the whole frame's work counts towards its time
+            .add_systems(Last, frame_limiter_system);
+    }
+}
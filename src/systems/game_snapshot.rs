@@ -0,0 +1,234 @@
+use bevy::prelude::*;
+use bevy::remote::{error_codes, BrpError, BrpResult, RemoteMethodSystemId, RemoteMethods};
+use serde::{Deserialize, Serialize};
+use crate::components::{Base, Enemy, Health, PathProgress, UpgradeBranch};
+use crate::resources::{AppState, Economy, EnemyPath, GameState, Score, TowerStats, TowerType, WavePlan, WaveRuntime};
+use crate::systems::smart_enemy_system::SmartEnemy;
+
+/// Remote method returning the current `GameSnapshot`
+pub const SNAPSHOT_METHOD: &str = "tower_defense/snapshot";
+
+// ============================================================================
+// SNAPSHOT
+// ============================================================================
+
+/// Everything a player can observe about the game at one moment, in a form that
+/// serializes cleanly. Positions are world coordinates as `[x, y]`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GameSnapshot {
+    /// `AppState` name, e.g. "Playing" or "Paused"
+    pub app_state: String,
+    /// `GameState` name, e.g. "Playing" or "GameOver"
+    pub game_state: String,
+    pub wave: WaveSnapshot,
+    pub economy: EconomySnapshot,
+    pub score: ScoreSnapshot,
+    pub base: Option<HealthSnapshot>,
+    /// Towers in entity order
+    pub towers: Vec<TowerState>,
+    /// Live enemies in entity order
+    pub enemies: Vec<EnemyState>,
+    /// Waypoints of the shared enemy path
+    pub path: Vec<[f32; 2]>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WaveSnapshot {
+    pub current: u32,
+    pub enemies_spawned: u32,
+    pub enemies_in_wave: u32,
+    /// Every enemy of the wave has spawned
+    pub complete: bool,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EconomySnapshot {
+    pub money: u32,
+    pub research_points: u32,
+    pub materials: u32,
+    pub energy: u32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ScoreSnapshot {
+    pub points: u32,
+    pub enemies_killed: u32,
+    pub enemies_escaped: u32,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct HealthSnapshot {
+    pub current: f32,
+    pub max: f32,
+}
+
+impl From<&Health> for HealthSnapshot {
+    fn from(health: &Health) -> Self {
+        Self {
+            current: health.current,
+            max: health.max,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TowerState {
+    /// `Entity::to_bits` of the tower
+    pub entity: u64,
+    pub tower_type: TowerType,
+    pub position: [f32; 2],
+    pub upgrade_level: u32,
+    #[serde(default)]
+    pub branch: Option<UpgradeBranch>,
+    pub damage: f32,
+    pub range: f32,
+    pub fire_rate: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnemyState {
+    /// `Entity::to_bits` of the enemy
+    pub entity: u64,
+    pub position: [f32; 2],
+    pub health: Option<HealthSnapshot>,
+    pub speed: f32,
+    /// Progress along the route the enemy is walking, 0.0 to 1.0
+    pub progress: f32,
+    pub smart: bool,
+}
+
+impl GameSnapshot {
+    /// Read the current game state out of a world. Resources or components the
+    /// world doesn't have are left at their defaults.
+    pub fn capture(world: &World) -> Self {
+        let wave = world
+            .get_resource::<WavePlan>()
+            .zip(world.get_resource::<WaveRuntime>())
+            .map(|(wave_plan, wave_runtime)| WaveSnapshot {
+                current: wave_plan.current_wave,
+                enemies_spawned: wave_runtime.enemies_spawned,
+                enemies_in_wave: wave_plan.enemies_in_wave(),
+                complete: wave_runtime.wave_complete(wave_plan),
+            })
+            .unwrap_or_default();
+        let economy = world
+            .get_resource::<Economy>()
+            .map(|economy| EconomySnapshot {
+                money: economy.money,
+                research_points: economy.research_points,
+                materials: economy.materials,
+                energy: economy.energy,
+            })
+            .unwrap_or_default();
+        let score = world
+            .get_resource::<Score>()
+            .map(|score| ScoreSnapshot {
+                points: score.current,
+                enemies_killed: score.enemies_killed,
+                enemies_escaped: score.enemies_escaped,
+            })
+            .unwrap_or_default();
+
+        let mut towers: Vec<TowerState> = world
+            .try_query::<(Entity, &TowerStats, &Transform)>()
+            .map(|mut query| {
+                query
+                    .iter(world)
+                    .map(|(entity, stats, transform)| TowerState {
+                        entity: entity.to_bits(),
+                        tower_type: stats.tower_type,
+                        position: transform.translation.truncate().to_array(),
+                        upgrade_level: stats.upgrade_level,
+                        branch: stats.branch,
+                        damage: stats.damage,
+                        range: stats.range,
+                        fire_rate: stats.fire_rate,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        towers.sort_by_key(|tower| tower.entity);
+
+        // Optional parts are looked up per enemy, since a query would come back
+        // empty whenever one of them was never registered
+        let mut enemies: Vec<EnemyState> = world
+            .try_query::<(Entity, &Enemy, &Transform)>()
+            .map(|mut query| {
+                query
+                    .iter(world)
+                    .map(|(entity, enemy, transform)| EnemyState {
+                        entity: entity.to_bits(),
+                        position: transform.translation.truncate().to_array(),
+                        health: world.get::<Health>(entity).map(HealthSnapshot::from),
+                        speed: enemy.speed,
+                        progress: world.get::<PathProgress>(entity).map_or(0.0, |progress| progress.current),
+                        smart: world.get::<SmartEnemy>(entity).is_some(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        enemies.sort_by_key(|enemy| enemy.entity);
+
+        let base = world
+            .try_query_filtered::<&Health, With<Base>>()
+            .and_then(|mut query| query.iter(world).next().map(HealthSnapshot::from));
+
+        Self {
+            app_state: world
+                .get_resource::<State<AppState>>()
+                .map(|state| format!("{:?}", state.get()))
+                .unwrap_or_default(),
+            game_state: world
+                .get_resource::<GameState>()
+                .map(|state| format!("{:?}", state))
+                .unwrap_or_default(),
+            wave,
+            economy,
+            score,
+            base,
+            towers,
+            enemies,
+            path: world
+                .get_resource::<EnemyPath>()
+                .map(|path| path.waypoints.iter().map(|point| point.to_array()).collect())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+// ============================================================================
+// REMOTE METHOD
+// ============================================================================
+
+/// Remote handler answering `SNAPSHOT_METHOD` with the current snapshot as JSON
+pub fn process_snapshot_request(In(_params): In<Option<serde_json::Value>>, world: &World) -> BrpResult {
+    serde_json::to_value(GameSnapshot::capture(world)).map_err(|error| BrpError {
+        code: error_codes::INTERNAL_ERROR,
+        message: error.to_string(),
+        data: None,
+    })
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin exposing `GameSnapshot` over the remote protocol when a remote plugin is present
+pub struct GameSnapshotPlugin;
+
+impl Plugin for GameSnapshotPlugin {
+    fn build(&self, _app: &mut App) {}
+
+    // Remote methods are registered once every plugin is built, so it doesn't
+    // matter whether the remote plugin was added before or after this one
+    fn finish(&self, app: &mut App) {
+        let world = app.world_mut();
+        if !world.contains_resource::<RemoteMethods>() {
+            return;
+        }
+        let system = world.register_system(process_snapshot_request);
+        world
+            .resource_mut::<RemoteMethods>()
+            .insert(SNAPSHOT_METHOD, RemoteMethodSystemId::Instant(system));
+    }
+}
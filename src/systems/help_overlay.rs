@@ -0,0 +1,296 @@
+use bevy::prelude::*;
+use crate::systems::input::{key_name, InputContext, InputHandler, InputMappingRegistry, InputRegistryAppExt};
+
+/// Key that toggles the help overlay
+pub const HELP_KEY: KeyCode = KeyCode::KeyH;
+
+const HIGHLIGHT_COLOR: Color = Color::srgb(1.0, 0.85, 0.3);
+const OVERLAY_BG: Color = Color::srgba(0.0, 0.0, 0.0, 0.55);
+const CALLOUT_BG: Color = Color::srgba(0.08, 0.08, 0.12, 0.95);
+
+/// Resource tracking whether the help overlay is shown
+#[derive(Resource, Debug, Default)]
+pub struct HelpOverlayState {
+    pub visible: bool,
+}
+
+/// A part of the screen the help overlay annotates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HelpRegion {
+    PlacementPanel,
+    Resources,
+    StartWave,
+    UpgradePanel,
+    Hud,
+}
+
+impl HelpRegion {
+    pub const ALL: [HelpRegion; 5] = [
+        HelpRegion::PlacementPanel,
+        HelpRegion::Resources,
+        HelpRegion::StartWave,
+        HelpRegion::UpgradePanel,
+        HelpRegion::Hud,
+    ];
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            HelpRegion::PlacementPanel => "TOWER SELECTION",
+            HelpRegion::Resources => "RESOURCES",
+            HelpRegion::StartWave => "START WAVE",
+            HelpRegion::UpgradePanel => "UPGRADE PANEL",
+            HelpRegion::Hud => "HUD",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            HelpRegion::PlacementPanel => "Left click a tower to select it, then click the map to place it.\nRight click a tower button for detailed stats.",
+            HelpRegion::Resources => "Money, research, materials and energy.\nTowers and upgrades are paid from here.",
+            HelpRegion::StartWave => "Sends the next wave down the path.",
+            HelpRegion::UpgradePanel => "Shown when a placed tower is clicked.\nUpgrade or overclock the selected tower.",
+            HelpRegion::Hud => "Enemies left in the wave, wave alerts and active buffs\nappear at the top of the screen.",
+        }
+    }
+
+    /// Keys listed in the region's callout
+    pub fn hotkeys(&self) -> &'static [KeyCode] {
+        match self {
+            HelpRegion::PlacementPanel => &[KeyCode::ShiftLeft],
+            HelpRegion::Resources => &[],
+            HelpRegion::StartWave => &[],
+            HelpRegion::UpgradePanel => &[KeyCode::KeyO],
+            HelpRegion::Hud => &[
+                KeyCode::Escape,
+                KeyCode::KeyC,
+                HELP_KEY,
+                KeyCode::F1,
+                KeyCode::F2,
+                KeyCode::F3,
+                KeyCode::F4,
+                KeyCode::F9,
+            ],
+        }
+    }
+
+    /// Box drawn around the region, matching where its panel is laid out
+    fn highlight_node(&self) -> Node {
+        let (right, top, width, height) = match self {
+            HelpRegion::PlacementPanel => (20.0, 20.0, 250.0, 340.0),
+            HelpRegion::Resources => (20.0, 365.0, 250.0, 50.0),
+            HelpRegion::StartWave => (20.0, 415.0, 250.0, 52.0),
+            HelpRegion::UpgradePanel => (240.0, 20.0, 250.0, 400.0),
+            HelpRegion::Hud => {
+                return Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Percent(30.0),
+                    top: Val::Px(60.0),
+                    width: Val::Percent(40.0),
+                    height: Val::Px(60.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                };
+            }
+        };
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(right),
+            top: Val::Px(top),
+            width: Val::Px(width),
+            height: Val::Px(height),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        }
+    }
+
+    /// Where the region's callout sits, next to its highlight
+    fn callout_node(&self) -> Node {
+        let mut node = Node {
+            position_type: PositionType::Absolute,
+            max_width: Val::Px(300.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(8.0)),
+            border: UiRect::all(Val::Px(1.0)),
+            ..default()
+        };
+        match self {
+            HelpRegion::PlacementPanel => {
+                node.right = Val::Px(500.0);
+                node.top = Val::Px(440.0);
+            }
+            HelpRegion::Resources => {
+                node.right = Val::Px(280.0);
+                node.top = Val::Px(560.0);
+            }
+            HelpRegion::StartWave => {
+                node.right = Val::Px(20.0);
+                node.top = Val::Px(480.0);
+            }
+            HelpRegion::UpgradePanel => {
+                node.right = Val::Px(500.0);
+                node.top = Val::Px(200.0);
+            }
+            HelpRegion::Hud => {
+                node.left = Val::Percent(30.0);
+                node.top = Val::Px(130.0);
+            }
+        }
+        node
+    }
+}
+
+/// Callout text for a region, with its hotkeys described by the input registry
+pub fn callout_text(region: HelpRegion, registry: &InputMappingRegistry) -> String {
+    let mut text = format!("{}\n{}", region.title(), region.description());
+    for key in region.hotkeys() {
+        if let Some(description) = registry.describe_key(*key) {
+            text.push_str(&format!("\n[{}] {}", key_name(*key), description));
+        }
+    }
+    text
+}
+
+/// Component marker for the dimmed help overlay
+#[derive(Component)]
+pub struct HelpOverlay;
+
+/// Component for a region's callout text
+#[derive(Component)]
+pub struct HelpCallout(pub HelpRegion);
+
+/// Component for the corner hint pointing at the help key
+#[derive(Component)]
+pub struct HelpHintText;
+
+/// Input handler toggling the help overlay
+pub struct HelpOverlayHandler;
+
+impl InputHandler for HelpOverlayHandler {
+    fn handle_input(&self, world: &mut World, key: KeyCode) -> bool {
+        if key != HELP_KEY {
+            return false;
+        }
+        let Some(mut state) = world.get_resource_mut::<HelpOverlayState>() else {
+            warn!("Help handler: HelpOverlayState resource not found");
+            return false;
+        };
+        state.visible = !state.visible;
+        true
+    }
+
+    fn get_description(&self) -> &str {
+        "Toggle this help overlay"
+    }
+
+    fn get_priority(&self) -> u8 {
+        50
+    }
+
+    fn get_id(&self) -> &str {
+        "help_overlay"
+    }
+
+    fn handles_key(&self, key: KeyCode) -> bool {
+        key == HELP_KEY
+    }
+
+    fn get_handled_keys(&self) -> Vec<KeyCode> {
+        vec![HELP_KEY]
+    }
+
+    fn get_context(&self) -> InputContext {
+        InputContext::UI
+    }
+}
+
+/// Setup system for the help overlay and the corner hint
+pub fn setup_help_overlay(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                top: Val::Px(0.0),
+                width: Val::Vw(100.0),
+                height: Val::Vh(100.0),
+                ..default()
+            },
+            BackgroundColor(OVERLAY_BG),
+            Visibility::Hidden,
+            ZIndex(900), // Above the game UI, below the pause menu
+            HelpOverlay,
+        ))
+        .with_children(|overlay| {
+            for region in HelpRegion::ALL {
+                overlay.spawn((region.highlight_node(), BorderColor(HIGHLIGHT_COLOR)));
+                overlay.spawn((
+                    region.callout_node(),
+                    BackgroundColor(CALLOUT_BG),
+                    BorderColor(HIGHLIGHT_COLOR),
+                )).with_children(|callout| {
+                    callout.spawn((
+                        Text::new(region.title()),
+                        TextFont {
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        HelpCallout(region),
+                    ));
+                });
+            }
+        });
+
+    commands.spawn((
+        Text::new(format!("{}: help", key_name(HELP_KEY))),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(Color::srgba(1.0, 1.0, 1.0, 0.7)),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(10.0),
+            bottom: Val::Px(10.0),
+            ..default()
+        },
+        HelpHintText,
+    ));
+}
+
+/// System to show or hide the overlay, refreshing the callouts from the input registry
+pub fn help_overlay_system(
+    state: Res<HelpOverlayState>,
+    registry: Option<Res<InputMappingRegistry>>,
+    mut overlay_query: Query<&mut Visibility, With<HelpOverlay>>,
+    mut callout_query: Query<(&mut Text, &HelpCallout)>,
+) {
+    let registry_changed = registry.as_ref().is_some_and(|registry| registry.is_changed());
+    if !state.is_changed() && !registry_changed {
+        return;
+    }
+
+    for mut visibility in &mut overlay_query {
+        visibility.set_if_neq(if state.visible { Visibility::Inherited } else { Visibility::Hidden });
+    }
+
+    let Some(registry) = registry else {
+        return;
+    };
+    for (mut text, callout) in &mut callout_query {
+        **text = callout_text(callout.0, &registry);
+    }
+}
+
+/// Plugin for the contextual help overlay
+pub struct HelpOverlayPlugin;
+
+impl Plugin for HelpOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<HelpOverlayState>()
+            .register_input_handler(HelpOverlayHandler)
+            .add_systems(Startup, setup_help_overlay)
+            .add_systems(Update, help_overlay_system);
+    }
+}
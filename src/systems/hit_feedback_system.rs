@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::resources::*;
+use crate::systems::combat_system::EnemyDamagedEvent;
+use crate::systems::enemy_system::EnemyRoutes;
+use crate::systems::tween::{ColorTween, Easing, TweenProgress};
+
+/// Seconds an enemy's white hit flash takes to fade back to its colour
+const HIT_FLASH_DURATION: f32 = 0.12;
+const HIT_FLASH_COLOR: Color = Color::WHITE;
+/// Enemies with at most this much maximum health are light enough to be knocked back
+pub const LIGHT_ENEMY_MAX_HEALTH: f32 = 150.0;
+/// Distance along the path a missile explosion pushes a light enemy back, in pixels
+pub const KNOCKBACK_DISTANCE: f32 = 12.0;
+/// Most an enemy can be pushed back within one second, in pixels, so a missile
+/// battery can slow a light enemy but never hold it in place
+pub const MAX_KNOCKBACK_PER_SECOND: f32 = 24.0;
+
+/// Whether an enemy is light enough to be knocked back
+pub fn is_light_enemy(health: &Health) -> bool {
+    health.max <= LIGHT_ENEMY_MAX_HEALTH
+}
+
+/// Whether hits from a tower type explode with enough force to knock enemies back
+pub fn causes_knockback(tower_type: TowerType) -> bool {
+    tower_type == TowerType::Missile
+}
+
+/// System to flash damaged enemies white and knock light enemies hit by missiles
+/// back along their path. Runs after collisions and before the next movement
+/// step, which places the enemy at its reduced progress.
+pub fn hit_feedback_system(
+    mut commands: Commands,
+    mut damage_events: EventReader<EnemyDamagedEvent>,
+    clock: Res<SimulationClock>,
+    enemy_path: Res<EnemyPath>,
+    mut enemies: Query<(
+        &Health,
+        &Sprite,
+        Option<&ColorTween>,
+        &mut PathProgress,
+        Option<&mut KnockbackLimiter>,
+        EnemyRoutes,
+    ), With<Enemy>>,
+) {
+    for event in damage_events.read() {
+        let Ok((health, sprite, flash, mut path_progress, limiter, routes)) = enemies.get_mut(event.enemy) else {
+            // Killed by the hit
+            continue;
+        };
+
+        // Fade back to the colour under any flash still running
+        let base_color = flash.map_or(sprite.color, |flash| flash.to);
+        commands.entity(event.enemy).insert(ColorTween::new(
+            HIT_FLASH_COLOR,
+            base_color,
+            TweenProgress::new(HIT_FLASH_DURATION, Easing::QuadOut),
+        ));
+
+        if !causes_knockback(event.tower_type) || !is_light_enemy(health) {
+            continue;
+        }
+        let Some(mut limiter) = limiter else {
+            continue;
+        };
+        let distance = limiter.allow(KNOCKBACK_DISTANCE, MAX_KNOCKBACK_PER_SECOND, clock.elapsed_secs());
+        let path = routes.active(&enemy_path);
+        let path_length = path.total_length();
+        if distance > 0.0 && path_length > 0.0 {
+            path_progress.advance(-distance / path_length);
+        }
+    }
+}
+
+/// Plugin for enemy hit flashes and missile knockback
+pub struct HitFeedbackPlugin;
+
+impl Plugin for HitFeedbackPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            hit_feedback_system
+                .after(CombatSet::Collision)
+                .in_set(GameSystemSet::Gameplay)
+                .run_if(in_state(AppState::Playing)),
+        );
+    }
+}
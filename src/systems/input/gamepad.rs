@@ -0,0 +1,231 @@
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use crate::components::Constructing;
+use crate::resources::*;
+use crate::systems::enemy_system::StartWaveEvent;
+use crate::systems::tower_ui::{try_upgrade_tower, TowerSelectionState};
+use crate::systems::ui_feedback::{UiCue, UiFeedbackEvent};
+use super::plugin::InputRegistryAppExt;
+use super::rebinding::KeyCapture;
+use super::registry::{is_playing, process_centralized_input, InputHandler, InputMappingRegistry};
+
+/// Key that sends the next wave
+pub const START_WAVE_KEY: KeyCode = KeyCode::KeyN;
+/// Key that upgrades the selected tower
+pub const UPGRADE_TOWER_KEY: KeyCode = KeyCode::KeyU;
+
+const START_WAVE_HANDLER_ID: &str = "start_wave";
+const UPGRADE_TOWER_HANDLER_ID: &str = "upgrade_tower";
+
+/// Face buttons and the handler key each one presses. South (place) and East
+/// (cancel) drive the placement cursor instead.
+pub const GAMEPAD_BUTTON_ACTIONS: [(GamepadButton, &str, KeyCode); 2] = [
+    (GamepadButton::North, START_WAVE_HANDLER_ID, START_WAVE_KEY),
+    (GamepadButton::West, UPGRADE_TOWER_HANDLER_ID, UPGRADE_TOWER_KEY),
+];
+
+/// Stick deflection on an axis below which the stick counts as centred there
+pub const STICK_DEADZONE: f32 = 0.5;
+/// Seconds a stick is held before the cursor starts repeating
+const STICK_REPEAT_DELAY: f32 = 0.35;
+/// Seconds between repeated steps while the stick stays held
+const STICK_REPEAT_INTERVAL: f32 = 0.12;
+
+// ============================================================================
+// STICK CURSOR
+// ============================================================================
+
+/// Cell step a stick points in, or zero inside the deadzone. Diagonals step both axes.
+pub fn stick_direction(stick: Vec2) -> IVec2 {
+    let axis = |value: f32| {
+        if value >= STICK_DEADZONE {
+            1
+        } else if value <= -STICK_DEADZONE {
+            -1
+        } else {
+            0
+        }
+    };
+    IVec2::new(axis(stick.x), axis(stick.y))
+}
+
+/// Turns a held stick into cursor steps like a held arrow key: one step on
+/// pushing it, then a steady repeat after a short delay
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StickRepeat {
+    direction: IVec2,
+    repeat_in: f32,
+}
+
+impl StickRepeat {
+    /// Step to move this frame with the stick pointing in `direction`
+    pub fn update(&mut self, direction: IVec2, delta_secs: f32) -> IVec2 {
+        if direction != self.direction {
+            self.direction = direction;
+            self.repeat_in = STICK_REPEAT_DELAY;
+            return direction;
+        }
+        if direction == IVec2::ZERO {
+            return IVec2::ZERO;
+        }
+        self.repeat_in -= delta_secs;
+        if self.repeat_in > 0.0 {
+            return IVec2::ZERO;
+        }
+        self.repeat_in += STICK_REPEAT_INTERVAL;
+        direction
+    }
+}
+
+// ============================================================================
+// INPUT HANDLERS
+// ============================================================================
+
+/// Handler sending the next wave, like the Start Wave button
+pub struct StartWaveHandler;
+
+impl InputHandler for StartWaveHandler {
+    fn handle_input(&self, world: &mut World, key: KeyCode) -> bool {
+        if key != START_WAVE_KEY || !is_playing(world) {
+            return false;
+        }
+        let (Some(wave_plan), Some(wave_runtime)) = (world.get_resource::<WavePlan>(), world.get_resource::<WaveRuntime>()) else {
+            return false;
+        };
+        if wave_plan.current_wave == 0 || wave_runtime.wave_complete(wave_plan) {
+            world.send_event(StartWaveEvent);
+        } else {
+            world.send_event(UiFeedbackEvent { cue: UiCue::Error });
+        }
+        true
+    }
+
+    fn get_description(&self) -> &str {
+        "Start the next wave"
+    }
+
+    fn get_priority(&self) -> u8 {
+        20
+    }
+
+    fn get_id(&self) -> &str {
+        START_WAVE_HANDLER_ID
+    }
+
+    fn handles_key(&self, key: KeyCode) -> bool {
+        key == START_WAVE_KEY
+    }
+
+    fn get_handled_keys(&self) -> Vec<KeyCode> {
+        vec![START_WAVE_KEY]
+    }
+}
+
+/// Handler upgrading the selected tower, like the upgrade panel's button
+pub struct UpgradeTowerHandler;
+
+impl InputHandler for UpgradeTowerHandler {
+    fn handle_input(&self, world: &mut World, key: KeyCode) -> bool {
+        if key != UPGRADE_TOWER_KEY || !is_playing(world) {
+            return false;
+        }
+        let Some(tower_entity) = world
+            .get_resource::<TowerSelectionState>()
+            .and_then(|selection| selection.selected_tower_entity)
+        else {
+            return false;
+        };
+        if !world.entity(tower_entity).contains::<TowerStats>() {
+            return false;
+        }
+        let under_construction = world.entity(tower_entity).contains::<Constructing>();
+        let upgraded = world.try_resource_scope(|world, mut economy: Mut<Economy>| {
+            let mut stats = world.get_mut::<TowerStats>(tower_entity)?;
+            try_upgrade_tower(&mut stats, under_construction, &mut economy).ok()?;
+            Some(stats.upgrade_level)
+        }).flatten();
+
+        let cue = match upgraded {
+            Some(level) => {
+                info!("Tower upgraded to level {}", level);
+                UiCue::Confirm
+            }
+            None => UiCue::Error,
+        };
+        world.send_event(UiFeedbackEvent { cue });
+        true
+    }
+
+    fn get_description(&self) -> &str {
+        "Upgrade the selected tower"
+    }
+
+    fn get_priority(&self) -> u8 {
+        20
+    }
+
+    fn get_id(&self) -> &str {
+        UPGRADE_TOWER_HANDLER_ID
+    }
+
+    fn handles_key(&self, key: KeyCode) -> bool {
+        key == UPGRADE_TOWER_KEY
+    }
+
+    fn get_handled_keys(&self) -> Vec<KeyCode> {
+        vec![UPGRADE_TOWER_KEY]
+    }
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// System to press the handler keys mapped to gamepad face buttons. They go
+/// through the registry like keyboard keys, so priorities and rebinding apply.
+pub fn process_gamepad_input(world: &mut World) {
+    if world.get_resource::<KeyCapture>().is_some_and(|capture| capture.is_active()) {
+        return;
+    }
+
+    let mut gamepads = world.query::<&Gamepad>();
+    let pressed: Vec<(&str, KeyCode)> = GAMEPAD_BUTTON_ACTIONS
+        .iter()
+        .filter(|(button, _, _)| gamepads.iter(world).any(|gamepad| gamepad.just_pressed(*button)))
+        .map(|(_, handler_id, action_key)| (*handler_id, *action_key))
+        .collect();
+    if pressed.is_empty() {
+        return;
+    }
+
+    // Temporarily remove the registry to avoid borrowing conflicts
+    let Some(registry) = world.remove_resource::<InputMappingRegistry>() else {
+        return;
+    };
+    for (handler_id, action_key) in pressed {
+        registry.process_input(world, registry.bound_key(handler_id, action_key));
+    }
+    world.insert_resource(registry);
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin for cursor-free play on a gamepad. The sticks, d-pad, triggers,
+/// South and East steer the placement cursor (see `VirtualCursorPlugin`);
+/// North starts the next wave and West upgrades the selected tower.
+pub struct GamepadInputPlugin;
+
+impl Plugin for GamepadInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_input_handler(StartWaveHandler)
+            .register_input_handler(UpgradeTowerHandler)
+            .add_systems(
+                PreUpdate,
+                process_gamepad_input
+                    .after(InputSystem)
+                    .after(process_centralized_input),
+            );
+    }
+}
@@ -95,15 +95,12 @@ impl InputHandler for F2DebugUIHandler {
             return false;
         }
         
-        // Check if debug features are enabled
+        // Check if debug features are enabled; apps built without the debug gate leave them open
         if let Some(debug_toggle) = world.get_resource::<crate::systems::debug_toggle::DebugToggle>() {
             if !debug_toggle.is_enabled() {
                 info!("F2 (Debug UI) blocked - Press ` (backtick) to enable debug features");
                 return true; // Consume the input to prevent fallthrough
             }
-        } else {
-            warn!("F2 handler: DebugToggle resource not found");
-            return false;
         }
         
         // Check if DebugUIState resource exists
@@ -266,15 +263,12 @@ impl InputHandler for F9CheatMenuHandler {
             return false;
         }
         
-        // Check if debug features are enabled
+        // Check if debug features are enabled; apps built without the debug gate leave them open
         if let Some(debug_toggle) = world.get_resource::<crate::systems::debug_toggle::DebugToggle>() {
             if !debug_toggle.is_enabled() {
                 info!("F9 (Cheat Menu) blocked - Press ` (backtick) to enable debug features");
                 return true; // Consume the input to prevent fallthrough
             }
-        } else {
-            warn!("F9 handler: DebugToggle resource not found");
-            return false;
         }
         
         // Check if CheatMenuState resource exists
@@ -13,6 +13,7 @@
 //! * **Plugin Architecture**: Easy integration via Bevy plugin system
 //! * **Runtime Monitoring**: Debug tools for monitoring input handling
 //! * **Extensible Design**: Easy to add new handlers and key combinations
+//! * **Gamepad Support**: Face buttons press handler keys, going through the same priorities
 //! 
 //! ## Quick Start
 //! 
@@ -55,6 +56,13 @@
 //! | F3  | grid_mode | Cycle grid visualization mode (Normal -> Debug -> Placement) | 20 |
 //! | F4  | grid_border | Toggle grid border visibility | 20 |
 //! | F9  | cheat_menu | Toggle cheat menu visibility | 40 |
+//! | H   | help_overlay | Toggle the help overlay (registered by HelpOverlayPlugin) | 50 |
+//! | K   | codex | Open and close the enemy codex (registered by CodexPlugin) | 50 |
+//! | O   | overclock | Overclock the selected tower (registered by OverclockPlugin) | 20 |
+//! | C   | action_camera | Toggle the action cam (registered by ActionCameraPlugin) | 20 |
+//! 
+//! Keys read directly by their own systems (Esc, Shift) are listed with
+//! `register_key_hint` so the help overlay can describe them too.
 //! 
 //! ## Adding Custom Handlers
 //! 
@@ -154,6 +162,8 @@
 pub mod registry;
 pub mod handlers;
 pub mod plugin;
+pub mod rebinding;
+pub mod gamepad;
 
 // Re-export commonly used types and functions
 pub use registry::{
@@ -162,6 +172,10 @@ pub use registry::{
     InputMappingRegistry, 
     InputConflict,
     InputRegistryStats,
+    KeyBinding,
+    KeyHint,
+    is_playing,
+    key_name,
     process_centralized_input,
 };
 
@@ -176,6 +190,18 @@ pub use handlers::{
     create_combined_grid_handler,
 };
 
+pub use rebinding::{KeyCapture, KeyRebind, key_code_id, parse_key_code};
+
+pub use gamepad::{
+    GamepadInputPlugin,
+    StartWaveHandler,
+    StickRepeat,
+    UpgradeTowerHandler,
+    stick_direction,
+    START_WAVE_KEY,
+    UPGRADE_TOWER_KEY,
+};
+
 pub use plugin::{
     InputRegistryPlugin,
     InputRegistryPluginBuilder,
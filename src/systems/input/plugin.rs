@@ -1,7 +1,7 @@
 use bevy::prelude::*;
 use std::sync::Arc;
 
-use crate::systems::input::registry::{InputMappingRegistry, InputHandler, process_centralized_input};
+use crate::systems::input::registry::{InputMappingRegistry, InputHandler, InputContext, process_centralized_input};
 use crate::systems::input::handlers::{create_standard_fkey_handlers, create_combined_grid_handler};
 
 /// Plugin for managing centralized input mapping
@@ -278,6 +278,9 @@ pub trait InputRegistryAppExt {
     
     /// Register multiple input handlers
     fn register_input_handlers(&mut self, handlers: Vec<Arc<dyn InputHandler>>) -> &mut Self;
+
+    /// List a key that a plugin's own system reads, for help screens
+    fn register_key_hint(&mut self, key: KeyCode, description: &'static str, context: InputContext) -> &mut Self;
 }
 
 impl InputRegistryAppExt for App {
@@ -301,4 +304,14 @@ impl InputRegistryAppExt for App {
         });
         self
     }
+
+    fn register_key_hint(&mut self, key: KeyCode, description: &'static str, context: InputContext) -> &mut Self {
+        // Hints are documentation only, so apps without the registry just skip them
+        self.add_systems(Startup, move |registry: Option<ResMut<InputMappingRegistry>>| {
+            if let Some(mut registry) = registry {
+                registry.register_hint(key, description, context);
+            }
+        });
+        self
+    }
 }
\ No newline at end of file
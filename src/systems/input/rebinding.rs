@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+use bevy::reflect::{DynamicEnum, DynamicVariant, FromReflect, Typed, VariantInfo};
+use serde::{Deserialize, Serialize};
+
+/// A rebinding as saved in settings.json, with keys stored by their `KeyCode` name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyRebind {
+    pub handler: String,
+    /// Key the handler was written for, e.g. "F1"
+    pub action: String,
+    /// Key the player moved it to, e.g. "KeyG"
+    pub key: String,
+}
+
+impl KeyRebind {
+    pub fn new(handler: impl Into<String>, action: KeyCode, key: KeyCode) -> Self {
+        Self {
+            handler: handler.into(),
+            action: key_code_id(action),
+            key: key_code_id(key),
+        }
+    }
+
+    /// Handler ID and keys, or `None` if either key name isn't a known key
+    pub fn parse(&self) -> Option<(String, KeyCode, KeyCode)> {
+        Some((self.handler.clone(), parse_key_code(&self.action)?, parse_key_code(&self.key)?))
+    }
+}
+
+/// Stable name of a key for saving, its `KeyCode` variant name
+pub fn key_code_id(key: KeyCode) -> String {
+    format!("{:?}", key)
+}
+
+/// Key saved with `key_code_id`. Only plain named keys can be saved.
+pub fn parse_key_code(name: &str) -> Option<KeyCode> {
+    // `from_reflect` panics on a variant name KeyCode doesn't have
+    let variant = KeyCode::type_info().as_enum().ok()?.variant(name)?;
+    if !matches!(variant, VariantInfo::Unit(_)) {
+        return None;
+    }
+    KeyCode::from_reflect(&DynamicEnum::new(name, DynamicVariant::Unit))
+}
+
+/// Resource for the controls menu waiting for the player to press a new key
+#[derive(Resource, Debug, Default, Clone, PartialEq)]
+pub struct KeyCapture {
+    /// Handler ID and the key it was written for, while waiting for a key
+    pub target: Option<(String, KeyCode)>,
+}
+
+impl KeyCapture {
+    pub fn is_active(&self) -> bool {
+        self.target.is_some()
+    }
+}
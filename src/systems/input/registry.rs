@@ -1,6 +1,8 @@
 use bevy::prelude::*;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use crate::resources::AppState;
+use super::rebinding::KeyCapture;
 
 /// Core trait for all input handlers in the system
 /// 
@@ -71,15 +73,66 @@ pub struct InputConflict {
     pub context2: InputContext,
 }
 
+/// A key read directly by its own system, listed so help screens can describe it
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyHint {
+    pub key: KeyCode,
+    pub description: String,
+    pub context: InputContext,
+}
+
+/// A key a registered handler listens for, and the key the player has it on
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyBinding {
+    pub handler_id: String,
+    pub description: String,
+    pub context: InputContext,
+    /// Key the handler was written for
+    pub action_key: KeyCode,
+    /// Key that triggers it now, after any rebinding
+    pub key: KeyCode,
+}
+
+/// Short display name of a key, e.g. "F1", "Esc" or "O"
+pub fn key_name(key: KeyCode) -> String {
+    match key {
+        KeyCode::Escape => "Esc".to_string(),
+        KeyCode::ShiftLeft | KeyCode::ShiftRight => "Shift".to_string(),
+        KeyCode::ControlLeft | KeyCode::ControlRight => "Ctrl".to_string(),
+        KeyCode::Backquote => "`".to_string(),
+        KeyCode::Space => "Space".to_string(),
+        _ => {
+            let name = format!("{:?}", key);
+            name.strip_prefix("Key")
+                .or_else(|| name.strip_prefix("Digit"))
+                .unwrap_or(&name)
+                .to_string()
+        }
+    }
+}
+
+/// Whether a run is being played, for handlers of gameplay keys
+pub fn is_playing(world: &World) -> bool {
+    world
+        .get_resource::<State<AppState>>()
+        .is_some_and(|state| *state.get() == AppState::Playing)
+}
+
 /// Registry for managing all input mappings and handlers
 #[derive(Resource)]
 pub struct InputMappingRegistry {
     /// Map of key codes to their registered handlers (sorted by priority)
     bindings: HashMap<KeyCode, Vec<Arc<dyn InputHandler>>>,
+    /// Every registered handler, in registration order
+    handlers: Vec<Arc<dyn InputHandler>>,
+    /// Player rebindings: (handler ID, key the handler was written for) to the key that triggers it
+    rebinds: HashMap<(String, KeyCode), KeyCode>,
     /// Set of all registered handler IDs for conflict detection
     registered_handlers: HashSet<String>,
     /// List of detected conflicts
     conflicts: Vec<InputConflict>,
+    /// Keys handled outside the registry, for help screens
+    hints: Vec<KeyHint>,
     /// Whether to log input events for debugging
     debug_logging: bool,
 }
@@ -95,8 +148,11 @@ impl InputMappingRegistry {
     pub fn new() -> Self {
         Self {
             bindings: HashMap::new(),
+            handlers: Vec::new(),
+            rebinds: HashMap::new(),
             registered_handlers: HashSet::new(),
             conflicts: Vec::new(),
+            hints: Vec::new(),
             debug_logging: false,
         }
     }
@@ -125,14 +181,16 @@ impl InputMappingRegistry {
             return Err(format!("Handler with ID '{}' is already registered", handler_id));
         }
         
-        // Register the handler for each of its keys
+        // Register the handler for each of its keys, on the key the player rebound it to if any
         let handled_keys = handler.get_handled_keys();
         for key in handled_keys {
-            self.register_key_handler(key, handler.clone())?;
+            let bound_key = self.bound_key(handler_id, key);
+            self.register_key_handler(bound_key, handler.clone())?;
         }
         
         // Add to registered handlers set
         self.registered_handlers.insert(handler_id.to_string());
+        self.handlers.push(handler.clone());
         
         info!("Registered input handler '{}' for keys: {:?}", 
               handler_id, handler.get_handled_keys());
@@ -140,6 +198,128 @@ impl InputMappingRegistry {
         Ok(())
     }
     
+    /// List a key that its own system reads, so it shows up next to the registered handlers
+    pub fn register_hint(&mut self, key: KeyCode, description: impl Into<String>, context: InputContext) {
+        self.hints.retain(|hint| hint.key != key);
+        self.hints.push(KeyHint {
+            key,
+            description: description.into(),
+            context,
+        });
+    }
+
+    /// What a key does: the description of its primary handler, or of its hint
+    pub fn describe_key(&self, key: KeyCode) -> Option<String> {
+        if let Some(handler) = self.get_primary_handler(key) {
+            return Some(handler.get_description().to_string());
+        }
+        self.hints
+            .iter()
+            .find(|hint| hint.key == key)
+            .map(|hint| hint.description.clone())
+    }
+
+    /// Key that triggers `handler_id`'s `action_key`, after any rebinding
+    pub fn bound_key(&self, handler_id: &str, action_key: KeyCode) -> KeyCode {
+        self.rebinds
+            .get(&(handler_id.to_string(), action_key))
+            .copied()
+            .unwrap_or(action_key)
+    }
+
+    /// Key `handler_id` was written for that `key` triggers
+    fn action_key(&self, handler_id: &str, key: KeyCode) -> KeyCode {
+        self.rebinds
+            .iter()
+            .find(|((id, _), bound)| id == handler_id && **bound == key)
+            .map_or(key, |((_, action_key), _)| *action_key)
+    }
+
+    /// Move one of a handler's keys to `key`. Rebinding to the original key
+    /// removes the rebinding. Clashes are recorded as conflicts like any other.
+    pub fn rebind(&mut self, handler_id: &str, action_key: KeyCode, key: KeyCode) -> Result<(), String> {
+        let Some(handler) = self.handlers.iter().find(|handler| handler.get_id() == handler_id) else {
+            return Err(format!("Handler '{}' is not registered", handler_id));
+        };
+        if !handler.get_handled_keys().contains(&action_key) {
+            return Err(format!("Handler '{}' does not use {:?}", handler_id, action_key));
+        }
+
+        let rebind_id = (handler_id.to_string(), action_key);
+        if key == action_key {
+            self.rebinds.remove(&rebind_id);
+        } else {
+            self.rebinds.insert(rebind_id, key);
+        }
+        self.rebuild_bindings();
+        info!("Rebound '{}' {:?} to {:?}", handler_id, action_key, key);
+        Ok(())
+    }
+
+    /// Rebindings away from the original keys, sorted by handler
+    pub fn get_rebinds(&self) -> Vec<(String, KeyCode, KeyCode)> {
+        let mut rebinds: Vec<(String, KeyCode, KeyCode)> = self
+            .rebinds
+            .iter()
+            .map(|((handler_id, action_key), key)| (handler_id.clone(), *action_key, *key))
+            .collect();
+        rebinds.sort_by(|a, b| (&a.0, format!("{:?}", a.1)).cmp(&(&b.0, format!("{:?}", b.1))));
+        rebinds
+    }
+
+    /// Replace every rebinding at once, e.g. with the ones saved in the settings.
+    /// Handlers registered later pick theirs up on registration.
+    pub fn set_rebinds(&mut self, rebinds: impl IntoIterator<Item = (String, KeyCode, KeyCode)>) {
+        self.rebinds = rebinds
+            .into_iter()
+            .filter(|(_, action_key, key)| action_key != key)
+            .map(|(handler_id, action_key, key)| ((handler_id, action_key), key))
+            .collect();
+        self.rebuild_bindings();
+    }
+
+    /// Every key of every registered handler with where it is bound, in registration order
+    pub fn get_key_bindings(&self) -> Vec<KeyBinding> {
+        self.handlers
+            .iter()
+            .flat_map(|handler| {
+                handler.get_handled_keys().into_iter().map(move |action_key| KeyBinding {
+                    handler_id: handler.get_id().to_string(),
+                    description: handler.get_description().to_string(),
+                    context: handler.get_context(),
+                    action_key,
+                    key: self.bound_key(handler.get_id(), action_key),
+                })
+            })
+            .collect()
+    }
+
+    /// Conflicts on `key` that involve `handler_id`
+    pub fn get_conflicts_for(&self, handler_id: &str, key: KeyCode) -> Vec<&InputConflict> {
+        self.conflicts
+            .iter()
+            .filter(|conflict| conflict.key == key && (conflict.handler1 == handler_id || conflict.handler2 == handler_id))
+            .collect()
+    }
+
+    /// Re-register every handler on its current keys, recomputing conflicts
+    fn rebuild_bindings(&mut self) {
+        self.bindings.clear();
+        self.conflicts.clear();
+        for handler in self.handlers.clone() {
+            for action_key in handler.get_handled_keys() {
+                let key = self.bound_key(handler.get_id(), action_key);
+                // Registering a key only fails on a broken handler, which registered fine before
+                let _ = self.register_key_handler(key, handler.clone());
+            }
+        }
+    }
+
+    /// Get all keys listed as hints
+    pub fn get_hints(&self) -> &[KeyHint] {
+        &self.hints
+    }
+
     /// Register a handler for a specific key (internal method)
     fn register_key_handler(&mut self, key: KeyCode, handler: Arc<dyn InputHandler>) -> Result<(), String> {
         // Check for conflicts with existing handlers
@@ -186,12 +366,14 @@ impl InputMappingRegistry {
         
         if let Some(handlers) = self.bindings.get(&key) {
             for handler in handlers {
-                if handler.handles_key(key) {
+                // Rebound handlers are handed the key they were written for
+                let action_key = self.action_key(handler.get_id(), key);
+                if handler.handles_key(action_key) {
                     if self.debug_logging {
                         debug!("Attempting to handle key {:?} with handler '{}'", key, handler.get_id());
                     }
                     
-                    if handler.handle_input(world, key) {
+                    if handler.handle_input(world, action_key) {
                         if self.debug_logging {
                             debug!("Key {:?} consumed by handler '{}'", key, handler.get_id());
                         }
@@ -253,6 +435,7 @@ impl InputMappingRegistry {
         
         // Remove from registered set
         self.registered_handlers.remove(handler_id);
+        self.handlers.retain(|h| h.get_id() != handler_id);
         
         // Remove related conflicts
         self.conflicts.retain(|c| c.handler1 != handler_id && c.handler2 != handler_id);
@@ -264,8 +447,11 @@ impl InputMappingRegistry {
     /// Clear all handlers and conflicts
     pub fn clear(&mut self) {
         self.bindings.clear();
+        self.handlers.clear();
+        self.rebinds.clear();
         self.registered_handlers.clear();
         self.conflicts.clear();
+        self.hints.clear();
         info!("Cleared all input handlers");
     }
     
@@ -320,6 +506,11 @@ pub fn process_centralized_input(
         .cloned()
         .collect();
     
+    // Keys pressed while the controls menu is waiting for a new binding are not actions
+    if world.get_resource::<KeyCapture>().is_some_and(|capture| capture.is_active()) {
+        return;
+    }
+    
     // Process each pressed key through the registry
     for key in pressed_keys {
         // Temporarily remove the registry to avoid borrowing conflicts
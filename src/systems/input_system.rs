@@ -5,9 +5,11 @@ use crate::resources::*;
 use crate::components::*;
 use crate::systems::combat_system::Target;
 use crate::systems::tower_ui::TowerSelectionState;
-use crate::systems::tower_rendering::spawn_tower_with_pattern;
+use crate::systems::tower_rendering::{range_ring, spawn_tower_with_pattern, tower_pattern_color, RangeRingAssets};
+use crate::systems::construction_system::begin_tower_construction;
 use crate::systems::unified_grid::{UnifiedGridSystem, GridVisualizationMode, snap_to_grid, world_to_grid};
-use crate::systems::obstacle_rendering::ObstacleGrid;
+use crate::systems::ui_feedback::UiFeedback;
+use crate::systems::placement_validator::PlacementContext;
 
 #[derive(Resource, Debug)]
 pub struct MouseInputState {
@@ -15,6 +17,12 @@ pub struct MouseInputState {
     pub world_position: Vec2,
     pub left_clicked: bool,
     pub right_clicked: bool,
+    /// Left button is currently held down
+    pub left_held: bool,
+    /// Left button was released this frame
+    pub left_released: bool,
+    /// World position where the current left-button drag started
+    pub drag_start: Option<Vec2>,
     pub placement_mode: PlacementMode,
     pub preview_position: Option<Vec2>,
 }
@@ -26,6 +34,9 @@ impl Default for MouseInputState {
             world_position: Vec2::ZERO,
             left_clicked: false,
             right_clicked: false,
+            left_held: false,
+            left_released: false,
+            drag_start: None,
             placement_mode: PlacementMode::Hybrid,
             preview_position: None,
         }
@@ -44,6 +55,13 @@ pub enum PlacementMode {
 #[derive(Component)]
 pub struct PlacementPreview;
 
+/// Marker for the translucent tower drawn where the selected type would be placed
+#[derive(Component)]
+pub struct PlacementGhost;
+
+/// Opacity of the placement ghost
+const GHOST_ALPHA: f32 = 0.5;
+
 #[derive(Component)]
 pub struct PlacementZoneMarker {
     pub zone_type: PlacementZoneType,
@@ -83,29 +101,50 @@ pub fn mouse_input_system(
     // Handle mouse clicks
     mouse_state.left_clicked = false;
     mouse_state.right_clicked = false;
+    mouse_state.left_released = false;
+    // A drag is kept for one frame after release so systems can read its final rectangle
+    if !mouse_state.left_held {
+        mouse_state.drag_start = None;
+    }
 
     for event in mouse_button_events.read() {
         if event.state.is_pressed() {
             match event.button {
-                MouseButton::Left => mouse_state.left_clicked = true,
+                MouseButton::Left => {
+                    mouse_state.left_clicked = true;
+                    mouse_state.left_held = true;
+                    mouse_state.drag_start = Some(mouse_state.world_position);
+                }
                 MouseButton::Right => mouse_state.right_clicked = true,
                 _ => {}
             }
+        } else if event.button == MouseButton::Left {
+            mouse_state.left_held = false;
+            mouse_state.left_released = true;
         }
     }
 }
 
+impl MouseInputState {
+    /// World-space rectangle spanned by the current (or just released) left-button drag
+    pub fn drag_rect(&self) -> Option<Rect> {
+        self.drag_start
+            .map(|start| Rect::from_corners(start, self.world_position))
+    }
+}
+
 /// Tower placement system - Enhanced with obstacle collision detection
 pub fn tower_placement_system(
     mut commands: Commands,
     mouse_state: Res<MouseInputState>,
     tower_selection_state: Res<TowerSelectionState>,
     mut economy: ResMut<Economy>,
-    existing_towers: Query<&Transform, With<TowerStats>>,
-    enemy_path: Res<EnemyPath>,
     ui_interaction_query: Query<&Interaction, With<Button>>,
-    unified_grid: Res<UnifiedGridSystem>,
-    obstacle_grid: Res<ObstacleGrid>,
+    placement: PlacementContext,
+    mut perks: Option<ResMut<RunPerks>>,
+    market: Option<Res<MarketState>>,
+    mut integrity: Option<ResMut<RunIntegrity>>,
+    mut feedback: UiFeedback,
 ) {
     // CRITICAL SAFETY CHECK: Don't place towers if any UI button is being interacted with
     let ui_is_active = ui_interaction_query.iter().any(|interaction| {
@@ -121,29 +160,44 @@ pub fn tower_placement_system(
                 let placement_pos = get_placement_position(
                     mouse_state.world_position,
                     mouse_state.placement_mode,
-                    &unified_grid,
+                    placement.unified_grid(),
                 );
 
-                // Validate placement using unified system (ensures consistency with red areas)
-                if is_valid_tower_placement_unified(
-                    placement_pos,
-                    &existing_towers,
-                    &enemy_path.waypoints,
-                    &unified_grid,
-                    Some(&obstacle_grid.grid),
-                    40.0, // Tower size - exactly one grid cell
-                ) {
-                    let cost = tower_type.get_cost();
-                    if economy.can_afford(&cost) {
-                        // Place the tower
-                        spawn_tower(&mut commands, placement_pos, tower_type);
+                // Validate the site; funds are checked below since a free tower perk may cover them
+                let verdict = placement.validator().check_position(placement_pos);
+                if verdict.is_buildable() {
+                    let cost = resolve_tower_cost(market.as_deref(), tower_type);
+                    let placed = if integrity.as_mut().is_some_and(|integrity| !integrity.allows_placement()) {
+                        println!("Placement refused: too many towers placed too quickly");
+                        false
+                    } else if perks.as_mut().is_some_and(|perks| perks.take_free_tower()) {
+                        // A Free Tower perk covers the whole cost, so cancelling refunds nothing
+                        let tower_entity = spawn_tower_with_pattern(&mut commands, placement_pos, tower_type);
+                        begin_tower_construction(&mut commands, tower_entity, placement_pos, tower_type, ResourceCost::zero());
+                        println!("Placed free {:?} tower at {:?}", tower_type, placement_pos);
+                        true
+                    } else if economy.can_afford(&cost) {
+                        // Pay, then place the tower, which keeps the cost for a cancel refund
                         economy.spend(&cost);
+                        spawn_tower(&mut commands, placement_pos, tower_type, cost);
                         println!("Placed {:?} tower at {:?}", tower_type, placement_pos);
+                        true
                     } else {
                         println!("Cannot afford {:?} tower", tower_type);
+                        false
+                    };
+                    // Only towers actually placed count against the rate limit
+                    if placed {
+                        if let Some(integrity) = integrity.as_mut() {
+                            integrity.record_placement();
+                        }
+                        feedback.confirm();
+                    } else {
+                        feedback.error();
                     }
                 } else {
-                    println!("Invalid tower placement position");
+                    println!("Invalid tower placement position: {}", verdict.get_reason());
+                    feedback.error();
                 }
             }
         }
@@ -157,10 +211,10 @@ pub fn tower_placement_preview_system(
     tower_selection_state: Res<TowerSelectionState>,
     existing_previews: Query<Entity, With<PlacementPreview>>,
     economy: Res<Economy>,
-    existing_towers: Query<&Transform, With<TowerStats>>,
-    enemy_path: Res<EnemyPath>,
-    unified_grid: Res<UnifiedGridSystem>,
-    obstacle_grid: Res<ObstacleGrid>,
+    perks: Option<Res<RunPerks>>,
+    market: Option<Res<MarketState>>,
+    placement: PlacementContext,
+    ring_assets: Option<Res<RangeRingAssets>>,
 ) {
     // Clear existing previews
     for entity in existing_previews.iter() {
@@ -173,39 +227,49 @@ pub fn tower_placement_preview_system(
             let placement_pos = get_placement_position(
                 mouse_state.world_position,
                 mouse_state.placement_mode,
-                &unified_grid,
+                placement.unified_grid(),
             );
 
-            let is_valid = is_valid_tower_placement_unified(
-                placement_pos,
-                &existing_towers,
-                &enemy_path.waypoints,
-                &unified_grid,
-                Some(&obstacle_grid.grid),
-                40.0, // Tower size - exactly one grid cell
-            );
-
-            let cost = tower_type.get_cost();
-            let can_afford = economy.can_afford(&cost);
-            let color = if is_valid && can_afford {
-                Color::srgba(0.0, 1.0, 0.0, 0.5) // Green
+            // A free tower perk covers the cost, so only the site matters
+            let free_tower = perks.is_some_and(|perks| perks.free_towers > 0);
+            let cost = if free_tower { ResourceCost::zero() } else { resolve_tower_cost(market.as_deref(), tower_type) };
+            let verdict = placement
+                .validator()
+                .with_funds(&economy, &cost)
+                .check_position(placement_pos);
+            let color = if verdict.is_buildable() {
+                Color::srgba(0.0, 1.0, 0.0, 0.3) // Green
             } else {
-                Color::srgba(1.0, 0.0, 0.0, 0.5) // Red
+                Color::srgba(1.0, 0.0, 0.0, 0.3) // Red
             };
 
-            // Spawn preview sprite
+            // Spawn preview sprite tinting the snapped cell
             commands.spawn((
                 Sprite {
                     color,
-                    custom_size: Some(Vec2::new(40.0, 40.0)), // Exactly one grid cell
+                    custom_size: Some(Vec2::splat(placement.tower_footprint())),
                     ..default()
                 },
                 Transform::from_translation(placement_pos.extend(1.0)),
                 PlacementPreview,
             ));
 
+            // Translucent tower on top of it
+            commands.spawn((
+                Sprite {
+                    color: tower_pattern_color(tower_type).with_alpha(GHOST_ALPHA),
+                    custom_size: Some(Vec2::splat(placement.tower_footprint() * 0.8)),
+                    ..default()
+                },
+                Transform::from_translation(placement_pos.extend(1.1)),
+                PlacementPreview,
+                PlacementGhost,
+            ));
+
             // Show range indicator
-            spawn_range_preview(&mut commands, placement_pos, tower_type);
+            if let Some(ring_assets) = ring_assets.as_deref() {
+                spawn_range_preview(&mut commands, placement_pos, tower_type, ring_assets, verdict.is_buildable());
+            }
         }
     }
 }
@@ -214,9 +278,14 @@ pub fn tower_placement_preview_system(
 pub fn screen_to_world_position(
     screen_pos: Vec2,
     camera_transform: &GlobalTransform,
-    _camera: &Camera,
+    camera: &Camera,
     window: &Window,
 ) -> Vec2 {
+    // Use the camera projection when it's available so zoomed cameras map correctly
+    if let Ok(world_pos) = camera.viewport_to_world_2d(camera_transform, screen_pos) {
+        return world_pos;
+    }
+
     let window_size = Vec2::new(window.width(), window.height());
     
     // Convert screen coordinates to normalized device coordinates (NDC)
@@ -291,124 +360,6 @@ pub fn is_valid_placement_position(position: Vec2, path_points: &[Vec2], min_dis
     true
 }
 
-pub fn is_valid_tower_placement(
-    position: Vec2,
-    existing_towers: &Query<&Transform, With<TowerStats>>,
-    path_points: &[Vec2],
-    tower_size: f32,
-) -> bool {
-    // Check path collision
-    if !is_valid_placement_position(position, path_points, tower_size) {
-        return false;
-    }
-
-    // Check overlap with existing towers - use full tower size for collision
-    for transform in existing_towers.iter() {
-        let distance = position.distance(transform.translation.truncate());
-        if distance < tower_size {
-            return false;
-        }
-    }
-
-    true
-}
-
-/// Enhanced tower placement validation that includes obstacle collision
-pub fn is_valid_tower_placement_with_obstacles(
-    position: Vec2,
-    existing_towers: &Query<&Transform, With<TowerStats>>,
-    path_points: &[Vec2],
-    obstacle_grid: &crate::systems::path_generation::PathGrid,
-    tower_size: f32,
-) -> bool {
-    // Check basic placement validity (path and existing towers)
-    if !is_valid_tower_placement(position, existing_towers, path_points, tower_size) {
-        return false;
-    }
-
-    // Check obstacle collision
-    if let Some(grid_pos) = obstacle_grid.world_to_grid(position) {
-        // Check if the tower position overlaps with any obstacle
-        if !obstacle_grid.is_traversable(grid_pos) {
-            return false;
-        }
-        
-        // Check surrounding cells for obstacle collision (tower occupies full cell)
-        let neighbors = grid_pos.neighbors(obstacle_grid.width, obstacle_grid.height);
-        for neighbor in neighbors {
-            let neighbor_world = obstacle_grid.grid_to_world(neighbor);
-            let distance = position.distance(neighbor_world);
-            
-            // If neighbor obstacle is too close to tower center
-            if distance < tower_size * 0.7 && !obstacle_grid.is_traversable(neighbor) {
-                return false;
-            }
-        }
-    }
-
-    true
-}
-
-/// Unified tower placement validation that uses the same logic as grid visualization
-/// This ensures consistency between red areas and actual placement blocking
-pub fn is_valid_tower_placement_unified(
-    position: Vec2,
-    existing_towers: &Query<&Transform, With<TowerStats>>,
-    path_points: &[Vec2],
-    unified_grid: &UnifiedGridSystem,
-    obstacle_grid: Option<&crate::systems::path_generation::PathGrid>,
-    tower_size: f32,
-) -> bool {
-    // First check if this is within unified grid bounds
-    if let Some(grid_pos) = crate::systems::unified_grid::world_to_grid(position, unified_grid) {
-        // Use the PathGrid system for placement validation if available
-        if let Some(path_grid) = obstacle_grid {
-            match path_grid.get_cell(grid_pos) {
-                Some(crate::systems::path_generation::grid::CellType::Empty) => {
-                    // Allow placement on empty cells
-                },
-                Some(crate::systems::path_generation::grid::CellType::TowerZone) => {
-                    // Allow placement on designated tower zones
-                },
-                Some(crate::systems::path_generation::grid::CellType::Path) => {
-                    // Prevent placement on path cells
-                    return false;
-                },
-                Some(crate::systems::path_generation::grid::CellType::Blocked) => {
-                    // Prevent placement on blocked cells (obstacles)
-                    return false;
-                },
-                None => {
-                    // Allow placement outside PathGrid bounds (fallback behavior)
-                }
-            }
-        }
-    } else {
-        // Outside unified grid bounds
-        return false;
-    }
-    
-    // Check existing tower overlaps - use full tower size for collision
-    for transform in existing_towers.iter() {
-        let distance = position.distance(transform.translation.truncate());
-        if distance < tower_size {
-            return false;
-        }
-    }
-    
-    // Check distance to path segments (additional safety check)
-    for i in 0..path_points.len().saturating_sub(1) {
-        let start = path_points[i];
-        let end = path_points[i + 1];
-        let distance = distance_to_line_segment(position, start, end);
-        if distance < tower_size / 2.0 {
-            return false;
-        }
-    }
-
-    true
-}
-
 pub fn distance_to_line_segment(point: Vec2, line_start: Vec2, line_end: Vec2) -> f32 {
     let line_vec = line_end - line_start;
     let point_vec = point - line_start;
@@ -422,22 +373,23 @@ pub fn distance_to_line_segment(point: Vec2, line_start: Vec2, line_end: Vec2) -
     point.distance(projection)
 }
 
-pub fn spawn_tower(commands: &mut Commands, position: Vec2, tower_type: TowerType) {
+/// Spawn a tower bought for `paid_cost`, which is refunded if construction is cancelled
+pub fn spawn_tower(commands: &mut Commands, position: Vec2, tower_type: TowerType, paid_cost: ResourceCost) {
     // Use the new pattern-based tower spawning system
-    spawn_tower_with_pattern(commands, position, tower_type);
+    let tower_entity = spawn_tower_with_pattern(commands, position, tower_type);
+
+    // New towers start as construction sites and activate once built
+    begin_tower_construction(commands, tower_entity, position, tower_type, paid_cost);
 }
 
-pub fn spawn_range_preview(commands: &mut Commands, position: Vec2, tower_type: TowerType) {
+/// Spawn the range ring of a `tower_type` tower about to go at `position`, green
+/// if it can be built there and red if not
+pub fn spawn_range_preview(commands: &mut Commands, position: Vec2, tower_type: TowerType, ring_assets: &RangeRingAssets, valid: bool) {
     let tower_stats = TowerStats::new(tower_type);
-    let range = tower_stats.range;
+    let material = if valid { &ring_assets.valid } else { &ring_assets.invalid };
     
     commands.spawn((
-        Sprite {
-            color: Color::srgba(1.0, 1.0, 1.0, 0.1),
-            custom_size: Some(Vec2::new(range * 2.0, range * 2.0)),
-            ..default()
-        },
-        Transform::from_translation(position.extend(0.5)),
+        range_ring(ring_assets, material, position, tower_stats.range, 0.5),
         PlacementPreview,
     ));
 }
@@ -0,0 +1,179 @@
+use bevy::prelude::*;
+use crate::resources::{leaderboard_row, AppState, GameSystemSet, LeaderboardMode, NumberFormatter, RunLeaderboard};
+
+const OVERLAY_BG: Color = Color::srgba(0.0, 0.0, 0.0, 0.7);
+const PANEL_BG: Color = Color::srgb(0.08, 0.12, 0.18);
+const PANEL_BORDER: Color = Color::srgb(0.22, 0.28, 0.38);
+const BUTTON_BG: Color = Color::srgb(0.15, 0.20, 0.28);
+const BUTTON_HOVER: Color = Color::srgb(0.20, 0.28, 0.38);
+const TEXT_PRIMARY: Color = Color::srgb(0.96, 0.96, 0.98);
+const TEXT_MUTED: Color = Color::srgb(0.58, 0.62, 0.68);
+const TEXT_GOLD: Color = Color::srgb(1.0, 0.85, 0.3);
+
+/// Request from the main menu to open the leaderboard page
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShowLeaderboardEvent;
+
+/// Component marker for the leaderboard page
+#[derive(Component)]
+pub struct LeaderboardPage;
+
+/// Component for the button closing the leaderboard page
+#[derive(Component)]
+pub struct LeaderboardCloseButton;
+
+/// Lines of the leaderboard page, one ranking per mode with the best run first
+pub fn leaderboard_lines(leaderboard: &RunLeaderboard, formatter: &NumberFormatter) -> Vec<String> {
+    if leaderboard.entries.is_empty() {
+        return vec!["No runs recorded yet".to_string()];
+    }
+    LeaderboardMode::ALL
+        .into_iter()
+        .flat_map(|mode| {
+            leaderboard
+                .entries_for(mode)
+                .enumerate()
+                .map(|(index, entry)| leaderboard_row(index + 1, entry, &formatter.count(entry.score)))
+        })
+        .collect()
+}
+
+/// System to open the leaderboard page when asked for from the main menu
+pub fn show_leaderboard_system(
+    mut commands: Commands,
+    mut events: EventReader<ShowLeaderboardEvent>,
+    leaderboard: Option<Res<RunLeaderboard>>,
+    formatter: Option<Res<NumberFormatter>>,
+    pages: Query<(), With<LeaderboardPage>>,
+) {
+    if events.read().count() == 0 || !pages.is_empty() {
+        return;
+    }
+    let formatter = formatter.as_deref().copied().unwrap_or_default();
+    let lines = match leaderboard.as_deref() {
+        Some(leaderboard) => leaderboard_lines(leaderboard, &formatter),
+        None => leaderboard_lines(&RunLeaderboard::default(), &formatter),
+    };
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Vw(100.0),
+            height: Val::Vh(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(OVERLAY_BG),
+        ZIndex(1150), // Above the main menu
+        LeaderboardPage,
+    )).with_children(|parent| {
+        parent.spawn((
+            Node {
+                width: Val::Px(560.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(24.0)),
+                row_gap: Val::Px(8.0),
+                border: UiRect::all(Val::Px(3.0)),
+                ..default()
+            },
+            BackgroundColor(PANEL_BG),
+            BorderColor(PANEL_BORDER),
+            BorderRadius::all(Val::Px(15.0)),
+        )).with_children(|panel| {
+            panel.spawn((
+                Text::new("LEADERBOARD"),
+                TextFont {
+                    font_size: 32.0,
+                    ..default()
+                },
+                TextColor(TEXT_PRIMARY),
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+
+            for (index, line) in lines.into_iter().enumerate() {
+                panel.spawn((
+                    Text::new(line),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(if index == 0 { TEXT_GOLD } else { TEXT_MUTED }),
+                ));
+            }
+
+            panel.spawn((
+                Button,
+                Node {
+                    width: Val::Px(200.0),
+                    height: Val::Px(44.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(2.0)),
+                    margin: UiRect::top(Val::Px(12.0)),
+                    ..default()
+                },
+                BackgroundColor(BUTTON_BG),
+                BorderColor(PANEL_BORDER),
+                BorderRadius::all(Val::Px(8.0)),
+                LeaderboardCloseButton,
+            )).with_children(|button| {
+                button.spawn((
+                    Text::new("BACK"),
+                    TextFont {
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(TEXT_PRIMARY),
+                ));
+            });
+        });
+    });
+}
+
+/// System to close the leaderboard page with its back button
+pub fn leaderboard_close_button_system(
+    mut commands: Commands,
+    mut interaction_query: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<LeaderboardCloseButton>)>,
+    pages: Query<Entity, With<LeaderboardPage>>,
+) {
+    for (interaction, mut background) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                for page in pages.iter() {
+                    commands.entity(page).despawn();
+                }
+            }
+            Interaction::Hovered => *background = BackgroundColor(BUTTON_HOVER),
+            Interaction::None => *background = BackgroundColor(BUTTON_BG),
+        }
+    }
+}
+
+/// System to remove the leaderboard page on leaving the main menu
+pub fn despawn_leaderboard_page_system(mut commands: Commands, pages: Query<Entity, With<LeaderboardPage>>) {
+    for page in pages.iter() {
+        commands.entity(page).despawn();
+    }
+}
+
+/// Plugin for the main menu's leaderboard page. The leaderboard itself is
+/// loaded and recorded to by the results screen.
+pub struct LeaderboardPagePlugin;
+
+impl Plugin for LeaderboardPagePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ShowLeaderboardEvent>()
+            .add_systems(OnExit(AppState::MainMenu), despawn_leaderboard_page_system)
+            .add_systems(
+                Update,
+                (show_leaderboard_system, leaderboard_close_button_system)
+                    .in_set(GameSystemSet::UI)
+                    .run_if(in_state(AppState::MainMenu)),
+            );
+    }
+}
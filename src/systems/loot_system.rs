@@ -0,0 +1,201 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::resources::*;
+use crate::systems::combat_system::Target;
+use crate::systems::input_system::{tower_placement_system, MouseInputState};
+
+/// Seconds a pickup stays on the battlefield before it expires
+const PICKUP_LIFETIME: f32 = 6.0;
+/// Seconds before expiry at which a pickup starts blinking
+const PICKUP_BLINK_TIME: f32 = 2.0;
+/// Size of a pickup sprite
+const PICKUP_SIZE: f32 = 14.0;
+/// Click radius for collecting a pickup
+const PICKUP_CLICK_RADIUS: f32 = 18.0;
+
+/// Marker for the HUD text listing active buffs
+#[derive(Component)]
+pub struct BuffIndicatorText;
+
+/// Roll an enemy's loot table and spawn the pickup it drops, if any.
+/// `drop_roll` and `pick_roll` are uniform random numbers in 0.0..1.0.
+pub fn spawn_loot_drop(
+    commands: &mut Commands,
+    loot_table: &LootTable,
+    position: Vec2,
+    drop_roll: f32,
+    pick_roll: f32,
+) -> Option<Entity> {
+    let kind = loot_table.roll(drop_roll, pick_roll)?;
+
+    let pickup = commands.spawn((
+        Sprite {
+            color: kind.get_color(),
+            custom_size: Some(Vec2::splat(PICKUP_SIZE)),
+            ..default()
+        },
+        // Diamond shape so pickups stand out from square enemies
+        Transform::from_translation(position.extend(2.0))
+            .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+        LootPickup::new(kind, loot_table.cash_amount, PICKUP_LIFETIME),
+    )).id();
+
+    println!("Enemy dropped {} at {:?}", kind.get_name(), position);
+    Some(pickup)
+}
+
+/// Apply a collected pickup's effect
+pub fn apply_pickup(
+    pickup: &LootPickup,
+    economy: &mut Economy,
+    buffs: &mut ActiveBuffs,
+    towers: &mut Query<(&mut Target, Option<&mut Heat>), With<TowerStats>>,
+) {
+    match pickup.kind {
+        PickupKind::CashBundle => {
+            economy.money += pickup.cash_amount;
+        }
+        PickupKind::FireRateBoost => {
+            buffs.activate_fire_rate_boost();
+        }
+        PickupKind::CooldownReset => {
+            for (mut target, heat) in towers.iter_mut() {
+                target.last_shot_time = f32::NEG_INFINITY;
+                // Also vents overclock heat, bringing overheated towers back online
+                if let Some(mut heat) = heat {
+                    heat.vent();
+                }
+            }
+        }
+    }
+}
+
+/// System to age pickups, blink them near expiry and remove expired ones
+pub fn loot_expiry_system(
+    mut commands: Commands,
+    clock: Res<SimulationClock>,
+    mut pickups: Query<(Entity, &mut LootPickup, &mut Visibility)>,
+) {
+    for (entity, mut pickup, mut visibility) in pickups.iter_mut() {
+        pickup.lifetime.tick(clock.delta());
+
+        if pickup.is_expired() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        // Blink faster the closer the pickup is to expiring
+        let remaining = pickup.remaining_secs();
+        let target = if remaining < PICKUP_BLINK_TIME && ((remaining * 8.0) as u32).is_multiple_of(2) {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+        visibility.set_if_neq(target);
+    }
+}
+
+/// System to collect pickups with a left click. Runs before tower placement and
+/// consumes the click, and ignores clicks already captured by UI.
+pub fn loot_collection_system(
+    mut commands: Commands,
+    mut mouse_state: ResMut<MouseInputState>,
+    mut economy: ResMut<Economy>,
+    mut buffs: ResMut<ActiveBuffs>,
+    pickups: Query<(Entity, &LootPickup, &Transform)>,
+    mut towers: Query<(&mut Target, Option<&mut Heat>), With<TowerStats>>,
+    ui_interaction_query: Query<&Interaction, With<Button>>,
+) {
+    if !mouse_state.left_clicked {
+        return;
+    }
+
+    let ui_is_active = ui_interaction_query.iter().any(|interaction| {
+        matches!(*interaction, Interaction::Pressed | Interaction::Hovered)
+    });
+    if ui_is_active {
+        return;
+    }
+
+    let click_pos = mouse_state.world_position;
+    let clicked_pickup = pickups.iter().find(|(_, _, transform)| {
+        transform.translation.truncate().distance(click_pos) < PICKUP_CLICK_RADIUS
+    });
+
+    if let Some((entity, pickup, _)) = clicked_pickup {
+        apply_pickup(pickup, &mut economy, &mut buffs, &mut towers);
+        commands.entity(entity).despawn();
+        mouse_state.left_clicked = false;
+
+        println!("Collected {}", pickup.kind.get_name());
+    }
+}
+
+/// System to count buff durations down
+pub fn buff_timer_system(clock: Res<SimulationClock>, mut buffs: ResMut<ActiveBuffs>) {
+    buffs.tick(clock.delta_secs());
+}
+
+/// System to spawn the HUD line showing active buffs
+pub fn setup_buff_hud(mut commands: Commands) {
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(PickupKind::FireRateBoost.get_color()),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(70.0),
+            left: Val::Percent(50.0),
+            margin: UiRect::left(Val::Px(-90.0)),
+            ..default()
+        },
+        Visibility::Hidden,
+        BuffIndicatorText,
+    ));
+}
+
+/// System to update the HUD buff indicators
+pub fn buff_hud_system(
+    buffs: Res<ActiveBuffs>,
+    mut indicator_query: Query<(&mut Text, &mut Visibility), With<BuffIndicatorText>>,
+) {
+    let Ok((mut text, mut visibility)) = indicator_query.single_mut() else {
+        return;
+    };
+
+    if buffs.is_fire_rate_boosted() {
+        **text = format!(
+            "FIRE RATE x{:.1}  {:.1}s",
+            buffs.fire_rate_multiplier(),
+            buffs.fire_rate_boost_remaining
+        );
+        visibility.set_if_neq(Visibility::Inherited);
+    } else {
+        visibility.set_if_neq(Visibility::Hidden);
+    }
+}
+
+/// Plugin to add enemy loot drops, pickup collection and temporary buffs
+pub struct LootPlugin;
+
+impl Plugin for LootPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<ActiveBuffs>()
+            .add_systems(Startup, setup_buff_hud)
+            .add_systems(
+                Update,
+                (
+                    loot_collection_system.before(tower_placement_system),
+                    loot_expiry_system,
+                    buff_timer_system,
+                )
+                    .in_set(GameSystemSet::Gameplay)
+                    .run_if(in_state(AppState::Playing)),
+            )
+            .add_systems(Update, buff_hud_system.in_set(GameSystemSet::UI));
+    }
+}
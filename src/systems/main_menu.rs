@@ -0,0 +1,364 @@
+use std::time::SystemTime;
+use bevy::prelude::*;
+use crate::resources::{existing_save_file, AppState, EnemySet, GameSystemSet, RunMode, SettingsReturnState};
+use crate::systems::leaderboard_page::ShowLeaderboardEvent;
+use crate::systems::map_select::ShowMapSelectEvent;
+use crate::systems::save_load::{SaveLoadRequest, SAVE_STATE_SAVE};
+use crate::systems::settings_menu::GameSettings;
+use crate::systems::suspend_system::{PendingSuspendedRun, SuspendedRunChoice, SUSPEND_SAVE};
+use crate::systems::whats_new::ShowChangelogEvent;
+
+// ============================================================================
+// MAIN MENU COMPONENTS & RESOURCES
+// ============================================================================
+
+#[derive(Component)]
+pub struct MainMenuOverlay;
+
+#[derive(Component)]
+pub struct MainMenuButton {
+    pub action: MainMenuAction,
+    pub enabled: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MainMenuAction {
+    /// Open the map select screen, which starts the game
+    NewGame,
+    /// Switch between a campaign and an endless run
+    ToggleRunMode,
+    Continue,
+    Settings,
+    /// Open the leaderboard page
+    Leaderboard,
+    /// Open the changelog
+    WhatsNew,
+    Quit,
+}
+
+/// Label for the run mode button, e.g. "MODE: CAMPAIGN (10 WAVES)"
+pub fn run_mode_label(settings: &GameSettings) -> String {
+    match settings.run_mode {
+        RunMode::Campaign => format!("MODE: CAMPAIGN ({} WAVES)", settings.victory_waves),
+        RunMode::Endless => "MODE: ENDLESS".to_string(),
+    }
+}
+
+/// Saved run Continue picks up
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SaveSlot {
+    /// Run suspended mid-wave when the game was last closed
+    Suspended,
+    /// Debug save state written with F5
+    SaveState,
+}
+
+/// Resource holding the save Continue chose until play has started
+#[derive(Resource, Debug, Default)]
+pub struct PendingContinue(pub Option<SaveSlot>);
+
+/// Pick the most recently written of the saves that exist
+pub fn newest_save(suspended: Option<SystemTime>, save_state: Option<SystemTime>) -> Option<SaveSlot> {
+    match (suspended, save_state) {
+        (Some(suspended), Some(save_state)) if save_state > suspended => Some(SaveSlot::SaveState),
+        (Some(_), _) => Some(SaveSlot::Suspended),
+        (None, Some(_)) => Some(SaveSlot::SaveState),
+        (None, None) => None,
+    }
+}
+
+/// When the save named `stem` was last written, in whichever format
+fn modified_time(stem: &str) -> Option<SystemTime> {
+    let file = existing_save_file(stem)?;
+    std::fs::metadata(file).and_then(|metadata| metadata.modified()).ok()
+}
+
+/// Save Continue would resume. The suspended run only counts if it could be read on launch.
+pub fn available_save(pending: &PendingSuspendedRun) -> Option<SaveSlot> {
+    let suspended = pending.0.as_ref().and_then(|_| modified_time(SUSPEND_SAVE));
+    newest_save(suspended, modified_time(SAVE_STATE_SAVE))
+}
+
+// ============================================================================
+// UI COLOR CONSTANTS (matching the pause menu)
+// ============================================================================
+
+struct UIColors;
+
+impl UIColors {
+    const PANEL_BG: Color = Color::srgb(0.08, 0.12, 0.18);
+    const PANEL_BORDER: Color = Color::srgb(0.22, 0.28, 0.38);
+    const BUTTON_DEFAULT: Color = Color::srgb(0.15, 0.20, 0.28);
+    const BUTTON_HOVER: Color = Color::srgb(0.20, 0.28, 0.38);
+    const BUTTON_DISABLED: Color = Color::srgb(0.10, 0.12, 0.16);
+    const BORDER_DEFAULT: Color = Color::srgb(0.32, 0.38, 0.48);
+    const BORDER_HOVER: Color = Color::srgb(0.48, 0.58, 0.70);
+    const BORDER_DISABLED: Color = Color::srgb(0.18, 0.22, 0.28);
+    const TEXT_PRIMARY: Color = Color::srgb(0.96, 0.96, 0.98);
+    const TEXT_MUTED: Color = Color::srgb(0.58, 0.62, 0.68);
+    const TEXT_SUCCESS: Color = Color::srgb(0.58, 0.88, 0.68);
+    const TEXT_INFO: Color = Color::srgb(0.58, 0.78, 1.0);
+    const TEXT_ERROR: Color = Color::srgb(1.0, 0.58, 0.58);
+    const OVERLAY_BG: Color = Color::srgb(0.03, 0.05, 0.09);
+}
+
+// ============================================================================
+// MAIN MENU SETUP SYSTEM
+// ============================================================================
+
+/// System to build the main menu on entering it, so Continue reflects the saves on disk
+pub fn spawn_main_menu_system(
+    mut commands: Commands,
+    pending: Res<PendingSuspendedRun>,
+    settings: Option<Res<GameSettings>>,
+) {
+    let can_continue = available_save(&pending).is_some();
+    let mode_label = run_mode_label(settings.as_deref().unwrap_or(&GameSettings::default()));
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            top: Val::Px(0.0),
+            width: Val::Vw(100.0),
+            height: Val::Vh(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        // Opaque, so the board isn't seen before a game is started
+        BackgroundColor(UIColors::OVERLAY_BG),
+        ZIndex(1100), // Above the pause menu and the resume prompt
+        MainMenuOverlay,
+    )).with_children(|parent| {
+        parent.spawn((
+            Node {
+                width: Val::Px(400.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(30.0)),
+                row_gap: Val::Px(20.0),
+                border: UiRect::all(Val::Px(3.0)),
+                ..default()
+            },
+            BackgroundColor(UIColors::PANEL_BG),
+            BorderColor(UIColors::PANEL_BORDER),
+            BorderRadius::all(Val::Px(15.0)),
+        )).with_children(|parent| {
+            parent.spawn((
+                Text::new("TOWER DEFENSE"),
+                TextFont {
+                    font_size: 40.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_PRIMARY),
+                Node {
+                    margin: UiRect::bottom(Val::Px(20.0)),
+                    ..default()
+                },
+            ));
+
+            create_main_menu_button(parent, "NEW GAME", MainMenuAction::NewGame, UIColors::TEXT_SUCCESS, true);
+            create_main_menu_button(parent, &mode_label, MainMenuAction::ToggleRunMode, UIColors::TEXT_INFO, true);
+            create_main_menu_button(parent, "CONTINUE", MainMenuAction::Continue, UIColors::TEXT_PRIMARY, can_continue);
+            create_main_menu_button(parent, "SETTINGS", MainMenuAction::Settings, UIColors::TEXT_INFO, true);
+            create_main_menu_button(parent, "LEADERBOARD", MainMenuAction::Leaderboard, UIColors::TEXT_INFO, true);
+            create_main_menu_button(parent, "WHAT'S NEW", MainMenuAction::WhatsNew, UIColors::TEXT_PRIMARY, true);
+            create_main_menu_button(parent, "QUIT", MainMenuAction::Quit, UIColors::TEXT_ERROR, true);
+        });
+    });
+}
+
+fn create_main_menu_button(
+    parent: &mut ChildSpawnerCommands,
+    text: &str,
+    action: MainMenuAction,
+    text_color: Color,
+    enabled: bool,
+) {
+    let (bg_color, border_color, text_color) = if enabled {
+        (UIColors::BUTTON_DEFAULT, UIColors::BORDER_DEFAULT, text_color)
+    } else {
+        (UIColors::BUTTON_DISABLED, UIColors::BORDER_DISABLED, UIColors::TEXT_MUTED)
+    };
+
+    parent.spawn((
+        Button,
+        Node {
+            width: Val::Px(280.0),
+            height: Val::Px(60.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(2.0)),
+            margin: UiRect::all(Val::Px(5.0)),
+            ..default()
+        },
+        BackgroundColor(bg_color),
+        BorderColor(border_color),
+        BorderRadius::all(Val::Px(8.0)),
+        MainMenuButton { action, enabled },
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(text),
+            TextFont {
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(text_color),
+        ));
+    });
+}
+
+// ============================================================================
+// MAIN MENU SYSTEMS
+// ============================================================================
+
+/// System to remove the main menu on leaving it
+pub fn despawn_main_menu_system(mut commands: Commands, overlays: Query<Entity, With<MainMenuOverlay>>) {
+    for overlay in overlays.iter() {
+        commands.entity(overlay).despawn();
+    }
+}
+
+/// System to handle main menu button interactions
+pub fn main_menu_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor, &MainMenuButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut settings_return: ResMut<SettingsReturnState>,
+    mut pending_continue: ResMut<PendingContinue>,
+    pending: Res<PendingSuspendedRun>,
+    mut settings: Option<ResMut<GameSettings>>,
+    mut page_events: (EventWriter<ShowLeaderboardEvent>, EventWriter<ShowChangelogEvent>, EventWriter<ShowMapSelectEvent>),
+    mut exit: EventWriter<AppExit>,
+) {
+    for (interaction, mut bg_color, mut border_color, button) in &mut interaction_query {
+        if !button.enabled {
+            continue;
+        }
+        match *interaction {
+            Interaction::Pressed => {
+                match button.action {
+                    MainMenuAction::NewGame => {
+                        page_events.2.write(ShowMapSelectEvent);
+                    }
+                    MainMenuAction::ToggleRunMode => {
+                        if let Some(settings) = settings.as_mut() {
+                            settings.run_mode = settings.run_mode.next();
+                            info!("Run mode set to {}", settings.run_mode.get_name());
+                        }
+                    }
+                    MainMenuAction::Continue => {
+                        // Checked again in case a save went missing since the menu was built
+                        let Some(slot) = available_save(&pending) else {
+                            warn!("Nothing to continue");
+                            continue;
+                        };
+                        pending_continue.0 = Some(slot);
+                        next_state.set(AppState::Playing);
+                        info!("Continuing the {:?} save from the main menu", slot);
+                    }
+                    MainMenuAction::Settings => {
+                        settings_return.0 = AppState::MainMenu;
+                        next_state.set(AppState::Settings);
+                    }
+                    MainMenuAction::Leaderboard => {
+                        page_events.0.write(ShowLeaderboardEvent);
+                    }
+                    MainMenuAction::WhatsNew => {
+                        page_events.1.write(ShowChangelogEvent);
+                    }
+                    MainMenuAction::Quit => {
+                        info!("Quit pressed in the main menu");
+                        exit.write(AppExit::Success);
+                    }
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_HOVER);
+                *border_color = BorderColor(UIColors::BORDER_HOVER);
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_DEFAULT);
+                *border_color = BorderColor(UIColors::BORDER_DEFAULT);
+            }
+        }
+    }
+}
+
+/// System to keep the run mode button label in step with the settings
+pub fn run_mode_label_system(
+    settings: Option<Res<GameSettings>>,
+    buttons: Query<(&MainMenuButton, &Children)>,
+    mut texts: Query<&mut Text>,
+) {
+    let Some(settings) = settings.filter(|settings| settings.is_changed()) else {
+        return;
+    };
+    let label = run_mode_label(&settings);
+    for (button, children) in buttons.iter() {
+        if button.action != MainMenuAction::ToggleRunMode {
+            continue;
+        }
+        for &child in &children[..] {
+            if let Ok(mut text) = texts.get_mut(child) {
+                **text = label.clone();
+            }
+        }
+    }
+}
+
+/// System to ask for the save chosen with Continue once play has started. Runs
+/// after the path is generated on entering play, so the restored map isn't
+/// replaced by a fresh one.
+pub fn continue_saved_run_system(
+    mut pending_continue: ResMut<PendingContinue>,
+    mut suspended_choices: EventWriter<SuspendedRunChoice>,
+    mut save_load: EventWriter<SaveLoadRequest>,
+) {
+    match pending_continue.0.take() {
+        Some(SaveSlot::Suspended) => {
+            suspended_choices.write(SuspendedRunChoice::Resume);
+        }
+        Some(SaveSlot::SaveState) => {
+            save_load.write(SaveLoadRequest::Load);
+        }
+        None => {}
+    }
+}
+
+// ============================================================================
+// MAIN MENU PLUGIN
+// ============================================================================
+
+/// Plugin to open the game on a main menu, keeping gameplay idle until a game is started
+pub struct MainMenuPlugin;
+
+impl Plugin for MainMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_state(AppState::MainMenu)
+            .init_resource::<SettingsReturnState>()
+            .init_resource::<PendingContinue>()
+            .add_event::<ShowLeaderboardEvent>()
+            .add_event::<ShowChangelogEvent>()
+            .add_event::<ShowMapSelectEvent>()
+            .configure_sets(Update, GameSystemSet::Gameplay.run_if(not(in_state(AppState::MainMenu))))
+            .add_systems(OnEnter(AppState::MainMenu), spawn_main_menu_system)
+            .add_systems(OnExit(AppState::MainMenu), despawn_main_menu_system)
+            .add_systems(
+                Update,
+                (
+                    (main_menu_button_system, run_mode_label_system)
+                        .chain()
+                        .in_set(GameSystemSet::UI)
+                        .run_if(in_state(AppState::MainMenu)),
+                    continue_saved_run_system
+                        .after(EnemySet::PathGeneration)
+                        .run_if(in_state(AppState::Playing)),
+                ),
+            );
+    }
+}
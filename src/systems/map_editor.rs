@@ -0,0 +1,395 @@
+use std::path::{Path, PathBuf};
+use bevy::prelude::*;
+use crate::resources::{AppState, GameSystemSet};
+use crate::systems::input_system::MouseInputState;
+use crate::systems::path_generation::{
+    map_file_stem, CellType, CustomMap, CustomMapError, GridPos, PathGrid, CUSTOM_MAP_DIR, CUSTOM_MAP_EXTENSION,
+};
+
+/// Above every gameplay sprite, so the board being painted hides the map behind it
+const EDITOR_CELL_Z: f32 = 8.0;
+const CELL_EMPTY: Color = Color::srgb(0.16, 0.22, 0.16);
+const CELL_BLOCKED: Color = Color::srgb(0.45, 0.35, 0.25);
+const CELL_ROUTE: Color = Color::srgb(0.55, 0.55, 0.3);
+const CELL_ENTRY: Color = Color::srgb(0.3, 0.85, 0.4);
+const CELL_EXIT: Color = Color::srgb(0.9, 0.3, 0.3);
+
+const PANEL_BG: Color = Color::srgba(0.08, 0.12, 0.18, 0.92);
+const PANEL_BORDER: Color = Color::srgb(0.22, 0.28, 0.38);
+const BUTTON_BG: Color = Color::srgb(0.15, 0.20, 0.28);
+const BUTTON_HOVER: Color = Color::srgb(0.20, 0.28, 0.38);
+const BUTTON_ACTIVE: Color = Color::srgb(0.25, 0.42, 0.60);
+const TEXT_PRIMARY: Color = Color::srgb(0.96, 0.96, 0.98);
+const TEXT_MUTED: Color = Color::srgb(0.58, 0.62, 0.68);
+
+/// What a click on the board does in the map editor
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum EditorTool {
+    /// Paint blocked cells
+    #[default]
+    Block,
+    /// Clear blocked cells
+    Erase,
+    /// Move the cell enemies enter from
+    Entry,
+    /// Move the cell enemies leave through
+    Exit,
+}
+
+impl EditorTool {
+    pub const ALL: [EditorTool; 4] = [EditorTool::Block, EditorTool::Erase, EditorTool::Entry, EditorTool::Exit];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            EditorTool::Block => "Block",
+            EditorTool::Erase => "Erase",
+            EditorTool::Entry => "Entry",
+            EditorTool::Exit => "Exit",
+        }
+    }
+
+    /// Whether the tool paints while the button is held, rather than once per click
+    pub fn paints_while_held(&self) -> bool {
+        matches!(self, EditorTool::Block | EditorTool::Erase)
+    }
+}
+
+/// Resource holding the map being painted in the editor
+#[derive(Resource, Debug, Clone)]
+pub struct MapEditor {
+    pub grid: PathGrid,
+    pub tool: EditorTool,
+    /// Route found by the last validation, cleared by any edit
+    pub route: Vec<GridPos>,
+    /// Result of the last validation or save
+    pub status: String,
+}
+
+impl Default for MapEditor {
+    fn default() -> Self {
+        Self {
+            grid: PathGrid::new_unified(),
+            tool: EditorTool::default(),
+            route: Vec::new(),
+            status: "Paint obstacles, then validate and save".to_string(),
+        }
+    }
+}
+
+impl MapEditor {
+    /// Apply the current tool to a cell. Entry and exit can't share a cell or
+    /// be blocked. Returns whether anything changed.
+    pub fn paint(&mut self, pos: GridPos) -> bool {
+        if !self.grid.contains(pos) {
+            return false;
+        }
+        let is_endpoint = pos == self.grid.entry_point || pos == self.grid.exit_point;
+        let changed = match self.tool {
+            EditorTool::Block if !is_endpoint => self.set_cell(pos, CellType::Blocked),
+            EditorTool::Block => false,
+            EditorTool::Erase => self.set_cell(pos, CellType::Empty),
+            EditorTool::Entry if pos != self.grid.exit_point && pos != self.grid.entry_point => {
+                self.set_cell(pos, CellType::Empty);
+                self.grid.entry_point = pos;
+                true
+            }
+            EditorTool::Exit if pos != self.grid.entry_point && pos != self.grid.exit_point => {
+                self.set_cell(pos, CellType::Empty);
+                self.grid.exit_point = pos;
+                true
+            }
+            EditorTool::Entry | EditorTool::Exit => false,
+        };
+        if changed {
+            self.route.clear();
+        }
+        changed
+    }
+
+    fn set_cell(&mut self, pos: GridPos, cell: CellType) -> bool {
+        if self.grid.get_cell(pos) == Some(cell) {
+            return false;
+        }
+        self.grid.set_cell(pos, cell)
+    }
+
+    /// The map as it would be saved under `name`
+    pub fn to_custom_map(&self, name: &str) -> CustomMap {
+        CustomMap::from_grid(name, &self.grid)
+    }
+
+    /// Check a route leads from the entry to the exit, keeping it to show on the board
+    pub fn validate(&mut self) -> Result<usize, CustomMapError> {
+        let result = self.to_custom_map("").to_grid_and_route();
+        self.route = result.as_ref().map(|(_, route)| route.clone()).unwrap_or_default();
+        self.status = match &result {
+            Ok((_, route)) => format!("Valid: enemies walk {} cells", route.len()),
+            Err(error) => format!("Invalid: {}", error),
+        };
+        result.map(|(_, route)| route.len())
+    }
+
+    /// Validate the map and save it into `dir` under the first free "Custom Map N" name
+    pub fn save(&mut self, dir: &Path) -> Result<PathBuf, CustomMapError> {
+        let result = self.validate().and_then(|_| self.to_custom_map(&next_map_name(dir)).save_to_dir(dir));
+        self.status = match &result {
+            Ok(path) => format!("Saved to {}", path.display()),
+            Err(error) => format!("Not saved: {}", error),
+        };
+        result
+    }
+}
+
+/// First "Custom Map N" whose file isn't taken in `dir`
+pub fn next_map_name(dir: &Path) -> String {
+    (1..)
+        .map(|number| format!("Custom Map {}", number))
+        .find(|name| !dir.join(format!("{}.{}", map_file_stem(name), CUSTOM_MAP_EXTENSION)).exists())
+        .unwrap_or_default()
+}
+
+/// Color a cell is drawn in on the editor board
+pub fn editor_cell_color(editor: &MapEditor, pos: GridPos) -> Color {
+    if pos == editor.grid.entry_point {
+        CELL_ENTRY
+    } else if pos == editor.grid.exit_point {
+        CELL_EXIT
+    } else if editor.grid.get_cell(pos) == Some(CellType::Blocked) {
+        CELL_BLOCKED
+    } else if editor.route.contains(&pos) {
+        CELL_ROUTE
+    } else {
+        CELL_EMPTY
+    }
+}
+
+/// Component for one cell of the board drawn by the editor
+#[derive(Component)]
+pub struct EditorCell {
+    pub position: GridPos,
+}
+
+/// Component marker for the editor toolbar
+#[derive(Component)]
+pub struct EditorToolbar;
+
+/// Component for the text reporting validation and saves
+#[derive(Component)]
+pub struct EditorStatusText;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditorAction {
+    /// Pick what clicks on the board do
+    Tool(EditorTool),
+    /// Check a route exists
+    Validate,
+    /// Validate and save into `CUSTOM_MAP_DIR`
+    Save,
+    /// Go back to the main menu
+    Exit,
+}
+
+/// Component for the editor's toolbar buttons
+#[derive(Component)]
+pub struct EditorButton {
+    pub action: EditorAction,
+}
+
+/// System to start a blank map and draw the board and toolbar on entering the editor
+pub fn enter_editor_system(mut commands: Commands) {
+    let editor = MapEditor::default();
+    let grid = &editor.grid;
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let position = GridPos::new(x, y);
+            commands.spawn((
+                Sprite {
+                    color: editor_cell_color(&editor, position),
+                    custom_size: Some(Vec2::splat(grid.cell_size - 1.0)),
+                    ..default()
+                },
+                Transform::from_translation(grid.grid_to_world(position).extend(EDITOR_CELL_Z)),
+                EditorCell { position },
+            ));
+        }
+    }
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(10.0),
+            left: Val::Percent(50.0),
+            margin: UiRect::left(Val::Px(-330.0)),
+            width: Val::Px(660.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            padding: UiRect::all(Val::Px(10.0)),
+            row_gap: Val::Px(6.0),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(PANEL_BG),
+        BorderColor(PANEL_BORDER),
+        BorderRadius::all(Val::Px(10.0)),
+        ZIndex(1100),
+        EditorToolbar,
+    )).with_children(|toolbar| {
+        toolbar.spawn(Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(6.0),
+            ..default()
+        }).with_children(|row| {
+            for tool in EditorTool::ALL {
+                spawn_editor_button(row, tool.get_name(), EditorAction::Tool(tool));
+            }
+            spawn_editor_button(row, "Validate", EditorAction::Validate);
+            spawn_editor_button(row, "Save", EditorAction::Save);
+            spawn_editor_button(row, "Exit", EditorAction::Exit);
+        });
+        toolbar.spawn((
+            Text::new(editor.status.clone()),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(TEXT_MUTED),
+            EditorStatusText,
+        ));
+    });
+
+    commands.insert_resource(editor);
+}
+
+fn spawn_editor_button(row: &mut ChildSpawnerCommands, label: &str, action: EditorAction) {
+    row.spawn((
+        Button,
+        Node {
+            width: Val::Px(84.0),
+            height: Val::Px(34.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(BUTTON_BG),
+        BorderColor(PANEL_BORDER),
+        BorderRadius::all(Val::Px(6.0)),
+        EditorButton { action },
+    )).with_children(|button| {
+        button.spawn((
+            Text::new(label),
+            TextFont {
+                font_size: 15.0,
+                ..default()
+            },
+            TextColor(TEXT_PRIMARY),
+        ));
+    });
+}
+
+/// System to remove the board and toolbar on leaving the editor
+pub fn exit_editor_system(
+    mut commands: Commands,
+    editor_entities: Query<Entity, Or<(With<EditorCell>, With<EditorToolbar>)>>,
+) {
+    for entity in editor_entities.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// System to paint the board under the cursor. Clicks on the toolbar are left to its buttons.
+pub fn editor_paint_system(
+    mouse: Res<MouseInputState>,
+    editor: Option<ResMut<MapEditor>>,
+    buttons: Query<&Interaction, With<Button>>,
+) {
+    let Some(mut editor) = editor else {
+        return;
+    };
+    let painting = mouse.left_clicked || (mouse.left_held && editor.tool.paints_while_held());
+    if !painting || buttons.iter().any(|interaction| *interaction != Interaction::None) {
+        return;
+    }
+    let Some(pos) = editor.grid.world_to_grid(mouse.world_position) else {
+        return;
+    };
+    // Only flag a real edit, so the board isn't redrawn every frame the button is held
+    if editor.bypass_change_detection().paint(pos) {
+        editor.set_changed();
+    }
+}
+
+/// System to handle the editor's toolbar buttons
+pub fn editor_button_system(
+    mut interaction_query: Query<(&Interaction, &mut BackgroundColor, &EditorButton), Changed<Interaction>>,
+    editor: Option<ResMut<MapEditor>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    let Some(mut editor) = editor else {
+        return;
+    };
+    for (interaction, mut background, button) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => match button.action {
+                EditorAction::Tool(tool) => editor.tool = tool,
+                EditorAction::Validate => {
+                    let _ = editor.validate();
+                }
+                EditorAction::Save => match editor.save(Path::new(CUSTOM_MAP_DIR)) {
+                    Ok(path) => info!("Custom map saved to {}", path.display()),
+                    Err(error) => warn!("Custom map not saved: {}", error),
+                },
+                EditorAction::Exit => next_state.set(AppState::MainMenu),
+            },
+            Interaction::Hovered => *background = BackgroundColor(BUTTON_HOVER),
+            Interaction::None if button.action == EditorAction::Tool(editor.tool) => {
+                *background = BackgroundColor(BUTTON_ACTIVE);
+            }
+            Interaction::None => *background = BackgroundColor(BUTTON_BG),
+        }
+    }
+}
+
+/// System to redraw the board, status and selected tool after an edit
+pub fn editor_display_system(
+    editor: Option<Res<MapEditor>>,
+    mut cells: Query<(&EditorCell, &mut Sprite)>,
+    mut status_texts: Query<&mut Text, With<EditorStatusText>>,
+    mut buttons: Query<(&EditorButton, &Interaction, &mut BackgroundColor)>,
+) {
+    let Some(editor) = editor.filter(|editor| editor.is_changed()) else {
+        return;
+    };
+    for (cell, mut sprite) in &mut cells {
+        sprite.color = editor_cell_color(&editor, cell.position);
+    }
+    for mut text in &mut status_texts {
+        **text = editor.status.clone();
+    }
+    for (button, interaction, mut background) in &mut buttons {
+        if button.action == EditorAction::Tool(editor.tool) {
+            *background = BackgroundColor(BUTTON_ACTIVE);
+        } else if *interaction == Interaction::None {
+            *background = BackgroundColor(BUTTON_BG);
+        }
+    }
+}
+
+/// Plugin for the map editor, opened from the map select screen. Maps are
+/// saved as RON into `CUSTOM_MAP_DIR`, where the map select screen finds them.
+pub struct MapEditorPlugin;
+
+impl Plugin for MapEditorPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(Update, GameSystemSet::Gameplay.run_if(not(in_state(AppState::Editor))))
+            .add_systems(OnEnter(AppState::Editor), enter_editor_system)
+            .add_systems(OnExit(AppState::Editor), exit_editor_system)
+            .add_systems(
+                Update,
+                (editor_button_system, editor_paint_system, editor_display_system)
+                    .chain()
+                    .in_set(GameSystemSet::UI)
+                    .run_if(in_state(AppState::Editor)),
+            );
+    }
+}
@@ -0,0 +1,587 @@
+use std::path::Path;
+use bevy::prelude::*;
+use crate::resources::{ActiveSeasonalEvent, AppState, EnemyPath, GameRng, GameSystemSet, GRID_HEIGHT, GRID_WIDTH};
+use crate::systems::map_share_system::apply_shared_map;
+use crate::systems::obstacle_rendering::{spawn_level_obstacles, ObstacleGrid};
+use crate::systems::seed_entry::{spawn_seed_field, SeedEntry};
+use crate::systems::path_generation::{
+    current_level_seed, generate_level_path_with_config, load_custom_maps, set_level_archetype, set_level_seed, CustomMap,
+    FixedLevelPath, LevelConfig, LevelLayout, MapArchetype, Obstacle, ObstacleType, SharedMap, CUSTOM_MAP_DIR,
+};
+
+const OVERLAY_BG: Color = Color::srgba(0.0, 0.0, 0.0, 0.7);
+const PANEL_BG: Color = Color::srgb(0.08, 0.12, 0.18);
+const PANEL_BORDER: Color = Color::srgb(0.22, 0.28, 0.38);
+const BUTTON_BG: Color = Color::srgb(0.15, 0.20, 0.28);
+const BUTTON_HOVER: Color = Color::srgb(0.20, 0.28, 0.38);
+const TEXT_PRIMARY: Color = Color::srgb(0.96, 0.96, 0.98);
+const TEXT_MUTED: Color = Color::srgb(0.58, 0.62, 0.68);
+
+/// Colors a map is drawn in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MapTheme {
+    /// Background behind the board
+    pub background: Color,
+    /// Multiplied into obstacle colors, white for none
+    pub obstacle_tint: Color,
+}
+
+/// A named map that can be picked before starting a game
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapConfig {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Obstacle placement strategy
+    pub archetype: MapArchetype,
+    /// Playable width in cells, up to the full 32-cell board
+    pub width: usize,
+    /// Playable height in cells, up to the full 18-cell board
+    pub height: usize,
+    /// Share of playable cells blocked by obstacles (0.0-0.5)
+    pub obstacle_density: f32,
+    /// Fixed seed for a hand-picked layout, or `None` for a fresh layout every game
+    pub seed: Option<u64>,
+    /// Enemy routes from separate entry points, 1 to `MAX_LEVEL_ROUTES`
+    pub routes: usize,
+    pub theme: MapTheme,
+}
+
+impl MapConfig {
+    /// Board size and density handed to level generation
+    pub fn layout(&self) -> LevelLayout {
+        LevelLayout {
+            width: self.width,
+            height: self.height,
+            obstacle_density: self.obstacle_density,
+        }
+    }
+
+    /// Board, routes and terrain of this map for the run's `LevelConfig`
+    pub fn level_config(&self) -> LevelConfig {
+        let mut level_config = LevelConfig {
+            layout: Some(self.layout()),
+            ..LevelConfig::default()
+        };
+        level_config.set_routes(self.routes);
+        level_config
+    }
+
+    /// Make this the map levels are generated from. A typed seed wins over the
+    /// map's own; maps without a fixed seed otherwise roll a new one.
+    pub fn apply(&self, seed: Option<u64>, level_config: &mut LevelConfig) {
+        set_level_seed(seed.or(self.seed).unwrap_or_else(rand::random));
+        set_level_archetype(self.archetype);
+        *level_config = self.level_config();
+    }
+
+    /// Card subtitle, e.g. "Maze  24x14", with the routes of multi-route maps
+    pub fn summary(&self) -> String {
+        let size = format!("{}  {}x{}", self.archetype.get_name(), self.width, self.height);
+        if self.routes > 1 {
+            format!("{}  {} routes", size, self.routes)
+        } else {
+            size
+        }
+    }
+}
+
+/// Resource listing the maps offered on the map select screen
+#[derive(Resource, Debug, Clone)]
+pub struct MapRegistry {
+    pub maps: Vec<MapConfig>,
+    /// Index of the map picked for the current game, `None` until one is
+    /// picked or when a custom map is played
+    pub selected: Option<usize>,
+    /// Maps saved by the map editor, reread each time the screen opens
+    pub custom: Vec<CustomMap>,
+}
+
+impl Default for MapRegistry {
+    fn default() -> Self {
+        Self {
+            maps: vec![
+                MapConfig {
+                    name: "Verdant Valley",
+                    description: "Rocky clusters around natural chokepoints",
+                    archetype: MapArchetype::Classic,
+                    width: 32,
+                    height: 18,
+                    obstacle_density: 0.12,
+                    seed: None,
+                    routes: 1,
+                    theme: MapTheme {
+                        background: Color::srgb(0.16, 0.24, 0.16),
+                        obstacle_tint: Color::WHITE,
+                    },
+                },
+                MapConfig {
+                    name: "Open Plains",
+                    description: "Few obstacles and long sightlines",
+                    archetype: MapArchetype::OpenField,
+                    width: 32,
+                    height: 18,
+                    obstacle_density: 0.04,
+                    seed: None,
+                    routes: 1,
+                    theme: MapTheme {
+                        background: Color::srgb(0.30, 0.27, 0.18),
+                        obstacle_tint: Color::srgb(1.0, 0.92, 0.8),
+                    },
+                },
+                MapConfig {
+                    name: "Labyrinth",
+                    description: "Winding corridors between stone walls",
+                    archetype: MapArchetype::Maze,
+                    width: 32,
+                    height: 18,
+                    obstacle_density: 0.24,
+                    seed: Some(1337),
+                    routes: 1,
+                    theme: MapTheme {
+                        background: Color::srgb(0.14, 0.14, 0.16),
+                        obstacle_tint: Color::srgb(0.8, 0.8, 0.9),
+                    },
+                },
+                MapConfig {
+                    name: "Archipelago",
+                    description: "Build zones ringed by reefs",
+                    archetype: MapArchetype::Islands,
+                    width: 32,
+                    height: 18,
+                    obstacle_density: 0.14,
+                    seed: Some(2024),
+                    routes: 1,
+                    theme: MapTheme {
+                        background: Color::srgb(0.10, 0.18, 0.30),
+                        obstacle_tint: Color::srgb(0.8, 0.95, 1.0),
+                    },
+                },
+                MapConfig {
+                    name: "The Pit",
+                    description: "A cramped arena with little room to build",
+                    archetype: MapArchetype::Classic,
+                    width: 22,
+                    height: 12,
+                    obstacle_density: 0.08,
+                    seed: Some(77),
+                    routes: 1,
+                    theme: MapTheme {
+                        background: Color::srgb(0.22, 0.10, 0.08),
+                        obstacle_tint: Color::srgb(1.0, 0.7, 0.6),
+                    },
+                },
+                MapConfig {
+                    name: "Crossroads",
+                    description: "Three gates open at once and meet at the base",
+                    archetype: MapArchetype::OpenField,
+                    width: 32,
+                    height: 18,
+                    obstacle_density: 0.08,
+                    seed: None,
+                    routes: 3,
+                    theme: MapTheme {
+                        background: Color::srgb(0.20, 0.22, 0.12),
+                        obstacle_tint: Color::srgb(0.95, 0.95, 0.8),
+                    },
+                },
+            ],
+            selected: None,
+            custom: Vec::new(),
+        }
+    }
+}
+
+impl MapRegistry {
+    /// Map picked for the current game
+    pub fn selected_map(&self) -> Option<&MapConfig> {
+        self.selected.and_then(|index| self.maps.get(index))
+    }
+
+    /// Pick the map at `index`, returning it, or `None` if there is no such map
+    pub fn select(&mut self, index: usize) -> Option<&MapConfig> {
+        if index >= self.maps.len() {
+            return None;
+        }
+        self.selected = Some(index);
+        self.maps.get(index)
+    }
+}
+
+/// Multiply an obstacle color by a theme tint, keeping its alpha
+pub fn tint_color(color: Color, tint: Color) -> Color {
+    let color = color.to_srgba();
+    let tint = tint.to_srgba();
+    Color::srgba(color.red * tint.red, color.green * tint.green, color.blue * tint.blue, color.alpha)
+}
+
+/// Request from the main menu to open the map select screen
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShowMapSelectEvent;
+
+/// Component marker for the map select screen
+#[derive(Component)]
+pub struct MapSelectPage;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MapSelectAction {
+    /// Start a game on the map at this registry index
+    Pick(usize),
+    /// Start a game on the custom map at this index in `MapRegistry::custom`
+    Custom(usize),
+    /// Open the map editor
+    Editor,
+    /// Go back to the main menu
+    Back,
+}
+
+/// Component for the buttons on the map select screen
+#[derive(Component)]
+pub struct MapSelectButton {
+    pub action: MapSelectAction,
+}
+
+/// System to open the map select screen when asked for from the main menu
+pub fn show_map_select_system(
+    mut commands: Commands,
+    mut events: EventReader<ShowMapSelectEvent>,
+    mut registry: ResMut<MapRegistry>,
+    seed_entry: Option<Res<SeedEntry>>,
+    pages: Query<(), With<MapSelectPage>>,
+) {
+    if events.read().count() == 0 || !pages.is_empty() {
+        return;
+    }
+    let (custom, failures) = load_custom_maps(Path::new(CUSTOM_MAP_DIR));
+    for (path, error) in failures {
+        warn!("Skipping custom map {}: {}", path.display(), error);
+    }
+    registry.custom = custom;
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Vw(100.0),
+            height: Val::Vh(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(OVERLAY_BG),
+        ZIndex(1150), // Above the main menu
+        MapSelectPage,
+    )).with_children(|parent| {
+        parent.spawn((
+            Node {
+                width: Val::Px(560.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(24.0)),
+                row_gap: Val::Px(8.0),
+                border: UiRect::all(Val::Px(3.0)),
+                ..default()
+            },
+            BackgroundColor(PANEL_BG),
+            BorderColor(PANEL_BORDER),
+            BorderRadius::all(Val::Px(15.0)),
+        )).with_children(|panel| {
+            panel.spawn((
+                Text::new("SELECT MAP"),
+                TextFont {
+                    font_size: 32.0,
+                    ..default()
+                },
+                TextColor(TEXT_PRIMARY),
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+
+            if let Some(seed_entry) = seed_entry.as_deref() {
+                spawn_seed_field(panel, seed_entry);
+            }
+
+            for (index, map) in registry.maps.iter().enumerate() {
+                spawn_map_card(panel, index, map);
+            }
+
+            for (index, map) in registry.custom.iter().enumerate() {
+                spawn_custom_map_card(panel, index, map);
+            }
+
+            panel.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(12.0),
+                margin: UiRect::top(Val::Px(12.0)),
+                ..default()
+            }).with_children(|row| {
+                spawn_page_button(row, "MAP EDITOR", MapSelectAction::Editor);
+                spawn_page_button(row, "BACK", MapSelectAction::Back);
+            });
+        });
+    });
+}
+
+fn spawn_page_button(row: &mut ChildSpawnerCommands, label: &str, action: MapSelectAction) {
+    row.spawn((
+        Button,
+        Node {
+            width: Val::Px(200.0),
+            height: Val::Px(44.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(BUTTON_BG),
+        BorderColor(PANEL_BORDER),
+        BorderRadius::all(Val::Px(8.0)),
+        MapSelectButton { action },
+    )).with_children(|button| {
+        button.spawn((
+            Text::new(label),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(TEXT_PRIMARY),
+        ));
+    });
+}
+
+/// Button for a map saved by the map editor
+fn spawn_custom_map_card(panel: &mut ChildSpawnerCommands, index: usize, map: &CustomMap) {
+    panel.spawn((
+        Button,
+        Node {
+            width: Val::Px(500.0),
+            height: Val::Px(40.0),
+            align_items: AlignItems::Center,
+            padding: UiRect::horizontal(Val::Px(10.0)),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(BUTTON_BG),
+        BorderColor(PANEL_BORDER),
+        BorderRadius::all(Val::Px(8.0)),
+        MapSelectButton { action: MapSelectAction::Custom(index) },
+    )).with_children(|card| {
+        card.spawn((
+            Text::new(format!("{}  (Custom  {} obstacles)", map.name, map.blocked.len())),
+            TextFont {
+                font_size: 16.0,
+                ..default()
+            },
+            TextColor(TEXT_PRIMARY),
+        ));
+    });
+}
+
+/// Button for one map: a swatch of its background next to its name and summary
+fn spawn_map_card(panel: &mut ChildSpawnerCommands, index: usize, map: &MapConfig) {
+    panel.spawn((
+        Button,
+        Node {
+            width: Val::Px(500.0),
+            height: Val::Px(56.0),
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(12.0),
+            padding: UiRect::horizontal(Val::Px(10.0)),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(BUTTON_BG),
+        BorderColor(PANEL_BORDER),
+        BorderRadius::all(Val::Px(8.0)),
+        MapSelectButton { action: MapSelectAction::Pick(index) },
+    )).with_children(|card| {
+        card.spawn((
+            Node {
+                width: Val::Px(36.0),
+                height: Val::Px(36.0),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(map.theme.background),
+            BorderColor(tint_color(Color::srgb(0.5, 0.45, 0.4), map.theme.obstacle_tint)),
+            BorderRadius::all(Val::Px(4.0)),
+        ));
+        card.spawn(Node {
+            flex_direction: FlexDirection::Column,
+            ..default()
+        }).with_children(|labels| {
+            labels.spawn((
+                Text::new(format!("{}  ({})", map.name, map.summary())),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(TEXT_PRIMARY),
+            ));
+            labels.spawn((
+                Text::new(map.description),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(TEXT_MUTED),
+            ));
+        });
+    });
+}
+
+/// System to start a game on the picked map, regenerating its path and
+/// obstacles, open the map editor, or go back to the main menu. Apps with a
+/// fixed path keep it.
+pub fn map_select_button_system(
+    mut commands: Commands,
+    mut interaction_query: Query<(&Interaction, &mut BackgroundColor, &MapSelectButton), Changed<Interaction>>,
+    mut registry: ResMut<MapRegistry>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut enemy_path: ResMut<EnemyPath>,
+    mut obstacle_grid: ResMut<ObstacleGrid>,
+    mut level_config: ResMut<LevelConfig>,
+    fixed_path: Option<Res<FixedLevelPath>>,
+    seed_entry: Option<Res<SeedEntry>>,
+    (mut clear_color, seasonal): (Option<ResMut<ClearColor>>, Option<Res<ActiveSeasonalEvent>>),
+    obstacles: Query<Entity, With<Obstacle>>,
+    pages: Query<Entity, With<MapSelectPage>>,
+) {
+    let typed_seed = seed_entry.as_deref().and_then(SeedEntry::seed);
+    for (interaction, mut background, button) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                let started = match button.action {
+                    MapSelectAction::Back => {
+                        for page in pages.iter() {
+                            commands.entity(page).despawn();
+                        }
+                        continue;
+                    }
+                    MapSelectAction::Editor => {
+                        next_state.set(AppState::Editor);
+                        continue;
+                    }
+                    MapSelectAction::Pick(index) => {
+                        let Some(map) = registry.select(index) else {
+                            warn!("No map at index {}", index);
+                            continue;
+                        };
+                        map.apply(typed_seed, &mut level_config);
+                        if fixed_path.is_none() {
+                            for obstacle in obstacles.iter() {
+                                commands.entity(obstacle).despawn();
+                            }
+                            *enemy_path = generate_level_path_with_config(1, &level_config);
+                            spawn_level_obstacles(&mut commands, &mut obstacle_grid, &level_config);
+                        }
+                        // A seasonal event's tint wins over the map's own background
+                        let seasonal_tint = seasonal
+                            .as_deref()
+                            .and_then(|active| active.event.as_ref())
+                            .and_then(|event| event.tint());
+                        if let (Some(clear_color), None) = (clear_color.as_deref_mut(), seasonal_tint) {
+                            clear_color.0 = map.theme.background;
+                        }
+                        map.name.to_string()
+                    }
+                    MapSelectAction::Custom(index) => {
+                        let Some(map) = registry.custom.get(index) else {
+                            warn!("No custom map at index {}", index);
+                            continue;
+                        };
+                        let shared_map = match custom_map_for_play(map) {
+                            Ok(shared_map) => shared_map,
+                            Err(error) => {
+                                warn!("Can't play {}: {}", map.name, error);
+                                continue;
+                            }
+                        };
+                        let name = map.name.clone();
+                        registry.selected = None;
+                        set_level_seed(typed_seed.unwrap_or_else(rand::random));
+                        set_level_archetype(shared_map.archetype);
+                        *level_config = LevelConfig::default();
+                        if fixed_path.is_none() {
+                            for obstacle in obstacles.iter() {
+                                commands.entity(obstacle).despawn();
+                            }
+                            apply_shared_map(&mut commands, &shared_map, &mut obstacle_grid, &mut enemy_path);
+                        }
+                        name
+                    }
+                };
+
+                for page in pages.iter() {
+                    commands.entity(page).despawn();
+                }
+                commands.insert_resource(GameRng::from_seed(current_level_seed()));
+                next_state.set(AppState::Playing);
+                info!("New game started on {} with seed {}", started, current_level_seed());
+                return;
+            }
+            Interaction::Hovered => *background = BackgroundColor(BUTTON_HOVER),
+            Interaction::None => *background = BackgroundColor(BUTTON_BG),
+        }
+    }
+}
+
+/// A saved custom map as a playable map with its route. Custom maps are
+/// painted on the full board, so other sizes are turned down.
+pub fn custom_map_for_play(map: &CustomMap) -> Result<SharedMap, String> {
+    if (map.width, map.height) != (GRID_WIDTH, GRID_HEIGHT) {
+        return Err(format!("{}x{} doesn't fill the {}x{} board", map.width, map.height, GRID_WIDTH, GRID_HEIGHT));
+    }
+    let (grid, route) = map.to_grid_and_route().map_err(|error| error.to_string())?;
+    Ok(SharedMap {
+        grid,
+        route,
+        extra_routes: Vec::new(),
+        archetype: MapArchetype::Classic,
+    })
+}
+
+/// System to tint newly spawned obstacles with the picked map's theme
+pub fn map_theme_obstacle_system(
+    registry: Res<MapRegistry>,
+    mut obstacles: Query<(&Obstacle, &mut Sprite), Added<Obstacle>>,
+) {
+    let Some(map) = registry.selected_map() else {
+        return;
+    };
+    for (obstacle, mut sprite) in obstacles.iter_mut() {
+        // Void is water or chasm, not part of the scenery
+        if obstacle.obstacle_type != ObstacleType::Void {
+            sprite.color = tint_color(sprite.color, map.theme.obstacle_tint);
+        }
+    }
+}
+
+/// System to remove the map select screen on leaving the main menu
+pub fn despawn_map_select_system(mut commands: Commands, pages: Query<Entity, With<MapSelectPage>>) {
+    for page in pages.iter() {
+        commands.entity(page).despawn();
+    }
+}
+
+/// Plugin for the map select screen New Game opens from the main menu, listing
+/// the built-in maps and any saved from the map editor
+pub struct MapSelectPlugin;
+
+impl Plugin for MapSelectPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MapRegistry>()
+            .init_resource::<LevelConfig>()
+            .add_event::<ShowMapSelectEvent>()
+            .add_systems(OnExit(AppState::MainMenu), despawn_map_select_system)
+            .add_systems(
+                Update,
+                (
+                    (show_map_select_system, map_select_button_system)
+                        .in_set(GameSystemSet::UI)
+                        .run_if(in_state(AppState::MainMenu)),
+                    map_theme_obstacle_system,
+                ),
+            );
+    }
+}
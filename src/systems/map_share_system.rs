@@ -0,0 +1,118 @@
+use bevy::prelude::*;
+use crate::resources::{AppState, EnemyPath, GameSystemSet};
+use crate::systems::obstacle_rendering::ObstacleGrid;
+use crate::systems::path_generation::{analyze_map, create_obstacle_entities, current_level_seed, GridPos, SharedMap, SHARED_MAP_FILE};
+use crate::systems::results_screen::RestartRunEvent;
+
+/// Event sent from the pause menu to share the current map or play a shared one
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapShareRequest {
+    /// Write the current map to `SHARED_MAP_FILE`
+    Export,
+    /// Start a new run on the map in `SHARED_MAP_FILE`
+    Import,
+}
+
+/// Imported map waiting for the restart that puts it in play
+#[derive(Resource, Debug, Default)]
+pub struct PendingSharedMap(pub Option<SharedMap>);
+
+/// Component for the pause menu text reporting the last export or import
+#[derive(Component)]
+pub struct MapShareStatusText;
+
+/// The map being played: the obstacle grid and the routes enemies follow through it
+pub fn current_shared_map(obstacle_grid: &ObstacleGrid, enemy_path: &EnemyPath) -> Option<SharedMap> {
+    let grid = &obstacle_grid.grid;
+    let grid_route = |waypoints: &[Vec2]| {
+        let mut route: Vec<GridPos> = Vec::with_capacity(waypoints.len());
+        for waypoint in waypoints {
+            let pos = grid.world_to_grid(*waypoint)?;
+            if route.last() != Some(&pos) {
+                route.push(pos);
+            }
+        }
+        Some(route)
+    };
+    Some(SharedMap {
+        grid: grid.clone(),
+        route: grid_route(&enemy_path.waypoints)?,
+        extra_routes: enemy_path
+            .extra_routes
+            .iter()
+            .filter(|route| !route.is_empty())
+            .map(|route| grid_route(route))
+            .collect::<Option<_>>()?,
+        archetype: obstacle_grid.analysis.archetype,
+    })
+}
+
+/// Replace the map in play with a shared one, instead of generating a level
+pub fn apply_shared_map(
+    commands: &mut Commands,
+    map: &SharedMap,
+    obstacle_grid: &mut ObstacleGrid,
+    enemy_path: &mut EnemyPath,
+) {
+    create_obstacle_entities(commands, &map.grid, current_level_seed().wrapping_add(5000));
+    obstacle_grid.grid = map.grid.clone();
+    obstacle_grid.wave_number = 1;
+    obstacle_grid.analysis = analyze_map(&map.grid, map.archetype);
+    let extra_routes = map
+        .extra_routes
+        .iter()
+        .map(|route| route.iter().map(|&pos| map.grid.grid_to_world(pos)).collect())
+        .collect();
+    *enemy_path = map.grid.to_enemy_path(map.route.clone()).with_extra_routes(extra_routes);
+}
+
+/// System to export the current map or queue an imported one for a fresh run
+pub fn handle_map_share_requests(
+    mut requests: EventReader<MapShareRequest>,
+    mut pending: ResMut<PendingSharedMap>,
+    mut restart_events: EventWriter<RestartRunEvent>,
+    mut next_state: ResMut<NextState<AppState>>,
+    obstacle_grid: Res<ObstacleGrid>,
+    enemy_path: Res<EnemyPath>,
+    mut status_query: Query<&mut Text, With<MapShareStatusText>>,
+) {
+    for request in requests.read() {
+        let status = match request {
+            MapShareRequest::Export => match current_shared_map(&obstacle_grid, &enemy_path) {
+                Some(map) => match map.save_to_file(SHARED_MAP_FILE) {
+                    Ok(()) => {
+                        info!("Map exported to {}: {}", SHARED_MAP_FILE, map.to_code());
+                        format!("Map saved to {}", SHARED_MAP_FILE)
+                    }
+                    Err(error) => format!("Export failed: {}", error),
+                },
+                None => "Export failed: the enemy path leaves the map".to_string(),
+            },
+            MapShareRequest::Import => match SharedMap::load_from_file(SHARED_MAP_FILE) {
+                Ok(map) => {
+                    info!("Imported {} map from {}", map.archetype.get_name(), SHARED_MAP_FILE);
+                    pending.0 = Some(map);
+                    restart_events.write(RestartRunEvent { new_seed: false });
+                    next_state.set(AppState::Playing);
+                    format!("Playing the map from {}", SHARED_MAP_FILE)
+                }
+                Err(error) => format!("Import failed: {}", error),
+            },
+        };
+        for mut text in &mut status_query {
+            **text = status.clone();
+        }
+    }
+}
+
+/// Plugin for exporting and importing maps from the pause menu
+pub struct MapSharePlugin;
+
+impl Plugin for MapSharePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<MapShareRequest>()
+            .init_resource::<PendingSharedMap>()
+            .add_systems(Update, handle_map_share_requests.in_set(GameSystemSet::UI));
+    }
+}
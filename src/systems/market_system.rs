@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+use crate::resources::{AppState, EnemySet, GameSystemSet, MarketState, WavePlan};
+use crate::systems::path_generation::current_level_seed;
+use crate::systems::settings_menu::GameSettings;
+
+/// System to keep the market in step with the settings and the wave: prices
+/// move once per wave started and go back to list price when a new run or an
+/// earlier checkpoint rewinds the wave count or changes the seed
+pub fn market_system(
+    settings: Option<Res<GameSettings>>,
+    wave_plan: Res<WavePlan>,
+    mut market: ResMut<MarketState>,
+) {
+    let enabled = settings.is_some_and(|settings| settings.market_mode_enabled);
+    if market.enabled != enabled {
+        market.enabled = enabled;
+        info!("Market prices {}", if enabled { "on" } else { "off" });
+    }
+
+    let wave = wave_plan.current_wave;
+    let seed = current_level_seed();
+    if wave < market.wave || market.seed != seed {
+        let mut fresh = MarketState::new(seed);
+        fresh.enabled = enabled;
+        *market = fresh;
+    }
+    if market.wave != wave {
+        market.advance_to(wave);
+    }
+}
+
+/// Plugin for the optional market mode where tower prices drift from wave to wave
+pub struct MarketPlugin;
+
+impl Plugin for MarketPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .insert_resource(MarketState::new(current_level_seed()))
+            .add_systems(
+                Update,
+                market_system
+                    .in_set(GameSystemSet::Gameplay)
+                    .after(EnemySet::WaveControl)
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
@@ -13,10 +13,71 @@ pub mod security;
 pub mod debug_toggle;
 pub mod tower_ui;
 pub mod tower_rendering;
+pub mod construction_system;
 pub mod unified_grid;
 pub mod obstacle_rendering;
 pub mod pause_system;
 pub mod settings_menu;
+pub mod results_screen;
+pub mod tween;
+pub mod system_order;
+pub mod loot_system;
+pub mod overclock_system;
+pub mod multi_select_system;
+pub mod checkpoint_system;
+pub mod action_camera;
+pub mod ui_feedback;
+pub mod advisor_system;
+pub mod spawn_preview;
+pub mod smart_enemy_system;
+pub mod base_system;
+pub mod shop_system;
+pub mod placement_validator;
+pub mod threat_alert_system;
+pub mod balance_system;
+pub mod effect_budget_system;
+pub mod help_overlay;
+pub mod enemy_count_hud;
+pub mod stress_test_system;
+pub mod map_share_system;
+pub mod popup_layout;
+pub mod placement_assist;
+pub mod boss_arena_system;
+pub mod game_snapshot;
+pub mod focus_zone_system;
+pub mod simulation_clock_system;
+pub mod reward_chest_system;
+pub mod codex_system;
+pub mod virtual_cursor;
+pub mod visual_regression;
+pub mod hit_feedback_system;
+pub mod void_terrain_system;
+pub mod suspend_system;
+pub mod bounty_system;
+pub mod frame_pacing_system;
+pub mod save_load;
+pub mod occupancy;
+pub mod main_menu;
+pub mod seasonal_event_system;
+pub mod remote_commands;
+pub mod wave_config_system;
+pub mod privacy_system;
+pub mod crowding_system;
+pub mod screen_shake;
+pub mod first_breach_system;
+pub mod market_system;
+pub mod whats_new;
+pub mod leaderboard_page;
+pub mod controls_menu;
+pub mod wave_preview_panel;
+pub mod status_effect_system;
+pub mod floating_text;
+pub mod tower_animation;
+pub mod enemy_death_system;
+pub mod particles;
+pub mod map_select;
+pub mod seed_entry;
+pub mod map_editor;
 
 pub use tower_system::*;
 pub use enemy_system::*;
@@ -31,7 +92,52 @@ pub use debug_visualization::*;
 pub use debug_ui::*;
 pub use tower_ui::*;
 pub use tower_rendering::*;
+pub use construction_system::*;
 pub use unified_grid::*;
 pub use obstacle_rendering::*;
 pub use pause_system::*;
-pub use settings_menu::*;
\ No newline at end of file
+pub use settings_menu::*;
+pub use results_screen::*;
+pub use tween::*;
+pub use system_order::*;
+pub use loot_system::*;
+pub use overclock_system::*;
+pub use multi_select_system::*;
+pub use checkpoint_system::*;
+pub use action_camera::*;
+pub use ui_feedback::*;
+pub use advisor_system::*;
+pub use spawn_preview::*;
+pub use smart_enemy_system::*;
+pub use base_system::*;
+pub use shop_system::*;
+pub use placement_validator::*;
+pub use threat_alert_system::*;
+pub use balance_system::*;
+pub use effect_budget_system::*;
+pub use help_overlay::*;
+pub use enemy_count_hud::*;
+pub use stress_test_system::*;
+pub use map_share_system::*;
+pub use popup_layout::*;
+pub use placement_assist::*;
+pub use boss_arena_system::*;
+pub use game_snapshot::*;
+pub use focus_zone_system::*;
+pub use simulation_clock_system::*;
+pub use reward_chest_system::*;
+pub use codex_system::*;
+pub use virtual_cursor::*;
+pub use visual_regression::*;
+pub use hit_feedback_system::*;
+pub use void_terrain_system::*;
+pub use suspend_system::*;
+pub use bounty_system::*;
+pub use frame_pacing_system::*;
+pub use save_load::*;
+pub use occupancy::*;
+pub use main_menu::*;
+pub use seasonal_event_system::*;
+pub use remote_commands::*;
+pub use wave_config_system::*;
+pub use privacy_system::*;
\ No newline at end of file
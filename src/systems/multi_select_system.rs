@@ -0,0 +1,550 @@
+use bevy::prelude::*;
+use std::collections::HashSet;
+use crate::components::{Constructing, PlacementGrace};
+use crate::resources::{AppState, Economy, GameConstants, GameSystemSet, NumberFormatter, ResourceCost, TowerStats};
+use crate::systems::combat_system::TargetingMode;
+use crate::systems::construction_system::sell_refund;
+use crate::systems::focus_zone_system::FocusZoneDrawing;
+use crate::systems::input::{InputContext, InputRegistryAppExt};
+use crate::systems::input_system::MouseInputState;
+use crate::systems::occupancy::OccupancyMap;
+use crate::systems::settings_menu::GameSettings;
+use crate::systems::tower_ui::{tower_selection_system, try_upgrade_tower, TowerSelectionState};
+
+/// Minimum drag distance (world units) before a drag becomes a band selection
+const MIN_BAND_DRAG: f32 = 8.0;
+
+// ============================================================================
+// STATE
+// ============================================================================
+
+/// Resource holding the set of towers selected for group operations
+#[derive(Resource, Debug, Default)]
+pub struct TowerMultiSelection {
+    pub towers: HashSet<Entity>,
+    /// Set by the first SELL ALL click; the second click confirms the sale
+    pub sell_confirm_pending: bool,
+}
+
+impl TowerMultiSelection {
+    pub fn is_active(&self) -> bool {
+        !self.towers.is_empty()
+    }
+
+    /// Add the tower if it isn't selected yet, otherwise remove it
+    pub fn toggle(&mut self, tower_entity: Entity) {
+        if !self.towers.remove(&tower_entity) {
+            self.towers.insert(tower_entity);
+        }
+        self.sell_confirm_pending = false;
+    }
+
+    pub fn clear(&mut self) {
+        self.towers.clear();
+        self.sell_confirm_pending = false;
+    }
+
+    /// Selected towers in a stable order, so batch operations are deterministic
+    pub fn sorted(&self) -> Vec<Entity> {
+        let mut towers: Vec<Entity> = self.towers.iter().copied().collect();
+        towers.sort();
+        towers
+    }
+}
+
+/// Batch operation applied to every selected tower
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub enum GroupOperation {
+    /// Upgrade each selected tower once, cheapest first, while money lasts
+    UpgradeAll,
+    /// Sell every selected tower and refund the total
+    SellAll,
+    SetTargeting(TargetingMode),
+}
+
+// ============================================================================
+// COMPONENTS
+// ============================================================================
+
+/// Rectangle drawn while band-selecting
+#[derive(Component)]
+pub struct SelectionBand;
+
+/// Ring drawn under each tower in the multi-selection
+#[derive(Component)]
+pub struct MultiSelectIndicator;
+
+/// Panel listing the multi-selection and its group actions
+#[derive(Component)]
+pub struct GroupActionPanel;
+
+#[derive(Component)]
+pub struct GroupSummaryText;
+
+/// Which group action a panel button triggers
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupActionButton {
+    UpgradeAll,
+    SellAll,
+    CycleTargeting,
+}
+
+#[derive(Component)]
+pub struct GroupActionButtonText(pub GroupActionButton);
+
+// ============================================================================
+// INPUT SYSTEMS
+// ============================================================================
+
+fn shift_held(keyboard_input: &ButtonInput<KeyCode>) -> bool {
+    keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight])
+}
+
+/// System to handle group action buttons, including the two-step sell confirmation
+/// unless it is turned off in the settings
+pub fn group_action_button_system(
+    settings: Option<Res<GameSettings>>,
+    mut multi_selection: ResMut<TowerMultiSelection>,
+    mut mouse_input_state: ResMut<MouseInputState>,
+    mut operations: EventWriter<GroupOperation>,
+    interaction_query: Query<(&Interaction, &GroupActionButton), Changed<Interaction>>,
+    targeting_query: Query<&TargetingMode>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        // Consume the mouse click to prevent tower placement
+        mouse_input_state.left_clicked = false;
+
+        match button {
+            GroupActionButton::UpgradeAll => {
+                multi_selection.sell_confirm_pending = false;
+                operations.write(GroupOperation::UpgradeAll);
+            }
+            GroupActionButton::SellAll => {
+                let confirm = settings.as_ref().is_none_or(|settings| settings.confirm_before_sell);
+                if multi_selection.sell_confirm_pending || !confirm {
+                    operations.write(GroupOperation::SellAll);
+                } else {
+                    multi_selection.sell_confirm_pending = true;
+                }
+            }
+            GroupActionButton::CycleTargeting => {
+                multi_selection.sell_confirm_pending = false;
+                // A group on mixed modes starts the cycle over
+                let shared = TargetingMode::shared(targeting_query.iter_many(multi_selection.sorted()).copied());
+                operations.write(GroupOperation::SetTargeting(shared.map_or_else(TargetingMode::default, TargetingMode::next)));
+            }
+        }
+    }
+}
+
+/// System to add or remove towers with shift-click. A plain click or a
+/// right-click drops the multi-selection so single selection takes over.
+pub fn multi_select_click_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut mouse_input_state: ResMut<MouseInputState>,
+    mut selection_state: ResMut<TowerSelectionState>,
+    mut multi_selection: ResMut<TowerMultiSelection>,
+    towers_query: Query<(Entity, &Transform), With<TowerStats>>,
+    constants: Res<GameConstants>,
+) {
+    if mouse_input_state.right_clicked && multi_selection.is_active() {
+        multi_selection.clear();
+        return;
+    }
+
+    if !mouse_input_state.left_clicked {
+        return;
+    }
+
+    if !shift_held(&keyboard_input) {
+        if multi_selection.is_active() {
+            multi_selection.clear();
+        }
+        return;
+    }
+
+    let click_pos = mouse_input_state.world_position;
+    let clicked_tower = towers_query
+        .iter()
+        .map(|(entity, transform)| (entity, transform.translation.truncate().distance(click_pos)))
+        .filter(|(_, distance)| *distance < constants.tower_click_radius)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _)| entity);
+
+    if let Some(tower_entity) = clicked_tower {
+        // Fold an existing single selection into the group
+        if let Some(selected) = selection_state.selected_tower_entity {
+            if selected != tower_entity {
+                multi_selection.towers.insert(selected);
+            }
+            selection_state.clear_selection();
+        }
+        multi_selection.toggle(tower_entity);
+        mouse_input_state.left_clicked = false;
+        println!("Multi-selection: {} tower(s)", multi_selection.towers.len());
+    }
+}
+
+/// System to select every tower inside a dragged rectangle. Holding shift adds
+/// to the current selection instead of replacing it.
+pub fn band_select_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_input_state: Res<MouseInputState>,
+    mut selection_state: ResMut<TowerSelectionState>,
+    mut multi_selection: ResMut<TowerMultiSelection>,
+    towers_query: Query<(Entity, &Transform), With<TowerStats>>,
+) {
+    // Dragging with a tower type selected is tower placement, not selection
+    if !mouse_input_state.left_released || selection_state.selected_placement_type.is_some() {
+        return;
+    }
+    let Some(band) = mouse_input_state.drag_rect() else {
+        return;
+    };
+    if band.size().max_element() < MIN_BAND_DRAG {
+        return;
+    }
+
+    let banded: Vec<Entity> = towers_query
+        .iter()
+        .filter(|(_, transform)| band.contains(transform.translation.truncate()))
+        .map(|(entity, _)| entity)
+        .collect();
+
+    if !shift_held(&keyboard_input) {
+        multi_selection.clear();
+    }
+    multi_selection.towers.extend(banded);
+    multi_selection.sell_confirm_pending = false;
+
+    if multi_selection.is_active() {
+        selection_state.clear_selection();
+        println!("Band-selected {} tower(s)", multi_selection.towers.len());
+    }
+}
+
+// ============================================================================
+// GROUP OPERATIONS
+// ============================================================================
+
+/// System to apply group operations to every selected tower
+pub fn group_operation_system(
+    mut commands: Commands,
+    mut operations: EventReader<GroupOperation>,
+    mut economy: ResMut<Economy>,
+    mut multi_selection: ResMut<TowerMultiSelection>,
+    mut selection_state: ResMut<TowerSelectionState>,
+    mut occupancy: ResMut<OccupancyMap>,
+    mut towers: Query<(&mut TowerStats, &mut TargetingMode, Option<&Constructing>, Option<&PlacementGrace>)>,
+) {
+    for operation in operations.read() {
+        match *operation {
+            GroupOperation::UpgradeAll => {
+                // Cheapest upgrades first so the budget covers as many towers as possible
+                let mut candidates: Vec<(Entity, ResourceCost)> = multi_selection
+                    .sorted()
+                    .into_iter()
+                    .filter_map(|entity| {
+                        let (stats, _, constructing, _) = towers.get(entity).ok()?;
+                        (constructing.is_none() && stats.can_upgrade())
+                            .then(|| (entity, stats.get_upgrade_cost()))
+                    })
+                    .collect();
+                candidates.sort_by_key(|(_, cost)| cost.money);
+
+                let mut upgraded = 0;
+                for (entity, _) in candidates {
+                    if let Ok((mut stats, _, constructing, _)) = towers.get_mut(entity) {
+                        if try_upgrade_tower(&mut stats, constructing.is_some(), &mut economy).is_ok() {
+                            upgraded += 1;
+                        }
+                    }
+                }
+                println!("Group upgrade: upgraded {} of {} tower(s)", upgraded, multi_selection.towers.len());
+            }
+            GroupOperation::SellAll => {
+                let mut total_refund = ResourceCost::zero();
+                for entity in multi_selection.sorted() {
+                    let Ok((stats, _, constructing, grace)) = towers.get(entity) else {
+                        continue;
+                    };
+                    // Unfinished and freshly built towers are refunded in full
+                    let refund = sell_refund(stats, constructing, grace);
+                    total_refund += &refund;
+                    commands.entity(entity).despawn();
+                    occupancy.vacate(entity);
+
+                    if selection_state.selected_tower_entity == Some(entity) {
+                        selection_state.clear_selection();
+                    }
+                }
+                economy.refund(&total_refund);
+                println!("Sold {} tower(s), refunded {:?}", multi_selection.towers.len(), total_refund);
+                multi_selection.clear();
+            }
+            GroupOperation::SetTargeting(mode) => {
+                for entity in multi_selection.sorted() {
+                    if let Ok((_, mut targeting_mode, _, _)) = towers.get_mut(entity) {
+                        *targeting_mode = mode;
+                    }
+                }
+                println!("Group targeting set to {}", mode.get_name());
+            }
+        }
+    }
+}
+
+// ============================================================================
+// VISUALS
+// ============================================================================
+
+/// System to draw the band rectangle while dragging
+pub fn selection_band_visual_system(
+    mut commands: Commands,
+    mouse_input_state: Res<MouseInputState>,
+    selection_state: Res<TowerSelectionState>,
+    focus_drawing: Option<Res<FocusZoneDrawing>>,
+    band_query: Query<Entity, With<SelectionBand>>,
+) {
+    for entity in band_query.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    // A drag while drawing a focus zone draws the zone instead
+    let drawing_focus = focus_drawing.is_some_and(|drawing| drawing.is_active());
+    if !mouse_input_state.left_held || selection_state.selected_placement_type.is_some() || drawing_focus {
+        return;
+    }
+    let Some(band) = mouse_input_state.drag_rect() else {
+        return;
+    };
+    if band.size().max_element() < MIN_BAND_DRAG {
+        return;
+    }
+
+    commands.spawn((
+        Sprite {
+            color: Color::srgba(0.3, 0.8, 1.0, 0.15),
+            custom_size: Some(band.size()),
+            ..default()
+        },
+        Transform::from_translation(band.center().extend(5.0)),
+        SelectionBand,
+    ));
+}
+
+/// System to mark selected towers and drop towers that no longer exist
+pub fn multi_select_indicator_system(
+    mut commands: Commands,
+    mut multi_selection: ResMut<TowerMultiSelection>,
+    indicator_query: Query<Entity, With<MultiSelectIndicator>>,
+    towers_query: Query<&Transform, With<TowerStats>>,
+    constants: Res<GameConstants>,
+) {
+    for entity in indicator_query.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    if multi_selection.towers.iter().any(|entity| !towers_query.contains(*entity)) {
+        multi_selection.towers.retain(|entity| towers_query.contains(*entity));
+    }
+
+    for entity in multi_selection.towers.iter() {
+        if let Ok(tower_transform) = towers_query.get(*entity) {
+            commands.spawn((
+                Sprite {
+                    color: Color::srgb(0.3, 0.8, 1.0), // Cyan group selection ring
+                    custom_size: Some(Vec2::splat(constants.group_selection_ring_size)),
+                    ..default()
+                },
+                Transform::from_translation(tower_transform.translation + Vec3::new(0.0, 0.0, -0.5)),
+                MultiSelectIndicator,
+            ));
+        }
+    }
+}
+
+/// System to show the group panel and keep its labels current
+pub fn group_panel_system(
+    multi_selection: Res<TowerMultiSelection>,
+    economy: Res<Economy>,
+    formatter: Res<NumberFormatter>,
+    towers: Query<(&TowerStats, Option<&Constructing>, Option<&PlacementGrace>)>,
+    targeting_query: Query<&TargetingMode>,
+    mut panel_query: Query<&mut Node, With<GroupActionPanel>>,
+    mut summary_query: Query<&mut Text, (With<GroupSummaryText>, Without<GroupActionButtonText>)>,
+    mut button_text_query: Query<(&mut Text, &GroupActionButtonText), Without<GroupSummaryText>>,
+) {
+    if let Ok(mut panel_node) = panel_query.single_mut() {
+        let display = if multi_selection.is_active() { Display::Flex } else { Display::None };
+        if panel_node.display != display {
+            panel_node.display = display;
+        }
+    }
+    if !multi_selection.is_active() {
+        return;
+    }
+
+    let mut sell_total = ResourceCost::zero();
+    let mut upgrade_total = ResourceCost::zero();
+    for (stats, constructing, grace) in multi_selection.towers.iter().filter_map(|entity| towers.get(*entity).ok()) {
+        sell_total += &sell_refund(stats, constructing, grace);
+        if constructing.is_none() && stats.can_upgrade() {
+            upgrade_total += &stats.get_upgrade_cost();
+        }
+    }
+
+    if let Ok(mut text) = summary_query.single_mut() {
+        **text = format!(
+            "{} towers selected\nUpgrade all: {} (have {})\nSell value: {}",
+            multi_selection.towers.len(),
+            formatter.money(upgrade_total.money),
+            formatter.money(economy.money),
+            formatter.money(sell_total.money)
+        );
+    }
+
+    for (mut text, button) in button_text_query.iter_mut() {
+        let label = match button.0 {
+            GroupActionButton::UpgradeAll => "UPGRADE ALL".to_string(),
+            GroupActionButton::SellAll if multi_selection.sell_confirm_pending => {
+                format!("CONFIRM SELL (+{})", formatter.money(sell_total.money))
+            }
+            GroupActionButton::SellAll => "SELL ALL".to_string(),
+            GroupActionButton::CycleTargeting => {
+                match TargetingMode::shared(targeting_query.iter_many(multi_selection.sorted()).copied()) {
+                    Some(mode) => format!("TARGET: {}", mode.get_name()),
+                    None => "TARGET: Mixed".to_string(),
+                }
+            }
+        };
+        if **text != label {
+            **text = label;
+        }
+    }
+}
+
+// ============================================================================
+// SETUP
+// ============================================================================
+
+/// Spawn the (hidden) group action panel
+pub fn setup_group_action_panel(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                right: Val::Px(240.0), // Same spot as the single-tower upgrade panel
+                top: Val::Px(20.0),
+                width: Val::Px(250.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(10.0)),
+                row_gap: Val::Px(6.0),
+                display: Display::None,
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.1, 0.2, 0.3, 0.9)),
+            GroupActionPanel,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Group Actions"),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.9, 0.9)),
+                GroupSummaryText,
+            ));
+
+            let buttons = [
+                (GroupActionButton::UpgradeAll, Color::srgb(0.4, 0.7, 0.4)),
+                (GroupActionButton::SellAll, Color::srgb(0.75, 0.4, 0.35)),
+                (GroupActionButton::CycleTargeting, Color::srgb(0.35, 0.5, 0.75)),
+            ];
+            for (button, color) in buttons {
+                parent
+                    .spawn((
+                        Button,
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Px(34.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(color),
+                        button,
+                    ))
+                    .with_children(|button_parent| {
+                        button_parent.spawn((
+                            Text::new(""),
+                            TextFont {
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                            GroupActionButtonText(button),
+                        ));
+                    });
+            }
+        });
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin to add multi-tower selection and group operations
+pub struct MultiSelectPlugin;
+
+impl Plugin for MultiSelectPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<TowerMultiSelection>()
+            .add_event::<GroupOperation>()
+            .register_key_hint(KeyCode::ShiftLeft, "Hold and click or drag to select several towers", InputContext::Game)
+            .add_systems(Startup, setup_group_action_panel)
+            .add_systems(
+                Update,
+                (
+                    group_action_button_system,
+                    multi_select_click_system,
+                    band_select_system,
+                )
+                    .chain()
+                    .in_set(GameSystemSet::UI)
+                    .before(tower_selection_system) // Shift-clicks never reach single selection
+                    .run_if(in_state(AppState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (
+                    selection_band_visual_system,
+                    multi_select_indicator_system,
+                    group_panel_system,
+                )
+                    .chain()
+                    .in_set(GameSystemSet::UI)
+                    .after(tower_selection_system),
+            )
+            .add_systems(
+                Update,
+                group_operation_system
+                    .in_set(GameSystemSet::Gameplay)
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
@@ -1,22 +1,28 @@
 use bevy::prelude::*;
 use crate::systems::path_generation::{
-    obstacles::{Obstacle, ObstacleType, create_obstacle_entities},
-    PathGrid,
+    obstacles::{Obstacle, ObstacleType, MapAnalysis, analyze_map, create_obstacle_entities},
+    current_level_archetype, current_level_seed, generate_level_grid,
+    LevelConfig, PathGrid,
 };
-use crate::resources::{EnemyPath, WaveManager};
+use crate::resources::{EnemyPath, WavePlan};
 
 /// Resource to store the current obstacle grid for rendering
 #[derive(Resource, Clone)]
 pub struct ObstacleGrid {
     pub grid: PathGrid,
     pub wave_number: u32,
+    /// Archetype, coverage and build-area breakdown of the current map
+    pub analysis: MapAnalysis,
 }
 
 impl Default for ObstacleGrid {
     fn default() -> Self {
+        let grid = PathGrid::new_unified();
+        let analysis = analyze_map(&grid, current_level_archetype());
         Self {
-            grid: PathGrid::new_unified(),
+            grid,
             wave_number: 0,
+            analysis,
         }
     }
 }
@@ -29,23 +35,34 @@ pub struct ObstacleEntity;
 pub fn setup_initial_obstacles(
     mut commands: Commands,
     mut obstacle_grid: ResMut<ObstacleGrid>,
-    wave_manager: Res<WaveManager>,
+    level_config: Res<LevelConfig>,
+    wave_plan: Res<WavePlan>,
 ) {
-    // Generate initial obstacle grid for wave 1
-    let seed = 1u64 * 12345 + 67890;
-    let difficulty = (1.0_f32 / 20.0).min(1.0);
+    spawn_level_obstacles(&mut commands, &mut obstacle_grid, &level_config);
+}
+
+/// Generate the wave 1 obstacle grid for the current seed, map archetype and
+/// `config`, store it and spawn its obstacle entities. Uses the same grid as
+/// `generate_level_path_with_config`, so rendered obstacles always match the enemy route.
+pub fn spawn_level_obstacles(commands: &mut Commands, obstacle_grid: &mut ObstacleGrid, config: &LevelConfig) {
+    let grid = generate_level_grid(1, config);
+    let analysis = analyze_map(&grid, current_level_archetype());
+    
+    // Spawn obstacle entities
+    create_obstacle_entities(commands, &grid, current_level_seed().wrapping_add(5000));
     
-    // Generate procedural map with obstacles
-    let grid = crate::systems::path_generation::obstacles::generate_procedural_map(seed, difficulty);
+    info!(
+        "Initialized {} map for wave 1 with {} obstacles ({:.0}% blocked, {} build areas)",
+        analysis.archetype.get_name(),
+        count_obstacles(&grid),
+        analysis.obstacle_coverage * 100.0,
+        analysis.buildable_regions,
+    );
     
     // Store the grid
-    obstacle_grid.grid = grid.clone();
+    obstacle_grid.grid = grid;
     obstacle_grid.wave_number = 1;
-    
-    // Spawn obstacle entities
-    create_obstacle_entities(&mut commands, &grid, seed + 5000);
-    
-    info!("Initialized obstacles for wave 1 with {} obstacles", count_obstacles(&grid));
+    obstacle_grid.analysis = analysis;
 }
 
 /// System to update obstacles when wave changes
@@ -54,7 +71,7 @@ pub fn setup_initial_obstacles(
 pub fn update_obstacles_on_wave_change(
     _commands: Commands,
     _obstacle_grid: ResMut<ObstacleGrid>,
-    _wave_manager: Res<WaveManager>,
+    _wave_plan: Res<WavePlan>,
     _existing_obstacles: Query<Entity, With<Obstacle>>,
 ) {
     // This system has been disabled because obstacles should persist across all waves
@@ -108,6 +125,7 @@ pub fn debug_obstacle_info_system(
             ObstacleType::Building => Color::srgba(0.6, 0.6, 0.7, 0.3),
             ObstacleType::Debris => Color::srgba(0.5, 0.4, 0.3, 0.3),
             ObstacleType::Crystal => Color::srgba(0.3, 0.5, 0.8, 0.3),
+            ObstacleType::Void => Color::srgba(0.05, 0.1, 0.25, 0.3),
         };
         
         // Draw a subtle outline around obstacles
@@ -165,6 +183,7 @@ impl Plugin for ObstacleRenderingPlugin {
     fn build(&self, app: &mut App) {
         app
             .init_resource::<ObstacleGrid>()
+            .init_resource::<LevelConfig>()
             .add_systems(Startup, setup_initial_obstacles)
             .add_systems(Update, (
                 update_obstacles_on_wave_change,
@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+use bevy::prelude::*;
+use crate::resources::TowerStats;
+use crate::systems::path_generation::grid::GridPos;
+use crate::systems::path_generation::obstacles::Obstacle;
+use crate::systems::unified_grid::{world_to_grid, UnifiedGridSystem};
+
+/// What holds a grid cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Occupant {
+    Tower(Entity),
+    Obstacle(Entity),
+}
+
+impl Occupant {
+    pub fn entity(&self) -> Entity {
+        match self {
+            Occupant::Tower(entity) | Occupant::Obstacle(entity) => *entity,
+        }
+    }
+}
+
+/// Resource recording which entity holds each grid cell. Kept up to date by
+/// observers as towers and obstacles are spawned and despawned, and by
+/// `track_moved_towers_system` as towers change cell. Selling frees a cell
+/// right away, ahead of the despawn.
+#[derive(Resource, Debug, Default)]
+pub struct OccupancyMap {
+    cells: HashMap<GridPos, Occupant>,
+    /// Cell of every recorded entity, including ones sharing a cell with a
+    /// later arrival (as stress test towers may); they take the cell back once
+    /// it is vacated
+    by_entity: HashMap<Entity, (GridPos, Occupant)>,
+}
+
+impl OccupancyMap {
+    /// Put `occupant` on `cell`, returning whatever held it before
+    pub fn occupy(&mut self, cell: GridPos, occupant: Occupant) -> Option<Occupant> {
+        self.vacate(occupant.entity());
+        self.by_entity.insert(occupant.entity(), (cell, occupant));
+        self.cells.insert(cell, occupant)
+    }
+
+    /// Forget `entity`, freeing its cell unless another entity shares it.
+    /// Returns the cell it was on.
+    pub fn vacate(&mut self, entity: Entity) -> Option<GridPos> {
+        let (cell, occupant) = self.by_entity.remove(&entity)?;
+        if self.cells.get(&cell) == Some(&occupant) {
+            match self.by_entity.values().find(|(other_cell, _)| *other_cell == cell) {
+                Some((_, sharing)) => self.cells.insert(cell, *sharing),
+                None => self.cells.remove(&cell),
+            };
+        }
+        Some(cell)
+    }
+
+    pub fn get(&self, cell: GridPos) -> Option<Occupant> {
+        self.cells.get(&cell).copied()
+    }
+
+    pub fn is_occupied(&self, cell: GridPos) -> bool {
+        self.cells.contains_key(&cell)
+    }
+
+    pub fn cell_of(&self, entity: Entity) -> Option<GridPos> {
+        self.by_entity.get(&entity).map(|(cell, _)| *cell)
+    }
+
+    /// Tower standing on `cell`, if any
+    pub fn tower_at(&self, cell: GridPos) -> Option<Entity> {
+        match self.get(cell) {
+            Some(Occupant::Tower(entity)) => Some(entity),
+            _ => None,
+        }
+    }
+
+    /// Every occupied cell and what holds it
+    pub fn iter(&self) -> impl Iterator<Item = (GridPos, Occupant)> + '_ {
+        self.cells.iter().map(|(cell, occupant)| (*cell, *occupant))
+    }
+
+    /// Number of occupied cells
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+}
+
+/// Problems found comparing the map against the towers and obstacles in the world
+pub fn occupancy_mismatches(
+    occupancy: &OccupancyMap,
+    towers: impl IntoIterator<Item = (Entity, GridPos)>,
+    obstacles: impl IntoIterator<Item = (Entity, GridPos)>,
+) -> Vec<String> {
+    let mut expected = HashMap::new();
+    let mut mismatches = Vec::new();
+    let occupants = towers
+        .into_iter()
+        .map(|(entity, cell)| (Occupant::Tower(entity), cell))
+        .chain(obstacles.into_iter().map(|(entity, cell)| (Occupant::Obstacle(entity), cell)));
+    for (occupant, cell) in occupants {
+        expected.insert(occupant.entity(), occupant);
+        let recorded = occupancy.cell_of(occupant.entity());
+        if recorded != Some(cell) {
+            mismatches.push(format!("{:?} is on cell {:?} but recorded at {:?}", occupant, cell, recorded));
+        }
+    }
+    for (cell, occupant) in occupancy.iter() {
+        if expected.get(&occupant.entity()) != Some(&occupant) {
+            mismatches.push(format!("cell {:?} records {:?}, which is gone or changed kind", cell, occupant));
+        }
+    }
+    mismatches
+}
+
+// ============================================================================
+// OBSERVERS
+// ============================================================================
+
+/// Observer recording a tower's cell as soon as it is spawned
+pub fn record_tower_observer(
+    trigger: Trigger<OnAdd, TowerStats>,
+    towers: Query<&Transform>,
+    unified_grid: Option<Res<UnifiedGridSystem>>,
+    mut occupancy: ResMut<OccupancyMap>,
+) {
+    let tower = trigger.target();
+    let (Ok(transform), Some(unified_grid)) = (towers.get(tower), unified_grid) else {
+        return;
+    };
+    if let Some(cell) = world_to_grid(transform.translation.truncate(), &unified_grid) {
+        occupancy.occupy(cell, Occupant::Tower(tower));
+    }
+}
+
+/// Observer recording an obstacle's cell as soon as it is spawned
+pub fn record_obstacle_observer(
+    trigger: Trigger<OnAdd, Obstacle>,
+    obstacles: Query<&Obstacle>,
+    mut occupancy: ResMut<OccupancyMap>,
+) {
+    let obstacle = trigger.target();
+    if let Ok(data) = obstacles.get(obstacle) {
+        occupancy.occupy(data.position, Occupant::Obstacle(obstacle));
+    }
+}
+
+/// Observer freeing the cell of a tower or obstacle as it is sold, cleared or despawned
+pub fn vacate_observer<C: Component>(trigger: Trigger<OnRemove, C>, mut occupancy: ResMut<OccupancyMap>) {
+    occupancy.vacate(trigger.target());
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// System moving a tower's record along when its transform takes it to another cell
+pub fn track_moved_towers_system(
+    unified_grid: Option<Res<UnifiedGridSystem>>,
+    mut occupancy: ResMut<OccupancyMap>,
+    towers: Query<(Entity, &Transform), (With<TowerStats>, Changed<Transform>)>,
+) {
+    let Some(unified_grid) = unified_grid else {
+        return;
+    };
+    for (tower, transform) in towers.iter() {
+        let cell = world_to_grid(transform.translation.truncate(), &unified_grid);
+        if cell != occupancy.cell_of(tower) {
+            match cell {
+                Some(cell) => {
+                    occupancy.occupy(cell, Occupant::Tower(tower));
+                }
+                None => {
+                    occupancy.vacate(tower);
+                }
+            }
+        }
+    }
+}
+
+/// System warning in debug builds when the map drifts from the towers and obstacles on the board
+#[cfg(debug_assertions)]
+pub fn occupancy_consistency_system(
+    occupancy: Res<OccupancyMap>,
+    unified_grid: Option<Res<UnifiedGridSystem>>,
+    towers: Query<(Entity, &Transform), With<TowerStats>>,
+    obstacles: Query<(Entity, &Obstacle)>,
+) {
+    let Some(unified_grid) = unified_grid else {
+        return;
+    };
+    let mismatches = occupancy_mismatches(
+        &occupancy,
+        towers.iter().filter_map(|(entity, transform)| {
+            world_to_grid(transform.translation.truncate(), &unified_grid).map(|cell| (entity, cell))
+        }),
+        obstacles.iter().map(|(entity, obstacle)| (entity, obstacle.position)),
+    );
+    if !mismatches.is_empty() {
+        warn!("Occupancy map out of sync: {}", mismatches.join("; "));
+    }
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin keeping the grid occupancy map in step with towers and obstacles
+pub struct OccupancyPlugin;
+
+impl Plugin for OccupancyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<OccupancyMap>()
+            .add_observer(record_tower_observer)
+            .add_observer(record_obstacle_observer)
+            .add_observer(vacate_observer::<TowerStats>)
+            .add_observer(vacate_observer::<Obstacle>)
+            .add_systems(PostUpdate, track_moved_towers_system);
+
+        #[cfg(debug_assertions)]
+        app.add_systems(Last, occupancy_consistency_system);
+    }
+}
@@ -0,0 +1,270 @@
+use bevy::prelude::*;
+use crate::components::{Constructing, Heat};
+use crate::resources::{AppState, CombatSet, GameSystemSet, SimulationClock, TowerStats};
+use crate::systems::input::{is_playing, InputHandler, InputRegistryAppExt};
+use crate::systems::input_system::MouseInputState;
+use crate::systems::tower_ui::{OverclockButton, OverclockButtonText, TowerSelectionState};
+
+/// Key toggling overclock on the selected tower
+pub const OVERCLOCK_KEY: KeyCode = KeyCode::KeyO;
+
+/// Width of the heat bar drawn under a tower
+const HEAT_BAR_WIDTH: f32 = 30.0;
+/// Height of the heat bar
+const HEAT_BAR_HEIGHT: f32 = 4.0;
+/// Offset of the heat bar from the tower centre (below the tower, clear of the build bar)
+const HEAT_BAR_OFFSET: Vec2 = Vec2::new(0.0, -26.0);
+
+/// Background sprite of a tower's heat bar
+#[derive(Component)]
+pub struct HeatBar {
+    pub parent_tower: Entity,
+}
+
+/// Fill sprite of a tower's heat bar
+#[derive(Component)]
+pub struct HeatBarFill {
+    pub parent_tower: Entity,
+}
+
+/// Color of the heat bar fill, shading from amber to red as heat builds
+fn heat_color(heat: &Heat) -> Color {
+    if heat.is_shut_down() {
+        return Color::srgb(0.5, 0.5, 0.5);
+    }
+    let t = heat.fraction();
+    Color::srgb(1.0, 0.75 * (1.0 - t), 0.1)
+}
+
+/// System to build and dissipate tower heat, shutting down overheated towers
+pub fn heat_system(
+    clock: Res<SimulationClock>,
+    mut towers: Query<(Entity, &mut Heat)>,
+) {
+    for (tower_entity, mut heat) in towers.iter_mut() {
+        if heat.tick(clock.delta_secs()) {
+            println!("Tower {:?} overheated and shut down", tower_entity);
+        }
+    }
+}
+
+/// System to spawn a heat bar for every newly built tower
+pub fn spawn_heat_bars_system(
+    mut commands: Commands,
+    towers: Query<(Entity, &Transform), Added<Heat>>,
+) {
+    for (tower_entity, transform) in towers.iter() {
+        let bar_position = transform.translation.truncate() + HEAT_BAR_OFFSET;
+
+        commands.spawn((
+            Sprite {
+                color: Color::srgb(0.15, 0.15, 0.15),
+                custom_size: Some(Vec2::new(HEAT_BAR_WIDTH, HEAT_BAR_HEIGHT)),
+                ..default()
+            },
+            Transform::from_translation(bar_position.extend(0.5)),
+            Visibility::Hidden,
+            HeatBar { parent_tower: tower_entity },
+        ));
+
+        // Fill grows from the left edge of the bar
+        commands.spawn((
+            Sprite {
+                color: Color::srgb(1.0, 0.75, 0.1),
+                custom_size: Some(Vec2::new(0.0, HEAT_BAR_HEIGHT)),
+                ..default()
+            },
+            Transform::from_translation(
+                (bar_position - Vec2::new(HEAT_BAR_WIDTH / 2.0, 0.0)).extend(0.6)
+            ),
+            Visibility::Hidden,
+            HeatBar { parent_tower: tower_entity },
+            HeatBarFill { parent_tower: tower_entity },
+        ));
+    }
+}
+
+/// System to update heat bars and remove bars whose tower is gone
+pub fn heat_bar_system(
+    mut commands: Commands,
+    towers: Query<&Heat, Without<Constructing>>,
+    tower_exists: Query<(), With<Heat>>,
+    mut bars: Query<(Entity, &HeatBar, &mut Visibility)>,
+    mut fills: Query<(&HeatBarFill, &mut Sprite, &mut Transform)>,
+) {
+    for (fill, mut sprite, mut transform) in fills.iter_mut() {
+        if let Ok(heat) = towers.get(fill.parent_tower) {
+            let previous_width = sprite.custom_size.map_or(0.0, |size| size.x);
+            let width = HEAT_BAR_WIDTH * heat.fraction();
+            sprite.custom_size = Some(Vec2::new(width, HEAT_BAR_HEIGHT));
+            sprite.color = heat_color(heat);
+            transform.translation.x += (width - previous_width) / 2.0;
+        }
+    }
+
+    // Bars only show while a finished tower is overclocked or still warm
+    for (bar_entity, bar, mut visibility) in bars.iter_mut() {
+        if !tower_exists.contains(bar.parent_tower) {
+            commands.entity(bar_entity).despawn();
+            continue;
+        }
+        let visible = towers
+            .get(bar.parent_tower)
+            .is_ok_and(|heat| heat.overclocked || heat.current > 0.0);
+        visibility.set_if_neq(if visible { Visibility::Inherited } else { Visibility::Hidden });
+    }
+}
+
+/// Toggle overclock on a tower, refusing while it is built or cooling down
+fn toggle_tower_overclock(tower_entity: Entity, heat: &mut Heat, under_construction: bool) {
+    if under_construction {
+        println!("Cannot overclock a tower that is still under construction");
+    } else if heat.toggle_overclock() {
+        println!("Tower {:?} overclock {}", tower_entity, if heat.overclocked { "ON" } else { "OFF" });
+    } else {
+        println!("Tower {:?} is overheated and cannot be overclocked yet", tower_entity);
+    }
+}
+
+/// Input handler toggling overclock on the selected tower
+pub struct OverclockHandler;
+
+impl InputHandler for OverclockHandler {
+    fn handle_input(&self, world: &mut World, key: KeyCode) -> bool {
+        if key != OVERCLOCK_KEY || !is_playing(world) {
+            return false;
+        }
+        let Some(tower_entity) = world
+            .get_resource::<TowerSelectionState>()
+            .and_then(|selection| selection.selected_tower_entity)
+        else {
+            return false;
+        };
+        let Ok(mut tower) = world.get_entity_mut(tower_entity) else {
+            return false;
+        };
+        if !tower.contains::<TowerStats>() {
+            return false;
+        }
+        let under_construction = tower.contains::<Constructing>();
+        let Some(mut heat) = tower.get_mut::<Heat>() else {
+            return false;
+        };
+        toggle_tower_overclock(tower_entity, &mut heat, under_construction);
+        true
+    }
+
+    fn get_description(&self) -> &str {
+        "Overclock the selected tower"
+    }
+
+    fn get_priority(&self) -> u8 {
+        20
+    }
+
+    fn get_id(&self) -> &str {
+        "overclock"
+    }
+
+    fn handles_key(&self, key: KeyCode) -> bool {
+        key == OVERCLOCK_KEY
+    }
+
+    fn get_handled_keys(&self) -> Vec<KeyCode> {
+        vec![OVERCLOCK_KEY]
+    }
+}
+
+/// System to toggle overclock on the selected tower via the upgrade panel button.
+/// The key goes through `OverclockHandler`.
+pub fn overclock_toggle_system(
+    selection_state: Res<TowerSelectionState>,
+    mut mouse_input_state: ResMut<MouseInputState>,
+    button_query: Query<&Interaction, (Changed<Interaction>, With<OverclockButton>)>,
+    mut towers: Query<(&mut Heat, Has<Constructing>), With<TowerStats>>,
+) {
+    let mut toggle_requested = false;
+    for interaction in button_query.iter() {
+        if *interaction == Interaction::Pressed {
+            // Consume the mouse click to prevent tower placement
+            mouse_input_state.left_clicked = false;
+            toggle_requested = true;
+        }
+    }
+
+    if !toggle_requested {
+        return;
+    }
+
+    let Some(tower_entity) = selection_state.selected_tower_entity else {
+        return;
+    };
+    let Ok((mut heat, under_construction)) = towers.get_mut(tower_entity) else {
+        return;
+    };
+
+    toggle_tower_overclock(tower_entity, &mut heat, under_construction);
+}
+
+/// System to show the selected tower's overclock state on the toggle button
+pub fn overclock_button_system(
+    selection_state: Res<TowerSelectionState>,
+    towers: Query<&Heat>,
+    mut text_query: Query<&mut Text, With<OverclockButtonText>>,
+    mut button_query: Query<&mut BackgroundColor, With<OverclockButton>>,
+) {
+    let heat = selection_state
+        .selected_tower_entity
+        .and_then(|tower_entity| towers.get(tower_entity).ok());
+
+    let (label, color) = match heat {
+        Some(heat) if heat.is_shut_down() => {
+            let remaining = heat
+                .shutdown_timer
+                .as_ref()
+                .map_or(0.0, |timer| timer.remaining_secs());
+            (format!("OVERHEATED ({:.1}s)", remaining), Color::srgb(0.4, 0.4, 0.4))
+        }
+        Some(heat) if heat.overclocked => (
+            format!("OVERCLOCK ON - Heat {:.0}%", heat.fraction() * 100.0),
+            Color::srgb(0.9, 0.45, 0.2),
+        ),
+        _ => ("OVERCLOCK (O)".to_string(), Color::srgb(0.5, 0.4, 0.3)),
+    };
+
+    if let Ok(mut text) = text_query.single_mut() {
+        if **text != label {
+            **text = label;
+        }
+    }
+    if let Ok(mut background) = button_query.single_mut() {
+        background.0 = color;
+    }
+}
+
+/// Plugin to add tower overclocking and heat management
+pub struct OverclockPlugin;
+
+impl Plugin for OverclockPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .register_input_handler(OverclockHandler)
+            .add_systems(
+                Update,
+                (
+                    overclock_toggle_system.run_if(in_state(AppState::Playing)),
+                    overclock_button_system,
+                )
+                    .chain()
+                    .in_set(GameSystemSet::UI),
+            )
+            .add_systems(
+                Update,
+                (heat_system, spawn_heat_bars_system, heat_bar_system)
+                    .chain()
+                    .in_set(GameSystemSet::Gameplay)
+                    .before(CombatSet::Firing) // Overheated towers stop firing this frame
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
@@ -0,0 +1,198 @@
+use std::f32::consts::TAU;
+use bevy::prelude::*;
+use crate::components::{CosmeticEffect, EffectCategory};
+use crate::resources::*;
+use crate::systems::tween::blend_colors;
+
+/// Share of its velocity a particle keeps each second, so bursts slow as they spread
+pub const PARTICLE_DRAG: f32 = 0.1;
+/// Above enemies and projectiles, below floating text
+const PARTICLE_Z: f32 = 3.0;
+
+/// Component for a one-shot particle emitter. It bursts on the first frame it
+/// exists and is despawned straight after. Every particle counts against the
+/// effect budget's particle cap, so a burst is cut short when the cap is reached.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ParticleEmitter {
+    /// Particles in the burst
+    pub burst_count: u32,
+    /// Seconds each particle lives
+    pub lifetime: f32,
+    /// Direction the burst is aimed, ignored for bursts spread all the way round
+    pub direction: Vec2,
+    /// Angle the burst fans out across, in radians; `TAU` for all round
+    pub angle_spread: f32,
+    /// Speed of the slowest particle, in pixels per second
+    pub min_speed: f32,
+    /// Speed of the fastest particle, in pixels per second
+    pub max_speed: f32,
+    /// Particle size in pixels
+    pub size: f32,
+    /// Colour particles start at
+    pub start_color: Color,
+    /// Colour particles blend into as they die, usually transparent
+    pub end_color: Color,
+}
+
+impl ParticleEmitter {
+    /// Sparks thrown off where a projectile strikes
+    pub fn impact(color: Color) -> Self {
+        Self {
+            burst_count: 6,
+            lifetime: 0.25,
+            direction: Vec2::Y,
+            angle_spread: TAU,
+            min_speed: 40.0,
+            max_speed: 110.0,
+            size: 2.5,
+            start_color: color,
+            end_color: color.with_alpha(0.0),
+        }
+    }
+
+    /// Fire and smoke from a missile's blast, thrown out to about its radius
+    pub fn explosion(radius: f32) -> Self {
+        Self {
+            burst_count: 18,
+            lifetime: 0.45,
+            direction: Vec2::Y,
+            angle_spread: TAU,
+            min_speed: radius,
+            max_speed: radius * 3.0,
+            size: 4.0,
+            start_color: Color::srgb(1.0, 0.75, 0.2),
+            end_color: Color::srgba(0.3, 0.3, 0.3, 0.0),
+        }
+    }
+
+    /// Dust kicked up around a tower as its construction starts
+    pub fn dust() -> Self {
+        Self {
+            burst_count: 10,
+            lifetime: 0.6,
+            direction: Vec2::Y,
+            angle_spread: TAU,
+            min_speed: 20.0,
+            max_speed: 60.0,
+            size: 4.0,
+            start_color: Color::srgba(0.65, 0.58, 0.45, 0.8),
+            end_color: Color::srgba(0.65, 0.58, 0.45, 0.0),
+        }
+    }
+
+    /// Fragments of a killed enemy in its own colour
+    pub fn death(color: Color) -> Self {
+        Self {
+            burst_count: 8,
+            lifetime: 0.4,
+            direction: Vec2::Y,
+            angle_spread: TAU,
+            min_speed: 30.0,
+            max_speed: 90.0,
+            size: 3.0,
+            start_color: color,
+            end_color: color.with_alpha(0.0),
+        }
+    }
+
+    /// Velocity of a particle, from two rolls between 0.0 and 1.0 picking its
+    /// angle within the spread and its speed within the range
+    pub fn velocity(&self, angle_roll: f32, speed_roll: f32) -> Vec2 {
+        let angle = self.direction.to_angle() + (angle_roll - 0.5) * self.angle_spread;
+        let speed = self.min_speed + (self.max_speed - self.min_speed) * speed_roll;
+        Vec2::from_angle(angle) * speed
+    }
+}
+
+/// Component for a single particle drifting, blending along its colour
+/// gradient and despawning when its lifetime ends
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct Particle {
+    pub velocity: Vec2,
+    pub age: f32,
+    pub lifetime: f32,
+    pub start_color: Color,
+    pub end_color: Color,
+}
+
+impl Particle {
+    /// Colour along the gradient for the particle's age
+    pub fn color(&self) -> Color {
+        let t = (self.age / self.lifetime).clamp(0.0, 1.0);
+        blend_colors(self.start_color, self.end_color, t)
+    }
+}
+
+/// Spawn an emitter bursting at `position` on the next particle update
+pub fn spawn_particles(commands: &mut Commands, position: Vec2, emitter: ParticleEmitter) {
+    commands.spawn((Transform::from_translation(position.extend(PARTICLE_Z)), emitter));
+}
+
+/// System to burst each new emitter into particles, within the effect budget.
+/// Particles are cosmetic, so they roll with `rand` and leave the seeded game RNG alone.
+pub fn particle_emitter_system(
+    mut commands: Commands,
+    mut effect_budget: Option<ResMut<EffectBudget>>,
+    emitters: Query<(Entity, &ParticleEmitter, &Transform)>,
+) {
+    for (entity, emitter, transform) in emitters.iter() {
+        commands.entity(entity).despawn();
+        for _ in 0..emitter.burst_count {
+            if !effect_budget.as_deref_mut().is_none_or(|budget| budget.admit(EffectCategory::Particle)) {
+                break;
+            }
+            commands.spawn((
+                Sprite {
+                    color: emitter.start_color,
+                    custom_size: Some(Vec2::splat(emitter.size)),
+                    ..default()
+                },
+                Transform::from_translation(transform.translation),
+                Particle {
+                    velocity: emitter.velocity(rand::random(), rand::random()),
+                    age: 0.0,
+                    lifetime: emitter.lifetime,
+                    start_color: emitter.start_color,
+                    end_color: emitter.end_color,
+                },
+                CosmeticEffect(EffectCategory::Particle),
+            ));
+        }
+    }
+}
+
+/// System to move, slow and recolour particles, despawning them once they die
+pub fn particle_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut particles: Query<(Entity, &mut Particle, &mut Transform, &mut Sprite)>,
+) {
+    let delta_secs = time.delta_secs();
+    let drag = PARTICLE_DRAG.powf(delta_secs);
+    for (entity, mut particle, mut transform, mut sprite) in particles.iter_mut() {
+        particle.age += delta_secs;
+        if particle.age >= particle.lifetime {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        transform.translation += (particle.velocity * delta_secs).extend(0.0);
+        particle.velocity *= drag;
+        sprite.color = particle.color();
+    }
+}
+
+/// Plugin for the burst particles of impacts, explosions, tower placement and enemy deaths
+pub struct ParticlePlugin;
+
+impl Plugin for ParticlePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (particle_emitter_system, particle_system)
+                .chain()
+                .after(CombatSet::Collision)
+                .in_set(GameSystemSet::Gameplay)
+                .run_if(in_state(AppState::Playing)),
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use std::collections::VecDeque;
+use super::grid::{GridPos, PathGrid};
+use super::pathfinding::find_path_with_costs;
+
+/// How much longer than the normal route an arena route aims to be
+pub const ARENA_LENGTH_FACTOR: f32 = 1.6;
+/// Detour cells tried before giving up on an arena route
+const ARENA_CANDIDATES: usize = 24;
+
+/// Longer alternative to `route` with the same entry and exit, for boss waves.
+///
+/// The arena route detours through the cell whose shortest loop from the entry
+/// to the exit comes closest to `ARENA_LENGTH_FACTOR` times the route's length,
+/// and the way on from that cell never crosses the way in. `blocked` cells, such
+/// as those under towers, are avoided. Returns `None` when no longer route exists.
+pub fn arena_route(grid: &PathGrid, route: &[GridPos], blocked: impl Fn(GridPos) -> bool) -> Option<Vec<GridPos>> {
+    let (&entry, &exit) = (route.first()?, route.last()?);
+    let passable = |pos: GridPos| grid.is_traversable(pos) && !blocked(pos);
+    let from_entry = step_distances(grid, entry, &passable);
+    let from_exit = step_distances(grid, exit, &passable);
+    let target = route.len() as f32 * ARENA_LENGTH_FACTOR;
+
+    // Cells the shortest entry-via-exit loop is longer than the route for,
+    // nearest the target length first
+    let mut candidates: Vec<(GridPos, usize)> = (0..grid.height)
+        .flat_map(|y| (0..grid.width).map(move |x| GridPos::new(x, y)))
+        .filter_map(|pos| {
+            let index = pos.y * grid.width + pos.x;
+            Some((pos, from_entry[index]? + from_exit[index]? + 1))
+        })
+        .filter(|(_, length)| *length > route.len())
+        .collect();
+    candidates.sort_by(|a, b| {
+        (a.1 as f32 - target)
+            .abs()
+            .total_cmp(&(b.1 as f32 - target).abs())
+            .then((a.0.y, a.0.x).cmp(&(b.0.y, b.0.x)))
+    });
+
+    let cost = |pos: GridPos| if blocked(pos) { f32::INFINITY } else { 0.0 };
+    candidates.into_iter().take(ARENA_CANDIDATES).find_map(|(via, _)| {
+        let mut arena = find_path_with_costs(grid, entry, via, cost)?;
+        // The way on to the exit may not cross the way in
+        let onward = find_path_with_costs(grid, via, exit, |pos| {
+            if arena.contains(&pos) { f32::INFINITY } else { cost(pos) }
+        })?;
+        arena.extend_from_slice(&onward[1..]);
+        Some(arena)
+    })
+}
+
+/// Fewest steps from `start` to every cell, row-major; `None` where it can't be reached
+fn step_distances(grid: &PathGrid, start: GridPos, passable: &impl Fn(GridPos) -> bool) -> Vec<Option<usize>> {
+    let mut steps = vec![None; grid.width * grid.height];
+    if !passable(start) {
+        return steps;
+    }
+
+    steps[start.y * grid.width + start.x] = Some(0);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((pos, distance)) = queue.pop_front() {
+        for neighbor in pos.neighbors(grid.width, grid.height) {
+            let index = neighbor.y * grid.width + neighbor.x;
+            if steps[index].is_none() && passable(neighbor) {
+                steps[index] = Some(distance + 1);
+                queue.push_back((neighbor, distance + 1));
+            }
+        }
+    }
+    steps
+}
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use bevy::prelude::*;
+use crate::components::Enemy;
 use crate::resources::EnemyPath;
 use super::grid::TowerZone;
 
@@ -58,7 +59,12 @@ impl PathCache {
         let enemy_path = grid.to_enemy_path(grid_path.clone());
         
         // Generate optimized placement zones
-        let zones = super::zone_optimization::calculate_optimal_tower_zones(&grid, &grid_path);
+        let zones = super::zone_optimization::calculate_exposure_tower_zones(
+            &grid,
+            std::slice::from_ref(&grid_path),
+            super::zone_optimization::ZONE_TOWER_RANGE,
+            Enemy::default().speed,
+        );
         
         (enemy_path, zones)
     }
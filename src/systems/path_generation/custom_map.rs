@@ -0,0 +1,166 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use super::grid::{CellType, GridError, GridPos, PathGrid};
+use super::pathfinding::find_path;
+
+/// Folder custom maps are saved to by the editor and listed from on the map select screen
+pub const CUSTOM_MAP_DIR: &str = "assets/maps";
+/// File extension of saved custom maps
+pub const CUSTOM_MAP_EXTENSION: &str = "ron";
+/// Largest grid a custom map may describe, far above the playable board
+const MAX_CUSTOM_MAP_CELLS: usize = 256 * 256;
+
+/// Errors from building, reading or writing a custom map
+#[derive(Debug, Clone, PartialEq)]
+pub enum CustomMapError {
+    /// The file isn't a custom map
+    Parse(String),
+    /// The size or a cell lies outside what a grid can hold
+    Grid(GridError),
+    /// No route leads from the entry to the exit
+    NoRoute,
+    /// The map file couldn't be read or written
+    Io(String),
+}
+
+impl fmt::Display for CustomMapError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CustomMapError::Parse(reason) => write!(f, "not a valid custom map: {}", reason),
+            CustomMapError::Grid(error) => write!(f, "custom map has an unusable grid: {}", error),
+            CustomMapError::NoRoute => write!(f, "no route from the entry to the exit"),
+            CustomMapError::Io(reason) => write!(f, "map file error: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for CustomMapError {}
+
+impl From<GridError> for CustomMapError {
+    fn from(error: GridError) -> Self {
+        CustomMapError::Grid(error)
+    }
+}
+
+/// A map painted in the map editor, saved as RON. Only the blocked cells are
+/// stored; everything else is open ground.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomMap {
+    pub name: String,
+    pub width: usize,
+    pub height: usize,
+    /// Cell enemies enter from, as (x, y)
+    pub entry: (usize, usize),
+    /// Cell enemies leave through, as (x, y)
+    pub exit: (usize, usize),
+    /// Cells blocked by obstacles, as (x, y)
+    pub blocked: Vec<(usize, usize)>,
+}
+
+impl CustomMap {
+    /// Capture a painted grid
+    pub fn from_grid(name: &str, grid: &PathGrid) -> Self {
+        let mut blocked = Vec::new();
+        for y in 0..grid.height {
+            for x in 0..grid.width {
+                if grid.get_cell(GridPos::new(x, y)) == Some(CellType::Blocked) {
+                    blocked.push((x, y));
+                }
+            }
+        }
+        Self {
+            name: name.to_string(),
+            width: grid.width,
+            height: grid.height,
+            entry: (grid.entry_point.x, grid.entry_point.y),
+            exit: (grid.exit_point.x, grid.exit_point.y),
+            blocked,
+        }
+    }
+
+    /// Grid the map describes, rejecting sizes and cells that don't fit
+    pub fn to_grid(&self) -> Result<PathGrid, CustomMapError> {
+        if self.width.saturating_mul(self.height) > MAX_CUSTOM_MAP_CELLS {
+            return Err(CustomMapError::Parse(format!("{}x{} is larger than any map", self.width, self.height)));
+        }
+        let mut grid = PathGrid::try_new(self.width, self.height)?;
+        grid.entry_point = GridPos::new(self.entry.0, self.entry.1);
+        grid.exit_point = GridPos::new(self.exit.0, self.exit.1);
+        grid.check_bounds(grid.entry_point)?;
+        grid.check_bounds(grid.exit_point)?;
+        for &(x, y) in &self.blocked {
+            grid.try_set_cell(GridPos::new(x, y), CellType::Blocked)?;
+        }
+        Ok(grid)
+    }
+
+    /// Grid and the route enemies take through it, checked with `find_path`
+    pub fn to_grid_and_route(&self) -> Result<(PathGrid, Vec<GridPos>), CustomMapError> {
+        let grid = self.to_grid()?;
+        let route = find_path(&grid, grid.entry_point, grid.exit_point).ok_or(CustomMapError::NoRoute)?;
+        Ok((grid, route))
+    }
+
+    pub fn to_ron(&self) -> Result<String, CustomMapError> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+            .map_err(|error| CustomMapError::Parse(error.to_string()))
+    }
+
+    pub fn from_ron(text: &str) -> Result<Self, CustomMapError> {
+        ron::from_str(text).map_err(|error| CustomMapError::Parse(error.to_string()))
+    }
+
+    /// Write the map into `dir`, named after the map, returning the file written
+    pub fn save_to_dir(&self, dir: &Path) -> Result<PathBuf, CustomMapError> {
+        std::fs::create_dir_all(dir).map_err(|error| CustomMapError::Io(error.to_string()))?;
+        let path = dir.join(format!("{}.{}", map_file_stem(&self.name), CUSTOM_MAP_EXTENSION));
+        std::fs::write(&path, self.to_ron()?).map_err(|error| CustomMapError::Io(error.to_string()))?;
+        Ok(path)
+    }
+
+    pub fn load_from_file(path: &Path) -> Result<Self, CustomMapError> {
+        let text = std::fs::read_to_string(path).map_err(|error| CustomMapError::Io(error.to_string()))?;
+        Self::from_ron(&text)
+    }
+}
+
+/// File name for a map name: lowercase letters and digits, other runs of
+/// characters turned into single underscores
+pub fn map_file_stem(name: &str) -> String {
+    let mut stem = String::with_capacity(name.len());
+    for character in name.chars() {
+        if character.is_ascii_alphanumeric() {
+            stem.push(character.to_ascii_lowercase());
+        } else if !stem.is_empty() && !stem.ends_with('_') {
+            stem.push('_');
+        }
+    }
+    let stem = stem.trim_end_matches('_');
+    if stem.is_empty() {
+        "custom_map".to_string()
+    } else {
+        stem.to_string()
+    }
+}
+
+/// Every custom map in `dir` that can be read, sorted by name. Unreadable
+/// files are returned separately so they can be reported.
+pub fn load_custom_maps(dir: &Path) -> (Vec<CustomMap>, Vec<(PathBuf, CustomMapError)>) {
+    let mut maps = Vec::new();
+    let mut failures = Vec::new();
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return (maps, failures);
+    };
+    for path in entries.filter_map(|entry| entry.ok()).map(|entry| entry.path()) {
+        if path.extension().and_then(|extension| extension.to_str()) != Some(CUSTOM_MAP_EXTENSION) {
+            continue;
+        }
+        match CustomMap::load_from_file(&path) {
+            Ok(map) => maps.push(map),
+            Err(error) => failures.push((path, error)),
+        }
+    }
+    maps.sort_by(|a, b| a.name.cmp(&b.name));
+    (maps, failures)
+}
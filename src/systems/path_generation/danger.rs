@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+use super::grid::{PathGrid, GridPos};
+
+/// Extra path cost for each tower whose range covers a cell
+pub const DANGER_COST_PER_TOWER: f32 = 2.0;
+
+/// Per-cell path cost derived from tower coverage, for enemies that route
+/// around defenses. Cells holding a tower are impassable.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DangerField {
+    pub width: usize,
+    pub height: usize,
+    /// Row-major costs, `[y * width + x]`
+    costs: Vec<f32>,
+}
+
+impl DangerField {
+    /// A field with no danger anywhere
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            costs: vec![0.0; width * height],
+        }
+    }
+
+    /// Build the field for a grid from each tower's position and range
+    pub fn from_towers(grid: &PathGrid, towers: &[(Vec2, f32)]) -> Self {
+        let mut field = Self::new(grid.width, grid.height);
+        for y in 0..grid.height {
+            for x in 0..grid.width {
+                let pos = GridPos::new(x, y);
+                let center = grid.grid_to_world(pos);
+                let covering = towers
+                    .iter()
+                    .filter(|(tower_pos, range)| tower_pos.distance(center) <= *range)
+                    .count();
+                field.costs[y * grid.width + x] = covering as f32 * DANGER_COST_PER_TOWER;
+            }
+        }
+
+        for (tower_pos, _) in towers {
+            if let Some(pos) = grid.world_to_grid(*tower_pos) {
+                field.costs[pos.y * grid.width + pos.x] = f32::INFINITY;
+            }
+        }
+        field
+    }
+
+    /// Cost of entering a cell; cells outside the field cost nothing extra
+    pub fn cost(&self, pos: GridPos) -> f32 {
+        if pos.x < self.width && pos.y < self.height {
+            self.costs[pos.y * self.width + pos.x]
+        } else {
+            0.0
+        }
+    }
+}
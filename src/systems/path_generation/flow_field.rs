@@ -0,0 +1,163 @@
+use bevy::prelude::*;
+use super::grid::{CellType, GridPos, PathGrid};
+
+/// When enemies reach each cell of their route and how long they stay there
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TravelTimeField {
+    pub width: usize,
+    pub height: usize,
+    /// Row-major seconds from the entry until an enemy first reaches each cell,
+    /// `[y * width + x]`; `None` off the route
+    arrival: Vec<Option<f32>>,
+    /// Route cells in order with the seconds an enemy spends crossing each, one
+    /// route after another on maps with several
+    pub dwell: Vec<(GridPos, f32)>,
+    /// Seconds from the entry to the exit, along the longest route
+    pub total_time: f32,
+}
+
+impl TravelTimeField {
+    /// Time an enemy moving at `speed` world units per second along `route`.
+    /// Each step's travel time is split between the two cells it joins, and
+    /// the first and last cells add the half cell before and after the route.
+    pub fn from_route(grid: &PathGrid, route: &[GridPos], speed: f32) -> Self {
+        let mut field = Self {
+            width: grid.width,
+            height: grid.height,
+            arrival: vec![None; grid.width * grid.height],
+            dwell: route.iter().map(|pos| (*pos, 0.0)).collect(),
+            total_time: 0.0,
+        };
+        if route.is_empty() || speed <= 0.0 {
+            return field;
+        }
+
+        let half_cell = grid.cell_size / 2.0 / speed;
+        let mut elapsed = 0.0;
+        field.mark_arrival(route[0], elapsed);
+        for (index, step) in route.windows(2).enumerate() {
+            let step_time = grid.grid_to_world(step[0]).distance(grid.grid_to_world(step[1])) / speed;
+            field.dwell[index].1 += step_time / 2.0;
+            field.dwell[index + 1].1 += step_time / 2.0;
+            elapsed += step_time;
+            field.mark_arrival(step[1], elapsed);
+        }
+        field.dwell[0].1 += half_cell;
+        field.dwell[route.len() - 1].1 += half_cell;
+        field.total_time = field.dwell.iter().map(|(_, seconds)| seconds).sum();
+        field
+    }
+
+    /// Time enemies moving at `speed` spend on each of `routes`, for maps they
+    /// enter from several sides. Cells count the time spent on them along every
+    /// route and are reached when the first route gets there.
+    pub fn from_routes(grid: &PathGrid, routes: &[Vec<GridPos>], speed: f32) -> Self {
+        let mut field = Self {
+            width: grid.width,
+            height: grid.height,
+            arrival: vec![None; grid.width * grid.height],
+            dwell: Vec::new(),
+            total_time: 0.0,
+        };
+        for route in routes {
+            let single = Self::from_route(grid, route, speed);
+            for (arrival, seconds) in field.arrival.iter_mut().zip(single.arrival) {
+                if let Some(seconds) = seconds {
+                    *arrival = Some(arrival.map_or(seconds, |earliest| earliest.min(seconds)));
+                }
+            }
+            field.dwell.extend(single.dwell);
+            field.total_time = field.total_time.max(single.total_time);
+        }
+        field
+    }
+
+    fn mark_arrival(&mut self, pos: GridPos, seconds: f32) {
+        if pos.x < self.width && pos.y < self.height {
+            let arrival = &mut self.arrival[pos.y * self.width + pos.x];
+            arrival.get_or_insert(seconds);
+        }
+    }
+
+    /// Seconds after leaving the entry that an enemy first reaches a cell
+    pub fn arrival(&self, pos: GridPos) -> Option<f32> {
+        if pos.x < self.width && pos.y < self.height {
+            self.arrival[pos.y * self.width + pos.x]
+        } else {
+            None
+        }
+    }
+
+    /// Seconds an enemy spends within `range` of a world position
+    pub fn exposure_at(&self, grid: &PathGrid, position: Vec2, range: f32) -> f32 {
+        self.dwell
+            .iter()
+            .filter(|(pos, _)| grid.grid_to_world(*pos).distance(position) <= range)
+            .map(|(_, seconds)| seconds)
+            .sum()
+    }
+}
+
+/// Seconds each enemy spends in range of a tower built on each cell
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExposureField {
+    pub width: usize,
+    pub height: usize,
+    /// Tower range the field was worked out for, in world units
+    pub range: f32,
+    /// Row-major exposure seconds, `[y * width + x]`; zero where towers can't be built
+    seconds: Vec<f32>,
+}
+
+impl ExposureField {
+    /// Exposure for every buildable cell of the grid
+    pub fn new(grid: &PathGrid, travel: &TravelTimeField, range: f32) -> Self {
+        let mut seconds = vec![0.0; grid.width * grid.height];
+        for y in 0..grid.height {
+            for x in 0..grid.width {
+                let pos = GridPos::new(x, y);
+                if is_buildable(grid, pos) {
+                    seconds[y * grid.width + x] = travel.exposure_at(grid, grid.grid_to_world(pos), range);
+                }
+            }
+        }
+        Self {
+            width: grid.width,
+            height: grid.height,
+            range,
+            seconds,
+        }
+    }
+
+    /// Exposure seconds of a cell; cells outside the field have none
+    pub fn at(&self, pos: GridPos) -> f32 {
+        if pos.x < self.width && pos.y < self.height {
+            self.seconds[pos.y * self.width + pos.x]
+        } else {
+            0.0
+        }
+    }
+
+    /// Highest exposure of any cell
+    pub fn max(&self) -> f32 {
+        self.seconds.iter().copied().fold(0.0, f32::max)
+    }
+
+    /// Cells with any exposure, highest first. Ties go to the lower row, then column.
+    pub fn ranked_cells(&self) -> Vec<(GridPos, f32)> {
+        let mut cells: Vec<(GridPos, f32)> = self
+            .seconds
+            .iter()
+            .enumerate()
+            .filter(|(_, seconds)| **seconds > 0.0)
+            .map(|(index, seconds)| (GridPos::new(index % self.width, index / self.width), *seconds))
+            .collect();
+        cells.sort_by(|a, b| b.1.total_cmp(&a.1).then((a.0.y, a.0.x).cmp(&(b.0.y, b.0.x))));
+        cells
+    }
+}
+
+/// Cells a tower may stand on, matching the placement validator
+fn is_buildable(grid: &PathGrid, pos: GridPos) -> bool {
+    matches!(grid.get_cell(pos), Some(CellType::Empty) | Some(CellType::TowerZone))
+}
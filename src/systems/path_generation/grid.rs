@@ -1,7 +1,40 @@
 use bevy::prelude::*;
-use crate::resources::EnemyPath;
+use std::fmt;
+use crate::resources::{EnemyPath, GRID_CELL_SIZE, GRID_HEIGHT, GRID_WIDTH};
 use crate::systems::input_system::PlacementZoneType;
 
+/// Errors from bounds-checked grid operations
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GridError {
+    /// Grid position outside the grid
+    OutOfBounds { pos: GridPos, width: usize, height: usize },
+    /// World position outside the grid area (or not a finite number)
+    WorldOutOfBounds { world_pos: Vec2 },
+    /// Grid dimensions that cannot be used (zero, or too small for generation)
+    InvalidDimensions { width: usize, height: usize },
+    /// Path conversion was given no positions
+    EmptyPath,
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::OutOfBounds { pos, width, height } => {
+                write!(f, "grid position ({}, {}) is outside the {}x{} grid", pos.x, pos.y, width, height)
+            }
+            GridError::WorldOutOfBounds { world_pos } => {
+                write!(f, "world position ({}, {}) is outside the grid", world_pos.x, world_pos.y)
+            }
+            GridError::InvalidDimensions { width, height } => {
+                write!(f, "invalid grid dimensions {}x{}", width, height)
+            }
+            GridError::EmptyPath => write!(f, "path has no positions"),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
 /// Represents the type of content in each grid cell
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum CellType {
@@ -14,6 +47,9 @@ pub enum CellType {
     TowerZone,
     /// Blocked cell - impassable obstacle
     Blocked,
+    /// Void cell (water or chasm) - ground enemies route around it, flying
+    /// enemies cross it and stray projectiles are lost in it
+    Void,
 }
 
 /// Grid position using integer coordinates
@@ -62,7 +98,7 @@ impl GridPos {
 }
 
 /// Grid-based representation of the game map for pathfinding
-#[derive(Debug, Clone, Resource)]
+#[derive(Debug, Clone, PartialEq, Resource)]
 pub struct PathGrid {
     /// Grid width in cells
     pub width: usize,
@@ -86,46 +122,90 @@ impl PathGrid {
         Self {
             width,
             height,
-            cell_size: 40.0, // Matches dense unified grid cell size
+            cell_size: GRID_CELL_SIZE, // Matches dense unified grid cell size
             cells,
             entry_point: GridPos::new(0, height / 2),
-            exit_point: GridPos::new(width - 1, height / 2),
+            exit_point: GridPos::new(width.saturating_sub(1), height / 2),
         }
     }
     
+    /// Create a new empty grid, rejecting zero-sized dimensions
+    pub fn try_new(width: usize, height: usize) -> Result<Self, GridError> {
+        if width == 0 || height == 0 {
+            return Err(GridError::InvalidDimensions { width, height });
+        }
+        Ok(Self::new(width, height))
+    }
+    
     /// Create a new grid using dense unified grid system dimensions (32x18)
     pub fn new_unified() -> Self {
-        Self::new(32, 18)
+        Self::new(GRID_WIDTH, GRID_HEIGHT)
     }
     
-    /// Get cell type at grid position (bounds-checked)
-    pub fn get_cell(&self, pos: GridPos) -> Option<CellType> {
-        if pos.x < self.width && pos.y < self.height {
-            Some(self.cells[pos.y][pos.x])
+    /// Check whether a grid position lies inside the grid
+    pub fn contains(&self, pos: GridPos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+    
+    /// Return an error if a grid position lies outside the grid
+    pub fn check_bounds(&self, pos: GridPos) -> Result<(), GridError> {
+        if self.contains(pos) {
+            Ok(())
         } else {
-            None
+            Err(GridError::OutOfBounds { pos, width: self.width, height: self.height })
         }
     }
     
+    /// Get cell type at grid position
+    pub fn try_get_cell(&self, pos: GridPos) -> Result<CellType, GridError> {
+        self.check_bounds(pos)?;
+        self.cells
+            .get(pos.y)
+            .and_then(|row| row.get(pos.x))
+            .copied()
+            .ok_or(GridError::OutOfBounds { pos, width: self.width, height: self.height })
+    }
+    
+    /// Set cell type at grid position
+    pub fn try_set_cell(&mut self, pos: GridPos, cell_type: CellType) -> Result<(), GridError> {
+        self.check_bounds(pos)?;
+        let cell = self.cells
+            .get_mut(pos.y)
+            .and_then(|row| row.get_mut(pos.x))
+            .ok_or(GridError::OutOfBounds { pos, width: self.width, height: self.height })?;
+        *cell = cell_type;
+        Ok(())
+    }
+    
+    /// Get cell type at grid position (bounds-checked)
+    pub fn get_cell(&self, pos: GridPos) -> Option<CellType> {
+        self.try_get_cell(pos).ok()
+    }
+    
     /// Set cell type at grid position (bounds-checked)
     pub fn set_cell(&mut self, pos: GridPos, cell_type: CellType) -> bool {
-        if pos.x < self.width && pos.y < self.height {
-            self.cells[pos.y][pos.x] = cell_type;
-            true
-        } else {
-            false
-        }
+        self.try_set_cell(pos, cell_type).is_ok()
     }
     
     /// Check if a position is traversable for pathfinding
     pub fn is_traversable(&self, pos: GridPos) -> bool {
         match self.get_cell(pos) {
             Some(CellType::Empty) | Some(CellType::Path) => true,
-            Some(CellType::Blocked) | Some(CellType::TowerZone) => false,
+            Some(CellType::Blocked) | Some(CellType::TowerZone) | Some(CellType::Void) => false,
             None => false,
         }
     }
     
+    /// Check if a position can be crossed by flying enemies (ground cells plus void)
+    pub fn is_flyable(&self, pos: GridPos) -> bool {
+        self.is_traversable(pos) || self.is_void(pos)
+    }
+    
+    /// Check if a position is a void cell
+    pub fn is_void(&self, pos: GridPos) -> bool {
+        self.get_cell(pos) == Some(CellType::Void)
+    }
+    
     /// Convert grid coordinates to world coordinates (center of cell)
     /// Uses unified grid coordinate system for consistency
     pub fn grid_to_world(&self, grid_pos: GridPos) -> Vec2 {
@@ -140,26 +220,58 @@ impl PathGrid {
         )
     }
     
+    /// Convert grid coordinates to world coordinates (center of cell),
+    /// rejecting positions outside the grid
+    pub fn try_grid_to_world(&self, grid_pos: GridPos) -> Result<Vec2, GridError> {
+        self.check_bounds(grid_pos)?;
+        Ok(self.grid_to_world(grid_pos))
+    }
+    
     /// Convert world coordinates to grid coordinates
     /// Uses unified grid coordinate system for consistency
     pub fn world_to_grid(&self, world_pos: Vec2) -> Option<GridPos> {
+        self.try_world_to_grid(world_pos).ok()
+    }
+    
+    /// Convert world coordinates to grid coordinates, rejecting positions
+    /// outside the grid and non-finite input
+    pub fn try_world_to_grid(&self, world_pos: Vec2) -> Result<GridPos, GridError> {
+        let out_of_bounds = GridError::WorldOutOfBounds { world_pos };
+        if !world_pos.is_finite() || self.cell_size <= 0.0 {
+            return Err(out_of_bounds);
+        }
+        
         let grid_offset = Vec2::new(
             -(self.width as f32 * self.cell_size) / 2.0,
             -(self.height as f32 * self.cell_size) / 2.0,
         );
         
         let relative_pos = world_pos - grid_offset;
-        let grid_x = (relative_pos.x / self.cell_size).floor() as i32;
-        let grid_y = (relative_pos.y / self.cell_size).floor() as i32;
+        let grid_x = (relative_pos.x / self.cell_size).floor();
+        let grid_y = (relative_pos.y / self.cell_size).floor();
         
-        if grid_x >= 0 && grid_x < self.width as i32 && 
-           grid_y >= 0 && grid_y < self.height as i32 {
-            Some(GridPos::new(grid_x as usize, grid_y as usize))
+        if grid_x >= 0.0 && grid_x < self.width as f32 && 
+           grid_y >= 0.0 && grid_y < self.height as f32 {
+            Ok(GridPos::new(grid_x as usize, grid_y as usize))
         } else {
-            None
+            Err(out_of_bounds)
         }
     }
     
+    /// Convert a path of grid positions to EnemyPath, rejecting empty paths
+    /// and positions outside the grid
+    pub fn try_to_enemy_path(&self, grid_path: &[GridPos]) -> Result<EnemyPath, GridError> {
+        if grid_path.is_empty() {
+            return Err(GridError::EmptyPath);
+        }
+        
+        let waypoints = grid_path.iter()
+            .map(|&pos| self.try_grid_to_world(pos))
+            .collect::<Result<Vec<Vec2>, GridError>>()?;
+            
+        Ok(EnemyPath::new(waypoints))
+    }
+    
     /// Convert a path of grid positions to EnemyPath with world coordinates
     pub fn to_enemy_path(&self, grid_path: Vec<GridPos>) -> EnemyPath {
         let waypoints: Vec<Vec2> = grid_path.iter()
@@ -192,7 +304,7 @@ impl PathGrid {
         // Simple algorithm - could be optimized with more sophisticated approaches
         for y in 0..self.height {
             for x in 0..self.width {
-                if self.cells[y][x] == CellType::Empty {
+                if self.get_cell(GridPos::new(x, y)) == Some(CellType::Empty) {
                     for h in 1..=(self.height - y) {
                         for w in 1..=(self.width - x) {
                             if self.is_rect_empty(GridPos::new(x, y), w, h) {
@@ -221,7 +333,7 @@ impl PathGrid {
         for dy in 0..height {
             for dx in 0..width {
                 let pos = GridPos::new(top_left.x + dx, top_left.y + dy);
-                if pos.x >= self.width || pos.y >= self.height || self.cells[pos.y][pos.x] != CellType::Empty {
+                if self.try_get_cell(pos) != Ok(CellType::Empty) {
                     return false;
                 }
             }
@@ -0,0 +1,270 @@
+use std::fmt;
+use super::grid::{CellType, GridError, GridPos, PathGrid};
+use super::obstacles::MapArchetype;
+
+/// Leading field of every map code; bump the number when the layout changes
+pub const MAP_CODE_PREFIX: &str = "TDMAP1";
+/// Largest grid a map code may describe, far above the generated maps
+const MAX_MAP_CELLS: usize = 256 * 256;
+/// File maps are exported to and imported from
+pub const SHARED_MAP_FILE: &str = "shared_map.txt";
+
+/// Errors from reading a map code
+#[derive(Debug, Clone, PartialEq)]
+pub enum MapCodeError {
+    /// The text isn't a map code, or a field can't be read
+    Format(String),
+    /// The grid described by the code can't be used
+    Grid(GridError),
+    /// The enemy route runs through a blocked cell
+    RouteBlocked(GridPos),
+    /// The map file couldn't be read or written
+    Io(String),
+}
+
+impl fmt::Display for MapCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MapCodeError::Format(reason) => write!(f, "not a valid map code: {}", reason),
+            MapCodeError::Grid(error) => write!(f, "map code has an unusable grid: {}", error),
+            MapCodeError::RouteBlocked(pos) => write!(f, "enemy route is blocked at ({}, {})", pos.x, pos.y),
+            MapCodeError::Io(reason) => write!(f, "map file error: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for MapCodeError {}
+
+impl From<GridError> for MapCodeError {
+    fn from(error: GridError) -> Self {
+        MapCodeError::Grid(error)
+    }
+}
+
+/// A complete map that can be shared: the grid with its obstacles, tower zones
+/// and entry/exit, the routes enemies take through it and the archetype it was
+/// generated as
+#[derive(Debug, Clone, PartialEq)]
+pub struct SharedMap {
+    pub grid: PathGrid,
+    pub route: Vec<GridPos>,
+    /// Routes from further entry points, on maps with several
+    pub extra_routes: Vec<Vec<GridPos>>,
+    pub archetype: MapArchetype,
+}
+
+impl SharedMap {
+    /// Encode the map as a single line of text.
+    ///
+    /// Fields are separated by `;`: prefix, archetype, size, cell size, entry,
+    /// exit, run-length encoded cells (row by row) and the routes, each a start
+    /// cell followed by one letter per step, separated by `|` with the main
+    /// route first.
+    pub fn to_code(&self) -> String {
+        let grid = &self.grid;
+        let archetype = MapArchetype::ALL.iter().position(|a| *a == self.archetype).unwrap_or(0);
+        format!(
+            "{};{};{}x{};{};{},{};{},{};{};{}",
+            MAP_CODE_PREFIX,
+            archetype,
+            grid.width,
+            grid.height,
+            grid.cell_size,
+            grid.entry_point.x,
+            grid.entry_point.y,
+            grid.exit_point.x,
+            grid.exit_point.y,
+            encode_cells(grid),
+            std::iter::once(&self.route)
+                .chain(&self.extra_routes)
+                .map(|route| encode_route(route))
+                .collect::<Vec<_>>()
+                .join("|")
+        )
+    }
+
+    /// Decode a map from `to_code` output, rejecting codes that don't describe a playable map
+    pub fn from_code(code: &str) -> Result<Self, MapCodeError> {
+        let fields: Vec<&str> = code.trim().split(';').collect();
+        if fields.first() != Some(&MAP_CODE_PREFIX) {
+            return Err(MapCodeError::Format(format!("expected it to start with {}", MAP_CODE_PREFIX)));
+        }
+        let [_, archetype, size, cell_size, entry, exit, cells, route] = fields[..] else {
+            return Err(MapCodeError::Format(format!("expected 8 fields, found {}", fields.len())));
+        };
+
+        let archetype = parse_number(archetype, "archetype")
+            .ok()
+            .and_then(|index| MapArchetype::ALL.get(index).copied())
+            .ok_or_else(|| MapCodeError::Format(format!("unknown archetype {}", archetype)))?;
+        let (width, height) = size
+            .split_once('x')
+            .ok_or_else(|| MapCodeError::Format(format!("invalid size {}", size)))?;
+        let (width, height) = (parse_number(width, "width")?, parse_number(height, "height")?);
+        if width.saturating_mul(height) > MAX_MAP_CELLS {
+            return Err(MapCodeError::Format(format!("{}x{} is larger than any map", width, height)));
+        }
+        let mut grid = PathGrid::try_new(width, height)?;
+        grid.cell_size = cell_size
+            .parse::<f32>()
+            .ok()
+            .filter(|size| size.is_finite() && *size > 0.0)
+            .ok_or_else(|| MapCodeError::Format(format!("invalid cell size {}", cell_size)))?;
+        grid.entry_point = parse_pos(entry)?;
+        grid.exit_point = parse_pos(exit)?;
+        grid.check_bounds(grid.entry_point)?;
+        grid.check_bounds(grid.exit_point)?;
+        grid.cells = decode_cells(cells, grid.width, grid.height)?;
+
+        let mut routes = route.split('|').map(decode_route).collect::<Result<Vec<_>, _>>()?;
+        for pos in routes.iter().flatten() {
+            grid.check_bounds(*pos)?;
+            if !grid.is_traversable(*pos) {
+                return Err(MapCodeError::RouteBlocked(*pos));
+            }
+        }
+        let route = routes.remove(0);
+
+        Ok(Self { grid, route, extra_routes: routes, archetype })
+    }
+
+    /// Write the map code to a file
+    pub fn save_to_file(&self, path: &str) -> Result<(), MapCodeError> {
+        std::fs::write(path, self.to_code()).map_err(|e| MapCodeError::Io(e.to_string()))
+    }
+
+    /// Read a map code from a file
+    pub fn load_from_file(path: &str) -> Result<Self, MapCodeError> {
+        let code = std::fs::read_to_string(path).map_err(|e| MapCodeError::Io(e.to_string()))?;
+        Self::from_code(&code)
+    }
+}
+
+fn cell_char(cell: CellType) -> char {
+    match cell {
+        CellType::Empty => '.',
+        CellType::Path => 'P',
+        CellType::TowerZone => 'Z',
+        CellType::Blocked => '#',
+        CellType::Void => '~',
+    }
+}
+
+fn char_cell(c: char) -> Option<CellType> {
+    match c {
+        '.' => Some(CellType::Empty),
+        'P' => Some(CellType::Path),
+        'Z' => Some(CellType::TowerZone),
+        '#' => Some(CellType::Blocked),
+        '~' => Some(CellType::Void),
+        _ => None,
+    }
+}
+
+/// Cells row by row as runs of `<count><cell>`
+fn encode_cells(grid: &PathGrid) -> String {
+    let mut encoded = String::new();
+    let mut run: Option<(CellType, usize)> = None;
+    for cell in grid.cells.iter().flatten() {
+        run = match run {
+            Some((kind, count)) if kind == *cell => Some((kind, count + 1)),
+            Some((kind, count)) => {
+                encoded.push_str(&format!("{}{}", count, cell_char(kind)));
+                Some((*cell, 1))
+            }
+            None => Some((*cell, 1)),
+        };
+    }
+    if let Some((kind, count)) = run {
+        encoded.push_str(&format!("{}{}", count, cell_char(kind)));
+    }
+    encoded
+}
+
+fn decode_cells(encoded: &str, width: usize, height: usize) -> Result<Vec<Vec<CellType>>, MapCodeError> {
+    let mut flat = Vec::with_capacity(width * height);
+    let mut count = String::new();
+    for c in encoded.chars() {
+        if c.is_ascii_digit() {
+            count.push(c);
+            continue;
+        }
+        let cell = char_cell(c).ok_or_else(|| MapCodeError::Format(format!("unknown cell '{}'", c)))?;
+        let run = parse_number(&count, "cell run")?;
+        if run > width * height - flat.len() {
+            return Err(MapCodeError::Format("more cells than the grid holds".to_string()));
+        }
+        flat.extend(std::iter::repeat_n(cell, run));
+        count.clear();
+    }
+    if !count.is_empty() || flat.len() != width * height {
+        return Err(MapCodeError::Format(format!("expected {} cells, found {}", width * height, flat.len())));
+    }
+    Ok(flat.chunks(width).map(<[CellType]>::to_vec).collect())
+}
+
+/// Start cell, then `R`/`L`/`U`/`D` for unit steps and `@x,y` for anything longer
+fn encode_route(route: &[GridPos]) -> String {
+    let Some(start) = route.first() else {
+        return String::new();
+    };
+    let mut encoded = format!("{},{}", start.x, start.y);
+    for step in route.windows(2) {
+        let (from, to) = (step[0], step[1]);
+        let letter = match (to.x as i64 - from.x as i64, to.y as i64 - from.y as i64) {
+            (1, 0) => Some('R'),
+            (-1, 0) => Some('L'),
+            (0, 1) => Some('U'),
+            (0, -1) => Some('D'),
+            _ => None,
+        };
+        match letter {
+            Some(letter) => encoded.push(letter),
+            None => encoded.push_str(&format!("@{},{}", to.x, to.y)),
+        }
+    }
+    encoded
+}
+
+fn decode_route(encoded: &str) -> Result<Vec<GridPos>, MapCodeError> {
+    let start_len = encoded
+        .find(|c: char| !(c.is_ascii_digit() || c == ','))
+        .unwrap_or(encoded.len());
+    let mut route = vec![parse_pos(&encoded[..start_len])?];
+    let mut rest = &encoded[start_len..];
+
+    while let Some(c) = rest.chars().next() {
+        let current = route[route.len() - 1];
+        rest = &rest[1..];
+        let next = match c {
+            'R' => GridPos::new(current.x.checked_add(1).ok_or_else(route_left_grid)?, current.y),
+            'L' => GridPos::new(current.x.checked_sub(1).ok_or_else(route_left_grid)?, current.y),
+            'U' => GridPos::new(current.x, current.y.checked_add(1).ok_or_else(route_left_grid)?),
+            'D' => GridPos::new(current.x, current.y.checked_sub(1).ok_or_else(route_left_grid)?),
+            '@' => {
+                let end = rest.find(|c: char| !(c.is_ascii_digit() || c == ',')).unwrap_or(rest.len());
+                let pos = parse_pos(&rest[..end])?;
+                rest = &rest[end..];
+                pos
+            }
+            _ => return Err(MapCodeError::Format(format!("unknown route step '{}'", c))),
+        };
+        route.push(next);
+    }
+    Ok(route)
+}
+
+fn route_left_grid() -> MapCodeError {
+    MapCodeError::Format("route leaves the grid".to_string())
+}
+
+fn parse_number(text: &str, what: &str) -> Result<usize, MapCodeError> {
+    text.parse().map_err(|_| MapCodeError::Format(format!("invalid {} '{}'", what, text)))
+}
+
+fn parse_pos(text: &str) -> Result<GridPos, MapCodeError> {
+    let (x, y) = text
+        .split_once(',')
+        .ok_or_else(|| MapCodeError::Format(format!("invalid cell '{}'", text)))?;
+    Ok(GridPos::new(parse_number(x, "x")?, parse_number(y, "y")?))
+}
@@ -3,16 +3,38 @@ pub mod pathfinding;
 pub mod obstacles;
 pub mod zone_optimization;
 pub mod cache;
+pub mod danger;
+pub mod map_code;
+pub mod flow_field;
+pub mod arena;
+pub mod custom_map;
+pub mod multi_route;
 
 pub use grid::*;
 pub use pathfinding::*;
 pub use obstacles::*;
 pub use zone_optimization::*;
 pub use cache::*;
+pub use danger::*;
+pub use map_code::*;
+pub use flow_field::*;
+pub use arena::*;
+pub use custom_map::*;
+pub use multi_route::*;
 
+use bevy::log::warn;
+use bevy::math::Vec2;
+use bevy::prelude::Resource;
+use crate::components::Enemy;
 use crate::resources::EnemyPath;
+use serde::{Deserialize, Serialize};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// Marker resource for apps that supply their own `EnemyPath`: levels are not
+/// generated and new runs keep the path as it is
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct FixedLevelPath;
+
 /// Main entry point for generating procedural level paths with time-based variety
 /// Enhanced with obstacles, A* pathfinding, random start/end positioning, and 2x path length requirement
 /// 
@@ -22,26 +44,152 @@ use std::time::{SystemTime, UNIX_EPOCH};
 /// # Returns
 /// * `EnemyPath` - Compatible with existing enemy movement system with varied layouts
 pub fn generate_level_path(wave_number: u32) -> EnemyPath {
-    // Generate time-based seed for map variety each startup
-    let time_seed = generate_startup_seed();
-    let seed = time_seed;
-    
-    // Generate procedural map with obstacles based on wave difficulty
-    let difficulty = (wave_number as f32 / 20.0).min(1.0); // Scales up to wave 20
-    let grid = obstacles::generate_procedural_map_with_random_sides(seed, difficulty);
+    generate_level_path_with_config(wave_number, &LevelConfig::default())
+}
+
+/// Level path for the current seed on the board, routes and void lake of `config`
+///
+/// # Arguments
+/// * `wave_number` - Current wave number (affects difficulty, not seed)
+/// * `config` - Map settings of the current run
+pub fn generate_level_path_with_config(wave_number: u32, config: &LevelConfig) -> EnemyPath {
+    let seed = current_level_seed();
+    let grid = generate_level_grid(wave_number, config);
     
     // Generate strategic path using A* pathfinding around obstacles
-    let grid_path = obstacles::generate_random_strategic_path(seed + 1000, &grid);
+    let grid_path = obstacles::generate_random_strategic_path(seed.wrapping_add(1000), &grid);
     
-    // Convert to world coordinates for enemy movement
-    grid.to_enemy_path(grid_path)
+    // Convert to world coordinates for enemy movement, falling back to a
+    // straight entry-exit route if the generated path is unusable
+    let path = grid.try_to_enemy_path(&grid_path).unwrap_or_else(|error| {
+        warn!("Level path conversion failed ({}), using straight route", error);
+        EnemyPath::new(vec![grid.grid_to_world(grid.entry_point), grid.grid_to_world(grid.exit_point)])
+    });
+
+    // Further routes from other entry points, with their own seed offset
+    let extra_routes = config.routes.clamp(1, MAX_LEVEL_ROUTES) - 1;
+    if extra_routes == 0 {
+        return path;
+    }
+    let routes: Vec<Vec<Vec2>> = multi_route::converging_routes(seed.wrapping_add(9000), &grid, &grid_path, extra_routes)
+        .iter()
+        .filter_map(|route| grid.try_to_enemy_path(route).ok())
+        .map(|route| route.waypoints)
+        .collect();
+    if routes.len() < extra_routes {
+        warn!("Room for {} of {} extra enemy routes on this map", routes.len(), extra_routes);
+    }
+    path.with_extra_routes(routes)
+}
+
+/// Obstacle grid for the current run, shared by path generation and obstacle rendering
+///
+/// # Arguments
+/// * `wave_number` - Current wave number (affects difficulty, not seed)
+/// * `config` - Map settings of the current run
+pub fn generate_level_grid(wave_number: u32, config: &LevelConfig) -> PathGrid {
+    // Time-based startup seed unless a run seed has been chosen since
+    let seed = current_level_seed();
+    
+    // Generate procedural map with obstacles based on wave difficulty and map archetype
+    let difficulty = (wave_number as f32 / 20.0).min(1.0); // Scales up to wave 20
+    let archetype = current_level_archetype();
+    let mut grid = match config.layout {
+        // Chosen maps set their own size and density
+        Some(layout) => obstacles::generate_framed_map(seed, archetype, layout.obstacle_density, layout.width, layout.height),
+        None => obstacles::generate_procedural_map_with_archetype(seed, difficulty, archetype),
+    };
+    
+    // The lake has its own seed offset so the rest of the layout is unchanged
+    if config.void_lake {
+        obstacles::carve_void_lake(&mut grid, seed.wrapping_add(7000));
+    }
+    grid
 }
 
-use std::sync::OnceLock;
+use std::sync::{Mutex, OnceLock};
+
+/// Map archetype used for the current run
+static LEVEL_ARCHETYPE: Mutex<MapArchetype> = Mutex::new(MapArchetype::Classic);
+
+/// Map archetype used by `generate_level_grid` for the current run
+pub fn current_level_archetype() -> MapArchetype {
+    LEVEL_ARCHETYPE
+        .lock()
+        .map(|archetype| *archetype)
+        .unwrap_or_default()
+}
+
+/// Replace the map archetype for subsequent `generate_level_grid` calls
+pub fn set_level_archetype(archetype: MapArchetype) {
+    if let Ok(mut level_archetype) = LEVEL_ARCHETYPE.lock() {
+        *level_archetype = archetype;
+    }
+}
+
+/// Board size and obstacle density for a chosen map, replacing the
+/// full-size board whose density scales with the wave
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LevelLayout {
+    /// Playable width in cells
+    pub width: usize,
+    /// Playable height in cells
+    pub height: usize,
+    /// Share of playable cells blocked by obstacles (0.0-0.5)
+    pub obstacle_density: f32,
+}
+
+/// Map settings of the current run, read by level generation. Kept with the
+/// run so restarts and resumed runs rebuild the same map.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LevelConfig {
+    /// Board of the chosen map, `None` for the default board
+    pub layout: Option<LevelLayout>,
+    /// Enemy routes, the main one included, 1 to `MAX_LEVEL_ROUTES`
+    pub routes: usize,
+    /// Whether the map gets a void lake
+    pub void_lake: bool,
+}
+
+impl Default for LevelConfig {
+    fn default() -> Self {
+        Self {
+            layout: None,
+            routes: 1,
+            void_lake: false,
+        }
+    }
+}
+
+impl LevelConfig {
+    /// Set the number of enemy routes, held to 1..=`MAX_LEVEL_ROUTES`
+    pub fn set_routes(&mut self, routes: usize) {
+        self.routes = routes.clamp(1, MAX_LEVEL_ROUTES);
+    }
+}
 
 /// Global startup seed that's generated once per application run
 static STARTUP_SEED: OnceLock<u64> = OnceLock::new();
 
+/// Seed chosen during the session (e.g. "New Seed" after a run), replacing the startup seed
+static LEVEL_SEED_OVERRIDE: Mutex<Option<u64>> = Mutex::new(None);
+
+/// Seed used by `generate_level_path` for the current run
+pub fn current_level_seed() -> u64 {
+    LEVEL_SEED_OVERRIDE
+        .lock()
+        .ok()
+        .and_then(|seed| *seed)
+        .unwrap_or_else(generate_startup_seed)
+}
+
+/// Replace the level seed for subsequent `generate_level_path` calls
+pub fn set_level_seed(seed: u64) {
+    if let Ok(mut level_seed) = LEVEL_SEED_OVERRIDE.lock() {
+        *level_seed = Some(seed);
+    }
+}
+
 /// Generate a startup-based seed for map variety
 /// Uses system time to ensure different maps each game session, but consistent within session
 fn generate_startup_seed() -> u64 {
@@ -108,7 +256,7 @@ pub fn generate_placement_zones(wave_number: u32) -> Vec<TowerZone> {
     // Mark path cells for zone calculation
     grid.apply_path(&grid_path);
     
-    let mut zones = calculate_optimal_tower_zones(&grid, &grid_path);
+    let mut zones = calculate_exposure_tower_zones(&grid, std::slice::from_ref(&grid_path), ZONE_TOWER_RANGE, Enemy::default().speed);
     
     // Fallback: If no zones generated, create strategic zones around the path
     if zones.is_empty() {
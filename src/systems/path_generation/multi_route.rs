@@ -0,0 +1,73 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use super::grid::{GridPos, PathGrid};
+use super::pathfinding::find_path;
+
+/// Most routes a level can send enemies down, the main one included
+pub const MAX_LEVEL_ROUTES: usize = 3;
+
+/// Routes from up to `count` further entry points to the exit of `route`, for
+/// maps enemies enter from several sides at once.
+///
+/// Entry points are picked from the edges of the playable area, leaving out the
+/// exit's edge and cells on `route`, and kept apart from the main entry and each
+/// other so every route opens a front of its own. The routes run to the same
+/// exit and so converge on it. Fewer routes come back when the map has no room
+/// for more.
+pub fn converging_routes(seed: u64, grid: &PathGrid, route: &[GridPos], count: usize) -> Vec<Vec<GridPos>> {
+    let (Some(&entry), Some(&exit)) = (route.first(), route.last()) else {
+        return Vec::new();
+    };
+    let Some((min, max)) = playable_bounds(grid) else {
+        return Vec::new();
+    };
+    let on_edge = |pos: GridPos| [pos.x == min.x, pos.x == max.x, pos.y == min.y, pos.y == max.y];
+    let exit_edges = on_edge(exit);
+    let min_spacing = ((max.x - min.x) + (max.y - min.y)) as f32 / 4.0;
+
+    let mut candidates: Vec<GridPos> = (min.y..=max.y)
+        .flat_map(|y| (min.x..=max.x).map(move |x| GridPos::new(x, y)))
+        .filter(|&pos| {
+            let edges = on_edge(pos);
+            let sides = edges.iter().filter(|&&edge| edge).count();
+            // Corners open onto two sides at once
+            sides == 1
+                && !edges.iter().zip(exit_edges).any(|(&edge, exit_edge)| edge && exit_edge)
+                && grid.is_traversable(pos)
+                && !route.contains(&pos)
+        })
+        .collect();
+    candidates.shuffle(&mut StdRng::seed_from_u64(seed));
+
+    let mut entries = vec![entry];
+    let mut routes = Vec::new();
+    for candidate in candidates {
+        if routes.len() >= count {
+            break;
+        }
+        if entries.iter().any(|other| other.manhattan_distance(&candidate) < min_spacing) {
+            continue;
+        }
+        if let Some(extra) = find_path(grid, candidate, exit) {
+            entries.push(candidate);
+            routes.push(extra);
+        }
+    }
+    routes
+}
+
+/// Corners of the smallest box holding every traversable cell, which is the
+/// board inside the frame of a smaller map
+fn playable_bounds(grid: &PathGrid) -> Option<(GridPos, GridPos)> {
+    let mut cells = (0..grid.height)
+        .flat_map(|y| (0..grid.width).map(move |x| GridPos::new(x, y)))
+        .filter(|&pos| grid.is_traversable(pos));
+    let first = cells.next()?;
+    Some(cells.fold((first, first), |(min, max), pos| {
+        (
+            GridPos::new(min.x.min(pos.x), min.y.min(pos.y)),
+            GridPos::new(max.x.max(pos.x), max.y.max(pos.y)),
+        )
+    }))
+}
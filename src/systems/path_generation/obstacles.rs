@@ -1,9 +1,14 @@
 use rand::{Rng, SeedableRng};
 use rand::rngs::StdRng;
 use bevy::prelude::*;
-use super::grid::{PathGrid, GridPos, CellType};
+use crate::resources::{GRID_CELL_SIZE, GRID_HEIGHT, GRID_WIDTH};
+use super::grid::{PathGrid, GridPos, CellType, GridError};
 use super::pathfinding::find_path;
 
+/// Smallest playable area `generate_framed_map` builds, so routes still have room to wind
+pub const MIN_FRAMED_WIDTH: usize = 12;
+pub const MIN_FRAMED_HEIGHT: usize = 8;
+
 /// Represents the four sides of the grid for start/end point placement
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum GridSide {
@@ -27,6 +32,77 @@ pub enum ObstacleType {
     Building,  // Structural obstacles with square appearance
     Debris,    // Small scattered obstacles
     Crystal,   // Special decorative obstacles
+    Void,      // Water or chasm that only flyers and stray projectiles enter
+}
+
+/// Generation archetypes that decide how obstacles are laid out on a map
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MapArchetype {
+    /// Strategic obstacle clusters around chokepoints (original generator)
+    #[default]
+    Classic,
+    /// Few scattered obstacles with long sightlines
+    OpenField,
+    /// Dense wall segments forming corridors
+    Maze,
+    /// Obstacle rings enclosing separate, clustered build zones
+    Islands,
+}
+
+impl MapArchetype {
+    pub const ALL: [MapArchetype; 4] = [
+        MapArchetype::Classic,
+        MapArchetype::OpenField,
+        MapArchetype::Maze,
+        MapArchetype::Islands,
+    ];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            MapArchetype::Classic => "Classic",
+            MapArchetype::OpenField => "Open Field",
+            MapArchetype::Maze => "Maze",
+            MapArchetype::Islands => "Islands",
+        }
+    }
+
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            MapArchetype::Classic => "Obstacle clusters around natural chokepoints",
+            MapArchetype::OpenField => "Few obstacles, long sightlines",
+            MapArchetype::Maze => "Dense corridors and winding lanes",
+            MapArchetype::Islands => "Build zones clustered between obstacle channels",
+        }
+    }
+
+    /// Next archetype in `ALL`, wrapping around (for cycling through them in UI)
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|archetype| archetype == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    /// Target share of blocked cells for this archetype at the given difficulty (0.0-1.0)
+    pub fn obstacle_density(&self, difficulty: f32) -> f32 {
+        let difficulty = difficulty.clamp(0.0, 1.0);
+        match self {
+            MapArchetype::Classic => (difficulty * 0.2).min(0.15),
+            MapArchetype::OpenField => (0.02 + difficulty * 0.04).min(0.05),
+            MapArchetype::Maze => (0.18 + difficulty * 0.12).min(0.28),
+            MapArchetype::Islands => (0.10 + difficulty * 0.08).min(0.16),
+        }
+    }
+}
+
+/// Summary of a generated map used for previews and strategic analysis
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapAnalysis {
+    pub archetype: MapArchetype,
+    /// Share of cells blocked by obstacles (0.0-1.0)
+    pub obstacle_coverage: f32,
+    /// Number of separate connected build areas
+    pub buildable_regions: usize,
+    /// Cell count of the largest connected build area
+    pub largest_region: usize,
 }
 
 /// Generate random start and end points on opposite sides of the grid
@@ -158,6 +234,23 @@ pub fn generate_procedural_map(seed: u64, difficulty: f32) -> PathGrid {
 /// # Returns
 /// * `PathGrid` - Generated map with obstacles and randomized entry/exit points
 pub fn generate_procedural_map_with_random_sides(seed: u64, difficulty: f32) -> PathGrid {
+    generate_procedural_map_with_archetype(seed, difficulty, MapArchetype::Classic)
+}
+
+/// Generate a procedural map with random start/end sides using the obstacle layout of an archetype
+///
+/// # Arguments
+/// * `seed` - Random seed for reproducible generation
+/// * `difficulty` - Difficulty factor (0.0 = easy, 1.0 = hard), scales obstacle density
+/// * `archetype` - Obstacle placement strategy
+///
+/// # Returns
+/// * `PathGrid` - Generated map with obstacles and randomized entry/exit points
+pub fn generate_procedural_map_with_archetype(seed: u64, difficulty: f32, archetype: MapArchetype) -> PathGrid {
+    if archetype != MapArchetype::Classic {
+        return generate_archetype_map(seed, difficulty, archetype);
+    }
+
     let mut rng = StdRng::seed_from_u64(seed);
     let mut grid = PathGrid::new_unified(); // Use dense unified 32x18 grid
     
@@ -231,6 +324,289 @@ pub fn generate_procedural_map_with_density(seed: u64, obstacle_density: f32) ->
     grid
 }
 
+/// Non-classic archetypes: place obstacles with the archetype's strategy and only
+/// require that a path exists (the layouts already produce long, varied routes)
+fn generate_archetype_map(seed: u64, difficulty: f32, archetype: MapArchetype) -> PathGrid {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut grid = PathGrid::new_unified(); // Use dense unified 32x18 grid
+
+    let (entry_point, exit_point) = generate_random_opposite_points(&mut rng, grid.width, grid.height);
+    grid.entry_point = entry_point;
+    grid.exit_point = exit_point;
+
+    place_archetype_obstacles(&mut grid, &mut rng, archetype, archetype.obstacle_density(difficulty));
+
+    let mut attempts = 0;
+    while find_path(&grid, grid.entry_point, grid.exit_point).is_none() && attempts < 10 {
+        reduce_obstacles(&mut grid, &mut rng, 0.1);
+        attempts += 1;
+    }
+
+    grid
+}
+
+/// Place obstacles with the archetype's strategy at the given density
+fn place_archetype_obstacles(grid: &mut PathGrid, rng: &mut StdRng, archetype: MapArchetype, density: f32) {
+    match archetype {
+        MapArchetype::Classic => place_strategic_obstacles_with_validation(grid, rng, density),
+        MapArchetype::OpenField => place_scattered_obstacles(grid, rng, density),
+        MapArchetype::Maze => place_maze_walls(grid, rng, density),
+        MapArchetype::Islands => place_island_rings(grid, rng, density),
+    }
+}
+
+/// Generate a map with a playable area smaller than the unified grid, framed
+/// in its middle. The cells around the playable area are blocked, so paths
+/// and towers stay inside it while world coordinates match every other map.
+///
+/// # Arguments
+/// * `seed` - Random seed for reproducible generation
+/// * `archetype` - Obstacle placement strategy
+/// * `obstacle_density` - Share of playable cells to block (0.0-0.5)
+/// * `width`, `height` - Playable area in cells, clamped to the unified grid
+pub fn generate_framed_map(seed: u64, archetype: MapArchetype, obstacle_density: f32, width: usize, height: usize) -> PathGrid {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut grid = PathGrid::new(width.clamp(MIN_FRAMED_WIDTH, GRID_WIDTH), height.clamp(MIN_FRAMED_HEIGHT, GRID_HEIGHT));
+
+    let (entry_point, exit_point) = generate_random_opposite_points(&mut rng, grid.width, grid.height);
+    grid.entry_point = entry_point;
+    grid.exit_point = exit_point;
+
+    place_archetype_obstacles(&mut grid, &mut rng, archetype, obstacle_density.clamp(0.0, 0.5));
+
+    let mut attempts = 0;
+    while find_path(&grid, grid.entry_point, grid.exit_point).is_none() && attempts < 10 {
+        reduce_obstacles(&mut grid, &mut rng, 0.1);
+        attempts += 1;
+    }
+
+    frame_in_unified_grid(&grid)
+}
+
+/// Copy a grid into the middle of a unified grid, blocking every cell around it
+pub fn frame_in_unified_grid(inner: &PathGrid) -> PathGrid {
+    let mut framed = PathGrid::new_unified();
+    if inner.width > framed.width || inner.height > framed.height {
+        return inner.clone();
+    }
+    let offset_x = (framed.width - inner.width) / 2;
+    let offset_y = (framed.height - inner.height) / 2;
+
+    for y in 0..framed.height {
+        for x in 0..framed.width {
+            framed.set_cell(GridPos::new(x, y), CellType::Blocked);
+        }
+    }
+    for y in 0..inner.height {
+        for x in 0..inner.width {
+            let cell = inner.get_cell(GridPos::new(x, y)).unwrap_or_default();
+            framed.set_cell(GridPos::new(x + offset_x, y + offset_y), cell);
+        }
+    }
+    framed.entry_point = GridPos::new(inner.entry_point.x + offset_x, inner.entry_point.y + offset_y);
+    framed.exit_point = GridPos::new(inner.exit_point.x + offset_x, inner.exit_point.y + offset_y);
+    framed
+}
+
+/// Block `cells` only if every one is empty and the entry/exit stay connected
+fn try_block_cells(grid: &mut PathGrid, cells: &[GridPos]) -> bool {
+    if cells.iter().any(|&pos| grid.get_cell(pos) != Some(CellType::Empty)) {
+        return false;
+    }
+
+    let old_grid = grid.clone();
+    for &pos in cells {
+        grid.set_cell(pos, CellType::Blocked);
+    }
+
+    if find_path(grid, grid.entry_point, grid.exit_point).is_some() {
+        true
+    } else {
+        *grid = old_grid;
+        false
+    }
+}
+
+/// Open field: single obstacles spread across the whole map
+fn place_scattered_obstacles(grid: &mut PathGrid, rng: &mut StdRng, density: f32) {
+    let target_obstacles = (grid.width * grid.height) as f32 * density;
+    let mut placed = 0;
+
+    for _ in 0..(target_obstacles as usize * 4) {
+        if placed as f32 >= target_obstacles {
+            break;
+        }
+        let pos = GridPos::new(
+            rng.random_range(2..grid.width - 2),
+            rng.random_range(1..grid.height - 1),
+        );
+        if try_block_cells(grid, &[pos]) {
+            placed += 1;
+        }
+    }
+}
+
+/// Maze: straight wall segments that form corridors
+fn place_maze_walls(grid: &mut PathGrid, rng: &mut StdRng, density: f32) {
+    let target_obstacles = (grid.width * grid.height) as f32 * density;
+    let mut placed = 0;
+
+    for _ in 0..(target_obstacles as usize * 2) {
+        if placed as f32 >= target_obstacles {
+            break;
+        }
+
+        let length = rng.random_range(3..=6);
+        let horizontal = rng.random::<bool>();
+        let start = GridPos::new(
+            rng.random_range(2..grid.width - 2),
+            rng.random_range(1..grid.height - 1),
+        );
+
+        let wall: Vec<GridPos> = (0..length)
+            .map(|i| if horizontal {
+                GridPos::new(start.x + i, start.y)
+            } else {
+                GridPos::new(start.x, start.y + i)
+            })
+            .filter(|pos| pos.x < grid.width - 1 && pos.y < grid.height - 1)
+            .collect();
+
+        if try_block_cells(grid, &wall) {
+            placed += wall.len();
+        }
+    }
+}
+
+/// Islands: rings of obstacles enclosing small build zones, so tower space
+/// comes in separate clusters instead of one open field
+fn place_island_rings(grid: &mut PathGrid, rng: &mut StdRng, density: f32) {
+    let target_obstacles = (grid.width * grid.height) as f32 * density;
+    let mut placed = 0;
+
+    for _ in 0..60 {
+        if placed as f32 >= target_obstacles {
+            break;
+        }
+
+        // Interior of 2-4 x 2-3 buildable cells, surrounded by a one-cell ring
+        let inner_width = rng.random_range(2..=4);
+        let inner_height = rng.random_range(2..=3);
+        let left = rng.random_range(2..grid.width - inner_width - 3);
+        let bottom = rng.random_range(1..grid.height - inner_height - 2);
+        let right = left + inner_width + 1;
+        let top = bottom + inner_height + 1;
+
+        let interior_clear = (left + 1..right)
+            .flat_map(|x| (bottom + 1..top).map(move |y| GridPos::new(x, y)))
+            .all(|pos| grid.get_cell(pos) == Some(CellType::Empty));
+        if !interior_clear {
+            continue;
+        }
+
+        let ring: Vec<GridPos> = (left..=right)
+            .flat_map(|x| (bottom..=top).map(move |y| GridPos::new(x, y)))
+            .filter(|pos| pos.x == left || pos.x == right || pos.y == bottom || pos.y == top)
+            .collect();
+
+        if try_block_cells(grid, &ring) {
+            placed += ring.len();
+        }
+    }
+}
+
+/// Carve an elliptical void lake into the empty interior of `grid`. Lakes that
+/// would cut the entry off from the exit are dropped and another spot is tried.
+///
+/// # Returns
+/// * Number of cells turned into void (0 if no spot kept the route open)
+pub fn carve_void_lake(grid: &mut PathGrid, seed: u64) -> usize {
+    if grid.width < 12 || grid.height < 8 {
+        return 0;
+    }
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    for _ in 0..8 {
+        let radius_x = rng.random_range(2..=4) as f32;
+        let radius_y = rng.random_range(2..=3) as f32;
+        let center = Vec2::new(
+            rng.random_range(5..grid.width - 5) as f32,
+            rng.random_range(3..grid.height - 3) as f32,
+        );
+
+        let lake: Vec<GridPos> = (0..grid.height)
+            .flat_map(|y| (0..grid.width).map(move |x| GridPos::new(x, y)))
+            .filter(|pos| {
+                let offset = Vec2::new(pos.x as f32, pos.y as f32) - center;
+                (offset.x / radius_x).powi(2) + (offset.y / radius_y).powi(2) <= 1.0
+            })
+            .filter(|&pos| grid.get_cell(pos) == Some(CellType::Empty))
+            .filter(|&pos| pos != grid.entry_point && pos != grid.exit_point)
+            .collect();
+        if lake.is_empty() {
+            continue;
+        }
+
+        let old_grid = grid.clone();
+        for &pos in &lake {
+            grid.set_cell(pos, CellType::Void);
+        }
+        if find_path(grid, grid.entry_point, grid.exit_point).is_some() {
+            return lake.len();
+        }
+        *grid = old_grid;
+    }
+
+    0
+}
+
+/// Analyze a generated map: obstacle coverage and connected build areas
+pub fn analyze_map(grid: &PathGrid, archetype: MapArchetype) -> MapAnalysis {
+    let total_cells = grid.width * grid.height;
+    let mut blocked = 0;
+    let mut visited = vec![vec![false; grid.width]; grid.height];
+    let mut buildable_regions = 0;
+    let mut largest_region = 0;
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let pos = GridPos::new(x, y);
+            match grid.get_cell(pos) {
+                Some(CellType::Blocked) => blocked += 1,
+                Some(CellType::Empty) | Some(CellType::TowerZone) if !visited[y][x] => {
+                    // Flood fill this build area
+                    let mut region_size = 0;
+                    let mut stack = vec![pos];
+                    visited[y][x] = true;
+                    while let Some(current) = stack.pop() {
+                        region_size += 1;
+                        for neighbor in current.neighbors(grid.width, grid.height) {
+                            let buildable = matches!(
+                                grid.get_cell(neighbor),
+                                Some(CellType::Empty) | Some(CellType::TowerZone)
+                            );
+                            if buildable && !visited[neighbor.y][neighbor.x] {
+                                visited[neighbor.y][neighbor.x] = true;
+                                stack.push(neighbor);
+                            }
+                        }
+                    }
+                    buildable_regions += 1;
+                    largest_region = largest_region.max(region_size);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    MapAnalysis {
+        archetype,
+        obstacle_coverage: blocked as f32 / total_cells.max(1) as f32,
+        buildable_regions,
+        largest_region,
+    }
+}
+
 /// Place obstacles strategically to create interesting chokepoints with A* validation
 fn place_strategic_obstacles_with_validation(grid: &mut PathGrid, rng: &mut StdRng, density: f32) {
     let total_cells = grid.width * grid.height;
@@ -538,9 +914,32 @@ fn calculate_center_position_bonus(grid: &PathGrid, pos: GridPos) -> f32 {
     center_ratio.max(0.0).min(1.0)
 }
 
+/// Smallest grid width the strategic path generator can lay waypoints on
+pub const MIN_GENERATION_WIDTH: usize = 12;
+/// Smallest grid height the strategic path generator can lay waypoints on
+pub const MIN_GENERATION_HEIGHT: usize = 8;
+
+/// Check that a grid can be used for strategic path generation: large enough
+/// for waypoint detours, cell storage matching its dimensions, and entry/exit
+/// points inside the grid
+pub fn validate_generation_grid(grid: &PathGrid) -> Result<(), GridError> {
+    let invalid = GridError::InvalidDimensions { width: grid.width, height: grid.height };
+    if grid.width < MIN_GENERATION_WIDTH || grid.height < MIN_GENERATION_HEIGHT {
+        return Err(invalid);
+    }
+    if grid.cells.len() != grid.height || grid.cells.iter().any(|row| row.len() != grid.width) {
+        return Err(invalid);
+    }
+    grid.check_bounds(grid.entry_point)?;
+    grid.check_bounds(grid.exit_point)
+}
+
 /// Generate a random path with strategic obstacles and A* pathfinding
 /// Enhanced to use A* pathfinding around obstacles with 2x length requirement
 /// 
+/// Never panics: grids that fail `validate_generation_grid` get a direct
+/// entry-to-exit route instead (see `try_generate_random_strategic_path`)
+/// 
 /// # Arguments
 /// * `seed` - Random seed for reproducible generation
 /// * `grid` - The grid with obstacles already placed
@@ -548,16 +947,28 @@ fn calculate_center_position_bonus(grid: &PathGrid, pos: GridPos) -> f32 {
 /// # Returns
 /// * `Vec<GridPos>` - A* calculated path around obstacles meeting length requirement
 pub fn generate_random_strategic_path(seed: u64, grid: &PathGrid) -> Vec<GridPos> {
-    let mut rng = StdRng::seed_from_u64(seed);
+    match try_generate_random_strategic_path(seed, grid) {
+        Ok(path) => path,
+        Err(error) => {
+            warn!("Strategic path generation failed ({}), using direct route", error);
+            generate_direct_path(grid)
+        }
+    }
+}
+
+/// Fallible version of `generate_random_strategic_path`
+/// 
+/// # Returns
+/// * `Err(GridError)` - The grid is unusable for generation (see `validate_generation_grid`)
+pub fn try_generate_random_strategic_path(seed: u64, grid: &PathGrid) -> Result<Vec<GridPos>, GridError> {
+    validate_generation_grid(grid)?;
     
-    // Ensure we're working with proper dense unified grid dimensions
-    assert_eq!(grid.width, 32, "Expected dense unified grid width of 32");
-    assert_eq!(grid.height, 18, "Expected dense unified grid height of 18");
+    let mut rng = StdRng::seed_from_u64(seed);
     
     // First, try to find A* path with existing obstacles
     if let Some(path) = find_path(grid, grid.entry_point, grid.exit_point) {
         if validate_path_length_requirement(&path, grid) {
-            return path;
+            return Ok(path);
         }
     }
     
@@ -604,13 +1015,27 @@ pub fn generate_random_strategic_path(seed: u64, grid: &PathGrid) -> Vec<GridPos
     
     // Validate final path
     if !final_path.is_empty() && validate_path_length_requirement(&final_path, grid) {
-        final_path
+        Ok(final_path)
     } else {
         // Ultimate fallback
-        generate_fallback_path(grid.entry_point, grid.exit_point, grid)
+        Ok(generate_fallback_path(grid.entry_point, grid.exit_point, grid))
     }
 }
 
+/// Recoverable fallback for grids that cannot host strategic generation:
+/// A* between entry and exit if possible, otherwise whichever endpoints lie
+/// inside the grid
+fn generate_direct_path(grid: &PathGrid) -> Vec<GridPos> {
+    if let Some(path) = find_path(grid, grid.entry_point, grid.exit_point) {
+        return path;
+    }
+    
+    [grid.entry_point, grid.exit_point]
+        .into_iter()
+        .filter(|&pos| grid.contains(pos))
+        .collect()
+}
+
 /// Validate that a strategic path meets all requirements
 fn validate_strategic_path(path: &[GridPos], grid: &PathGrid) -> bool {
     if path.len() < 5 {  // Start + 3-5 turns + End = at least 5 points
@@ -942,6 +1367,8 @@ pub fn create_obstacle_entities(
                 };
                 
                 spawn_obstacle_sprite(commands, world_pos, pos, obstacle_type);
+            } else if grid.is_void(pos) {
+                spawn_obstacle_sprite(commands, grid.grid_to_world(pos), pos, ObstacleType::Void);
             }
         }
     }
@@ -959,9 +1386,10 @@ fn spawn_obstacle_sprite(
         ObstacleType::Building => (Color::srgb(0.6, 0.6, 0.7), 0.95), // Gray, full size
         ObstacleType::Debris => (Color::srgb(0.5, 0.4, 0.3), 0.7),    // Dark brown, small
         ObstacleType::Crystal => (Color::srgb(0.3, 0.5, 0.8), 0.8),   // Blue, medium
+        ObstacleType::Void => (Color::srgb(0.05, 0.1, 0.25), 1.0),    // Deep blue, fills the cell
     };
     
-    let sprite_size = 40.0 * size_factor; // Scale based on grid cell size
+    let sprite_size = GRID_CELL_SIZE * size_factor; // Scale based on grid cell size
     
     commands.spawn((
         Sprite {
@@ -1,6 +1,6 @@
 use std::collections::{HashMap, BinaryHeap};
 use std::cmp::Ordering;
-use super::grid::{PathGrid, GridPos};
+use super::grid::{CellType, PathGrid, GridPos};
 
 /// A* pathfinding node for priority queue
 #[derive(Debug, Clone)]
@@ -69,6 +69,38 @@ impl Ord for PathNode {
 /// * `Some(Vec<GridPos>)` - Path from start to goal if found
 /// * `None` - No path exists
 pub fn find_path(grid: &PathGrid, start: GridPos, goal: GridPos) -> Option<Vec<GridPos>> {
+    find_path_with_costs(grid, start, goal, |_| 0.0)
+}
+
+/// Find a path for flying enemies, which cross void cells as if they were open ground
+pub fn find_flight_path(grid: &PathGrid, start: GridPos, goal: GridPos) -> Option<Vec<GridPos>> {
+    let mut sky = grid.clone();
+    for cell in sky.cells.iter_mut().flatten() {
+        if *cell == CellType::Void {
+            *cell = CellType::Empty;
+        }
+    }
+    find_path(&sky, start, goal)
+}
+
+/// Find the cheapest path using A* with an extra cost for entering each cell
+///
+/// # Arguments
+/// * `grid` - The pathfinding grid
+/// * `start` - Starting grid position
+/// * `goal` - Goal grid position
+/// * `cell_cost` - Non-negative cost added to every step into a cell; an infinite
+///   cost makes the cell impassable
+///
+/// # Returns
+/// * `Some(Vec<GridPos>)` - Path from start to goal if found
+/// * `None` - No path exists
+pub fn find_path_with_costs(
+    grid: &PathGrid,
+    start: GridPos,
+    goal: GridPos,
+    cell_cost: impl Fn(GridPos) -> f32,
+) -> Option<Vec<GridPos>> {
     // Early validation
     if !grid.is_traversable(start) || !grid.is_traversable(goal) {
         return None;
@@ -98,11 +130,14 @@ pub fn find_path(grid: &PathGrid, start: GridPos, goal: GridPos) -> Option<Vec<G
         
         // Check neighbors
         for neighbor in current.neighbors(grid.width, grid.height) {
-            if !grid.is_traversable(neighbor) {
+            let extra_cost = cell_cost(neighbor);
+            if !grid.is_traversable(neighbor) || !extra_cost.is_finite() {
                 continue;
             }
             
-            let tentative_g_score = g_score[&current] + 1.0; // All moves cost 1
+            // Every move costs 1 plus whatever the cost layer adds, so the
+            // Manhattan heuristic stays admissible
+            let tentative_g_score = g_score[&current] + 1.0 + extra_cost.max(0.0);
             
             let neighbor_g_score = g_score.get(&neighbor).copied().unwrap_or(f32::INFINITY);
             
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
-use crate::resources::{AppState, GameSystemSet};
+use crate::resources::{AppState, GameSystemSet, SettingsReturnState};
+use crate::systems::input::{InputContext, InputRegistryAppExt, KeyCapture};
+use crate::systems::map_share_system::{MapShareRequest, MapShareStatusText};
 
 // ============================================================================
 // PAUSE MENU COMPONENTS
@@ -20,6 +22,8 @@ pub struct PauseButton {
 pub enum PauseMenuAction {
     Resume,
     Settings,
+    ExportMap,
+    ImportMap,
     Exit,
 }
 
@@ -90,7 +94,7 @@ pub fn setup_pause_menu(mut commands: Commands) {
         parent.spawn((
             Node {
                 width: Val::Px(400.0),
-                height: Val::Px(500.0),
+                height: Val::Px(700.0),
                 flex_direction: FlexDirection::Column,
                 justify_content: JustifyContent::Center,
                 align_items: AlignItems::Center,
@@ -124,9 +128,24 @@ pub fn setup_pause_menu(mut commands: Commands) {
             // Settings button
             create_pause_button(parent, "SETTINGS", PauseMenuAction::Settings, UIColors::TEXT_INFO);
             
+            // Map sharing buttons
+            create_pause_button(parent, "EXPORT MAP", PauseMenuAction::ExportMap, UIColors::TEXT_ACCENT);
+            create_pause_button(parent, "PLAY SHARED MAP", PauseMenuAction::ImportMap, UIColors::TEXT_ACCENT);
+            
             // Exit button
             create_pause_button(parent, "EXIT GAME", PauseMenuAction::Exit, UIColors::TEXT_ERROR);
             
+            // Result of the last map export or import
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_SECONDARY),
+                MapShareStatusText,
+            ));
+            
             // Keyboard shortcut hint
             parent.spawn((
                 Text::new("Press ESC to resume"),
@@ -192,7 +211,13 @@ pub fn pause_toggle_system(
     keyboard_input: Res<ButtonInput<KeyCode>>,
     current_state: Res<State<AppState>>,
     mut next_state: ResMut<NextState<AppState>>,
+    settings_return: Res<SettingsReturnState>,
+    key_capture: Option<Res<KeyCapture>>,
 ) {
+    // Escape cancels waiting for a new key binding rather than leaving the settings
+    if key_capture.is_some_and(|capture| capture.is_active() || capture.is_changed()) {
+        return;
+    }
     if keyboard_input.just_pressed(KeyCode::Escape) {
         match current_state.get() {
             AppState::Playing => {
@@ -204,9 +229,12 @@ pub fn pause_toggle_system(
                 info!("Game resumed");
             }
             AppState::Settings => {
-                // From settings, go back to pause menu
-                next_state.set(AppState::Paused);
-                info!("Returned to pause menu from settings");
+                // From settings, go back to the menu that opened them
+                next_state.set(settings_return.0);
+                info!("Returned to {:?} from settings", settings_return.0);
+            }
+            AppState::MainMenu | AppState::GameOver | AppState::Editor => {
+                // Nothing to pause; these screens have their own buttons
             }
         }
     }
@@ -235,6 +263,8 @@ pub fn pause_menu_button_system(
         (Changed<Interaction>, With<Button>),
     >,
     mut next_state: ResMut<NextState<AppState>>,
+    mut settings_return: ResMut<SettingsReturnState>,
+    mut map_share: EventWriter<MapShareRequest>,
     mut exit: EventWriter<AppExit>,
 ) {
     for (interaction, mut bg_color, mut border_color, pause_button) in &mut interaction_query {
@@ -246,9 +276,18 @@ pub fn pause_menu_button_system(
                         info!("Resume button pressed");
                     }
                     PauseMenuAction::Settings => {
+                        settings_return.0 = AppState::Paused;
                         next_state.set(AppState::Settings);
                         info!("Settings button pressed");
                     }
+                    PauseMenuAction::ExportMap => {
+                        map_share.write(MapShareRequest::Export);
+                        info!("Export map button pressed");
+                    }
+                    PauseMenuAction::ImportMap => {
+                        map_share.write(MapShareRequest::Import);
+                        info!("Play shared map button pressed");
+                    }
                     PauseMenuAction::Exit => {
                         info!("Exit button pressed");
                         exit.write(AppExit::Success);
@@ -274,11 +313,12 @@ pub fn time_scale_system(
 ) {
     if app_state.is_changed() {
         match app_state.get() {
-            AppState::Playing => {
+            // The results screen animates on game time, with nothing left to simulate
+            AppState::Playing | AppState::GameOver => {
                 time.unpause();
                 info!("Game time resumed");
             }
-            AppState::Paused | AppState::Settings => {
+            AppState::Paused | AppState::Settings | AppState::MainMenu | AppState::Editor => {
                 time.pause();
                 info!("Game time paused");
             }
@@ -296,6 +336,9 @@ impl Plugin for PauseSystemPlugin {
     fn build(&self, app: &mut App) {
         app
             .init_state::<AppState>()
+            .init_resource::<SettingsReturnState>()
+            .add_event::<MapShareRequest>()
+            .register_key_hint(KeyCode::Escape, "Pause menu", InputContext::System)
             .add_systems(Startup, setup_pause_menu)
             .add_systems(
                 Update,
@@ -0,0 +1,211 @@
+use bevy::prelude::*;
+use crate::components::Enemy;
+use crate::resources::{AppState, EnemyPath, GameConstants, GameSystemSet, TowerStats, TowerType, WavePlan};
+use crate::systems::advisor_system::route_cells;
+use crate::systems::input_system::{get_placement_position, MouseInputState};
+use crate::systems::obstacle_rendering::ObstacleGrid;
+use crate::systems::path_generation::{ExposureField, TravelTimeField};
+use crate::systems::settings_menu::GameSettings;
+use crate::systems::tower_ui::TowerSelectionState;
+use crate::systems::unified_grid::UnifiedGridSystem;
+
+/// Opacity of the tint on the cells with the longest exposure
+const ASSIST_MAX_ALPHA: f32 = 0.35;
+const ASSIST_COLOR: Color = Color::srgb(0.2, 0.9, 1.0);
+const ESTIMATE_COLOR: Color = Color::srgb(0.85, 1.0, 0.9);
+
+// ============================================================================
+// ESTIMATE
+// ============================================================================
+
+/// Damage a tower deals to each enemy walking past it
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlacementEstimate {
+    pub dps: f32,
+    /// Seconds each enemy spends in range
+    pub exposure_seconds: f32,
+    pub damage_per_enemy: f32,
+}
+
+impl PlacementEstimate {
+    pub fn new(stats: &TowerStats, exposure_seconds: f32) -> Self {
+        let dps = stats.damage * stats.fire_rate;
+        Self {
+            dps,
+            exposure_seconds,
+            damage_per_enemy: dps * exposure_seconds,
+        }
+    }
+
+    /// Label shown above the placement preview, e.g. "48 dmg/enemy"
+    pub fn label(&self) -> String {
+        format!(
+            "{:.0} dmg/enemy\n{:.1} DPS x {:.1}s in range",
+            self.damage_per_enemy, self.dps, self.exposure_seconds
+        )
+    }
+}
+
+// ============================================================================
+// STATE
+// ============================================================================
+
+/// Enemy travel times and tower exposure for the tower type being placed
+#[derive(Resource, Debug, Default)]
+pub struct PlacementAssist {
+    /// Tower type the fields were worked out for; `None` outside placement
+    pub tower_type: Option<TowerType>,
+    /// Enemy speed the travel times assume
+    pub enemy_speed: f32,
+    pub travel: TravelTimeField,
+    pub exposure: ExposureField,
+}
+
+/// Marker for the cell tints of the assist overlay
+#[derive(Component)]
+pub struct PlacementAssistTile;
+
+/// Marker for the damage estimate above the placement preview
+#[derive(Component)]
+pub struct PlacementEstimateText;
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// System to rebuild the fields when the tower being placed, the map, the path
+/// or the next wave's enemy speed changes
+pub fn update_placement_assist_system(
+    selection_state: Res<TowerSelectionState>,
+    enemy_path: Res<EnemyPath>,
+    obstacle_grid: Res<ObstacleGrid>,
+    unified_grid: Res<UnifiedGridSystem>,
+    wave_plan: Res<WavePlan>,
+    mut assist: ResMut<PlacementAssist>,
+) {
+    let tower_type = selection_state
+        .selected_placement_type
+        .filter(|_| selection_state.is_placement_mode());
+    let enemy_speed = Enemy::for_wave(wave_plan.next_wave()).speed;
+    let unchanged = tower_type == assist.tower_type
+        && enemy_speed == assist.enemy_speed
+        && !enemy_path.is_changed()
+        && !obstacle_grid.is_changed();
+    if unchanged {
+        return;
+    }
+
+    assist.tower_type = tower_type;
+    assist.enemy_speed = enemy_speed;
+    let Some(tower_type) = tower_type else {
+        assist.travel = TravelTimeField::default();
+        assist.exposure = ExposureField::default();
+        return;
+    };
+
+    let routes = route_cells(&enemy_path, &unified_grid);
+    assist.travel = TravelTimeField::from_routes(&obstacle_grid.grid, &routes, enemy_speed);
+    assist.exposure = ExposureField::new(&obstacle_grid.grid, &assist.travel, TowerStats::new(tower_type).range);
+}
+
+/// System to tint buildable cells by how long enemies stay in range of them,
+/// unless the overlay is turned off in the settings
+pub fn placement_assist_overlay_system(
+    mut commands: Commands,
+    assist: Res<PlacementAssist>,
+    obstacle_grid: Res<ObstacleGrid>,
+    settings: Option<Res<GameSettings>>,
+    tiles: Query<Entity, With<PlacementAssistTile>>,
+) {
+    let settings_changed = settings.as_ref().is_some_and(|settings| settings.is_changed());
+    if !assist.is_changed() && !settings_changed {
+        return;
+    }
+
+    for entity in tiles.iter() {
+        commands.entity(entity).despawn();
+    }
+    let overlay_enabled = settings.as_ref().is_none_or(|settings| settings.assist_overlay_enabled);
+    if assist.tower_type.is_none() || !overlay_enabled {
+        return;
+    }
+
+    let grid = &obstacle_grid.grid;
+    let best = assist.exposure.max();
+    for (cell, seconds) in assist.exposure.ranked_cells() {
+        commands.spawn((
+            Sprite {
+                color: ASSIST_COLOR.with_alpha(ASSIST_MAX_ALPHA * seconds / best),
+                custom_size: Some(Vec2::splat(grid.cell_size)),
+                ..default()
+            },
+            Transform::from_translation(grid.grid_to_world(cell).extend(0.3)),
+            PlacementAssistTile,
+        ));
+    }
+}
+
+/// System to show the damage estimate for the spot under the cursor
+pub fn placement_estimate_system(
+    mut commands: Commands,
+    assist: Res<PlacementAssist>,
+    mouse_state: Res<MouseInputState>,
+    unified_grid: Res<UnifiedGridSystem>,
+    obstacle_grid: Res<ObstacleGrid>,
+    constants: Res<GameConstants>,
+    mut estimate_query: Query<(Entity, &mut Text2d, &mut Transform), With<PlacementEstimateText>>,
+) {
+    let Some(tower_type) = assist.tower_type else {
+        for (entity, _, _) in estimate_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+
+    let position = get_placement_position(mouse_state.world_position, mouse_state.placement_mode, &unified_grid);
+    let stats = TowerStats::new(tower_type);
+    let exposure_seconds = assist.travel.exposure_at(&obstacle_grid.grid, position, stats.range);
+    let label = PlacementEstimate::new(&stats, exposure_seconds).label();
+    let translation = (position + Vec2::new(0.0, constants.tower_footprint)).extend(6.0);
+
+    if let Ok((_, mut text, mut transform)) = estimate_query.single_mut() {
+        if **text != label {
+            **text = label;
+        }
+        transform.translation = translation;
+    } else {
+        commands.spawn((
+            Text2d::new(label),
+            TextFont {
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(ESTIMATE_COLOR),
+            Transform::from_translation(translation),
+            PlacementEstimateText,
+        ));
+    }
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin to highlight where the tower being placed keeps enemies in range longest
+pub struct PlacementAssistPlugin;
+
+impl Plugin for PlacementAssistPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlacementAssist>().add_systems(
+            Update,
+            (
+                update_placement_assist_system,
+                placement_assist_overlay_system,
+                placement_estimate_system,
+            )
+                .chain()
+                .in_set(GameSystemSet::Gameplay)
+                .run_if(in_state(AppState::Playing)),
+        );
+    }
+}
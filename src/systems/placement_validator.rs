@@ -0,0 +1,180 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use crate::resources::*;
+use crate::systems::input_system::distance_to_line_segment;
+use crate::systems::obstacle_rendering::ObstacleGrid;
+use crate::systems::occupancy::{Occupant, OccupancyMap};
+use crate::systems::path_generation::grid::{CellType, GridPos, PathGrid};
+use crate::systems::unified_grid::{grid_to_world, world_to_grid, UnifiedGridSystem};
+
+/// Outcome of checking whether a tower can be built somewhere
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlacementVerdict {
+    Buildable,
+    /// On a path cell or too close to the enemy path
+    OnPath,
+    /// On an obstacle
+    Blocked,
+    /// Over a void cell
+    OverVoid,
+    /// Overlapping an existing tower
+    Occupied,
+    /// Outside the buildable grid
+    OutOfZone,
+    /// The site is fine but the tower can't be paid for
+    InsufficientFunds,
+}
+
+impl PlacementVerdict {
+    pub fn is_buildable(&self) -> bool {
+        *self == PlacementVerdict::Buildable
+    }
+
+    pub fn get_reason(&self) -> &'static str {
+        match self {
+            PlacementVerdict::Buildable => "Buildable",
+            PlacementVerdict::OnPath => "Too close to the enemy path",
+            PlacementVerdict::Blocked => "Blocked by an obstacle",
+            PlacementVerdict::OverVoid => "Nothing to build on over the void",
+            PlacementVerdict::Occupied => "Another tower is in the way",
+            PlacementVerdict::OutOfZone => "Outside the build area",
+            PlacementVerdict::InsufficientFunds => "Not enough resources",
+        }
+    }
+}
+
+/// Verdict for a path grid cell on its own, ignoring towers and funds
+pub fn terrain_verdict(path_grid: Option<&PathGrid>, cell: GridPos) -> PlacementVerdict {
+    match path_grid.and_then(|path_grid| path_grid.get_cell(cell)) {
+        Some(CellType::Path) => PlacementVerdict::OnPath,
+        Some(CellType::Blocked) => PlacementVerdict::Blocked,
+        Some(CellType::Void) => PlacementVerdict::OverVoid,
+        // Empty cells, tower zones and cells outside the path grid are buildable
+        Some(CellType::Empty) | Some(CellType::TowerZone) | None => PlacementVerdict::Buildable,
+    }
+}
+
+/// Single source of truth for tower placement rules, shared by the placement
+/// systems, the advisor and tests. Site checks run first; funds are only
+/// checked once a cost has been attached with `with_funds`.
+pub struct PlacementValidator<'a> {
+    unified_grid: &'a UnifiedGridSystem,
+    path_grid: Option<&'a PathGrid>,
+    path_points: &'a [Vec2],
+    /// Waypoints of the path's further routes, kept clear like the main one
+    extra_routes: &'a [Vec<Vec2>],
+    /// Towers and obstacles by cell; a tower fills its cell, so this is all
+    /// the overlap check needs
+    occupancy: &'a OccupancyMap,
+    /// Footprint of a tower, used to keep towers clear of the path lines
+    tower_size: f32,
+    affordable: Option<bool>,
+}
+
+impl<'a> PlacementValidator<'a> {
+    pub fn new(
+        unified_grid: &'a UnifiedGridSystem,
+        path_grid: Option<&'a PathGrid>,
+        path_points: &'a [Vec2],
+        occupancy: &'a OccupancyMap,
+        tower_size: f32,
+    ) -> Self {
+        Self {
+            unified_grid,
+            path_grid,
+            path_points,
+            extra_routes: &[],
+            occupancy,
+            tower_size,
+            affordable: None,
+        }
+    }
+
+    /// Also require `cost` to be affordable from `economy`
+    pub fn with_funds(mut self, economy: &Economy, cost: &ResourceCost) -> Self {
+        self.affordable = Some(economy.can_afford(cost));
+        self
+    }
+
+    /// Also keep clear of the path's routes from further entry points
+    pub fn with_extra_routes(mut self, extra_routes: &'a [Vec<Vec2>]) -> Self {
+        self.extra_routes = extra_routes;
+        self
+    }
+
+    /// Whether a tower can be built centred on this grid cell
+    pub fn is_cell_buildable(&self, cell: GridPos) -> PlacementVerdict {
+        self.check_position(grid_to_world(cell, self.unified_grid))
+    }
+
+    /// Whether a tower can be built centred on this world position
+    pub fn check_position(&self, position: Vec2) -> PlacementVerdict {
+        let Some(cell) = world_to_grid(position, self.unified_grid) else {
+            return PlacementVerdict::OutOfZone;
+        };
+
+        let terrain = terrain_verdict(self.path_grid, cell);
+        if !terrain.is_buildable() {
+            return terrain;
+        }
+
+        match self.occupancy.get(cell) {
+            Some(Occupant::Obstacle(_)) => return PlacementVerdict::Blocked,
+            Some(Occupant::Tower(_)) => return PlacementVerdict::Occupied,
+            None => {}
+        }
+
+        // Keep clear of the path lines themselves, which can cut through cells not marked as path
+        let near_path = std::iter::once(self.path_points)
+            .chain(self.extra_routes.iter().map(Vec::as_slice))
+            .flat_map(|route| route.windows(2))
+            .any(|segment| distance_to_line_segment(position, segment[0], segment[1]) < self.tower_size / 2.0);
+        if near_path {
+            return PlacementVerdict::OnPath;
+        }
+
+        if self.affordable == Some(false) {
+            return PlacementVerdict::InsufficientFunds;
+        }
+        PlacementVerdict::Buildable
+    }
+}
+
+/// System parameter bundling everything placement checks read, for systems
+/// that only need a validator. Systems selling towers through it free their
+/// cells straight away with `vacate`, so later checks in the same frame see
+/// them as empty before the despawn lands.
+#[derive(SystemParam)]
+pub struct PlacementContext<'w> {
+    unified_grid: Res<'w, UnifiedGridSystem>,
+    obstacle_grid: Res<'w, ObstacleGrid>,
+    enemy_path: Res<'w, EnemyPath>,
+    constants: Res<'w, GameConstants>,
+    occupancy: ResMut<'w, OccupancyMap>,
+}
+
+impl PlacementContext<'_> {
+    pub fn validator(&self) -> PlacementValidator<'_> {
+        PlacementValidator::new(
+            &self.unified_grid,
+            Some(&self.obstacle_grid.grid),
+            &self.enemy_path.waypoints,
+            &self.occupancy,
+            self.constants.tower_footprint,
+        )
+        .with_extra_routes(&self.enemy_path.extra_routes)
+    }
+
+    /// Free the cell of a tower being sold
+    pub fn vacate(&mut self, tower: Entity) {
+        self.occupancy.vacate(tower);
+    }
+
+    pub fn unified_grid(&self) -> &UnifiedGridSystem {
+        &self.unified_grid
+    }
+
+    pub fn tower_footprint(&self) -> f32 {
+        self.constants.tower_footprint
+    }
+}
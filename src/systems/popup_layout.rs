@@ -0,0 +1,129 @@
+use bevy::prelude::*;
+use crate::resources::{PLAY_AREA_HEIGHT, PLAY_AREA_WIDTH};
+
+/// Space left between a popup and the element it belongs to
+pub const POPUP_GAP: f32 = 8.0;
+/// Space kept between a popup and the edge of the screen
+pub const VIEWPORT_MARGIN: f32 = 8.0;
+
+/// Which side of its anchor a popup sits on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PopupSide {
+    Left,
+    Right,
+    Above,
+    Below,
+}
+
+impl PopupSide {
+    pub fn opposite(self) -> Self {
+        match self {
+            PopupSide::Left => PopupSide::Right,
+            PopupSide::Right => PopupSide::Left,
+            PopupSide::Above => PopupSide::Below,
+            PopupSide::Below => PopupSide::Above,
+        }
+    }
+
+    fn is_horizontal(self) -> bool {
+        matches!(self, PopupSide::Left | PopupSide::Right)
+    }
+}
+
+/// Where a popup ends up, in UI pixels (origin top-left, y down)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PopupPlacement {
+    /// Top-left corner of the popup
+    pub position: Vec2,
+    /// Side of the anchor the popup was put on
+    pub side: PopupSide,
+}
+
+impl PopupPlacement {
+    pub fn rect(&self, size: Vec2) -> Rect {
+        Rect::from_corners(self.position, self.position + size)
+    }
+}
+
+/// Place a popup of `size` next to `anchor` inside a `viewport` sized screen.
+///
+/// The popup goes on the `preferred` side, flips to the opposite side when it
+/// wouldn't fit there, and takes whichever side has more room when neither fits.
+/// Along the other axis it lines up with the anchor's start edge. The result is
+/// always clamped on screen, so a popup larger than the room beside its anchor
+/// may cover the anchor rather than leave the screen.
+pub fn place_popup(anchor: Rect, size: Vec2, viewport: Vec2, preferred: PopupSide) -> PopupPlacement {
+    let room = |side: PopupSide| match side {
+        PopupSide::Left => anchor.min.x - POPUP_GAP - VIEWPORT_MARGIN,
+        PopupSide::Right => viewport.x - anchor.max.x - POPUP_GAP - VIEWPORT_MARGIN,
+        PopupSide::Above => anchor.min.y - POPUP_GAP - VIEWPORT_MARGIN,
+        PopupSide::Below => viewport.y - anchor.max.y - POPUP_GAP - VIEWPORT_MARGIN,
+    };
+    let needed = |side: PopupSide| if side.is_horizontal() { size.x } else { size.y };
+
+    let flipped = preferred.opposite();
+    let side = if room(preferred) >= needed(preferred) {
+        preferred
+    } else if room(flipped) >= needed(flipped) || room(flipped) > room(preferred) {
+        flipped
+    } else {
+        preferred
+    };
+
+    let position = match side {
+        PopupSide::Left => Vec2::new(anchor.min.x - POPUP_GAP - size.x, anchor.min.y),
+        PopupSide::Right => Vec2::new(anchor.max.x + POPUP_GAP, anchor.min.y),
+        PopupSide::Above => Vec2::new(anchor.min.x, anchor.min.y - POPUP_GAP - size.y),
+        PopupSide::Below => Vec2::new(anchor.min.x, anchor.max.y + POPUP_GAP),
+    };
+
+    PopupPlacement {
+        position: clamp_to_viewport(position, size, viewport),
+        side,
+    }
+}
+
+/// Pull a popup's top-left corner in so the whole popup stays on screen.
+/// A popup larger than the screen is pinned to the top-left margin.
+pub fn clamp_to_viewport(position: Vec2, size: Vec2, viewport: Vec2) -> Vec2 {
+    let min = Vec2::splat(VIEWPORT_MARGIN);
+    let max = (viewport - size - Vec2::splat(VIEWPORT_MARGIN)).max(min);
+    position.clamp(min, max)
+}
+
+/// On-screen rect of a laid out UI node, in logical pixels
+pub fn ui_node_rect(transform: &GlobalTransform, node: &ComputedNode) -> Rect {
+    let scale = node.inverse_scale_factor();
+    Rect::from_center_size(transform.translation().truncate() * scale, node.size() * scale)
+}
+
+/// Laid out size of a UI node in logical pixels, or `fallback` before its first layout
+pub fn measured_size(node: &ComputedNode, fallback: Vec2) -> Vec2 {
+    let size = node.size() * node.inverse_scale_factor();
+    if size.x > 0.0 && size.y > 0.0 { size } else { fallback }
+}
+
+/// Logical size of the window, or the play area when there's no window
+pub fn viewport_size(window: Option<&Window>) -> Vec2 {
+    window
+        .map(|window| Vec2::new(window.width(), window.height()))
+        .filter(|size| size.x > 0.0 && size.y > 0.0)
+        .unwrap_or(Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT))
+}
+
+/// Rough size of a block of text, for popups laid out before the text is measured
+pub fn estimated_text_size(text: &str, font_size: f32) -> Vec2 {
+    let longest = text.lines().map(|line| line.chars().count()).max().unwrap_or(0);
+    let lines = text.lines().count().max(1);
+    Vec2::new(longest as f32 * font_size * 0.6, lines as f32 * font_size * 1.2)
+}
+
+/// Convert a world position to UI pixels for a camera centred on the origin
+pub fn world_to_ui(world: Vec2, viewport: Vec2) -> Vec2 {
+    Vec2::new(world.x + viewport.x / 2.0, viewport.y / 2.0 - world.y)
+}
+
+/// Convert a UI pixel position back to the world for a camera centred on the origin
+pub fn ui_to_world(ui: Vec2, viewport: Vec2) -> Vec2 {
+    Vec2::new(ui.x - viewport.x / 2.0, viewport.y / 2.0 - ui.y)
+}
@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+use std::path::Path;
+use crate::resources::*;
+use crate::systems::debug_ui::profiler::PROFILE_EXPORT_DIR;
+use crate::systems::save_load::SAVE_STATE_SAVE;
+use crate::systems::settings_menu::GameSettings;
+use crate::systems::suspend_system::{PendingSuspendedRun, SUSPEND_SAVE};
+
+/// Files the game writes outside of the settings: profile statistics,
+/// leaderboards and exports
+pub const LOCAL_DATA_FILES: [&str; 4] = [
+    ENEMY_CODEX_FILE,
+    PRESTIGE_PROFILE_FILE,
+    LEADERBOARD_FILE,
+    STRESS_TEST_CSV,
+];
+/// Saved runs, written in whichever save format is picked
+pub const LOCAL_SAVES: [&str; 2] = [SUSPEND_SAVE, SAVE_STATE_SAVE];
+/// Directories of captures the game writes
+pub const LOCAL_DATA_DIRS: [&str; 1] = [PROFILE_EXPORT_DIR];
+
+/// Request, confirmed in the settings menu, to delete every local data file
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WipeLocalDataEvent;
+
+/// What a wipe removed and what it couldn't
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WipeReport {
+    pub removed: Vec<String>,
+    /// Path and error of everything that exists but could not be deleted
+    pub failed: Vec<(String, String)>,
+}
+
+/// Delete the local data files and capture directories under `root`.
+/// Missing ones are skipped; the settings file is never touched.
+pub fn wipe_local_data_in(root: &Path) -> WipeReport {
+    let mut report = WipeReport::default();
+    let saves = LOCAL_SAVES
+        .iter()
+        .flat_map(|stem| SaveFormat::ALL.map(|format| format.file_name(stem)));
+    for file in LOCAL_DATA_FILES.map(str::to_string).into_iter().chain(saves) {
+        let path = root.join(&file);
+        if path.exists() {
+            match std::fs::remove_file(&path) {
+                Ok(()) => report.removed.push(file),
+                Err(e) => report.failed.push((file, e.to_string())),
+            }
+        }
+    }
+    for dir in LOCAL_DATA_DIRS {
+        let path = root.join(dir);
+        if path.exists() {
+            match std::fs::remove_dir_all(&path) {
+                Ok(()) => report.removed.push(format!("{}/", dir)),
+                Err(e) => report.failed.push((format!("{}/", dir), e.to_string())),
+            }
+        }
+    }
+    report
+}
+
+/// Delete the local data files next to the game
+pub fn wipe_local_data() -> WipeReport {
+    wipe_local_data_in(Path::new("."))
+}
+
+/// System to wipe local data once confirmed, clearing the in-memory copies too
+/// so nothing is written back on exit
+pub fn wipe_local_data_system(
+    mut wipe_events: EventReader<WipeLocalDataEvent>,
+    mut codex: Option<ResMut<EnemyCodex>>,
+    mut prestige_profile: Option<ResMut<PrestigeProfile>>,
+    mut leaderboard: Option<ResMut<RunLeaderboard>>,
+    mut pending_run: Option<ResMut<PendingSuspendedRun>>,
+) {
+    if wipe_events.read().last().is_none() {
+        return;
+    }
+
+    let report = wipe_local_data();
+    for (path, error) in &report.failed {
+        warn!("Failed to delete {}: {}", path, error);
+    }
+    info!("Wiped local data: {} removed", report.removed.len());
+
+    if let Some(codex) = codex.as_deref_mut() {
+        codex.stats.clear();
+    }
+    if let Some(profile) = prestige_profile.as_deref_mut() {
+        *profile = PrestigeProfile::default();
+    }
+    if let Some(leaderboard) = leaderboard.as_deref_mut() {
+        *leaderboard = RunLeaderboard::default();
+    }
+    if let Some(pending_run) = pending_run.as_deref_mut() {
+        pending_run.0 = None;
+    }
+}
+
+/// System to stop or resume recording codex statistics with the privacy setting
+pub fn apply_statistics_opt_out_system(settings: Res<GameSettings>, codex: Option<ResMut<EnemyCodex>>) {
+    let Some(mut codex) = codex else {
+        return;
+    };
+    let opted_out = !settings.statistics_enabled;
+    if (settings.is_changed() || codex.is_added()) && codex.opted_out != opted_out {
+        codex.opted_out = opted_out;
+    }
+}
+
+/// Plugin honouring the privacy settings: statistics opt-out and the local data wipe
+pub struct PrivacyPlugin;
+
+impl Plugin for PrivacyPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<WipeLocalDataEvent>().add_systems(
+            Update,
+            (apply_statistics_opt_out_system, wipe_local_data_system).in_set(GameSystemSet::UI),
+        );
+    }
+}
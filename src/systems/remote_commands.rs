@@ -0,0 +1,330 @@
+use std::collections::VecDeque;
+use bevy::prelude::*;
+use bevy::remote::{error_codes, BrpError, BrpResult, RemoteMethodSystemId, RemoteMethods};
+use serde::{Deserialize, Serialize};
+use crate::components::{Constructing, PlacementGrace};
+use crate::resources::{resolve_tower_cost, AppState, Economy, GameSystemSet, MarketState, RunIntegrity, TowerStats, TowerType, WavePlan, WaveRuntime};
+use crate::systems::construction_system::sell_refund;
+use crate::systems::enemy_system::StartWaveEvent;
+use crate::systems::input_system::{get_placement_position, spawn_tower, PlacementMode};
+use crate::systems::placement_validator::PlacementContext;
+use crate::systems::tower_ui::{try_upgrade_tower, TowerSelectionState};
+
+/// Remote method queuing a `RemoteCommand`, answering with its ticket
+pub const COMMAND_METHOD: &str = "tower_defense/command";
+/// Remote method answering with the `CommandOutcome` of a ticket
+pub const COMMAND_RESULT_METHOD: &str = "tower_defense/command_result";
+
+/// Most commands waiting to be applied; further ones are refused until the queue drains
+pub const MAX_PENDING_COMMANDS: usize = 64;
+/// Outcomes remembered for clients to collect, oldest forgotten first
+pub const MAX_KEPT_OUTCOMES: usize = 256;
+
+// ============================================================================
+// COMMANDS
+// ============================================================================
+
+/// Game action a remote client can ask for. Entities are `Entity::to_bits`, as
+/// in `GameSnapshot`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RemoteCommand {
+    /// Build a tower at a world position, snapped to the grid as a click would be
+    PlaceTower { tower_type: TowerType, position: [f32; 2] },
+    UpgradeTower { entity: u64 },
+    SellTower { entity: u64 },
+    StartWave,
+}
+
+impl RemoteCommand {
+    /// Check the command is well formed, before it is queued. Whether it can
+    /// be carried out is only known once it is applied.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            RemoteCommand::PlaceTower { position, .. } => {
+                if position.iter().all(|coordinate| coordinate.is_finite()) {
+                    Ok(())
+                } else {
+                    Err(format!("position {:?} is not finite", position))
+                }
+            }
+            RemoteCommand::UpgradeTower { entity } | RemoteCommand::SellTower { entity } => Entity::try_from_bits(*entity)
+                .map(|_| ())
+                .map_err(|_| format!("{} is not an entity", entity)),
+            RemoteCommand::StartWave => Ok(()),
+        }
+    }
+}
+
+/// Command as sent by a client. Clients that may deliver out of order number
+/// their commands; those are applied in number order, and a number at or below
+/// one already applied arrived too late and is refused.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemoteCommandRequest {
+    #[serde(default)]
+    pub sequence: Option<u64>,
+    #[serde(flatten)]
+    pub command: RemoteCommand,
+}
+
+/// What became of a queued command
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "status", content = "message", rename_all = "snake_case")]
+pub enum CommandOutcome {
+    /// Waiting for the next time commands are applied
+    Pending,
+    Applied(String),
+    Rejected(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueuedCommand {
+    pub ticket: u64,
+    pub sequence: Option<u64>,
+    pub command: RemoteCommand,
+}
+
+/// Resource remote handlers push commands into. Only
+/// `apply_remote_commands_system` changes the game in response, at one point
+/// in the frame, so remote calls never touch the world mid-update.
+#[derive(Resource, Debug, Default)]
+pub struct RemoteCommandQueue {
+    next_ticket: u64,
+    pending: Vec<QueuedCommand>,
+    /// Highest sequence number applied so far
+    last_sequence: Option<u64>,
+    outcomes: VecDeque<(u64, CommandOutcome)>,
+}
+
+impl RemoteCommandQueue {
+    /// Queue a command, returning the ticket to collect its outcome with
+    pub fn push(&mut self, request: RemoteCommandRequest) -> Result<u64, String> {
+        request.command.validate()?;
+        if self.pending.len() >= MAX_PENDING_COMMANDS {
+            return Err(format!("{} commands are already waiting", MAX_PENDING_COMMANDS));
+        }
+        if let Some(sequence) = request.sequence {
+            if self.last_sequence.is_some_and(|last| sequence <= last) {
+                return Err(format!("sequence {} arrived after a later command was applied", sequence));
+            }
+            if self.pending.iter().any(|queued| queued.sequence == Some(sequence)) {
+                return Err(format!("sequence {} is already queued", sequence));
+            }
+        }
+        let ticket = self.next_ticket;
+        self.next_ticket += 1;
+        self.pending.push(QueuedCommand { ticket, sequence: request.sequence, command: request.command });
+        Ok(ticket)
+    }
+
+    /// Take every pending command in the order to apply them. Numbered
+    /// commands are put in number order among the places they took in the
+    /// queue; unnumbered ones keep their place.
+    pub fn drain(&mut self) -> Vec<QueuedCommand> {
+        let mut commands = std::mem::take(&mut self.pending);
+        let slots: Vec<usize> = (0..commands.len()).filter(|&index| commands[index].sequence.is_some()).collect();
+        let mut numbered: Vec<QueuedCommand> = slots.iter().map(|&index| commands[index].clone()).collect();
+        numbered.sort_by_key(|queued| queued.sequence);
+        if let Some(highest) = numbered.last().and_then(|queued| queued.sequence) {
+            self.last_sequence = Some(highest);
+        }
+        for (slot, queued) in slots.into_iter().zip(numbered) {
+            commands[slot] = queued;
+        }
+        commands
+    }
+
+    /// Remember the outcome of an applied or refused command
+    pub fn record(&mut self, ticket: u64, outcome: CommandOutcome) {
+        self.outcomes.push_back((ticket, outcome));
+        while self.outcomes.len() > MAX_KEPT_OUTCOMES {
+            self.outcomes.pop_front();
+        }
+    }
+
+    /// Outcome of a ticket, or `None` if it was never issued or has been forgotten
+    pub fn outcome(&self, ticket: u64) -> Option<CommandOutcome> {
+        if self.pending.iter().any(|queued| queued.ticket == ticket) {
+            return Some(CommandOutcome::Pending);
+        }
+        self.outcomes
+            .iter()
+            .find(|(recorded, _)| *recorded == ticket)
+            .map(|(_, outcome)| outcome.clone())
+    }
+
+    pub fn pending_len(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// System to carry out queued remote commands, re-checking each against the
+/// game as it is now. Runs with input, before anything else reacts this frame.
+pub fn apply_remote_commands_system(
+    mut commands: Commands,
+    mut queue: ResMut<RemoteCommandQueue>,
+    mut economy: ResMut<Economy>,
+    wave_plan: Res<WavePlan>,
+    wave_runtime: Res<WaveRuntime>,
+    market: Option<Res<MarketState>>,
+    mut integrity: Option<ResMut<RunIntegrity>>,
+    mut selection_state: Option<ResMut<TowerSelectionState>>,
+    mut placement: PlacementContext,
+    mut towers: Query<(&mut TowerStats, Option<&PlacementGrace>), Without<Constructing>>,
+    mut wave_start_events: EventWriter<StartWaveEvent>,
+) {
+    // Spawns and despawns wait for the end of the system, so this frame's own
+    // changes are tracked here for the commands after them
+    let mut placed: Vec<Vec2> = Vec::new();
+    let mut sold: Vec<Entity> = Vec::new();
+    let mut wave_started = false;
+    for QueuedCommand { ticket, command, .. } in queue.drain() {
+        let result = match command {
+            RemoteCommand::PlaceTower { tower_type, position } => {
+                let position = get_placement_position(Vec2::from_array(position), PlacementMode::Hybrid, placement.unified_grid());
+                let cost = resolve_tower_cost(market.as_deref(), tower_type);
+                let verdict = placement.validator().with_funds(&economy, &cost).check_position(position);
+                if placed.iter().any(|other| other.distance(position) < placement.tower_footprint()) {
+                    Err("another command is building there".to_string())
+                } else if !verdict.is_buildable() {
+                    Err(verdict.get_reason().to_string())
+                } else if integrity.as_mut().is_some_and(|integrity| !integrity.try_record_placement()) {
+                    Err("placement rate limit reached".to_string())
+                } else {
+                    economy.spend(&cost);
+                    spawn_tower(&mut commands, position, tower_type, cost);
+                    placed.push(position);
+                    Ok(format!("building {} at {:?}", tower_type.get_name(), position))
+                }
+            }
+            RemoteCommand::UpgradeTower { entity } => {
+                let tower = Entity::try_from_bits(entity).ok().filter(|tower| !sold.contains(tower));
+                match tower.and_then(|tower| towers.get_mut(tower).ok()) {
+                    Some((mut stats, _)) => match try_upgrade_tower(&mut stats, false, &mut economy) {
+                        Ok(()) => Ok(format!("upgraded {} to level {}", stats.tower_type.get_name(), stats.upgrade_level)),
+                        Err(refusal) => Err(refusal.get_reason().to_string()),
+                    },
+                    None => Err(format!("no finished tower {}", entity)),
+                }
+            }
+            RemoteCommand::SellTower { entity } => {
+                let tower = Entity::try_from_bits(entity).ok().filter(|tower| !sold.contains(tower));
+                match tower.and_then(|tower| towers.get(tower).ok().map(|(stats, grace)| (tower, stats, grace))) {
+                    Some((tower, stats, grace)) => {
+                        let refund = sell_refund(stats, None, grace);
+                        let name = stats.tower_type.get_name();
+                        economy.refund(&refund);
+                        commands.entity(tower).despawn();
+                        placement.vacate(tower);
+                        sold.push(tower);
+                        if let Some(selection_state) = selection_state.as_deref_mut() {
+                            if selection_state.selected_tower_entity == Some(tower) {
+                                selection_state.clear_selection();
+                            }
+                        }
+                        Ok(format!("sold {} for {:?}", name, refund))
+                    }
+                    None => Err(format!("no finished tower {}", entity)),
+                }
+            }
+            RemoteCommand::StartWave => {
+                if !wave_started && (wave_plan.current_wave == 0 || wave_runtime.wave_complete(&wave_plan)) {
+                    wave_started = true;
+                    wave_start_events.write(StartWaveEvent);
+                    Ok(format!("starting wave {}", wave_plan.next_wave()))
+                } else {
+                    Err("the current wave is still in progress".to_string())
+                }
+            }
+        };
+        let outcome = match result {
+            Ok(message) => {
+                info!("Remote command {}: {}", ticket, message);
+                CommandOutcome::Applied(message)
+            }
+            Err(reason) => {
+                info!("Remote command {} refused: {}", ticket, reason);
+                CommandOutcome::Rejected(reason)
+            }
+        };
+        queue.record(ticket, outcome);
+    }
+}
+
+// ============================================================================
+// REMOTE METHODS
+// ============================================================================
+
+fn invalid_params(message: String) -> BrpError {
+    BrpError {
+        code: error_codes::INVALID_PARAMS,
+        message,
+        data: None,
+    }
+}
+
+/// Remote handler queuing the `RemoteCommandRequest` in its params, answering `{"ticket": n}`
+pub fn process_command_request(
+    In(params): In<Option<serde_json::Value>>,
+    mut queue: ResMut<RemoteCommandQueue>,
+) -> BrpResult {
+    let params = params.ok_or_else(|| invalid_params("missing command".to_string()))?;
+    let request: RemoteCommandRequest = serde_json::from_value(params).map_err(|error| invalid_params(error.to_string()))?;
+    let ticket = queue.push(request).map_err(invalid_params)?;
+    Ok(serde_json::json!({ "ticket": ticket }))
+}
+
+/// Remote handler answering `{"ticket": n}` with that command's `CommandOutcome`
+pub fn process_command_result_request(
+    In(params): In<Option<serde_json::Value>>,
+    queue: Res<RemoteCommandQueue>,
+) -> BrpResult {
+    let ticket = params
+        .as_ref()
+        .and_then(|params| params.get("ticket"))
+        .and_then(serde_json::Value::as_u64)
+        .ok_or_else(|| invalid_params("missing ticket".to_string()))?;
+    let outcome = queue
+        .outcome(ticket)
+        .ok_or_else(|| invalid_params(format!("unknown or expired ticket {}", ticket)))?;
+    serde_json::to_value(outcome).map_err(|error| BrpError {
+        code: error_codes::INTERNAL_ERROR,
+        message: error.to_string(),
+        data: None,
+    })
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin letting remote clients drive the game through a validated command queue
+pub struct RemoteCommandPlugin;
+
+impl Plugin for RemoteCommandPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RemoteCommandQueue>().add_systems(
+            Update,
+            apply_remote_commands_system
+                .in_set(GameSystemSet::Input)
+                .run_if(in_state(AppState::Playing)),
+        );
+    }
+
+    // As with the snapshot method, registered once every plugin is built
+    fn finish(&self, app: &mut App) {
+        let world = app.world_mut();
+        if !world.contains_resource::<RemoteMethods>() {
+            return;
+        }
+        let command = world.register_system(process_command_request);
+        let result = world.register_system(process_command_result_request);
+        let mut methods = world.resource_mut::<RemoteMethods>();
+        methods.insert(COMMAND_METHOD, RemoteMethodSystemId::Instant(command));
+        methods.insert(COMMAND_RESULT_METHOD, RemoteMethodSystemId::Instant(result));
+    }
+}
@@ -0,0 +1,892 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::resources::*;
+use crate::systems::combat_system::WaveStatus;
+use crate::systems::obstacle_rendering::{spawn_level_obstacles, ObstacleGrid};
+use crate::systems::path_generation::{current_level_seed, generate_level_path_with_config, set_level_seed, FixedLevelPath, LevelConfig, Obstacle};
+use crate::systems::map_share_system::{apply_shared_map, PendingSharedMap};
+use crate::systems::security::SecurityContext;
+use crate::systems::settings_menu::GameSettings;
+use crate::systems::suspend_system::SuspendedRun;
+use crate::systems::tower_ui::TowerSelectionState;
+use crate::systems::tween::{Easing, TweenProgress, UiOffsetTween};
+
+// ============================================================================
+// RESULTS SCREEN COMPONENTS
+// ============================================================================
+
+#[derive(Component)]
+pub struct ResultsOverlay;
+
+#[derive(Component)]
+pub struct ResultsButton {
+    pub action: ResultsAction,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ResultsAction {
+    ContinueFreePlay,
+    RetryFromCheckpoint,
+    RetrySameSeed,
+    NewSeed,
+    MainMenu,
+    TogglePrestige(PrestigeModifier),
+}
+
+/// Text of a prestige modifier toggle on the results screen
+#[derive(Component)]
+pub struct PrestigeOptionText {
+    pub modifier: PrestigeModifier,
+}
+
+/// Text showing the score multiplier of the prestige picked for the next run
+#[derive(Component)]
+pub struct PrestigeSummaryText;
+
+/// How a rolling counter renders its current value
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CounterFormat {
+    Integer,
+    Money,
+}
+
+/// Text counter that rolls up from zero to its target value
+#[derive(Component)]
+pub struct RollingCounter {
+    pub label: &'static str,
+    pub target: f32,
+    pub format: CounterFormat,
+    pub progress: TweenProgress,
+}
+
+impl RollingCounter {
+    pub fn new(label: &'static str, target: f32, format: CounterFormat, delay: f32) -> Self {
+        Self {
+            label,
+            target,
+            format,
+            // Ease-out so the roll-up settles gently
+            progress: TweenProgress::new(1.2, Easing::CubicOut).with_delay(delay),
+        }
+    }
+
+    /// Value shown right now
+    pub fn current_value(&self) -> f32 {
+        self.target * self.progress.eased()
+    }
+
+    pub fn display_text(&self, formatter: &NumberFormatter) -> String {
+        let value = self.current_value().round() as u64;
+        match self.format {
+            CounterFormat::Integer => format!("{}: {}", self.label, formatter.count(value)),
+            CounterFormat::Money => format!("{}: {}", self.label, formatter.money(value)),
+        }
+    }
+}
+
+/// Camera move towards the base played once the run ends
+#[derive(Resource)]
+pub struct EndCinematic {
+    pub progress: TweenProgress,
+    pub start_position: Vec2,
+    pub focus_position: Vec2,
+    pub start_scale: f32,
+    pub focus_scale: f32,
+}
+
+/// Event sent when the player starts a new run from the results screen
+#[derive(Event)]
+pub struct RestartRunEvent {
+    pub new_seed: bool,
+}
+
+/// Event sent when the player retries from the latest wave checkpoint
+#[derive(Event)]
+pub struct RestoreCheckpointEvent;
+
+/// Event sent when the player keeps playing a won campaign in free play
+#[derive(Event)]
+pub struct StartFreePlayEvent;
+
+/// How a finished free play run placed, for the results screen
+#[derive(Debug, Clone, PartialEq)]
+pub struct FreePlayResult {
+    pub entry: LeaderboardEntry,
+    /// Leaderboard rank, `None` if the run didn't make the board
+    pub rank: Option<usize>,
+    pub campaign_wave: u32,
+}
+
+// ============================================================================
+// UI COLOR CONSTANTS (matching pause menu)
+// ============================================================================
+
+struct UIColors;
+
+impl UIColors {
+    const PANEL_BG: Color = Color::srgb(0.08, 0.12, 0.18);
+    const PANEL_BORDER: Color = Color::srgb(0.22, 0.28, 0.38);
+    const BUTTON_DEFAULT: Color = Color::srgb(0.15, 0.20, 0.28);
+    const BUTTON_HOVER: Color = Color::srgb(0.20, 0.28, 0.38);
+    const BUTTON_DISABLED: Color = Color::srgb(0.10, 0.12, 0.16);
+    const BORDER_DEFAULT: Color = Color::srgb(0.32, 0.38, 0.48);
+    const BORDER_HOVER: Color = Color::srgb(0.48, 0.58, 0.70);
+    const BORDER_DISABLED: Color = Color::srgb(0.18, 0.22, 0.28);
+    const TEXT_PRIMARY: Color = Color::srgb(0.96, 0.96, 0.98);
+    const TEXT_MUTED: Color = Color::srgb(0.58, 0.62, 0.68);
+    const TEXT_SUCCESS: Color = Color::srgb(0.58, 0.88, 0.68);
+    const TEXT_ERROR: Color = Color::srgb(1.0, 0.58, 0.58);
+    const TEXT_INFO: Color = Color::srgb(0.58, 0.78, 1.0);
+    const OVERLAY_BG: Color = Color::srgba(0.0, 0.0, 0.0, 0.45);
+}
+
+/// Seconds the camera takes to settle on the base
+const CINEMATIC_DURATION: f32 = 2.5;
+/// Orthographic scale the camera zooms to (smaller = closer)
+const CINEMATIC_ZOOM: f32 = 0.6;
+/// Seconds between successive counters starting their roll-up
+const COUNTER_STAGGER: f32 = 0.35;
+/// Leaderboard rows listed on the results screen
+const RESULTS_TOP_RUNS: usize = 5;
+
+// ============================================================================
+// RESULTS SCREEN SYSTEMS
+// ============================================================================
+
+/// System to snapshot the run and start the end-of-run presentation once the game ends
+pub fn capture_run_results_system(
+    mut commands: Commands,
+    game_state: Res<GameState>,
+    run_results: Option<Res<RunResults>>,
+    score: Res<Score>,
+    wave_plan: Res<WavePlan>,
+    wave_status: Res<WaveStatus>,
+    enemy_path: Res<EnemyPath>,
+    checkpoints: Option<Res<CheckpointState>>,
+    formatter: Res<NumberFormatter>,
+    (free_play, mut leaderboard): (Option<Res<FreePlayRun>>, Option<ResMut<RunLeaderboard>>),
+    (run_prestige, mut prestige_profile): (Option<Res<RunPrestige>>, Option<ResMut<PrestigeProfile>>),
+    camera_query: Query<(&Transform, &Projection), With<Camera2d>>,
+    settings: Option<Res<GameSettings>>,
+    (security, integrity): (Option<Res<SecurityContext>>, Option<Res<RunIntegrity>>),
+) {
+    if run_results.is_some() {
+        return;
+    }
+    let Some(mut outcome) = RunOutcome::from_game_state(&game_state) else {
+        return;
+    };
+    let prestige = run_prestige.map(|run| run.modifiers).unwrap_or_default();
+    // Only cheat-free runs are ranked
+    let competitive = integrity.as_deref().is_none_or(|integrity| {
+        security
+            .as_deref()
+            .map_or(integrity.is_clean(), |security| security.has_competitive_integrity(integrity))
+    });
+    let save_records = settings.as_ref().is_none_or(|settings| settings.save_records);
+
+    // Winning a campaign unlocks prestige modifiers for the following runs
+    if let Some(profile) = prestige_profile.as_mut().filter(|_| outcome == RunOutcome::Victory) {
+        if profile.unlock() {
+            info!("Prestige modifiers unlocked");
+            if let Err(error) = profile.save() {
+                warn!("Failed to save the prestige profile to {}: {}", PRESTIGE_PROFILE_FILE, error);
+            }
+        }
+    }
+
+    // Losing in free play ends the continuation; the campaign stays won and
+    // the run is ranked among free play runs on what it added
+    let free_play = free_play.filter(|run| run.active && outcome == RunOutcome::Defeat);
+    let entry = match free_play.as_deref() {
+        Some(run) => {
+            outcome = RunOutcome::FreePlayEnded;
+            LeaderboardEntry {
+                waves: run.waves_survived(wave_plan.current_wave),
+                score: run.score_gained(&score),
+                date: today(),
+                seed: current_level_seed(),
+                mode: LeaderboardMode::FreePlay,
+                prestige,
+            }
+        }
+        None => LeaderboardEntry {
+            waves: wave_plan.current_wave,
+            score: score.current,
+            date: today(),
+            seed: current_level_seed(),
+            mode: settings.as_ref().map(|settings| settings.run_mode).unwrap_or_default().into(),
+            prestige,
+        },
+    };
+
+    // Every ranked run goes on the profile's leaderboard, whatever the outcome
+    let mode = entry.mode;
+    let run_rank = leaderboard.as_mut().filter(|_| competitive).and_then(|leaderboard| {
+        let rank = leaderboard.record(entry.clone());
+        // Ranked either way; only written out while saving records is on
+        if save_records {
+            if let Err(error) = leaderboard.save() {
+                warn!("Failed to save the leaderboard to {}: {}", LEADERBOARD_FILE, error);
+            }
+        }
+        rank
+    });
+    let top_runs: Vec<LeaderboardEntry> = leaderboard
+        .as_deref()
+        .map(|leaderboard| leaderboard.entries_for(mode).take(RESULTS_TOP_RUNS).cloned().collect())
+        .unwrap_or_default();
+    let free_play_result = free_play.map(|run| FreePlayResult { entry, rank: run_rank, campaign_wave: run.start_wave });
+
+    let results = RunResults::capture(
+        outcome,
+        &score,
+        wave_plan.current_wave,
+        wave_status.enemies_escaped,
+        current_level_seed(),
+    ).with_prestige(prestige).with_tampered(!competitive);
+    info!("Run ended: {:?}", results);
+
+    // The base sits at the end of the enemy path
+    if let Ok((camera_transform, projection)) = camera_query.single() {
+        let start_scale = match projection {
+            Projection::Orthographic(orthographic) => orthographic.scale,
+            _ => 1.0,
+        };
+        commands.insert_resource(EndCinematic {
+            progress: TweenProgress::new(CINEMATIC_DURATION, Easing::SmoothStep),
+            start_position: camera_transform.translation.truncate(),
+            focus_position: enemy_path.waypoints.last().copied().unwrap_or(Vec2::ZERO),
+            start_scale,
+            focus_scale: start_scale * CINEMATIC_ZOOM,
+        });
+    }
+
+    // Offer a checkpoint retry after a defeat while retries are left
+    let checkpoint_retry = checkpoints
+        .filter(|_| outcome == RunOutcome::Defeat)
+        .and_then(|checkpoints| {
+            checkpoints
+                .available()
+                .map(|checkpoint| (checkpoint.wave, checkpoints.retries_remaining()))
+        });
+
+    spawn_results_panel(
+        &mut commands,
+        &results,
+        checkpoint_retry,
+        free_play_result,
+        (run_rank, &top_runs),
+        prestige_profile.as_deref(),
+        &formatter,
+    );
+    commands.insert_resource(results);
+}
+
+fn spawn_results_panel(
+    commands: &mut Commands,
+    results: &RunResults,
+    checkpoint_retry: Option<(u32, u32)>,
+    free_play: Option<FreePlayResult>,
+    (run_rank, top_runs): (Option<usize>, &[LeaderboardEntry]),
+    prestige_profile: Option<&PrestigeProfile>,
+    formatter: &NumberFormatter,
+) {
+    let (title, title_color) = match results.outcome {
+        RunOutcome::Victory => ("VICTORY", UIColors::TEXT_SUCCESS),
+        RunOutcome::Defeat => ("DEFEAT", UIColors::TEXT_ERROR),
+        RunOutcome::FreePlayEnded => ("FREE PLAY OVER", UIColors::TEXT_INFO),
+    };
+
+    let counters = match &free_play {
+        Some(free_play) => vec![
+            ("Bonus Waves Survived", free_play.entry.waves as f32, CounterFormat::Integer),
+            ("Free Play Score", free_play.entry.score as f32, CounterFormat::Integer),
+            ("Enemies Killed", results.enemies_killed as f32, CounterFormat::Integer),
+            ("Money Earned", results.money_earned as f32, CounterFormat::Money),
+        ],
+        None => vec![
+            ("Waves Reached", results.waves_reached as f32, CounterFormat::Integer),
+            ("Enemies Killed", results.enemies_killed as f32, CounterFormat::Integer),
+            ("Damage Dealt", results.damage_dealt, CounterFormat::Integer),
+            ("Money Earned", results.money_earned as f32, CounterFormat::Money),
+            ("Score", results.score as f32, CounterFormat::Integer),
+        ],
+    };
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            top: Val::Px(0.0),
+            width: Val::Vw(100.0),
+            height: Val::Vh(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(UIColors::OVERLAY_BG),
+        ZIndex(900), // Below the pause menu
+        ResultsOverlay,
+    )).with_children(|parent| {
+        parent.spawn((
+            Node {
+                width: Val::Px(420.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(30.0)),
+                row_gap: Val::Px(10.0),
+                border: UiRect::all(Val::Px(3.0)),
+                ..default()
+            },
+            BackgroundColor(UIColors::PANEL_BG),
+            BorderColor(UIColors::PANEL_BORDER),
+            BorderRadius::all(Val::Px(15.0)),
+            // Slide the panel down into place while the camera moves
+            UiOffsetTween::new(
+                Vec2::new(0.0, -80.0),
+                Vec2::ZERO,
+                TweenProgress::new(0.6, Easing::BackOut).with_delay(CINEMATIC_DURATION * 0.3),
+            ),
+        )).with_children(|parent| {
+            // Title
+            parent.spawn((
+                Text::new(title),
+                TextFont {
+                    font_size: 40.0,
+                    ..default()
+                },
+                TextColor(title_color),
+                Node {
+                    margin: UiRect::bottom(Val::Px(15.0)),
+                    ..default()
+                },
+            ));
+
+            // Rolling stat counters, staggered so they roll up one after another
+            for (index, (label, target, format)) in counters.into_iter().enumerate() {
+                let delay = CINEMATIC_DURATION * 0.5 + index as f32 * COUNTER_STAGGER;
+                let counter = RollingCounter::new(label, target, format, delay);
+                parent.spawn((
+                    Text::new(counter.display_text(formatter)),
+                    TextFont {
+                        font_size: 20.0,
+                        ..default()
+                    },
+                    TextColor(UIColors::TEXT_PRIMARY),
+                    counter,
+                ));
+            }
+
+            if let Some(free_play) = &free_play {
+                parent.spawn((
+                    Text::new(free_play_standing(free_play)),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(UIColors::TEXT_INFO),
+                ));
+            }
+
+            if !results.prestige.is_empty() {
+                parent.spawn((
+                    Text::new(format!(
+                        "Prestige: {} (x{:.2} score)",
+                        results.prestige.label(),
+                        results.prestige.score_multiplier()
+                    )),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(UIColors::TEXT_INFO),
+                ));
+            }
+
+            if results.tampered {
+                parent.spawn((
+                    Text::new("Unranked: cheats or admin tools were used this run"),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(UIColors::TEXT_ERROR),
+                ));
+            } else if free_play.is_none() {
+                parent.spawn((
+                    Text::new(leaderboard_standing(run_rank)),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(UIColors::TEXT_INFO),
+                ));
+            }
+
+            // Best runs of the profile, with this one among them if it placed
+            for (index, entry) in top_runs.iter().enumerate() {
+                let rank = index + 1;
+                let color = if Some(rank) == run_rank { UIColors::TEXT_SUCCESS } else { UIColors::TEXT_MUTED };
+                parent.spawn((
+                    Text::new(leaderboard_row(rank, entry, &formatter.count(entry.score))),
+                    TextFont {
+                        font_size: 13.0,
+                        ..default()
+                    },
+                    TextColor(color),
+                ));
+            }
+
+            // Seed used for this run
+            parent.spawn((
+                Text::new(format!("Seed: {}", results.seed)),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_MUTED),
+                Node {
+                    margin: UiRect::bottom(Val::Px(15.0)),
+                    ..default()
+                },
+            ));
+
+            if results.outcome == RunOutcome::Victory {
+                create_results_button(parent, "CONTINUE IN FREE PLAY", ResultsAction::ContinueFreePlay, UIColors::TEXT_INFO, true);
+            }
+            if let Some(profile) = prestige_profile.filter(|profile| profile.unlocked) {
+                spawn_prestige_selection(parent, profile);
+            }
+            if let Some((wave, retries_left)) = checkpoint_retry {
+                let label = format!("RETRY FROM WAVE {} ({} left)", wave, retries_left);
+                create_results_button(parent, &label, ResultsAction::RetryFromCheckpoint, UIColors::TEXT_SUCCESS, true);
+            }
+            create_results_button(parent, "RETRY SAME SEED", ResultsAction::RetrySameSeed, UIColors::TEXT_SUCCESS, true);
+            create_results_button(parent, "NEW SEED", ResultsAction::NewSeed, UIColors::TEXT_INFO, true);
+            create_results_button(parent, "MAIN MENU", ResultsAction::MainMenu, UIColors::TEXT_PRIMARY, true);
+        });
+    });
+}
+
+/// Prestige selection step for the next run started from this screen
+fn spawn_prestige_selection(parent: &mut ChildSpawnerCommands, profile: &PrestigeProfile) {
+    parent.spawn((
+        Text::new("PRESTIGE FOR NEXT RUN"),
+        TextFont {
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(UIColors::TEXT_MUTED),
+    ));
+    for modifier in PrestigeModifier::ALL {
+        parent.spawn((
+            Button,
+            Node {
+                width: Val::Px(280.0),
+                height: Val::Px(36.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(UIColors::BUTTON_DEFAULT),
+            BorderColor(UIColors::BORDER_DEFAULT),
+            BorderRadius::all(Val::Px(8.0)),
+            ResultsButton { action: ResultsAction::TogglePrestige(modifier) },
+        )).with_children(|parent| {
+            parent.spawn((
+                Text::new(prestige_option_label(modifier, &profile.selected)),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_PRIMARY),
+                PrestigeOptionText { modifier },
+            ));
+        });
+    }
+    parent.spawn((
+        Text::new(prestige_summary(&profile.selected)),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(UIColors::TEXT_INFO),
+        Node {
+            margin: UiRect::bottom(Val::Px(10.0)),
+            ..default()
+        },
+        PrestigeSummaryText,
+    ));
+}
+
+/// Toggle label of a prestige modifier, e.g. "[x] Tough Enemies (+20% enemy HP)"
+pub fn prestige_option_label(modifier: PrestigeModifier, selected: &PrestigeSet) -> String {
+    let mark = if selected.contains(modifier) { "x" } else { " " };
+    format!("[{}] {} ({})", mark, modifier.get_name(), modifier.get_description())
+}
+
+/// Score multiplier line for the prestige picked for the next run
+pub fn prestige_summary(selected: &PrestigeSet) -> String {
+    format!("Next run score: x{:.2}", selected.score_multiplier())
+}
+
+/// Leaderboard line for a finished run
+pub fn leaderboard_standing(rank: Option<usize>) -> String {
+    match rank {
+        Some(rank) => format!("Leaderboard: #{}", rank),
+        None => "Leaderboard: not placed".to_string(),
+    }
+}
+
+/// Leaderboard line for a finished free play run
+pub fn free_play_standing(free_play: &FreePlayResult) -> String {
+    let standing = match free_play.rank {
+        Some(rank) => format!("Free play leaderboard: #{}", rank),
+        None => "Free play leaderboard: not placed".to_string(),
+    };
+    let prestige = &free_play.entry.prestige;
+    if prestige.is_empty() {
+        format!("{} (campaign won on wave {})", standing, free_play.campaign_wave)
+    } else {
+        format!("{} (campaign won on wave {}, prestige: {})", standing, free_play.campaign_wave, prestige.label())
+    }
+}
+
+fn create_results_button(
+    parent: &mut ChildSpawnerCommands,
+    text: &str,
+    action: ResultsAction,
+    text_color: Color,
+    enabled: bool,
+) {
+    let (background, border) = if enabled {
+        (UIColors::BUTTON_DEFAULT, UIColors::BORDER_DEFAULT)
+    } else {
+        (UIColors::BUTTON_DISABLED, UIColors::BORDER_DISABLED)
+    };
+
+    parent.spawn((
+        Button,
+        Node {
+            width: Val::Px(280.0),
+            height: Val::Px(50.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(background),
+        BorderColor(border),
+        BorderRadius::all(Val::Px(8.0)),
+        ResultsButton { action },
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(text),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(text_color),
+        ));
+    });
+}
+
+/// System to move and zoom the camera towards the base after the run ends
+pub fn end_cinematic_camera_system(
+    time: Res<Time>,
+    cinematic: Option<ResMut<EndCinematic>>,
+    mut camera_query: Query<(&mut Transform, &mut Projection), With<Camera2d>>,
+) {
+    let Some(mut cinematic) = cinematic else {
+        return;
+    };
+    if cinematic.progress.is_finished() {
+        return;
+    }
+    cinematic.progress.tick(time.delta_secs());
+    let eased = cinematic.progress.eased();
+
+    if let Ok((mut transform, mut projection)) = camera_query.single_mut() {
+        let position = cinematic.start_position.lerp(cinematic.focus_position, eased);
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+
+        if let Projection::Orthographic(orthographic) = projection.as_mut() {
+            orthographic.scale = cinematic.start_scale + (cinematic.focus_scale - cinematic.start_scale) * eased;
+        }
+    }
+}
+
+/// System to animate result counters rolling up
+pub fn rolling_counter_system(
+    time: Res<Time>,
+    formatter: Res<NumberFormatter>,
+    mut counter_query: Query<(&mut RollingCounter, &mut Text)>,
+) {
+    for (mut counter, mut text) in counter_query.iter_mut() {
+        if counter.progress.is_finished() {
+            continue;
+        }
+        counter.progress.tick(time.delta_secs());
+        **text = counter.display_text(&formatter);
+    }
+}
+
+/// System to handle results screen button interactions
+pub fn results_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor, &ResultsButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut restart_events: EventWriter<RestartRunEvent>,
+    mut restore_events: EventWriter<RestoreCheckpointEvent>,
+    mut free_play_events: EventWriter<StartFreePlayEvent>,
+    mut prestige_profile: Option<ResMut<PrestigeProfile>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, mut bg_color, mut border_color, results_button) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                match results_button.action {
+                    ResultsAction::ContinueFreePlay => {
+                        free_play_events.write(StartFreePlayEvent);
+                    }
+                    ResultsAction::RetryFromCheckpoint => {
+                        restore_events.write(RestoreCheckpointEvent);
+                    }
+                    ResultsAction::TogglePrestige(modifier) => {
+                        if let Some(profile) = prestige_profile.as_mut() {
+                            profile.toggle(modifier);
+                            if let Err(error) = profile.save() {
+                                warn!("Failed to save the prestige profile to {}: {}", PRESTIGE_PROFILE_FILE, error);
+                            }
+                        }
+                    }
+                    ResultsAction::MainMenu => {
+                        // The board is reset behind the menu, ready for New Game
+                        restart_events.write(RestartRunEvent { new_seed: true });
+                        next_state.set(AppState::MainMenu);
+                    }
+                    action => {
+                        let new_seed = action == ResultsAction::NewSeed;
+                        restart_events.write(RestartRunEvent { new_seed });
+                    }
+                }
+                info!("{:?} button pressed", results_button.action);
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_HOVER);
+                *border_color = BorderColor(UIColors::BORDER_HOVER);
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_DEFAULT);
+                *border_color = BorderColor(UIColors::BORDER_DEFAULT);
+            }
+        }
+    }
+}
+
+/// System to refresh the prestige toggles after the selection changes
+pub fn prestige_label_system(
+    prestige_profile: Option<Res<PrestigeProfile>>,
+    mut option_texts: Query<(&PrestigeOptionText, &mut Text), Without<PrestigeSummaryText>>,
+    mut summary_texts: Query<&mut Text, With<PrestigeSummaryText>>,
+) {
+    let Some(profile) = prestige_profile.filter(|profile| profile.is_changed()) else {
+        return;
+    };
+    for (option, mut text) in &mut option_texts {
+        **text = prestige_option_label(option.modifier, &profile.selected);
+    }
+    for mut text in &mut summary_texts {
+        **text = prestige_summary(&profile.selected);
+    }
+}
+
+/// System to move between `AppState::Playing` and `AppState::GameOver` as the
+/// run ends and is restarted, retried from a checkpoint or continued
+pub fn game_over_state_system(
+    game_state: Res<GameState>,
+    app_state: Res<State<AppState>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    // A choice already made this frame (e.g. Main Menu) wins
+    if !matches!(*next_state, NextState::Unchanged) {
+        return;
+    }
+    match app_state.get() {
+        AppState::Playing if RunOutcome::from_game_state(&game_state).is_some() => {
+            next_state.set(AppState::GameOver);
+        }
+        AppState::GameOver if *game_state == GameState::Playing => {
+            next_state.set(AppState::Playing);
+        }
+        _ => {}
+    }
+}
+
+/// System to reset the run when the player retries from the results screen
+pub fn restart_run_system(
+    mut commands: Commands,
+    mut restart_events: EventReader<RestartRunEvent>,
+    (mut wave_plan, mut wave_runtime): (ResMut<WavePlan>, ResMut<WaveRuntime>),
+    mut wave_status: ResMut<WaveStatus>,
+    mut score: ResMut<Score>,
+    mut economy: ResMut<Economy>,
+    mut game_state: ResMut<GameState>,
+    mut enemy_path: ResMut<EnemyPath>,
+    mut selection_state: ResMut<TowerSelectionState>,
+    mut obstacle_grid: ResMut<ObstacleGrid>,
+    mut buffs: ResMut<ActiveBuffs>,
+    mut checkpoints: ResMut<CheckpointState>,
+    mut base_query: Query<&mut Health, With<Base>>,
+    (settings, fixed_path, level_config, mut shared_map, mut free_play, (prestige_profile, run_prestige)): (
+        Option<Res<GameSettings>>,
+        Option<Res<FixedLevelPath>>,
+        Option<Res<LevelConfig>>,
+        Option<ResMut<PendingSharedMap>>,
+        Option<ResMut<FreePlayRun>>,
+        (Option<Res<PrestigeProfile>>, Option<ResMut<RunPrestige>>),
+    ),
+    run_entities: Query<Entity, Or<(With<Enemy>, With<DyingEnemy>, With<Projectile>, With<TowerStats>, With<ResultsOverlay>, With<Obstacle>, With<LootPickup>)>>,
+    (mut camera_query, cinematic): (Query<(&mut Transform, &mut Projection), With<Camera2d>>, Option<Res<EndCinematic>>),
+) {
+    let Some(event) = restart_events.read().last() else {
+        return;
+    };
+
+    if event.new_seed {
+        set_level_seed(rand::random());
+    }
+
+    // Clear the battlefield; tower visuals and scaffolding clean themselves up
+    for entity in run_entities.iter() {
+        commands.entity(entity).despawn();
+    }
+    // The run being replaced can no longer be resumed
+    SuspendedRun::remove_file();
+
+    *wave_plan = WavePlan::default();
+    *wave_runtime = WaveRuntime::new();
+    *wave_status = WaveStatus::default();
+    *score = Score::new();
+    *economy = Economy::default();
+    // The prestige picked on the results screen applies to the new run
+    let prestige = prestige_profile
+        .filter(|profile| profile.unlocked)
+        .map(|profile| profile.selected)
+        .unwrap_or_default();
+    economy.money = prestige.starting_money(economy.money);
+    if let Some(mut run_prestige) = run_prestige {
+        run_prestige.modifiers = prestige;
+    }
+    *buffs = ActiveBuffs::default();
+    // The base is rebuilt at full health for the new run, which refills the lives
+    for mut health in &mut base_query {
+        *health = Health::new(BASE_MAX_HEALTH);
+    }
+    let difficulty = settings.map(|settings| settings.difficulty).unwrap_or_default();
+    *checkpoints = CheckpointState::new(difficulty.checkpoint_retries());
+    *game_state = GameState::Playing;
+    if let Some(free_play) = free_play.as_mut() {
+        **free_play = FreePlayRun::default();
+    }
+    // An imported map replaces generation for this run only
+    if let Some(map) = shared_map.as_mut().and_then(|pending| pending.0.take()) {
+        apply_shared_map(&mut commands, &map, &mut obstacle_grid, &mut enemy_path);
+    } else if fixed_path.is_none() {
+        // The map settings are kept, so a retry is on the same map
+        let level_config = level_config.as_deref().copied().unwrap_or_default();
+        *enemy_path = generate_level_path_with_config(1, &level_config);
+        spawn_level_obstacles(&mut commands, &mut obstacle_grid, &level_config);
+    }
+    commands.insert_resource(GameRng::from_seed(current_level_seed()));
+    selection_state.clear_selection();
+    dismiss_results_screen(&mut commands, &mut camera_query, cinematic);
+
+    info!("Started new run with seed {} and prestige {}", current_level_seed(), prestige.label());
+}
+
+/// System to carry a won campaign over into free play. The board, towers,
+/// economy and score stay as they are; only the end-of-run state is undone so
+/// the next wave can be started as usual.
+pub fn start_free_play_system(
+    mut commands: Commands,
+    mut free_play_events: EventReader<StartFreePlayEvent>,
+    mut free_play: ResMut<FreePlayRun>,
+    mut game_state: ResMut<GameState>,
+    wave_plan: Res<WavePlan>,
+    score: Res<Score>,
+    run_results: Option<Res<RunResults>>,
+    mut selection_state: ResMut<TowerSelectionState>,
+    overlays: Query<Entity, With<ResultsOverlay>>,
+    mut camera_query: Query<(&mut Transform, &mut Projection), With<Camera2d>>,
+    cinematic: Option<Res<EndCinematic>>,
+) {
+    if free_play_events.read().last().is_none() || *game_state != GameState::Victory {
+        return;
+    }
+
+    free_play.start(wave_plan.current_wave, &score, run_results.as_deref().cloned());
+    *game_state = GameState::Playing;
+    for overlay in &overlays {
+        commands.entity(overlay).despawn();
+    }
+    selection_state.clear_selection();
+    dismiss_results_screen(&mut commands, &mut camera_query, cinematic);
+
+    info!("Continuing in free play after winning on wave {}", free_play.start_wave);
+}
+
+/// Put the camera back where the end cinematic started and drop the run results
+pub fn dismiss_results_screen(
+    commands: &mut Commands,
+    camera_query: &mut Query<(&mut Transform, &mut Projection), With<Camera2d>>,
+    cinematic: Option<Res<EndCinematic>>,
+) {
+    if let Some(cinematic) = cinematic {
+        if let Ok((mut transform, mut projection)) = camera_query.single_mut() {
+            transform.translation.x = cinematic.start_position.x;
+            transform.translation.y = cinematic.start_position.y;
+            if let Projection::Orthographic(orthographic) = projection.as_mut() {
+                orthographic.scale = cinematic.start_scale;
+            }
+        }
+    }
+    commands.remove_resource::<EndCinematic>();
+    commands.remove_resource::<RunResults>();
+}
+
+// ============================================================================
+// RESULTS SCREEN PLUGIN
+// ============================================================================
+
+pub struct ResultsScreenPlugin;
+
+impl Plugin for ResultsScreenPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<RestartRunEvent>()
+            .add_event::<RestoreCheckpointEvent>()
+            .add_event::<StartFreePlayEvent>()
+            .init_resource::<FreePlayRun>()
+            .insert_resource(RunLeaderboard::load())
+            .insert_resource(PrestigeProfile::load())
+            .init_resource::<RunPrestige>()
+            .add_systems(
+                Update,
+                (
+                    results_button_system,
+                    prestige_label_system,
+                    restart_run_system,
+                    start_free_play_system,
+                    capture_run_results_system,
+                    game_over_state_system,
+                    end_cinematic_camera_system,
+                    rolling_counter_system,
+                ).chain().in_set(GameSystemSet::UI)
+            );
+    }
+}
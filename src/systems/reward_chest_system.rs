@@ -0,0 +1,260 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::resources::*;
+use crate::systems::boss_arena_system::BossArenaSettings;
+use crate::systems::results_screen::RestartRunEvent;
+use crate::systems::shop_system::{reinforce_bases, shop_open_system};
+use crate::systems::ui_feedback::UiFeedback;
+
+// ============================================================================
+// CHEST COMPONENTS
+// ============================================================================
+
+#[derive(Component)]
+pub struct RewardChestOverlay;
+
+/// Button taking the chest reward at `index`
+#[derive(Component)]
+pub struct RewardChestButton {
+    pub index: usize,
+}
+
+// ============================================================================
+// UI COLOR CONSTANTS (matching the shop)
+// ============================================================================
+
+struct UIColors;
+
+impl UIColors {
+    const PANEL_BG: Color = Color::srgb(0.08, 0.12, 0.18);
+    const PANEL_BORDER: Color = Color::srgb(0.70, 0.55, 0.20);
+    const BUTTON_DEFAULT: Color = Color::srgb(0.15, 0.20, 0.28);
+    const BUTTON_HOVER: Color = Color::srgb(0.20, 0.28, 0.38);
+    const BORDER_DEFAULT: Color = Color::srgb(0.32, 0.38, 0.48);
+    const BORDER_HOVER: Color = Color::srgb(0.85, 0.70, 0.30);
+    const TEXT_PRIMARY: Color = Color::srgb(0.96, 0.96, 0.98);
+    const TEXT_SECONDARY: Color = Color::srgb(0.78, 0.82, 0.88);
+    const TEXT_ACCENT: Color = Color::srgb(0.88, 0.92, 0.62);
+    const OVERLAY_BG: Color = Color::srgba(0.0, 0.0, 0.0, 0.35);
+}
+
+// ============================================================================
+// CHEST SYSTEMS
+// ============================================================================
+
+/// System to offer a chest once a boss wave is cleared, and forfeit it when the next wave starts
+pub fn reward_chest_open_system(
+    wave_plan: Res<WavePlan>,
+    wave_runtime: Res<WaveRuntime>,
+    game_state: Res<GameState>,
+    boss_settings: Res<BossArenaSettings>,
+    enemies: Query<(), With<Enemy>>,
+    mut chest: ResMut<RewardChest>,
+    mut rng: ResMut<GameRng>,
+) {
+    let wave = wave_plan.current_wave;
+    if chest.open {
+        if wave > chest.wave {
+            chest.close();
+        }
+        return;
+    }
+
+    let cleared = wave_runtime.wave_complete(&wave_plan) && enemies.is_empty();
+    if cleared && boss_settings.is_boss_wave(wave) && wave > chest.wave && *game_state == GameState::Playing {
+        chest.open_after(wave, &mut rng);
+        info!("Reward chest after wave {}: {:?}", wave, chest.rewards);
+    }
+}
+
+/// System to rebuild the chest panel whenever the chest opens or closes
+pub fn reward_chest_panel_system(
+    mut commands: Commands,
+    chest: Res<RewardChest>,
+    formatter: Res<NumberFormatter>,
+    overlays: Query<Entity, With<RewardChestOverlay>>,
+) {
+    if !chest.is_changed() && !(chest.open && formatter.is_changed()) {
+        return;
+    }
+
+    for entity in overlays.iter() {
+        commands.entity(entity).despawn();
+    }
+    if chest.open {
+        spawn_chest_panel(&mut commands, &chest, &formatter);
+    }
+}
+
+fn spawn_chest_panel(commands: &mut Commands, chest: &RewardChest, formatter: &NumberFormatter) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            top: Val::Px(0.0),
+            width: Val::Vw(100.0),
+            height: Val::Vh(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(UIColors::OVERLAY_BG),
+        ZIndex(820), // Above the shop, below the results screen and pause menu
+        RewardChestOverlay,
+    )).with_children(|parent| {
+        parent.spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(25.0)),
+                row_gap: Val::Px(15.0),
+                border: UiRect::all(Val::Px(3.0)),
+                ..default()
+            },
+            BackgroundColor(UIColors::PANEL_BG),
+            BorderColor(UIColors::PANEL_BORDER),
+            BorderRadius::all(Val::Px(15.0)),
+        )).with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("BOSS CHEST - WAVE {}", chest.wave)),
+                TextFont {
+                    font_size: 28.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_PRIMARY),
+            ));
+            parent.spawn((
+                Text::new("Choose one reward"),
+                TextFont {
+                    font_size: 15.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_SECONDARY),
+            ));
+
+            parent.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(12.0),
+                ..default()
+            }).with_children(|parent| {
+                for (index, reward) in chest.rewards.iter().enumerate() {
+                    create_reward_button(parent, index, reward, formatter);
+                }
+            });
+        });
+    });
+}
+
+fn create_reward_button(parent: &mut ChildSpawnerCommands, index: usize, reward: &ChestReward, formatter: &NumberFormatter) {
+    parent.spawn((
+        Button,
+        Node {
+            width: Val::Px(170.0),
+            height: Val::Px(130.0),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            padding: UiRect::all(Val::Px(10.0)),
+            row_gap: Val::Px(8.0),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(UIColors::BUTTON_DEFAULT),
+        BorderColor(UIColors::BORDER_DEFAULT),
+        BorderRadius::all(Val::Px(8.0)),
+        RewardChestButton { index },
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(reward.get_name(formatter)),
+            TextFont {
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(UIColors::TEXT_ACCENT),
+        ));
+        parent.spawn((
+            Text::new(reward.get_description()),
+            TextFont {
+                font_size: 13.0,
+                ..default()
+            },
+            TextColor(UIColors::TEXT_SECONDARY),
+            TextLayout::new_with_justify(JustifyText::Center),
+        ));
+    });
+}
+
+/// System to hand out the reward the player picks from the chest
+pub fn reward_chest_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor, &RewardChestButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut chest: ResMut<RewardChest>,
+    mut economy: ResMut<Economy>,
+    mut perks: ResMut<RunPerks>,
+    mut base_query: Query<&mut Health, With<Base>>,
+    mut feedback: UiFeedback,
+) {
+    for (interaction, mut bg_color, mut border_color, button) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                if let Some(reward) = chest.try_claim(button.index, &mut economy, &mut perks) {
+                    if reward == ChestReward::Perk(Perk::Reinforcements) {
+                        reinforce_bases(&mut base_query);
+                    }
+                    println!("Took {} from the chest", reward.get_name(&NumberFormatter::default()));
+                    feedback.confirm();
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_HOVER);
+                *border_color = BorderColor(UIColors::BORDER_HOVER);
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_DEFAULT);
+                *border_color = BorderColor(UIColors::BORDER_DEFAULT);
+            }
+        }
+    }
+}
+
+/// System to drop an unopened chest when a new run starts
+pub fn reset_chest_on_restart_system(
+    mut restart_events: EventReader<RestartRunEvent>,
+    mut chest: ResMut<RewardChest>,
+) {
+    if restart_events.read().last().is_none() {
+        return;
+    }
+    *chest = RewardChest::default();
+}
+
+// ============================================================================
+// CHEST PLUGIN
+// ============================================================================
+
+/// Plugin offering a choice of one of three rewards after every boss wave
+pub struct RewardChestPlugin;
+
+impl Plugin for RewardChestPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<RewardChest>()
+            .init_resource::<BossArenaSettings>()
+            .add_systems(
+                Update,
+                (reward_chest_button_system, reset_chest_on_restart_system, reward_chest_panel_system)
+                    .chain()
+                    .in_set(GameSystemSet::UI),
+            )
+            .add_systems(
+                Update,
+                reward_chest_open_system
+                    .in_set(GameSystemSet::Gameplay)
+                    .after(EnemySet::Cleanup)
+                    .before(shop_open_system)
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
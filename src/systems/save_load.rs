@@ -0,0 +1,129 @@
+use bevy::ecs::event::EventCursor;
+use bevy::prelude::*;
+use crate::resources::{GameSystemSet, SaveFormat};
+use crate::systems::debug_toggle::DebugToggle;
+use crate::systems::input::{InputContext, InputHandler, InputRegistryAppExt};
+use crate::systems::suspend_system::{save_format, RunRestorer, SuspendedRun};
+
+/// Save the debug save state is written to. It shares the suspend file format.
+pub const SAVE_STATE_SAVE: &str = "debug_save_state";
+pub const SAVE_STATE_KEY: KeyCode = KeyCode::F5;
+pub const LOAD_STATE_KEY: KeyCode = KeyCode::F8;
+
+/// Request from the debug panel or a shortcut to save or load the game state
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SaveLoadRequest {
+    Save,
+    Load,
+}
+
+/// Write the run in `world` as the save named `stem` in `format`, mid-wave or
+/// between waves
+pub fn save_game_state(world: &World, stem: &str, format: SaveFormat) -> Result<SuspendedRun, String> {
+    let run = SuspendedRun::snapshot(world).ok_or("no run in play to save")?;
+    SuspendedRun::migrations()
+        .write_file(&run, stem, format)
+        .map_err(|e| e.to_string())?;
+    Ok(run)
+}
+
+/// Read a run saved with `save_game_state`
+pub fn load_game_state(stem: &str) -> Result<SuspendedRun, String> {
+    SuspendedRun::migrations().read_file(stem).map_err(|e| e.to_string())
+}
+
+/// Input handler for the save and load state shortcuts, gated behind debug features like the cheat menu
+pub struct SaveStateHandler;
+
+impl InputHandler for SaveStateHandler {
+    fn handle_input(&self, world: &mut World, key: KeyCode) -> bool {
+        let request = if key == SAVE_STATE_KEY {
+            SaveLoadRequest::Save
+        } else if key == LOAD_STATE_KEY {
+            SaveLoadRequest::Load
+        } else {
+            return false;
+        };
+
+        if let Some(debug_toggle) = world.get_resource::<DebugToggle>() {
+            if !debug_toggle.is_enabled() {
+                info!("{:?} (Save/Load State) blocked - Press ` (backtick) to enable debug features", key);
+                return true;
+            }
+        }
+
+        world.send_event(request);
+        true
+    }
+
+    fn get_description(&self) -> &str {
+        "Save (F5) or load (F8) the game state"
+    }
+
+    fn get_priority(&self) -> u8 {
+        40
+    }
+
+    fn get_id(&self) -> &str {
+        "save_load_state"
+    }
+
+    fn handles_key(&self, key: KeyCode) -> bool {
+        key == SAVE_STATE_KEY || key == LOAD_STATE_KEY
+    }
+
+    fn get_handled_keys(&self) -> Vec<KeyCode> {
+        vec![SAVE_STATE_KEY, LOAD_STATE_KEY]
+    }
+
+    fn get_context(&self) -> InputContext {
+        InputContext::Admin
+    }
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// System to write the game state to `SAVE_STATE_SAVE` on request. Reads the
+/// whole world, so it takes the requests through its own cursor.
+pub fn save_state_system(world: &World, mut requests: Local<EventCursor<SaveLoadRequest>>) {
+    let Some(events) = world.get_resource::<Events<SaveLoadRequest>>() else {
+        return;
+    };
+    if requests.read(events).filter(|request| **request == SaveLoadRequest::Save).count() == 0 {
+        return;
+    }
+    match save_game_state(world, SAVE_STATE_SAVE, save_format(world)) {
+        Ok(run) => info!("Saved wave {} with {} towers to {}", run.wave.current, run.towers.len(), SAVE_STATE_SAVE),
+        Err(error) => warn!("Failed to save the game state: {}", error),
+    }
+}
+
+/// System to put the game state in `SAVE_STATE_SAVE` back on the board on request
+pub fn load_state_system(mut requests: EventReader<SaveLoadRequest>, mut restorer: RunRestorer) {
+    if requests.read().filter(|request| **request == SaveLoadRequest::Load).count() == 0 {
+        return;
+    }
+    let restored = load_game_state(SAVE_STATE_SAVE).and_then(|run| restorer.restore(&run).map(|()| run));
+    match restored {
+        Ok(run) => info!("Loaded wave {} with {} towers from {}", run.wave.current, run.towers.len(), SAVE_STATE_SAVE),
+        Err(error) => warn!("Failed to load the game state from {}: {}", SAVE_STATE_SAVE, error),
+    }
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin saving and restoring the game state mid-session from the debug panel or F5/F8
+pub struct SaveLoadPlugin;
+
+impl Plugin for SaveLoadPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SaveLoadRequest>()
+            .register_input_handler(SaveStateHandler)
+            // Saved before loading, so a save and load in one frame round-trips
+            .add_systems(Update, (save_state_system, load_state_system).chain().in_set(GameSystemSet::UI));
+    }
+}
@@ -0,0 +1,104 @@
+use bevy::prelude::*;
+use bevy::transform::TransformSystem;
+use crate::components::{Base, Health};
+use crate::resources::{AppState, GameSystemSet};
+use crate::systems::settings_menu::GameSettings;
+
+/// Largest camera offset, in pixels, at full trauma and full intensity
+pub const MAX_SHAKE_OFFSET: f32 = 12.0;
+/// Trauma lost per second
+pub const SHAKE_DECAY: f32 = 1.5;
+/// Trauma added each time the base is hit
+pub const BASE_HIT_TRAUMA: f32 = 0.4;
+
+/// Resource tracking how hard the camera is shaking. Trauma decays over time and
+/// the offset grows with its square, so small knocks stay subtle.
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct ScreenShake {
+    pub trauma: f32,
+    /// Offset applied to the camera this frame, taken back off next frame
+    pub offset: Vec2,
+}
+
+impl ScreenShake {
+    pub fn add_trauma(&mut self, amount: f32) {
+        self.trauma = (self.trauma + amount).clamp(0.0, 1.0);
+    }
+}
+
+/// Camera offset for a trauma level, scaled by the intensity setting. Driven by
+/// elapsed time rather than the game RNG so seeded runs stay reproducible.
+pub fn shake_offset(trauma: f32, intensity: f32, elapsed: f32) -> Vec2 {
+    let magnitude = MAX_SHAKE_OFFSET * intensity.clamp(0.0, 1.0) * trauma * trauma;
+    Vec2::new((elapsed * 43.1).sin(), (elapsed * 37.7 + 1.3).sin()) * magnitude
+}
+
+/// System to shake the camera when the base loses health
+pub fn base_hit_shake_system(
+    mut shake: ResMut<ScreenShake>,
+    bases: Query<&Health, (With<Base>, Changed<Health>)>,
+    mut last_health: Local<Option<f32>>,
+) {
+    let Ok(health) = bases.single() else {
+        return;
+    };
+    if last_health.is_some_and(|last| health.current < last) {
+        shake.add_trauma(BASE_HIT_TRAUMA);
+    }
+    *last_health = Some(health.current);
+}
+
+/// System to take last frame's shake back off the camera before anything else moves it
+pub fn remove_screen_shake_system(
+    mut shake: ResMut<ScreenShake>,
+    mut camera_query: Query<&mut Transform, With<Camera2d>>,
+) {
+    if shake.offset == Vec2::ZERO {
+        return;
+    }
+    if let Ok(mut transform) = camera_query.single_mut() {
+        transform.translation -= shake.offset.extend(0.0);
+    }
+    shake.offset = Vec2::ZERO;
+}
+
+/// System to decay trauma and offset the camera by it, scaled by the screen shake setting
+pub fn apply_screen_shake_system(
+    time: Res<Time>,
+    settings: Option<Res<GameSettings>>,
+    mut shake: ResMut<ScreenShake>,
+    mut camera_query: Query<&mut Transform, With<Camera2d>>,
+) {
+    if shake.trauma <= 0.0 {
+        return;
+    }
+    shake.trauma = (shake.trauma - SHAKE_DECAY * time.delta_secs()).max(0.0);
+
+    let intensity = settings.map_or(1.0, |settings| settings.screen_shake_intensity);
+    let offset = shake_offset(shake.trauma, intensity, time.elapsed_secs());
+    if let Ok(mut transform) = camera_query.single_mut() {
+        transform.translation += offset.extend(0.0);
+        shake.offset = offset;
+    }
+}
+
+/// Plugin to shake the camera on heavy hits, as strongly as the settings allow
+pub struct ScreenShakePlugin;
+
+impl Plugin for ScreenShakePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<ScreenShake>()
+            .add_systems(PreUpdate, remove_screen_shake_system)
+            .add_systems(
+                Update,
+                base_hit_shake_system
+                    .in_set(GameSystemSet::Gameplay)
+                    .run_if(in_state(AppState::Playing)),
+            )
+            .add_systems(
+                PostUpdate,
+                apply_screen_shake_system.before(TransformSystem::TransformPropagate),
+            );
+    }
+}
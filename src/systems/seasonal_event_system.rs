@@ -0,0 +1,120 @@
+use bevy::prelude::*;
+use crate::components::{Enemy, Health};
+use crate::resources::{ActiveSeasonalEvent, Economy, GameSystemSet, MonthDay, Score, SeasonalEvents};
+use crate::systems::settings_menu::GameSettings;
+
+/// Enemy worth a bonus bounty while a seasonal event runs
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GoldenEnemy {
+    pub bonus_bounty: u32,
+}
+
+/// Background color for the active event, or the default when none runs
+pub fn biome_clear_color(active: &ActiveSeasonalEvent) -> Color {
+    active
+        .event
+        .as_ref()
+        .and_then(|event| event.tint())
+        .unwrap_or(ClearColor::default().0)
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// System to pick the seasonal event from the date and the settings override.
+/// Keeps the golden enemy count when the same event stays selected.
+pub fn select_seasonal_event_system(
+    game_settings: Res<GameSettings>,
+    seasonal_events: Res<SeasonalEvents>,
+    mut active: ResMut<ActiveSeasonalEvent>,
+) {
+    if !game_settings.is_changed() && !seasonal_events.is_changed() {
+        return;
+    }
+    let event = seasonal_events.active(MonthDay::today(), &game_settings.seasonal_event).cloned();
+    if active.event != event {
+        match &event {
+            Some(event) => info!("Seasonal event active: {}", event.name),
+            None if active.event.is_some() => info!("Seasonal event ended"),
+            None => {}
+        }
+        *active = ActiveSeasonalEvent { event, enemies_spawned: 0 };
+    }
+}
+
+/// System to tint the map background for the active event
+pub fn apply_biome_tint_system(active: Res<ActiveSeasonalEvent>, clear_color: Option<ResMut<ClearColor>>) {
+    if !active.is_changed() {
+        return;
+    }
+    let color = biome_clear_color(&active);
+    if let Some(mut clear_color) = clear_color.filter(|clear_color| clear_color.0 != color) {
+        clear_color.0 = color;
+    }
+}
+
+// ============================================================================
+// OBSERVERS
+// ============================================================================
+
+/// Observer making every Nth enemy golden while an event with golden enemies runs
+pub fn golden_enemy_observer(
+    trigger: Trigger<OnAdd, Enemy>,
+    mut commands: Commands,
+    mut active: ResMut<ActiveSeasonalEvent>,
+    mut sprites: Query<&mut Sprite>,
+) {
+    let Some(golden) = active.next_spawn_golden() else {
+        return;
+    };
+    let [red, green, blue] = golden.color;
+    let bonus_bounty = golden.bonus_bounty;
+    let enemy = trigger.target();
+    // Hit feedback flashes from the sprite's own color, so this sticks
+    if let Ok(mut sprite) = sprites.get_mut(enemy) {
+        sprite.color = Color::srgb(red, green, blue);
+    }
+    commands.entity(enemy).insert(GoldenEnemy { bonus_bounty });
+}
+
+/// Observer paying a golden enemy's bonus when it is killed. Leaks and
+/// restarts also despawn it, but leave it with health.
+pub fn golden_bounty_observer(
+    trigger: Trigger<OnRemove, GoldenEnemy>,
+    enemies: Query<(&GoldenEnemy, &Health)>,
+    mut economy: ResMut<Economy>,
+    mut score: ResMut<Score>,
+) {
+    let Ok((golden, health)) = enemies.get(trigger.target()) else {
+        return;
+    };
+    if health.is_dead() {
+        economy.money += golden.bonus_bounty;
+        score.record_money_earned(golden.bonus_bounty);
+        info!("Golden enemy killed: +{} bonus bounty", golden.bonus_bounty);
+    }
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin enabling themed content for seasonal events. With no event active
+/// nothing is changed, so core balance is untouched.
+pub struct SeasonalEventPlugin;
+
+impl Plugin for SeasonalEventPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(SeasonalEvents::load())
+            .init_resource::<ActiveSeasonalEvent>()
+            .add_observer(golden_enemy_observer)
+            .add_observer(golden_bounty_observer)
+            .add_systems(
+                Update,
+                (select_seasonal_event_system, apply_biome_tint_system)
+                    .chain()
+                    .in_set(GameSystemSet::UI),
+            );
+    }
+}
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use crate::resources::RunIntegrity;
 
 /// Security context resource managing authorization and access control
 /// Controls who can access debug features and with what privileges
@@ -51,6 +52,13 @@ impl SecurityContext {
             && !self.is_session_expired()
     }
     
+    /// Competitive integrity mode: no admin privileges this session and a
+    /// run whose every change came from legal player actions. The leaderboard
+    /// only records runs that pass this check.
+    pub fn has_competitive_integrity(&self, integrity: &RunIntegrity) -> bool {
+        !self.admin_privileges && integrity.is_clean()
+    }
+    
     /// Check if console output is permitted
     pub fn has_console_output_permission(&self) -> bool {
         self.development_build && !self.is_session_expired()
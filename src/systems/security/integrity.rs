@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+use crate::resources::{GameSystemSet, RunIntegrity, TamperReason};
+use crate::systems::debug_toggle::DebugToggle;
+use crate::systems::debug_ui::{CheatMenuState, CheatMultipliers};
+use crate::systems::results_screen::RestartRunEvent;
+use super::SecurityContext;
+
+/// Whether any cheat menu multiplier is off its neutral value
+pub fn cheat_multipliers_active(multipliers: &CheatMultipliers) -> bool {
+    [
+        multipliers.tower_damage,
+        multipliers.tower_range,
+        multipliers.tower_fire_rate,
+        multipliers.enemy_health,
+        multipliers.enemy_speed,
+    ]
+    .iter()
+    .any(|multiplier| *multiplier != 1.0)
+}
+
+/// System to keep the run's integrity record: a new run starts clean, and
+/// opening the cheat menu, cheats in effect or admin tools mark it tampered
+pub fn run_integrity_system(
+    real_time: Res<Time<Real>>,
+    mut restart_events: EventReader<RestartRunEvent>,
+    mut integrity: ResMut<RunIntegrity>,
+    security: Option<Res<SecurityContext>>,
+    debug_toggle: Option<Res<DebugToggle>>,
+    cheat_state: Option<Res<CheatMenuState>>,
+    multipliers: Option<Res<CheatMultipliers>>,
+) {
+    if restart_events.read().count() > 0 {
+        integrity.reset();
+    }
+    integrity.tick(real_time.delta_secs_f64());
+
+    let admin = security.is_some_and(|security| security.admin_privileges)
+        || debug_toggle.is_some_and(|toggle| toggle.is_enabled());
+    if admin {
+        integrity.flag(TamperReason::AdminMode);
+    }
+    let cheats = cheat_state.is_some_and(|state| state.visible || state.god_mode)
+        || multipliers.is_some_and(|multipliers| cheat_multipliers_active(&multipliers));
+    if cheats {
+        integrity.flag(TamperReason::CheatMenu);
+    }
+}
+
+/// Plugin tracking whether runs stay cheat-free and so leaderboard-eligible
+pub struct RunIntegrityPlugin;
+
+impl Plugin for RunIntegrityPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<SecurityContext>()
+            .init_resource::<RunIntegrity>()
+            .add_systems(Update, run_integrity_system.in_set(GameSystemSet::Input));
+    }
+}
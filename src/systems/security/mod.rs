@@ -5,10 +5,12 @@ pub mod context;
 pub mod features;
 pub mod validation;
 pub mod admin_toggle;
+pub mod integrity;
 
 pub use context::*;
 pub use features::*;
 pub use validation::*;
+pub use integrity::*;
 pub use admin_toggle::{AdminToggleEvent, admin_toggle_system, admin_settings_persistence_system, initialize_admin_from_settings, admin_status_display_system, deferred_admin_settings_load};
 
 use bevy::prelude::*;
@@ -0,0 +1,250 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use crate::resources::{AppState, GameSystemSet};
+use crate::systems::path_generation::current_level_seed;
+
+/// Longest seed that can be typed, the digits of `u64::MAX`
+pub const MAX_SEED_DIGITS: usize = 20;
+
+const FIELD_BG: Color = Color::srgb(0.05, 0.08, 0.12);
+const FIELD_BORDER: Color = Color::srgb(0.32, 0.38, 0.48);
+const FIELD_BORDER_FOCUSED: Color = Color::srgb(0.58, 0.78, 1.0);
+const TEXT_PRIMARY: Color = Color::srgb(0.96, 0.96, 0.98);
+const TEXT_MUTED: Color = Color::srgb(0.58, 0.62, 0.68);
+
+/// Resource holding the seed typed on the map select screen. An empty entry
+/// leaves the seed to the picked map.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Eq)]
+pub struct SeedEntry {
+    pub text: String,
+    /// Whether typing goes into the field
+    pub focused: bool,
+}
+
+impl SeedEntry {
+    /// Entry prefilled with a seed, e.g. from `--seed`
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            text: seed.to_string(),
+            focused: false,
+        }
+    }
+
+    /// Seed typed in, or `None` to leave it to the map
+    pub fn seed(&self) -> Option<u64> {
+        parse_seed(&self.text)
+    }
+
+    /// Type a character. Only digits that keep the entry a valid seed are accepted.
+    pub fn push(&mut self, character: char) -> bool {
+        if !character.is_ascii_digit() || self.text.len() >= MAX_SEED_DIGITS {
+            return false;
+        }
+        self.text.push(character);
+        if parse_seed(&self.text).is_none() {
+            self.text.pop();
+            return false;
+        }
+        true
+    }
+
+    /// Delete the last character
+    pub fn pop(&mut self) {
+        self.text.pop();
+    }
+
+    /// Text shown in the field, with a cursor while it has focus
+    pub fn label(&self) -> String {
+        let cursor = if self.focused { "_" } else { "" };
+        if self.text.is_empty() && !self.focused {
+            "Map default".to_string()
+        } else {
+            format!("{}{}", self.text, cursor)
+        }
+    }
+}
+
+/// Parse a typed or command line seed
+pub fn parse_seed(text: &str) -> Option<u64> {
+    text.trim().parse().ok()
+}
+
+/// Seed passed on the command line as `--seed 1234` or `--seed=1234`.
+/// `Ok(None)` when no seed was passed.
+pub fn seed_from_args(args: impl IntoIterator<Item = String>) -> Result<Option<u64>, String> {
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let value = if arg == "--seed" {
+            args.next().ok_or_else(|| "--seed needs a value".to_string())?
+        } else if let Some(value) = arg.strip_prefix("--seed=") {
+            value.to_string()
+        } else {
+            continue;
+        };
+        return parse_seed(&value)
+            .map(Some)
+            .ok_or_else(|| format!("'{}' is not a valid seed, expected a whole number", value));
+    }
+    Ok(None)
+}
+
+/// HUD and results label for a seed, e.g. "Seed 1234"
+pub fn seed_label(seed: u64) -> String {
+    format!("Seed {}", seed)
+}
+
+/// Component for the seed field button on the map select screen
+#[derive(Component)]
+pub struct SeedField;
+
+/// Component for the text inside the seed field
+#[derive(Component)]
+pub struct SeedFieldText;
+
+/// Component for the seed shown in the corner of the HUD
+#[derive(Component)]
+pub struct SeedHud;
+
+/// Spawn the labelled seed field into a panel
+pub fn spawn_seed_field(panel: &mut ChildSpawnerCommands, entry: &SeedEntry) {
+    panel.spawn(Node {
+        flex_direction: FlexDirection::Row,
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(10.0),
+        margin: UiRect::bottom(Val::Px(6.0)),
+        ..default()
+    }).with_children(|row| {
+        row.spawn((
+            Text::new("SEED"),
+            TextFont {
+                font_size: 16.0,
+                ..default()
+            },
+            TextColor(TEXT_MUTED),
+        ));
+        row.spawn((
+            Button,
+            Node {
+                width: Val::Px(240.0),
+                height: Val::Px(36.0),
+                align_items: AlignItems::Center,
+                padding: UiRect::horizontal(Val::Px(8.0)),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(FIELD_BG),
+            BorderColor(FIELD_BORDER),
+            BorderRadius::all(Val::Px(6.0)),
+            SeedField,
+        )).with_children(|field| {
+            field.spawn((
+                Text::new(entry.label()),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(TEXT_PRIMARY),
+                SeedFieldText,
+            ));
+        });
+    });
+}
+
+/// System to focus the seed field when clicked and type into it while focused
+pub fn seed_field_input_system(
+    mut entry: ResMut<SeedEntry>,
+    fields: Query<&Interaction, (Changed<Interaction>, With<SeedField>)>,
+    mut keyboard_events: EventReader<KeyboardInput>,
+) {
+    if fields.iter().any(|interaction| *interaction == Interaction::Pressed) {
+        entry.focused = true;
+    }
+    if !entry.focused {
+        keyboard_events.clear();
+        return;
+    }
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Character(characters) => {
+                for character in characters.chars() {
+                    entry.push(character);
+                }
+            }
+            Key::Backspace => entry.pop(),
+            Key::Enter | Key::Escape => entry.focused = false,
+            _ => {}
+        }
+    }
+}
+
+/// System to redraw the seed field when the entry changes
+pub fn seed_field_display_system(
+    entry: Res<SeedEntry>,
+    mut texts: Query<&mut Text, With<SeedFieldText>>,
+    mut borders: Query<&mut BorderColor, With<SeedField>>,
+) {
+    if !entry.is_changed() {
+        return;
+    }
+    for mut text in &mut texts {
+        **text = entry.label();
+    }
+    let border = if entry.focused { FIELD_BORDER_FOCUSED } else { FIELD_BORDER };
+    for mut border_color in &mut borders {
+        border_color.0 = border;
+    }
+}
+
+/// System to spawn the seed label in the bottom left corner of the HUD
+pub fn setup_seed_hud(mut commands: Commands) {
+    commands.spawn((
+        Text::new(seed_label(current_level_seed())),
+        TextFont {
+            font_size: 12.0,
+            ..default()
+        },
+        TextColor(TEXT_MUTED),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(6.0),
+            left: Val::Px(8.0),
+            ..default()
+        },
+        SeedHud,
+    ));
+}
+
+/// System to keep the HUD seed in step with the level seed, which new runs,
+/// map picks and restored runs all replace
+pub fn seed_hud_system(mut texts: Query<&mut Text, With<SeedHud>>) {
+    let label = seed_label(current_level_seed());
+    for mut text in &mut texts {
+        if **text != label {
+            **text = label.clone();
+        }
+    }
+}
+
+/// Plugin for entering a seed before a game and showing the run's seed on the HUD
+pub struct SeedEntryPlugin;
+
+impl Plugin for SeedEntryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SeedEntry>()
+            .add_systems(Startup, setup_seed_hud)
+            .add_systems(
+                Update,
+                (
+                    (seed_field_input_system, seed_field_display_system)
+                        .chain()
+                        .in_set(GameSystemSet::UI)
+                        .run_if(in_state(AppState::MainMenu)),
+                    seed_hud_system.in_set(GameSystemSet::UI),
+                ),
+            );
+    }
+}
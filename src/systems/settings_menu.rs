@@ -1,5 +1,10 @@
 use bevy::prelude::*;
-use crate::resources::{AppState, GameSystemSet};
+use bevy::ui::RelativeCursorPosition;
+use crate::components::EffectCategory;
+use crate::resources::{AppState, Difficulty, RunMode, DEFAULT_VICTORY_WAVES, VICTORY_WAVE_CHOICES, EffectBudget, PathStyle, FrameLimit, GameSystemSet, SettingsReturnState, MigrationRegistry, NumberFormatter, existing_save_file, NumberLocale, SaveError, SaveFormat, SeasonalEventOverride, SeasonalEvents};
+use crate::systems::controls_menu::ControlsList;
+use crate::systems::input::KeyRebind;
+use crate::systems::privacy_system::{WipeLocalDataEvent, LOCAL_DATA_DIRS, LOCAL_DATA_FILES};
 
 // ============================================================================
 // SETTINGS MENU COMPONENTS
@@ -36,12 +41,153 @@ pub struct FullscreenText;
 #[derive(Component)]
 pub struct VSyncText;
 
+#[derive(Component)]
+pub struct DifficultyToggle;
+
+#[derive(Component)]
+pub struct DifficultyText;
+
+/// Toggle buttons for UI feedback (sounds and gamepad rumble)
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeedbackToggle {
+    UiSounds,
+    Haptics,
+}
+
+/// Text showing the state of a `FeedbackToggle`
+#[derive(Component)]
+pub struct FeedbackToggleText(pub FeedbackToggle);
+
+#[derive(Component)]
+pub struct AdvisorToggle;
+
+#[derive(Component)]
+pub struct AdvisorText;
+
+#[derive(Component)]
+pub struct SaveFormatToggle;
+
+#[derive(Component)]
+pub struct SaveFormatText;
+
+/// Toggle buttons for how numbers and money are written
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NumberFormatToggle {
+    Locale,
+    Compact,
+}
+
+/// Text showing the state of a `NumberFormatToggle`
+#[derive(Component)]
+pub struct NumberFormatToggleText(pub NumberFormatToggle);
+
+/// Toggle buttons for frame rate and power use
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PerformanceToggle {
+    FrameLimit,
+    PowerSaving,
+}
+
+/// Text showing the state of a `PerformanceToggle`
+#[derive(Component)]
+pub struct PerformanceToggleText(pub PerformanceToggle);
+
+#[derive(Component)]
+pub struct SeasonalEventToggle;
+
+#[derive(Component)]
+pub struct SeasonalEventText;
+
+/// Toggle buttons for what the game records about play and keeps on disk
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PrivacyToggle {
+    Statistics,
+    SaveRecords,
+}
+
+/// Text showing the state of a `PrivacyToggle`
+#[derive(Component)]
+pub struct PrivacyToggleText(pub PrivacyToggle);
+
+/// Pages of the settings menu
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SettingsTab {
+    #[default]
+    General,
+    Gameplay,
+    Controls,
+}
+
+impl SettingsTab {
+    pub const ALL: [SettingsTab; 3] = [SettingsTab::General, SettingsTab::Gameplay, SettingsTab::Controls];
+
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            SettingsTab::General => "GENERAL",
+            SettingsTab::Gameplay => "GAMEPLAY",
+            SettingsTab::Controls => "CONTROLS",
+        }
+    }
+}
+
+/// Container holding one tab's settings
+#[derive(Component)]
+pub struct SettingsTabContent(pub SettingsTab);
+
+/// Resource holding the settings tab on show
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ActiveSettingsTab(pub SettingsTab);
+
+/// Buttons on the gameplay tab for autosave, confirmations and assists
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameplayOption {
+    AutosaveInterval,
+    ConfirmSell,
+    AutoStartWaves,
+    AssistOverlay,
+    DamageNumbers,
+    ScreenShake,
+    PathStyle,
+    FirstBreachMoment,
+    MarketMode,
+    VictoryWaves,
+    WhatsNew,
+}
+
+/// Text showing the state of a `GameplayOption`
+#[derive(Component)]
+pub struct GameplayOptionText(pub GameplayOption);
+
+/// Fill bar of a volume slider, showing its current value
 #[derive(Component)]
 pub struct SettingsSlider {
     pub setting_type: SettingsType,
     pub value: f32,
 }
 
+/// Clickable area of a volume slider; clicking or dragging along it sets the value
+#[derive(Component)]
+pub struct SettingsSliderTrack(pub SettingsType);
+
+/// Handle of a volume slider
+#[derive(Component)]
+pub struct SettingsSliderHandle(pub SettingsType);
+
+/// Percentage shown next to a volume slider
+#[derive(Component)]
+pub struct SettingsSliderText(pub SettingsType);
+
+/// Volume for a cursor at `normalized_x` across a slider track, where the
+/// track runs from -0.5 to 0.5. Snaps to whole percent.
+pub fn slider_value_at(normalized_x: f32) -> f32 {
+    ((normalized_x + 0.5).clamp(0.0, 1.0) * 100.0).round() / 100.0
+}
+
+/// Left edge of a slider handle showing `value`, centred on the value
+fn slider_handle_left(value: f32) -> Val {
+    Val::Percent(value * 100.0 - 1.0)
+}
+
 #[derive(Clone, Debug)]
 pub enum SettingsMenuAction {
     Back,
@@ -49,6 +195,7 @@ pub enum SettingsMenuAction {
     ToggleFullscreen,
     ToggleVSync,
     ChangeResolution(ResolutionOption),
+    WipeLocalData,
 }
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -92,7 +239,7 @@ impl ResolutionOption {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum SettingsType {
     MasterVolume,
     SFXVolume,
@@ -152,6 +299,123 @@ pub struct GameSettings {
     pub sfx_volume: f32,
     pub music_volume: f32,
     pub debug_admin_enabled: bool,
+    /// Applies from the next run (e.g. checkpoint retries)
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    /// Play sound cues for UI hover/click/error feedback
+    #[serde(default = "enabled_by_default")]
+    pub ui_sounds_enabled: bool,
+    /// Rumble connected gamepads for UI feedback
+    #[serde(default = "enabled_by_default")]
+    pub ui_haptics_enabled: bool,
+    /// Show between-wave suggestions (sell idle towers, best upgrade, build spot)
+    #[serde(default = "enabled_by_default")]
+    pub advisor_enabled: bool,
+    /// Format saves are written in; either format loads regardless
+    #[serde(default)]
+    pub save_format: SaveFormat,
+    /// Separators used for money and large numbers
+    #[serde(default)]
+    pub number_locale: NumberLocale,
+    /// Shorten large numbers to e.g. "12.5k"
+    #[serde(default)]
+    pub compact_numbers: bool,
+    /// Highest frame rate to render at
+    #[serde(default)]
+    pub frame_limit: FrameLimit,
+    /// Update far less often while the window is unfocused or minimized
+    #[serde(default)]
+    pub power_saving: bool,
+    /// Seasonal event to run, or follow the date
+    #[serde(default)]
+    pub seasonal_event: SeasonalEventOverride,
+    /// Record lifetime enemy statistics for the codex
+    #[serde(default = "enabled_by_default")]
+    pub statistics_enabled: bool,
+    /// Write statistics and leaderboards to disk
+    #[serde(default = "enabled_by_default")]
+    pub save_records: bool,
+    /// Seconds between autosaves of a run in progress, 0 for never
+    #[serde(default = "default_autosave_interval")]
+    pub autosave_interval_secs: u32,
+    /// Group sells need a second click to go through
+    #[serde(default = "enabled_by_default")]
+    pub confirm_before_sell: bool,
+    /// Start the next wave on its own once the board is clear
+    #[serde(default)]
+    pub auto_start_waves: bool,
+    /// Show the placement assist overlay while choosing where to build
+    #[serde(default = "enabled_by_default")]
+    pub assist_overlay_enabled: bool,
+    #[serde(default = "enabled_by_default")]
+    pub damage_numbers_enabled: bool,
+    /// Strength of camera shake, from 0 (off) to 1
+    #[serde(default = "default_screen_shake")]
+    pub screen_shake_intensity: f32,
+    /// How the enemy path is drawn
+    #[serde(default)]
+    pub path_style: PathStyle,
+    /// Slow down and show the base the first time an enemy leaks in a run
+    #[serde(default = "enabled_by_default")]
+    pub first_breach_moment_enabled: bool,
+    /// Tower prices drift up and down from wave to wave
+    #[serde(default)]
+    pub market_mode_enabled: bool,
+    /// Campaign or endless, chosen on the main menu
+    #[serde(default)]
+    pub run_mode: RunMode,
+    /// Waves a campaign has to survive to win
+    #[serde(default = "default_victory_waves")]
+    pub victory_waves: u32,
+    /// Game version whose release notes the player last saw
+    #[serde(default)]
+    pub last_seen_version: String,
+    /// Show the "What's new" overlay after an update
+    #[serde(default = "enabled_by_default")]
+    pub whats_new_enabled: bool,
+    /// Keys the player moved away from their defaults
+    #[serde(default)]
+    pub key_bindings: Vec<KeyRebind>,
+}
+
+fn default_victory_waves() -> u32 {
+    DEFAULT_VICTORY_WAVES
+}
+
+fn enabled_by_default() -> bool {
+    true
+}
+
+/// Autosave intervals offered, in seconds; 0 turns autosave off
+pub const AUTOSAVE_INTERVALS: [u32; 5] = [0, 30, 60, 120, 300];
+/// Screen shake strengths offered
+pub const SCREEN_SHAKE_LEVELS: [f32; 3] = [0.0, 0.5, 1.0];
+
+fn default_autosave_interval() -> u32 {
+    60
+}
+
+fn default_screen_shake() -> f32 {
+    1.0
+}
+
+/// Format version written to settings.json. When the format changes, bump this
+/// and register a migration from the previous version in `GameSettings::migrations`.
+pub const SETTINGS_VERSION: u32 = 2;
+
+/// v1 files predate versioning and may lack the settings added since; write
+/// them out explicitly with their defaults
+fn migrate_settings_v1_to_v2(settings: &mut serde_json::Map<String, serde_json::Value>) -> Result<(), String> {
+    let defaults = serde_json::to_value(GameSettings::default()).map_err(|e| e.to_string())?;
+    let serde_json::Value::Object(defaults) = defaults else {
+        return Err("default settings are not a JSON object".to_string());
+    };
+    for field in ["difficulty", "ui_sounds_enabled", "ui_haptics_enabled", "advisor_enabled"] {
+        if let Some(default) = defaults.get(field) {
+            settings.entry(field).or_insert_with(|| default.clone());
+        }
+    }
+    Ok(())
 }
 
 impl Default for GameSettings {
@@ -164,52 +428,227 @@ impl Default for GameSettings {
             sfx_volume: 0.8,
             music_volume: 0.6,
             debug_admin_enabled: false, // Secure default
+            difficulty: Difficulty::Normal,
+            ui_sounds_enabled: true,
+            ui_haptics_enabled: true,
+            advisor_enabled: true,
+            save_format: SaveFormat::Json,
+            number_locale: NumberLocale::English,
+            compact_numbers: false,
+            frame_limit: FrameLimit::Unlimited,
+            power_saving: false,
+            seasonal_event: SeasonalEventOverride::Auto,
+            statistics_enabled: true,
+            save_records: true,
+            autosave_interval_secs: default_autosave_interval(),
+            confirm_before_sell: true,
+            auto_start_waves: false,
+            assist_overlay_enabled: true,
+            damage_numbers_enabled: true,
+            screen_shake_intensity: default_screen_shake(),
+            path_style: PathStyle::default(),
+            first_breach_moment_enabled: true,
+            market_mode_enabled: false,
+            run_mode: RunMode::default(),
+            victory_waves: DEFAULT_VICTORY_WAVES,
+            last_seen_version: String::new(),
+            whats_new_enabled: true,
+            key_bindings: Vec::new(),
         }
     }
 }
 
 impl GameSettings {
-    const SETTINGS_FILE: &'static str = "settings.json";
-    
-    /// Load settings from file, or create default settings if file doesn't exist
-    pub fn load() -> Self {
-        match std::fs::read_to_string(Self::SETTINGS_FILE) {
-            Ok(contents) => {
-                match serde_json::from_str::<GameSettings>(&contents) {
-                    Ok(settings) => {
-                        println!("Loaded settings from {}", Self::SETTINGS_FILE);
-                        settings
-                    }
-                    Err(e) => {
-                        println!("Failed to parse settings file: {}. Using defaults.", e);
-                        Self::default()
-                    }
-                }
+    pub fn feedback_enabled(&self, toggle: FeedbackToggle) -> bool {
+        match toggle {
+            FeedbackToggle::UiSounds => self.ui_sounds_enabled,
+            FeedbackToggle::Haptics => self.ui_haptics_enabled,
+        }
+    }
+
+    pub fn toggle_feedback(&mut self, toggle: FeedbackToggle) {
+        match toggle {
+            FeedbackToggle::UiSounds => self.ui_sounds_enabled = !self.ui_sounds_enabled,
+            FeedbackToggle::Haptics => self.ui_haptics_enabled = !self.ui_haptics_enabled,
+        }
+    }
+
+    /// Formatter for the chosen number settings
+    pub fn number_formatter(&self) -> NumberFormatter {
+        NumberFormatter::new(self.number_locale, self.compact_numbers)
+    }
+
+    pub fn number_format_label(&self, toggle: NumberFormatToggle) -> &'static str {
+        match toggle {
+            NumberFormatToggle::Locale => self.number_locale.get_name(),
+            NumberFormatToggle::Compact => if self.compact_numbers { "ON" } else { "OFF" },
+        }
+    }
+
+    pub fn cycle_number_format(&mut self, toggle: NumberFormatToggle) {
+        match toggle {
+            NumberFormatToggle::Locale => self.number_locale = self.number_locale.next(),
+            NumberFormatToggle::Compact => self.compact_numbers = !self.compact_numbers,
+        }
+    }
+
+    pub fn performance_label(&self, toggle: PerformanceToggle) -> &'static str {
+        match toggle {
+            PerformanceToggle::FrameLimit => self.frame_limit.get_name(),
+            PerformanceToggle::PowerSaving => if self.power_saving { "ON" } else { "OFF" },
+        }
+    }
+
+    pub fn cycle_performance(&mut self, toggle: PerformanceToggle) {
+        match toggle {
+            PerformanceToggle::FrameLimit => self.frame_limit = self.frame_limit.next(),
+            PerformanceToggle::PowerSaving => self.power_saving = !self.power_saving,
+        }
+    }
+
+    pub fn privacy_enabled(&self, toggle: PrivacyToggle) -> bool {
+        match toggle {
+            PrivacyToggle::Statistics => self.statistics_enabled,
+            PrivacyToggle::SaveRecords => self.save_records,
+        }
+    }
+
+    /// Label for a gameplay option's button
+    pub fn gameplay_option_label(&self, option: GameplayOption) -> String {
+        let on_off = |enabled: bool| if enabled { "ON" } else { "OFF" }.to_string();
+        match option {
+            GameplayOption::AutosaveInterval => match self.autosave_interval_secs {
+                0 => "OFF".to_string(),
+                secs if secs % 60 == 0 => format!("{} MIN", secs / 60),
+                secs => format!("{}s", secs),
+            },
+            GameplayOption::ConfirmSell => on_off(self.confirm_before_sell),
+            GameplayOption::AutoStartWaves => on_off(self.auto_start_waves),
+            GameplayOption::AssistOverlay => on_off(self.assist_overlay_enabled),
+            GameplayOption::DamageNumbers => on_off(self.damage_numbers_enabled),
+            GameplayOption::ScreenShake => match self.screen_shake_intensity {
+                intensity if intensity <= 0.0 => "OFF".to_string(),
+                intensity => format!("{:.0}%", intensity * 100.0),
+            },
+            GameplayOption::PathStyle => self.path_style.get_name().to_uppercase(),
+            GameplayOption::FirstBreachMoment => on_off(self.first_breach_moment_enabled),
+            GameplayOption::MarketMode => on_off(self.market_mode_enabled),
+            GameplayOption::VictoryWaves => format!("{} WAVES", self.victory_waves),
+            GameplayOption::WhatsNew => on_off(self.whats_new_enabled),
+        }
+    }
+
+    /// Flip a gameplay option, or step it to its next value
+    pub fn cycle_gameplay_option(&mut self, option: GameplayOption) {
+        match option {
+            GameplayOption::AutosaveInterval => {
+                let index = AUTOSAVE_INTERVALS.iter().position(|&secs| secs == self.autosave_interval_secs);
+                self.autosave_interval_secs = AUTOSAVE_INTERVALS[index.map_or(0, |index| (index + 1) % AUTOSAVE_INTERVALS.len())];
             }
-            Err(_) => {
-                println!("Settings file not found. Creating default settings.");
-                let default_settings = Self::default();
-                default_settings.save(); // Save default settings to file
-                default_settings
+            GameplayOption::ConfirmSell => self.confirm_before_sell = !self.confirm_before_sell,
+            GameplayOption::AutoStartWaves => self.auto_start_waves = !self.auto_start_waves,
+            GameplayOption::AssistOverlay => self.assist_overlay_enabled = !self.assist_overlay_enabled,
+            GameplayOption::DamageNumbers => self.damage_numbers_enabled = !self.damage_numbers_enabled,
+            GameplayOption::ScreenShake => {
+                let index = SCREEN_SHAKE_LEVELS.iter().position(|&level| level == self.screen_shake_intensity);
+                self.screen_shake_intensity = SCREEN_SHAKE_LEVELS[index.map_or(0, |index| (index + 1) % SCREEN_SHAKE_LEVELS.len())];
             }
+            GameplayOption::PathStyle => self.path_style = self.path_style.next(),
+            GameplayOption::FirstBreachMoment => self.first_breach_moment_enabled = !self.first_breach_moment_enabled,
+            GameplayOption::MarketMode => self.market_mode_enabled = !self.market_mode_enabled,
+            GameplayOption::VictoryWaves => {
+                let index = VICTORY_WAVE_CHOICES.iter().position(|&waves| waves == self.victory_waves);
+                self.victory_waves = VICTORY_WAVE_CHOICES[index.map_or(0, |index| (index + 1) % VICTORY_WAVE_CHOICES.len())];
+            }
+            GameplayOption::WhatsNew => self.whats_new_enabled = !self.whats_new_enabled,
+        }
+    }
+
+    /// Volume a slider controls, `None` for settings that aren't volumes
+    pub fn volume(&self, setting_type: SettingsType) -> Option<f32> {
+        match setting_type {
+            SettingsType::MasterVolume => Some(self.master_volume),
+            SettingsType::SFXVolume => Some(self.sfx_volume),
+            SettingsType::MusicVolume => Some(self.music_volume),
+            SettingsType::Resolution | SettingsType::Fullscreen | SettingsType::VSync => None,
+        }
+    }
+
+    /// Set the volume a slider controls, clamped to 0..=1
+    pub fn set_volume(&mut self, setting_type: SettingsType, value: f32) {
+        let value = value.clamp(0.0, 1.0);
+        match setting_type {
+            SettingsType::MasterVolume => self.master_volume = value,
+            SettingsType::SFXVolume => self.sfx_volume = value,
+            SettingsType::MusicVolume => self.music_volume = value,
+            SettingsType::Resolution | SettingsType::Fullscreen | SettingsType::VSync => {}
+        }
+    }
+
+    pub fn toggle_privacy(&mut self, toggle: PrivacyToggle) {
+        match toggle {
+            PrivacyToggle::Statistics => self.statistics_enabled = !self.statistics_enabled,
+            PrivacyToggle::SaveRecords => self.save_records = !self.save_records,
         }
     }
+
+    /// Name settings are saved under; the extension follows `save_format`
+    pub const SETTINGS_SAVE: &'static str = "settings";
+
+    /// Migrations from every older settings format to `SETTINGS_VERSION`
+    pub fn migrations() -> MigrationRegistry {
+        MigrationRegistry::new(SETTINGS_VERSION)
+            .register(1, migrate_settings_v1_to_v2)
+    }
+
+    /// Parse settings saved by this or any older version of the game
+    pub fn from_json(contents: &str) -> Result<Self, SaveError> {
+        Self::migrations().load(contents)
+    }
+
+    /// Serialize settings stamped with the current format version
+    pub fn to_json(&self) -> Result<String, SaveError> {
+        Self::migrations().save(self)
+    }
+
+    /// Parse settings saved in either format, detected from the file's contents
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SaveError> {
+        Self::migrations().load_bytes(bytes)
+    }
+
+    /// Serialize settings in the format they select
+    pub fn to_bytes(&self) -> Result<Vec<u8>, SaveError> {
+        Self::migrations().save_bytes(self, self.save_format)
+    }
     
-    /// Save current settings to file
-    pub fn save(&self) {
-        match serde_json::to_string_pretty(self) {
-            Ok(json) => {
-                if let Err(e) = std::fs::write(Self::SETTINGS_FILE, json) {
-                    println!("Failed to save settings: {}", e);
-                } else {
-                    println!("Settings saved to {}", Self::SETTINGS_FILE);
-                }
+    /// Load settings from file, or create default settings if file doesn't exist.
+    /// A file that can't be loaded falls back to defaults and returns the error for the UI.
+    pub fn load() -> (Self, Option<SaveError>) {
+        let Some(file) = existing_save_file(Self::SETTINGS_SAVE) else {
+            println!("Settings file not found. Creating default settings.");
+            let default_settings = Self::default();
+            default_settings.save(); // Save default settings to file
+            return (default_settings, None);
+        };
+        match Self::migrations().read_file(Self::SETTINGS_SAVE) {
+            Ok(settings) => {
+                println!("Loaded settings from {}", file);
+                (settings, None)
             }
             Err(e) => {
-                println!("Failed to serialize settings: {}", e);
+                println!("Failed to load settings file: {}. Using defaults.", e);
+                (Self::default(), Some(e))
             }
         }
     }
+    
+    /// Save current settings to file, in the format they select
+    pub fn save(&self) {
+        match Self::migrations().write_file(self, Self::SETTINGS_SAVE, self.save_format) {
+            Ok(file) => println!("Settings saved to {}", file),
+            Err(e) => println!("Failed to save settings: {}", e),
+        }
+    }
 }
 
 // ============================================================================
@@ -238,7 +677,7 @@ pub fn setup_settings_menu(mut commands: Commands) {
         parent.spawn((
             Node {
                 width: Val::Px(500.0),
-                height: Val::Px(500.0),  // More compact height
+                height: Val::Px(900.0),  // Gameplay options live on their own tab
                 flex_direction: FlexDirection::Column,
                 justify_content: JustifyContent::FlexStart,
                 align_items: AlignItems::Center,
@@ -266,25 +705,125 @@ pub fn setup_settings_menu(mut commands: Commands) {
                 },
             ));
             
-            // Graphics Section Header
-            create_section_header(parent, "GRAPHICS");
+            // Tab bar
+            create_settings_tabs(parent);
             
-            // Resolution setting
-            create_resolution_setting(parent);
+            // General tab: graphics, audio and privacy
+            parent.spawn((settings_tab_content_node(SettingsTab::General), SettingsTabContent(SettingsTab::General)))
+                .with_children(|parent| {
+                    // Graphics Section Header
+                    create_section_header(parent, "GRAPHICS");
+                    
+                    // Resolution setting
+                    create_resolution_setting(parent);
+                    
+                    // Fullscreen toggle
+                    create_fullscreen_toggle(parent);
+                    
+                    // VSync toggle
+                    create_vsync_toggle(parent);
+                    
+                    // Frame limit and power saving
+                    create_performance_toggle(parent, "Frame Limit:", PerformanceToggle::FrameLimit, 110.0);
+                    create_performance_toggle(parent, "Power Saving:", PerformanceToggle::PowerSaving, 80.0);
+                    
+                    // Audio Section Header
+                    create_section_header(parent, "AUDIO");
+                    
+                    // Volume sliders (more compact)
+                    create_compact_volume_slider(parent, "Master", SettingsType::MasterVolume, 1.0);
+                    create_compact_volume_slider(parent, "SFX", SettingsType::SFXVolume, 0.8);
+                    create_compact_volume_slider(parent, "Music", SettingsType::MusicVolume, 0.6);
+                    
+                    // UI feedback toggles
+                    create_feedback_toggle(parent, "UI Sounds:", FeedbackToggle::UiSounds);
+                    create_feedback_toggle(parent, "Gamepad Rumble:", FeedbackToggle::Haptics);
+                    
+                    // Privacy Section Header
+                    create_section_header(parent, "PRIVACY");
+                    
+                    // Statistics and record keeping toggles
+                    create_privacy_toggle(parent, "Collect Statistics:", PrivacyToggle::Statistics);
+                    create_privacy_toggle(parent, "Save Records:", PrivacyToggle::SaveRecords);
+                    
+                    // Wipe local data, behind a confirmation
+                    create_wipe_data_setting(parent);
+                });
             
-            // Fullscreen toggle
-            create_fullscreen_toggle(parent);
+            // Gameplay tab: rules, display preferences, assists and autosave
+            parent.spawn((settings_tab_content_node(SettingsTab::Gameplay), SettingsTabContent(SettingsTab::Gameplay)))
+                .with_children(|parent| {
+                    // Gameplay Section Header
+                    create_section_header(parent, "GAMEPLAY");
+                    
+                    // Difficulty selector
+                    create_difficulty_setting(parent);
+                    
+                    // Advisor toggle
+                    create_advisor_toggle(parent);
+                    
+                    // Save format selector
+                    create_save_format_toggle(parent);
+                    
+                    // Number format selectors
+                    create_number_format_toggle(parent, "Number Format:", NumberFormatToggle::Locale, 150.0);
+                    create_number_format_toggle(parent, "Compact Numbers:", NumberFormatToggle::Compact, 80.0);
+                    
+                    // Seasonal event selector
+                    create_seasonal_event_toggle(parent);
+                    
+                    // How the enemy path is drawn
+                    create_gameplay_option(parent, "Path Style:", GameplayOption::PathStyle);
+                    
+                    // Tower prices that drift between waves
+                    create_gameplay_option(parent, "Market Prices:", GameplayOption::MarketMode);
+                    
+                    // Waves a campaign has to survive
+                    create_gameplay_option(parent, "Victory At:", GameplayOption::VictoryWaves);
+                    
+                    // Release notes on the main menu after an update
+                    create_gameplay_option(parent, "What's New:", GameplayOption::WhatsNew);
+                    
+                    // Assists Section Header
+                    create_section_header(parent, "ASSISTS");
+                    
+                    create_gameplay_option(parent, "Confirm Before Sell:", GameplayOption::ConfirmSell);
+                    create_gameplay_option(parent, "Auto-Start Waves:", GameplayOption::AutoStartWaves);
+                    create_gameplay_option(parent, "Placement Overlay:", GameplayOption::AssistOverlay);
+                    create_gameplay_option(parent, "Damage Numbers:", GameplayOption::DamageNumbers);
+                    create_gameplay_option(parent, "Screen Shake:", GameplayOption::ScreenShake);
+                    create_gameplay_option(parent, "First Breach Moment:", GameplayOption::FirstBreachMoment);
+                    
+                    // Autosave Section Header
+                    create_section_header(parent, "AUTOSAVE");
+                    
+                    create_gameplay_option(parent, "Autosave Every:", GameplayOption::AutosaveInterval);
+                });
             
-            // VSync toggle
-            create_vsync_toggle(parent);
-            
-            // Audio Section Header
-            create_section_header(parent, "AUDIO");
-            
-            // Volume sliders (more compact)
-            create_compact_volume_slider(parent, "Master", SettingsType::MasterVolume, 1.0);
-            create_compact_volume_slider(parent, "SFX", SettingsType::SFXVolume, 0.8);
-            create_compact_volume_slider(parent, "Music", SettingsType::MusicVolume, 0.6);
+            // Controls tab: the input registry's keys, rebuilt as bindings change
+            parent.spawn((settings_tab_content_node(SettingsTab::Controls), SettingsTabContent(SettingsTab::Controls)))
+                .with_children(|parent| {
+                    create_section_header(parent, "KEY BINDINGS");
+                    
+                    parent.spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            flex_direction: FlexDirection::Column,
+                            row_gap: Val::Px(6.0),
+                            ..default()
+                        },
+                        ControlsList,
+                    ));
+                    
+                    parent.spawn((
+                        Text::new("Click a binding, then press the new key"),
+                        TextFont {
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(UIColors::TEXT_MUTED),
+                    ));
+                });
             
             // Spacer to push buttons to bottom
             parent.spawn(Node {
@@ -327,6 +866,62 @@ pub fn setup_settings_menu(mut commands: Commands) {
     commands.insert_resource(SettingsMenuEntity(settings_menu_entity));
 }
 
+fn create_settings_tabs(parent: &mut ChildSpawnerCommands) {
+    parent.spawn(Node {
+        width: Val::Percent(100.0),
+        flex_direction: FlexDirection::Row,
+        justify_content: JustifyContent::Center,
+        column_gap: Val::Px(10.0),
+        ..default()
+    }).with_children(|parent| {
+        for tab in SettingsTab::ALL {
+            parent.spawn((
+                Button,
+                Node {
+                    width: Val::Px(140.0),
+                    height: Val::Px(32.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                },
+                BackgroundColor(tab_background(tab == SettingsTab::default())),
+                BorderColor(UIColors::BORDER_DEFAULT),
+                tab,
+            )).with_children(|button| {
+                button.spawn((
+                    Text::new(tab.get_name()),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(UIColors::TEXT_PRIMARY),
+                ));
+            });
+        }
+    });
+}
+
+/// Column holding one tab's settings; only the default tab starts visible
+fn settings_tab_content_node(tab: SettingsTab) -> Node {
+    Node {
+        width: Val::Percent(100.0),
+        flex_direction: FlexDirection::Column,
+        align_items: AlignItems::Center,
+        row_gap: Val::Px(15.0),
+        display: if tab == SettingsTab::default() { Display::Flex } else { Display::None },
+        ..default()
+    }
+}
+
+fn tab_background(active: bool) -> Color {
+    if active {
+        UIColors::BUTTON_HOVER
+    } else {
+        UIColors::BUTTON_DEFAULT
+    }
+}
+
 fn create_section_header(parent: &mut ChildSpawnerCommands, text: &str) {
     parent.spawn((
         Text::new(text),
@@ -369,14 +964,19 @@ fn create_compact_volume_slider(
             TextColor(UIColors::TEXT_PRIMARY),
         ));
         
-        // Slider container (more compact)
-        parent.spawn(Node {
-            width: Val::Px(150.0),  // Smaller width
-            height: Val::Px(16.0),  // Smaller height
-            justify_content: JustifyContent::FlexStart,
-            align_items: AlignItems::Center,
-            ..default()
-        }).with_children(|parent| {
+        // Slider container (more compact), clicked or dragged to set the value
+        parent.spawn((
+            Node {
+                width: Val::Px(150.0),  // Smaller width
+                height: Val::Px(16.0),  // Smaller height
+                justify_content: JustifyContent::FlexStart,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            Interaction::default(),
+            RelativeCursorPosition::default(),
+            SettingsSliderTrack(setting_type),
+        )).with_children(|parent| {
             // Slider track
             parent.spawn((
                 Node {
@@ -408,13 +1008,14 @@ fn create_compact_volume_slider(
             parent.spawn((
                 Node {
                     position_type: PositionType::Absolute,
-                    left: Val::Percent(initial_value * 100.0 - 1.0), // Center on value
+                    left: slider_handle_left(initial_value), // Center on value
                     width: Val::Px(12.0),
                     height: Val::Px(12.0),
                     ..default()
                 },
                 BackgroundColor(UIColors::SLIDER_HANDLE),
                 BorderRadius::all(Val::Px(6.0)),
+                SettingsSliderHandle(setting_type),
             ));
         });
         
@@ -426,6 +1027,7 @@ fn create_compact_volume_slider(
                 ..default()
             },
             TextColor(UIColors::TEXT_SECONDARY),
+            SettingsSliderText(setting_type),
         ));
     });
 }
@@ -568,76 +1170,515 @@ fn create_vsync_toggle(parent: &mut ChildSpawnerCommands) {
     });
 }
 
-fn create_settings_button(
-    parent: &mut ChildSpawnerCommands,
-    text: &str,
-    action: SettingsMenuAction,
-    text_color: Color,
-) {
-    parent.spawn((
-        Button,
-        Node {
-            width: Val::Px(160.0),
-            height: Val::Px(45.0),
-            justify_content: JustifyContent::Center,
-            align_items: AlignItems::Center,
-            border: UiRect::all(Val::Px(2.0)),
-            ..default()
-        },
-        BackgroundColor(UIColors::BUTTON_DEFAULT),
-        BorderColor(UIColors::BORDER_DEFAULT),
-        BorderRadius::all(Val::Px(6.0)),
-        SettingsButton { action },
-    )).with_children(|parent| {
+fn create_feedback_toggle(parent: &mut ChildSpawnerCommands, label: &str, toggle: FeedbackToggle) {
+    parent.spawn(Node {
+        width: Val::Percent(100.0),
+        flex_direction: FlexDirection::Row,
+        justify_content: JustifyContent::SpaceBetween,
+        align_items: AlignItems::Center,
+        ..default()
+    }).with_children(|parent| {
+        // Label
         parent.spawn((
-            Text::new(text),
+            Text::new(label),
             TextFont {
-                font_size: 16.0,
+                font_size: 14.0,
                 ..default()
             },
-            TextColor(text_color),
+            TextColor(UIColors::TEXT_PRIMARY),
         ));
+        
+        // Toggle button
+        parent.spawn((
+            Button,
+            Node {
+                width: Val::Px(80.0),
+                height: Val::Px(28.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(UIColors::BUTTON_DEFAULT),
+            BorderColor(UIColors::BORDER_DEFAULT),
+            toggle,
+        )).with_children(|button| {
+            button.spawn((
+                Text::new("ON"),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_PRIMARY),
+                FeedbackToggleText(toggle),
+            ));
+        });
     });
 }
 
-// ============================================================================
-// SETTINGS MENU SYSTEMS
-// ============================================================================
-
-#[derive(Resource)]
-pub struct SettingsMenuEntity(pub Entity);
-
-/// System to show/hide settings menu based on app state
-pub fn settings_menu_visibility_system(
-    app_state: Res<State<AppState>>,
-    settings_menu_entity: Res<SettingsMenuEntity>,
-    mut visibility_query: Query<&mut Visibility>,
-) {
-    if app_state.is_changed() {
-        if let Ok(mut visibility) = visibility_query.get_mut(settings_menu_entity.0) {
-            *visibility = match app_state.get() {
-                AppState::Settings => Visibility::Visible,
-                _ => Visibility::Hidden,
-            };
-        }
-    }
-}
-
-/// System to handle settings menu button interactions
-pub fn settings_menu_button_system(
-    mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor, &mut BorderColor, &SettingsButton),
-        (Changed<Interaction>, With<Button>),
-    >,
-    mut next_state: ResMut<NextState<AppState>>,
-) {
-    for (interaction, mut bg_color, mut border_color, settings_button) in &mut interaction_query {
+fn create_advisor_toggle(parent: &mut ChildSpawnerCommands) {
+    parent.spawn(Node {
+        width: Val::Percent(100.0),
+        flex_direction: FlexDirection::Row,
+        justify_content: JustifyContent::SpaceBetween,
+        align_items: AlignItems::Center,
+        ..default()
+    }).with_children(|parent| {
+        // Label
+        parent.spawn((
+            Text::new("Advisor:"),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(UIColors::TEXT_PRIMARY),
+        ));
+        
+        // Toggle button
+        parent.spawn((
+            Button,
+            Node {
+                width: Val::Px(80.0),
+                height: Val::Px(28.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(UIColors::BUTTON_DEFAULT),
+            BorderColor(UIColors::BORDER_DEFAULT),
+            AdvisorToggle,
+        )).with_children(|button| {
+            button.spawn((
+                Text::new("ON"),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_PRIMARY),
+                AdvisorText,
+            ));
+        });
+    });
+}
+
+fn create_save_format_toggle(parent: &mut ChildSpawnerCommands) {
+    parent.spawn(Node {
+        width: Val::Percent(100.0),
+        flex_direction: FlexDirection::Row,
+        justify_content: JustifyContent::SpaceBetween,
+        align_items: AlignItems::Center,
+        ..default()
+    }).with_children(|parent| {
+        // Label
+        parent.spawn((
+            Text::new("Save Format:"),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(UIColors::TEXT_PRIMARY),
+        ));
+        
+        // Toggle button
+        parent.spawn((
+            Button,
+            Node {
+                width: Val::Px(80.0),
+                height: Val::Px(28.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(UIColors::BUTTON_DEFAULT),
+            BorderColor(UIColors::BORDER_DEFAULT),
+            SaveFormatToggle,
+        )).with_children(|button| {
+            button.spawn((
+                Text::new("JSON"),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_PRIMARY),
+                SaveFormatText,
+            ));
+        });
+    });
+}
+
+fn create_number_format_toggle(parent: &mut ChildSpawnerCommands, label: &str, toggle: NumberFormatToggle, width: f32) {
+    parent.spawn(Node {
+        width: Val::Percent(100.0),
+        flex_direction: FlexDirection::Row,
+        justify_content: JustifyContent::SpaceBetween,
+        align_items: AlignItems::Center,
+        ..default()
+    }).with_children(|parent| {
+        // Label
+        parent.spawn((
+            Text::new(label),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(UIColors::TEXT_PRIMARY),
+        ));
+        
+        // Toggle button
+        parent.spawn((
+            Button,
+            Node {
+                width: Val::Px(width),
+                height: Val::Px(28.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(UIColors::BUTTON_DEFAULT),
+            BorderColor(UIColors::BORDER_DEFAULT),
+            toggle,
+        )).with_children(|button| {
+            button.spawn((
+                Text::new(GameSettings::default().number_format_label(toggle)),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_PRIMARY),
+                NumberFormatToggleText(toggle),
+            ));
+        });
+    });
+}
+
+fn create_performance_toggle(parent: &mut ChildSpawnerCommands, label: &str, toggle: PerformanceToggle, width: f32) {
+    parent.spawn(Node {
+        width: Val::Percent(100.0),
+        flex_direction: FlexDirection::Row,
+        justify_content: JustifyContent::SpaceBetween,
+        align_items: AlignItems::Center,
+        ..default()
+    }).with_children(|parent| {
+        // Label
+        parent.spawn((
+            Text::new(label),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(UIColors::TEXT_PRIMARY),
+        ));
+        
+        // Toggle button
+        parent.spawn((
+            Button,
+            Node {
+                width: Val::Px(width),
+                height: Val::Px(28.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(UIColors::BUTTON_DEFAULT),
+            BorderColor(UIColors::BORDER_DEFAULT),
+            toggle,
+        )).with_children(|button| {
+            button.spawn((
+                Text::new(GameSettings::default().performance_label(toggle)),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_PRIMARY),
+                PerformanceToggleText(toggle),
+            ));
+        });
+    });
+}
+
+fn create_seasonal_event_toggle(parent: &mut ChildSpawnerCommands) {
+    parent.spawn(Node {
+        width: Val::Percent(100.0),
+        flex_direction: FlexDirection::Row,
+        justify_content: JustifyContent::SpaceBetween,
+        align_items: AlignItems::Center,
+        ..default()
+    }).with_children(|parent| {
+        // Label
+        parent.spawn((
+            Text::new("Seasonal Event:"),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(UIColors::TEXT_PRIMARY),
+        ));
+        
+        // Toggle button
+        parent.spawn((
+            Button,
+            Node {
+                width: Val::Px(150.0),
+                height: Val::Px(28.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(UIColors::BUTTON_DEFAULT),
+            BorderColor(UIColors::BORDER_DEFAULT),
+            SeasonalEventToggle,
+        )).with_children(|button| {
+            button.spawn((
+                Text::new(SeasonalEvents::default().override_label(&SeasonalEventOverride::Auto)),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_PRIMARY),
+                SeasonalEventText,
+            ));
+        });
+    });
+}
+
+fn create_privacy_toggle(parent: &mut ChildSpawnerCommands, label: &str, toggle: PrivacyToggle) {
+    parent.spawn(Node {
+        width: Val::Percent(100.0),
+        flex_direction: FlexDirection::Row,
+        justify_content: JustifyContent::SpaceBetween,
+        align_items: AlignItems::Center,
+        ..default()
+    }).with_children(|parent| {
+        // Label
+        parent.spawn((
+            Text::new(label),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(UIColors::TEXT_PRIMARY),
+        ));
+        
+        // Toggle button
+        parent.spawn((
+            Button,
+            Node {
+                width: Val::Px(80.0),
+                height: Val::Px(28.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(UIColors::BUTTON_DEFAULT),
+            BorderColor(UIColors::BORDER_DEFAULT),
+            toggle,
+        )).with_children(|button| {
+            button.spawn((
+                Text::new("ON"),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_PRIMARY),
+                PrivacyToggleText(toggle),
+            ));
+        });
+    });
+}
+
+fn create_gameplay_option(parent: &mut ChildSpawnerCommands, label: &str, option: GameplayOption) {
+    parent.spawn(Node {
+        width: Val::Percent(100.0),
+        flex_direction: FlexDirection::Row,
+        justify_content: JustifyContent::SpaceBetween,
+        align_items: AlignItems::Center,
+        ..default()
+    }).with_children(|parent| {
+        // Label
+        parent.spawn((
+            Text::new(label),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(UIColors::TEXT_PRIMARY),
+        ));
+        
+        // Toggle button
+        parent.spawn((
+            Button,
+            Node {
+                width: Val::Px(80.0),
+                height: Val::Px(28.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(UIColors::BUTTON_DEFAULT),
+            BorderColor(UIColors::BORDER_DEFAULT),
+            option,
+        )).with_children(|button| {
+            button.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_PRIMARY),
+                GameplayOptionText(option),
+            ));
+        });
+    });
+}
+
+fn create_wipe_data_setting(parent: &mut ChildSpawnerCommands) {
+    parent.spawn(Node {
+        width: Val::Percent(100.0),
+        flex_direction: FlexDirection::Row,
+        justify_content: JustifyContent::SpaceBetween,
+        align_items: AlignItems::Center,
+        ..default()
+    }).with_children(|parent| {
+        // Label
+        parent.spawn((
+            Text::new("Local Data:"),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(UIColors::TEXT_PRIMARY),
+        ));
+        
+        create_settings_button(parent, "WIPE", SettingsMenuAction::WipeLocalData, UIColors::TEXT_ERROR);
+    });
+}
+
+fn create_difficulty_setting(parent: &mut ChildSpawnerCommands) {
+    parent.spawn(Node {
+        width: Val::Percent(100.0),
+        flex_direction: FlexDirection::Row,
+        justify_content: JustifyContent::SpaceBetween,
+        align_items: AlignItems::Center,
+        ..default()
+    }).with_children(|parent| {
+        // Label
+        parent.spawn((
+            Text::new("Difficulty:"),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(UIColors::TEXT_PRIMARY),
+        ));
+        
+        // Cycle button
+        parent.spawn((
+            Button,
+            Node {
+                width: Val::Px(120.0),
+                height: Val::Px(28.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(UIColors::BUTTON_DEFAULT),
+            BorderColor(UIColors::BORDER_DEFAULT),
+            DifficultyToggle,
+        )).with_children(|button| {
+            button.spawn((
+                Text::new("Normal"),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_PRIMARY),
+                DifficultyText,
+            ));
+        });
+    });
+}
+
+fn create_settings_button(
+    parent: &mut ChildSpawnerCommands,
+    text: &str,
+    action: SettingsMenuAction,
+    text_color: Color,
+) {
+    parent.spawn((
+        Button,
+        Node {
+            width: Val::Px(160.0),
+            height: Val::Px(45.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(UIColors::BUTTON_DEFAULT),
+        BorderColor(UIColors::BORDER_DEFAULT),
+        BorderRadius::all(Val::Px(6.0)),
+        SettingsButton { action },
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(text),
+            TextFont {
+                font_size: 16.0,
+                ..default()
+            },
+            TextColor(text_color),
+        ));
+    });
+}
+
+// ============================================================================
+// SETTINGS MENU SYSTEMS
+// ============================================================================
+
+#[derive(Resource)]
+pub struct SettingsMenuEntity(pub Entity);
+
+/// System to show/hide settings menu based on app state
+pub fn settings_menu_visibility_system(
+    app_state: Res<State<AppState>>,
+    settings_menu_entity: Res<SettingsMenuEntity>,
+    mut visibility_query: Query<&mut Visibility>,
+) {
+    if app_state.is_changed() {
+        if let Ok(mut visibility) = visibility_query.get_mut(settings_menu_entity.0) {
+            *visibility = match app_state.get() {
+                AppState::Settings => Visibility::Visible,
+                _ => Visibility::Hidden,
+            };
+        }
+    }
+}
+
+/// System to handle settings menu button interactions
+pub fn settings_menu_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor, &SettingsButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<AppState>>,
+    settings_return: Res<SettingsReturnState>,
+    wipe_dialog_query: Query<(), With<WipeDataDialog>>,
+) {
+    for (interaction, mut bg_color, mut border_color, settings_button) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
                 match &settings_button.action {
                     SettingsMenuAction::Back => {
-                        next_state.set(AppState::Paused);
-                        info!("Back button pressed - returning to pause menu");
+                        next_state.set(settings_return.0);
+                        info!("Back button pressed - returning to {:?}", settings_return.0);
                     }
                     SettingsMenuAction::ResetToDefaults => {
                         info!("Reset to defaults button pressed");
@@ -651,6 +1692,11 @@ pub fn settings_menu_button_system(
                     SettingsMenuAction::ChangeResolution(_resolution) => {
                         info!("Resolution change pressed");
                     }
+                    SettingsMenuAction::WipeLocalData => {
+                        if wipe_dialog_query.is_empty() {
+                            spawn_wipe_data_dialog(&mut commands);
+                        }
+                    }
                 }
             }
             Interaction::Hovered => {
@@ -701,29 +1747,293 @@ pub fn fullscreen_toggle_system(
     }
 }
 
-/// System to handle VSync toggle button
-pub fn vsync_toggle_system(
+/// System to handle VSync toggle button
+pub fn vsync_toggle_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<VSyncToggle>),
+    >,
+    mut windows: Query<&mut Window>,
+    mut game_settings: ResMut<GameSettings>,
+) {
+    for (interaction, mut bg_color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                game_settings.vsync_enabled = !game_settings.vsync_enabled;
+                
+                if let Ok(mut window) = windows.single_mut() {
+                    window.present_mode = if game_settings.vsync_enabled {
+                        bevy::window::PresentMode::AutoVsync
+                    } else {
+                        bevy::window::PresentMode::AutoNoVsync
+                    };
+                }
+                
+                info!("VSync toggled: {}", game_settings.vsync_enabled);
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_HOVER);
+                *border_color = BorderColor(UIColors::BORDER_HOVER);
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_DEFAULT);
+                *border_color = BorderColor(UIColors::BORDER_DEFAULT);
+            }
+        }
+    }
+}
+
+/// System to handle resolution button (cycles through available resolutions)
+pub fn resolution_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor, &mut ResolutionButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut windows: Query<&mut Window>,
+    mut game_settings: ResMut<GameSettings>,
+) {
+    for (interaction, mut bg_color, mut border_color, mut resolution_button) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                // Cycle to next resolution
+                let resolutions = ResolutionOption::all();
+                let current_index = resolutions.iter().position(|r| *r == resolution_button.resolution).unwrap_or(0);
+                let next_index = (current_index + 1) % resolutions.len();
+                let new_resolution = resolutions[next_index].clone();
+                
+                resolution_button.resolution = new_resolution.clone();
+                game_settings.current_resolution = new_resolution.clone();
+                
+                if let Ok(mut window) = windows.single_mut() {
+                    let size = new_resolution.to_vec2();
+                    window.resolution = (size.x, size.y).into();
+                }
+                
+                info!("Resolution changed to: {}", new_resolution.to_string());
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_HOVER);
+                *border_color = BorderColor(UIColors::BORDER_HOVER);
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_DEFAULT);
+                *border_color = BorderColor(UIColors::BORDER_DEFAULT);
+            }
+        }
+    }
+}
+
+/// System to handle difficulty button (cycles Easy -> Normal -> Hard)
+pub fn difficulty_toggle_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<DifficultyToggle>),
+    >,
+    mut game_settings: ResMut<GameSettings>,
+) {
+    for (interaction, mut bg_color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                game_settings.difficulty = game_settings.difficulty.next();
+                info!("Difficulty set to {} (applies to the next run)", game_settings.difficulty.get_name());
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_HOVER);
+                *border_color = BorderColor(UIColors::BORDER_HOVER);
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_DEFAULT);
+                *border_color = BorderColor(UIColors::BORDER_DEFAULT);
+            }
+        }
+    }
+}
+
+/// System to handle the UI sound and gamepad rumble toggle buttons
+pub fn feedback_toggle_system(
+    mut interaction_query: Query<
+        (&Interaction, &FeedbackToggle, &mut BackgroundColor, &mut BorderColor),
+        Changed<Interaction>,
+    >,
+    mut game_settings: ResMut<GameSettings>,
+) {
+    for (interaction, toggle, mut bg_color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                game_settings.toggle_feedback(*toggle);
+                info!("{:?} toggled: {}", toggle, game_settings.feedback_enabled(*toggle));
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_HOVER);
+                *border_color = BorderColor(UIColors::BORDER_HOVER);
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_DEFAULT);
+                *border_color = BorderColor(UIColors::BORDER_DEFAULT);
+            }
+        }
+    }
+}
+
+/// System to handle the advisor toggle button
+pub fn advisor_toggle_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<AdvisorToggle>),
+    >,
+    mut game_settings: ResMut<GameSettings>,
+) {
+    for (interaction, mut bg_color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                game_settings.advisor_enabled = !game_settings.advisor_enabled;
+                info!("Advisor toggled: {}", game_settings.advisor_enabled);
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_HOVER);
+                *border_color = BorderColor(UIColors::BORDER_HOVER);
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_DEFAULT);
+                *border_color = BorderColor(UIColors::BORDER_DEFAULT);
+            }
+        }
+    }
+}
+
+/// System to handle the save format toggle button
+pub fn save_format_toggle_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<SaveFormatToggle>),
+    >,
+    mut game_settings: ResMut<GameSettings>,
+) {
+    for (interaction, mut bg_color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                game_settings.save_format = match game_settings.save_format {
+                    SaveFormat::Json => SaveFormat::Binary,
+                    SaveFormat::Binary => SaveFormat::Json,
+                };
+                info!("Save format changed to: {}", game_settings.save_format.get_name());
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_HOVER);
+                *border_color = BorderColor(UIColors::BORDER_HOVER);
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_DEFAULT);
+                *border_color = BorderColor(UIColors::BORDER_DEFAULT);
+            }
+        }
+    }
+}
+
+/// System to handle the number format toggle buttons
+pub fn number_format_toggle_system(
+    mut interaction_query: Query<
+        (&Interaction, &NumberFormatToggle, &mut BackgroundColor, &mut BorderColor),
+        Changed<Interaction>,
+    >,
+    mut game_settings: ResMut<GameSettings>,
+) {
+    for (interaction, toggle, mut bg_color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                game_settings.cycle_number_format(*toggle);
+                info!("{:?} changed to: {}", toggle, game_settings.number_format_label(*toggle));
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_HOVER);
+                *border_color = BorderColor(UIColors::BORDER_HOVER);
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_DEFAULT);
+                *border_color = BorderColor(UIColors::BORDER_DEFAULT);
+            }
+        }
+    }
+}
+
+/// System to handle the frame limit and power saving toggle buttons
+pub fn performance_toggle_system(
+    mut interaction_query: Query<
+        (&Interaction, &PerformanceToggle, &mut BackgroundColor, &mut BorderColor),
+        Changed<Interaction>,
+    >,
+    mut game_settings: ResMut<GameSettings>,
+) {
+    for (interaction, toggle, mut bg_color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                game_settings.cycle_performance(*toggle);
+                info!("{:?} changed to: {}", toggle, game_settings.performance_label(*toggle));
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_HOVER);
+                *border_color = BorderColor(UIColors::BORDER_HOVER);
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_DEFAULT);
+                *border_color = BorderColor(UIColors::BORDER_DEFAULT);
+            }
+        }
+    }
+}
+
+/// System to cycle the seasonal event between Auto, Off and each defined event.
+/// Refreshes its own text, as event names come from the event definitions.
+pub fn seasonal_event_toggle_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<SeasonalEventToggle>),
+    >,
+    mut text_query: Query<&mut Text, With<SeasonalEventText>>,
+    mut game_settings: ResMut<GameSettings>,
+    seasonal_events: Option<Res<SeasonalEvents>>,
+) {
+    let default_events = SeasonalEvents::default();
+    let seasonal_events = seasonal_events.as_deref().unwrap_or(&default_events);
+    for (interaction, mut bg_color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                game_settings.seasonal_event = seasonal_events.next_override(&game_settings.seasonal_event);
+                info!("Seasonal event changed to: {}", seasonal_events.override_label(&game_settings.seasonal_event));
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_HOVER);
+                *border_color = BorderColor(UIColors::BORDER_HOVER);
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_DEFAULT);
+                *border_color = BorderColor(UIColors::BORDER_DEFAULT);
+            }
+        }
+    }
+
+    if game_settings.is_changed() {
+        for mut text in text_query.iter_mut() {
+            **text = seasonal_events.override_label(&game_settings.seasonal_event);
+        }
+    }
+}
+
+/// System to flip the statistics and record keeping toggles. Refreshes its own
+/// text, like the seasonal event toggle.
+pub fn privacy_toggle_system(
     mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor, &mut BorderColor),
-        (Changed<Interaction>, With<VSyncToggle>),
+        (&Interaction, &mut BackgroundColor, &mut BorderColor, &PrivacyToggle),
+        Changed<Interaction>,
     >,
-    mut windows: Query<&mut Window>,
+    mut text_query: Query<(&mut Text, &PrivacyToggleText)>,
     mut game_settings: ResMut<GameSettings>,
 ) {
-    for (interaction, mut bg_color, mut border_color) in &mut interaction_query {
+    for (interaction, mut bg_color, mut border_color, toggle) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
-                game_settings.vsync_enabled = !game_settings.vsync_enabled;
-                
-                if let Ok(mut window) = windows.single_mut() {
-                    window.present_mode = if game_settings.vsync_enabled {
-                        bevy::window::PresentMode::AutoVsync
-                    } else {
-                        bevy::window::PresentMode::AutoNoVsync
-                    };
-                }
-                
-                info!("VSync toggled: {}", game_settings.vsync_enabled);
+                game_settings.toggle_privacy(*toggle);
+                info!("{:?} toggled to: {}", toggle, game_settings.privacy_enabled(*toggle));
             }
             Interaction::Hovered => {
                 *bg_color = BackgroundColor(UIColors::BUTTON_HOVER);
@@ -735,35 +2045,54 @@ pub fn vsync_toggle_system(
             }
         }
     }
+
+    if game_settings.is_changed() {
+        for (mut text, toggle_text) in text_query.iter_mut() {
+            **text = if game_settings.privacy_enabled(toggle_text.0) { "ON" } else { "OFF" }.to_string();
+        }
+    }
 }
 
-/// System to handle resolution button (cycles through available resolutions)
-pub fn resolution_button_system(
+/// System to switch between the settings tabs
+pub fn settings_tab_system(
+    mut active_tab: ResMut<ActiveSettingsTab>,
+    interaction_query: Query<(&Interaction, &SettingsTab), (Changed<Interaction>, With<Button>)>,
+    mut tab_buttons: Query<(&SettingsTab, &mut BackgroundColor), With<Button>>,
+    mut contents: Query<(&SettingsTabContent, &mut Node)>,
+) {
+    for (interaction, tab) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed && active_tab.0 != *tab {
+            active_tab.0 = *tab;
+        }
+    }
+
+    if !active_tab.is_changed() {
+        return;
+    }
+    for (tab, mut background) in tab_buttons.iter_mut() {
+        background.0 = tab_background(*tab == active_tab.0);
+    }
+    for (content, mut node) in contents.iter_mut() {
+        node.display = if content.0 == active_tab.0 { Display::Flex } else { Display::None };
+    }
+}
+
+/// System to step the gameplay tab's options. Refreshes its own text, like the
+/// privacy toggles.
+pub fn gameplay_option_system(
     mut interaction_query: Query<
-        (&Interaction, &mut BackgroundColor, &mut BorderColor, &mut ResolutionButton),
-        (Changed<Interaction>, With<Button>),
+        (&Interaction, &mut BackgroundColor, &mut BorderColor, &GameplayOption),
+        Changed<Interaction>,
     >,
-    mut windows: Query<&mut Window>,
+    mut text_query: Query<(&mut Text, &GameplayOptionText)>,
     mut game_settings: ResMut<GameSettings>,
+    mut initialized: Local<bool>,
 ) {
-    for (interaction, mut bg_color, mut border_color, mut resolution_button) in &mut interaction_query {
+    for (interaction, mut bg_color, mut border_color, option) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
-                // Cycle to next resolution
-                let resolutions = ResolutionOption::all();
-                let current_index = resolutions.iter().position(|r| *r == resolution_button.resolution).unwrap_or(0);
-                let next_index = (current_index + 1) % resolutions.len();
-                let new_resolution = resolutions[next_index].clone();
-                
-                resolution_button.resolution = new_resolution.clone();
-                game_settings.current_resolution = new_resolution.clone();
-                
-                if let Ok(mut window) = windows.single_mut() {
-                    let size = new_resolution.to_vec2();
-                    window.resolution = (size.x, size.y).into();
-                }
-                
-                info!("Resolution changed to: {}", new_resolution.to_string());
+                game_settings.cycle_gameplay_option(*option);
+                info!("{:?} set to: {}", option, game_settings.gameplay_option_label(*option));
             }
             Interaction::Hovered => {
                 *bg_color = BackgroundColor(UIColors::BUTTON_HOVER);
@@ -775,14 +2104,105 @@ pub fn resolution_button_system(
             }
         }
     }
+
+    if game_settings.is_changed() || !*initialized {
+        *initialized = true;
+        for (mut text, option_text) in text_query.iter_mut() {
+            **text = game_settings.gameplay_option_label(option_text.0);
+        }
+    }
+}
+
+/// System to set a volume from where its slider is clicked or dragged. The
+/// track stays pressed while the mouse is held, so dragging past either end
+/// pins the value at 0% or 100%.
+pub fn settings_slider_drag_system(
+    track_query: Query<(&Interaction, &RelativeCursorPosition, &SettingsSliderTrack)>,
+    mut game_settings: ResMut<GameSettings>,
+) {
+    for (interaction, cursor, track) in track_query.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let Some(normalized) = cursor.normalized else {
+            continue;
+        };
+        let value = slider_value_at(normalized.x);
+        // Only touch the settings when the value moves, so holding still doesn't re-save
+        if game_settings.volume(track.0).is_some_and(|current| current != value) {
+            game_settings.set_volume(track.0, value);
+        }
+    }
+}
+
+/// System to move slider fills, handles and percentages to the current volumes
+pub fn settings_slider_display_system(
+    game_settings: Res<GameSettings>,
+    mut fill_query: Query<(&mut SettingsSlider, &mut Node), Without<SettingsSliderHandle>>,
+    mut handle_query: Query<(&SettingsSliderHandle, &mut Node), Without<SettingsSlider>>,
+    mut text_query: Query<(&SettingsSliderText, &mut Text)>,
+    mut initialized: Local<bool>,
+) {
+    if !game_settings.is_changed() && *initialized {
+        return;
+    }
+    *initialized = true;
+
+    for (mut slider, mut node) in fill_query.iter_mut() {
+        if let Some(value) = game_settings.volume(slider.setting_type) {
+            slider.value = value;
+            node.width = Val::Percent(value * 100.0);
+        }
+    }
+    for (handle, mut node) in handle_query.iter_mut() {
+        if let Some(value) = game_settings.volume(handle.0) {
+            node.left = slider_handle_left(value);
+        }
+    }
+    for (slider_text, mut text) in text_query.iter_mut() {
+        if let Some(value) = game_settings.volume(slider_text.0) {
+            **text = format!("{:.0}%", value * 100.0);
+        }
+    }
+}
+
+/// System to hold damage numbers back through the effect budget when they are turned off
+pub fn apply_effect_settings_system(game_settings: Res<GameSettings>, budget: Option<ResMut<EffectBudget>>) {
+    let Some(mut budget) = budget else {
+        return;
+    };
+    if !game_settings.is_changed() && !budget.is_added() {
+        return;
+    }
+    let cap = if game_settings.damage_numbers_enabled {
+        EffectBudget::default().cap(EffectCategory::DamageNumber)
+    } else {
+        0
+    };
+    if budget.cap(EffectCategory::DamageNumber) != cap {
+        budget.set_cap(EffectCategory::DamageNumber, cap);
+    }
+}
+
+/// System to keep the shared number formatter in step with the settings
+pub fn apply_number_format_system(game_settings: Res<GameSettings>, mut formatter: ResMut<NumberFormatter>) {
+    if game_settings.is_changed() {
+        formatter.set_if_neq(game_settings.number_formatter());
+    }
 }
 
 /// System to update settings UI text based on current settings
 pub fn update_settings_ui_system(
     game_settings: Res<GameSettings>,
-    mut resolution_text_query: Query<&mut Text, (With<ResolutionText>, Without<FullscreenText>, Without<VSyncText>)>,
-    mut fullscreen_text_query: Query<&mut Text, (With<FullscreenText>, Without<ResolutionText>, Without<VSyncText>)>,
-    mut vsync_text_query: Query<&mut Text, (With<VSyncText>, Without<ResolutionText>, Without<FullscreenText>)>,
+    mut resolution_text_query: Query<&mut Text, (With<ResolutionText>, Without<FullscreenText>, Without<VSyncText>, Without<DifficultyText>, Without<AdvisorText>, Without<SaveFormatText>)>,
+    mut fullscreen_text_query: Query<&mut Text, (With<FullscreenText>, Without<ResolutionText>, Without<VSyncText>, Without<DifficultyText>, Without<AdvisorText>, Without<SaveFormatText>)>,
+    mut vsync_text_query: Query<&mut Text, (With<VSyncText>, Without<ResolutionText>, Without<FullscreenText>, Without<DifficultyText>, Without<AdvisorText>, Without<SaveFormatText>)>,
+    mut difficulty_text_query: Query<&mut Text, (With<DifficultyText>, Without<ResolutionText>, Without<FullscreenText>, Without<VSyncText>, Without<AdvisorText>, Without<SaveFormatText>)>,
+    mut feedback_text_query: Query<(&mut Text, &FeedbackToggleText), (Without<ResolutionText>, Without<FullscreenText>, Without<VSyncText>, Without<DifficultyText>, Without<AdvisorText>, Without<SaveFormatText>)>,
+    mut advisor_text_query: Query<&mut Text, (With<AdvisorText>, Without<ResolutionText>, Without<FullscreenText>, Without<VSyncText>, Without<DifficultyText>, Without<FeedbackToggleText>, Without<SaveFormatText>)>,
+    mut save_format_text_query: Query<&mut Text, (With<SaveFormatText>, Without<ResolutionText>, Without<FullscreenText>, Without<VSyncText>, Without<DifficultyText>, Without<FeedbackToggleText>, Without<AdvisorText>)>,
+    mut number_format_text_query: Query<(&mut Text, &NumberFormatToggleText), (Without<ResolutionText>, Without<FullscreenText>, Without<VSyncText>, Without<DifficultyText>, Without<FeedbackToggleText>, Without<AdvisorText>, Without<SaveFormatText>)>,
+    mut performance_text_query: Query<(&mut Text, &PerformanceToggleText), (Without<ResolutionText>, Without<FullscreenText>, Without<VSyncText>, Without<DifficultyText>, Without<FeedbackToggleText>, Without<AdvisorText>, Without<SaveFormatText>, Without<NumberFormatToggleText>)>,
     mut resolution_button_query: Query<&mut ResolutionButton>,
 ) {
     if game_settings.is_changed() {
@@ -801,6 +2221,36 @@ pub fn update_settings_ui_system(
             **text = if game_settings.vsync_enabled { "ON" } else { "OFF" }.to_string();
         }
         
+        // Update difficulty text
+        if let Ok(mut text) = difficulty_text_query.single_mut() {
+            **text = game_settings.difficulty.get_name().to_string();
+        }
+        
+        // Update UI feedback toggle texts
+        for (mut text, toggle_text) in feedback_text_query.iter_mut() {
+            **text = if game_settings.feedback_enabled(toggle_text.0) { "ON" } else { "OFF" }.to_string();
+        }
+        
+        // Update advisor toggle text
+        if let Ok(mut text) = advisor_text_query.single_mut() {
+            **text = if game_settings.advisor_enabled { "ON" } else { "OFF" }.to_string();
+        }
+        
+        // Update save format text
+        if let Ok(mut text) = save_format_text_query.single_mut() {
+            **text = game_settings.save_format.get_name().to_string();
+        }
+        
+        // Update number format texts
+        for (mut text, toggle_text) in number_format_text_query.iter_mut() {
+            **text = game_settings.number_format_label(toggle_text.0).to_string();
+        }
+        
+        // Update frame limit and power saving texts
+        for (mut text, toggle_text) in performance_text_query.iter_mut() {
+            **text = game_settings.performance_label(toggle_text.0).to_string();
+        }
+        
         // Update resolution button state
         if let Ok(mut resolution_button) = resolution_button_query.single_mut() {
             resolution_button.resolution = game_settings.current_resolution.clone();
@@ -812,15 +2262,306 @@ pub fn update_settings_ui_system(
 // SETTINGS PERSISTENCE SYSTEMS
 // ============================================================================
 
-/// System to automatically save settings when they change
+/// System to automatically save settings when they change. Never overwrites a
+/// settings file written by a newer version of the game.
 pub fn save_settings_on_change(
     settings: Res<GameSettings>,
+    load_error: Res<SettingsLoadError>,
 ) {
-    if settings.is_changed() {
+    if settings.is_changed() && !load_error.blocks_saving() {
         settings.save();
     }
 }
 
+// ============================================================================
+// SETTINGS LOAD ERROR DIALOG
+// ============================================================================
+
+/// Why the settings file couldn't be loaded at startup, if it couldn't
+#[derive(Resource, Debug, Default)]
+pub struct SettingsLoadError(pub Option<SaveError>);
+
+impl SettingsLoadError {
+    /// A file from a newer build is kept as it is rather than replaced by defaults
+    pub fn blocks_saving(&self) -> bool {
+        matches!(self.0, Some(SaveError::TooNew { .. }))
+    }
+
+    /// Explanation shown in the startup dialog
+    pub fn message(&self) -> Option<String> {
+        let error = self.0.as_ref()?;
+        Some(match error {
+            SaveError::TooNew { found, supported } => format!(
+                "Your settings were saved by a newer version of the game (format {}, this build reads up to {}).\n\nDefault settings are used for this session. Changes won't be saved, so the newer file stays intact.",
+                found, supported
+            ),
+            other => format!("Your settings could not be loaded: {}.\n\nDefault settings are used instead.", other),
+        })
+    }
+}
+
+#[derive(Component)]
+pub struct SettingsLoadErrorDialog;
+
+#[derive(Component)]
+pub struct SettingsLoadErrorDismissButton;
+
+/// System to show the settings load error, if any, when the game starts
+pub fn setup_settings_load_error_dialog(
+    mut commands: Commands,
+    load_error: Res<SettingsLoadError>,
+) {
+    let Some(message) = load_error.message() else {
+        return;
+    };
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            top: Val::Px(0.0),
+            width: Val::Vw(100.0),
+            height: Val::Vh(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(UIColors::OVERLAY_BG),
+        ZIndex(1100), // Above the settings menu
+        SettingsLoadErrorDialog,
+    )).with_children(|parent| {
+        parent.spawn((
+            Node {
+                width: Val::Px(460.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(20.0)),
+                row_gap: Val::Px(14.0),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(UIColors::PANEL_BG),
+            BorderColor(UIColors::PANEL_BORDER),
+            BorderRadius::all(Val::Px(8.0)),
+        )).with_children(|parent| {
+            parent.spawn((
+                Text::new("Settings Not Loaded"),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_ERROR),
+            ));
+            
+            parent.spawn((
+                Text::new(message),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_PRIMARY),
+            ));
+            
+            parent.spawn((
+                Button,
+                Node {
+                    width: Val::Px(120.0),
+                    height: Val::Px(40.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(UIColors::BUTTON_DEFAULT),
+                BorderColor(UIColors::BORDER_DEFAULT),
+                BorderRadius::all(Val::Px(6.0)),
+                SettingsLoadErrorDismissButton,
+            )).with_children(|button| {
+                button.spawn((
+                    Text::new("OK"),
+                    TextFont {
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(UIColors::TEXT_PRIMARY),
+                ));
+            });
+        });
+    });
+}
+
+/// System to close the settings load error dialog
+pub fn settings_load_error_dialog_system(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor),
+        (Changed<Interaction>, With<SettingsLoadErrorDismissButton>),
+    >,
+    dialog_query: Query<Entity, With<SettingsLoadErrorDialog>>,
+) {
+    for (interaction, mut bg_color, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                for dialog in dialog_query.iter() {
+                    commands.entity(dialog).despawn();
+                }
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_HOVER);
+                *border_color = BorderColor(UIColors::BORDER_HOVER);
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_DEFAULT);
+                *border_color = BorderColor(UIColors::BORDER_DEFAULT);
+            }
+        }
+    }
+}
+
+// ============================================================================
+// WIPE LOCAL DATA CONFIRMATION
+// ============================================================================
+
+#[derive(Component)]
+pub struct WipeDataDialog;
+
+/// Button of the wipe confirmation; `confirm` wipes, otherwise the dialog just closes
+#[derive(Component)]
+pub struct WipeDataDialogButton {
+    pub confirm: bool,
+}
+
+/// Explanation shown in the wipe confirmation, listing what goes
+pub fn wipe_data_message() -> String {
+    let mut targets: Vec<String> = LOCAL_DATA_FILES.iter().map(|file| file.to_string()).collect();
+    targets.extend(LOCAL_DATA_DIRS.iter().map(|dir| format!("{}/", dir)));
+    format!(
+        "This deletes enemy statistics, leaderboards, the prestige profile, saved runs and captures:\n{}\n\nSettings are kept. This cannot be undone.",
+        targets.join(", ")
+    )
+}
+
+fn spawn_wipe_data_dialog(commands: &mut Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            top: Val::Px(0.0),
+            width: Val::Vw(100.0),
+            height: Val::Vh(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(UIColors::OVERLAY_BG),
+        ZIndex(1100), // Above the settings menu
+        WipeDataDialog,
+    )).with_children(|parent| {
+        parent.spawn((
+            Node {
+                width: Val::Px(460.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(20.0)),
+                row_gap: Val::Px(14.0),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(UIColors::PANEL_BG),
+            BorderColor(UIColors::PANEL_BORDER),
+            BorderRadius::all(Val::Px(8.0)),
+        )).with_children(|parent| {
+            parent.spawn((
+                Text::new("Wipe Local Data?"),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_ERROR),
+            ));
+            
+            parent.spawn((
+                Text::new(wipe_data_message()),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_PRIMARY),
+            ));
+            
+            parent.spawn(Node {
+                column_gap: Val::Px(20.0),
+                ..default()
+            }).with_children(|parent| {
+                for (label, confirm, color) in [("CANCEL", false, UIColors::TEXT_PRIMARY), ("WIPE", true, UIColors::TEXT_ERROR)] {
+                    parent.spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(120.0),
+                            height: Val::Px(40.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(UIColors::BUTTON_DEFAULT),
+                        BorderColor(UIColors::BORDER_DEFAULT),
+                        BorderRadius::all(Val::Px(6.0)),
+                        WipeDataDialogButton { confirm },
+                    )).with_children(|button| {
+                        button.spawn((
+                            Text::new(label),
+                            TextFont {
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(color),
+                        ));
+                    });
+                }
+            });
+        });
+    });
+}
+
+/// System to answer the wipe confirmation. Leaving the settings closes it unanswered.
+pub fn wipe_data_dialog_system(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor, &WipeDataDialogButton),
+        Changed<Interaction>,
+    >,
+    dialog_query: Query<Entity, With<WipeDataDialog>>,
+    mut wipe_events: EventWriter<WipeLocalDataEvent>,
+    app_state: Res<State<AppState>>,
+) {
+    let mut close = *app_state.get() != AppState::Settings;
+    for (interaction, mut bg_color, mut border_color, button) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                if button.confirm {
+                    wipe_events.write(WipeLocalDataEvent);
+                }
+                close = true;
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_HOVER);
+                *border_color = BorderColor(UIColors::BORDER_HOVER);
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_DEFAULT);
+                *border_color = BorderColor(UIColors::BORDER_DEFAULT);
+            }
+        }
+    }
+
+    if close {
+        for dialog in dialog_query.iter() {
+            commands.entity(dialog).despawn();
+        }
+    }
+}
+
 /// System to apply loaded settings to the window on startup
 pub fn apply_loaded_settings_to_window(
     settings: Res<GameSettings>,
@@ -865,10 +2606,22 @@ impl Plugin for SettingsSystemPlugin {
     fn build(&self, app: &mut App) {
         app
             // GameSettings resource is now loaded earlier in main.rs to ensure availability
-            .add_systems(Startup, (setup_settings_menu, apply_loaded_settings_to_window))
+            .init_resource::<SettingsLoadError>()
+            .init_resource::<SettingsReturnState>()
+            .init_resource::<NumberFormatter>()
+            .init_resource::<ActiveSettingsTab>()
+            .add_event::<WipeLocalDataEvent>()
+            .add_systems(Startup, (setup_settings_menu, apply_loaded_settings_to_window, setup_settings_load_error_dialog))
             .add_systems(
                 Update,
-                (settings_menu_visibility_system, save_settings_on_change).in_set(GameSystemSet::UI)
+                (
+                    settings_menu_visibility_system,
+                    apply_number_format_system,
+                    apply_effect_settings_system,
+                    save_settings_on_change,
+                    settings_load_error_dialog_system,
+                    wipe_data_dialog_system,
+                ).in_set(GameSystemSet::UI)
             )
             .add_systems(
                 Update,
@@ -877,6 +2630,18 @@ impl Plugin for SettingsSystemPlugin {
                     fullscreen_toggle_system,
                     vsync_toggle_system,
                     resolution_button_system,
+                    difficulty_toggle_system,
+                    feedback_toggle_system,
+                    advisor_toggle_system,
+                    save_format_toggle_system,
+                    number_format_toggle_system,
+                    performance_toggle_system,
+                    seasonal_event_toggle_system,
+                    privacy_toggle_system,
+                    settings_tab_system,
+                    gameplay_option_system,
+                    settings_slider_drag_system,
+                    settings_slider_display_system,
                     update_settings_ui_system,
                 )
                     .in_set(GameSystemSet::Settings)
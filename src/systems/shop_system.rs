@@ -0,0 +1,312 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::resources::*;
+use crate::systems::results_screen::RestartRunEvent;
+use crate::systems::ui_feedback::UiFeedback;
+
+// ============================================================================
+// SHOP COMPONENTS
+// ============================================================================
+
+#[derive(Component)]
+pub struct ShopOverlay;
+
+#[derive(Component)]
+pub struct ShopButton {
+    pub action: ShopAction,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShopAction {
+    /// Buy the offer at this index
+    Buy(usize),
+    Reroll,
+    Close,
+}
+
+// ============================================================================
+// UI COLOR CONSTANTS (matching pause menu)
+// ============================================================================
+
+struct UIColors;
+
+impl UIColors {
+    const PANEL_BG: Color = Color::srgb(0.08, 0.12, 0.18);
+    const PANEL_BORDER: Color = Color::srgb(0.22, 0.28, 0.38);
+    const BUTTON_DEFAULT: Color = Color::srgb(0.15, 0.20, 0.28);
+    const BUTTON_HOVER: Color = Color::srgb(0.20, 0.28, 0.38);
+    const BORDER_DEFAULT: Color = Color::srgb(0.32, 0.38, 0.48);
+    const BORDER_HOVER: Color = Color::srgb(0.48, 0.58, 0.70);
+    const TEXT_PRIMARY: Color = Color::srgb(0.96, 0.96, 0.98);
+    const TEXT_SECONDARY: Color = Color::srgb(0.78, 0.82, 0.88);
+    const TEXT_ACCENT: Color = Color::srgb(0.88, 0.92, 0.62);
+    const TEXT_INFO: Color = Color::srgb(0.58, 0.78, 1.0);
+    const OVERLAY_BG: Color = Color::srgba(0.0, 0.0, 0.0, 0.35);
+}
+
+/// Price label such as "$60" or "10 RP"
+fn cost_label(cost: &ResourceCost, formatter: &NumberFormatter) -> String {
+    match (cost.money, cost.research_points) {
+        (0, research) => format!("{} RP", formatter.count(research)),
+        (money, 0) => formatter.money(money),
+        (money, research) => format!("{} + {} RP", formatter.money(money), formatter.count(research)),
+    }
+}
+
+// ============================================================================
+// SHOP SYSTEMS
+// ============================================================================
+
+/// Give every base the extra health of one Reinforcements perk
+pub fn reinforce_bases(base_query: &mut Query<&mut Health, With<Base>>) {
+    let extra_health = LIVES_PER_PERK as f32 * ENEMY_BASE_DAMAGE;
+    for mut health in base_query.iter_mut() {
+        health.max += extra_health;
+        health.current += extra_health;
+    }
+}
+
+/// System to open the shop once a shop wave is cleared, and close it when the next wave starts.
+/// A reward chest for the same wave is chosen from before the shop opens.
+pub fn shop_open_system(
+    wave_plan: Res<WavePlan>,
+    wave_runtime: Res<WaveRuntime>,
+    game_state: Res<GameState>,
+    enemies: Query<(), With<Enemy>>,
+    chest: Option<Res<RewardChest>>,
+    mut shop: ResMut<PerkShop>,
+    mut rng: ResMut<GameRng>,
+) {
+    let wave = wave_plan.current_wave;
+    if shop.open {
+        if wave > shop.visited_wave {
+            shop.close();
+        }
+        return;
+    }
+    if chest.is_some_and(|chest| chest.open) {
+        return;
+    }
+
+    let cleared = wave_runtime.wave_complete(&wave_plan) && enemies.is_empty();
+    if cleared && is_shop_wave(wave) && wave > shop.visited_wave && *game_state == GameState::Playing {
+        shop.open_after(wave, &mut rng);
+        info!("Shop opened after wave {}: {:?}", wave, shop.offers);
+    }
+}
+
+/// System to rebuild the shop panel whenever the offers change
+pub fn shop_panel_system(
+    mut commands: Commands,
+    shop: Res<PerkShop>,
+    formatter: Res<NumberFormatter>,
+    overlays: Query<Entity, With<ShopOverlay>>,
+) {
+    if !shop.is_changed() && !(shop.open && formatter.is_changed()) {
+        return;
+    }
+
+    for entity in overlays.iter() {
+        commands.entity(entity).despawn();
+    }
+    if shop.open {
+        spawn_shop_panel(&mut commands, &shop, &formatter);
+    }
+}
+
+fn spawn_shop_panel(commands: &mut Commands, shop: &PerkShop, formatter: &NumberFormatter) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            top: Val::Px(0.0),
+            width: Val::Vw(100.0),
+            height: Val::Vh(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(UIColors::OVERLAY_BG),
+        ZIndex(800), // Below the results screen and pause menu
+        ShopOverlay,
+    )).with_children(|parent| {
+        parent.spawn((
+            Node {
+                width: Val::Px(460.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(25.0)),
+                row_gap: Val::Px(10.0),
+                border: UiRect::all(Val::Px(3.0)),
+                ..default()
+            },
+            BackgroundColor(UIColors::PANEL_BG),
+            BorderColor(UIColors::PANEL_BORDER),
+            BorderRadius::all(Val::Px(15.0)),
+        )).with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("SUPPLY DROP - WAVE {}", shop.visited_wave)),
+                TextFont {
+                    font_size: 28.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_PRIMARY),
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+
+            for (index, perk) in shop.offers.iter().enumerate() {
+                create_shop_button(
+                    parent,
+                    ShopAction::Buy(index),
+                    &format!("{} - {}", perk.get_name(), cost_label(&perk.get_cost(), formatter)),
+                    Some(perk.get_description()),
+                    UIColors::TEXT_ACCENT,
+                );
+            }
+
+            let reroll_label = format!("REROLL ({})", cost_label(&shop.reroll_cost(), formatter));
+            create_shop_button(parent, ShopAction::Reroll, &reroll_label, None, UIColors::TEXT_INFO);
+            create_shop_button(parent, ShopAction::Close, "CONTINUE", None, UIColors::TEXT_PRIMARY);
+        });
+    });
+}
+
+fn create_shop_button(
+    parent: &mut ChildSpawnerCommands,
+    action: ShopAction,
+    text: &str,
+    detail: Option<&str>,
+    text_color: Color,
+) {
+    parent.spawn((
+        Button,
+        Node {
+            width: Val::Px(380.0),
+            min_height: Val::Px(46.0),
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            padding: UiRect::axes(Val::Px(10.0), Val::Px(6.0)),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(UIColors::BUTTON_DEFAULT),
+        BorderColor(UIColors::BORDER_DEFAULT),
+        BorderRadius::all(Val::Px(8.0)),
+        ShopButton { action },
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(text),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(text_color),
+        ));
+        if let Some(detail) = detail {
+            parent.spawn((
+                Text::new(detail),
+                TextFont {
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_SECONDARY),
+            ));
+        }
+    });
+}
+
+/// System to handle shop button presses: buying, rerolling and leaving
+pub fn shop_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor, &ShopButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut shop: ResMut<PerkShop>,
+    mut economy: ResMut<Economy>,
+    mut perks: ResMut<RunPerks>,
+    mut rng: ResMut<GameRng>,
+    mut base_query: Query<&mut Health, With<Base>>,
+    mut feedback: UiFeedback,
+) {
+    for (interaction, mut bg_color, mut border_color, shop_button) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => match shop_button.action {
+                ShopAction::Buy(index) => match shop.try_buy(index, &mut economy, &mut perks) {
+                    Some(perk) => {
+                        if perk == Perk::Reinforcements {
+                            reinforce_bases(&mut base_query);
+                        }
+                        println!("Bought perk {}", perk.get_name());
+                        feedback.confirm();
+                    }
+                    None => {
+                        println!("Cannot afford that perk");
+                        feedback.error();
+                    }
+                },
+                ShopAction::Reroll => {
+                    if shop.try_reroll(&mut economy, &mut rng) {
+                        feedback.confirm();
+                    } else {
+                        println!("Cannot afford a reroll");
+                        feedback.error();
+                    }
+                }
+                ShopAction::Close => shop.close(),
+            },
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_HOVER);
+                *border_color = BorderColor(UIColors::BORDER_HOVER);
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_DEFAULT);
+                *border_color = BorderColor(UIColors::BORDER_DEFAULT);
+            }
+        }
+    }
+}
+
+/// System to drop the run's perks and close the shop when a new run starts
+pub fn reset_perks_on_restart_system(
+    mut restart_events: EventReader<RestartRunEvent>,
+    mut perks: ResMut<RunPerks>,
+    mut shop: ResMut<PerkShop>,
+) {
+    if restart_events.read().last().is_none() {
+        return;
+    }
+    *perks = RunPerks::default();
+    *shop = PerkShop::default();
+}
+
+// ============================================================================
+// SHOP PLUGIN
+// ============================================================================
+
+/// Plugin for the between-wave shop selling one-run perks
+pub struct ShopPlugin;
+
+impl Plugin for ShopPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<RunPerks>()
+            .init_resource::<PerkShop>()
+            .add_systems(
+                Update,
+                (shop_button_system, reset_perks_on_restart_system, shop_panel_system)
+                    .chain()
+                    .in_set(GameSystemSet::UI),
+            )
+            .add_systems(
+                Update,
+                shop_open_system
+                    .in_set(GameSystemSet::Gameplay)
+                    .after(EnemySet::Cleanup)
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
@@ -0,0 +1,216 @@
+use bevy::prelude::*;
+use crate::resources::{AppState, Economy, GameState, GameSystemSet, IncomeRemainder, SimulationClock, GAME_SPEEDS};
+use crate::systems::input::{InputContext, InputRegistryAppExt};
+use crate::systems::input_system::MouseInputState;
+
+/// Key that stops and restarts the game without opening the pause menu
+pub const SPEED_HOLD_KEY: KeyCode = KeyCode::KeyP;
+/// Key that cycles through `GAME_SPEEDS`
+pub const SPEED_CYCLE_KEY: KeyCode = KeyCode::Tab;
+
+const SPEED_BUTTON_ACTIVE: Color = Color::srgb(0.3, 0.55, 0.8);
+const SPEED_BUTTON_HOVER: Color = Color::srgb(0.3, 0.35, 0.45);
+const SPEED_BUTTON_DEFAULT: Color = Color::srgb(0.18, 0.2, 0.26);
+
+// ============================================================================
+// COMPONENTS
+// ============================================================================
+
+/// Marker for the row of game speed buttons
+#[derive(Component)]
+pub struct GameSpeedControls;
+
+/// A game speed button: hold the game, or run it at one of `GAME_SPEEDS`
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub enum GameSpeedButton {
+    Hold,
+    Speed(f32),
+}
+
+impl GameSpeedButton {
+    pub fn label(&self) -> String {
+        match self {
+            GameSpeedButton::Hold => "||".to_string(),
+            GameSpeedButton::Speed(speed) => format!("{}x", speed),
+        }
+    }
+
+    /// Whether this button shows the clock's current setting
+    pub fn is_active(&self, clock: &SimulationClock) -> bool {
+        match self {
+            GameSpeedButton::Hold => clock.is_held(),
+            GameSpeedButton::Speed(speed) => !clock.is_held() && clock.speed() == *speed,
+        }
+    }
+
+    /// Put the clock on this button's setting; picking a speed also lets go of a hold
+    pub fn apply(&self, clock: &mut SimulationClock) {
+        match self {
+            GameSpeedButton::Hold => clock.set_held(!clock.is_held()),
+            GameSpeedButton::Speed(speed) => {
+                clock.set_held(false);
+                clock.set_speed(*speed);
+            }
+        }
+    }
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// System to step the simulation clock by the real frame time. The clock only
+/// runs while the game is being played, and `Time<Virtual>` follows its speed,
+/// slow motion included, so movement and combat keep pace with the timers.
+pub fn advance_simulation_clock_system(
+    real_time: Res<Time<Real>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    app_state: Res<State<AppState>>,
+    game_state: Res<GameState>,
+    mut clock: ResMut<SimulationClock>,
+) {
+    let running = *app_state.get() == AppState::Playing && *game_state == GameState::Playing;
+    clock.advance(real_time.delta(), running);
+
+    if virtual_time.relative_speed() != clock.effective_speed() {
+        virtual_time.set_relative_speed(clock.effective_speed());
+    }
+}
+
+/// System to cycle the game speed with the Tab key and hold the game with P
+pub fn game_speed_input_system(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut clock: ResMut<SimulationClock>,
+) {
+    if keyboard_input.just_pressed(SPEED_CYCLE_KEY) {
+        clock.set_held(false);
+        clock.cycle_speed();
+        println!("Game speed: {}x", clock.speed());
+    }
+    if keyboard_input.just_pressed(SPEED_HOLD_KEY) {
+        GameSpeedButton::Hold.apply(&mut clock);
+        println!("Game {}", if clock.is_held() { "held" } else { "resumed" });
+    }
+}
+
+/// System to spawn the game speed buttons in the bottom-left corner
+pub fn setup_game_speed_controls(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(20.0),
+                bottom: Val::Px(50.0),
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(4.0),
+                ..default()
+            },
+            GameSpeedControls,
+        ))
+        .with_children(|row| {
+            let buttons = std::iter::once(GameSpeedButton::Hold)
+                .chain(GAME_SPEEDS.iter().map(|speed| GameSpeedButton::Speed(*speed)));
+            for button in buttons {
+                row.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(40.0),
+                        height: Val::Px(28.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(SPEED_BUTTON_DEFAULT),
+                    button,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new(button.label()),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            }
+        });
+}
+
+/// System to set the game speed from its buttons
+pub fn game_speed_button_system(
+    mut clock: ResMut<SimulationClock>,
+    mut mouse_input_state: ResMut<MouseInputState>,
+    interaction_query: Query<(&Interaction, &GameSpeedButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in interaction_query.iter() {
+        if *interaction == Interaction::Pressed {
+            // Consume the mouse click to prevent tower placement
+            mouse_input_state.left_clicked = false;
+            button.apply(&mut clock);
+        }
+    }
+}
+
+/// System to highlight the button matching the current game speed
+pub fn game_speed_button_style_system(
+    clock: Res<SimulationClock>,
+    mut button_query: Query<(&Interaction, &GameSpeedButton, &mut BackgroundColor)>,
+) {
+    for (interaction, button, mut color) in button_query.iter_mut() {
+        let target = if button.is_active(&clock) {
+            SPEED_BUTTON_ACTIVE
+        } else if *interaction == Interaction::Hovered {
+            SPEED_BUTTON_HOVER
+        } else {
+            SPEED_BUTTON_DEFAULT
+        };
+        color.set_if_neq(BackgroundColor(target));
+    }
+}
+
+/// System to pay passive income for the simulation time that passed
+pub fn passive_income_system(
+    clock: Res<SimulationClock>,
+    mut economy: ResMut<Economy>,
+    mut remainder: Local<IncomeRemainder>,
+) {
+    if clock.delta_secs() > 0.0 {
+        economy.accrue_passive_income(clock.delta_secs(), &mut remainder);
+    }
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin driving the simulation clock, game speed controls and passive income
+pub struct SimulationClockPlugin;
+
+impl Plugin for SimulationClockPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SimulationClock>()
+            .register_key_hint(SPEED_CYCLE_KEY, "Cycle game speed", InputContext::Game)
+            .register_key_hint(SPEED_HOLD_KEY, "Hold or resume the game", InputContext::Game)
+            .add_systems(Startup, setup_game_speed_controls)
+            .add_systems(
+                Update,
+                (game_speed_input_system, advance_simulation_clock_system)
+                    .chain()
+                    .in_set(GameSystemSet::Input),
+            )
+            .add_systems(
+                Update,
+                (game_speed_button_system, game_speed_button_style_system)
+                    .chain()
+                    .in_set(GameSystemSet::UI)
+                    .run_if(in_state(AppState::Playing)),
+            )
+            .add_systems(
+                Update,
+                passive_income_system
+                    .in_set(GameSystemSet::Gameplay)
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
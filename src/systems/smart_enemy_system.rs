@@ -0,0 +1,183 @@
+use bevy::prelude::*;
+use std::time::Duration;
+use crate::components::{Enemy, PathProgress};
+use crate::resources::{AppState, EnemyPath, EnemySet, GameSystemSet, TowerStats};
+use crate::systems::enemy_system::EnemyRoutes;
+use crate::systems::obstacle_rendering::ObstacleGrid;
+use crate::systems::path_generation::{find_path_with_costs, DangerField, MapArchetype, PathGrid};
+
+/// Tint that sets smart enemies apart from the red swarm
+pub const SMART_ENEMY_COLOR: Color = Color::srgb(0.75, 0.3, 1.0);
+
+// ============================================================================
+// COMPONENTS & RESOURCES
+// ============================================================================
+
+/// Marks an enemy that routes around tower coverage instead of following the shared path
+#[derive(Component, Debug, Default)]
+pub struct SmartEnemy;
+
+/// Route a smart enemy is following in place of the shared `EnemyPath`.
+/// Its `PathProgress` is measured along this route.
+#[derive(Component, Debug, Clone)]
+pub struct SmartRoute {
+    pub path: EnemyPath,
+}
+
+/// Resource controlling which enemies are smart and how often they reroute
+#[derive(Resource, Debug, Clone)]
+pub struct SmartEnemySettings {
+    pub enabled: bool,
+    /// Every Nth enemy of a wave is smart
+    pub spawn_every: u32,
+    /// Only maze maps leave enough open corridors for rerouting to matter
+    pub maze_only: bool,
+    /// Seconds between danger recomputations and reroutes
+    pub reroute_interval: f32,
+}
+
+impl Default for SmartEnemySettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            spawn_every: 4,
+            maze_only: true,
+            reroute_interval: 1.0,
+        }
+    }
+}
+
+impl SmartEnemySettings {
+    /// Every how many enemies one is smart on this map, or `None` if none are
+    pub fn smart_every(&self, archetype: MapArchetype) -> Option<u32> {
+        (self.enabled && self.spawn_every > 0 && (!self.maze_only || archetype == MapArchetype::Maze))
+            .then_some(self.spawn_every)
+    }
+
+    /// Whether the enemy with this spawn index should be smart on this map
+    pub fn spawns_smart(&self, spawn_index: u32, archetype: MapArchetype) -> bool {
+        self.smart_every(archetype)
+            .is_some_and(|every| (spawn_index + 1).is_multiple_of(every))
+    }
+}
+
+/// Resource holding the latest danger layer and the reroute timer
+#[derive(Resource, Debug)]
+pub struct DangerMap {
+    pub field: DangerField,
+    pub timer: Timer,
+}
+
+impl Default for DangerMap {
+    fn default() -> Self {
+        Self {
+            field: DangerField::default(),
+            timer: Timer::from_seconds(SmartEnemySettings::default().reroute_interval, TimerMode::Repeating),
+        }
+    }
+}
+
+// ============================================================================
+// ROUTING
+// ============================================================================
+
+/// Cheapest route from a world position to the goal with tower coverage
+/// counted as extra cost. The route starts at `from` so the enemy doesn't jump.
+pub fn smart_route(grid: &PathGrid, danger: &DangerField, from: Vec2, goal: Vec2) -> Option<EnemyPath> {
+    let start = grid.world_to_grid(from)?;
+    let goal = grid.world_to_grid(goal)?;
+    let cells = find_path_with_costs(grid, start, goal, |pos| danger.cost(pos))?;
+
+    // The first cell is the one the enemy is already in
+    let mut waypoints = vec![from];
+    waypoints.extend(cells.iter().skip(1).map(|&pos| grid.grid_to_world(pos)));
+    if waypoints.len() == 1 {
+        waypoints.push(grid.grid_to_world(goal));
+    }
+    Some(EnemyPath::new(waypoints))
+}
+
+/// Join the stretch of `travelled` an enemy at `progress` has already walked
+/// onto the front of `route`, which starts where the enemy stands. Returns the
+/// joined route and the progress along it matching the enemy's position, so a
+/// reroute keeps the enemy as far along as it was.
+pub fn continue_route(travelled: &EnemyPath, progress: f32, route: EnemyPath) -> (EnemyPath, f32) {
+    let from = route.waypoints[0];
+    let walked_segments = travelled.waypoints.len() - 1;
+    let walked = (progress.clamp(0.0, 1.0) * walked_segments as f32).floor() as usize + 1;
+    let mut waypoints = travelled.waypoints[..walked.min(travelled.waypoints.len())].to_vec();
+    waypoints.extend(route.waypoints);
+    let path = EnemyPath::new(waypoints);
+    let progress = path.progress_nearest(from);
+    (path, progress)
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// System to periodically rebuild the danger layer from tower ranges and
+/// send every smart enemy down the least-defended route to the exit
+pub fn smart_reroute_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    settings: Res<SmartEnemySettings>,
+    mut danger_map: ResMut<DangerMap>,
+    obstacle_grid: Option<Res<ObstacleGrid>>,
+    enemy_path: Res<EnemyPath>,
+    towers: Query<(&Transform, &TowerStats), Without<Enemy>>,
+    mut smart_enemies: Query<(Entity, &Transform, &mut PathProgress, EnemyRoutes), With<SmartEnemy>>,
+) {
+    let interval = Duration::from_secs_f32(settings.reroute_interval);
+    if danger_map.timer.duration() != interval {
+        danger_map.timer.set_duration(interval);
+    }
+    if !danger_map.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+    let (Some(obstacle_grid), Some(&exit)) = (obstacle_grid, enemy_path.waypoints.last()) else {
+        return;
+    };
+    if smart_enemies.is_empty() {
+        return;
+    }
+
+    let tower_ranges: Vec<(Vec2, f32)> = towers
+        .iter()
+        .map(|(transform, stats)| (transform.translation.truncate(), stats.range))
+        .collect();
+    danger_map.field = DangerField::from_towers(&obstacle_grid.grid, &tower_ranges);
+
+    for (entity, transform, mut progress, routes) in smart_enemies.iter_mut() {
+        if progress.is_complete() {
+            continue;
+        }
+        if let Some(route) = smart_route(&obstacle_grid.grid, &danger_map.field, transform.translation.truncate(), exit) {
+            let (path, carried) = continue_route(routes.active(&enemy_path), progress.current, route);
+            progress.current = carried;
+            commands.entity(entity).insert(SmartRoute { path });
+        }
+    }
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin for enemies that steer clear of heavily defended cells
+pub struct SmartEnemyPlugin;
+
+impl Plugin for SmartEnemyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SmartEnemySettings>()
+            .init_resource::<DangerMap>()
+            .add_systems(
+                Update,
+                smart_reroute_system
+                    .after(EnemySet::Spawning)
+                    .before(EnemySet::Movement)
+                    .in_set(GameSystemSet::Gameplay)
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
@@ -0,0 +1,265 @@
+use bevy::prelude::*;
+use crate::components::Enemy;
+use crate::resources::{
+    AppState, EnemyPath, GameSystemSet, NumberFormatter, PathVariants, WaveComposition, WaveConfig, WavePlan, WaveRuntime, PLAY_AREA_HEIGHT, PLAY_AREA_WIDTH,
+};
+use crate::systems::boss_arena_system::BossArenaSettings;
+use crate::systems::combat_system::kill_reward_range;
+use crate::systems::enemy_system::compose_configured_wave;
+use crate::systems::obstacle_rendering::ObstacleGrid;
+use crate::systems::popup_layout::{estimated_text_size, place_popup, ui_to_world, world_to_ui, PopupSide};
+use crate::systems::smart_enemy_system::SmartEnemySettings;
+
+/// Distance kept between a preview arrow and the edge of the play area
+const ARROW_EDGE_INSET: f32 = 24.0;
+/// Shaft length of an arrow carrying none of the wave
+const ARROW_MIN_LENGTH: f32 = 30.0;
+/// Extra shaft length of an arrow carrying the whole wave
+const ARROW_VOLUME_LENGTH: f32 = 50.0;
+const ARROW_COLOR: Color = Color::srgba(1.0, 0.55, 0.1, 0.85);
+const LABEL_FONT_SIZE: f32 = 14.0;
+/// Distance between the starts of the dashes tracing a boss wave's arena route
+const ARENA_DASH_SPACING: f32 = 24.0;
+const ARENA_DASH_LENGTH: f32 = 12.0;
+const ARENA_ROUTE_COLOR: Color = Color::srgba(0.9, 0.3, 0.9, 0.8);
+/// Extra label line on the preview of a wave taking the arena route
+const ARENA_TAG: &str = "Boss: arena route";
+
+// ============================================================================
+// PREVIEW
+// ============================================================================
+
+/// Where the next wave will come from and how much of it comes that way
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EntryPreview {
+    /// Entry point, pulled inside the play area so the arrow stays visible
+    pub position: Vec2,
+    /// Unit direction enemies head in as they enter
+    pub direction: Vec2,
+    pub enemies: u32,
+    /// Fraction of the wave using this entry
+    pub share: f32,
+}
+
+/// Split a wave across the entry of each path. Enemies are dealt to paths in
+/// turn, so earlier paths take the remainder of an uneven split.
+pub fn entry_previews(paths: &[&EnemyPath], enemy_count: u32) -> Vec<EntryPreview> {
+    let usable: Vec<&EnemyPath> = paths.iter().copied().filter(|path| !path.waypoints.is_empty()).collect();
+    if usable.is_empty() || enemy_count == 0 {
+        return Vec::new();
+    }
+
+    let path_count = usable.len() as u32;
+    let half_area = Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT) / 2.0 - Vec2::splat(ARROW_EDGE_INSET);
+    usable
+        .iter()
+        .enumerate()
+        .map(|(index, path)| {
+            let enemies = enemy_count / path_count + u32::from((index as u32) < enemy_count % path_count);
+            EntryPreview {
+                position: path.waypoints[0].clamp(-half_area, half_area),
+                direction: path.get_smooth_direction_at_progress(0.0),
+                enemies,
+                share: enemies as f32 / enemy_count as f32,
+            }
+        })
+        .collect()
+}
+
+/// Resource holding the entry arrows for the upcoming wave; empty while a wave is running
+#[derive(Resource, Debug, Default)]
+pub struct SpawnPreview {
+    /// Wave the preview is for
+    pub wave: u32,
+    /// What the wave is made of
+    pub composition: WaveComposition,
+    pub entries: Vec<EntryPreview>,
+    /// Route the wave takes instead of the shared path when it's a boss wave
+    pub arena: Option<EnemyPath>,
+}
+
+/// Marker for the sprites and labels making up the entry arrows
+#[derive(Component)]
+pub struct SpawnPreviewArrow;
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// System to work out the next wave's entries between waves and clear them once it starts
+pub fn update_spawn_preview_system(
+    wave_plan: Res<WavePlan>,
+    wave_runtime: Res<WaveRuntime>,
+    enemy_path: Res<EnemyPath>,
+    enemies: Query<(), With<Enemy>>,
+    wave_config: Option<Res<WaveConfig>>,
+    smart_settings: Option<Res<SmartEnemySettings>>,
+    obstacle_grid: Option<Res<ObstacleGrid>>,
+    arena_settings: Option<Res<BossArenaSettings>>,
+    path_variants: Option<Res<PathVariants>>,
+    mut preview: ResMut<SpawnPreview>,
+) {
+    let between_waves = (wave_plan.current_wave == 0 || wave_runtime.wave_complete(&wave_plan)) && enemies.is_empty();
+    if !between_waves {
+        if !preview.entries.is_empty() || preview.arena.is_some() {
+            preview.entries.clear();
+            preview.arena = None;
+        }
+        return;
+    }
+
+    let next_wave = wave_plan.next_wave();
+    let composition = compose_configured_wave(next_wave, wave_config.as_deref(), smart_settings.as_deref(), obstacle_grid.as_deref());
+    // Enemies take the map's routes in turn, as `enemy_spawning_system` sends them
+    let routes: Vec<EnemyPath> = (0..enemy_path.route_count()).filter_map(|index| enemy_path.route(index)).collect();
+    let entries = entry_previews(&routes.iter().collect::<Vec<_>>(), composition.total_enemies());
+    let arena = match (arena_settings, path_variants) {
+        (Some(settings), Some(variants)) if settings.uses_arena(next_wave) => variants.arena.clone(),
+        _ => None,
+    };
+    // Only touch the resource when something moved, so the arrows aren't rebuilt every frame
+    if preview.wave != next_wave || preview.entries != entries || preview.composition != composition || preview.arena != arena {
+        preview.wave = next_wave;
+        preview.composition = composition;
+        preview.entries = entries;
+        preview.arena = arena;
+    }
+}
+
+/// System to draw an arrow at each entry, longer for entries carrying more of the wave.
+/// The wave's tags are listed under the arrow carrying the most of it, and a boss
+/// wave's arena route is traced in dashes.
+pub fn spawn_preview_visual_system(
+    mut commands: Commands,
+    preview: Res<SpawnPreview>,
+    formatter: Res<NumberFormatter>,
+    arrows: Query<Entity, With<SpawnPreviewArrow>>,
+) {
+    if !preview.is_changed() {
+        return;
+    }
+
+    for entity in arrows.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let (min_reward, max_reward) = kill_reward_range();
+    let bounty = (preview.composition.bounty(min_reward), preview.composition.bounty(max_reward));
+    let mut tags: Vec<&str> = preview.composition.tags().iter().map(|tag| tag.label()).collect();
+    if let Some(arena) = &preview.arena {
+        tags.push(ARENA_TAG);
+        spawn_route_dashes(&mut commands, arena);
+    }
+    for (index, entry) in preview.entries.iter().enumerate() {
+        let length = ARROW_MIN_LENGTH + ARROW_VOLUME_LENGTH * entry.share;
+        let rotation = Quat::from_rotation_z(entry.direction.to_angle());
+        let shaft_center = entry.position + entry.direction * length / 2.0;
+        let tip = entry.position + entry.direction * length;
+
+        commands.spawn((
+            Sprite {
+                color: ARROW_COLOR,
+                custom_size: Some(Vec2::new(length, 6.0)),
+                ..default()
+            },
+            Transform::from_translation(shaft_center.extend(5.0)).with_rotation(rotation),
+            SpawnPreviewArrow,
+        ));
+        commands.spawn((
+            Sprite {
+                color: ARROW_COLOR,
+                custom_size: Some(Vec2::splat(14.0)),
+                ..default()
+            },
+            Transform::from_translation(tip.extend(5.0))
+                .with_rotation(rotation * Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+            SpawnPreviewArrow,
+        ));
+        let label = if index == 0 {
+            entry_label(preview.wave, entry, Some(bounty), &tags, &formatter)
+        } else {
+            entry_label(preview.wave, entry, None, &[], &formatter)
+        };
+        let label_position = entry_label_position(entry, &label);
+        commands.spawn((
+            Text2d::new(label),
+            TextFont {
+                font_size: LABEL_FONT_SIZE,
+                ..default()
+            },
+            TextColor(ARROW_COLOR),
+            Transform::from_translation(label_position.extend(5.0)),
+            SpawnPreviewArrow,
+        ));
+    }
+}
+
+/// Trace a route in evenly spaced dashes, so it reads apart from the solid path
+fn spawn_route_dashes(commands: &mut Commands, route: &EnemyPath) {
+    for segment in route.waypoints.windows(2) {
+        let (start, end) = (segment[0], segment[1]);
+        let direction = (end - start).normalize_or_zero();
+        let rotation = Quat::from_rotation_z(direction.to_angle());
+        let dashes = (start.distance(end) / ARENA_DASH_SPACING).ceil() as usize;
+        for dash in 0..dashes {
+            let center = start + direction * (dash as f32 * ARENA_DASH_SPACING + ARENA_DASH_LENGTH / 2.0);
+            commands.spawn((
+                Sprite {
+                    color: ARENA_ROUTE_COLOR,
+                    custom_size: Some(Vec2::new(ARENA_DASH_LENGTH, 4.0)),
+                    ..default()
+                },
+                Transform::from_translation(center.extend(4.0)).with_rotation(rotation),
+                SpawnPreviewArrow,
+            ));
+        }
+    }
+}
+
+/// Arrow label, e.g. "Wave 3: 8 (100%)", followed by the wave's bounty range
+/// and its tags on lines of their own
+pub fn entry_label(wave: u32, entry: &EntryPreview, bounty: Option<(u32, u32)>, tags: &[&str], formatter: &NumberFormatter) -> String {
+    let mut label = format!("Wave {}: {} ({:.0}%)", wave, entry.enemies, entry.share * 100.0);
+    if let Some((min_bounty, max_bounty)) = bounty {
+        label.push_str(&format!("\nBounty {}-{}", formatter.money(min_bounty), formatter.money(max_bounty)));
+    }
+    if !tags.is_empty() {
+        label.push_str(&format!("\n{}", tags.join(", ")));
+    }
+    label
+}
+
+/// Centre of an entry's label: beside the arrow's base, flipped away from the
+/// edges of the play area so it stays readable
+pub fn entry_label_position(entry: &EntryPreview, label: &str) -> Vec2 {
+    let area = Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT);
+    let size = estimated_text_size(label, LABEL_FONT_SIZE);
+    let anchor = Rect::from_center_size(world_to_ui(entry.position, area), Vec2::splat(ARROW_MIN_LENGTH));
+    // Below a sideways arrow, beside an upright one
+    let preferred = if entry.direction.x.abs() >= entry.direction.y.abs() {
+        PopupSide::Below
+    } else {
+        PopupSide::Right
+    };
+    let placement = place_popup(anchor, size, area, preferred);
+    ui_to_world(placement.position + size / 2.0, area)
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin previewing where the next wave enters the map
+pub struct SpawnPreviewPlugin;
+
+impl Plugin for SpawnPreviewPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpawnPreview>().add_systems(
+            Update,
+            (update_spawn_preview_system, spawn_preview_visual_system)
+                .chain()
+                .in_set(GameSystemSet::Gameplay)
+                .run_if(in_state(AppState::Playing)),
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::systems::combat_system::{DamageKind, DamagedEnemy, EnemyDamage};
+
+/// System to tick status effects: slows and chain marks wear off, and burns deal
+/// their damage, with kills paid out like any other. Slows are applied to speed
+/// by the movement system.
+pub fn status_effect_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut damage: EnemyDamage,
+    mut slowed: Query<(Entity, &mut Slow)>,
+    mut chained: Query<(Entity, &mut Chained)>,
+    mut burning: Query<(
+        Entity,
+        &Transform,
+        &mut Health,
+        &mut Burn,
+        Option<&LootTable>,
+        Option<&EnemyType>,
+    ), With<Enemy>>,
+) {
+    let delta_secs = time.delta_secs();
+
+    for (entity, mut slow) in slowed.iter_mut() {
+        if slow.tick(delta_secs) {
+            commands.entity(entity).remove::<Slow>();
+        }
+    }
+    for (entity, mut chain) in chained.iter_mut() {
+        if chain.tick(delta_secs) {
+            commands.entity(entity).remove::<Chained>();
+        }
+    }
+
+    for (entity, transform, mut health, mut burn, loot_table, enemy_type) in burning.iter_mut() {
+        // Enemies killed by a hit this frame are already on their way out
+        if health.is_dead() {
+            continue;
+        }
+        let burn_damage = burn.tick(delta_secs);
+        let killed = burn_damage > 0.0
+            && damage.apply(
+                &mut commands,
+                DamagedEnemy {
+                    entity,
+                    position: transform.translation.truncate(),
+                    health: &mut *health,
+                    enemy_type,
+                    loot_table,
+                },
+                burn_damage,
+                burn.tower_type,
+                DamageKind::Burn,
+            );
+        if !killed && burn.is_finished() {
+            commands.entity(entity).remove::<Burn>();
+        }
+    }
+}
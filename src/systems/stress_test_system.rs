@@ -0,0 +1,246 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::resources::*;
+use crate::systems::tower_rendering::spawn_tower_with_pattern;
+
+/// Enemies in a stress test can't be killed, so the load stays constant
+const STRESS_ENEMY_HEALTH: f32 = 1.0e9;
+/// Progress at which stress enemies are sent back to the start instead of reaching the base
+const STRESS_ENEMY_RECYCLE_PROGRESS: f32 = 0.95;
+/// Radius of the ring of dummy targets the synthetic projectiles fly between
+const DUMMY_TARGET_RADIUS: f32 = 400.0;
+/// Number of dummy targets on the ring
+const DUMMY_TARGET_COUNT: u32 = 16;
+/// Speed of the synthetic projectiles
+const STRESS_PROJECTILE_SPEED: f32 = 300.0;
+/// Distance at which a projectile counts as having reached its dummy target
+const DUMMY_TARGET_REACHED: f32 = 5.0;
+/// Area the synthetic towers are laid out over
+const TOWER_AREA: Vec2 = Vec2::new(1000.0, 500.0);
+
+/// Marker for every entity spawned by the stress test
+#[derive(Component)]
+pub struct StressTestEntity;
+
+/// Dummy target of synthetic projectiles, by its slot on the ring
+#[derive(Component, Debug, Clone, Copy)]
+pub struct StressDummyTarget(pub u32);
+
+/// Event to start a stress test with the load set in `StressTestConfig`
+#[derive(Event, Debug, Clone, Copy)]
+pub struct StartStressTestEvent;
+
+/// Position of the dummy target at a ring slot
+fn dummy_target_position(slot: u32) -> Vec2 {
+    let angle = slot as f32 / DUMMY_TARGET_COUNT as f32 * std::f32::consts::TAU;
+    Vec2::from_angle(angle) * DUMMY_TARGET_RADIUS
+}
+
+/// Evenly spread grid positions for `count` towers
+pub fn stress_tower_positions(count: u32) -> Vec<Vec2> {
+    if count == 0 {
+        return Vec::new();
+    }
+    let columns = ((count as f32 * TOWER_AREA.x / TOWER_AREA.y).sqrt().ceil() as u32).max(1);
+    let rows = count.div_ceil(columns);
+    let spacing = Vec2::new(TOWER_AREA.x / columns as f32, TOWER_AREA.y / rows as f32);
+    let origin = -TOWER_AREA / 2.0 + spacing / 2.0;
+    (0..count)
+        .map(|index| origin + spacing * Vec2::new((index % columns) as f32, (index / columns) as f32))
+        .collect()
+}
+
+/// Ring slot across from the given one
+fn opposite_slot(slot: u32) -> u32 {
+    (slot + DUMMY_TARGET_COUNT / 2) % DUMMY_TARGET_COUNT
+}
+
+/// Spawn one synthetic projectile flying from one dummy target to the opposite one
+fn spawn_stress_projectile(commands: &mut Commands, ring: &[Entity], index: u32) {
+    let slot = index % DUMMY_TARGET_COUNT;
+    let opposite = opposite_slot(slot);
+    let Some(target) = ring.get(opposite as usize) else {
+        return;
+    };
+    commands.spawn((
+        Projectile::new(0.0, STRESS_PROJECTILE_SPEED, *target, dummy_target_position(opposite), TowerType::Basic),
+        Sprite {
+            color: Color::srgb(0.9, 0.9, 0.9),
+            custom_size: Some(Vec2::splat(4.0)),
+            ..default()
+        },
+        Transform::from_translation(dummy_target_position(slot).extend(2.0)),
+        StressTestEntity,
+    ));
+}
+
+/// System to clear the board and spawn the synthetic load, without starting a wave
+pub fn start_stress_test_system(
+    mut commands: Commands,
+    mut events: EventReader<StartStressTestEvent>,
+    config: Res<StressTestConfig>,
+    mut run: ResMut<StressTestRun>,
+    enemy_path: Res<EnemyPath>,
+    existing: Query<Entity, Or<(With<Enemy>, With<Projectile>, With<TowerStats>, With<StressDummyTarget>)>>,
+) {
+    if events.read().count() == 0 {
+        return;
+    }
+    let config = *config;
+
+    for entity in &existing {
+        commands.entity(entity).despawn();
+    }
+
+    for index in 0..config.enemies {
+        let progress = index as f32 / config.enemies as f32 * STRESS_ENEMY_RECYCLE_PROGRESS;
+        commands.spawn((
+            Enemy::for_wave(1),
+            Health::new(STRESS_ENEMY_HEALTH),
+            PathProgress { current: progress },
+            SwarmOffset::for_spawn_index(index),
+            Sprite {
+                color: Color::srgb(1.0, 0.2, 0.2),
+                custom_size: Some(Vec2::splat(ENEMY_COLLISION_RADIUS * 2.0)),
+                ..default()
+            },
+            Transform::from_translation(enemy_path.get_smooth_position_at_progress(progress).extend(1.0)),
+            StressTestEntity,
+        ));
+    }
+
+    for (index, position) in stress_tower_positions(config.towers).into_iter().enumerate() {
+        let tower_type = TowerType::ALL[index % TowerType::ALL.len()];
+        let tower = spawn_tower_with_pattern(&mut commands, position, tower_type);
+        commands.entity(tower).insert(StressTestEntity);
+    }
+
+    let ring: Vec<Entity> = (0..DUMMY_TARGET_COUNT)
+        .map(|slot| {
+            commands
+                .spawn((
+                    Transform::from_translation(dummy_target_position(slot).extend(0.0)),
+                    StressDummyTarget(slot),
+                    StressTestEntity,
+                ))
+                .id()
+        })
+        .collect();
+    for index in 0..config.projectiles {
+        spawn_stress_projectile(&mut commands, &ring, index);
+    }
+
+    run.start(config);
+    info!(
+        "Stress test started: {} enemies, {} towers, {} projectiles for {:.0}s",
+        config.enemies, config.towers, config.projectiles, config.duration
+    );
+}
+
+/// System to keep the load constant: enemies loop back to the start of the path,
+/// projectiles bounce between dummy targets and lost ones are replaced
+pub fn maintain_stress_load_system(
+    mut commands: Commands,
+    run: Res<StressTestRun>,
+    mut enemies: Query<&mut PathProgress, (With<Enemy>, With<StressTestEntity>)>,
+    mut projectiles: Query<(&Transform, &mut Projectile), With<StressTestEntity>>,
+    targets: Query<(Entity, &StressDummyTarget)>,
+) {
+    if !run.active {
+        return;
+    }
+
+    for mut progress in &mut enemies {
+        if progress.current >= STRESS_ENEMY_RECYCLE_PROGRESS {
+            progress.current = 0.0;
+        }
+    }
+
+    let mut ring: Vec<(u32, Entity)> = targets.iter().map(|(entity, target)| (target.0, entity)).collect();
+    ring.sort_by_key(|(slot, _)| *slot);
+    let ring: Vec<Entity> = ring.into_iter().map(|(_, entity)| entity).collect();
+
+    let mut in_flight = 0;
+    for (transform, mut projectile) in &mut projectiles {
+        in_flight += 1;
+        if transform.translation.truncate().distance(projectile.target_position) >= DUMMY_TARGET_REACHED {
+            continue;
+        }
+        let Ok((_, reached)) = targets.get(projectile.target_entity) else {
+            continue;
+        };
+        let back = opposite_slot(reached.0);
+        if let Some(target) = ring.get(back as usize) {
+            projectile.target_entity = *target;
+            projectile.target_position = dummy_target_position(back);
+        }
+    }
+
+    for index in in_flight..run.config.projectiles {
+        spawn_stress_projectile(&mut commands, &ring, index);
+    }
+}
+
+/// System to sample frame times while a stress test runs, then export them and clear the load
+pub fn stress_test_sampling_system(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut run: ResMut<StressTestRun>,
+    enemies: Query<(), With<Enemy>>,
+    towers: Query<(), With<TowerStats>>,
+    projectiles: Query<(), With<Projectile>>,
+    stress_entities: Query<Entity, With<StressTestEntity>>,
+) {
+    if !run.active {
+        return;
+    }
+
+    let finished = run.record(
+        time.delta_secs(),
+        enemies.iter().count() as u32,
+        towers.iter().count() as u32,
+        projectiles.iter().count() as u32,
+    );
+    if !finished {
+        return;
+    }
+
+    run.active = false;
+    for entity in &stress_entities {
+        commands.entity(entity).despawn();
+    }
+
+    let summary = run.summary();
+    info!(
+        "Stress test finished: {} frames, avg {:.2} ms, p95 {:.2} ms, max {:.2} ms",
+        summary.frames, summary.average_ms, summary.p95_ms, summary.max_ms
+    );
+    match run.write_csv() {
+        Ok(()) => info!("Stress test samples written to {}", STRESS_TEST_CSV),
+        Err(error) => warn!("Failed to write {}: {}", STRESS_TEST_CSV, error),
+    }
+}
+
+/// Plugin for the synthetic load generator used to profile the gameplay systems
+pub struct StressTestPlugin;
+
+impl Plugin for StressTestPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<StressTestConfig>()
+            .init_resource::<StressTestRun>()
+            .add_event::<StartStressTestEvent>()
+            .add_systems(
+                Update,
+                (
+                    start_stress_test_system.before(EnemySet::WaveControl),
+                    maintain_stress_load_system
+                        .after(EnemySet::Movement)
+                        .before(EnemySet::Cleanup),
+                    stress_test_sampling_system.after(CombatSet::Collision),
+                )
+                    .in_set(GameSystemSet::Gameplay)
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
@@ -0,0 +1,787 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use crate::components::*;
+use crate::resources::*;
+use crate::systems::boss_arena_system::PinnedRoute;
+use crate::systems::combat_system::{FocusZone, TargetingMode, WaveStatus};
+use crate::systems::enemy_system::{compose_configured_wave, enemy_color, own_route};
+use crate::systems::game_snapshot::{EconomySnapshot, GameSnapshot, HealthSnapshot, TowerState};
+use crate::systems::map_share_system::{apply_shared_map, current_shared_map};
+use crate::systems::obstacle_rendering::ObstacleGrid;
+use crate::systems::path_generation::{current_level_seed, set_level_archetype, set_level_seed, LevelConfig, Obstacle, SharedMap};
+use crate::systems::settings_menu::GameSettings;
+use crate::systems::smart_enemy_system::{SmartEnemy, SmartEnemySettings, SmartRoute};
+use crate::systems::tower_rendering::spawn_tower_with_pattern;
+use crate::systems::tower_ui::TowerSelectionState;
+use crate::systems::void_terrain_system::{FlightRoute, FLYING_ENEMY_COLOR};
+
+/// Save a run quit mid-wave is suspended to; the extension follows the save format
+pub const SUSPEND_SAVE: &str = "suspended_run";
+/// Current version of the suspend file format
+pub const SUSPEND_VERSION: u32 = 1;
+
+// ============================================================================
+// SUSPENDED RUN
+// ============================================================================
+
+/// A run quit mid-wave, with enough of the board to pick it up exactly where it
+/// was left. Projectiles in flight are not kept; towers fire again on resume.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SuspendedRun {
+    pub seed: u64,
+    /// Board, routes and terrain the level is generated with, so a restart
+    /// after resuming is on the same map
+    #[serde(default)]
+    pub level: LevelConfig,
+    /// `SharedMap` code of the map in play
+    pub map_code: String,
+    /// Waypoints of the shared enemy path as `[x, y]`
+    pub path: Vec<[f32; 2]>,
+    /// Waypoints of the path's routes from further entry points
+    #[serde(default)]
+    pub extra_paths: Vec<Vec<[f32; 2]>>,
+    pub wave: SuspendedWave,
+    pub economy: EconomySnapshot,
+    pub score: SuspendedScore,
+    pub base: Option<HealthSnapshot>,
+    pub towers: Vec<SuspendedTower>,
+    pub enemies: Vec<SuspendedEnemy>,
+    /// Shop perks in purchase order
+    pub perks: Vec<Perk>,
+    pub free_towers: u32,
+    pub prestige: PrestigeSet,
+    /// Free play progress, if the run was in free play
+    pub free_play: Option<SuspendedFreePlay>,
+    pub checkpoint_retries_used: u32,
+    /// Cheat record of the run, so suspending doesn't wipe it clean
+    #[serde(default)]
+    pub integrity: RunIntegrity,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SuspendedWave {
+    pub current: u32,
+    pub enemies_spawned: u32,
+    pub pending_spawns: u32,
+    /// Seconds into the spawn timer's current interval
+    pub spawn_elapsed: f32,
+    pub spawning_paused: bool,
+    pub enemies_remaining: u32,
+    pub enemies_killed: u32,
+    pub enemies_escaped: u32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SuspendedScore {
+    pub points: u32,
+    pub enemies_killed: u32,
+    pub enemies_escaped: u32,
+    pub damage_dealt: f32,
+    pub money_earned: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SuspendedTower {
+    pub tower_type: TowerType,
+    pub position: [f32; 2],
+    pub upgrade_level: u32,
+    #[serde(default)]
+    pub branch: Option<UpgradeBranch>,
+    #[serde(default)]
+    pub targeting: TargetingMode,
+    /// Corners of the tower's focus zone as `[min, max]`
+    #[serde(default)]
+    pub focus_zone: Option<[[f32; 2]; 2]>,
+    #[serde(default)]
+    pub heat: SuspendedHeat,
+}
+
+impl SuspendedTower {
+    /// The tower as observed in `GameSnapshot`, with the settings the player gave it
+    fn capture(tower: &TowerState, entity: EntityRef) -> Self {
+        Self {
+            tower_type: tower.tower_type,
+            position: tower.position,
+            upgrade_level: tower.upgrade_level,
+            branch: tower.branch,
+            targeting: entity.get::<TargetingMode>().copied().unwrap_or_default(),
+            focus_zone: entity.get::<FocusZone>().map(|zone| [zone.rect.min.to_array(), zone.rect.max.to_array()]),
+            heat: entity.get::<Heat>().map(SuspendedHeat::from).unwrap_or_default(),
+        }
+    }
+}
+
+/// Overclock state of a tower
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct SuspendedHeat {
+    pub current: f32,
+    pub overclocked: bool,
+    /// Seconds into an overheat shutdown, if the tower is offline
+    pub shutdown_elapsed: Option<f32>,
+}
+
+impl From<&Heat> for SuspendedHeat {
+    fn from(heat: &Heat) -> Self {
+        Self {
+            current: heat.current,
+            overclocked: heat.overclocked,
+            shutdown_elapsed: heat.shutdown_timer.as_ref().map(Timer::elapsed_secs),
+        }
+    }
+}
+
+impl SuspendedHeat {
+    pub fn to_heat(self) -> Heat {
+        Heat {
+            current: self.current,
+            overclocked: self.overclocked,
+            shutdown_timer: self.shutdown_elapsed.map(|elapsed| {
+                let mut timer = Timer::from_seconds(OVERHEAT_SHUTDOWN_SECONDS, TimerMode::Once);
+                timer.set_elapsed(std::time::Duration::from_secs_f32(elapsed.max(0.0)));
+                timer
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SuspendedEnemy {
+    pub position: [f32; 2],
+    /// Progress along the route the enemy is walking, 0.0 to 1.0
+    pub progress: f32,
+    pub health: HealthSnapshot,
+    pub speed: f32,
+    pub reward: u32,
+    pub smart: bool,
+    pub flying: bool,
+    #[serde(default)]
+    pub enemy_type: EnemyType,
+    /// Lane of a swarm enemy
+    pub lateral: Option<f32>,
+    /// Waypoints of the route the enemy walks in place of the shared path
+    pub route: Option<Vec<[f32; 2]>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SuspendedFreePlay {
+    pub start_wave: u32,
+    pub start_score: u32,
+}
+
+fn points(path: &EnemyPath) -> Vec<[f32; 2]> {
+    path.waypoints.iter().map(|point| point.to_array()).collect()
+}
+
+fn waypoints(points: &[[f32; 2]]) -> Option<EnemyPath> {
+    (!points.is_empty()).then(|| EnemyPath::new(points.iter().map(|point| Vec2::from_array(*point)).collect()))
+}
+
+/// Whether a wave is under way: spawning or with enemies still on the board
+pub fn is_mid_wave(wave_plan: &WavePlan, wave_runtime: &WaveRuntime, game_state: GameState, live_enemies: usize) -> bool {
+    game_state == GameState::Playing
+        && wave_plan.current_wave > 0
+        && (!wave_runtime.wave_complete(wave_plan) || live_enemies > 0)
+}
+
+impl SuspendedRun {
+    pub fn migrations() -> MigrationRegistry {
+        MigrationRegistry::new(SUSPEND_VERSION)
+    }
+
+    pub fn to_json(&self) -> Result<String, SaveError> {
+        Self::migrations().save(self)
+    }
+
+    pub fn from_json(contents: &str) -> Result<Self, SaveError> {
+        Self::migrations().load(contents)
+    }
+
+    /// The run waiting in `SUSPEND_SAVE`, if there is one that can be read
+    pub fn load() -> Option<Self> {
+        existing_save_file(SUSPEND_SAVE)?;
+        Self::migrations()
+            .read_file(SUSPEND_SAVE)
+            .inspect_err(|error| warn!("Ignoring suspended run in {}: {}", SUSPEND_SAVE, error))
+            .ok()
+    }
+
+    /// Write the run to `SUSPEND_SAVE` in `format`, returning the file written
+    pub fn save(&self, format: SaveFormat) -> Result<String, SaveError> {
+        Self::migrations().write_file(self, SUSPEND_SAVE, format)
+    }
+
+    /// Delete `SUSPEND_SAVE` so a run can only be resumed once
+    pub fn remove_file() {
+        if let Err(error) = remove_save(SUSPEND_SAVE) {
+            warn!("Failed to delete {}: {}", SUSPEND_SAVE, error);
+        }
+    }
+
+    /// Read the run out of a world, or `None` when no wave is under way or the
+    /// world is missing part of a run
+    pub fn capture(world: &World) -> Option<Self> {
+        let run = Self::snapshot(world)?;
+        let game_state = *world.get_resource::<GameState>()?;
+        let (wave_plan, wave_runtime) = (world.get_resource::<WavePlan>()?, world.get_resource::<WaveRuntime>()?);
+        is_mid_wave(wave_plan, wave_runtime, game_state, run.enemies.len()).then_some(run)
+    }
+
+    /// Read the run out of a world at any point, between waves included, or
+    /// `None` when the world is missing part of a run. Builds on `GameSnapshot`,
+    /// adding what resuming needs beyond what a player can observe.
+    pub fn snapshot(world: &World) -> Option<Self> {
+        let wave_runtime = world.get_resource::<WaveRuntime>()?;
+        let wave_status = world.get_resource::<WaveStatus>()?;
+        world.get_resource::<Economy>()?;
+        let score = world.get_resource::<Score>()?;
+        let enemy_path = world.get_resource::<EnemyPath>()?;
+        let map = current_shared_map(world.get_resource::<ObstacleGrid>()?, enemy_path)?;
+        let game = GameSnapshot::capture(world);
+
+        let enemies = game
+            .enemies
+            .iter()
+            .map(|enemy| {
+                let entity = Entity::from_bits(enemy.entity);
+                SuspendedEnemy {
+                    position: enemy.position,
+                    progress: enemy.progress,
+                    health: enemy.health.unwrap_or_default(),
+                    speed: enemy.speed,
+                    reward: world.get::<Enemy>(entity).map_or(0, |enemy| enemy.reward),
+                    smart: enemy.smart,
+                    flying: world.get::<FlightRoute>(entity).is_some(),
+                    enemy_type: world.get::<EnemyType>(entity).copied().unwrap_or_default(),
+                    lateral: world.get::<SwarmOffset>(entity).map(|offset| offset.lateral),
+                    route: own_route(world.entity(entity)).map(points),
+                }
+            })
+            .collect();
+        let towers = game
+            .towers
+            .iter()
+            .map(|tower| SuspendedTower::capture(tower, world.entity(Entity::from_bits(tower.entity))))
+            .collect();
+        let perks = world.get_resource::<RunPerks>().cloned().unwrap_or_default();
+
+        Some(Self {
+            seed: current_level_seed(),
+            level: world.get_resource::<LevelConfig>().copied().unwrap_or_default(),
+            map_code: map.to_code(),
+            path: game.path,
+            extra_paths: enemy_path
+                .extra_routes
+                .iter()
+                .map(|route| route.iter().map(|point| point.to_array()).collect())
+                .collect(),
+            wave: SuspendedWave {
+                current: game.wave.current,
+                enemies_spawned: game.wave.enemies_spawned,
+                pending_spawns: wave_runtime.pending_spawns,
+                spawn_elapsed: wave_runtime.spawn_timer.elapsed_secs(),
+                spawning_paused: wave_runtime.spawning_paused,
+                enemies_remaining: wave_status.enemies_remaining,
+                enemies_killed: wave_status.enemies_killed,
+                enemies_escaped: wave_status.enemies_escaped,
+            },
+            economy: game.economy,
+            score: SuspendedScore {
+                points: game.score.points,
+                enemies_killed: game.score.enemies_killed,
+                enemies_escaped: game.score.enemies_escaped,
+                damage_dealt: score.damage_dealt,
+                money_earned: score.money_earned,
+            },
+            base: game.base,
+            towers,
+            enemies,
+            perks: perks.purchased,
+            free_towers: perks.free_towers,
+            prestige: world.get_resource::<RunPrestige>().map(|prestige| prestige.modifiers).unwrap_or_default(),
+            free_play: world
+                .get_resource::<FreePlayRun>()
+                .filter(|run| run.active)
+                .map(|run| SuspendedFreePlay { start_wave: run.start_wave, start_score: run.start_score }),
+            checkpoint_retries_used: world
+                .get_resource::<CheckpointState>()
+                .map_or(0, |checkpoints| checkpoints.retries_used),
+            integrity: world.get_resource::<RunIntegrity>().cloned().unwrap_or_default(),
+        })
+    }
+}
+
+// ============================================================================
+// COMPONENTS & RESOURCES
+// ============================================================================
+
+/// Resource holding the suspended run found on launch until the player resumes or discards it
+#[derive(Resource, Debug, Default)]
+pub struct PendingSuspendedRun(pub Option<SuspendedRun>);
+
+/// Event sent by the resume prompt
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuspendedRunChoice {
+    Resume,
+    Discard,
+}
+
+#[derive(Component)]
+pub struct SuspendPromptOverlay;
+
+#[derive(Component)]
+pub struct SuspendPromptButton {
+    pub choice: SuspendedRunChoice,
+}
+
+// ============================================================================
+// UI COLOR CONSTANTS (matching the results screen)
+// ============================================================================
+
+struct UIColors;
+
+impl UIColors {
+    const PANEL_BG: Color = Color::srgb(0.08, 0.12, 0.18);
+    const PANEL_BORDER: Color = Color::srgb(0.32, 0.38, 0.48);
+    const BUTTON_DEFAULT: Color = Color::srgb(0.15, 0.20, 0.28);
+    const BUTTON_HOVER: Color = Color::srgb(0.20, 0.28, 0.38);
+    const BORDER_DEFAULT: Color = Color::srgb(0.32, 0.38, 0.48);
+    const BORDER_HOVER: Color = Color::srgb(0.55, 0.65, 0.85);
+    const TEXT_PRIMARY: Color = Color::srgb(0.96, 0.96, 0.98);
+    const TEXT_SECONDARY: Color = Color::srgb(0.78, 0.82, 0.88);
+    const OVERLAY_BG: Color = Color::srgba(0.0, 0.0, 0.0, 0.6);
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// System to suspend the run to `SUSPEND_SAVE` when the game is closed mid-wave
+pub fn suspend_run_on_exit_system(world: &World) {
+    let exiting = world.get_resource::<Events<AppExit>>().is_some_and(|events| !events.is_empty());
+    if !exiting {
+        return;
+    }
+    let Some(run) = SuspendedRun::capture(world) else {
+        return;
+    };
+    match run.save(save_format(world)) {
+        Ok(file) => info!("Suspended wave {} to {}", run.wave.current, file),
+        Err(error) => warn!("Failed to suspend the run to {}: {}", SUSPEND_SAVE, error),
+    }
+}
+
+/// System to autosave the run to `SUSPEND_SAVE` mid-wave, every
+/// `autosave_interval_secs` of play as set in the settings
+pub fn autosave_run_system(world: &World, mut since_save: Local<f32>) {
+    let interval = world
+        .get_resource::<GameSettings>()
+        .map_or(0, |settings| settings.autosave_interval_secs);
+    if interval == 0 {
+        *since_save = 0.0;
+        return;
+    }
+    *since_save += world.get_resource::<Time>().map_or(0.0, |time| time.delta_secs());
+    if *since_save < interval as f32 {
+        return;
+    }
+    *since_save = 0.0;
+
+    // Between waves there is nothing a crash could lose
+    let Some(run) = SuspendedRun::capture(world) else {
+        return;
+    };
+    match run.save(save_format(world)) {
+        Ok(file) => info!("Autosaved wave {} to {}", run.wave.current, file),
+        Err(error) => warn!("Failed to autosave the run to {}: {}", SUSPEND_SAVE, error),
+    }
+}
+
+/// System to delete `SUSPEND_SAVE` once the run is lost or won, so an autosave
+/// from before the end can't be resumed to undo it
+pub fn clear_suspended_run_system(game_state: Res<GameState>, mut pending: ResMut<PendingSuspendedRun>) {
+    if !game_state.is_changed() || RunOutcome::from_game_state(&game_state).is_none() {
+        return;
+    }
+    pending.0 = None;
+    SuspendedRun::remove_file();
+}
+
+/// Format run saves are written in, as picked in the settings
+pub fn save_format(world: &World) -> SaveFormat {
+    world.get_resource::<GameSettings>().map_or_else(SaveFormat::default, |settings| settings.save_format)
+}
+
+/// System to offer the suspended run found on launch
+pub fn spawn_suspend_prompt_system(mut commands: Commands, pending: Res<PendingSuspendedRun>, formatter: Res<NumberFormatter>) {
+    let Some(run) = pending.0.as_ref() else {
+        return;
+    };
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            top: Val::Px(0.0),
+            width: Val::Vw(100.0),
+            height: Val::Vh(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(UIColors::OVERLAY_BG),
+        ZIndex(950), // Above the results screen, below the pause menu
+        SuspendPromptOverlay,
+    )).with_children(|parent| {
+        parent.spawn((
+            Node {
+                width: Val::Px(380.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(25.0)),
+                row_gap: Val::Px(12.0),
+                border: UiRect::all(Val::Px(3.0)),
+                ..default()
+            },
+            BackgroundColor(UIColors::PANEL_BG),
+            BorderColor(UIColors::PANEL_BORDER),
+            BorderRadius::all(Val::Px(15.0)),
+        )).with_children(|parent| {
+            parent.spawn((
+                Text::new("SUSPENDED RUN"),
+                TextFont {
+                    font_size: 28.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_PRIMARY),
+            ));
+            parent.spawn((
+                Text::new(format!(
+                    "Wave {} with {} enemies on the board, {} towers and {}",
+                    run.wave.current,
+                    run.enemies.len(),
+                    run.towers.len(),
+                    formatter.money(run.economy.money)
+                )),
+                TextFont {
+                    font_size: 15.0,
+                    ..default()
+                },
+                TextColor(UIColors::TEXT_SECONDARY),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+            create_prompt_button(parent, "Resume suspended run", SuspendedRunChoice::Resume);
+            create_prompt_button(parent, "Discard", SuspendedRunChoice::Discard);
+        });
+    });
+}
+
+fn create_prompt_button(parent: &mut ChildSpawnerCommands, text: &str, choice: SuspendedRunChoice) {
+    parent.spawn((
+        Button,
+        Node {
+            width: Val::Px(280.0),
+            height: Val::Px(46.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(UIColors::BUTTON_DEFAULT),
+        BorderColor(UIColors::BORDER_DEFAULT),
+        BorderRadius::all(Val::Px(8.0)),
+        SuspendPromptButton { choice },
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(text),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(UIColors::TEXT_PRIMARY),
+        ));
+    });
+}
+
+/// System to handle the resume prompt's buttons
+pub fn suspend_prompt_button_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor, &mut BorderColor, &SuspendPromptButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut choices: EventWriter<SuspendedRunChoice>,
+) {
+    for (interaction, mut bg_color, mut border_color, button) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                choices.write(button.choice);
+            }
+            Interaction::Hovered => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_HOVER);
+                *border_color = BorderColor(UIColors::BORDER_HOVER);
+            }
+            Interaction::None => {
+                *bg_color = BackgroundColor(UIColors::BUTTON_DEFAULT);
+                *border_color = BorderColor(UIColors::BORDER_DEFAULT);
+            }
+        }
+    }
+}
+
+/// System to put the suspended run back on the board, or drop it, once the player chooses.
+/// The suspend file is deleted on a discard or a successful resume, so a run can't be
+/// resumed twice; a run that fails to restore stays on disk.
+pub fn resume_suspended_run_system(
+    mut commands: Commands,
+    mut choices: EventReader<SuspendedRunChoice>,
+    mut pending: ResMut<PendingSuspendedRun>,
+    overlays: Query<Entity, With<SuspendPromptOverlay>>,
+    mut restorer: RunRestorer,
+) {
+    let Some(choice) = choices.read().last().copied() else {
+        return;
+    };
+    for overlay in overlays.iter() {
+        commands.entity(overlay).despawn();
+    }
+    let Some(run) = pending.0.take().filter(|_| choice == SuspendedRunChoice::Resume) else {
+        SuspendedRun::remove_file();
+        info!("Suspended run discarded");
+        return;
+    };
+
+    match restorer.restore(&run) {
+        Ok(()) => {
+            SuspendedRun::remove_file();
+            info!(
+                "Resumed wave {} with {} enemies and {} towers",
+                run.wave.current,
+                run.enemies.len(),
+                run.towers.len()
+            );
+        }
+        Err(error) => warn!("Suspended run can't be resumed, {}", error),
+    }
+}
+
+/// System parameter putting a `SuspendedRun` back on the board in place of the
+/// run in play
+#[derive(SystemParam)]
+pub struct RunRestorer<'w, 's> {
+    commands: Commands<'w, 's>,
+    wave_plan: ResMut<'w, WavePlan>,
+    wave_runtime: ResMut<'w, WaveRuntime>,
+    wave_status: ResMut<'w, WaveStatus>,
+    score: ResMut<'w, Score>,
+    economy: ResMut<'w, Economy>,
+    game_state: ResMut<'w, GameState>,
+    enemy_path: ResMut<'w, EnemyPath>,
+    obstacle_grid: ResMut<'w, ObstacleGrid>,
+    selection_state: ResMut<'w, TowerSelectionState>,
+    level_config: Option<ResMut<'w, LevelConfig>>,
+    wave_config: Option<Res<'w, WaveConfig>>,
+    smart_settings: Option<Res<'w, SmartEnemySettings>>,
+    perks: Option<ResMut<'w, RunPerks>>,
+    run_prestige: Option<ResMut<'w, RunPrestige>>,
+    free_play: Option<ResMut<'w, FreePlayRun>>,
+    checkpoints: Option<ResMut<'w, CheckpointState>>,
+    integrity: Option<ResMut<'w, RunIntegrity>>,
+    run_entities: Query<'w, 's, Entity, Or<(With<Enemy>, With<DyingEnemy>, With<Projectile>, With<TowerStats>, With<Obstacle>, With<LootPickup>)>>,
+    base_query: Query<'w, 's, &'static mut Health, With<Base>>,
+}
+
+impl RunRestorer<'_, '_> {
+    /// Replace the run in play with `run`. Leaves the board untouched and
+    /// returns why when the run can't be restored.
+    pub fn restore(&mut self, run: &SuspendedRun) -> Result<(), String> {
+        let map = match SharedMap::from_code(&run.map_code) {
+            Ok(map) => map,
+            Err(error) => return Err(format!("its map is broken: {}", error)),
+        };
+        let Some(path) = waypoints(&run.path) else {
+            return Err("it has no enemy path".to_string());
+        };
+        let extra_routes = run
+            .extra_paths
+            .iter()
+            .map(|route| route.iter().map(|point| Vec2::from_array(*point)).collect())
+            .collect();
+
+        for entity in self.run_entities.iter() {
+            self.commands.entity(entity).despawn();
+        }
+
+        // Map and path exactly as they were, with the seed the level was generated from
+        set_level_seed(run.seed);
+        set_level_archetype(map.archetype);
+        if let Some(level_config) = self.level_config.as_mut() {
+            **level_config = run.level;
+        }
+        apply_shared_map(&mut self.commands, &map, &mut self.obstacle_grid, &mut self.enemy_path);
+        *self.enemy_path = path.with_extra_routes(extra_routes);
+
+        for tower in &run.towers {
+            let snapshot = TowerSnapshot {
+                tower_type: tower.tower_type,
+                position: Vec2::from_array(tower.position),
+                upgrade_level: tower.upgrade_level,
+                branch: tower.branch,
+            };
+            let tower_entity = spawn_tower_with_pattern(&mut self.commands, snapshot.position, snapshot.tower_type);
+            let mut tower_commands = self.commands.entity(tower_entity);
+            tower_commands.insert((snapshot.restored_stats(), tower.targeting, tower.heat.to_heat()));
+            if let Some([min, max]) = tower.focus_zone {
+                tower_commands.insert(FocusZone {
+                    rect: Rect::from_corners(Vec2::from_array(min), Vec2::from_array(max)),
+                });
+            }
+        }
+
+        for suspended in &run.enemies {
+            let color = if suspended.flying {
+                FLYING_ENEMY_COLOR
+            } else {
+                enemy_color(EnemyKind::of_enemy(suspended.smart), suspended.enemy_type)
+            };
+            let mut enemy = self.commands.spawn((
+                Enemy {
+                    speed: suspended.speed,
+                    reward: suspended.reward,
+                    ..default()
+                },
+                suspended.enemy_type,
+                Health {
+                    current: suspended.health.current,
+                    max: suspended.health.max,
+                },
+                PathProgress { current: suspended.progress },
+                KnockbackLimiter::default(),
+                LootTable::for_enemy(suspended.enemy_type, run.wave.current),
+                Sprite {
+                    color,
+                    custom_size: Some(Vec2::splat(ENEMY_COLLISION_RADIUS * 2.0)),
+                    ..default()
+                },
+                Transform::from_translation(Vec2::from_array(suspended.position).extend(0.0)),
+            ));
+            if suspended.smart {
+                enemy.insert(SmartEnemy);
+            }
+            if let Some(lateral) = suspended.lateral {
+                enemy.insert(SwarmOffset { lateral });
+            }
+            if let Some(path) = suspended.route.as_deref().and_then(waypoints) {
+                if suspended.flying {
+                    enemy.insert(FlightRoute { path });
+                } else if suspended.smart {
+                    enemy.insert(SmartRoute { path });
+                } else {
+                    enemy.insert(PinnedRoute { path });
+                }
+            }
+        }
+
+        // The same wave composition, part way through its spawn schedule
+        *self.wave_plan = WavePlan::default();
+        *self.wave_runtime = WaveRuntime {
+            max_live_enemies: self.wave_runtime.max_live_enemies,
+            max_spawns_per_frame: self.wave_runtime.max_spawns_per_frame,
+            ..WaveRuntime::new()
+        };
+        if run.wave.current > 0 {
+            self.wave_plan.current_wave = run.wave.current - 1;
+            let composition = compose_configured_wave(
+                run.wave.current,
+                self.wave_config.as_deref(),
+                self.smart_settings.as_deref(),
+                Some(&*self.obstacle_grid),
+            );
+            start_composed_wave(&mut self.wave_plan, &mut self.wave_runtime, composition);
+        }
+        self.wave_runtime.enemies_spawned = run.wave.enemies_spawned;
+        self.wave_runtime.pending_spawns = run.wave.pending_spawns;
+        self.wave_runtime.spawning_paused = run.wave.spawning_paused;
+        let spawn_elapsed = std::time::Duration::from_secs_f32(run.wave.spawn_elapsed.max(0.0));
+        self.wave_runtime.spawn_timer.set_elapsed(spawn_elapsed);
+
+        *self.wave_status = WaveStatus {
+            enemies_remaining: run.wave.enemies_remaining,
+            enemies_killed: run.wave.enemies_killed,
+            enemies_escaped: run.wave.enemies_escaped,
+            wave_complete: false,
+        };
+        self.economy.money = run.economy.money;
+        self.economy.research_points = run.economy.research_points;
+        self.economy.materials = run.economy.materials;
+        self.economy.energy = run.economy.energy;
+        *self.score = Score {
+            current: run.score.points,
+            enemies_killed: run.score.enemies_killed,
+            enemies_escaped: run.score.enemies_escaped,
+            damage_dealt: run.score.damage_dealt,
+            money_earned: run.score.money_earned,
+        };
+        *self.game_state = GameState::Playing;
+
+        if let Some(perks) = self.perks.as_mut() {
+            **perks = RunPerks {
+                purchased: run.perks.clone(),
+                free_towers: run.free_towers,
+            };
+        }
+        if let Some(prestige) = self.run_prestige.as_mut() {
+            prestige.modifiers = run.prestige;
+        }
+        if let Some(free_play) = self.free_play.as_mut() {
+            **free_play = match run.free_play {
+                Some(suspended) => FreePlayRun {
+                    active: true,
+                    start_wave: suspended.start_wave,
+                    start_score: suspended.start_score,
+                    campaign: None,
+                },
+                None => FreePlayRun::default(),
+            };
+        }
+        if let Some(checkpoints) = self.checkpoints.as_mut() {
+            checkpoints.latest = None;
+            checkpoints.retries_used = run.checkpoint_retries_used;
+        }
+        if let Some(integrity) = self.integrity.as_mut() {
+            **integrity = run.integrity.clone();
+        }
+        if let Some(base) = run.base {
+            for mut base_health in self.base_query.iter_mut() {
+                base_health.current = base.current;
+                base_health.max = base.max;
+            }
+        }
+        self.selection_state.clear_selection();
+        Ok(())
+    }
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin to suspend a run quit mid-wave and offer to resume it on the next launch
+pub struct SuspendPlugin;
+
+impl Plugin for SuspendPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(PendingSuspendedRun(SuspendedRun::load()))
+            .add_event::<SuspendedRunChoice>()
+            // The main menu offers the run through Continue instead
+            .add_systems(Startup, spawn_suspend_prompt_system.run_if(not(in_state(AppState::MainMenu))))
+            .add_systems(
+                Update,
+                (suspend_prompt_button_system, resume_suspended_run_system)
+                    .chain()
+                    .in_set(GameSystemSet::UI),
+            )
+            .add_systems(Update, (autosave_run_system.run_if(in_state(AppState::Playing)), clear_suspended_run_system))
+            // Last, so exit requests sent during Update are seen before the app closes
+            .add_systems(Last, suspend_run_on_exit_system);
+    }
+}
@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use crate::resources::{CombatSet, EnemySet, GameSystemSet};
+
+/// Gameplay systems in the order they must run each frame. Checked against the
+/// resolved `Update` schedule in debug builds.
+pub const GAMEPLAY_SYSTEM_ORDER: &[&str] = &[
+    "manual_wave_system",
+    "path_generation_system",
+    "enemy_spawning_system",
+    "enemy_movement_system",
+    "enemy_cleanup_system",
+    "tower_targeting_system",
+    "projectile_spawning_system",
+    "projectile_movement_system",
+    "collision_system",
+    "game_state_system",
+];
+
+/// A problem found when checking the resolved schedule against an expected order
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SystemOrderViolation {
+    /// Expected system is not in the schedule
+    Missing(String),
+    /// `first` is expected to run before `second` but was scheduled after it
+    OutOfOrder { first: String, second: String },
+}
+
+/// Short names (last path segment) of a schedule's systems in resolved execution order.
+/// Returns `None` until the schedule has been initialized by running once.
+pub fn resolved_system_names(schedule: &Schedule) -> Option<Vec<String>> {
+    let systems = schedule.systems().ok()?;
+    Some(
+        systems
+            .map(|(_, system)| {
+                let name = system.name();
+                name.rsplit("::").next().unwrap_or(&name).to_string()
+            })
+            .collect(),
+    )
+}
+
+/// Check that every system in `expected` appears in `resolved`, in the same relative order
+pub fn find_order_violations(resolved: &[String], expected: &[&str]) -> Vec<SystemOrderViolation> {
+    let mut violations = Vec::new();
+    let mut previous: Option<(&str, usize)> = None;
+
+    for &name in expected {
+        let Some(index) = resolved.iter().position(|resolved_name| resolved_name == name) else {
+            violations.push(SystemOrderViolation::Missing(name.to_string()));
+            continue;
+        };
+
+        if let Some((previous_name, previous_index)) = previous {
+            if index < previous_index {
+                violations.push(SystemOrderViolation::OutOfOrder {
+                    first: previous_name.to_string(),
+                    second: name.to_string(),
+                });
+            }
+        }
+        previous = Some((name, index));
+    }
+
+    violations
+}
+
+/// Debug-build system that logs the resolved `Update` order once, after its first
+/// run, and reports any gameplay systems running out of their declared order
+pub fn validate_system_order_system(world: &mut World, mut validated: Local<bool>) {
+    if *validated {
+        return;
+    }
+
+    let Some(resolved) = world
+        .get_resource::<Schedules>()
+        .and_then(|schedules| schedules.get(Update))
+        .and_then(resolved_system_names)
+    else {
+        return;
+    };
+    *validated = true;
+
+    debug!("Resolved Update schedule ({} systems):\n  {}", resolved.len(), resolved.join("\n  "));
+
+    let violations = find_order_violations(&resolved, GAMEPLAY_SYSTEM_ORDER);
+    if violations.is_empty() {
+        info!("System order validated: {} gameplay systems in declared order", GAMEPLAY_SYSTEM_ORDER.len());
+    }
+    for violation in violations {
+        match violation {
+            // Missing systems are expected in partial apps (tests, examples)
+            SystemOrderViolation::Missing(name) => debug!("System order: {} not in Update schedule", name),
+            SystemOrderViolation::OutOfOrder { first, second } => {
+                error!("System order regression: {} must run before {}", first, second)
+            }
+        }
+    }
+}
+
+/// Plugin declaring the gameplay ordering (`EnemySet` then `CombatSet`, both inside
+/// `GameSystemSet::Gameplay`) and, in debug builds, validating the resolved schedule
+pub struct SystemOrderPlugin;
+
+impl Plugin for SystemOrderPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .configure_sets(Update, (
+                EnemySet::WaveControl,
+                EnemySet::PathGeneration,
+                EnemySet::Spawning,
+                EnemySet::Movement,
+                EnemySet::Cleanup,
+            ).chain().in_set(GameSystemSet::Gameplay))
+            .configure_sets(Update, (
+                CombatSet::Targeting,
+                CombatSet::Firing,
+                CombatSet::ProjectileMovement,
+                CombatSet::Collision,
+            ).chain().after(EnemySet::Cleanup).in_set(GameSystemSet::Gameplay));
+
+        #[cfg(debug_assertions)]
+        app.add_systems(Last, validate_system_order_system);
+    }
+}
@@ -0,0 +1,206 @@
+use bevy::prelude::*;
+use crate::components::*;
+use crate::resources::*;
+use crate::systems::results_screen::{RestartRunEvent, RestoreCheckpointEvent};
+use crate::systems::tween::{AlphaTween, Easing, TweenProgress};
+use crate::systems::ui_feedback::UiFeedback;
+
+/// Seconds an alert banner stays before it has fully faded
+const BANNER_DURATION: f32 = 2.5;
+/// Seconds the screen flash takes to fade
+const FLASH_DURATION: f32 = 0.6;
+
+/// Path progress points that raise an alert the first time the lead enemy passes them in a wave
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PathMilestone {
+    Halfway,
+    NearBase,
+}
+
+impl PathMilestone {
+    /// Milestones from least to most urgent
+    pub const ALL: [PathMilestone; 2] = [PathMilestone::Halfway, PathMilestone::NearBase];
+
+    /// Fraction of the path the lead enemy has to pass
+    pub fn threshold(&self) -> f32 {
+        match self {
+            PathMilestone::Halfway => 0.5,
+            PathMilestone::NearBase => 0.8,
+        }
+    }
+
+    pub fn get_message(&self) -> &'static str {
+        match self {
+            PathMilestone::Halfway => "Enemies are halfway down the path",
+            PathMilestone::NearBase => "Enemies approaching the base!",
+        }
+    }
+
+    /// Banner text and screen flash colour; the later milestone is louder
+    pub fn get_color(&self) -> Color {
+        match self {
+            PathMilestone::Halfway => Color::srgb(1.0, 0.78, 0.3),
+            PathMilestone::NearBase => Color::srgb(1.0, 0.3, 0.25),
+        }
+    }
+
+    /// Peak opacity of the screen flash
+    pub fn flash_alpha(&self) -> f32 {
+        match self {
+            PathMilestone::Halfway => 0.12,
+            PathMilestone::NearBase => 0.3,
+        }
+    }
+}
+
+/// Event sent when the lead enemy of a wave crosses a milestone
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct PathMilestoneEvent {
+    pub milestone: PathMilestone,
+    pub wave: u32,
+}
+
+/// Resource remembering which milestones already fired this wave
+#[derive(Resource, Debug, Default)]
+pub struct PathMilestoneState {
+    pub wave: u32,
+    pub triggered: Vec<PathMilestone>,
+}
+
+impl PathMilestoneState {
+    /// Milestones newly passed at `furthest` progress in `wave`, each reported once per wave
+    pub fn cross(&mut self, wave: u32, furthest: f32) -> Vec<PathMilestone> {
+        if wave != self.wave {
+            self.wave = wave;
+            self.triggered.clear();
+        }
+
+        let crossed: Vec<PathMilestone> = PathMilestone::ALL
+            .into_iter()
+            .filter(|milestone| furthest >= milestone.threshold() && !self.triggered.contains(milestone))
+            .collect();
+        self.triggered.extend(&crossed);
+        crossed
+    }
+}
+
+/// Marker for the alert banner and screen flash
+#[derive(Component)]
+pub struct ThreatAlertUi;
+
+/// System to watch the lead enemy's progress and raise each milestone once per wave.
+/// A restarted or rewound run may replay a wave number, so its alerts fire again.
+pub fn path_progress_monitor_system(
+    wave_plan: Res<WavePlan>,
+    enemies: Query<&PathProgress, With<Enemy>>,
+    mut state: ResMut<PathMilestoneState>,
+    mut restart_events: EventReader<RestartRunEvent>,
+    mut restore_events: EventReader<RestoreCheckpointEvent>,
+    mut milestone_events: EventWriter<PathMilestoneEvent>,
+    mut feedback: UiFeedback,
+) {
+    if restart_events.read().count() + restore_events.read().count() > 0 {
+        *state = PathMilestoneState::default();
+    }
+
+    let furthest = enemies.iter().map(|progress| progress.current).fold(0.0, f32::max);
+    let wave = wave_plan.current_wave;
+
+    // Milestones crossed in the same frame share one sting
+    let crossed = state.cross(wave, furthest);
+    if !crossed.is_empty() {
+        feedback.alert();
+    }
+    for milestone in crossed {
+        milestone_events.write(PathMilestoneEvent { milestone, wave });
+    }
+}
+
+/// System to show a fading banner and screen flash for each milestone alert
+pub fn threat_alert_ui_system(
+    mut commands: Commands,
+    mut milestone_events: EventReader<PathMilestoneEvent>,
+    existing: Query<Entity, With<ThreatAlertUi>>,
+    budget: Option<ResMut<EffectBudget>>,
+) {
+    // Several milestones in one frame only show the most urgent
+    let Some(event) = milestone_events.read().last() else {
+        return;
+    };
+    for entity in existing.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let color = event.milestone.get_color();
+    let flash_alpha = event.milestone.flash_alpha();
+    // The flash is cosmetic and skipped when effects are over budget; the banner always shows
+    let show_flash = budget.is_none_or(|mut budget| budget.admit(EffectCategory::ScreenFlash));
+    if show_flash {
+        commands.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(0.0),
+                top: Val::Px(0.0),
+                width: Val::Vw(100.0),
+                height: Val::Vh(100.0),
+                ..default()
+            },
+            BackgroundColor(color.with_alpha(flash_alpha)),
+            AlphaTween::new(
+                flash_alpha,
+                0.0,
+                TweenProgress::new(FLASH_DURATION, Easing::QuadOut).despawn_on_complete(),
+            ),
+            ZIndex(700), // Below the shop, results screen and pause menu
+            CosmeticEffect(EffectCategory::ScreenFlash),
+            ThreatAlertUi,
+        ));
+    }
+
+    // The banner holds, then fades out and despawns
+    commands.spawn((
+        Text::new(event.milestone.get_message()),
+        TextFont {
+            font_size: 32.0,
+            ..default()
+        },
+        TextColor(color),
+        TextLayout::new_with_justify(JustifyText::Center),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(90.0),
+            width: Val::Vw(100.0),
+            ..default()
+        },
+        AlphaTween::new(
+            1.0,
+            0.0,
+            TweenProgress::new(BANNER_DURATION * 0.4, Easing::QuadIn)
+                .with_delay(BANNER_DURATION * 0.6)
+                .despawn_on_complete(),
+        ),
+        ZIndex(701),
+        ThreatAlertUi,
+    ));
+    info!("Wave {} alert: {}", event.wave, event.milestone.get_message());
+}
+
+/// Plugin raising alerts as enemies get close to the base
+pub struct ThreatAlertPlugin;
+
+impl Plugin for ThreatAlertPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<PathMilestoneState>()
+            .add_event::<PathMilestoneEvent>()
+            .add_systems(
+                Update,
+                (path_progress_monitor_system, threat_alert_ui_system)
+                    .chain()
+                    .in_set(GameSystemSet::Gameplay)
+                    .after(EnemySet::Movement)
+                    .before(EnemySet::Cleanup)
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+use std::f32::consts::{PI, TAU};
+use bevy::prelude::*;
+use crate::components::{CosmeticEffect, EffectCategory};
+use crate::resources::*;
+use crate::systems::combat_system::TowerFiredEvent;
+use crate::systems::tower_rendering::TowerVisualPart;
+use crate::systems::tween::{AlphaTween, Easing, TweenProgress};
+
+/// Seconds a tower takes to settle back to its normal size after firing
+pub const FIRING_PULSE_DURATION: f32 = 0.15;
+/// How much bigger a tower pops when it fires
+pub const FIRING_PULSE_SCALE: f32 = 1.15;
+/// How fast towers turn to face their target, in radians per second
+pub const TOWER_TURN_RATE: f32 = 10.0;
+/// How long a muzzle flash stays on screen
+pub const MUZZLE_FLASH_DURATION: f32 = 0.08;
+const MUZZLE_FLASH_COLOR: Color = Color::srgb(1.0, 0.95, 0.7);
+const MUZZLE_FLASH_SIZE: f32 = 8.0;
+/// Above the tower's pattern
+const MUZZLE_FLASH_Z: f32 = 0.5;
+
+/// Component for how a tower's pattern is drawn: the way it faces and the
+/// pulse left from its last shot. Patterns face +X at rest.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq)]
+pub struct TowerPose {
+    /// Angle the pattern is turned to, in radians
+    pub facing: f32,
+    /// Angle it is turning toward
+    pub target_facing: f32,
+    /// Seconds left of the firing pulse
+    pub pulse: f32,
+}
+
+impl TowerPose {
+    /// Start a firing pulse and turn toward `direction`
+    pub fn fire(&mut self, direction: Vec2) {
+        if direction != Vec2::ZERO {
+            self.target_facing = direction.to_angle();
+        }
+        self.pulse = FIRING_PULSE_DURATION;
+    }
+
+    pub fn is_animating(&self) -> bool {
+        self.pulse > 0.0 || self.facing != self.target_facing
+    }
+
+    /// Turn the short way round toward the target facing and wind the pulse down
+    pub fn update(&mut self, delta_secs: f32) {
+        let remaining = (self.target_facing - self.facing + PI).rem_euclid(TAU) - PI;
+        let max_turn = TOWER_TURN_RATE * delta_secs;
+        self.facing = if remaining.abs() <= max_turn {
+            self.target_facing
+        } else {
+            self.facing + max_turn.copysign(remaining)
+        };
+        self.pulse = (self.pulse - delta_secs).max(0.0);
+    }
+
+    /// Scale of the pattern, popping up on a shot and easing back to 1.0
+    pub fn scale(&self) -> f32 {
+        1.0 + (FIRING_PULSE_SCALE - 1.0) * (self.pulse / FIRING_PULSE_DURATION)
+    }
+}
+
+/// Component for where a tower visual part sits relative to its tower at rest,
+/// recorded the first time the part is animated
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct PartRest {
+    pub offset: Vec2,
+    pub rotation: Quat,
+    pub scale: Vec3,
+}
+
+impl PartRest {
+    fn new(transform: &Transform, centre: Vec2) -> Self {
+        Self {
+            offset: transform.translation.truncate() - centre,
+            rotation: transform.rotation,
+            scale: transform.scale,
+        }
+    }
+
+    /// Place the part as its tower's pose turns and scales the whole pattern about its centre
+    pub fn apply(&self, centre: Vec2, pose: &TowerPose, transform: &mut Transform) {
+        let scale = pose.scale();
+        let offset = Vec2::from_angle(pose.facing).rotate(self.offset * scale);
+        transform.translation = (centre + offset).extend(transform.translation.z);
+        transform.rotation = Quat::from_rotation_z(pose.facing) * self.rotation;
+        transform.scale = self.scale * scale;
+    }
+}
+
+/// Marker for the brief flash at a tower's muzzle when it fires
+#[derive(Component)]
+pub struct MuzzleFlash;
+
+/// System to start a tower's firing pulse and turn it toward its target for
+/// each shot, with a muzzle flash when the effect budget allows
+pub fn tower_fired_system(
+    mut commands: Commands,
+    mut fired_events: EventReader<TowerFiredEvent>,
+    mut effect_budget: Option<ResMut<EffectBudget>>,
+    mut towers: Query<(&Transform, Option<&mut TowerPose>)>,
+) {
+    for event in fired_events.read() {
+        let Ok((transform, pose)) = towers.get_mut(event.tower) else {
+            continue;
+        };
+        let centre = transform.translation.truncate();
+        match pose {
+            Some(mut pose) => pose.fire(event.target - centre),
+            None => {
+                let mut pose = TowerPose::default();
+                pose.fire(event.target - centre);
+                commands.entity(event.tower).insert(pose);
+            }
+        }
+
+        if !effect_budget.as_deref_mut().is_none_or(|budget| budget.admit(EffectCategory::Particle)) {
+            continue;
+        }
+        // The tower base is hidden, so the flash is made visible in its own right
+        let flash = commands
+            .spawn((
+                Sprite {
+                    color: MUZZLE_FLASH_COLOR,
+                    custom_size: Some(Vec2::splat(MUZZLE_FLASH_SIZE)),
+                    ..default()
+                },
+                Transform::from_translation((event.muzzle - centre).extend(MUZZLE_FLASH_Z)),
+                Visibility::Visible,
+                AlphaTween::new(
+                    1.0,
+                    0.0,
+                    TweenProgress::new(MUZZLE_FLASH_DURATION, Easing::QuadIn).despawn_on_complete(),
+                ),
+                CosmeticEffect(EffectCategory::Particle),
+                MuzzleFlash,
+            ))
+            .id();
+        commands.entity(event.tower).add_child(flash);
+    }
+}
+
+/// System to turn and pulse each tower's visual parts with its pose. Parts of
+/// towers at rest are left alone.
+pub fn tower_animation_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut towers: Query<(Entity, &Transform, &mut TowerPose)>,
+    mut parts: Query<(Entity, &TowerVisualPart, &mut Transform, Option<&PartRest>), Without<TowerPose>>,
+) {
+    let delta_secs = time.delta_secs();
+    let mut poses = HashMap::new();
+    for (entity, transform, mut pose) in towers.iter_mut() {
+        let animating = pose.is_animating();
+        if animating {
+            pose.update(delta_secs);
+        }
+        poses.insert(entity, (transform.translation.truncate(), *pose, animating));
+    }
+
+    for (part_entity, part, mut transform, rest) in parts.iter_mut() {
+        let Some(&(centre, pose, animating)) = poses.get(&part.parent_tower) else {
+            continue;
+        };
+        let rest = match rest {
+            Some(rest) => *rest,
+            None => {
+                let rest = PartRest::new(&transform, centre);
+                commands.entity(part_entity).insert(rest);
+                rest
+            }
+        };
+        if animating {
+            rest.apply(centre, &pose, &mut transform);
+        }
+    }
+}
+
+/// Plugin for towers turning to face their targets, pulsing and flashing as they fire
+pub struct TowerAnimationPlugin;
+
+impl Plugin for TowerAnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (tower_fired_system, tower_animation_system)
+                .chain()
+                .after(CombatSet::Firing)
+                .in_set(GameSystemSet::Gameplay)
+                .run_if(in_state(AppState::Playing)),
+        );
+    }
+}
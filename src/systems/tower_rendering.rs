@@ -1,7 +1,8 @@
 use bevy::prelude::*;
 use crate::resources::{TowerType, TowerStats};
-use crate::components::{GamePosition, Health};
-use crate::systems::combat_system::Target;
+use crate::components::{FiringPattern, GamePosition, Health, Heat};
+use crate::systems::advisor_system::TowerActivity;
+use crate::systems::combat_system::{BarrelCycle, Target, TargetingMode};
 
 /// Component to mark entities that are part of a tower's visual pattern
 #[derive(Component)]
@@ -9,8 +10,56 @@ pub struct TowerVisualPart {
     pub parent_tower: Entity,
 }
 
-/// System to spawn towers with distinctive visual patterns
-pub fn spawn_tower_with_pattern(commands: &mut Commands, position: Vec2, tower_type: TowerType) {
+/// Ring outline thickness as a fraction of the range it shows
+const RANGE_RING_THICKNESS: f32 = 0.03;
+pub const RANGE_RING_VALID_COLOR: Color = Color::srgba(0.2, 1.0, 0.3, 0.6);
+pub const RANGE_RING_INVALID_COLOR: Color = Color::srgba(1.0, 0.25, 0.2, 0.6);
+pub const RANGE_RING_SELECTED_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.5);
+pub const RANGE_RING_UPGRADE_COLOR: Color = Color::srgba(1.0, 0.85, 0.3, 0.7);
+
+/// Shared mesh and materials for tower range rings. The mesh is a ring of
+/// radius 1, scaled up to each range.
+#[derive(Resource, Debug, Clone)]
+pub struct RangeRingAssets {
+    pub mesh: Handle<Mesh>,
+    /// Range of a tower that can go where it is shown
+    pub valid: Handle<ColorMaterial>,
+    /// Range of a tower that can't be built where it is shown
+    pub invalid: Handle<ColorMaterial>,
+    /// Range of a placed tower that is selected or under the mouse
+    pub selected: Handle<ColorMaterial>,
+    /// Range a placed tower would reach after its next upgrade
+    pub upgrade: Handle<ColorMaterial>,
+}
+
+/// Mesh and transform drawing a range ring of radius `range` around `position`
+pub fn range_ring(
+    assets: &RangeRingAssets,
+    material: &Handle<ColorMaterial>,
+    position: Vec2,
+    range: f32,
+    z: f32,
+) -> (Mesh2d, MeshMaterial2d<ColorMaterial>, Transform) {
+    (
+        Mesh2d(assets.mesh.clone()),
+        MeshMaterial2d(material.clone()),
+        Transform::from_translation(position.extend(z)).with_scale(Vec3::new(range, range, 1.0)),
+    )
+}
+
+/// Main colour of each tower type's pattern
+pub fn tower_pattern_color(tower_type: TowerType) -> Color {
+    match tower_type {
+        TowerType::Basic => Color::srgb(0.6, 0.4, 0.2),
+        TowerType::Advanced => Color::srgb(0.4, 0.4, 0.8),
+        TowerType::Laser => Color::srgb(1.0, 0.2, 0.2),
+        TowerType::Missile => Color::srgb(0.9, 0.9, 0.2),
+        TowerType::Tesla => Color::srgb(0.2, 0.6, 1.0),
+    }
+}
+
+/// System to spawn towers with distinctive visual patterns, returning the base tower entity
+pub fn spawn_tower_with_pattern(commands: &mut Commands, position: Vec2, tower_type: TowerType) -> Entity {
     let tower_stats = TowerStats::new(tower_type);
     
     // Spawn the main tower entity (invisible base)
@@ -21,10 +70,17 @@ pub fn spawn_tower_with_pattern(commands: &mut Commands, position: Vec2, tower_t
         Health::new(100.0),
         GamePosition::new(position.x, position.y),
         Target::default(),
+        BarrelCycle::default(),
+        FiringPattern::for_tower(tower_type),
+        TargetingMode::default(),
+        Heat::default(),
+        TowerActivity::default(),
     )).id();
 
     // Spawn the visual pattern based on tower type
     spawn_visual_pattern(commands, tower_entity, position, tower_type);
+
+    tower_entity
 }
 
 /// Spawns distinctive visual patterns for each tower type
@@ -40,7 +96,7 @@ fn spawn_visual_pattern(commands: &mut Commands, parent_tower: Entity, position:
 
 /// Basic Tower: Diamond with center dot pattern
 fn spawn_basic_pattern(commands: &mut Commands, parent_tower: Entity, position: Vec2) {
-    let brown_color = Color::srgb(0.6, 0.4, 0.2);
+    let brown_color = tower_pattern_color(TowerType::Basic);
     
     // Outer diamond (rotated square)
     commands.spawn((
@@ -83,7 +139,7 @@ fn spawn_advanced_pattern(commands: &mut Commands, parent_tower: Entity, positio
     // Outer ring
     commands.spawn((
         Sprite {
-            color: Color::srgb(0.4, 0.4, 0.8), // Blue
+            color: tower_pattern_color(TowerType::Advanced),
             custom_size: Some(Vec2::new(36.0, 36.0)),
             ..default()
         },
@@ -105,7 +161,7 @@ fn spawn_advanced_pattern(commands: &mut Commands, parent_tower: Entity, positio
 
 /// Laser Tower: Cross/plus pattern for precision
 fn spawn_laser_pattern(commands: &mut Commands, parent_tower: Entity, position: Vec2) {
-    let red_color = Color::srgb(1.0, 0.2, 0.2);
+    let red_color = tower_pattern_color(TowerType::Laser);
     
     // Horizontal bar
     commands.spawn((
@@ -143,7 +199,7 @@ fn spawn_laser_pattern(commands: &mut Commands, parent_tower: Entity, position:
 
 /// Missile Tower: Triangle/arrow pattern pointing up
 fn spawn_missile_pattern(commands: &mut Commands, parent_tower: Entity, position: Vec2) {
-    let yellow_color = Color::srgb(0.9, 0.9, 0.2);
+    let yellow_color = tower_pattern_color(TowerType::Missile);
     
     // Main triangle body (rotated square to form diamond)
     commands.spawn((
@@ -171,7 +227,7 @@ fn spawn_missile_pattern(commands: &mut Commands, parent_tower: Entity, position
 
 /// Tesla Tower: Concentric squares with energy dots pattern
 fn spawn_tesla_pattern(commands: &mut Commands, parent_tower: Entity, position: Vec2) {
-    let electric_blue = Color::srgb(0.2, 0.6, 1.0);
+    let electric_blue = tower_pattern_color(TowerType::Tesla);
     let bright_cyan = Color::srgb(0.4, 0.8, 1.0);
     let white_energy = Color::srgb(0.9, 0.95, 1.0);
     
@@ -264,11 +320,30 @@ pub fn cleanup_tower_visual_parts(
     }
 }
 
+/// Setup system for the range ring mesh and materials; skipped without 2D rendering
+pub fn setup_range_ring_assets(
+    mut commands: Commands,
+    meshes: Option<ResMut<Assets<Mesh>>>,
+    materials: Option<ResMut<Assets<ColorMaterial>>>,
+) {
+    let (Some(mut meshes), Some(mut materials)) = (meshes, materials) else {
+        return;
+    };
+    commands.insert_resource(RangeRingAssets {
+        mesh: meshes.add(Annulus::new(1.0 - RANGE_RING_THICKNESS, 1.0)),
+        valid: materials.add(RANGE_RING_VALID_COLOR),
+        invalid: materials.add(RANGE_RING_INVALID_COLOR),
+        selected: materials.add(RANGE_RING_SELECTED_COLOR),
+        upgrade: materials.add(RANGE_RING_UPGRADE_COLOR),
+    });
+}
+
 /// Plugin to add tower rendering systems
 pub struct TowerRenderingPlugin;
 
 impl Plugin for TowerRenderingPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, cleanup_tower_visual_parts);
+        app.add_systems(Startup, setup_range_ring_assets)
+            .add_systems(Update, cleanup_tower_visual_parts);
     }
 }
\ No newline at end of file
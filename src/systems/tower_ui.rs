@@ -1,8 +1,13 @@
 use bevy::prelude::*;
 use crate::resources::*;
 use crate::components::*;
+use crate::systems::combat_system::TargetingMode;
 use crate::systems::input_system::MouseInputState;
 use crate::systems::enemy_system::StartWaveEvent;
+use crate::systems::ui_feedback::UiFeedback;
+use crate::systems::tower_rendering::{range_ring, RangeRingAssets};
+use crate::systems::popup_layout::{measured_size, place_popup, ui_node_rect, viewport_size, PopupSide};
+use bevy::window::PrimaryWindow;
 
 // ============================================================================
 // UI COLOR CONSTANTS
@@ -97,8 +102,8 @@ impl Default for TowerSelectionState {
 pub struct TowerStatPopupState {
     /// Currently displayed tower type in popup (None = hidden)
     pub active_tower_type: Option<TowerType>,
-    /// Position where the popup should appear
-    pub position: Vec2,
+    /// On-screen rect of the button the popup belongs to, in UI pixels
+    pub anchor: Rect,
     /// Whether popup is visible
     pub visible: bool,
 }
@@ -107,16 +112,16 @@ impl Default for TowerStatPopupState {
     fn default() -> Self {
         Self {
             active_tower_type: None,
-            position: Vec2::new(300.0, 200.0),
+            anchor: Rect::default(),
             visible: false,
         }
     }
 }
 
 impl TowerStatPopupState {
-    pub fn show_for_tower(&mut self, tower_type: TowerType, position: Vec2) {
+    pub fn show_for_tower(&mut self, tower_type: TowerType, anchor: Rect) {
         self.active_tower_type = Some(tower_type);
-        self.position = position;
+        self.anchor = anchor;
         self.visible = true;
     }
 
@@ -191,10 +196,45 @@ pub struct TowerTypeButton {
 #[derive(Component)]
 pub struct UpgradeButton;
 
+/// Component for one of the two specialization buttons; `slot` indexes the
+/// selected tower type's `UpgradePath` branches
+#[derive(Component)]
+pub struct BranchButton {
+    pub slot: usize,
+}
+
+/// Component for the row holding the specialization buttons, shown while the
+/// selected tower can specialize
+#[derive(Component)]
+pub struct BranchButtonRow;
+
+/// Component for the overclock toggle button
+#[derive(Component)]
+pub struct OverclockButton;
+
+/// Component for the button that starts drawing a tower's focus zone
+#[derive(Component)]
+pub struct FocusZoneButton;
+
+/// Button cycling the selected tower's targeting mode
+#[derive(Component)]
+pub struct TargetingButton;
+
 /// Component for selected tower indicator
 #[derive(Component)]
 pub struct SelectedTowerIndicator;
 
+/// Ring showing the attack range of the selected or hovered tower
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct RangeIndicator {
+    pub tower: Entity,
+    /// Radius drawn this frame, easing toward the range it shows
+    pub radius: f32,
+}
+
+/// How quickly a range ring eases to a new radius, per second
+const RANGE_INDICATOR_EASE_RATE: f32 = 12.0;
+
 /// Component for tower tooltips
 #[derive(Component)]
 pub struct TowerTooltip;
@@ -258,6 +298,7 @@ pub fn tower_selection_system(
     mouse_input: Res<MouseInputState>,
     mouse_button_input: Res<ButtonInput<MouseButton>>,
     towers_query: Query<(Entity, &Transform), With<TowerStats>>,
+    constants: Res<GameConstants>,
 ) {
     // Handle right-click unselection
     if mouse_button_input.just_pressed(MouseButton::Right) {
@@ -278,8 +319,7 @@ pub fn tower_selection_system(
             let tower_pos = transform.translation.truncate();
             let distance = click_pos.distance(tower_pos);
             
-            // Tower click radius (slightly larger than tower size for easier clicking)
-            if distance < 40.0 && distance < closest_distance {
+            if distance < constants.tower_click_radius && distance < closest_distance {
                 closest_distance = distance;
                 closest_tower = Some(entity);
             }
@@ -310,7 +350,7 @@ pub fn tower_type_button_system(
     mut button_queries: ParamSet<(
         // Query for handling interactions (Changed<Interaction>)
         Query<
-            (&Interaction, &TowerTypeButton, &mut BackgroundColor, &mut BorderColor, &mut HoverState, &GlobalTransform),
+            (&Interaction, &TowerTypeButton, &mut BackgroundColor, &mut BorderColor, &mut HoverState, &GlobalTransform, &ComputedNode),
             (Changed<Interaction>, With<Button>),
         >,
         // Query for updating all buttons when selection state changes
@@ -323,7 +363,7 @@ pub fn tower_type_button_system(
     // First, handle button interactions using the first query
     {
         let mut interaction_query = button_queries.p0();
-        for (interaction, tower_button, mut bg_color, mut border_color, mut hover_state, global_transform) in interaction_query.iter_mut() {
+        for (interaction, tower_button, mut bg_color, mut border_color, mut hover_state, global_transform, computed_node) in interaction_query.iter_mut() {
             let is_selected = Some(tower_button.tower_type) == selection_state.selected_placement_type;
             
             match *interaction {
@@ -337,11 +377,9 @@ pub fn tower_type_button_system(
                         *border_color = UIColors::BORDER_SELECTED.into();
                         println!("Selected tower type: {:?}", tower_button.tower_type);
                     } else if mouse_button_input.pressed(MouseButton::Right) {
-                        // Right click: Show stat popup
-                        let button_pos = global_transform.translation().truncate();
-                        // Position popup to the left of the button to avoid UI overlap
-                        let popup_pos = Vec2::new(button_pos.x - 320.0, button_pos.y);
-                        popup_state.show_for_tower(tower_button.tower_type, popup_pos);
+                        // Right click: Show stat popup beside the button
+                        let anchor = ui_node_rect(global_transform, computed_node);
+                        popup_state.show_for_tower(tower_button.tower_type, anchor);
                         println!("Showing stat popup for tower: {:?}", tower_button.tower_type);
                     }
                 }
@@ -396,6 +434,40 @@ pub fn tower_type_button_system(
     }
 }
 
+/// Why a tower couldn't be upgraded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpgradeRefusal {
+    UnderConstruction,
+    MaxLevel,
+    CannotAfford,
+}
+
+impl UpgradeRefusal {
+    pub fn get_reason(&self) -> &'static str {
+        match self {
+            UpgradeRefusal::UnderConstruction => "the tower is still under construction",
+            UpgradeRefusal::MaxLevel => "the tower is fully upgraded",
+            UpgradeRefusal::CannotAfford => "cannot afford the upgrade",
+        }
+    }
+}
+
+/// Upgrade a tower, paying for it out of `economy`. Every way of upgrading
+/// (button, key, remote command, advisor) goes through here.
+pub fn try_upgrade_tower(stats: &mut TowerStats, under_construction: bool, economy: &mut Economy) -> Result<(), UpgradeRefusal> {
+    if under_construction {
+        return Err(UpgradeRefusal::UnderConstruction);
+    }
+    if !stats.can_upgrade() {
+        return Err(UpgradeRefusal::MaxLevel);
+    }
+    if !economy.try_spend(&stats.get_upgrade_cost()) {
+        return Err(UpgradeRefusal::CannotAfford);
+    }
+    stats.upgrade();
+    Ok(())
+}
+
 /// System to handle upgrade button clicks
 pub fn upgrade_button_system(
     selection_state: ResMut<TowerSelectionState>,
@@ -405,7 +477,8 @@ pub fn upgrade_button_system(
         (&Interaction, &mut BackgroundColor),
         (Changed<Interaction>, With<UpgradeButton>),
     >,
-    mut towers_query: Query<&mut TowerStats>,
+    mut towers_query: Query<(&mut TowerStats, Has<Constructing>)>,
+    mut feedback: UiFeedback,
 ) {
     for (interaction, mut color) in interaction_query.iter_mut() {
         if *interaction == Interaction::Pressed {
@@ -413,17 +486,18 @@ pub fn upgrade_button_system(
             mouse_input_state.left_clicked = false;
             
             if let Some(tower_entity) = selection_state.selected_tower_entity {
-                if let Ok(mut tower_stats) = towers_query.get_mut(tower_entity) {
-                    let upgrade_cost = tower_stats.get_upgrade_cost();
-                    
-                    if economy.can_afford(&upgrade_cost) && tower_stats.can_upgrade() {
-                        economy.spend(&upgrade_cost);
-                        tower_stats.upgrade();
-                        println!("Tower upgraded to level {}", tower_stats.upgrade_level);
-                        *color = Color::srgb(0.4, 0.8, 0.4).into(); // Success feedback
-                    } else {
-                        println!("Cannot afford upgrade or tower at max level");
-                        *color = Color::srgb(0.8, 0.4, 0.4).into(); // Error feedback
+                if let Ok((mut tower_stats, under_construction)) = towers_query.get_mut(tower_entity) {
+                    match try_upgrade_tower(&mut tower_stats, under_construction, &mut economy) {
+                        Ok(()) => {
+                            println!("Tower upgraded to level {}", tower_stats.upgrade_level);
+                            *color = Color::srgb(0.4, 0.8, 0.4).into(); // Success feedback
+                            feedback.confirm();
+                        }
+                        Err(refusal) => {
+                            println!("Cannot upgrade: {}", refusal.get_reason());
+                            *color = Color::srgb(0.8, 0.4, 0.4).into(); // Error feedback
+                            feedback.error();
+                        }
                     }
                 }
             }
@@ -435,12 +509,135 @@ pub fn upgrade_button_system(
     }
 }
 
+/// System to specialize the selected tower down the branch its button offers
+pub fn branch_button_system(
+    selection_state: Res<TowerSelectionState>,
+    mut mouse_input_state: ResMut<MouseInputState>,
+    mut interaction_query: Query<
+        (&Interaction, &BranchButton, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    mut towers_query: Query<(&mut TowerStats, Has<Constructing>)>,
+    mut feedback: UiFeedback,
+) {
+    for (interaction, button, mut color) in interaction_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                // Consume the mouse click to prevent tower placement
+                mouse_input_state.left_clicked = false;
+
+                let Some(tower_entity) = selection_state.selected_tower_entity else {
+                    continue;
+                };
+                let Ok((mut tower_stats, under_construction)) = towers_query.get_mut(tower_entity) else {
+                    continue;
+                };
+                let branch = UpgradePath::for_tower(tower_stats.tower_type).branches[button.slot];
+                if !under_construction && tower_stats.specialize(branch) {
+                    println!("{} tower specialized as {}", tower_stats.tower_type.get_name(), branch.get_name());
+                    feedback.confirm();
+                } else {
+                    feedback.error();
+                }
+            }
+            Interaction::Hovered => *color = Color::srgb(0.7, 0.58, 0.28).into(),
+            Interaction::None => *color = Color::srgb(0.55, 0.45, 0.2).into(),
+        }
+    }
+}
+
+/// System to show the specialization buttons while the selected tower can
+/// specialize, labelled with its type's branches
+pub fn branch_button_label_system(
+    selection_state: Res<TowerSelectionState>,
+    towers_query: Query<&TowerStats>,
+    mut row_query: Query<&mut Node, With<BranchButtonRow>>,
+    mut text_query: Query<(&mut Text, &BranchButtonText)>,
+) {
+    let selected = selection_state
+        .selected_tower_entity
+        .and_then(|tower_entity| towers_query.get(tower_entity).ok())
+        .filter(|stats| stats.can_specialize());
+
+    if let Ok(mut row) = row_query.single_mut() {
+        let display = if selected.is_some() { Display::Flex } else { Display::None };
+        if row.display != display {
+            row.display = display;
+        }
+    }
+
+    let Some(stats) = selected else {
+        return;
+    };
+    let path = UpgradePath::for_tower(stats.tower_type);
+    for (mut text, label) in text_query.iter_mut() {
+        let branch = path.branches[label.slot];
+        let content = format!("{}\n{}", branch.get_name().to_uppercase(), branch.get_description());
+        if **text != content {
+            **text = content;
+        }
+    }
+}
+
+/// System to cycle the selected tower's targeting mode from its panel button
+pub fn targeting_button_system(
+    selection_state: Res<TowerSelectionState>,
+    mut mouse_input_state: ResMut<MouseInputState>,
+    mut interaction_query: Query<
+        (&Interaction, &mut BackgroundColor),
+        (Changed<Interaction>, With<TargetingButton>),
+    >,
+    mut towers_query: Query<&mut TargetingMode>,
+    mut feedback: UiFeedback,
+) {
+    for (interaction, mut color) in interaction_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                // Consume the mouse click to prevent tower placement
+                mouse_input_state.left_clicked = false;
+
+                let Some(tower_entity) = selection_state.selected_tower_entity else {
+                    continue;
+                };
+                if let Ok(mut targeting_mode) = towers_query.get_mut(tower_entity) {
+                    *targeting_mode = targeting_mode.next();
+                    println!("Tower {:?} now targets {}", tower_entity, targeting_mode.get_name());
+                    feedback.confirm();
+                }
+            }
+            Interaction::Hovered => *color = Color::srgb(0.45, 0.4, 0.62).into(),
+            Interaction::None => *color = Color::srgb(0.35, 0.3, 0.5).into(),
+        }
+    }
+}
+
+/// System to show the selected tower's targeting mode on its button
+pub fn targeting_button_label_system(
+    selection_state: Res<TowerSelectionState>,
+    towers_query: Query<&TargetingMode>,
+    mut text_query: Query<&mut Text, With<TargetingButtonText>>,
+) {
+    let targeting_mode = selection_state
+        .selected_tower_entity
+        .and_then(|tower_entity| towers_query.get(tower_entity).ok())
+        .copied()
+        .unwrap_or_default();
+    let label = format!("TARGET: {}", targeting_mode.get_name().to_uppercase());
+
+    if let Ok(mut text) = text_query.single_mut() {
+        if **text != label {
+            **text = label;
+        }
+    }
+}
+
 /// System to update selected tower visual indicator
 pub fn selected_tower_indicator_system(
     mut commands: Commands,
     selection_state: Res<TowerSelectionState>,
     indicator_query: Query<Entity, With<SelectedTowerIndicator>>,
     towers_query: Query<&Transform, With<TowerStats>>,
+    constants: Res<GameConstants>,
 ) {
     // Remove existing indicators
     for entity in indicator_query.iter() {
@@ -453,7 +650,7 @@ pub fn selected_tower_indicator_system(
             commands.spawn((
                 Sprite {
                     color: Color::srgb(1.0, 1.0, 0.0), // Yellow selection ring
-                    custom_size: Some(Vec2::new(50.0, 50.0)),
+                    custom_size: Some(Vec2::splat(constants.selection_ring_size)),
                     ..default()
                 },
                 Transform::from_translation(
@@ -465,6 +662,95 @@ pub fn selected_tower_indicator_system(
     }
 }
 
+/// Range to show for a tower: the range after its next upgrade while that
+/// upgrade is previewed, otherwise its current range
+pub fn range_indicator_target(tower_stats: &TowerStats, previewing_upgrade: bool) -> f32 {
+    if previewing_upgrade && tower_stats.can_upgrade() {
+        let mut preview_stats = tower_stats.clone();
+        preview_stats.upgrade();
+        preview_stats.range
+    } else {
+        tower_stats.range
+    }
+}
+
+/// Tower the range ring belongs to: the selected tower in upgrade mode, or
+/// else the tower under the mouse while no tower type is picked for placement
+fn range_indicator_tower(
+    selection_state: &TowerSelectionState,
+    cursor: Vec2,
+    click_radius: f32,
+    towers: impl Iterator<Item = (Entity, Vec2)>,
+) -> Option<Entity> {
+    if selection_state.is_upgrade_mode() {
+        return selection_state.selected_tower_entity;
+    }
+    if selection_state.selected_placement_type.is_some() {
+        return None;
+    }
+    towers
+        .map(|(entity, position)| (entity, cursor.distance(position)))
+        .filter(|(_, distance)| *distance < click_radius)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _)| entity)
+}
+
+/// System to draw the range of the selected or hovered tower as a ring, growing
+/// to the post-upgrade range while the Upgrade button is hovered
+pub fn range_indicator_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    (selection_state, mouse_state, constants): (Res<TowerSelectionState>, Res<MouseInputState>, Res<GameConstants>),
+    ring_assets: Option<Res<RangeRingAssets>>,
+    towers_query: Query<(Entity, &Transform, &TowerStats)>,
+    upgrade_button_query: Query<&Interaction, With<UpgradeButton>>,
+    mut indicator_query: Query<(Entity, &mut RangeIndicator, &mut Transform, &mut MeshMaterial2d<ColorMaterial>), Without<TowerStats>>,
+) {
+    let Some(ring_assets) = ring_assets else {
+        return;
+    };
+    let target = range_indicator_tower(
+        &selection_state,
+        mouse_state.world_position,
+        constants.tower_click_radius,
+        towers_query.iter().map(|(entity, transform, _)| (entity, transform.translation.truncate())),
+    )
+    .and_then(|tower| towers_query.get(tower).ok());
+
+    let Some((tower, tower_transform, tower_stats)) = target else {
+        for (entity, ..) in indicator_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+
+    let previewing_upgrade = selection_state.selected_tower_entity == Some(tower)
+        && upgrade_button_query.iter().any(|interaction| *interaction != Interaction::None);
+    let radius = range_indicator_target(tower_stats, previewing_upgrade);
+    let material = if previewing_upgrade { &ring_assets.upgrade } else { &ring_assets.selected };
+    let position = tower_transform.translation.truncate();
+
+    let Some((_, mut indicator, mut transform, mut ring_material)) = indicator_query.iter_mut().next() else {
+        commands.spawn((
+            range_ring(&ring_assets, material, position, radius, 0.4),
+            RangeIndicator { tower, radius },
+        ));
+        return;
+    };
+
+    if indicator.tower == tower {
+        let blend = 1.0 - (-RANGE_INDICATOR_EASE_RATE * time.delta_secs()).exp();
+        indicator.radius += (radius - indicator.radius) * blend;
+    } else {
+        // A different tower: show its range straight away
+        *indicator = RangeIndicator { tower, radius };
+    }
+    *transform = Transform::from_translation(position.extend(0.4)).with_scale(Vec3::new(indicator.radius, indicator.radius, 1.0));
+    if ring_material.0 != *material {
+        ring_material.0 = material.clone();
+    }
+}
+
 // ============================================================================
 // UI SETUP FUNCTIONS
 // ============================================================================
@@ -616,14 +902,14 @@ pub fn setup_tower_placement_panel(mut commands: Commands) {
 }
 
 /// Setup the enhanced tower stat popup system
-pub fn setup_tower_stat_popup(mut commands: Commands) {
+pub fn setup_tower_stat_popup(mut commands: Commands, constants: Res<GameConstants>) {
     commands
         .spawn((
             Node {
                 position_type: PositionType::Absolute,
-                left: Val::Px(50.0), // Will be updated dynamically
+                left: Val::Px(constants.tooltip_left), // Will be updated dynamically
                 top: Val::Px(100.0), // Will be updated dynamically
-                width: Val::Px(340.0), // Wider for comprehensive info
+                width: Val::Px(constants.stat_popup_width), // Wider for comprehensive info
                 height: Val::Auto, // Auto height to fit content
                 flex_direction: FlexDirection::Column,
                 padding: UiRect::all(Val::Px(20.0)),
@@ -823,7 +1109,7 @@ pub fn setup_tower_upgrade_panel(mut commands: Commands) {
                 right: Val::Px(240.0), // Next to placement panel
                 top: Val::Px(20.0),
                 width: Val::Px(250.0),
-                height: Val::Px(350.0),
+                height: Val::Px(540.0),
                 flex_direction: FlexDirection::Column,
                 padding: UiRect::all(Val::Px(10.0)),
                 row_gap: Val::Px(5.0),
@@ -915,6 +1201,129 @@ pub fn setup_tower_upgrade_panel(mut commands: Commands) {
                         UpgradeButtonText,
                     ));
                 });
+
+            // Specialization choice, offered from BRANCH_LEVEL
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(50.0),
+                        column_gap: Val::Px(5.0),
+                        margin: UiRect::top(Val::Px(5.0)),
+                        display: Display::None,
+                        ..default()
+                    },
+                    BranchButtonRow,
+                ))
+                .with_children(|row| {
+                    for slot in 0..2 {
+                        row.spawn((
+                            Button,
+                            Node {
+                                flex_grow: 1.0,
+                                height: Val::Percent(100.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(0.55, 0.45, 0.2)),
+                            BranchButton { slot },
+                        ))
+                        .with_children(|button| {
+                            button.spawn((
+                                Text::new(""),
+                                TextFont {
+                                    font_size: 12.0,
+                                    ..default()
+                                },
+                                TextColor(Color::WHITE),
+                                TextLayout::new_with_justify(JustifyText::Center),
+                                BranchButtonText { slot },
+                            ));
+                        });
+                    }
+                });
+
+            // Overclock toggle button
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(34.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::top(Val::Px(5.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.5, 0.4, 0.3)),
+                    OverclockButton,
+                ))
+                .with_children(|button| {
+                    button.spawn((
+                        Text::new("OVERCLOCK (O)"),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        OverclockButtonText,
+                    ));
+                });
+
+            // Focus zone button
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(34.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::top(Val::Px(5.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.3, 0.4, 0.5)),
+                    FocusZoneButton,
+                ))
+                .with_children(|button| {
+                    button.spawn((
+                        Text::new("FOCUS: FULL RANGE (F)"),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        FocusZoneButtonText,
+                    ));
+                });
+
+            // Targeting mode button
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(34.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        margin: UiRect::top(Val::Px(5.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.35, 0.3, 0.5)),
+                    TargetingButton,
+                ))
+                .with_children(|button| {
+                    button.spawn((
+                        Text::new(format!("TARGET: {}", TargetingMode::default().get_name().to_uppercase())),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        TargetingButtonText,
+                    ));
+                });
         });
 }
 
@@ -981,16 +1390,9 @@ fn create_single_tower_button(parent: &mut ChildSpawnerCommands, tower_type: Tow
                 },
             ));
             
-            // Enhanced cost indicator with better formatting
-            let cost = tower_type.get_cost();
-            let cost_text = if cost.money > 0 && (cost.research_points > 0 || cost.materials > 0 || cost.energy > 0) {
-                format!("${}+", cost.money) // Show + for complex costs
-            } else {
-                format!("${}", cost.money)
-            };
-            
+            // Cost indicator, kept in the player's number format and market price by `tower_cost_label_system`
             button.spawn((
-                Text::new(cost_text),
+                Text::new(tower_cost_label(tower_type, None, &NumberFormatter::default())),
                 TextFont {
                     font_size: 12.0,  // Improved readability
                     ..default()
@@ -1000,10 +1402,44 @@ fn create_single_tower_button(parent: &mut ChildSpawnerCommands, tower_type: Tow
                     align_self: AlignSelf::Center,
                     ..default()
                 },
+                TowerCostLabel(tower_type),
             ));
         });
 }
 
+/// Cost shown on a tower button, with a "+" for costs beyond money and an
+/// arrow when market prices moved at the last wave
+pub fn tower_cost_label(tower_type: TowerType, market: Option<&MarketState>, formatter: &NumberFormatter) -> String {
+    let cost = resolve_tower_cost(market, tower_type);
+    let money = formatter.money(cost.money);
+    let label = if cost.money > 0 && (cost.research_points > 0 || cost.materials > 0 || cost.energy > 0) {
+        format!("{}+", money) // Show + for complex costs
+    } else {
+        money
+    };
+    match market.map_or(PriceTrend::Steady, |market| market.trend(tower_type)) {
+        PriceTrend::Steady => label,
+        trend => format!("{} {}", label, trend.arrow()),
+    }
+}
+
+/// Colour of a tower button's cost: red while rising, green at a dip
+pub fn price_trend_color(trend: PriceTrend) -> Color {
+    match trend {
+        PriceTrend::Rising => UIColors::TEXT_ERROR,
+        PriceTrend::Falling => UIColors::TEXT_SUCCESS,
+        PriceTrend::Steady => UIColors::TEXT_ACCENT,
+    }
+}
+
+/// Firing pattern shown in the upgrade panel, with its live state while charging or ramped
+pub fn firing_pattern_label(pattern: &FiringPattern) -> String {
+    match pattern.status_text() {
+        Some(status) => format!("{} ({})", pattern.get_name(), status),
+        None => pattern.get_name().to_string(),
+    }
+}
+
 // ============================================================================
 // UI UPDATE COMPONENTS
 // ============================================================================
@@ -1027,9 +1463,28 @@ pub struct UpgradeCostText;
 #[derive(Component)]
 pub struct UpgradeButtonText;
 
+/// Label of a specialization button
+#[derive(Component)]
+pub struct BranchButtonText {
+    pub slot: usize,
+}
+
+#[derive(Component)]
+pub struct OverclockButtonText;
+
+#[derive(Component)]
+pub struct FocusZoneButtonText;
+
+#[derive(Component)]
+pub struct TargetingButtonText;
+
 #[derive(Component)]
 pub struct ResourceStatusText;
 
+/// Cost text on the button for a tower type
+#[derive(Component)]
+pub struct TowerCostLabel(pub TowerType);
+
 #[derive(Component)]
 pub struct TooltipText;
 
@@ -1040,38 +1495,60 @@ pub struct TooltipText;
 /// Enhanced system to update resource status display with better formatting
 pub fn update_resource_status_system(
     economy: Res<Economy>,
+    formatter: Res<NumberFormatter>,
     mut resource_query: Query<&mut Text, With<ResourceStatusText>>,
 ) {
-    if economy.is_changed() {
+    if economy.is_changed() || formatter.is_changed() {
         if let Ok(mut text) = resource_query.single_mut() {
             // Enhanced formatting with ASCII symbols for better compatibility
             **text = format!(
-                "${}  |  R:{}  |  M:{}  |  E:{}",
-                economy.money,
-                economy.research_points,
-                economy.materials,
-                economy.energy
+                "{}  |  R:{}  |  M:{}  |  E:{}",
+                formatter.money(economy.money),
+                formatter.count(economy.research_points),
+                formatter.count(economy.materials),
+                formatter.count(economy.energy)
             );
         }
     }
 }
 
-/// System to handle hover tooltips for tower buttons with improved positioning
+/// System to rewrite the tower button costs when the number format or market prices change
+pub fn tower_cost_label_system(
+    formatter: Res<NumberFormatter>,
+    market: Option<Res<MarketState>>,
+    mut label_query: Query<(&mut Text, &mut TextColor, &TowerCostLabel)>,
+) {
+    if !formatter.is_changed() && !market.as_ref().is_some_and(|market| market.is_changed()) {
+        return;
+    }
+    for (mut text, mut color, label) in label_query.iter_mut() {
+        **text = tower_cost_label(label.0, market.as_deref(), &formatter);
+        let trend = market.as_ref().map_or(PriceTrend::Steady, |market| market.trend(label.0));
+        color.0 = price_trend_color(trend);
+    }
+}
+
+/// System to handle hover tooltips for tower buttons, placed beside the hovered button
 pub fn tower_tooltip_system(
-    button_query: Query<(&HoverState, &GlobalTransform, &TowerTypeButton), With<Button>>,
-    mut tooltip_query: Query<(&mut Node, &mut Text), (With<TowerTooltip>, Without<TowerTypeButton>)>,
+    button_query: Query<(&HoverState, &GlobalTransform, &ComputedNode, &TowerTypeButton), With<Button>>,
+    mut tooltip_query: Query<(&mut Node, &ComputedNode), (With<TowerTooltip>, Without<TowerTypeButton>)>,
+    mut tooltip_text_query: Query<&mut Text, With<TooltipText>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
     economy: Res<Economy>,
+    market: Option<Res<MarketState>>,
+    formatter: Res<NumberFormatter>,
+    constants: Res<GameConstants>,
 ) {
     let mut show_tooltip = false;
     let mut tooltip_content = String::new();
-    let mut tooltip_position = Vec2::ZERO;
+    let mut hovered = None;
 
     // Find any hovered button and get its position
-    for (hover_state, _global_transform, tower_button) in button_query.iter() {
+    for (hover_state, global_transform, computed_node, tower_button) in button_query.iter() {
         if hover_state.is_hovered {
             show_tooltip = true;
             let tower_type = tower_button.tower_type;
-            let cost = tower_type.get_cost();
+            let cost = resolve_tower_cost(market.as_deref(), tower_type);
             let stats = TowerStats::new(tower_type);
             let can_afford = economy.can_afford(&cost);
             
@@ -1080,15 +1557,15 @@ pub fn tower_tooltip_system(
             
             // Enhanced formatting with better visual hierarchy
             let mut cost_parts = Vec::new();
-            cost_parts.push(format!("${}", cost.money));
+            cost_parts.push(formatter.money(cost.money));
             if cost.research_points > 0 {
-                cost_parts.push(format!("R:{}", cost.research_points));
+                cost_parts.push(format!("R:{}", formatter.count(cost.research_points)));
             }
             if cost.materials > 0 {
-                cost_parts.push(format!("M:{}", cost.materials));
+                cost_parts.push(format!("M:{}", formatter.count(cost.materials)));
             }
             if cost.energy > 0 {
-                cost_parts.push(format!("E:{}", cost.energy));
+                cost_parts.push(format!("E:{}", formatter.count(cost.energy)));
             }
             let cost_display = cost_parts.join(" | ");
             
@@ -1099,8 +1576,10 @@ pub fn tower_tooltip_system(
                 "[X] INSUFFICIENT RESOURCES"
             };
             
+            let pattern = FiringPattern::for_tower(tower_type);
+            
             tooltip_content = format!(
-                "{}\n{}\n\nCost: {}\nStatus: {}\n\nPerformance:\n* DPS: {:.1}\n* Damage: {:.1}\n* Range: {:.1}\n* Fire Rate: {:.1}/sec",
+                "{}\n{}\n\nCost: {}\nStatus: {}\n\nPerformance:\n* DPS: {:.1}\n* Damage: {:.1}\n* Range: {:.1}\n* Fire Rate: {:.1}/sec\n* Firing: {} ({})",
                 tower_type.get_name(),
                 tower_type.get_description(),
                 cost_display,
@@ -1108,27 +1587,36 @@ pub fn tower_tooltip_system(
                 dps,
                 stats.damage,
                 stats.range,
-                stats.fire_rate
+                stats.fire_rate,
+                pattern.get_name(),
+                pattern.get_description()
             );
             
-            // Position tooltip to the left of the tower selection panel
-            // Since tower buttons are in a fixed UI panel on the right side,
-            // we can use fixed positioning relative to the panel
-            tooltip_position = Vec2::new(
-                50.0,  // Fixed position on left side of screen
-                200.0 + (tower_type as u8 as f32 * 100.0)  // Staggered vertically by tower type
-            );
+            hovered = Some((tower_type, ui_node_rect(global_transform, computed_node)));
             break; // Only show tooltip for first hovered button
         }
     }
 
     // Update tooltip visibility and content
-    if let Ok((mut tooltip_node, mut tooltip_text)) = tooltip_query.single_mut() {
+    if let Ok((mut tooltip_node, tooltip_computed)) = tooltip_query.single_mut() {
         if show_tooltip {
+            let tooltip_position = match hovered {
+                // Beside the button, flipping away from screen edges
+                Some((_, anchor)) if anchor.width() > 0.0 => {
+                    let size = measured_size(tooltip_computed, Vec2::new(250.0, 180.0));
+                    let viewport = viewport_size(window_query.single().ok());
+                    place_popup(anchor, size, viewport, PopupSide::Left).position
+                }
+                // The buttons haven't been laid out yet
+                Some((tower_type, _)) => constants.tooltip_position(tower_type),
+                None => Vec2::ZERO,
+            };
             tooltip_node.display = Display::Flex;
             tooltip_node.left = Val::Px(tooltip_position.x);
             tooltip_node.top = Val::Px(tooltip_position.y);
-            **tooltip_text = tooltip_content;
+            if let Ok(mut tooltip_text) = tooltip_text_query.single_mut() {
+                **tooltip_text = tooltip_content;
+            }
         } else {
             tooltip_node.display = Display::None;
         }
@@ -1139,7 +1627,8 @@ pub fn tower_tooltip_system(
 pub fn update_upgrade_panel_system(
     selection_state: Res<TowerSelectionState>,
     economy: Res<Economy>,
-    towers_query: Query<&TowerStats>,
+    formatter: Res<NumberFormatter>,
+    towers_query: Query<(&TowerStats, Option<&FiringPattern>)>,
     mut panel_query: Query<&mut Node, With<TowerUpgradePanel>>,
     mut tower_info_query: Query<&mut Text, (With<TowerInfoText>, Without<CurrentStatsText>, Without<UpgradePreviewText>, Without<UpgradeCostText>, Without<UpgradeButtonText>)>,
     mut current_stats_query: Query<&mut Text, (With<CurrentStatsText>, Without<TowerInfoText>, Without<UpgradePreviewText>, Without<UpgradeCostText>, Without<UpgradeButtonText>)>,
@@ -1159,7 +1648,7 @@ pub fn update_upgrade_panel_system(
 
     // Update panel content if a tower is selected
     if let Some(tower_entity) = selection_state.selected_tower_entity {
-        if let Ok(tower_stats) = towers_query.get(tower_entity) {
+        if let Ok((tower_stats, pattern)) = towers_query.get(tower_entity) {
             // Update tower info
             if let Ok(mut text) = tower_info_query.single_mut() {
                 **text = format!("{} Tower (Level {})", 
@@ -1172,6 +1661,9 @@ pub fn update_upgrade_panel_system(
                     },
                     tower_stats.upgrade_level
                 );
+                if let Some(branch) = tower_stats.branch {
+                    text.push_str(&format!("\n{} specialization", branch.get_name()));
+                }
             }
 
             // Update current stats
@@ -1182,6 +1674,9 @@ pub fn update_upgrade_panel_system(
                     tower_stats.range,
                     tower_stats.fire_rate
                 );
+                if let Some(pattern) = pattern {
+                    text.push_str(&format!("\nFiring: {}", firing_pattern_label(pattern)));
+                }
             }
 
             // Update upgrade preview
@@ -1206,8 +1701,11 @@ pub fn update_upgrade_panel_system(
                 if tower_stats.can_upgrade() {
                     let cost = tower_stats.get_upgrade_cost();
                     **text = format!(
-                        "Upgrade Cost:\nMoney: ${}\nResearch: {}\nMaterials: {}\nEnergy: {}",
-                        cost.money, cost.research_points, cost.materials, cost.energy
+                        "Upgrade Cost:\nMoney: {}\nResearch: {}\nMaterials: {}\nEnergy: {}",
+                        formatter.money(cost.money),
+                        formatter.count(cost.research_points),
+                        formatter.count(cost.materials),
+                        formatter.count(cost.energy)
                     );
                 } else {
                     **text = "".to_string();
@@ -1256,21 +1754,19 @@ pub fn update_upgrade_panel_system(
 /// System to automatically show/hide stat popup on hover
 pub fn hover_stat_popup_system(
     mut popup_state: ResMut<TowerStatPopupState>,
-    button_query: Query<(&HoverState, &GlobalTransform, &TowerTypeButton), With<Button>>,
+    button_query: Query<(&HoverState, &GlobalTransform, &ComputedNode, &TowerTypeButton), With<Button>>,
 ) {
     let mut any_hovered = false;
     let mut hovered_tower = None;
-    let mut hover_position = Vec2::ZERO;
+    let mut hover_anchor = Rect::default();
 
     // Check for any currently hovered button
-    for (hover_state, global_transform, tower_button) in button_query.iter() {
+    for (hover_state, global_transform, computed_node, tower_button) in button_query.iter() {
         if hover_state.is_hovered {
             any_hovered = true;
             hovered_tower = Some(tower_button.tower_type);
-            let button_pos = global_transform.translation().truncate();
-            // Position popup to the left of the tower selection panel to avoid overlap
-            // Fixed position ensures consistent placement
-            hover_position = Vec2::new(50.0, button_pos.y);
+            // The popup is placed beside the button when it's shown
+            hover_anchor = ui_node_rect(global_transform, computed_node);
             break; // Only show popup for first hovered button
         }
     }
@@ -1280,7 +1776,7 @@ pub fn hover_stat_popup_system(
         if let Some(tower_type) = hovered_tower {
             // Only update if it's a different tower type or not currently showing
             if popup_state.active_tower_type != Some(tower_type) || !popup_state.visible {
-                popup_state.show_for_tower(tower_type, hover_position);
+                popup_state.show_for_tower(tower_type, hover_anchor);
             }
         }
     } else {
@@ -1320,7 +1816,12 @@ pub fn popup_close_button_system(
 pub fn tower_stat_popup_system(
     popup_state: Res<TowerStatPopupState>,
     economy: Res<Economy>,
-    mut popup_query: Query<&mut Node, With<TowerStatPopup>>,
+    market: Option<Res<MarketState>>,
+    formatter: Res<NumberFormatter>,
+    constants: Res<GameConstants>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut popup_query: Query<(&mut Node, &ComputedNode), (With<TowerStatPopup>, Without<TowerTooltip>)>,
+    tooltip_query: Query<(&Node, &GlobalTransform, &ComputedNode), (With<TowerTooltip>, Without<TowerStatPopup>)>,
     mut header_query: Query<&mut Text, (With<PopupHeader>, Without<PopupDescriptionSection>, Without<PopupStatsSection>, Without<PopupCostSection>, Without<PopupUpgradeSection>)>,
     mut description_query: Query<&mut Text, (With<PopupDescriptionSection>, Without<PopupHeader>, Without<PopupStatsSection>, Without<PopupCostSection>, Without<PopupUpgradeSection>)>,
     mut stats_query: Query<&mut Text, (With<PopupStatsSection>, Without<PopupHeader>, Without<PopupDescriptionSection>, Without<PopupCostSection>, Without<PopupUpgradeSection>)>,
@@ -1328,11 +1829,21 @@ pub fn tower_stat_popup_system(
     mut upgrade_query: Query<&mut Text, (With<PopupUpgradeSection>, Without<PopupHeader>, Without<PopupDescriptionSection>, Without<PopupStatsSection>, Without<PopupCostSection>)>,
 ) {
     // Update popup visibility and position
-    if let Ok(mut popup_node) = popup_query.single_mut() {
+    if let Ok((mut popup_node, popup_computed)) = popup_query.single_mut() {
         if popup_state.is_showing() {
+            // Keep clear of the hover tooltip as well as the button
+            let mut anchor = popup_state.anchor;
+            if let Ok((tooltip_node, tooltip_transform, tooltip_computed)) = tooltip_query.single() {
+                if tooltip_node.display != Display::None && tooltip_computed.size() != Vec2::ZERO {
+                    anchor = anchor.union(ui_node_rect(tooltip_transform, tooltip_computed));
+                }
+            }
+            let size = measured_size(popup_computed, constants.stat_popup_size());
+            let viewport = viewport_size(window_query.single().ok());
+            let placement = place_popup(anchor, size, viewport, PopupSide::Left);
             popup_node.display = Display::Flex;
-            popup_node.left = Val::Px(popup_state.position.x);
-            popup_node.top = Val::Px(popup_state.position.y);
+            popup_node.left = Val::Px(placement.position.x);
+            popup_node.top = Val::Px(placement.position.y);
         } else {
             popup_node.display = Display::None;
         }
@@ -1341,7 +1852,7 @@ pub fn tower_stat_popup_system(
     // Update popup content when visible and tower type is available
     if let Some(tower_type) = popup_state.active_tower_type {
         let stats = TowerStats::new(tower_type);
-        let cost = tower_type.get_cost();
+        let cost = resolve_tower_cost(market.as_deref(), tower_type);
         let can_afford = economy.can_afford(&cost);
 
         // Update header
@@ -1379,16 +1890,16 @@ pub fn tower_stat_popup_system(
 
             let mut cost_parts = Vec::new();
             if cost.money > 0 {
-                cost_parts.push(format!("Money: ${}", cost.money));
+                cost_parts.push(format!("Money: {}", formatter.money(cost.money)));
             }
             if cost.research_points > 0 {
-                cost_parts.push(format!("Research: {}", cost.research_points));
+                cost_parts.push(format!("Research: {}", formatter.count(cost.research_points)));
             }
             if cost.materials > 0 {
-                cost_parts.push(format!("Materials: {}", cost.materials));
+                cost_parts.push(format!("Materials: {}", formatter.count(cost.materials)));
             }
             if cost.energy > 0 {
-                cost_parts.push(format!("Energy: {}", cost.energy));
+                cost_parts.push(format!("Energy: {}", formatter.count(cost.energy)));
             }
 
             **text = format!(
@@ -1410,12 +1921,12 @@ pub fn tower_stat_popup_system(
                 let fire_rate_increase = preview_stats.fire_rate - stats.fire_rate;
                 
                 **text = format!(
-                    "Level 2 Stats:\nDamage: {:.1} (+{:.1})\nRange: {:.1} (+{:.1})\nFire Rate: {:.1} (+{:.1})\n\nUpgrade Cost: ${} R:{} M:{} E:{}",
+                    "Level 2 Stats:\nDamage: {:.1} (+{:.1})\nRange: {:.1} (+{:.1})\nFire Rate: {:.1} (+{:.1})\n\nUpgrade Cost: {} R:{} M:{} E:{}",
                     preview_stats.damage, damage_increase,
                     preview_stats.range, range_increase,
                     preview_stats.fire_rate, fire_rate_increase,
-                    upgrade_cost.money, upgrade_cost.research_points, 
-                    upgrade_cost.materials, upgrade_cost.energy
+                    formatter.money(upgrade_cost.money), formatter.count(upgrade_cost.research_points),
+                    formatter.count(upgrade_cost.materials), formatter.count(upgrade_cost.energy)
                 );
             } else {
                 **text = "This tower cannot be upgraded further.".to_string();
@@ -1428,18 +1939,14 @@ pub fn tower_stat_popup_system(
 pub fn popup_outside_click_system(
     mut popup_state: ResMut<TowerStatPopupState>,
     mouse_input: Res<MouseInputState>,
-    popup_query: Query<&GlobalTransform, With<TowerStatPopup>>,
+    popup_query: Query<(&GlobalTransform, &ComputedNode), With<TowerStatPopup>>,
 ) {
     if popup_state.is_showing() && mouse_input.left_clicked {
-        // Check if click is outside popup bounds
-        if let Ok(popup_transform) = popup_query.single() {
-            let popup_pos = popup_transform.translation().truncate();
-            let click_pos = mouse_input.world_position;
-            
-            // Define popup bounds (approximate)
-            let popup_bounds = Rect::from_center_size(popup_pos, Vec2::new(340.0, 400.0));
+        // Check if click is outside popup bounds, both in UI pixels
+        if let Ok((popup_transform, popup_computed)) = popup_query.single() {
+            let popup_bounds = ui_node_rect(popup_transform, popup_computed);
             
-            if !popup_bounds.contains(click_pos) {
+            if !popup_bounds.contains(mouse_input.current_position) {
                 popup_state.hide();
                 println!("Popup closed via outside click");
             }
@@ -1455,11 +1962,12 @@ pub fn start_wave_button_system(
     >,
     mut wave_start_events: EventWriter<StartWaveEvent>,
     mut mouse_input_state: ResMut<MouseInputState>,
-    wave_manager: Res<WaveManager>,
+    wave_plan: Res<WavePlan>,
+    wave_runtime: Res<WaveRuntime>,
 ) {
     for (interaction, mut bg_color, mut border_color) in &mut interaction_query {
         // Check if wave can be started
-        let can_start_wave = wave_manager.current_wave == 0 || wave_manager.wave_complete();
+        let can_start_wave = wave_plan.current_wave == 0 || wave_runtime.wave_complete(&wave_plan);
         
         match *interaction {
             Interaction::Pressed => {
@@ -1500,23 +2008,24 @@ pub fn start_wave_button_system(
 
 /// System to update Start Wave button text and state based on wave manager
 pub fn update_start_wave_button_system(
-    wave_manager: Res<WaveManager>,
+    wave_plan: Res<WavePlan>,
+    wave_runtime: Res<WaveRuntime>,
     mut text_query: Query<&mut Text, With<StartWaveButtonText>>,
     mut button_query: Query<(&mut BackgroundColor, &mut BorderColor), (With<StartWaveButton>, Without<StartWaveButtonText>)>,
 ) {
-    if wave_manager.is_changed() {
-        let can_start_wave = wave_manager.current_wave == 0 || wave_manager.wave_complete();
+    if wave_plan.is_changed() || wave_runtime.is_changed() {
+        let can_start_wave = wave_plan.current_wave == 0 || wave_runtime.wave_complete(&wave_plan);
         
         // Update button text
         if let Ok(mut text) = text_query.single_mut() {
             **text = if can_start_wave {
-                if wave_manager.current_wave == 0 {
+                if wave_plan.current_wave == 0 {
                     "START FIRST WAVE".to_string()
                 } else {
-                    format!("START WAVE {}", wave_manager.current_wave + 1)
+                    format!("START WAVE {}", wave_plan.next_wave())
                 }
             } else {
-                format!("WAVE {} IN PROGRESS", wave_manager.current_wave)
+                format!("WAVE {} IN PROGRESS", wave_plan.current_wave)
             };
         }
         
@@ -1536,13 +2045,14 @@ pub fn update_start_wave_button_system(
 /// System to provide real-time affordability feedback on tower buttons
 pub fn tower_affordability_system(
     economy: Res<Economy>,
+    market: Option<Res<MarketState>>,
     selection_state: Res<TowerSelectionState>,
     mut button_query: Query<(&TowerTypeButton, &mut BackgroundColor, &mut BorderColor), (With<Button>, Without<HoverState>)>,
     hover_query: Query<&HoverState, With<TowerTypeButton>>,
 ) {
-    if economy.is_changed() {
+    if economy.is_changed() || market.as_ref().is_some_and(|market| market.is_changed()) {
         for (tower_button, mut bg_color, mut border_color) in button_query.iter_mut() {
-            let cost = tower_button.tower_type.get_cost();
+            let cost = resolve_tower_cost(market.as_deref(), tower_button.tower_type);
             let can_afford = economy.can_afford(&cost);
             let is_selected = Some(tower_button.tower_type) == selection_state.selected_placement_type;
             
@@ -0,0 +1,384 @@
+use bevy::prelude::*;
+
+// ============================================================================
+// EASING
+// ============================================================================
+
+/// Easing curves mapping linear progress (0.0-1.0) to eased progress
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    SmoothStep,
+    /// Overshoots slightly before settling, good for pop-in effects
+    BackOut,
+}
+
+impl Easing {
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::QuadIn => t * t,
+            Easing::QuadOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Easing::QuadInOut => {
+                if t < 0.5 {
+                    2.0 * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+                }
+            }
+            Easing::CubicIn => t * t * t,
+            Easing::CubicOut => 1.0 - (1.0 - t).powi(3),
+            Easing::CubicInOut => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::SmoothStep => t * t * (3.0 - 2.0 * t),
+            Easing::BackOut => {
+                let c1 = 1.70158;
+                let c3 = c1 + 1.0;
+                1.0 + c3 * (t - 1.0).powi(3) + c1 * (t - 1.0).powi(2)
+            }
+        }
+    }
+}
+
+// ============================================================================
+// TWEEN PROGRESS
+// ============================================================================
+
+/// What happens to an entity once one of its tweens finishes
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub enum TweenOnComplete {
+    /// Remove the tween component and leave the entity at the end value
+    #[default]
+    Remove,
+    /// Despawn the whole entity (e.g. floating text that faded out)
+    Despawn,
+}
+
+/// Shared timing state for all tween components
+#[derive(Clone, Debug, PartialEq)]
+pub struct TweenProgress {
+    pub duration: f32,
+    /// Seconds to wait before the tween starts moving
+    pub delay: f32,
+    pub elapsed: f32,
+    pub easing: Easing,
+    pub on_complete: TweenOnComplete,
+}
+
+impl TweenProgress {
+    pub fn new(duration: f32, easing: Easing) -> Self {
+        Self {
+            duration: duration.max(f32::EPSILON),
+            delay: 0.0,
+            elapsed: 0.0,
+            easing,
+            on_complete: TweenOnComplete::Remove,
+        }
+    }
+
+    pub fn with_delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn despawn_on_complete(mut self) -> Self {
+        self.on_complete = TweenOnComplete::Despawn;
+        self
+    }
+
+    pub fn tick(&mut self, delta_secs: f32) {
+        self.elapsed = (self.elapsed + delta_secs).min(self.delay + self.duration);
+    }
+
+    /// Linear progress (0.0-1.0) ignoring the easing curve
+    pub fn fraction(&self) -> f32 {
+        ((self.elapsed - self.delay) / self.duration).clamp(0.0, 1.0)
+    }
+
+    /// Eased progress used to interpolate between start and end values
+    pub fn eased(&self) -> f32 {
+        self.easing.apply(self.fraction())
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.delay + self.duration
+    }
+}
+
+// ============================================================================
+// TWEEN COMPONENTS
+// ============================================================================
+
+/// Moves an entity's `Transform` translation
+#[derive(Component, Clone, Debug)]
+pub struct TranslationTween {
+    pub from: Vec3,
+    pub to: Vec3,
+    pub progress: TweenProgress,
+}
+
+/// Scales an entity's `Transform`
+#[derive(Component, Clone, Debug)]
+pub struct ScaleTween {
+    pub from: Vec3,
+    pub to: Vec3,
+    pub progress: TweenProgress,
+}
+
+/// Blends the color of a `Sprite`, `TextColor` or `BackgroundColor`
+#[derive(Component, Clone, Debug)]
+pub struct ColorTween {
+    pub from: Color,
+    pub to: Color,
+    pub progress: TweenProgress,
+}
+
+/// Fades the alpha of a `Sprite`, `TextColor` or `BackgroundColor`, keeping its hue
+#[derive(Component, Clone, Debug)]
+pub struct AlphaTween {
+    pub from: f32,
+    pub to: f32,
+    pub progress: TweenProgress,
+}
+
+/// Slides a UI `Node` by animating its `left`/`top` offsets in pixels
+#[derive(Component, Clone, Debug)]
+pub struct UiOffsetTween {
+    pub from: Vec2,
+    pub to: Vec2,
+    pub progress: TweenProgress,
+}
+
+impl TranslationTween {
+    pub fn new(from: Vec3, to: Vec3, progress: TweenProgress) -> Self {
+        Self { from, to, progress }
+    }
+}
+
+impl ScaleTween {
+    pub fn new(from: Vec3, to: Vec3, progress: TweenProgress) -> Self {
+        Self { from, to, progress }
+    }
+}
+
+impl ColorTween {
+    pub fn new(from: Color, to: Color, progress: TweenProgress) -> Self {
+        Self { from, to, progress }
+    }
+}
+
+impl AlphaTween {
+    pub fn new(from: f32, to: f32, progress: TweenProgress) -> Self {
+        Self { from, to, progress }
+    }
+}
+
+impl UiOffsetTween {
+    pub fn new(from: Vec2, to: Vec2, progress: TweenProgress) -> Self {
+        Self { from, to, progress }
+    }
+}
+
+/// Which kind of tween finished
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TweenKind {
+    Translation,
+    Scale,
+    Color,
+    Alpha,
+    UiOffset,
+}
+
+/// Event sent when a tween reaches its end value
+#[derive(Event, Clone, Copy, Debug)]
+pub struct TweenCompleted {
+    pub entity: Entity,
+    pub kind: TweenKind,
+}
+
+// ============================================================================
+// TWEEN SYSTEMS
+// ============================================================================
+
+/// Remove or despawn according to the tween's completion policy and report it
+fn finish_tween<T: Component>(
+    commands: &mut Commands,
+    completed: &mut EventWriter<TweenCompleted>,
+    entity: Entity,
+    progress: &TweenProgress,
+    kind: TweenKind,
+) {
+    match progress.on_complete {
+        TweenOnComplete::Remove => {
+            commands.entity(entity).remove::<T>();
+        }
+        TweenOnComplete::Despawn => {
+            commands.entity(entity).despawn();
+        }
+    }
+    completed.write(TweenCompleted { entity, kind });
+}
+
+/// Linear blend between two colours, `t` running from 0.0 (`from`) to 1.0 (`to`)
+pub fn blend_colors(from: Color, to: Color, t: f32) -> Color {
+    let from = from.to_linear();
+    let to = to.to_linear();
+    Color::LinearRgba(LinearRgba::new(
+        from.red + (to.red - from.red) * t,
+        from.green + (to.green - from.green) * t,
+        from.blue + (to.blue - from.blue) * t,
+        from.alpha + (to.alpha - from.alpha) * t,
+    ))
+}
+
+pub fn translation_tween_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut completed: EventWriter<TweenCompleted>,
+    mut query: Query<(Entity, &mut TranslationTween, &mut Transform)>,
+) {
+    for (entity, mut tween, mut transform) in query.iter_mut() {
+        tween.progress.tick(time.delta_secs());
+        transform.translation = tween.from.lerp(tween.to, tween.progress.eased());
+
+        if tween.progress.is_finished() {
+            finish_tween::<TranslationTween>(&mut commands, &mut completed, entity, &tween.progress, TweenKind::Translation);
+        }
+    }
+}
+
+pub fn scale_tween_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut completed: EventWriter<TweenCompleted>,
+    mut query: Query<(Entity, &mut ScaleTween, &mut Transform)>,
+) {
+    for (entity, mut tween, mut transform) in query.iter_mut() {
+        tween.progress.tick(time.delta_secs());
+        transform.scale = tween.from.lerp(tween.to, tween.progress.eased());
+
+        if tween.progress.is_finished() {
+            finish_tween::<ScaleTween>(&mut commands, &mut completed, entity, &tween.progress, TweenKind::Scale);
+        }
+    }
+}
+
+pub fn color_tween_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut completed: EventWriter<TweenCompleted>,
+    mut query: Query<(
+        Entity,
+        &mut ColorTween,
+        Option<&mut Sprite>,
+        Option<&mut TextColor>,
+        Option<&mut BackgroundColor>,
+    )>,
+) {
+    for (entity, mut tween, sprite, text_color, background) in query.iter_mut() {
+        tween.progress.tick(time.delta_secs());
+        let color = blend_colors(tween.from, tween.to, tween.progress.eased());
+
+        if let Some(mut sprite) = sprite {
+            sprite.color = color;
+        }
+        if let Some(mut text_color) = text_color {
+            text_color.0 = color;
+        }
+        if let Some(mut background) = background {
+            background.0 = color;
+        }
+
+        if tween.progress.is_finished() {
+            finish_tween::<ColorTween>(&mut commands, &mut completed, entity, &tween.progress, TweenKind::Color);
+        }
+    }
+}
+
+pub fn alpha_tween_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut completed: EventWriter<TweenCompleted>,
+    mut query: Query<(
+        Entity,
+        &mut AlphaTween,
+        Option<&mut Sprite>,
+        Option<&mut TextColor>,
+        Option<&mut BackgroundColor>,
+    )>,
+) {
+    for (entity, mut tween, sprite, text_color, background) in query.iter_mut() {
+        tween.progress.tick(time.delta_secs());
+        let alpha = tween.from + (tween.to - tween.from) * tween.progress.eased();
+
+        if let Some(mut sprite) = sprite {
+            sprite.color.set_alpha(alpha);
+        }
+        if let Some(mut text_color) = text_color {
+            text_color.0.set_alpha(alpha);
+        }
+        if let Some(mut background) = background {
+            background.0.set_alpha(alpha);
+        }
+
+        if tween.progress.is_finished() {
+            finish_tween::<AlphaTween>(&mut commands, &mut completed, entity, &tween.progress, TweenKind::Alpha);
+        }
+    }
+}
+
+pub fn ui_offset_tween_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut completed: EventWriter<TweenCompleted>,
+    mut query: Query<(Entity, &mut UiOffsetTween, &mut Node)>,
+) {
+    for (entity, mut tween, mut node) in query.iter_mut() {
+        tween.progress.tick(time.delta_secs());
+        let offset = tween.from.lerp(tween.to, tween.progress.eased());
+        node.left = Val::Px(offset.x);
+        node.top = Val::Px(offset.y);
+
+        if tween.progress.is_finished() {
+            finish_tween::<UiOffsetTween>(&mut commands, &mut completed, entity, &tween.progress, TweenKind::UiOffset);
+        }
+    }
+}
+
+// ============================================================================
+// TWEEN PLUGIN
+// ============================================================================
+
+/// Plugin to drive tween components. Tweens run on virtual time, so they
+/// freeze together with the rest of the game while paused.
+pub struct TweenPlugin;
+
+impl Plugin for TweenPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<TweenCompleted>()
+            .add_systems(
+                Update,
+                (
+                    translation_tween_system,
+                    scale_tween_system,
+                    color_tween_system,
+                    alpha_tween_system,
+                    ui_offset_tween_system,
+                ),
+            );
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::time::Duration;
+use bevy::audio::Volume;
+use bevy::ecs::system::SystemParam;
+use bevy::input::gamepad::{Gamepad, GamepadRumbleIntensity, GamepadRumbleRequest};
+use bevy::prelude::*;
+use crate::resources::GameSystemSet;
+use crate::systems::settings_menu::GameSettings;
+
+// ============================================================================
+// UI FEEDBACK CUES
+// ============================================================================
+
+/// Directory the asset server loads cue sounds from
+pub const UI_SOUND_ASSET_ROOT: &str = "assets";
+
+/// Kinds of UI feedback. Each maps to a sound and, where it makes sense, a gamepad rumble.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UiCue {
+    Hover,
+    Click,
+    /// An action went through (tower placed, upgrade bought)
+    Confirm,
+    /// An action was refused (can't afford, invalid placement)
+    Error,
+    /// Gameplay warning, such as enemies closing in on the base
+    Alert,
+}
+
+impl UiCue {
+    pub const ALL: [UiCue; 5] = [UiCue::Hover, UiCue::Click, UiCue::Confirm, UiCue::Error, UiCue::Alert];
+
+    /// Sound asset played for this cue. Cues without a sound file stay silent.
+    pub fn sound_path(&self) -> &'static str {
+        match self {
+            UiCue::Hover => "sounds/ui/hover.ogg",
+            UiCue::Click => "sounds/ui/click.ogg",
+            UiCue::Confirm => "sounds/ui/confirm.ogg",
+            UiCue::Error => "sounds/ui/error.ogg",
+            UiCue::Alert => "sounds/ui/alert.ogg",
+        }
+    }
+
+    /// Whether the cue's sound file is present under the asset root
+    pub fn has_sound_file(&self) -> bool {
+        Path::new(UI_SOUND_ASSET_ROOT).join(self.sound_path()).is_file()
+    }
+
+    /// Loudness of the cue before the master and SFX volume are applied
+    pub fn volume(&self) -> f32 {
+        match self {
+            UiCue::Hover => 0.4,
+            UiCue::Click => 0.8,
+            UiCue::Confirm | UiCue::Error | UiCue::Alert => 1.0,
+        }
+    }
+
+    /// Gamepad rumble for this cue, if any
+    pub fn rumble(&self) -> Option<(GamepadRumbleIntensity, Duration)> {
+        match self {
+            UiCue::Hover => None,
+            UiCue::Click => Some((GamepadRumbleIntensity::weak_motor(0.3), Duration::from_millis(60))),
+            UiCue::Confirm => Some((GamepadRumbleIntensity::weak_motor(0.5), Duration::from_millis(100))),
+            UiCue::Error => Some((GamepadRumbleIntensity::strong_motor(0.7), Duration::from_millis(200))),
+            UiCue::Alert => Some((GamepadRumbleIntensity::strong_motor(0.5), Duration::from_millis(300))),
+        }
+    }
+}
+
+/// Event carrying a UI feedback request to `ui_feedback_system`
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UiFeedbackEvent {
+    pub cue: UiCue,
+}
+
+/// System parameter UI systems use to request feedback, so no system plays
+/// sounds or rumbles the gamepad itself
+#[derive(SystemParam)]
+pub struct UiFeedback<'w> {
+    events: EventWriter<'w, UiFeedbackEvent>,
+}
+
+impl UiFeedback<'_> {
+    pub fn cue(&mut self, cue: UiCue) {
+        self.events.write(UiFeedbackEvent { cue });
+    }
+
+    pub fn hover(&mut self) {
+        self.cue(UiCue::Hover);
+    }
+
+    pub fn click(&mut self) {
+        self.cue(UiCue::Click);
+    }
+
+    pub fn confirm(&mut self) {
+        self.cue(UiCue::Confirm);
+    }
+
+    pub fn error(&mut self) {
+        self.cue(UiCue::Error);
+    }
+
+    pub fn alert(&mut self) {
+        self.cue(UiCue::Alert);
+    }
+}
+
+/// Marker for buttons that should not get the automatic hover/click feedback
+#[derive(Component)]
+pub struct NoUiFeedback;
+
+/// Loaded sound handles for each cue
+#[derive(Resource, Default)]
+pub struct UiSoundHandles {
+    pub sounds: HashMap<UiCue, Handle<AudioSource>>,
+}
+
+// ============================================================================
+// UI FEEDBACK SYSTEMS
+// ============================================================================
+
+/// System to load the UI cue sounds at startup. Cues whose file is missing are
+/// not loaded, so they stay silent instead of logging a load error.
+pub fn load_ui_sounds(
+    mut commands: Commands,
+    asset_server: Option<Res<AssetServer>>,
+) {
+    let Some(asset_server) = asset_server else {
+        return;
+    };
+    let sounds = UiCue::ALL
+        .iter()
+        .filter(|cue| cue.has_sound_file())
+        .map(|cue| (*cue, asset_server.load(cue.sound_path())))
+        .collect();
+    commands.insert_resource(UiSoundHandles { sounds });
+}
+
+/// System to give every button hover and click feedback
+pub fn button_feedback_system(
+    mut pressed_buttons: Local<HashSet<Entity>>,
+    buttons: Query<(Entity, Ref<Interaction>, Has<NoUiFeedback>), With<Button>>,
+    mut feedback: UiFeedback,
+) {
+    for (entity, interaction, silent) in buttons.iter() {
+        if silent || !interaction.is_changed() {
+            continue;
+        }
+        match *interaction {
+            Interaction::Pressed => {
+                pressed_buttons.insert(entity);
+                feedback.click();
+            }
+            Interaction::Hovered => {
+                // Releasing a click goes back to Hovered; that isn't a new hover
+                if !pressed_buttons.remove(&entity) {
+                    feedback.hover();
+                }
+            }
+            Interaction::None => {
+                pressed_buttons.remove(&entity);
+            }
+        }
+    }
+}
+
+/// System to turn feedback requests into sounds and gamepad rumble, honouring
+/// the UI sound and rumble toggles in the settings
+pub fn ui_feedback_system(
+    mut commands: Commands,
+    mut feedback_events: EventReader<UiFeedbackEvent>,
+    settings: Option<Res<GameSettings>>,
+    sounds: Option<Res<UiSoundHandles>>,
+    asset_server: Option<Res<AssetServer>>,
+    gamepads: Query<Entity, With<Gamepad>>,
+    mut rumble_requests: EventWriter<GamepadRumbleRequest>,
+) {
+    // The same cue requested several times in one frame plays once
+    let mut cues: Vec<UiCue> = Vec::new();
+    for event in feedback_events.read() {
+        if !cues.contains(&event.cue) {
+            cues.push(event.cue);
+        }
+    }
+    if cues.is_empty() {
+        return;
+    }
+
+    let settings = settings.map(|settings| settings.clone()).unwrap_or_default();
+
+    for cue in cues {
+        if settings.ui_sounds_enabled {
+            // Sounds that are missing or still loading are skipped rather than queued
+            let handle = sounds.as_ref().and_then(|sounds| sounds.sounds.get(&cue));
+            let loaded = asset_server
+                .as_ref()
+                .zip(handle)
+                .is_some_and(|(asset_server, handle)| asset_server.is_loaded_with_dependencies(handle.id()));
+            if let (Some(handle), true) = (handle, loaded) {
+                let volume = settings.master_volume * settings.sfx_volume * cue.volume();
+                commands.spawn((
+                    AudioPlayer::new(handle.clone()),
+                    PlaybackSettings::DESPAWN.with_volume(Volume::Linear(volume)),
+                ));
+            }
+        }
+
+        if settings.ui_haptics_enabled {
+            if let Some((intensity, duration)) = cue.rumble() {
+                for gamepad in gamepads.iter() {
+                    rumble_requests.write(GamepadRumbleRequest::Add {
+                        duration,
+                        intensity,
+                        gamepad,
+                    });
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// UI FEEDBACK PLUGIN
+// ============================================================================
+
+/// Plugin to route UI hover/click/error feedback to audio cues and gamepad rumble
+pub struct UiFeedbackPlugin;
+
+impl Plugin for UiFeedbackPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<UiFeedbackEvent>()
+            .add_event::<GamepadRumbleRequest>()
+            .add_systems(Startup, load_ui_sounds)
+            .add_systems(Update, button_feedback_system.in_set(GameSystemSet::UI))
+            // Play everything requested this frame, including from gameplay systems
+            .add_systems(Update, ui_feedback_system.after(GameSystemSet::Gameplay));
+    }
+}
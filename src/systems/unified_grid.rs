@@ -1,5 +1,7 @@
 use bevy::prelude::*;
+use crate::resources::{GRID_CELL_SIZE, GRID_HEIGHT, GRID_WIDTH};
 use crate::systems::path_generation::grid::{PathGrid, GridPos, CellType};
+use crate::systems::placement_validator::terrain_verdict;
 
 /// Different visualization modes for the unified grid system
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -50,9 +52,9 @@ impl Default for UnifiedGridSystem {
             grid_entities: Vec::new(),
             // Dense grid covering full game area - 1280x720 screen
             // Optimized for maximum density with 1:1 aspect ratio cells
-            grid_width: 32,  // 1280 pixels / 40 = 32 cells
-            grid_height: 18, // 720 pixels / 40 = 18 cells
-            cell_size: 40.0, // 40x40 pixel squares (1:1 ratio)
+            grid_width: GRID_WIDTH,   // 1280 pixels / 40 = 32 cells
+            grid_height: GRID_HEIGHT, // 720 pixels / 40 = 18 cells
+            cell_size: GRID_CELL_SIZE, // 40x40 pixel squares (1:1 ratio)
             show_grid: true, // Always visible for dense grid
             show_path: true,
             show_zones: true,
@@ -181,6 +183,13 @@ pub fn update_grid_visualization(
                                 } else {
                                     Color::srgba(0.7, 0.7, 0.7, 0.4) // Default grid border when zones hidden
                                 }
+                            },
+                            CellType::Void => {
+                                if unified_grid.show_obstacles {
+                                    Color::srgba(0.1, 0.3, 0.6, 0.7) // Deep blue void - invalid placement
+                                } else {
+                                    Color::srgba(0.7, 0.7, 0.7, 0.4) // Default grid border when obstacles hidden
+                                }
                             }
                         }
                     } else {
@@ -189,12 +198,7 @@ pub fn update_grid_visualization(
                 },
                 GridVisualizationMode::Placement => {
                     // Determine if this cell is a valid placement location using the correct logic
-                    let is_valid_placement = is_valid_placement_cell(
-                        grid_tile.grid_pos,
-                        &path_grid,
-                        unified_grid.grid_width,
-                        unified_grid.grid_height,
-                    );
+                    let is_valid_placement = terrain_verdict(path_grid.as_deref(), grid_tile.grid_pos).is_buildable();
                     
                     if is_valid_placement {
                         Color::srgba(0.0, 1.0, 0.0, 0.3) // Green for valid placement
@@ -218,8 +222,8 @@ pub fn snap_to_grid(world_pos: Vec2, unified_grid: &UnifiedGridSystem) -> Vec2 {
     );
     
     let relative_pos = world_pos - grid_offset;
-    let grid_x = (relative_pos.x / unified_grid.cell_size).round();
-    let grid_y = (relative_pos.y / unified_grid.cell_size).round();
+    let grid_x = (relative_pos.x / unified_grid.cell_size).floor();
+    let grid_y = (relative_pos.y / unified_grid.cell_size).floor();
     
     grid_offset + Vec2::new(
         grid_x * unified_grid.cell_size + unified_grid.cell_size / 2.0,
@@ -307,31 +311,3 @@ pub fn f4_grid_border_toggle(
             if unified_grid.hide_grid_borders { "hidden" } else { "visible" });
     }
 }
-
-/// Helper function to determine if a grid cell is valid for tower placement
-/// This uses the same logic as the tower placement system for consistency
-fn is_valid_placement_cell(
-    grid_pos: GridPos,
-    path_grid: &Option<Res<PathGrid>>,
-    grid_width: usize,
-    grid_height: usize,
-) -> bool {
-    // Check bounds
-    if grid_pos.x >= grid_width || grid_pos.y >= grid_height {
-        return false;
-    }
-    
-    // Check against PathGrid if available (this includes obstacles and path)
-    if let Some(path_grid) = path_grid {
-        match path_grid.get_cell(grid_pos) {
-            Some(CellType::Empty) => true, // Allow placement on any empty cell
-            Some(CellType::TowerZone) => true, // Explicitly designated tower zones
-            Some(CellType::Path) => false, // Can't place on path
-            Some(CellType::Blocked) => false, // Can't place on obstacles
-            None => true, // Allow placement outside PathGrid bounds
-        }
-    } else {
-        // Without PathGrid, allow placement everywhere (let other systems handle restrictions)
-        true
-    }
-}
\ No newline at end of file
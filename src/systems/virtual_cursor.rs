@@ -0,0 +1,305 @@
+use bevy::prelude::*;
+use crate::resources::*;
+use crate::systems::input::{stick_direction, InputContext, InputRegistryAppExt, StickRepeat};
+use crate::systems::input_system::{mouse_input_system, MouseInputState};
+use crate::systems::path_generation::grid::GridPos;
+use crate::systems::tower_ui::TowerSelectionState;
+use crate::systems::unified_grid::{grid_to_world, world_to_grid, UnifiedGridSystem};
+
+/// Key switching between mouse and keyboard placement
+const KEYBOARD_CURSOR_KEY: KeyCode = KeyCode::KeyG;
+const CURSOR_UP_KEYS: [KeyCode; 2] = [KeyCode::ArrowUp, KeyCode::KeyW];
+const CURSOR_DOWN_KEYS: [KeyCode; 2] = [KeyCode::ArrowDown, KeyCode::KeyS];
+const CURSOR_LEFT_KEYS: [KeyCode; 2] = [KeyCode::ArrowLeft, KeyCode::KeyA];
+const CURSOR_RIGHT_KEYS: [KeyCode; 2] = [KeyCode::ArrowRight, KeyCode::KeyD];
+const CURSOR_CONFIRM_KEYS: [KeyCode; 2] = [KeyCode::Enter, KeyCode::NumpadEnter];
+const CURSOR_CANCEL_KEY: KeyCode = KeyCode::Backspace;
+const PREVIOUS_TOWER_KEY: KeyCode = KeyCode::KeyQ;
+const NEXT_TOWER_KEY: KeyCode = KeyCode::KeyE;
+
+const CURSOR_HIGHLIGHT_COLOR: Color = Color::srgba(1.0, 1.0, 1.0, 0.25);
+
+// ============================================================================
+// STATE
+// ============================================================================
+
+/// Device steering the placement cursor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CursorSource {
+    #[default]
+    Mouse,
+    Keyboard,
+    Gamepad,
+}
+
+/// One frame of cursor input from a keyboard or gamepad
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CursorCommand {
+    /// Cells to move by
+    pub step: IVec2,
+    /// Tower types to cycle the selection by
+    pub cycle: i32,
+    pub confirm: bool,
+    pub cancel: bool,
+}
+
+impl CursorCommand {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Merge the input of two devices
+    pub fn combine(self, other: Self) -> Self {
+        Self {
+            step: self.step + other.step,
+            cycle: self.cycle + other.cycle,
+            confirm: self.confirm || other.confirm,
+            cancel: self.cancel || other.cancel,
+        }
+    }
+}
+
+/// Resource for a grid cursor that keyboard and gamepad placement steer in
+/// place of the mouse
+#[derive(Resource, Debug)]
+pub struct VirtualCursor {
+    pub source: CursorSource,
+    pub cell: GridPos,
+    /// Screen position of the mouse when the cursor took over; moving away hands control back
+    pub mouse_anchor: Vec2,
+}
+
+impl Default for VirtualCursor {
+    fn default() -> Self {
+        Self {
+            source: CursorSource::Mouse,
+            cell: GridPos::new(0, 0),
+            mouse_anchor: Vec2::ZERO,
+        }
+    }
+}
+
+impl VirtualCursor {
+    pub fn is_active(&self) -> bool {
+        self.source != CursorSource::Mouse
+    }
+
+    pub fn take_over(&mut self, source: CursorSource, cell: GridPos, mouse_position: Vec2) {
+        self.source = source;
+        self.cell = cell;
+        self.mouse_anchor = mouse_position;
+    }
+
+    pub fn release(&mut self) {
+        self.source = CursorSource::Mouse;
+    }
+
+    /// Move by `step` cells, staying on the grid
+    pub fn step(&mut self, step: IVec2, grid_width: usize, grid_height: usize) {
+        let max = IVec2::new(grid_width as i32 - 1, grid_height as i32 - 1).max(IVec2::ZERO);
+        let cell = (IVec2::new(self.cell.x as i32, self.cell.y as i32) + step).clamp(IVec2::ZERO, max);
+        self.cell = GridPos::new(cell.x as usize, cell.y as usize);
+    }
+}
+
+/// World point placement reads for a cursor cell. Sits a quarter cell below and
+/// left of the centre so both the rounding grid snap and `world_to_grid` land on
+/// `cell`, while staying within click range of a tower built there.
+pub fn cursor_world_position(cell: GridPos, unified_grid: &UnifiedGridSystem) -> Vec2 {
+    grid_to_world(cell, unified_grid) - Vec2::splat(unified_grid.cell_size / 4.0)
+}
+
+/// Tower type `offset` places along `TowerType::ALL` from the current one
+pub fn cycle_tower_type(current: Option<TowerType>, offset: i32) -> TowerType {
+    let count = TowerType::ALL.len() as i32;
+    let index = match current.and_then(|tower_type| TowerType::ALL.iter().position(|t| *t == tower_type)) {
+        Some(index) => index as i32 + offset,
+        // Nothing selected yet: forwards starts at the first type, backwards at the last
+        None if offset > 0 => offset - 1,
+        None => count + offset,
+    };
+    TowerType::ALL[index.rem_euclid(count) as usize]
+}
+
+fn axis_step(keyboard_input: &ButtonInput<KeyCode>) -> IVec2 {
+    let mut step = IVec2::ZERO;
+    if keyboard_input.any_just_pressed(CURSOR_UP_KEYS) {
+        step.y += 1;
+    }
+    if keyboard_input.any_just_pressed(CURSOR_DOWN_KEYS) {
+        step.y -= 1;
+    }
+    if keyboard_input.any_just_pressed(CURSOR_LEFT_KEYS) {
+        step.x -= 1;
+    }
+    if keyboard_input.any_just_pressed(CURSOR_RIGHT_KEYS) {
+        step.x += 1;
+    }
+    step
+}
+
+pub fn keyboard_cursor_command(keyboard_input: &ButtonInput<KeyCode>) -> CursorCommand {
+    let mut cycle = 0;
+    if keyboard_input.just_pressed(PREVIOUS_TOWER_KEY) {
+        cycle -= 1;
+    }
+    if keyboard_input.just_pressed(NEXT_TOWER_KEY) {
+        cycle += 1;
+    }
+    CursorCommand {
+        step: axis_step(keyboard_input),
+        cycle,
+        confirm: keyboard_input.any_just_pressed(CURSOR_CONFIRM_KEYS),
+        cancel: keyboard_input.just_pressed(CURSOR_CANCEL_KEY),
+    }
+}
+
+pub fn gamepad_cursor_command(gamepad: &Gamepad) -> CursorCommand {
+    let axis = |negative, positive| gamepad.just_pressed(positive) as i32 - gamepad.just_pressed(negative) as i32;
+    CursorCommand {
+        step: IVec2::new(
+            axis(GamepadButton::DPadLeft, GamepadButton::DPadRight),
+            axis(GamepadButton::DPadDown, GamepadButton::DPadUp),
+        ),
+        cycle: axis(GamepadButton::LeftTrigger, GamepadButton::RightTrigger)
+            + axis(GamepadButton::LeftTrigger2, GamepadButton::RightTrigger2),
+        confirm: gamepad.just_pressed(GamepadButton::South),
+        cancel: gamepad.just_pressed(GamepadButton::East),
+    }
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// System to drive placement from the keyboard or a gamepad. The cursor writes
+/// its cell into `MouseInputState`, so placement and its preview treat it like
+/// the mouse.
+pub fn virtual_cursor_system(
+    time: Res<Time>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut stick_repeat: Local<StickRepeat>,
+    mut cursor: ResMut<VirtualCursor>,
+    mut mouse_state: ResMut<MouseInputState>,
+    mut selection_state: ResMut<TowerSelectionState>,
+    unified_grid: Res<UnifiedGridSystem>,
+) {
+    let start_cell = world_to_grid(mouse_state.world_position, &unified_grid)
+        .unwrap_or(GridPos::new(unified_grid.grid_width / 2, unified_grid.grid_height / 2));
+
+    if keyboard_input.just_pressed(KEYBOARD_CURSOR_KEY) {
+        if cursor.source == CursorSource::Keyboard {
+            cursor.release();
+        } else {
+            cursor.take_over(CursorSource::Keyboard, start_cell, mouse_state.current_position);
+        }
+    }
+
+    let gamepad_command = gamepads
+        .iter()
+        .map(gamepad_cursor_command)
+        .fold(CursorCommand::default(), CursorCommand::combine);
+    // Either stick steers the cursor too, repeating while held
+    let stick = gamepads.iter().map(|gamepad| gamepad.left_stick() + gamepad.right_stick()).sum::<Vec2>();
+    let gamepad_command = gamepad_command.combine(CursorCommand {
+        step: stick_repeat.update(stick_direction(stick), time.delta_secs()),
+        ..default()
+    });
+    if !gamepad_command.is_empty() && cursor.source != CursorSource::Gamepad {
+        let cell = if cursor.is_active() { cursor.cell } else { start_cell };
+        cursor.take_over(CursorSource::Gamepad, cell, mouse_state.current_position);
+    }
+
+    // Moving or clicking the mouse hands control back to it
+    let mouse_used = mouse_state.current_position != cursor.mouse_anchor
+        || mouse_state.left_clicked
+        || mouse_state.right_clicked;
+    if cursor.is_active() && mouse_used {
+        cursor.release();
+    }
+
+    let command = match cursor.source {
+        CursorSource::Mouse => return,
+        CursorSource::Keyboard => keyboard_cursor_command(&keyboard_input),
+        CursorSource::Gamepad => gamepad_command,
+    };
+
+    if command.step != IVec2::ZERO {
+        cursor.step(command.step, unified_grid.grid_width, unified_grid.grid_height);
+    }
+    if command.cycle != 0 {
+        let tower_type = cycle_tower_type(selection_state.selected_placement_type, command.cycle);
+        selection_state.set_placement_mode(Some(tower_type));
+    }
+    if command.cancel {
+        selection_state.clear_selection();
+    }
+
+    mouse_state.world_position = cursor_world_position(cursor.cell, &unified_grid);
+    if command.confirm {
+        // A tap: pressed and released in the same frame, so it never starts a drag
+        mouse_state.left_clicked = true;
+        mouse_state.left_released = true;
+        mouse_state.drag_start = Some(mouse_state.world_position);
+    }
+}
+
+/// Marker for the frame drawn around the cursor cell
+#[derive(Component)]
+pub struct VirtualCursorHighlight;
+
+/// System to frame the cursor cell while the keyboard or a gamepad is placing
+pub fn virtual_cursor_highlight_system(
+    mut commands: Commands,
+    cursor: Res<VirtualCursor>,
+    unified_grid: Res<UnifiedGridSystem>,
+    highlights: Query<Entity, With<VirtualCursorHighlight>>,
+) {
+    if !cursor.is_changed() {
+        return;
+    }
+
+    for entity in highlights.iter() {
+        commands.entity(entity).despawn();
+    }
+    if cursor.is_active() {
+        commands.spawn((
+            Sprite {
+                color: CURSOR_HIGHLIGHT_COLOR,
+                custom_size: Some(Vec2::splat(unified_grid.cell_size)),
+                ..default()
+            },
+            // Just under the placement preview, which carries the validity colours
+            Transform::from_translation(grid_to_world(cursor.cell, &unified_grid).extend(0.9)),
+            VirtualCursorHighlight,
+        ));
+    }
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin letting towers be placed with a keyboard or gamepad grid cursor
+pub struct VirtualCursorPlugin;
+
+impl Plugin for VirtualCursorPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<VirtualCursor>()
+            .register_key_hint(KEYBOARD_CURSOR_KEY, "Keyboard placement cursor", InputContext::Game)
+            .register_key_hint(KeyCode::KeyW, "Move placement cursor (WASD or arrows)", InputContext::Game)
+            .register_key_hint(PREVIOUS_TOWER_KEY, "Previous tower type (placement cursor)", InputContext::Game)
+            .register_key_hint(NEXT_TOWER_KEY, "Next tower type (placement cursor)", InputContext::Game)
+            .register_key_hint(KeyCode::Enter, "Place tower (placement cursor)", InputContext::Game)
+            .register_key_hint(CURSOR_CANCEL_KEY, "Cancel selection (placement cursor)", InputContext::Game)
+            .add_systems(
+                Update,
+                virtual_cursor_system
+                    .after(mouse_input_system)
+                    .in_set(GameSystemSet::Input)
+                    .run_if(in_state(AppState::Playing)),
+            )
+            .add_systems(Update, virtual_cursor_highlight_system.in_set(GameSystemSet::UI));
+    }
+}
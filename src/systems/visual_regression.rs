@@ -0,0 +1,478 @@
+//! Screenshot-based visual regression runs for the UI.
+//!
+//! Start the game with `TD_VISUAL_CAPTURE=<output dir>` to walk through every
+//! major screen at each capture resolution, save a frame of each and compare it
+//! against the baselines in `tests/visual_baselines`. Add `TD_VISUAL_UPDATE=1`
+//! to store the captured frames as the new baselines instead. The app exits
+//! with an error when any frame drifts past the tolerance, has no baseline to
+//! compare against, or top-level panels overlap.
+
+use bevy::asset::RenderAssetUsages;
+use bevy::image::{CompressedImageFormats, ImageSampler, ImageType};
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{Screenshot, ScreenshotCaptured};
+use bevy::window::PrimaryWindow;
+use std::path::{Path, PathBuf};
+use crate::resources::*;
+use crate::systems::settings_menu::ResolutionOption;
+use crate::systems::tower_rendering::spawn_tower_with_pattern;
+use crate::systems::tower_ui::TowerSelectionState;
+
+/// Environment variable naming the directory captured frames are written to
+pub const VISUAL_CAPTURE_ENV: &str = "TD_VISUAL_CAPTURE";
+/// Environment variable that turns a capture run into a baseline update
+pub const VISUAL_UPDATE_ENV: &str = "TD_VISUAL_UPDATE";
+/// Where the stored baselines live
+pub const VISUAL_BASELINE_DIR: &str = "tests/visual_baselines";
+/// Resolutions every screen is captured at
+pub const CAPTURE_RESOLUTIONS: [ResolutionOption; 3] = [
+    ResolutionOption::Res1280x720,
+    ResolutionOption::Res1920x1080,
+    ResolutionOption::Res1024x768,
+];
+/// Frames a screen gets to lay out and settle before it is captured
+const SETTLE_FRAMES: u32 = 15;
+
+// ============================================================================
+// SCREENS AND FRAMES
+// ============================================================================
+
+/// Screen a capture run visits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VisualScreen {
+    Hud,
+    PlacementPanel,
+    UpgradePanel,
+    Pause,
+    Settings,
+}
+
+impl VisualScreen {
+    pub const ALL: [VisualScreen; 5] = [
+        VisualScreen::Hud,
+        VisualScreen::PlacementPanel,
+        VisualScreen::UpgradePanel,
+        VisualScreen::Pause,
+        VisualScreen::Settings,
+    ];
+
+    pub fn file_stem(&self) -> &'static str {
+        match self {
+            VisualScreen::Hud => "hud",
+            VisualScreen::PlacementPanel => "placement_panel",
+            VisualScreen::UpgradePanel => "upgrade_panel",
+            VisualScreen::Pause => "pause",
+            VisualScreen::Settings => "settings",
+        }
+    }
+
+    /// App state the screen is shown in
+    pub fn app_state(&self) -> AppState {
+        match self {
+            VisualScreen::Pause => AppState::Paused,
+            VisualScreen::Settings => AppState::Settings,
+            _ => AppState::Playing,
+        }
+    }
+}
+
+/// One frame of a capture run
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaptureShot {
+    pub screen: VisualScreen,
+    pub resolution: ResolutionOption,
+}
+
+impl CaptureShot {
+    /// e.g. "upgrade_panel_1280x720.png"
+    pub fn file_name(&self) -> String {
+        format!("{}_{}.png", self.screen.file_stem(), self.resolution.to_string())
+    }
+}
+
+/// Every screen at every capture resolution, one resolution at a time
+pub fn capture_plan() -> Vec<CaptureShot> {
+    CAPTURE_RESOLUTIONS
+        .iter()
+        .flat_map(|resolution| {
+            VisualScreen::ALL.iter().map(|screen| CaptureShot { screen: *screen, resolution: resolution.clone() })
+        })
+        .collect()
+}
+
+/// Decoded RGBA8 frame
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    pub size: UVec2,
+    pub rgba: Vec<u8>,
+}
+
+impl Frame {
+    pub fn new(size: UVec2, rgba: Vec<u8>) -> Self {
+        Self { size, rgba }
+    }
+
+    pub fn from_image(image: &Image) -> Option<Self> {
+        let rgba = image.clone().try_into_dynamic().ok()?.to_rgba8();
+        Some(Self::new(UVec2::new(rgba.width(), rgba.height()), rgba.into_raw()))
+    }
+
+    /// Decode a stored PNG
+    pub fn load_png(path: &Path) -> Result<Self, String> {
+        let bytes = std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let image = Image::from_buffer(
+            &bytes,
+            ImageType::Extension("png"),
+            CompressedImageFormats::NONE,
+            true,
+            ImageSampler::Default,
+            RenderAssetUsages::default(),
+        )
+        .map_err(|e| format!("Failed to decode {}: {}", path.display(), e))?;
+        Self::from_image(&image).ok_or_else(|| format!("Unsupported pixel format in {}", path.display()))
+    }
+}
+
+/// How far a captured frame may drift from its baseline
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiffTolerance {
+    /// Largest per-channel difference a pixel may have and still match
+    pub channel_delta: u8,
+    /// Share of pixels allowed to mismatch, for antialiasing and font hinting noise
+    pub max_mismatched_share: f32,
+}
+
+impl Default for DiffTolerance {
+    fn default() -> Self {
+        Self {
+            channel_delta: 8,
+            max_mismatched_share: 0.005,
+        }
+    }
+}
+
+/// Result of comparing a frame against its baseline
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrameDiff {
+    pub mismatched_pixels: u32,
+    pub total_pixels: u32,
+    /// Largest channel difference found anywhere in the frame
+    pub max_channel_delta: u8,
+}
+
+impl FrameDiff {
+    pub fn mismatched_share(&self) -> f32 {
+        if self.total_pixels == 0 {
+            return 0.0;
+        }
+        self.mismatched_pixels as f32 / self.total_pixels as f32
+    }
+
+    pub fn within(&self, tolerance: &DiffTolerance) -> bool {
+        self.mismatched_share() <= tolerance.max_mismatched_share
+    }
+}
+
+/// Pixel-by-pixel comparison; frames of different sizes can't be compared
+pub fn compare_frames(baseline: &Frame, candidate: &Frame, tolerance: &DiffTolerance) -> Result<FrameDiff, String> {
+    if baseline.size != candidate.size {
+        return Err(format!("Frame is {}x{}, baseline is {}x{}", candidate.size.x, candidate.size.y, baseline.size.x, baseline.size.y));
+    }
+
+    let mut diff = FrameDiff {
+        mismatched_pixels: 0,
+        total_pixels: baseline.size.x * baseline.size.y,
+        max_channel_delta: 0,
+    };
+    for (expected, actual) in baseline.rgba.chunks_exact(4).zip(candidate.rgba.chunks_exact(4)) {
+        let delta = expected.iter().zip(actual).map(|(a, b)| a.abs_diff(*b)).max().unwrap_or(0);
+        diff.max_channel_delta = diff.max_channel_delta.max(delta);
+        if delta > tolerance.channel_delta {
+            diff.mismatched_pixels += 1;
+        }
+    }
+    Ok(diff)
+}
+
+/// Pairs of panels that partly overlap. A panel fully inside another, like a
+/// menu on its backdrop, is intended and not reported.
+pub fn overlapping_panels(panels: &[(String, Rect)]) -> Vec<(String, String)> {
+    let contains = |outer: Rect, inner: Rect| outer.union(inner) == outer;
+    let mut overlaps = Vec::new();
+    for (index, (name_a, a)) in panels.iter().enumerate() {
+        for (name_b, b) in &panels[index + 1..] {
+            let shared = a.intersect(*b);
+            if shared.is_empty() || shared.width() < 1.0 || shared.height() < 1.0 {
+                continue;
+            }
+            if !contains(*a, *b) && !contains(*b, *a) {
+                overlaps.push((name_a.clone(), name_b.clone()));
+            }
+        }
+    }
+    overlaps
+}
+
+// ============================================================================
+// CAPTURE RUN
+// ============================================================================
+
+/// Verdict on one captured frame
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShotOutcome {
+    Matched(FrameDiff),
+    Regressed(FrameDiff),
+    /// Stored as a new baseline on request
+    NewBaseline,
+    Failed(String),
+}
+
+impl ShotOutcome {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, ShotOutcome::Regressed(_) | ShotOutcome::Failed(_))
+    }
+}
+
+/// Resource driving a capture run through the plan
+#[derive(Resource, Debug)]
+pub struct VisualCapture {
+    pub output_dir: PathBuf,
+    pub baseline_dir: PathBuf,
+    pub update_baselines: bool,
+    pub tolerance: DiffTolerance,
+    pub plan: Vec<CaptureShot>,
+    /// Shot currently being set up or captured
+    pub index: usize,
+    pub frames_waited: u32,
+    /// A screenshot has been requested and not delivered yet
+    pub awaiting_capture: bool,
+    pub outcomes: Vec<(CaptureShot, ShotOutcome)>,
+    pub overlaps: Vec<(CaptureShot, String, String)>,
+}
+
+impl VisualCapture {
+    pub fn new(output_dir: PathBuf, update_baselines: bool) -> Self {
+        Self {
+            output_dir,
+            baseline_dir: PathBuf::from(VISUAL_BASELINE_DIR),
+            update_baselines,
+            tolerance: DiffTolerance::default(),
+            plan: capture_plan(),
+            index: 0,
+            frames_waited: 0,
+            awaiting_capture: false,
+            outcomes: Vec::new(),
+            overlaps: Vec::new(),
+        }
+    }
+
+    pub fn current_shot(&self) -> Option<CaptureShot> {
+        self.plan.get(self.index).cloned()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.index >= self.plan.len()
+    }
+
+    pub fn has_failures(&self) -> bool {
+        !self.overlaps.is_empty() || self.outcomes.iter().any(|(_, outcome)| outcome.is_failure())
+    }
+
+    /// Judge a captured frame against its baseline, storing it as the baseline
+    /// when updating. A missing baseline fails the shot rather than passing it.
+    pub fn judge(&self, shot: &CaptureShot, frame: &Frame) -> ShotOutcome {
+        let baseline_path = self.baseline_dir.join(shot.file_name());
+        if self.update_baselines {
+            return ShotOutcome::NewBaseline;
+        }
+        if !baseline_path.exists() {
+            return ShotOutcome::Failed(format!(
+                "No baseline at {}; run with {}=1 to record it",
+                baseline_path.display(),
+                VISUAL_UPDATE_ENV
+            ));
+        }
+        match Frame::load_png(&baseline_path).and_then(|baseline| compare_frames(&baseline, frame, &self.tolerance)) {
+            Ok(diff) if diff.within(&self.tolerance) => ShotOutcome::Matched(diff),
+            Ok(diff) => ShotOutcome::Regressed(diff),
+            Err(e) => ShotOutcome::Failed(e),
+        }
+    }
+
+    /// Plain-text report, one line per shot
+    pub fn report(&self) -> String {
+        let mut lines: Vec<String> = self
+            .outcomes
+            .iter()
+            .map(|(shot, outcome)| {
+                let verdict = match outcome {
+                    ShotOutcome::Matched(diff) => format!("ok ({:.3}% differs)", diff.mismatched_share() * 100.0),
+                    ShotOutcome::Regressed(diff) => format!(
+                        "REGRESSED ({:.3}% differs, max delta {})",
+                        diff.mismatched_share() * 100.0,
+                        diff.max_channel_delta
+                    ),
+                    ShotOutcome::NewBaseline => "new baseline".to_string(),
+                    ShotOutcome::Failed(e) => format!("FAILED: {}", e),
+                };
+                format!("{}: {}", shot.file_name(), verdict)
+            })
+            .collect();
+        lines.extend(
+            self.overlaps
+                .iter()
+                .map(|(shot, a, b)| format!("{}: OVERLAP between {} and {}", shot.file_name(), a, b)),
+        );
+        lines.join("\n")
+    }
+}
+
+/// Marker for entities a capture run spawned to stage a screen
+#[derive(Component)]
+pub struct VisualCaptureProp;
+
+/// Put the game into the state a screen is shown in
+fn stage_screen(
+    commands: &mut Commands,
+    screen: VisualScreen,
+    selection_state: &mut TowerSelectionState,
+    next_state: &mut NextState<AppState>,
+) {
+    selection_state.clear_selection();
+    match screen {
+        VisualScreen::PlacementPanel => selection_state.set_placement_mode(Some(TowerType::Basic)),
+        VisualScreen::UpgradePanel => {
+            let tower = spawn_tower_with_pattern(commands, Vec2::ZERO, TowerType::Basic);
+            commands.entity(tower).insert(VisualCaptureProp);
+            selection_state.set_upgrade_mode(tower);
+        }
+        _ => {}
+    }
+    next_state.set(screen.app_state());
+}
+
+/// Write a captured frame to the output directory, and to the baselines when
+/// it becomes one
+fn store_frame(capture: &VisualCapture, shot: &CaptureShot, image: &Image, outcome: &ShotOutcome) -> Result<(), String> {
+    let dynamic = image.clone().try_into_dynamic().map_err(|e| e.to_string())?;
+    let mut targets = vec![capture.output_dir.join(shot.file_name())];
+    if *outcome == ShotOutcome::NewBaseline {
+        targets.push(capture.baseline_dir.join(shot.file_name()));
+    }
+    for path in targets {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        }
+        dynamic.save(&path).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    }
+    Ok(())
+}
+
+/// Observer receiving the screenshot of the current shot
+fn on_frame_captured(captured: Trigger<ScreenshotCaptured>, mut capture: ResMut<VisualCapture>) {
+    let Some(shot) = capture.current_shot() else {
+        return;
+    };
+    let image = &captured.event().0;
+    let outcome = match Frame::from_image(image) {
+        Some(frame) => capture.judge(&shot, &frame),
+        None => ShotOutcome::Failed("Screenshot has an unsupported pixel format".to_string()),
+    };
+    let outcome = match store_frame(&capture, &shot, image, &outcome) {
+        Ok(()) => outcome,
+        Err(e) => ShotOutcome::Failed(e),
+    };
+
+    capture.outcomes.push((shot, outcome));
+    capture.index += 1;
+    capture.frames_waited = 0;
+    capture.awaiting_capture = false;
+}
+
+/// System to step a capture run: stage each screen at its resolution, let it
+/// settle, check the panel layout and take a screenshot. Exits once every shot
+/// is judged.
+pub fn visual_capture_system(
+    mut commands: Commands,
+    mut capture: ResMut<VisualCapture>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut selection_state: ResMut<TowerSelectionState>,
+    mut next_state: ResMut<NextState<AppState>>,
+    panels: Query<(Entity, &ComputedNode, &GlobalTransform, &InheritedVisibility, Option<&Name>), Without<ChildOf>>,
+    props: Query<Entity, With<VisualCaptureProp>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    if capture.awaiting_capture {
+        return;
+    }
+    let Some(shot) = capture.current_shot() else {
+        let report = capture.report();
+        info!("Visual regression run finished:\n{}", report);
+        let report_path = capture.output_dir.join("report.txt");
+        if let Err(e) = std::fs::write(&report_path, &report) {
+            warn!("Failed to write {}: {}", report_path.display(), e);
+        }
+        exit.write(if capture.has_failures() { AppExit::error() } else { AppExit::Success });
+        return;
+    };
+
+    if capture.frames_waited == 0 {
+        for prop in &props {
+            commands.entity(prop).despawn();
+        }
+        if let Ok(mut window) = windows.single_mut() {
+            let size = shot.resolution.to_vec2();
+            window.resolution.set_scale_factor_override(Some(1.0));
+            window.resolution.set(size.x, size.y);
+        }
+        stage_screen(&mut commands, shot.screen, &mut selection_state, &mut next_state);
+    }
+
+    capture.frames_waited += 1;
+    if capture.frames_waited < SETTLE_FRAMES {
+        return;
+    }
+
+    let visible_panels: Vec<(String, Rect)> = panels
+        .iter()
+        .filter(|(_, node, _, visibility, _)| visibility.get() && !node.is_empty())
+        .map(|(entity, node, transform, _, name)| {
+            let label = name.map_or_else(|| format!("{:?}", entity), |name| name.to_string());
+            (label, Rect::from_center_size(transform.translation().truncate(), node.size()))
+        })
+        .collect();
+    for (a, b) in overlapping_panels(&visible_panels) {
+        capture.overlaps.push((shot.clone(), a, b));
+    }
+
+    capture.awaiting_capture = true;
+    commands.spawn(Screenshot::primary_window()).observe(on_frame_captured);
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin running a visual regression capture instead of a normal session
+pub struct VisualRegressionPlugin {
+    pub output_dir: PathBuf,
+    pub update_baselines: bool,
+}
+
+impl VisualRegressionPlugin {
+    /// Capture run requested through the environment, if any
+    pub fn from_env() -> Option<Self> {
+        let output_dir = std::env::var_os(VISUAL_CAPTURE_ENV)?;
+        Some(Self {
+            output_dir: PathBuf::from(output_dir),
+            update_baselines: std::env::var(VISUAL_UPDATE_ENV).is_ok_and(|value| value == "1"),
+        })
+    }
+}
+
+impl Plugin for VisualRegressionPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(VisualCapture::new(self.output_dir.clone(), self.update_baselines))
+            .add_systems(Update, visual_capture_system.after(GameSystemSet::UI));
+    }
+}
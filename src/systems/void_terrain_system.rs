@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+use crate::components::{Enemy, EnemyType, Projectile};
+use crate::resources::{AppState, CombatSet, EnemyPath, EnemySet, GameSystemSet};
+use crate::systems::obstacle_rendering::ObstacleGrid;
+use crate::systems::path_generation::{find_flight_path, CellType, PathGrid};
+
+/// Tint that sets flying enemies apart from the red swarm
+pub const FLYING_ENEMY_COLOR: Color = EnemyType::Flying.color();
+/// On maps with void, every Nth enemy of a wave flies
+pub const FLYING_EVERY: u32 = 3;
+
+// ============================================================================
+// COMPONENTS & RESOURCES
+// ============================================================================
+
+/// Route a flying enemy takes straight over void cells in place of the shared
+/// `EnemyPath`. Its `PathProgress` is measured along this route.
+#[derive(Component, Debug, Clone)]
+pub struct FlightRoute {
+    pub path: EnemyPath,
+}
+
+/// Resource holding the shortcut flyers take on the current map, or `None`
+/// when the map has no void for them to cross
+#[derive(Resource, Debug, Clone, Default)]
+pub struct FlightPath {
+    pub path: Option<EnemyPath>,
+}
+
+impl FlightPath {
+    /// Whether the enemy with this spawn index should fly on this map
+    pub fn spawns_flying(&self, spawn_index: u32) -> bool {
+        self.path.is_some() && (spawn_index + 2).is_multiple_of(FLYING_EVERY)
+    }
+}
+
+/// Route from the start to the end of `ground_path` that crosses void cells.
+/// `None` if the map has no void or flying over it is no shorter.
+pub fn flight_path(grid: &PathGrid, ground_path: &EnemyPath) -> Option<EnemyPath> {
+    if !grid.cells.iter().flatten().any(|cell| *cell == CellType::Void) {
+        return None;
+    }
+    let start = grid.world_to_grid(*ground_path.waypoints.first()?)?;
+    let goal = grid.world_to_grid(*ground_path.waypoints.last()?)?;
+    let route = find_flight_path(grid, start, goal)?;
+    let path = grid.try_to_enemy_path(&route).ok()?;
+    (path.total_length() < ground_path.total_length()).then_some(path)
+}
+
+// ============================================================================
+// SYSTEMS
+// ============================================================================
+
+/// System to recompute the flyers' shortcut when the map or the shared path changes
+pub fn update_flight_path_system(
+    obstacle_grid: Res<ObstacleGrid>,
+    enemy_path: Res<EnemyPath>,
+    mut flight: ResMut<FlightPath>,
+) {
+    if !obstacle_grid.is_changed() && !enemy_path.is_changed() {
+        return;
+    }
+    flight.path = flight_path(&obstacle_grid.grid, &enemy_path);
+}
+
+/// System to drop projectiles whose target is gone once they drift over void.
+/// Homing projectiles still chasing a live enemy fly on.
+pub fn void_projectile_cleanup_system(
+    mut commands: Commands,
+    obstacle_grid: Res<ObstacleGrid>,
+    projectiles: Query<(Entity, &Transform, &Projectile)>,
+    enemies: Query<(), With<Enemy>>,
+) {
+    for (entity, transform, projectile) in projectiles.iter() {
+        if enemies.contains(projectile.target_entity) {
+            continue;
+        }
+        let over_void = obstacle_grid
+            .grid
+            .world_to_grid(transform.translation.truncate())
+            .is_some_and(|cell| obstacle_grid.grid.is_void(cell));
+        if over_void {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+// ============================================================================
+// PLUGIN
+// ============================================================================
+
+/// Plugin for void cells: flyers' shortcut over them and projectiles lost in them
+pub struct VoidTerrainPlugin;
+
+impl Plugin for VoidTerrainPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FlightPath>()
+            .add_systems(
+                Update,
+                (
+                    update_flight_path_system
+                        .after(EnemySet::PathGeneration)
+                        .before(EnemySet::Spawning),
+                    void_projectile_cleanup_system
+                        .after(CombatSet::ProjectileMovement)
+                        .before(CombatSet::Collision),
+                )
+                    .in_set(GameSystemSet::Gameplay)
+                    .run_if(in_state(AppState::Playing)),
+            );
+    }
+}
@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use crate::resources::{WaveConfig, WAVE_DEFINITIONS_DIR};
+
+/// Plugin loading the hand-authored wave definitions. Waves they define are
+/// played as written; later waves fall back to procedural composition.
+pub struct WaveConfigPlugin;
+
+impl Plugin for WaveConfigPlugin {
+    fn build(&self, app: &mut App) {
+        let wave_config = WaveConfig::load();
+        info!("Loaded {} wave definitions from {}", wave_config.len(), WAVE_DEFINITIONS_DIR);
+        app.insert_resource(wave_config);
+    }
+}
@@ -0,0 +1,152 @@
+use bevy::prelude::*;
+use crate::components::EnemyType;
+use crate::resources::{AppState, GameSystemSet, WaveComposition, WaveTag};
+use crate::systems::spawn_preview::SpawnPreview;
+
+const PANEL_BG: Color = Color::srgba(0.08, 0.12, 0.18, 0.92);
+const PANEL_BORDER: Color = Color::srgb(0.22, 0.28, 0.38);
+const TEXT_PRIMARY: Color = Color::srgb(0.96, 0.96, 0.98);
+const TEXT_MUTED: Color = Color::srgb(0.58, 0.62, 0.68);
+const BOSS_COLOR: Color = Color::srgb(1.0, 0.35, 0.6);
+
+/// One line of the wave preview: how many of an enemy type are coming
+#[derive(Debug, Clone, PartialEq)]
+pub struct WavePreviewRow {
+    pub name: &'static str,
+    pub enemy_type: EnemyType,
+    pub count: u32,
+}
+
+/// Enemies of a wave by name, in the order their groups first appear. Groups
+/// sharing a name, like the swarm stream and a swarm burst, are added together.
+pub fn wave_preview_rows(composition: &WaveComposition) -> Vec<WavePreviewRow> {
+    let mut rows: Vec<WavePreviewRow> = Vec::new();
+    for group in composition.groups.iter().filter(|group| group.count > 0) {
+        let name = group.kind.name_with(group.enemy_type);
+        match rows.iter_mut().find(|row| row.name == name) {
+            Some(row) => row.count += group.count,
+            None => rows.push(WavePreviewRow {
+                name,
+                enemy_type: group.enemy_type,
+                count: group.count,
+            }),
+        }
+    }
+    rows
+}
+
+/// Whether a wave ends with a boss
+pub fn wave_has_boss(composition: &WaveComposition) -> bool {
+    composition.tags().contains(&WaveTag::Boss)
+}
+
+/// Component marker for the wave preview panel under the Start Wave button
+#[derive(Component)]
+pub struct WavePreviewPanel;
+
+/// Setup system for the wave preview panel, hidden until there is a wave to preview
+pub fn setup_wave_preview_panel(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(20.0),
+            top: Val::Px(450.0), // Under the placement panel and its Start Wave button
+            width: Val::Px(250.0),
+            flex_direction: FlexDirection::Column,
+            padding: UiRect::all(Val::Px(12.0)),
+            row_gap: Val::Px(4.0),
+            border: UiRect::all(Val::Px(2.0)),
+            display: Display::None,
+            ..default()
+        },
+        BackgroundColor(PANEL_BG),
+        BorderColor(PANEL_BORDER),
+        BorderRadius::all(Val::Px(8.0)),
+        WavePreviewPanel,
+    ));
+}
+
+/// System to list the next wave's enemies between waves, and hide the panel while a wave runs
+pub fn wave_preview_panel_system(
+    mut commands: Commands,
+    preview: Res<SpawnPreview>,
+    mut panels: Query<(Entity, &mut Node, Option<&Children>), With<WavePreviewPanel>>,
+) {
+    if !preview.is_changed() {
+        return;
+    }
+
+    // The spawn preview only has entries between waves
+    let showing = !preview.entries.is_empty();
+    for (panel, mut node, children) in panels.iter_mut() {
+        node.display = if showing { Display::Flex } else { Display::None };
+        if let Some(children) = children {
+            for &child in &children[..] {
+                commands.entity(child).despawn();
+            }
+        }
+        if !showing {
+            continue;
+        }
+
+        commands.entity(panel).with_children(|parent| {
+            parent.spawn((
+                Text::new(format!("NEXT: WAVE {}", preview.wave)),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(TEXT_PRIMARY),
+            ));
+            if wave_has_boss(&preview.composition) {
+                parent.spawn((
+                    Text::new("BOSS WAVE"),
+                    TextFont {
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(BOSS_COLOR),
+                ));
+            }
+            for row in wave_preview_rows(&preview.composition) {
+                parent.spawn(Node {
+                    column_gap: Val::Px(8.0),
+                    align_items: AlignItems::Center,
+                    ..default()
+                }).with_children(|line| {
+                    line.spawn((
+                        Node {
+                            width: Val::Px(10.0),
+                            height: Val::Px(10.0),
+                            ..default()
+                        },
+                        BackgroundColor(row.enemy_type.color()),
+                        BorderRadius::all(Val::Px(5.0)),
+                    ));
+                    line.spawn((
+                        Text::new(format!("{} x{}", row.name, row.count)),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(TEXT_MUTED),
+                    ));
+                });
+            }
+        });
+    }
+}
+
+/// Plugin for the panel previewing the next wave's enemies next to the Start Wave button
+pub struct WavePreviewPanelPlugin;
+
+impl Plugin for WavePreviewPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, setup_wave_preview_panel).add_systems(
+            Update,
+            wave_preview_panel_system
+                .in_set(GameSystemSet::UI)
+                .run_if(in_state(AppState::Playing)),
+        );
+    }
+}
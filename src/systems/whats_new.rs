@@ -0,0 +1,224 @@
+use bevy::prelude::*;
+use crate::resources::{is_newer_version, AppState, Changelog, ChangelogEntry, GameSystemSet, GAME_VERSION};
+use crate::systems::settings_menu::GameSettings;
+
+const OVERLAY_BG: Color = Color::srgba(0.0, 0.0, 0.0, 0.7);
+const PANEL_BG: Color = Color::srgb(0.08, 0.12, 0.18);
+const PANEL_BORDER: Color = Color::srgb(0.22, 0.28, 0.38);
+const BUTTON_BG: Color = Color::srgb(0.15, 0.20, 0.28);
+const BUTTON_HOVER: Color = Color::srgb(0.20, 0.28, 0.38);
+const TEXT_PRIMARY: Color = Color::srgb(0.96, 0.96, 0.98);
+const TEXT_MUTED: Color = Color::srgb(0.58, 0.62, 0.68);
+const TEXT_VERSION: Color = Color::srgb(0.58, 0.78, 1.0);
+
+/// Request from the main menu to show the full changelog
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShowChangelogEvent;
+
+/// Component marker for the "What's new" overlay
+#[derive(Component)]
+pub struct WhatsNewOverlay;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WhatsNewAction {
+    /// Close the overlay until the next update
+    Dismiss,
+    /// Close it and stop showing release notes after updates
+    DontShowAgain,
+}
+
+/// Component for the overlay's buttons
+#[derive(Component)]
+pub struct WhatsNewButton(pub WhatsNewAction);
+
+/// Release notes to show on reaching the main menu: everything since the version
+/// the player last saw, or nothing if this build isn't newer or the overlay is off
+pub fn whats_new_entries<'a>(changelog: &'a Changelog, settings: &GameSettings, current: &str) -> Vec<&'a ChangelogEntry> {
+    if !settings.whats_new_enabled || !is_newer_version(current, &settings.last_seen_version) {
+        return Vec::new();
+    }
+    changelog.since(&settings.last_seen_version, current)
+}
+
+/// Spawn the overlay listing `entries`, newest first
+fn spawn_whats_new_overlay(commands: &mut Commands, title: &str, entries: &[&ChangelogEntry]) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Vw(100.0),
+            height: Val::Vh(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(OVERLAY_BG),
+        ZIndex(1150), // Above the main menu
+        WhatsNewOverlay,
+    )).with_children(|parent| {
+        parent.spawn((
+            Node {
+                width: Val::Px(520.0),
+                max_height: Val::Vh(80.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(24.0)),
+                row_gap: Val::Px(10.0),
+                border: UiRect::all(Val::Px(3.0)),
+                overflow: Overflow::clip_y(),
+                ..default()
+            },
+            BackgroundColor(PANEL_BG),
+            BorderColor(PANEL_BORDER),
+            BorderRadius::all(Val::Px(15.0)),
+        )).with_children(|panel| {
+            panel.spawn((
+                Text::new(title),
+                TextFont {
+                    font_size: 30.0,
+                    ..default()
+                },
+                TextColor(TEXT_PRIMARY),
+            ));
+
+            for entry in entries {
+                panel.spawn((
+                    Text::new(format!("Version {}", entry.version)),
+                    TextFont {
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(TEXT_VERSION),
+                ));
+                for note in &entry.notes {
+                    panel.spawn((
+                        Text::new(format!("- {}", note)),
+                        TextFont {
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(TEXT_MUTED),
+                    ));
+                }
+            }
+
+            panel.spawn(Node {
+                column_gap: Val::Px(12.0),
+                justify_content: JustifyContent::Center,
+                margin: UiRect::top(Val::Px(10.0)),
+                ..default()
+            }).with_children(|row| {
+                spawn_whats_new_button(row, "DISMISS", WhatsNewAction::Dismiss);
+                spawn_whats_new_button(row, "DON'T SHOW AGAIN", WhatsNewAction::DontShowAgain);
+            });
+        });
+    });
+}
+
+fn spawn_whats_new_button(parent: &mut ChildSpawnerCommands, label: &str, action: WhatsNewAction) {
+    parent.spawn((
+        Button,
+        Node {
+            padding: UiRect::axes(Val::Px(16.0), Val::Px(10.0)),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(BUTTON_BG),
+        BorderColor(PANEL_BORDER),
+        BorderRadius::all(Val::Px(8.0)),
+        WhatsNewButton(action),
+    )).with_children(|button| {
+        button.spawn((
+            Text::new(label),
+            TextFont {
+                font_size: 16.0,
+                ..default()
+            },
+            TextColor(TEXT_PRIMARY),
+        ));
+    });
+}
+
+/// System to show the release notes on reaching the main menu after an update
+pub fn show_whats_new_on_update_system(
+    mut commands: Commands,
+    changelog: Res<Changelog>,
+    settings: Option<Res<GameSettings>>,
+    overlays: Query<(), With<WhatsNewOverlay>>,
+) {
+    let Some(settings) = settings else {
+        return;
+    };
+    let entries = whats_new_entries(&changelog, &settings, GAME_VERSION);
+    if entries.is_empty() || !overlays.is_empty() {
+        return;
+    }
+    spawn_whats_new_overlay(&mut commands, "What's new", &entries);
+    info!("Showing release notes since version '{}'", settings.last_seen_version);
+}
+
+/// System to show the full changelog when asked for from the main menu
+pub fn show_changelog_system(
+    mut commands: Commands,
+    mut events: EventReader<ShowChangelogEvent>,
+    changelog: Res<Changelog>,
+    overlays: Query<(), With<WhatsNewOverlay>>,
+) {
+    if events.read().count() == 0 || !overlays.is_empty() {
+        return;
+    }
+    let entries: Vec<&ChangelogEntry> = changelog.entries.iter().collect();
+    spawn_whats_new_overlay(&mut commands, "Changelog", &entries);
+}
+
+/// System to handle the overlay's buttons. Either one marks this version as
+/// seen; "Don't show again" also turns the overlay off in the settings.
+pub fn whats_new_button_system(
+    mut commands: Commands,
+    mut interaction_query: Query<(&Interaction, &mut BackgroundColor, &WhatsNewButton), Changed<Interaction>>,
+    mut settings: Option<ResMut<GameSettings>>,
+    overlays: Query<Entity, With<WhatsNewOverlay>>,
+) {
+    for (interaction, mut background, button) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                if let Some(settings) = settings.as_mut() {
+                    if settings.last_seen_version != GAME_VERSION {
+                        settings.last_seen_version = GAME_VERSION.to_string();
+                    }
+                    if button.0 == WhatsNewAction::DontShowAgain {
+                        settings.whats_new_enabled = false;
+                    }
+                }
+                for overlay in overlays.iter() {
+                    commands.entity(overlay).despawn();
+                }
+            }
+            Interaction::Hovered => *background = BackgroundColor(BUTTON_HOVER),
+            Interaction::None => *background = BackgroundColor(BUTTON_BG),
+        }
+    }
+}
+
+/// System to remove the overlay on leaving the main menu
+pub fn despawn_whats_new_system(mut commands: Commands, overlays: Query<Entity, With<WhatsNewOverlay>>) {
+    for overlay in overlays.iter() {
+        commands.entity(overlay).despawn();
+    }
+}
+
+/// Plugin for the release notes shown on the main menu after an update
+pub struct WhatsNewPlugin;
+
+impl Plugin for WhatsNewPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Changelog::embedded())
+            .add_event::<ShowChangelogEvent>()
+            .add_systems(OnEnter(AppState::MainMenu), show_whats_new_on_update_system)
+            .add_systems(OnExit(AppState::MainMenu), despawn_whats_new_system)
+            .add_systems(
+                Update,
+                (show_changelog_system, whats_new_button_system)
+                    .in_set(GameSystemSet::UI)
+                    .run_if(in_state(AppState::MainMenu)),
+            );
+    }
+}
@@ -0,0 +1,152 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::input::mouse::AccumulatedMouseScroll;
+use std::sync::Arc;
+use std::time::Duration;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::action_camera::*;
+use tower_defense_bevy::systems::input::{InputHandler, InputMappingRegistry};
+
+/// World with a 2D camera at `position` and the action cam switched on
+fn camera_world(position: Vec2) -> World {
+    let mut world = World::new();
+    world.insert_resource(Time::<()>::default());
+    world.insert_resource(ActionCamera {
+        enabled: true,
+        ..default()
+    });
+    world.init_resource::<CombatActivity>();
+    world.init_resource::<GameState>();
+    world.init_resource::<Events<ToggleActionCameraEvent>>();
+    world.init_resource::<ButtonInput<KeyCode>>();
+    world.init_resource::<ButtonInput<MouseButton>>();
+    world.init_resource::<AccumulatedMouseScroll>();
+    world.spawn((
+        Camera2d,
+        Transform::from_translation(position.extend(0.0)),
+        Projection::Orthographic(OrthographicProjection::default_2d()),
+    ));
+    world
+}
+
+fn camera_view(world: &mut World) -> (Vec2, f32) {
+    let (transform, projection) = world
+        .query_filtered::<(&Transform, &Projection), With<Camera2d>>()
+        .single(world)
+        .unwrap();
+    let scale = match projection {
+        Projection::Orthographic(orthographic) => orthographic.scale,
+        _ => panic!("expected an orthographic camera"),
+    };
+    (transform.translation.truncate(), scale)
+}
+
+#[test]
+fn test_kill_rate_tracks_recent_kills() {
+    let mut activity = CombatActivity::default();
+    let position = Vec2::new(250.0, -90.0);
+    let region = CombatActivity::region_of(position);
+
+    // Two kills a second for a while settles near 2 kills per second
+    for _ in 0..40 {
+        activity.record_kill(position);
+        activity.decay(0.5);
+    }
+    let rate = activity.kills_per_second(region);
+    assert!((rate - 2.0).abs() < 0.4, "rate was {}", rate);
+    assert_eq!(activity.hottest().map(|(hottest, _)| hottest), Some(region));
+
+    // Quiet for long enough and the region drops out entirely
+    activity.decay(ACTIVITY_WINDOW_SECONDS * 10.0);
+    assert!(activity.hottest().is_none());
+}
+
+#[test]
+fn test_target_follows_busiest_region() {
+    let home = (Vec2::new(10.0, 20.0), 1.0);
+    let mut activity = CombatActivity::default();
+    assert_eq!(action_camera_target(&activity, home.0, home.1), home);
+
+    let busy = Vec2::new(-300.0, 100.0);
+    for _ in 0..20 {
+        activity.record_kill(busy);
+    }
+    activity.record_kill(Vec2::new(400.0, 400.0));
+
+    let (position, scale) = action_camera_target(&activity, home.0, home.1);
+    assert_eq!(position, CombatActivity::region_center(CombatActivity::region_of(busy)));
+    assert!(scale < home.1 && scale >= ACTION_CAMERA_MAX_ZOOM, "scale was {}", scale);
+}
+
+#[test]
+fn test_camera_eases_towards_fighting_during_wave() {
+    let mut world = camera_world(Vec2::ZERO);
+    world.spawn(Enemy::default());
+    let busy = Vec2::new(500.0, 0.0);
+    for _ in 0..20 {
+        world.resource_mut::<CombatActivity>().record_kill(busy);
+    }
+
+    world.resource_mut::<Time>().advance_by(Duration::from_millis(100));
+    world.run_system_once(action_camera_system).unwrap();
+
+    let (position, scale) = camera_view(&mut world);
+    assert!(position.x > 0.0 && position.x < busy.x, "camera should move part of the way, was at {}", position);
+    assert!(scale < 1.0);
+}
+
+#[test]
+fn test_manual_input_hands_camera_back() {
+    let mut world = camera_world(Vec2::new(300.0, 200.0));
+    world.resource_mut::<ActionCamera>().home_position = Vec2::new(-20.0, 5.0);
+    if let Projection::Orthographic(orthographic) = world
+        .query_filtered::<&mut Projection, With<Camera2d>>()
+        .single_mut(&mut world)
+        .unwrap()
+        .as_mut()
+    {
+        orthographic.scale = 0.7;
+    }
+
+    world.resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::ArrowLeft);
+    world.run_system_once(action_camera_toggle_system).unwrap();
+
+    assert!(!world.resource::<ActionCamera>().enabled);
+    assert_eq!(camera_view(&mut world), (Vec2::new(-20.0, 5.0), 1.0));
+}
+
+#[test]
+fn test_toggle_remembers_home_view() {
+    let mut world = camera_world(Vec2::new(40.0, -60.0));
+    world.resource_mut::<ActionCamera>().enabled = false;
+
+    world.send_event(ToggleActionCameraEvent);
+    world.run_system_once(action_camera_toggle_system).unwrap();
+
+    let action_camera = world.resource::<ActionCamera>();
+    assert!(action_camera.enabled);
+    assert_eq!(action_camera.home_position, Vec2::new(40.0, -60.0));
+
+    // The action cam lets go as soon as the run ends
+    *world.resource_mut::<GameState>() = GameState::GameOver;
+    world.resource_mut::<Events<ToggleActionCameraEvent>>().clear();
+    world.run_system_once(action_camera_toggle_system).unwrap();
+    assert!(!world.resource::<ActionCamera>().enabled);
+}
+
+#[test]
+fn test_action_camera_key_is_a_rebindable_handler() {
+    let mut world = camera_world(Vec2::ZERO);
+    let mut registry = InputMappingRegistry::new();
+    registry.register_handler(Arc::new(ActionCameraHandler)).unwrap();
+    registry.rebind(ActionCameraHandler.get_id(), ACTION_CAMERA_KEY, KeyCode::KeyV).unwrap();
+
+    // Outside a run the key does nothing
+    world.insert_resource(State::new(AppState::MainMenu));
+    assert!(!registry.process_input(&mut world, KeyCode::KeyV));
+
+    world.insert_resource(State::new(AppState::Playing));
+    assert!(!registry.process_input(&mut world, ACTION_CAMERA_KEY));
+    assert!(registry.process_input(&mut world, KeyCode::KeyV));
+    assert_eq!(world.resource::<Events<ToggleActionCameraEvent>>().len(), 1);
+}
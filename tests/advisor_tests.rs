@@ -0,0 +1,203 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::advisor_system::*;
+use tower_defense_bevy::systems::combat_system::Target;
+use tower_defense_bevy::systems::obstacle_rendering::ObstacleGrid;
+use tower_defense_bevy::systems::occupancy::OccupancyMap;
+use tower_defense_bevy::systems::settings_menu::GameSettings;
+use tower_defense_bevy::systems::tower_ui::TowerSelectionState;
+use tower_defense_bevy::systems::ui_feedback::UiFeedbackEvent;
+use tower_defense_bevy::systems::unified_grid::UnifiedGridSystem;
+
+fn advised(world: &mut World, tower_type: TowerType, upgrade_level: u32, idle_waves: u32) -> AdvisedTower {
+    let mut stats = TowerStats::new(tower_type);
+    while stats.upgrade_level < upgrade_level {
+        stats.upgrade();
+    }
+    AdvisedTower {
+        entity: world.spawn_empty().id(),
+        stats,
+        idle_waves,
+    }
+}
+
+fn advisor_world(money: u32) -> World {
+    let mut world = World::new();
+    world.insert_resource(Economy::new(money, 100, 100, 100));
+    world.insert_resource(WavePlan::default());
+    world.insert_resource(WaveRuntime::new());
+    world.insert_resource(GameSettings::default());
+    world.insert_resource(EnemyPath::new(vec![Vec2::new(-400.0, 0.0), Vec2::new(400.0, 0.0)]));
+    world.init_resource::<UnifiedGridSystem>();
+    world.init_resource::<ObstacleGrid>();
+    world.init_resource::<TowerSelectionState>();
+    world.init_resource::<GameConstants>();
+    world.init_resource::<OccupancyMap>();
+    world.init_resource::<Advisor>();
+    world.init_resource::<Events<ApplySuggestionEvent>>();
+    world.init_resource::<Events<UiFeedbackEvent>>();
+    world
+}
+
+#[test]
+fn test_idle_streak_resets_on_a_shot() {
+    let mut activity = TowerActivity::default();
+    activity.end_wave();
+    activity.end_wave();
+    assert_eq!(activity.idle_waves, 2);
+
+    activity.shots_this_wave = 4;
+    activity.end_wave();
+    assert_eq!(activity.idle_waves, 0);
+    assert_eq!(activity.shots_this_wave, 0);
+}
+
+#[test]
+fn test_shots_are_counted_once() {
+    let mut world = World::new();
+    let tower = world.spawn((Target::default(), TowerActivity::default())).id();
+
+    world.entity_mut(tower).get_mut::<Target>().unwrap().last_shot_time = 1.5;
+    world.run_system_once(track_tower_activity_system).unwrap();
+    world.run_system_once(track_tower_activity_system).unwrap();
+    assert_eq!(world.get::<TowerActivity>(tower).unwrap().shots_this_wave, 1);
+
+    world.entity_mut(tower).get_mut::<Target>().unwrap().last_shot_time = 2.5;
+    world.run_system_once(track_tower_activity_system).unwrap();
+    assert_eq!(world.get::<TowerActivity>(tower).unwrap().shots_this_wave, 2);
+}
+
+#[test]
+fn test_suggests_sale_upgrade_and_build() {
+    let mut world = World::new();
+    let towers = vec![
+        advised(&mut world, TowerType::Basic, 1, 0),
+        advised(&mut world, TowerType::Laser, 3, 0),
+        advised(&mut world, TowerType::Advanced, 1, IDLE_WAVES_BEFORE_SELL),
+        advised(&mut world, TowerType::Basic, 1, IDLE_WAVES_BEFORE_SELL + 2),
+    ];
+    let spot = ZoneSpot {
+        position: Vec2::new(80.0, 40.0),
+        strategic_value: 1.4,
+    };
+    let economy = Economy::new(1000, 100, 100, 100);
+
+    let suggestions = advisor_suggestions(&towers, Some(spot), &economy);
+    assert_eq!(suggestions.len(), MAX_ADVISOR_SUGGESTIONS);
+
+    // The longest-idle tower is the one to sell
+    match &suggestions[0] {
+        AdvisorSuggestion::SellIdle { tower, idle_waves, .. } => {
+            assert_eq!(*tower, towers[3].entity);
+            assert_eq!(*idle_waves, IDLE_WAVES_BEFORE_SELL + 2);
+        }
+        other => panic!("expected a sale, got {:?}", other),
+    }
+
+    // The cheap level 1 tower gives more DPS per dollar than the level 3 one
+    match &suggestions[1] {
+        AdvisorSuggestion::Upgrade { tower, next_level, dps_gain, .. } => {
+            assert_eq!(*tower, towers[0].entity);
+            assert_eq!(*next_level, 2);
+            assert!(*dps_gain > 0.0);
+        }
+        other => panic!("expected an upgrade, got {:?}", other),
+    }
+
+    assert!(matches!(
+        suggestions[2],
+        AdvisorSuggestion::BuildInZone { position, .. } if position == spot.position
+    ));
+    assert!(suggestions.iter().all(|suggestion| !suggestion.label(&NumberFormatter::default()).is_empty()));
+}
+
+#[test]
+fn test_no_spending_advice_without_money() {
+    let mut world = World::new();
+    let towers = vec![advised(&mut world, TowerType::Basic, 1, 0)];
+    let spot = ZoneSpot {
+        position: Vec2::ZERO,
+        strategic_value: 1.0,
+    };
+
+    let suggestions = advisor_suggestions(&towers, Some(spot), &Economy::new(5, 0, 0, 0));
+    assert!(suggestions.is_empty(), "got {:?}", suggestions);
+}
+
+#[test]
+fn test_applying_sale_refunds_tower() {
+    let mut world = advisor_world(0);
+    let tower = world.spawn((TowerStats::new(TowerType::Basic), Transform::default())).id();
+    let refund = TowerStats::new(TowerType::Basic).sell_value();
+
+    world.send_event(ApplySuggestionEvent(AdvisorSuggestion::SellIdle {
+        tower,
+        tower_type: TowerType::Basic,
+        idle_waves: IDLE_WAVES_BEFORE_SELL,
+        refund: refund.clone(),
+    }));
+    world.run_system_once(apply_suggestion_system).unwrap();
+
+    assert!(world.get_entity(tower).is_err());
+    assert_eq!(world.resource::<Economy>().money, refund.money);
+
+    // Applying it again has nothing left to sell
+    world.send_event(ApplySuggestionEvent(AdvisorSuggestion::SellIdle {
+        tower,
+        tower_type: TowerType::Basic,
+        idle_waves: IDLE_WAVES_BEFORE_SELL,
+        refund: refund.clone(),
+    }));
+    world.run_system_once(apply_suggestion_system).unwrap();
+    assert_eq!(world.resource::<Economy>().money, refund.money);
+}
+
+#[test]
+fn test_applying_sale_in_the_grace_window_refunds_in_full() {
+    let mut world = advisor_world(0);
+    let paid = TowerType::Basic.get_cost();
+    let tower = world
+        .spawn((TowerStats::new(TowerType::Basic), PlacementGrace::new(paid.clone()), Transform::default()))
+        .id();
+
+    world.send_event(ApplySuggestionEvent(AdvisorSuggestion::SellIdle {
+        tower,
+        tower_type: TowerType::Basic,
+        idle_waves: IDLE_WAVES_BEFORE_SELL,
+        refund: TowerStats::new(TowerType::Basic).sell_value(),
+    }));
+    world.run_system_once(apply_suggestion_system).unwrap();
+    assert_eq!(world.resource::<Economy>().money, paid.money);
+}
+
+#[test]
+fn test_advisor_tallies_waves_and_respects_setting() {
+    let mut world = advisor_world(1000);
+    let tower = world
+        .spawn((TowerStats::new(TowerType::Basic), TowerActivity::default(), Transform::default()))
+        .id();
+
+    // Wave 1 and 2 finish without the tower firing
+    for _ in 0..2 {
+        world.resource_scope(|world, mut wave_plan: Mut<WavePlan>| start_wave(&mut wave_plan, &mut world.resource_mut::<WaveRuntime>(), 0));
+        world.run_system_once(advisor_system).unwrap();
+    }
+    assert_eq!(world.get::<TowerActivity>(tower).unwrap().idle_waves, 2);
+    let suggestions = world.resource::<Advisor>().suggestions.clone();
+    assert!(matches!(suggestions.first(), Some(AdvisorSuggestion::SellIdle { .. })), "got {:?}", suggestions);
+
+    // Running the system again within the same break doesn't count the wave twice
+    world.run_system_once(advisor_system).unwrap();
+    assert_eq!(world.get::<TowerActivity>(tower).unwrap().idle_waves, 2);
+
+    world.resource_mut::<GameSettings>().advisor_enabled = false;
+    world.run_system_once(advisor_system).unwrap();
+    assert!(world.resource::<Advisor>().suggestions.is_empty());
+
+    // No advice while enemies are on the field
+    world.resource_mut::<GameSettings>().advisor_enabled = true;
+    world.spawn(Enemy::default());
+    world.run_system_once(advisor_system).unwrap();
+    assert!(world.resource::<Advisor>().suggestions.is_empty());
+}
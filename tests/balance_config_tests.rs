@@ -0,0 +1,103 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::balance_system::*;
+use tower_defense_bevy::systems::debug_ui::balance_panel::BalanceStat;
+
+fn balance_world(balance: BalanceConfig) -> World {
+    let mut world = World::new();
+    world.insert_resource(balance);
+    world.init_resource::<WavePlan>();
+    world.init_resource::<WaveRuntime>();
+    world
+}
+
+fn tower_stats(world: &mut World, tower: Entity) -> TowerStats {
+    world.get::<TowerStats>(tower).unwrap().clone()
+}
+
+#[test]
+fn test_defaults_match_built_in_stats() {
+    let balance = BalanceConfig::default();
+    for tower_type in TowerType::ALL {
+        assert_eq!(balance.tower_factors(tower_type), TowerFactors::IDENTITY);
+    }
+    for wave in [1, 5, 20] {
+        assert_eq!(balance.enemies.health_for_wave(wave), Enemy::health_for_wave(wave));
+        assert_eq!(balance.enemies.speed_for_wave(wave), Enemy::for_wave(wave).speed);
+    }
+}
+
+#[test]
+fn test_balance_file_round_trips_as_ron() {
+    // Stats the file leaves out keep their built-in values
+    let loaded = BalanceConfig::from_ron("(enemies: (base_health: 80.0))").unwrap();
+    assert_eq!(loaded.enemies.base_health, 80.0);
+    assert_eq!(loaded.enemies.health_per_wave, EnemyBalance::default().health_per_wave);
+    assert_eq!(loaded.towers, BalanceConfig::default().towers);
+
+    let mut tuned = loaded.clone();
+    tuned.tower_mut(TowerType::Laser).damage = 30.0;
+    assert_eq!(BalanceConfig::from_ron(&tuned.to_ron().unwrap()).unwrap(), tuned);
+}
+
+#[test]
+fn test_tuning_applies_to_existing_towers_and_survives_upgrades() {
+    let mut world = balance_world(BalanceConfig::default());
+    let tower = world.spawn(TowerStats::new(TowerType::Basic)).id();
+    let built_in = TowerStats::new(TowerType::Basic);
+
+    world.run_system_once(apply_tower_balance_system).unwrap();
+    assert_eq!(tower_stats(&mut world, tower).damage, built_in.damage);
+
+    // Live edit doubles damage
+    world.resource_mut::<BalanceConfig>().tower_mut(TowerType::Basic).damage = built_in.damage * 2.0;
+    world.run_system_once(apply_tower_balance_system).unwrap();
+    assert!((tower_stats(&mut world, tower).damage - built_in.damage * 2.0).abs() < 1e-4);
+
+    // Upgrades rebuild stats from the built-in table; tuning is applied again on top
+    world.get_mut::<TowerStats>(tower).unwrap().upgrade();
+    let mut upgraded = TowerStats::new(TowerType::Basic);
+    upgraded.upgrade();
+    world.run_system_once(apply_tower_balance_system).unwrap();
+    assert!((tower_stats(&mut world, tower).damage - upgraded.damage * 2.0).abs() < 1e-4);
+
+    // Resetting the config takes the tuning back out
+    *world.resource_mut::<BalanceConfig>() = BalanceConfig::default();
+    world.run_system_once(apply_tower_balance_system).unwrap();
+    assert!((tower_stats(&mut world, tower).damage - upgraded.damage).abs() < 1e-4);
+}
+
+#[test]
+fn test_enemy_tuning_keeps_health_share() {
+    let mut world = balance_world(BalanceConfig::default());
+    world.resource_mut::<WavePlan>().current_wave = 2;
+    let base_health = Enemy::health_for_wave(2);
+    let mut health = Health::new(base_health);
+    health.current = base_health / 2.0;
+    let enemy = world.spawn((Enemy::for_wave(2), health)).id();
+    world.run_system_once(apply_enemy_balance_system).unwrap();
+
+    world.resource_mut::<BalanceConfig>().enemies.base_health += base_health;
+    world.run_system_once(apply_enemy_balance_system).unwrap();
+
+    let health = world.get::<Health>(enemy).unwrap();
+    assert!((health.max - base_health * 2.0).abs() < 1e-3);
+    assert!((health.current - base_health).abs() < 1e-3, "still at half health");
+    assert_eq!(world.get::<Enemy>(enemy).unwrap().speed, Enemy::for_wave(2).speed);
+}
+
+#[test]
+fn test_panel_steps_respect_minimums() {
+    let mut balance = BalanceConfig::default();
+    let stat = BalanceStat::TowerFireRate(TowerType::Missile);
+    let start = stat.get(&balance);
+
+    stat.adjust(&mut balance, 1.0);
+    assert!((stat.get(&balance) - (start + stat.step())).abs() < 1e-5);
+
+    stat.adjust(&mut balance, -100.0);
+    assert_eq!(stat.get(&balance), stat.minimum());
+
+    assert_eq!(BalanceStat::all().len(), TowerType::ALL.len() * 3 + 4, "every stat is listed");
+}
@@ -0,0 +1,164 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::state::app::StatesPlugin;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::base_system::*;
+use tower_defense_bevy::systems::combat_system::{game_state_system, WaveStatus};
+use tower_defense_bevy::systems::enemy_system::{enemy_cleanup_system, EnemyEscapedEvent};
+use tower_defense_bevy::systems::results_screen::game_over_state_system;
+use tower_defense_bevy::systems::tween::ColorTween;
+
+fn base_world() -> (World, Entity) {
+    let mut world = World::new();
+    world.init_resource::<Score>();
+    world.init_resource::<WaveStatus>();
+    world.init_resource::<WavePlan>();
+    world.init_resource::<WaveRuntime>();
+    world.init_resource::<GameState>();
+    world.init_resource::<Events<EnemyEscapedEvent>>();
+    world.init_resource::<PlayerBase>();
+    world.insert_resource(EnemyPath::new(vec![Vec2::new(-300.0, 0.0), Vec2::new(300.0, 50.0)]));
+    let _ = world.run_system_once(setup_base);
+    let base = world.query_filtered::<Entity, With<Base>>().single(&world).unwrap();
+    (world, base)
+}
+
+fn spawn_escaped_enemy(world: &mut World) -> Entity {
+    world.spawn((Enemy::default(), PathProgress { current: 1.0 })).id()
+}
+
+#[test]
+fn test_base_spawns_at_path_exit_with_full_ring() {
+    let (mut world, base) = base_world();
+    let position = world.get::<Transform>(base).unwrap().translation.truncate();
+    assert_eq!(position, Vec2::new(300.0, 50.0));
+    assert_eq!(world.get::<Health>(base).unwrap().current, BASE_MAX_HEALTH);
+
+    let segments = world.query::<&BaseHealthSegment>().iter(&world).count();
+    assert_eq!(segments, BASE_RING_SEGMENTS);
+}
+
+#[test]
+fn test_lit_segments_round_up() {
+    assert_eq!(Base::lit_segments(1.0), BASE_RING_SEGMENTS);
+    assert_eq!(Base::lit_segments(0.5), BASE_RING_SEGMENTS / 2);
+    assert_eq!(Base::lit_segments(0.01), 1, "a base with health left keeps a segment");
+    assert_eq!(Base::lit_segments(0.0), 0);
+    assert_eq!(Base::lit_segments(-1.0), 0);
+}
+
+#[test]
+fn test_escaped_enemy_damages_base_and_flashes() {
+    let (mut world, base) = base_world();
+    let enemy = spawn_escaped_enemy(&mut world);
+    let still_walking = world.spawn((Enemy::default(), PathProgress { current: 0.9 })).id();
+
+    let _ = world.run_system_once(enemy_cleanup_system);
+
+    assert!(world.get_entity(enemy).is_err());
+    assert!(world.get_entity(still_walking).is_ok());
+    assert_eq!(world.get::<Health>(base).unwrap().current, BASE_MAX_HEALTH - ENEMY_BASE_DAMAGE);
+    assert!(world.get::<ColorTween>(base).is_some(), "hit flash starts");
+    assert_eq!(world.resource::<Score>().enemies_escaped, 1);
+    assert_eq!(world.resource::<WaveStatus>().enemies_escaped, 1);
+
+    let _ = world.run_system_once(base_health_ring_system);
+    let lit = Base::lit_segments(0.9);
+    let colors: Vec<(usize, Color)> = world
+        .query::<(&BaseHealthSegment, &Sprite)>()
+        .iter(&world)
+        .map(|(segment, sprite)| (segment.index, sprite.color))
+        .collect();
+    for (index, color) in colors {
+        assert_eq!(color == base_ring_color(0.9), index < lit, "segment {index}");
+    }
+}
+
+#[test]
+fn test_destroyed_base_ends_the_run() {
+    let (mut world, base) = base_world();
+    let escapes_to_destroy = (BASE_MAX_HEALTH / ENEMY_BASE_DAMAGE) as u32;
+
+    for _ in 0..escapes_to_destroy - 1 {
+        spawn_escaped_enemy(&mut world);
+    }
+    let _ = world.run_system_once(enemy_cleanup_system);
+    let _ = world.run_system_once(base_destroyed_system);
+    assert_eq!(*world.resource::<GameState>(), GameState::Playing);
+
+    spawn_escaped_enemy(&mut world);
+    let _ = world.run_system_once(enemy_cleanup_system);
+    assert!(world.get::<Health>(base).unwrap().is_dead());
+    let _ = world.run_system_once(base_destroyed_system);
+    assert_eq!(*world.resource::<GameState>(), GameState::GameOver);
+}
+
+#[test]
+fn test_escape_count_alone_no_longer_ends_the_run() {
+    let (mut world, _) = base_world();
+    world.resource_mut::<WaveStatus>().enemies_escaped = 50;
+    let _ = world.run_system_once(game_state_system);
+    assert_eq!(*world.resource::<GameState>(), GameState::Playing);
+}
+
+#[test]
+fn test_base_follows_regenerated_path() {
+    let (mut world, base) = base_world();
+    world.insert_resource(EnemyPath::new(vec![Vec2::ZERO, Vec2::new(-120.0, 200.0)]));
+    let _ = world.run_system_once(base_position_system);
+    let position = world.get::<Transform>(base).unwrap().translation.truncate();
+    assert_eq!(position, Vec2::new(-120.0, 200.0));
+}
+
+#[test]
+fn test_player_base_lives_follow_base_health() {
+    let full = PlayerBase::default();
+    assert_eq!(full.lives, (BASE_MAX_HEALTH / ENEMY_BASE_DAMAGE) as u32);
+    assert_eq!(full.max_lives, full.lives);
+
+    let mut health = Health::new(BASE_MAX_HEALTH);
+    health.take_damage(ENEMY_BASE_DAMAGE * 0.5);
+    assert_eq!(PlayerBase::from_health(&health).lives, full.lives, "a partly damaged life still counts");
+    health.take_damage(BASE_MAX_HEALTH);
+    assert!(PlayerBase::from_health(&health).is_destroyed());
+
+    assert_eq!(lives_label(&PlayerBase { lives: 7, max_lives: 10 }), "Lives: 7/10");
+}
+
+#[test]
+fn test_escape_costs_a_life() {
+    let (mut world, base) = base_world();
+    spawn_escaped_enemy(&mut world);
+    spawn_escaped_enemy(&mut world);
+    let _ = world.run_system_once(enemy_cleanup_system);
+    let _ = world.run_system_once(player_base_sync_system);
+    assert_eq!(world.resource::<PlayerBase>().lives, PlayerBase::default().lives - 2);
+
+    // Repairing the base gives the lives back
+    world.get_mut::<Health>(base).unwrap().current = BASE_MAX_HEALTH;
+    let _ = world.run_system_once(player_base_sync_system);
+    assert_eq!(*world.resource::<PlayerBase>(), PlayerBase::default());
+}
+
+#[test]
+fn test_run_end_switches_to_game_over_screen_and_back() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin))
+        .init_state::<AppState>()
+        .init_resource::<GameState>()
+        .add_systems(Update, game_over_state_system);
+    app.update();
+    let app_state = |app: &App| *app.world().resource::<State<AppState>>().get();
+    assert_eq!(app_state(&app), AppState::Playing);
+
+    *app.world_mut().resource_mut::<GameState>() = GameState::GameOver;
+    app.update();
+    app.update();
+    assert_eq!(app_state(&app), AppState::GameOver);
+
+    // Restarting puts the game back in play
+    *app.world_mut().resource_mut::<GameState>() = GameState::Playing;
+    app.update();
+    app.update();
+    assert_eq!(app_state(&app), AppState::Playing);
+}
@@ -0,0 +1,148 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::boss_arena_system::*;
+use tower_defense_bevy::systems::obstacle_rendering::ObstacleGrid;
+use tower_defense_bevy::systems::path_generation::*;
+use tower_defense_bevy::systems::unified_grid::UnifiedGridSystem;
+
+/// Every step moves to a neighbouring traversable cell and no cell comes up twice
+fn assert_walkable(grid: &PathGrid, route: &[GridPos]) {
+    for step in route.windows(2) {
+        assert_eq!(step[0].manhattan_distance(&step[1]), 1.0, "{:?} to {:?}", step[0], step[1]);
+    }
+    for (index, pos) in route.iter().enumerate() {
+        assert!(grid.is_traversable(*pos), "{:?} is not traversable", pos);
+        assert!(!route[index + 1..].contains(pos), "{:?} is walked twice", pos);
+    }
+}
+
+fn straight_route(grid: &PathGrid, row: usize) -> Vec<GridPos> {
+    (0..grid.width).map(|x| GridPos::new(x, row)).collect()
+}
+
+#[test]
+fn test_arena_route_is_a_longer_loop_between_the_same_ends() {
+    let mut grid = PathGrid::new(12, 8);
+    let route = straight_route(&grid, 4);
+    grid.apply_path(&route);
+
+    let arena = arena_route(&grid, &route, |_| false).expect("an open map has room for a detour");
+    assert_eq!(arena.first(), route.first());
+    assert_eq!(arena.last(), route.last());
+    assert!(arena.len() > route.len());
+    assert!(arena.len() < route.len() * 2);
+    assert_walkable(&grid, &arena);
+}
+
+#[test]
+fn test_arena_route_avoids_blocked_cells() {
+    let mut grid = PathGrid::new(12, 8);
+    let route = straight_route(&grid, 4);
+    grid.apply_path(&route);
+
+    // Towers fill every row above the route
+    let blocked = |pos: GridPos| pos.y < 4;
+    let arena = arena_route(&grid, &route, blocked).expect("the rows below are still open");
+    assert!(arena.iter().all(|pos| !blocked(*pos)));
+    assert_walkable(&grid, &arena);
+}
+
+#[test]
+fn test_no_arena_route_in_a_single_corridor() {
+    let mut grid = PathGrid::new(12, 1);
+    let route = straight_route(&grid, 0);
+    grid.apply_path(&route);
+    assert_eq!(arena_route(&grid, &route, |_| false), None);
+}
+
+#[test]
+fn test_boss_waves() {
+    let settings = BossArenaSettings::default();
+    assert!(!settings.is_boss_wave(0));
+    assert!(!settings.is_boss_wave(4));
+    assert!(settings.is_boss_wave(5));
+    assert!(settings.uses_arena(10));
+
+    let disabled = BossArenaSettings { enabled: false, ..default() };
+    assert!(disabled.is_boss_wave(5));
+    assert!(!disabled.uses_arena(5));
+}
+
+fn arena_world() -> (World, EnemyPath) {
+    let mut world = World::new();
+    let obstacle_grid = ObstacleGrid::default();
+    let grid = &obstacle_grid.grid;
+    let normal = grid.to_enemy_path(straight_route(grid, grid.height / 2));
+
+    world.insert_resource(EnemyPath::new(normal.waypoints.clone()));
+    world.insert_resource(obstacle_grid);
+    world.insert_resource(WavePlan::default());
+    world.insert_resource(WaveRuntime::new());
+    world.init_resource::<UnifiedGridSystem>();
+    world.init_resource::<GameConstants>();
+    world.init_resource::<BossArenaSettings>();
+    world.init_resource::<PathVariants>();
+    (world, normal)
+}
+
+#[test]
+fn test_arena_route_is_worked_out_alongside_the_normal_path() {
+    let (mut world, normal) = arena_world();
+    world.run_system_once(track_normal_path_system).unwrap();
+    world.run_system_once(update_arena_path_system).unwrap();
+
+    let variants = world.resource::<PathVariants>();
+    assert_eq!(variants.normal.as_ref(), Some(&normal));
+    assert_eq!(variants.active, PathVariant::Normal);
+    let arena = variants.arena.as_ref().expect("an empty map has room for an arena route");
+    assert!(arena.total_length() > normal.total_length());
+    assert_eq!(arena.waypoints.first(), normal.waypoints.first());
+    assert_eq!(arena.waypoints.last(), normal.waypoints.last());
+}
+
+#[test]
+fn test_boss_wave_switches_to_the_arena_and_back() {
+    let (mut world, normal) = arena_world();
+    world.run_system_once(track_normal_path_system).unwrap();
+    world.run_system_once(update_arena_path_system).unwrap();
+    let arena = world.resource::<PathVariants>().arena.clone().unwrap();
+
+    // An enemy left over from the previous wave is halfway along the normal path
+    let straggler = world.spawn((Enemy::default(), PathProgress { current: 0.5 })).id();
+    world.resource_scope(|world, mut wave_plan: Mut<WavePlan>| {
+        wave_plan.current_wave = 4;
+        start_wave(&mut wave_plan, &mut world.resource_mut::<WaveRuntime>(), 10);
+    });
+    world.run_system_once(boss_arena_switch_system).unwrap();
+
+    assert_eq!(*world.resource::<EnemyPath>(), arena);
+    assert_eq!(world.resource::<PathVariants>().active, PathVariant::Arena);
+    let pinned = world.get::<PinnedRoute>(straggler).expect("the straggler keeps its route");
+    assert_eq!(pinned.path, normal);
+    assert_eq!(world.get::<PathProgress>(straggler).unwrap().current, 0.5);
+
+    // Switching doesn't undo itself while the wave is running
+    world.run_system_once(track_normal_path_system).unwrap();
+    world.run_system_once(boss_arena_switch_system).unwrap();
+    assert_eq!(*world.resource::<EnemyPath>(), arena);
+
+    // Everything spawned and gone: the normal route comes back
+    world.despawn(straggler);
+    world.resource_mut::<WaveRuntime>().enemies_spawned = world.resource::<WavePlan>().enemies_in_wave();
+    world.run_system_once(boss_arena_switch_system).unwrap();
+    assert_eq!(*world.resource::<EnemyPath>(), normal);
+    assert_eq!(world.resource::<PathVariants>().active, PathVariant::Normal);
+}
+
+#[test]
+fn test_ordinary_waves_keep_the_normal_path() {
+    let (mut world, normal) = arena_world();
+    world.run_system_once(track_normal_path_system).unwrap();
+    world.run_system_once(update_arena_path_system).unwrap();
+
+    world.resource_scope(|world, mut wave_plan: Mut<WavePlan>| start_wave(&mut wave_plan, &mut world.resource_mut::<WaveRuntime>(), 10));
+    world.run_system_once(boss_arena_switch_system).unwrap();
+    assert_eq!(*world.resource::<EnemyPath>(), normal);
+    assert_eq!(world.resource::<PathVariants>().active, PathVariant::Normal);
+}
@@ -0,0 +1,99 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use tower_defense_bevy::components::*;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::bounty_system::*;
+use tower_defense_bevy::systems::combat_system::{
+    collision_system, kill_reward, kill_reward_range, EnemyDamagedEvent, EnemyKilledEvent, WaveStatus,
+};
+use tower_defense_bevy::systems::enemy_system::{compose_wave, enemy_cleanup_system, EnemyEscapedEvent};
+use tower_defense_bevy::systems::spawn_preview::{entry_label, EntryPreview};
+
+#[test]
+fn test_wave_bounty_comes_from_the_composition() {
+    let composition = compose_wave(4, None, None);
+    let (min_reward, max_reward) = kill_reward_range();
+    // Wave 4 brings the first tank, which pays triple
+    assert_eq!(composition.count_of_type(EnemyType::Tank), 1);
+    assert_eq!(composition.bounty(min_reward), (composition.total_enemies() + 2) * min_reward);
+    assert!(composition.bounty(max_reward) > composition.bounty(min_reward));
+
+    let entry = EntryPreview { position: Vec2::ZERO, direction: Vec2::X, enemies: 14, share: 1.0 };
+    let formatter = NumberFormatter::default();
+    let label = entry_label(4, &entry, Some((70, 210)), &["Swarm"], &formatter);
+    assert_eq!(label, "Wave 4: 14 (100%)\nBounty $70-$210\nSwarm");
+    assert_eq!(entry_label(4, &entry, None, &[], &formatter), "Wave 4: 14 (100%)");
+}
+
+#[test]
+fn test_leaks_show_up_as_missed_bounty() {
+    let mut ledger = BountyLedger::default();
+    ledger.start_wave(3, 5);
+    ledger.record_kill(10);
+    ledger.record_kill(14);
+    ledger.record_leak();
+
+    let wave = ledger.wave(3).unwrap();
+    assert_eq!(wave.earned, 24);
+    assert_eq!(wave.missed(), 12, "valued at the wave's average kill");
+    assert_eq!(wave.expected(), 36);
+    let formatter = NumberFormatter::default();
+    assert_eq!(wave.summary(&formatter), "Wave 3 bounty: $24 of $36 ($12 missed to 1 leak)");
+    let german = NumberFormatter::new(NumberLocale::German, false);
+    assert_eq!(wave.summary(&german), "Wave 3 bounty: 24 $ of 36 $ (12 $ missed to 1 leak)");
+
+    // Without a kill to average over, leaks are valued at the lowest bounty
+    ledger.start_wave(4, 5);
+    ledger.record_leak();
+    ledger.record_leak();
+    assert_eq!(ledger.latest().unwrap().summary(&formatter), "Wave 4 bounty: $0 of $10 ($10 missed to 2 leaks)");
+}
+
+#[test]
+fn test_restarting_a_wave_replaces_its_entry() {
+    let mut ledger = BountyLedger::default();
+    for wave in 1..=5 {
+        ledger.start_wave(wave, 5);
+        ledger.record_kill(8);
+    }
+    ledger.start_wave(4, 5);
+    let waves: Vec<u32> = ledger.waves.iter().map(|entry| entry.wave).collect();
+    assert_eq!(waves, vec![1, 2, 3, 4]);
+    assert_eq!(ledger.latest().unwrap().earned, 0);
+}
+
+#[test]
+fn test_kills_and_leaks_are_booked_to_the_running_wave() {
+    let mut world = World::new();
+    world.init_resource::<Economy>();
+    world.init_resource::<Score>();
+    world.init_resource::<WaveStatus>();
+    world.init_resource::<Events<EnemyKilledEvent>>();
+    world.init_resource::<Events<EnemyDamagedEvent>>();
+    world.init_resource::<Events<EnemyEscapedEvent>>();
+    let mut wave_plan = WavePlan::default();
+    let mut wave_runtime = WaveRuntime::new();
+    start_wave(&mut wave_plan, &mut wave_runtime, 2);
+    world.insert_resource(wave_plan);
+    world.insert_resource(wave_runtime);
+    world.init_resource::<BountyLedger>();
+    world.run_system_once(record_wave_bounty_system).unwrap();
+
+    let enemy = world.spawn((Enemy::default(), Health::new(10.0), Transform::default())).id();
+    world.spawn((Projectile::new(50.0, 300.0, enemy, Vec2::ZERO, TowerType::Tesla), Transform::default()));
+    world.run_system_once(collision_system).unwrap();
+
+    let mut progress = PathProgress::new();
+    progress.advance(1.0);
+    world.spawn((Enemy::default(), progress, Transform::default()));
+    world.run_system_once(enemy_cleanup_system).unwrap();
+
+    let wave = world.resource::<BountyLedger>().wave(1).cloned().unwrap();
+    assert_eq!((wave.kills, wave.leaks, wave.earned), (1, 1, kill_reward(TowerType::Tesla)));
+
+    world.resource_mut::<WaveRuntime>().enemies_spawned = 2;
+    let (ledger, wave_plan, wave_runtime) = (world.resource::<BountyLedger>(), world.resource::<WavePlan>(), world.resource::<WaveRuntime>());
+    let formatter = NumberFormatter::default();
+    assert_eq!(wave_summary_text(ledger, wave_plan, wave_runtime, 0, &formatter), Some(wave.summary(&formatter)));
+    assert_eq!(wave_summary_text(ledger, wave_plan, wave_runtime, 1, &formatter), None);
+}
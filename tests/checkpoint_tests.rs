@@ -0,0 +1,131 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::checkpoint_system::{record_checkpoint_system, restore_checkpoint_system};
+use tower_defense_bevy::systems::combat_system::WaveStatus;
+use tower_defense_bevy::systems::results_screen::RestoreCheckpointEvent;
+use tower_defense_bevy::systems::tower_ui::TowerSelectionState;
+
+/// World with the resources the checkpoint systems touch
+fn checkpoint_world(max_retries: u32) -> World {
+    let mut world = World::new();
+    world.init_resource::<WavePlan>();
+    world.init_resource::<WaveRuntime>();
+    world.init_resource::<WaveStatus>();
+    world.init_resource::<Economy>();
+    world.init_resource::<Score>();
+    world.init_resource::<GameState>();
+    world.init_resource::<ActiveBuffs>();
+    world.init_resource::<TowerSelectionState>();
+    world.init_resource::<Events<RestoreCheckpointEvent>>();
+    world.insert_resource(CheckpointState::new(max_retries));
+    world
+}
+
+#[test]
+fn test_checkpoint_waves() {
+    assert!(!is_checkpoint_wave(0));
+    assert!(!is_checkpoint_wave(1));
+    assert!(is_checkpoint_wave(CHECKPOINT_INTERVAL));
+    assert!(!is_checkpoint_wave(CHECKPOINT_INTERVAL + 1));
+    assert!(is_checkpoint_wave(CHECKPOINT_INTERVAL * 2));
+}
+
+#[test]
+fn test_difficulty_retries_and_cycle() {
+    assert!(Difficulty::Easy.checkpoint_retries() > Difficulty::Normal.checkpoint_retries());
+    assert_eq!(Difficulty::Hard.checkpoint_retries(), 0);
+    assert_eq!(Difficulty::default(), Difficulty::Normal);
+
+    let mut difficulty = Difficulty::Easy;
+    for _ in 0..Difficulty::ALL.len() {
+        difficulty = difficulty.next();
+    }
+    assert_eq!(difficulty, Difficulty::Easy);
+}
+
+#[test]
+fn test_retries_are_limited() {
+    let mut state = CheckpointState::new(1);
+    assert!(state.consume_retry().is_none(), "nothing recorded yet");
+
+    state.record(WaveCheckpoint {
+        wave: 5,
+        economy: Economy::default(),
+        score: Score::default(),
+        towers: Vec::new(),
+        perks: RunPerks::default(),
+    });
+    assert_eq!(state.consume_retry().map(|checkpoint| checkpoint.wave), Some(5));
+    assert_eq!(state.retries_remaining(), 0);
+    assert!(state.available().is_none());
+    assert!(state.consume_retry().is_none());
+}
+
+#[test]
+fn test_snapshot_restores_upgrade_level() {
+    let mut stats = TowerStats::new(TowerType::Basic);
+    stats.upgrade();
+    stats.upgrade();
+
+    let snapshot = TowerSnapshot::capture(&stats, Vec2::new(10.0, 20.0));
+    let restored = snapshot.restored_stats();
+    assert_eq!(restored.upgrade_level, stats.upgrade_level);
+    assert_eq!(restored.damage, stats.damage);
+}
+
+#[test]
+fn test_checkpoint_recorded_when_wave_starts() {
+    let mut world = checkpoint_world(1);
+    world.spawn((TowerStats::new(TowerType::Basic), Transform::from_xyz(50.0, 60.0, 0.0)));
+
+    world.resource_mut::<WavePlan>().current_wave = 4;
+    world.run_system_once(record_checkpoint_system).unwrap();
+    assert!(world.resource::<CheckpointState>().latest.is_none());
+
+    world.resource_mut::<WavePlan>().current_wave = 5;
+    world.resource_mut::<Economy>().money = 321;
+    world.run_system_once(record_checkpoint_system).unwrap();
+
+    let state = world.resource::<CheckpointState>();
+    let checkpoint = state.latest.as_ref().expect("checkpoint at wave 5");
+    assert_eq!(checkpoint.wave, 5);
+    assert_eq!(checkpoint.economy.money, 321);
+    assert_eq!(checkpoint.towers.len(), 1);
+    assert_eq!(checkpoint.towers[0].position, Vec2::new(50.0, 60.0));
+}
+
+#[test]
+fn test_restore_rebuilds_run_before_checkpoint_wave() {
+    let mut world = checkpoint_world(1);
+    let mut upgraded = TowerStats::new(TowerType::Basic);
+    upgraded.upgrade();
+    world.resource_mut::<CheckpointState>().record(WaveCheckpoint {
+        wave: 5,
+        economy: Economy { money: 400, ..default() },
+        score: Score::default(),
+        towers: vec![TowerSnapshot::capture(&upgraded, Vec2::new(-30.0, 40.0))],
+        perks: RunPerks::default(),
+    });
+
+    // Defeated later on with a different board
+    world.resource_mut::<WavePlan>().current_wave = 7;
+    world.resource_mut::<Economy>().money = 5;
+    *world.resource_mut::<GameState>() = GameState::GameOver;
+    world.spawn((TowerStats::new(TowerType::Laser), Transform::default()));
+
+    world.send_event(RestoreCheckpointEvent);
+    world.run_system_once(restore_checkpoint_system).unwrap();
+
+    assert_eq!(world.resource::<WavePlan>().current_wave, 4);
+    assert_eq!(world.resource::<Economy>().money, 400);
+    assert_eq!(*world.resource::<GameState>(), GameState::Playing);
+    assert_eq!(world.resource::<CheckpointState>().retries_remaining(), 0);
+
+    let towers = world
+        .query::<(&TowerStats, &Transform)>()
+        .iter(&world)
+        .map(|(stats, transform)| (stats.tower_type, stats.upgrade_level, transform.translation.truncate()))
+        .collect::<Vec<_>>();
+    assert_eq!(towers, vec![(TowerType::Basic, upgraded.upgrade_level, Vec2::new(-30.0, 40.0))]);
+}
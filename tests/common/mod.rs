@@ -0,0 +1,297 @@
+//! Headless UI test driver: runs the game's input, UI and gameplay systems
+//! and feeds them synthetic mouse and keyboard events, so whole player
+//! flows (place, upgrade, pause) can be asserted on world state.
+#![allow(dead_code)] // Each test binary only uses part of the driver
+
+use bevy::gizmos::GizmoPlugin;
+use bevy::input::keyboard::{Key, KeyboardInput, NativeKey, NativeKeyCode};
+use bevy::input::mouse::MouseButtonInput;
+use bevy::input::{ButtonState, InputPlugin};
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+use bevy::window::PrimaryWindow;
+use std::time::Duration;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::combat_system::*;
+use tower_defense_bevy::systems::construction_system::ConstructionPlugin;
+use tower_defense_bevy::systems::enemy_system::*;
+use tower_defense_bevy::systems::input_system::*;
+use tower_defense_bevy::systems::main_menu::MainMenuPlugin;
+use tower_defense_bevy::systems::map_editor::MapEditorPlugin;
+use tower_defense_bevy::systems::map_select::MapSelectPlugin;
+use tower_defense_bevy::systems::obstacle_rendering::ObstacleGrid;
+use tower_defense_bevy::systems::occupancy::OccupancyPlugin;
+use tower_defense_bevy::systems::path_generation::FixedLevelPath;
+use tower_defense_bevy::systems::pause_system::PauseSystemPlugin;
+use tower_defense_bevy::systems::save_load::SaveLoadRequest;
+use tower_defense_bevy::systems::seed_entry::SeedEntryPlugin;
+use tower_defense_bevy::systems::simulation_clock_system::advance_simulation_clock_system;
+use tower_defense_bevy::systems::suspend_system::{PendingSuspendedRun, SuspendedRunChoice};
+use tower_defense_bevy::systems::system_order::SystemOrderPlugin;
+use tower_defense_bevy::systems::tower_rendering::TowerRenderingPlugin;
+use tower_defense_bevy::systems::tower_ui::*;
+use tower_defense_bevy::systems::ui_feedback::UiFeedbackEvent;
+use tower_defense_bevy::systems::unified_grid::{setup_unified_grid, UnifiedGridSystem};
+
+/// Simulated frame length
+pub const FRAME: Duration = Duration::from_millis(50);
+
+/// One recorded player action
+#[derive(Debug, Clone, Copy)]
+pub enum RecordedInput {
+    /// Move the cursor over a world position, leaving any hovered button
+    CursorTo(Vec2),
+    MousePress(MouseButton),
+    MouseRelease(MouseButton),
+    KeyPress(KeyCode),
+    KeyRelease(KeyCode),
+    /// Let frames pass without input
+    Wait(u32),
+}
+
+/// Full game app without a renderer or window backend.
+///
+/// UI layout is not computed headlessly, so pressing a button sets its
+/// `Interaction` the way bevy_ui's focus system would for a cursor over it.
+pub struct UiTestApp {
+    pub app: App,
+}
+
+impl UiTestApp {
+    pub fn new() -> Self {
+        let mut app = Self::game_app();
+        app.update();
+        Self { app }
+    }
+
+    /// Game app opening on the main menu, as the full game does
+    pub fn at_main_menu() -> Self {
+        let mut app = Self::game_app();
+        app.add_event::<SuspendedRunChoice>()
+            .add_event::<SaveLoadRequest>()
+            .init_resource::<PendingSuspendedRun>()
+            // Picking a map keeps the test path rather than generating one
+            .insert_resource(FixedLevelPath)
+            .add_plugins((MainMenuPlugin, MapSelectPlugin, SeedEntryPlugin, MapEditorPlugin));
+        app.update();
+        Self { app }
+    }
+
+    fn game_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin, InputPlugin, WindowPlugin::default(), AssetPlugin::default()))
+            .init_asset::<Shader>() // GizmoPlugin loads its line shaders into this
+            .add_plugins(GizmoPlugin)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+            .add_plugins((TowerRenderingPlugin, ConstructionPlugin, OccupancyPlugin, PauseSystemPlugin, SystemOrderPlugin))
+            .add_event::<StartWaveEvent>()
+            .add_event::<EnemyEscapedEvent>()
+            .add_event::<EnemyKilledEvent>()
+            .add_event::<TowerFiredEvent>()
+            .add_event::<EnemyDamagedEvent>()
+            .add_event::<UiFeedbackEvent>()
+            .init_state::<AppState>()
+            .insert_resource(GameConstants::default())
+            .insert_resource(GameRng::from_seed(1))
+            .init_resource::<Score>()
+            .init_resource::<WavePlan>()
+            .init_resource::<WaveRuntime>()
+            .init_resource::<GameState>()
+            .init_resource::<Economy>()
+            .init_resource::<NumberFormatter>()
+            .init_resource::<SimulationClock>()
+            .init_resource::<MouseInputState>()
+            .init_resource::<WaveStatus>()
+            .init_resource::<TowerSelectionState>()
+            .init_resource::<TowerStatPopupState>()
+            .init_resource::<UnifiedGridSystem>()
+            .init_resource::<ObstacleGrid>()
+            .insert_resource(EnemyPath::new(vec![Vec2::new(-600.0, 0.0), Vec2::new(600.0, 0.0)]))
+            .configure_sets(Update, (GameSystemSet::Input, GameSystemSet::UI, GameSystemSet::Gameplay).chain())
+            .add_systems(Startup, (setup_camera, setup_unified_grid, setup_tower_placement_panel, setup_tower_upgrade_panel, setup_tower_stat_popup).chain())
+            .add_systems(Update, (mouse_input_system, advance_simulation_clock_system).in_set(GameSystemSet::Input))
+            .add_systems(
+                Update,
+                (
+                    tower_type_button_system,
+                    upgrade_button_system,
+                    tower_selection_system,
+                    popup_close_button_system,
+                    popup_outside_click_system,
+                    start_wave_button_system,
+                    update_upgrade_panel_system,
+                    update_start_wave_button_system,
+                )
+                    .chain()
+                    .in_set(GameSystemSet::UI),
+            )
+            .add_systems(
+                Update,
+                (
+                    tower_placement_system,
+                    manual_wave_system.in_set(EnemySet::WaveControl),
+                    enemy_spawning_system.in_set(EnemySet::Spawning),
+                    enemy_movement_system.in_set(EnemySet::Movement),
+                    enemy_cleanup_system.in_set(EnemySet::Cleanup),
+                    tower_targeting_system.in_set(CombatSet::Targeting),
+                    projectile_spawning_system.in_set(CombatSet::Firing),
+                    projectile_movement_system.in_set(CombatSet::ProjectileMovement),
+                    collision_system.in_set(CombatSet::Collision),
+                    game_state_system.after(CombatSet::Collision),
+                )
+                    .in_set(GameSystemSet::Gameplay)
+                    .run_if(in_state(AppState::Playing)),
+            );
+        app
+    }
+
+    fn window(&mut self) -> Entity {
+        let world = self.app.world_mut();
+        world
+            .query_filtered::<Entity, With<PrimaryWindow>>()
+            .single(world)
+            .expect("headless app has a primary window")
+    }
+
+    /// Screen position that the camera maps onto this world position
+    pub fn world_to_screen(&mut self, world_pos: Vec2) -> Vec2 {
+        let window = self.window();
+        let window = self.app.world().get::<Window>(window).unwrap();
+        Vec2::new(world_pos.x + window.width() / 2.0, window.height() / 2.0 - world_pos.y)
+    }
+
+    /// Apply one recorded input. Mouse and key events are read on the next frame.
+    pub fn apply(&mut self, input: RecordedInput) {
+        let window = self.window();
+        match input {
+            RecordedInput::CursorTo(world_pos) => {
+                let screen_pos = self.world_to_screen(world_pos);
+                let world = self.app.world_mut();
+                world.get_mut::<Window>(window).unwrap().set_cursor_position(Some(screen_pos));
+                set_interactions(world, |_| Interaction::None);
+            }
+            RecordedInput::MousePress(button) => {
+                self.app.world_mut().send_event(MouseButtonInput { button, state: ButtonState::Pressed, window });
+            }
+            RecordedInput::MouseRelease(button) => {
+                let world = self.app.world_mut();
+                world.send_event(MouseButtonInput { button, state: ButtonState::Released, window });
+                set_interactions(world, |interaction| match interaction {
+                    Interaction::Pressed => Interaction::Hovered,
+                    other => other,
+                });
+            }
+            RecordedInput::KeyPress(key_code) | RecordedInput::KeyRelease(key_code) => {
+                let state = match input {
+                    RecordedInput::KeyPress(_) => ButtonState::Pressed,
+                    _ => ButtonState::Released,
+                };
+                self.app.world_mut().send_event(KeyboardInput {
+                    key_code,
+                    logical_key: Key::Unidentified(NativeKey::Unidentified),
+                    state,
+                    text: None,
+                    repeat: false,
+                    window,
+                });
+            }
+            RecordedInput::Wait(frames) => self.frames(frames),
+        }
+    }
+
+    /// Replay a recording, running one frame after every input
+    pub fn play(&mut self, recording: &[RecordedInput]) {
+        for input in recording {
+            self.apply(*input);
+            if !matches!(input, RecordedInput::Wait(_)) {
+                self.app.update();
+            }
+        }
+    }
+
+    pub fn frames(&mut self, count: u32) {
+        for _ in 0..count {
+            self.app.update();
+        }
+    }
+
+    /// Left-click the map at a world position
+    pub fn click_world(&mut self, world_pos: Vec2) {
+        self.play(&[
+            RecordedInput::CursorTo(world_pos),
+            RecordedInput::MousePress(MouseButton::Left),
+            RecordedInput::MouseRelease(MouseButton::Left),
+        ]);
+    }
+
+    pub fn tap_key(&mut self, key_code: KeyCode) {
+        self.play(&[RecordedInput::KeyPress(key_code), RecordedInput::KeyRelease(key_code)]);
+    }
+
+    /// Type text one character per frame, as a text field sees it
+    pub fn type_text(&mut self, text: &str) {
+        let window = self.window();
+        for character in text.chars() {
+            self.app.world_mut().send_event(KeyboardInput {
+                key_code: KeyCode::Unidentified(NativeKeyCode::Unidentified),
+                logical_key: Key::Character(character.to_string().into()),
+                state: ButtonState::Pressed,
+                text: Some(character.to_string().into()),
+                repeat: false,
+                window,
+            });
+            self.app.update();
+        }
+    }
+
+    /// Left-click the first button carrying `M` that matches `filter`
+    pub fn click_button_where<M: Component>(&mut self, filter: impl Fn(&M) -> bool) {
+        let world = self.app.world_mut();
+        let button = world
+            .query_filtered::<(Entity, &M), With<Button>>()
+            .iter(world)
+            .find(|(_, marker)| filter(marker))
+            .map(|(entity, _)| entity)
+            .unwrap_or_else(|| panic!("no {} button to click", std::any::type_name::<M>()));
+
+        // Hover first so the press is a fresh change, as with a real pointer
+        *world.get_mut::<Interaction>(button).unwrap() = Interaction::Hovered;
+        self.app.update();
+        *self.app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Pressed;
+        self.play(&[
+            RecordedInput::MousePress(MouseButton::Left),
+            RecordedInput::MouseRelease(MouseButton::Left),
+        ]);
+    }
+
+    pub fn click_button<M: Component>(&mut self) {
+        self.click_button_where::<M>(|_| true);
+    }
+
+    pub fn app_state(&self) -> AppState {
+        *self.app.world().resource::<State<AppState>>().get()
+    }
+
+    /// Towers on the map with their positions
+    pub fn towers(&mut self) -> Vec<(Entity, Vec2, TowerType)> {
+        let world = self.app.world_mut();
+        world
+            .query::<(Entity, &Transform, &TowerStats)>()
+            .iter(world)
+            .map(|(entity, transform, stats)| (entity, transform.translation.truncate(), stats.tower_type))
+            .collect()
+    }
+}
+
+fn setup_camera(mut commands: Commands) {
+    commands.spawn((Camera::default(), Transform::default(), GlobalTransform::default()));
+}
+
+fn set_interactions(world: &mut World, next: impl Fn(Interaction) -> Interaction) {
+    let mut interactions = world.query::<&mut Interaction>();
+    for mut interaction in interactions.iter_mut(world) {
+        let updated = next(*interaction);
+        interaction.set_if_neq(updated);
+    }
+}
@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use std::time::Duration;
+use tower_defense_bevy::components::*;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::combat_system::Target;
+use tower_defense_bevy::systems::construction_system::{construction_progress_system, placement_grace_system, sell_refund};
+
+#[test]
+fn test_constructing_progress() {
+    let mut constructing = Constructing::new(2.0, ResourceCost::money(40));
+    assert_eq!(constructing.progress(), 0.0);
+    assert!(!constructing.is_complete());
+
+    constructing.build_timer.tick(std::time::Duration::from_secs(1));
+    assert!((constructing.progress() - 0.5).abs() < 0.001);
+    assert!(!constructing.is_complete());
+
+    constructing.build_timer.tick(std::time::Duration::from_secs(2));
+    assert_eq!(constructing.progress(), 1.0);
+    assert!(constructing.is_complete());
+}
+
+#[test]
+fn test_build_time_scales_with_tier() {
+    let tiers = [
+        TowerType::Basic,
+        TowerType::Advanced,
+        TowerType::Laser,
+        TowerType::Missile,
+        TowerType::Tesla,
+    ];
+
+    for pair in tiers.windows(2) {
+        assert!(pair[0].get_build_time() < pair[1].get_build_time(),
+            "{:?} should build faster than {:?}", pair[0], pair[1]);
+    }
+}
+
+#[test]
+fn test_cancelled_construction_refunds_full_cost() {
+    let mut economy = Economy::default();
+    economy.research_points = 10;
+    let initial = (economy.money, economy.research_points, economy.materials, economy.energy);
+
+    let cost = TowerType::Advanced.get_cost();
+    assert!(economy.can_afford(&cost));
+    economy.spend(&cost);
+
+    let constructing = Constructing::new(TowerType::Advanced.get_build_time(), cost);
+    economy.refund(&constructing.paid_cost);
+
+    assert_eq!((economy.money, economy.research_points, economy.materials, economy.energy), initial);
+}
+
+/// World holding one Basic tower whose construction finishes on the first tick
+fn built_tower_world() -> (World, Entity) {
+    let mut world = World::new();
+    world.init_resource::<SimulationClock>();
+    let mut constructing = Constructing::new(TowerType::Basic.get_build_time(), TowerType::Basic.get_cost());
+    constructing.build_timer.tick(Duration::from_secs_f32(TowerType::Basic.get_build_time()));
+    let tower = world
+        .spawn((TowerStats::new(TowerType::Basic), Target::default(), constructing))
+        .id();
+    world.run_system_once(construction_progress_system).unwrap();
+    (world, tower)
+}
+
+fn refund_of(world: &World, tower: Entity) -> ResourceCost {
+    let stats = world.get::<TowerStats>(tower).unwrap();
+    sell_refund(stats, world.get::<Constructing>(tower), world.get::<PlacementGrace>(tower))
+}
+
+#[test]
+fn test_freshly_built_tower_sells_for_full_price() {
+    let (mut world, tower) = built_tower_world();
+    assert!(world.get::<Constructing>(tower).is_none());
+    assert_eq!(refund_of(&world, tower), TowerType::Basic.get_cost());
+
+    // The grace window runs out after a few seconds
+    world.get_mut::<PlacementGrace>(tower).unwrap().timer.tick(Duration::from_secs_f32(PLACEMENT_GRACE_PERIOD));
+    world.run_system_once(placement_grace_system).unwrap();
+    assert!(world.get::<PlacementGrace>(tower).is_none());
+    assert_eq!(refund_of(&world, tower), TowerStats::new(TowerType::Basic).sell_value());
+}
+
+#[test]
+fn test_grace_ends_once_the_tower_fires() {
+    let (mut world, tower) = built_tower_world();
+    world.run_system_once(placement_grace_system).unwrap();
+    assert!(world.get::<PlacementGrace>(tower).is_some(), "no time passed and no shot fired");
+
+    world.get_mut::<Target>(tower).unwrap().last_shot_time = 4.2;
+    world.run_system_once(placement_grace_system).unwrap();
+    assert!(world.get::<PlacementGrace>(tower).is_none());
+    assert!(refund_of(&world, tower).money < TowerType::Basic.get_cost().money);
+}
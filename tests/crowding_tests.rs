@@ -0,0 +1,108 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use tower_defense_bevy::components::{Enemy, Health};
+use tower_defense_bevy::resources::EnemySpatialIndex;
+use tower_defense_bevy::systems::crowding_system::{
+    crowd_badge_system, crowd_depth, crowd_layout_system, crowd_scale, rebuild_enemy_spatial_index_system, CrowdBadge,
+    CrowdingConfig, CROWD_MIN_SCALE,
+};
+
+fn crowding_world() -> World {
+    let mut world = World::new();
+    world.init_resource::<EnemySpatialIndex>();
+    world.init_resource::<CrowdingConfig>();
+    world
+}
+
+fn spawn_enemy(world: &mut World, position: Vec2) -> Entity {
+    world
+        .spawn((Enemy::default(), Health::new(50.0), Transform::from_translation(position.extend(0.0))))
+        .id()
+}
+
+fn run_crowding(world: &mut World) {
+    world.run_system_once(rebuild_enemy_spatial_index_system).unwrap();
+    world.run_system_once(crowd_layout_system).unwrap();
+    world.run_system_once(crowd_badge_system).unwrap();
+}
+
+#[test]
+fn test_crowd_scale_shrinks_to_a_floor() {
+    assert_eq!(crowd_scale(0), 1.0);
+    assert_eq!(crowd_scale(1), 1.0);
+    assert!(crowd_scale(3) < crowd_scale(2));
+    assert_eq!(crowd_scale(100), CROWD_MIN_SCALE);
+}
+
+#[test]
+fn test_oldest_enemy_is_drawn_on_top() {
+    assert!(crowd_depth(0, 3) > crowd_depth(1, 3));
+    assert!(crowd_depth(1, 3) > crowd_depth(2, 3));
+    assert_eq!(crowd_depth(0, 1), crowd_depth(2, 3));
+}
+
+#[test]
+fn test_spatial_index_buckets_enemies_by_cell() {
+    let mut world = crowding_world();
+    let first = spawn_enemy(&mut world, Vec2::new(5.0, 5.0));
+    let second = spawn_enemy(&mut world, Vec2::new(30.0, 10.0));
+    let apart = spawn_enemy(&mut world, Vec2::new(200.0, 5.0));
+    world.run_system_once(rebuild_enemy_spatial_index_system).unwrap();
+
+    let index = world.resource::<EnemySpatialIndex>();
+    let cell = EnemySpatialIndex::cell_of(Vec2::new(5.0, 5.0));
+    assert_eq!(index.enemies_in(cell), &[first, second]);
+    assert_eq!(index.enemies_in(EnemySpatialIndex::cell_of(Vec2::new(200.0, 5.0))), &[apart]);
+    assert_eq!(index.count_in(IVec2::new(-10, -10)), 0);
+}
+
+#[test]
+fn test_stacked_enemies_shrink_and_layer_deterministically() {
+    let mut world = crowding_world();
+    let enemies: Vec<Entity> = (0..3).map(|i| spawn_enemy(&mut world, Vec2::new(10.0 + i as f32, 10.0))).collect();
+    let alone = spawn_enemy(&mut world, Vec2::new(300.0, 10.0));
+    run_crowding(&mut world);
+
+    let transform = |world: &World, entity: Entity| *world.get::<Transform>(entity).unwrap();
+    for &enemy in &enemies {
+        assert_eq!(transform(&world, enemy).scale, Vec3::splat(crowd_scale(3)));
+    }
+    assert!(transform(&world, enemies[0]).translation.z > transform(&world, enemies[1]).translation.z);
+    assert!(transform(&world, enemies[1]).translation.z > transform(&world, enemies[2]).translation.z);
+    assert_eq!(transform(&world, alone).scale, Vec3::ONE);
+
+    // Once the pile breaks up the enemies return to full size
+    world.entity_mut(enemies[1]).get_mut::<Transform>().unwrap().translation.x = 500.0;
+    world.entity_mut(enemies[2]).get_mut::<Transform>().unwrap().translation.x = 700.0;
+    run_crowding(&mut world);
+    assert_eq!(transform(&world, enemies[0]).scale, Vec3::ONE);
+}
+
+#[test]
+fn test_count_badge_follows_crowded_cells() {
+    let mut world = crowding_world();
+    let threshold = world.resource::<CrowdingConfig>().badge_threshold;
+    let enemies: Vec<Entity> = (0..=threshold).map(|_| spawn_enemy(&mut world, Vec2::new(10.0, 10.0))).collect();
+    run_crowding(&mut world);
+
+    let badges = |world: &mut World| {
+        world
+            .query::<(&CrowdBadge, &Text2d)>()
+            .iter(world)
+            .map(|(badge, text)| (badge.cell, text.0.clone()))
+            .collect::<Vec<_>>()
+    };
+    let cell = EnemySpatialIndex::cell_of(Vec2::new(10.0, 10.0));
+    assert_eq!(badges(&mut world), vec![(cell, format!("x{}", threshold + 1))]);
+
+    // Dropping to the threshold removes the badge
+    world.despawn(enemies[0]);
+    run_crowding(&mut world);
+    assert!(badges(&mut world).is_empty());
+
+    // Badges can be turned off entirely
+    spawn_enemy(&mut world, Vec2::new(10.0, 10.0));
+    world.resource_mut::<CrowdingConfig>().badges_enabled = false;
+    run_crowding(&mut world);
+    assert!(badges(&mut world).is_empty());
+}
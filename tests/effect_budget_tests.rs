@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::effect_budget_system::effect_budget_monitor_system;
+
+const FAST_FRAME: f32 = 1.0 / 120.0;
+const SLOW_FRAME: f32 = 1.0 / 20.0;
+
+/// Feed frames until at least `seconds` have passed
+fn run_frames(budget: &mut EffectBudget, frame_time: f32, seconds: f32, live: [usize; EFFECT_CATEGORY_COUNT]) {
+    let frames = (seconds / frame_time).ceil() as usize;
+    for _ in 0..frames {
+        budget.record_frame(frame_time, live);
+    }
+}
+
+fn admitted(budget: &mut EffectBudget, category: EffectCategory, requests: usize) -> usize {
+    (0..requests).filter(|_| budget.admit(category)).count()
+}
+
+#[test]
+fn test_full_density_admits_everything_up_to_the_cap() {
+    let mut budget = EffectBudget::default();
+    budget.set_cap(EffectCategory::DamageNumber, 10);
+
+    assert_eq!(admitted(&mut budget, EffectCategory::DamageNumber, 8), 8);
+    assert_eq!(admitted(&mut budget, EffectCategory::DamageNumber, 8), 2, "a burst stops at the cap");
+    assert_eq!(budget.live(EffectCategory::DamageNumber), 10);
+    assert_eq!(admitted(&mut budget, EffectCategory::Particle, 5), 5, "caps are per category");
+}
+
+#[test]
+fn test_slow_frames_thin_effects_and_recover() {
+    let mut budget = EffectBudget::default();
+    let idle = [0; EFFECT_CATEGORY_COUNT];
+
+    run_frames(&mut budget, SLOW_FRAME, DENSITY_ADJUST_INTERVAL * 1.5, idle);
+    assert_eq!(budget.density, 1.0 - EFFECT_DENSITY_STEP, "one step per interval");
+
+    run_frames(&mut budget, SLOW_FRAME, DENSITY_ADJUST_INTERVAL * 10.0, idle);
+    assert_eq!(budget.density, MIN_EFFECT_DENSITY, "never below the floor");
+    assert_eq!(admitted(&mut budget, EffectCategory::Particle, 100), (100.0 * MIN_EFFECT_DENSITY) as usize);
+
+    // Fast frames flush the window and density climbs back
+    run_frames(&mut budget, FAST_FRAME, DENSITY_ADJUST_INTERVAL * 10.0, idle);
+    assert_eq!(budget.density, 1.0);
+}
+
+#[test]
+fn test_full_category_degrades_until_headroom_returns() {
+    let mut budget = EffectBudget::default();
+    let mut live = [0; EFFECT_CATEGORY_COUNT];
+    live[EffectCategory::Trail.index()] = budget.cap(EffectCategory::Trail);
+
+    run_frames(&mut budget, FAST_FRAME, DENSITY_ADJUST_INTERVAL * 1.5, live);
+    assert!(budget.is_over_budget());
+    assert!(budget.density < 1.0);
+    let lowered = budget.density;
+
+    // Just under the cap: no longer over budget, but not enough headroom to restore
+    live[EffectCategory::Trail.index()] = budget.cap(EffectCategory::Trail) - 1;
+    run_frames(&mut budget, FAST_FRAME, DENSITY_ADJUST_INTERVAL * 3.0, live);
+    assert_eq!(budget.density, lowered);
+
+    live[EffectCategory::Trail.index()] = 0;
+    run_frames(&mut budget, FAST_FRAME, DENSITY_ADJUST_INTERVAL * 10.0, live);
+    assert_eq!(budget.density, 1.0);
+}
+
+#[test]
+fn test_monitor_counts_tagged_effects() {
+    let mut world = World::new();
+    world.init_resource::<Time<Real>>();
+    world.init_resource::<EffectBudget>();
+    world.spawn(CosmeticEffect(EffectCategory::Decal));
+    world.spawn(CosmeticEffect(EffectCategory::Decal));
+    world.spawn(CosmeticEffect(EffectCategory::ScreenFlash));
+    world.spawn(Transform::default());
+
+    world.run_system_once(effect_budget_monitor_system).unwrap();
+    let budget = world.resource::<EffectBudget>();
+    assert_eq!(budget.live(EffectCategory::Decal), 2);
+    assert_eq!(budget.live(EffectCategory::ScreenFlash), 1);
+    assert_eq!(budget.total_live(), 3);
+}
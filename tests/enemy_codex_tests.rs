@@ -0,0 +1,117 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::codex_system::{codex_summary, CodexHandler, CodexPanel, CODEX_KEY};
+use tower_defense_bevy::systems::combat_system::{collision_system, EnemyDamagedEvent, EnemyKilledEvent, WaveStatus};
+use tower_defense_bevy::systems::enemy_system::{enemy_cleanup_system, EnemyEscapedEvent};
+use tower_defense_bevy::systems::input::{InputHandler, InputMappingRegistry};
+use tower_defense_bevy::systems::smart_enemy_system::SmartEnemy;
+
+fn combat_world() -> World {
+    let mut world = World::new();
+    world.init_resource::<Economy>();
+    world.init_resource::<Score>();
+    world.init_resource::<WaveStatus>();
+    world.init_resource::<EnemyCodex>();
+    world.init_resource::<Events<EnemyKilledEvent>>();
+    world.init_resource::<Events<EnemyDamagedEvent>>();
+    world.init_resource::<Events<EnemyEscapedEvent>>();
+    world
+}
+
+/// Fire a projectile of `damage` straight into `enemy`
+fn hit(world: &mut World, enemy: Entity, damage: f32) {
+    world.spawn((
+        Projectile::new(damage, 300.0, enemy, Vec2::ZERO, TowerType::Basic),
+        Transform::default(),
+    ));
+    world.run_system_once(collision_system).unwrap();
+}
+
+#[test]
+fn test_codex_summary_formats_counts() {
+    let stats = EnemyTypeStats { kills: 1_204, leaks: 37, damage_taken: 0.0 };
+    let formatter = NumberFormatter::default();
+    assert_eq!(codex_summary(EnemyType::Tank, &stats, &formatter), "You've killed 1,204 Tank enemies; 37 leaked");
+
+    let formatter = NumberFormatter::new(NumberLocale::German, false);
+    assert_eq!(codex_summary(EnemyType::Tank, &stats, &formatter), "You've killed 1.204 Tank enemies; 37 leaked");
+}
+
+#[test]
+fn test_lore_unlocks_at_kill_milestones() {
+    let mut codex = EnemyCodex::default();
+    let first = CODEX_MILESTONES[0];
+    for _ in 1..first {
+        assert_eq!(codex.record_kill(EnemyType::Fast), None);
+    }
+    assert!(codex.unlocked_lore(EnemyType::Fast).is_empty());
+    assert_eq!(codex.next_milestone(EnemyType::Fast), Some(first));
+
+    assert_eq!(codex.record_kill(EnemyType::Fast), Some(first));
+    assert_eq!(codex.unlocked_lore(EnemyType::Fast), vec![codex_lore(EnemyType::Fast)[0]]);
+    assert_eq!(codex.next_milestone(EnemyType::Fast), Some(CODEX_MILESTONES[1]));
+    assert!(codex.unlocked_lore(EnemyType::Basic).is_empty(), "milestones are per type");
+
+    assert_eq!(codex.kill_heat(EnemyType::Fast), 1.0);
+    assert_eq!(codex.kill_heat(EnemyType::Basic), 0.0);
+}
+
+#[test]
+fn test_codex_round_trips_through_json() {
+    let mut codex = EnemyCodex::default();
+    codex.record_kill(EnemyType::Basic);
+    codex.record_leak(EnemyType::Fast);
+    codex.record_damage(EnemyType::Basic, 42.5);
+
+    let json = codex.to_json().unwrap();
+    assert_eq!(EnemyCodex::from_json(&json).unwrap(), codex);
+    assert!(EnemyCodex::from_json("not json").is_err());
+}
+
+#[test]
+fn test_combat_records_damage_and_kills_per_type() {
+    let mut world = combat_world();
+    let fast = world
+        .spawn((Enemy::default(), EnemyType::Fast, Health::new(30.0), Transform::default()))
+        .id();
+
+    hit(&mut world, fast, 10.0);
+    let stats = world.resource::<EnemyCodex>().stats(EnemyType::Fast);
+    assert_eq!(stats, EnemyTypeStats { kills: 0, leaks: 0, damage_taken: 10.0 });
+
+    // Overkill only counts the health the enemy had left
+    hit(&mut world, fast, 50.0);
+    let codex = world.resource::<EnemyCodex>();
+    assert_eq!(codex.stats(EnemyType::Fast), EnemyTypeStats { kills: 1, leaks: 0, damage_taken: 30.0 });
+    assert_eq!(codex.stats(EnemyType::Basic), EnemyTypeStats::default());
+}
+
+#[test]
+fn test_escapes_are_recorded_as_leaks() {
+    let mut world = combat_world();
+    world.spawn((Enemy::default(), PathProgress { current: 1.0 }));
+    world.spawn((Enemy::default(), SmartEnemy, PathProgress { current: 1.0 }));
+    world.spawn((Enemy::default(), EnemyType::Tank, PathProgress { current: 1.0 }));
+    world.spawn((Enemy::default(), EnemyType::Tank, PathProgress { current: 0.5 }));
+
+    world.run_system_once(enemy_cleanup_system).unwrap();
+    let codex = world.resource::<EnemyCodex>();
+    assert_eq!(codex.stats(EnemyType::Basic).leaks, 2, "enemies without a type are basic, smart or not");
+    assert_eq!(codex.stats(EnemyType::Tank).leaks, 1);
+}
+
+#[test]
+fn test_codex_key_is_a_rebindable_handler() {
+    let mut world = World::new();
+    world.init_resource::<CodexPanel>();
+    let mut registry = InputMappingRegistry::new();
+    registry.register_handler(std::sync::Arc::new(CodexHandler)).unwrap();
+    registry.rebind(CodexHandler.get_id(), CODEX_KEY, KeyCode::KeyJ).unwrap();
+
+    assert!(!registry.process_input(&mut world, CODEX_KEY));
+    assert!(registry.process_input(&mut world, KeyCode::KeyJ));
+    assert!(world.resource::<CodexPanel>().open);
+    assert!(registry.process_input(&mut world, KeyCode::KeyJ));
+    assert!(!world.resource::<CodexPanel>().open);
+}
@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use std::time::Duration;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::combat_system::{
+    collision_system, tower_targeting_system, EnemyDamagedEvent, EnemyKilledEvent, Target, WaveStatus,
+};
+use tower_defense_bevy::systems::enemy_death_system::*;
+
+fn death_world() -> World {
+    let mut world = World::new();
+    world.init_resource::<Time>();
+    world.init_resource::<Economy>();
+    world.init_resource::<Score>();
+    world.init_resource::<WaveStatus>();
+    world.init_resource::<Events<EnemyKilledEvent>>();
+    world.init_resource::<Events<EnemyDamagedEvent>>();
+    world
+}
+
+/// Kill an enemy at `position` with a single shot
+fn kill_enemy(world: &mut World, position: Vec2) -> Entity {
+    let enemy = world
+        .spawn((
+            Enemy::default(),
+            Health::new(10.0),
+            PathProgress { current: 0.5 },
+            Sprite::from_color(Color::srgb(1.0, 0.2, 0.2), Vec2::splat(20.0)),
+            Transform::from_translation(position.extend(1.0)),
+        ))
+        .id();
+    world.spawn((
+        Transform::from_translation(position.extend(0.0)),
+        Projectile::new(50.0, 300.0, enemy, position, TowerType::Basic),
+    ));
+    world.run_system_once(collision_system).unwrap();
+    enemy
+}
+
+fn run(world: &mut World, seconds: f32) {
+    world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(seconds));
+    world.run_system_once(enemy_death_system).unwrap();
+}
+
+#[test]
+fn test_killed_enemy_lingers_but_cannot_be_targeted() {
+    let mut world = death_world();
+    let corpse = kill_enemy(&mut world, Vec2::new(50.0, 0.0));
+
+    assert!(world.get::<DyingEnemy>(corpse).is_some());
+    assert!(world.get::<Enemy>(corpse).is_none());
+    assert!(world.get::<Health>(corpse).is_none());
+    assert!(world.get::<Sprite>(corpse).is_some(), "keeps its visuals for the animation");
+    assert_eq!(world.resource::<WaveStatus>().enemies_killed, 1);
+
+    let tower = world
+        .spawn((TowerStats::new(TowerType::Basic), Transform::default(), Target::default()))
+        .id();
+    world.run_system_once(tower_targeting_system).unwrap();
+    assert_eq!(world.get::<Target>(tower).unwrap().entity, None);
+}
+
+#[test]
+fn test_dying_enemy_shrinks_fades_and_despawns() {
+    let mut world = death_world();
+    let corpse = kill_enemy(&mut world, Vec2::ZERO);
+
+    run(&mut world, ENEMY_DEATH_DURATION / 2.0);
+    let scale = world.get::<Transform>(corpse).unwrap().scale.x;
+    let alpha = world.get::<Sprite>(corpse).unwrap().color.alpha();
+    assert!(scale > 0.0 && scale < 1.0, "half shrunk, got {scale}");
+    assert!(alpha > 0.0 && alpha < 1.0, "half faded, got {alpha}");
+    assert_eq!(world.query::<&DeathCoin>().iter(&world).count(), 1);
+
+    run(&mut world, ENEMY_DEATH_DURATION);
+    assert!(world.get_entity(corpse).is_err());
+    assert_eq!(world.query::<&DeathCoin>().iter(&world).count(), 1, "one coin per death");
+}
+
+#[test]
+fn test_death_coin_respects_the_effect_budget() {
+    let mut world = death_world();
+    let mut budget = EffectBudget::default();
+    budget.set_cap(EffectCategory::Particle, 0);
+    world.insert_resource(budget);
+    let corpse = kill_enemy(&mut world, Vec2::ZERO);
+
+    run(&mut world, 0.01);
+    assert_eq!(world.query::<&DeathCoin>().iter(&world).count(), 0);
+    assert!(world.get::<DyingEnemy>(corpse).is_some(), "the enemy still fades out");
+}
@@ -55,4 +55,77 @@ fn test_path_distance_to_progress_conversion() {
     // 50 units along a 200 unit path should be 0.25 progress
     let progress = 50.0 / total_length;
     assert_eq!(progress, 0.25);
-}
\ No newline at end of file
+}
+#[test]
+fn test_swarm_offsets_are_bounded_and_spread() {
+    let offsets: Vec<f32> = (0..20).map(|index| SwarmOffset::for_spawn_index(index).lateral).collect();
+    assert!(offsets.iter().all(|offset| offset.abs() <= SWARM_MAX_LATERAL_OFFSET));
+    assert!(SWARM_MAX_LATERAL_OFFSET + ENEMY_COLLISION_RADIUS <= 20.0, "swarm must stay inside a 40px path cell");
+
+    // Enemies spawned back to back never share a lane
+    for pair in offsets.windows(2) {
+        assert!((pair[0] - pair[1]).abs() > 1.0, "offsets {:?} too close", pair);
+    }
+    // Stable: the same spawn index always gets the same lane
+    assert_eq!(SwarmOffset::for_spawn_index(7), SwarmOffset::for_spawn_index(7));
+}
+
+#[test]
+fn test_path_direction_follows_turns() {
+    let path = EnemyPath::new(vec![
+        Vec2::new(0.0, 0.0),
+        Vec2::new(100.0, 0.0),
+        Vec2::new(100.0, 100.0),
+    ]);
+
+    assert!(path.get_smooth_direction_at_progress(0.0).abs_diff_eq(Vec2::X, 0.05));
+    assert!(path.get_smooth_direction_at_progress(1.0).abs_diff_eq(Vec2::Y, 0.05));
+    assert_eq!(EnemyPath::new(vec![Vec2::ZERO]).get_smooth_direction_at_progress(0.5), Vec2::ZERO);
+}
+
+#[test]
+fn test_lateral_offset_shrinks_near_obstacles() {
+    use tower_defense_bevy::systems::enemy_system::bounded_lateral_offset;
+
+    // Open ground keeps the full offset
+    assert_eq!(bounded_lateral_offset(Vec2::ZERO, Vec2::Y, 6.0, |_| true), 6.0);
+    // Offsets beyond the swarm bound are clamped
+    assert_eq!(bounded_lateral_offset(Vec2::ZERO, Vec2::Y, 50.0, |_| true), SWARM_MAX_LATERAL_OFFSET);
+
+    // A wall 14px to the left: the enemy's edge must stay short of it
+    let wall = |point: Vec2| point.y < 14.0;
+    let offset = bounded_lateral_offset(Vec2::ZERO, Vec2::Y, 8.0, wall);
+    assert!(offset + ENEMY_COLLISION_RADIUS < 14.0 && offset > 0.0, "offset was {}", offset);
+    // The other side is unaffected
+    assert_eq!(bounded_lateral_offset(Vec2::ZERO, Vec2::Y, -8.0, wall), -8.0);
+
+    // Nothing fits: walk the centre line
+    assert_eq!(bounded_lateral_offset(Vec2::ZERO, Vec2::Y, 8.0, |point| point.y <= 0.0), 0.0);
+}
+
+#[test]
+fn test_swarm_enemies_walk_beside_the_path() {
+    use bevy::ecs::system::RunSystemOnce;
+    use std::time::Duration;
+    use tower_defense_bevy::systems::enemy_system::enemy_movement_system;
+
+    let mut world = World::new();
+    world.insert_resource(EnemyPath::new(vec![Vec2::new(0.0, 0.0), Vec2::new(400.0, 0.0)]));
+    world.insert_resource(Time::<()>::default());
+    world.resource_mut::<Time>().advance_by(Duration::from_secs(1));
+
+    let lane = world.spawn((
+        Enemy::default(),
+        PathProgress::new(),
+        Transform::default(),
+        SwarmOffset { lateral: 5.0 },
+    )).id();
+    let centre = world.spawn((Enemy::default(), PathProgress::new(), Transform::default())).id();
+    world.run_system_once(enemy_movement_system).unwrap();
+
+    let lane_position = world.get::<Transform>(lane).unwrap().translation.truncate();
+    let centre_position = world.get::<Transform>(centre).unwrap().translation.truncate();
+    assert!((lane_position.x - centre_position.x).abs() < 0.01, "offset must not change progress");
+    assert!((lane_position.y - 5.0).abs() < 0.01, "left of travel is +y, was {}", lane_position);
+    assert!(centre_position.y.abs() < 0.01);
+}
@@ -0,0 +1,97 @@
+use std::time::Duration;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use tower_defense_bevy::components::*;
+use tower_defense_bevy::resources::{TowerStats, TowerType};
+use tower_defense_bevy::systems::combat_system::{projectile_spawning_system, Target, TowerFiredEvent};
+use tower_defense_bevy::systems::tower_ui::firing_pattern_label;
+
+const COOLDOWN: f32 = 1.0;
+
+#[test]
+fn test_tower_types_get_their_patterns() {
+    assert_eq!(FiringPattern::for_tower(TowerType::Basic), FiringPattern::Uniform);
+    assert_eq!(FiringPattern::for_tower(TowerType::Missile), FiringPattern::Uniform);
+    assert_eq!(FiringPattern::for_tower(TowerType::Advanced).get_name(), "Burst");
+    assert_eq!(FiringPattern::for_tower(TowerType::Laser).get_name(), "Charge-Up");
+    assert_eq!(FiringPattern::for_tower(TowerType::Tesla).get_name(), "Ramping");
+}
+
+#[test]
+fn test_bursts_fire_rounds_close_together_then_pause() {
+    let target = Entity::from_raw(1);
+    let mut pattern = FiringPattern::for_tower(TowerType::Advanced);
+    assert_eq!(pattern.try_fire(target, 10.0, COOLDOWN, 0.016), Some(1.0));
+    assert_eq!(pattern.try_fire(target, 0.05, COOLDOWN, 0.016), None);
+    assert_eq!(pattern.try_fire(target, BURST_ROUND_DELAY, COOLDOWN, 0.016), Some(1.0));
+    assert_eq!(pattern.try_fire(target, BURST_ROUND_DELAY, COOLDOWN, 0.016), Some(1.0));
+
+    // The pause after the burst keeps the average at one round per cooldown
+    let pause = COOLDOWN * BURST_ROUNDS as f32 - (BURST_ROUNDS - 1) as f32 * BURST_ROUND_DELAY;
+    assert_eq!(pattern.try_fire(target, BURST_ROUND_DELAY, COOLDOWN, 0.016), None);
+    assert_eq!(pattern.try_fire(target, pause - 0.01, COOLDOWN, 0.016), None);
+    assert_eq!(pattern.try_fire(target, pause, COOLDOWN, 0.016), Some(1.0));
+}
+
+#[test]
+fn test_lasers_charge_before_a_heavy_shot() {
+    let target = Entity::from_raw(1);
+    let mut pattern = FiringPattern::for_tower(TowerType::Laser);
+    assert_eq!(pattern.try_fire(target, 0.5, COOLDOWN, 0.5), None, "no charging during the cooldown");
+    assert_eq!(pattern.status_text(), None);
+
+    assert_eq!(pattern.try_fire(target, 2.0, COOLDOWN, 0.4), None);
+    assert_eq!(pattern.status_text().as_deref(), Some("Charging 40%"));
+    assert_eq!(firing_pattern_label(&pattern), "Charge-Up (Charging 40%)");
+
+    // Losing the target drops the charge
+    pattern.lose_target();
+    assert_eq!(pattern.try_fire(target, 2.0, COOLDOWN, 0.6), None);
+    assert_eq!(pattern.try_fire(target, 2.0, COOLDOWN, 0.4), Some(CHARGED_SHOT_DAMAGE_MULTIPLIER));
+    assert_eq!(pattern.status_text(), None);
+}
+
+#[test]
+fn test_tesla_ramps_up_on_one_target_and_resets_on_another() {
+    let first = Entity::from_raw(1);
+    let second = Entity::from_raw(2);
+    let mut pattern = FiringPattern::for_tower(TowerType::Tesla);
+    assert_eq!(pattern.try_fire(first, COOLDOWN, COOLDOWN, 0.016), Some(1.0));
+    assert_eq!(pattern.try_fire(first, 0.7, COOLDOWN, 0.016), None);
+    assert_eq!(pattern.try_fire(first, 0.8, COOLDOWN, 0.016), Some(1.0), "x1.25 after one shot");
+
+    for _ in 0..10 {
+        pattern.try_fire(first, COOLDOWN, COOLDOWN, 0.016);
+    }
+    assert_eq!(pattern.try_fire(first, COOLDOWN / MAX_RAMP_MULTIPLIER, COOLDOWN, 0.016), Some(1.0), "capped ramp");
+    assert_eq!(firing_pattern_label(&pattern), "Ramping (Ramped x2.50)");
+
+    assert_eq!(pattern.try_fire(second, 0.8, COOLDOWN, 0.016), None, "a new target starts over");
+}
+
+#[test]
+fn test_charged_laser_shots_deal_extra_damage() {
+    let mut world = World::new();
+    let mut time = Time::<()>::default();
+    time.advance_by(Duration::from_secs(10));
+    world.insert_resource(time);
+    world.init_resource::<Events<TowerFiredEvent>>();
+
+    let enemy = world.spawn((Enemy::default(), Transform::from_xyz(0.0, 100.0, 0.0))).id();
+    let stats = TowerStats::new(TowerType::Laser);
+    let damage = stats.damage;
+    world.spawn((
+        stats,
+        Transform::default(),
+        Target { entity: Some(enemy), last_shot_time: 0.0 },
+        FiringPattern::for_tower(TowerType::Laser),
+    ));
+    world.run_system_once(projectile_spawning_system).unwrap();
+
+    let projectiles: Vec<f32> = world
+        .query::<&Projectile>()
+        .iter(&world)
+        .map(|projectile| projectile.damage)
+        .collect();
+    assert_eq!(projectiles, vec![damage * CHARGED_SHOT_DAMAGE_MULTIPLIER]);
+}
@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+use tower_defense_bevy::components::Base;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::enemy_system::EnemyEscapedEvent;
+use tower_defense_bevy::systems::first_breach_system::*;
+use tower_defense_bevy::systems::results_screen::RestartRunEvent;
+use tower_defense_bevy::systems::settings_menu::GameSettings;
+use tower_defense_bevy::systems::ui_feedback::UiFeedbackEvent;
+
+const BASE_POSITION: Vec2 = Vec2::new(300.0, 40.0);
+
+fn breach_world() -> (World, Entity) {
+    let mut world = World::new();
+    world.init_resource::<Score>();
+    world.init_resource::<SimulationClock>();
+    world.init_resource::<GameState>();
+    world.init_resource::<ButtonInput<KeyCode>>();
+    world.init_resource::<Time<Real>>();
+    world.init_resource::<Events<EnemyEscapedEvent>>();
+    world.init_resource::<Events<RestartRunEvent>>();
+    world.init_resource::<Events<UiFeedbackEvent>>();
+    world.insert_resource(EnemyPath::new(vec![Vec2::ZERO, BASE_POSITION]));
+    world.spawn((Base, Transform::from_translation(BASE_POSITION.extend(0.0))));
+    let camera = world
+        .spawn((Camera2d, Transform::default(), Projection::Orthographic(OrthographicProjection::default_2d())))
+        .id();
+    (world, camera)
+}
+
+/// Record an escape the way the cleanup system does
+fn leak(world: &mut World) {
+    world.resource_mut::<Score>().enemy_escaped();
+    world.send_event(EnemyEscapedEvent { enemy: Entity::PLACEHOLDER, is_smart: false });
+}
+
+fn banner_count(world: &mut World) -> usize {
+    world.query_filtered::<(), With<BreachBanner>>().iter(world).count()
+}
+
+#[test]
+fn test_only_the_first_leak_of_a_run_counts() {
+    assert!(is_first_breach(1, 1));
+    assert!(is_first_breach(3, 3), "several enemies leaking together still count as the first");
+    assert!(!is_first_breach(1, 2));
+    assert!(!is_first_breach(0, 0));
+}
+
+#[test]
+fn test_first_leak_starts_slow_motion_and_banner() {
+    let (mut world, _) = breach_world();
+    let trigger = world.register_system(first_breach_trigger_system);
+    world.run_system(trigger).unwrap();
+    assert!(world.get_resource::<BreachMoment>().is_none(), "nothing leaked yet");
+
+    leak(&mut world);
+    world.run_system(trigger).unwrap();
+    let moment = world.resource::<BreachMoment>().clone();
+    assert_eq!(moment.focus, BASE_POSITION);
+    assert_eq!(world.resource::<SimulationClock>().time_scale(), BREACH_SLOW_MOTION);
+    assert_eq!(banner_count(&mut world), 1);
+}
+
+#[test]
+fn test_later_leaks_and_disabled_setting_do_not_trigger() {
+    let (mut world, _) = breach_world();
+    world.resource_mut::<Score>().enemies_escaped = 4;
+    leak(&mut world);
+    let trigger = world.register_system(first_breach_trigger_system);
+    world.run_system(trigger).unwrap();
+    assert!(world.get_resource::<BreachMoment>().is_none());
+
+    let (mut world, _) = breach_world();
+    world.insert_resource(GameSettings { first_breach_moment_enabled: false, ..default() });
+    leak(&mut world);
+    let trigger = world.register_system(first_breach_trigger_system);
+    world.run_system(trigger).unwrap();
+    assert!(world.get_resource::<BreachMoment>().is_none());
+    assert_eq!(world.resource::<SimulationClock>().time_scale(), 1.0);
+}
+
+#[test]
+fn test_skipping_restores_speed_and_camera() {
+    let (mut world, camera) = breach_world();
+    leak(&mut world);
+    let trigger = world.register_system(first_breach_trigger_system);
+    let moment = world.register_system(first_breach_moment_system);
+    world.run_system(trigger).unwrap();
+
+    // Mid-moment the camera has moved towards the base
+    world.get_mut::<Transform>(camera).unwrap().translation = BASE_POSITION.extend(0.0);
+    world.resource_mut::<ButtonInput<KeyCode>>().press(BREACH_SKIP_KEY);
+    world.run_system(moment).unwrap();
+
+    assert!(world.get_resource::<BreachMoment>().is_none());
+    assert_eq!(world.resource::<SimulationClock>().time_scale(), 1.0);
+    assert_eq!(world.get::<Transform>(camera).unwrap().translation, Vec3::ZERO);
+    assert_eq!(banner_count(&mut world), 0);
+}
+
+#[test]
+fn test_moment_ends_on_its_own() {
+    let (mut world, _) = breach_world();
+    leak(&mut world);
+    let trigger = world.register_system(first_breach_trigger_system);
+    let moment = world.register_system(first_breach_moment_system);
+    world.run_system(trigger).unwrap();
+
+    world.resource_mut::<BreachMoment>().remaining = 0.0;
+    world.run_system(moment).unwrap();
+    assert!(world.get_resource::<BreachMoment>().is_none());
+    assert_eq!(world.resource::<SimulationClock>().time_scale(), 1.0);
+}
@@ -33,14 +33,14 @@ fn test_critical_digit_key_conflict_demonstration() {
     
     // Record initial state
     let initial_spawn_rate = app.world().resource::<DebugUIState>().enemy_spawn_rate;
-    let initial_wave = app.world().resource::<WaveManager>().current_wave;
+    let initial_wave = app.world().resource::<WavePlan>().current_wave;
     
     // Run update cycle
     app.update();
     
     // Check for conflicting changes - BOTH systems should have responded
     let final_spawn_rate = app.world().resource::<DebugUIState>().enemy_spawn_rate;
-    let final_wave = app.world().resource::<WaveManager>().current_wave;
+    let final_wave = app.world().resource::<WavePlan>().current_wave;
     
     // THIS TEST SHOULD FAIL - proving both systems respond to same key
     // When fixed, only ONE system should respond based on context
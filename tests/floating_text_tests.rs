@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use std::time::Duration;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::combat_system::{collision_system, DamageKind, EnemyDamagedEvent, EnemyKilledEvent, WaveStatus};
+use tower_defense_bevy::systems::floating_text::*;
+use tower_defense_bevy::systems::settings_menu::GameSettings;
+
+fn text_world() -> World {
+    let mut world = World::new();
+    world.init_resource::<Time>();
+    world.init_resource::<EffectBudget>();
+    world.init_resource::<GameSettings>();
+    world.init_resource::<Events<EnemyDamagedEvent>>();
+    world
+}
+
+fn hit(world: &mut World, amount: f32, kind: DamageKind) {
+    world.send_event(EnemyDamagedEvent {
+        enemy: Entity::PLACEHOLDER,
+        position: Vec2::new(100.0, 50.0),
+        amount,
+        tower_type: TowerType::Basic,
+        kind,
+    });
+    world.run_system_once(damage_number_system).unwrap();
+    // A fresh reader each run, so drop the hit once it has been read
+    world.resource_mut::<Events<EnemyDamagedEvent>>().clear();
+}
+
+fn numbers(world: &mut World) -> Vec<(Entity, String, Color)> {
+    let mut texts = world.query_filtered::<(Entity, &Text2d, &TextColor), With<FloatingText>>();
+    texts.iter(world).map(|(entity, text, color)| (entity, text.0.clone(), color.0)).collect()
+}
+
+fn age(world: &mut World, seconds: f32) {
+    world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(seconds));
+    world.run_system_once(floating_text_system).unwrap();
+}
+
+#[test]
+fn test_collisions_tell_direct_critical_and_splash_hits_apart() {
+    let mut world = World::new();
+    world.init_resource::<Economy>();
+    world.init_resource::<Score>();
+    world.init_resource::<WaveStatus>();
+    world.init_resource::<Events<EnemyKilledEvent>>();
+    world.init_resource::<Events<EnemyDamagedEvent>>();
+    let tough = world.spawn((Enemy::default(), Health::new(1000.0), Transform::default())).id();
+    let frail = world.spawn((Enemy::default(), Health::new(60.0), Transform::from_xyz(200.0, 0.0, 0.0))).id();
+    let bystander = world.spawn((Enemy::default(), Health::new(1000.0), Transform::from_xyz(30.0, 0.0, 0.0))).id();
+    world.spawn((
+        Projectile::new(40.0, 300.0, tough, Vec2::ZERO, TowerType::Missile).with_splash(50.0),
+        Transform::default(),
+    ));
+    world.spawn((Projectile::new(20.0, 300.0, frail, Vec2::ZERO, TowerType::Basic), Transform::from_xyz(200.0, 0.0, 0.0)));
+
+    world.run_system_once(collision_system).unwrap();
+    let events: Vec<EnemyDamagedEvent> = world.resource_mut::<Events<EnemyDamagedEvent>>().drain().collect();
+    let kind_of = |enemy: Entity| events.iter().find(|event| event.enemy == enemy).map(|event| event.kind);
+    assert_eq!(kind_of(tough), Some(DamageKind::Direct));
+    assert_eq!(kind_of(bystander), Some(DamageKind::Splash));
+    assert_eq!(kind_of(frail), Some(DamageKind::Critical), "a third of its health in one shot");
+    assert!(events.iter().all(|event| event.position == world.get::<Transform>(event.enemy).unwrap().translation.truncate()));
+}
+
+#[test]
+fn test_numbers_are_coloured_by_damage_kind() {
+    let mut world = text_world();
+    hit(&mut world, 12.4, DamageKind::Critical);
+
+    let numbers = numbers(&mut world);
+    assert_eq!(numbers.len(), 1);
+    assert_eq!(numbers[0].1, "12!");
+    assert_eq!(numbers[0].2, damage_number_color(DamageKind::Critical));
+    assert_ne!(damage_number_color(DamageKind::Burn), damage_number_color(DamageKind::Splash));
+    assert_eq!(damage_number_label(0.2, DamageKind::Burn), "1", "chip damage still shows");
+}
+
+#[test]
+fn test_numbers_rise_fade_and_return_to_the_pool() {
+    let mut world = text_world();
+    hit(&mut world, 20.0, DamageKind::Direct);
+    let (number, ..) = numbers(&mut world)[0];
+    let start = world.get::<Transform>(number).unwrap().translation.y;
+
+    age(&mut world, FLOATING_TEXT_LIFETIME / 2.0);
+    assert!(world.get::<Transform>(number).unwrap().translation.y > start);
+    assert!(world.get::<TextColor>(number).unwrap().0.alpha() < 1.0);
+
+    age(&mut world, FLOATING_TEXT_LIFETIME);
+    assert!(numbers(&mut world).is_empty());
+    assert_eq!(world.get::<Visibility>(number), Some(&Visibility::Hidden));
+    assert!(world.get::<CosmeticEffect>(number).is_none(), "pooled text no longer counts against the budget");
+
+    // The next hit reuses the pooled entity
+    hit(&mut world, 5.0, DamageKind::Burn);
+    assert_eq!(numbers(&mut world), vec![(number, "5".to_string(), damage_number_color(DamageKind::Burn))]);
+    assert_eq!(world.get::<Visibility>(number), Some(&Visibility::Visible));
+}
+
+#[test]
+fn test_numbers_respect_the_setting_and_budget() {
+    let mut world = text_world();
+    world.resource_mut::<GameSettings>().damage_numbers_enabled = false;
+    hit(&mut world, 20.0, DamageKind::Direct);
+    assert!(numbers(&mut world).is_empty());
+
+    world.resource_mut::<GameSettings>().damage_numbers_enabled = true;
+    world.resource_mut::<EffectBudget>().set_cap(EffectCategory::DamageNumber, 2);
+    for _ in 0..5 {
+        hit(&mut world, 20.0, DamageKind::Direct);
+    }
+    assert_eq!(numbers(&mut world).len(), 2);
+}
@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+use tower_defense_bevy::resources::{TowerStats, TowerType};
+use tower_defense_bevy::systems::path_generation::*;
+use tower_defense_bevy::systems::placement_assist::PlacementEstimate;
+
+/// A straight route along row 5 of an empty 12x10 grid, marked on the grid
+fn straight_route(grid: &mut PathGrid) -> Vec<GridPos> {
+    let route: Vec<GridPos> = (0..grid.width).map(|x| GridPos::new(x, 5)).collect();
+    grid.apply_path(&route);
+    route
+}
+
+/// Generated map with its route marked, as the game builds levels
+fn generated_level(seed: u64, archetype: MapArchetype) -> (PathGrid, Vec<GridPos>) {
+    let mut grid = generate_procedural_map_with_archetype(seed, 0.5, archetype);
+    let route = generate_random_strategic_path(seed.wrapping_add(1000), &grid);
+    grid.apply_path(&route);
+    (grid, route)
+}
+
+/// Highest exposure of any cell inside a zone
+fn best_cell_exposure(zone: &TowerZone, exposure: &ExposureField) -> f32 {
+    let (start, end) = zone.grid_bounds;
+    (start.y.min(end.y)..=start.y.max(end.y))
+        .flat_map(|y| (start.x.min(end.x)..=start.x.max(end.x)).map(move |x| GridPos::new(x, y)))
+        .map(|pos| exposure.at(pos))
+        .fold(0.0, f32::max)
+}
+
+#[test]
+fn test_travel_times_follow_the_route() {
+    let mut grid = PathGrid::new(12, 10);
+    let route = straight_route(&mut grid);
+    // One cell per second
+    let travel = TravelTimeField::from_route(&grid, &route, grid.cell_size);
+
+    assert_eq!(travel.arrival(GridPos::new(0, 5)), Some(0.0));
+    assert_eq!(travel.arrival(GridPos::new(7, 5)), Some(7.0));
+    assert_eq!(travel.arrival(GridPos::new(7, 4)), None);
+    assert!(travel.dwell.iter().all(|(_, seconds)| (*seconds - 1.0).abs() < 1e-4));
+    assert!((travel.total_time - 12.0).abs() < 1e-4);
+
+    // Half the speed, twice the time
+    let slow = TravelTimeField::from_route(&grid, &route, grid.cell_size / 2.0);
+    assert!((slow.total_time - 24.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_travel_times_cover_every_route() {
+    let mut grid = PathGrid::new(12, 10);
+    let route = straight_route(&mut grid);
+    // A short second route coming down column 9 onto the first
+    let side: Vec<GridPos> = (5..=8).rev().map(|y| GridPos::new(9, y)).chain((10..12).map(|x| GridPos::new(x, 5))).collect();
+    let travel = TravelTimeField::from_routes(&grid, &[route.clone(), side.clone()], grid.cell_size);
+
+    assert_eq!(travel.arrival(GridPos::new(9, 8)), Some(0.0));
+    assert_eq!(travel.arrival(GridPos::new(10, 5)), Some(4.0), "the earlier of the two routes");
+    assert_eq!(travel.dwell.len(), route.len() + side.len());
+    assert!((travel.total_time - 12.0).abs() < 1e-4, "the longest route");
+}
+
+#[test]
+fn test_exposure_counts_seconds_in_range() {
+    let mut grid = PathGrid::new(12, 10);
+    let route = straight_route(&mut grid);
+    let travel = TravelTimeField::from_route(&grid, &route, grid.cell_size);
+
+    // Right beside the route a short range reaches one cell, a longer one three
+    let beside = GridPos::new(6, 4);
+    let short = ExposureField::new(&grid, &travel, grid.cell_size * 1.2);
+    let long = ExposureField::new(&grid, &travel, grid.cell_size * 1.5);
+    assert!((short.at(beside) - 1.0).abs() < 1e-4);
+    assert!((long.at(beside) - 3.0).abs() < 1e-4);
+
+    // Towers can't stand on the route, and cells out of reach see nothing
+    assert_eq!(long.at(GridPos::new(6, 5)), 0.0);
+    assert_eq!(long.at(GridPos::new(6, 0)), 0.0);
+    assert_eq!(long.at(beside), travel.exposure_at(&grid, grid.grid_to_world(beside), long.range));
+}
+
+#[test]
+fn test_inside_of_a_bend_beats_a_straight() {
+    // Route runs along row 2, turns up column 8 and back along row 6
+    let mut grid = PathGrid::new(14, 10);
+    let mut route: Vec<GridPos> = (0..=8).map(|x| GridPos::new(x, 2)).collect();
+    route.extend((3..=6).map(|y| GridPos::new(8, y)));
+    route.extend((0..8).rev().map(|x| GridPos::new(x, 6)));
+    grid.apply_path(&route);
+
+    let travel = TravelTimeField::from_route(&grid, &route, 50.0);
+    let exposure = ExposureField::new(&grid, &travel, ZONE_TOWER_RANGE);
+    let inside = exposure.at(GridPos::new(6, 4));
+    let straight = exposure.at(GridPos::new(2, 0));
+    assert!(inside > straight * 2.0, "inside {} straight {}", inside, straight);
+
+    let (best, _) = exposure.ranked_cells()[0];
+    assert!(best.y > 2 && best.y < 6 && best.x < 8, "best cell {:?} should sit inside the bend", best);
+}
+
+#[test]
+fn test_exposure_zones_are_buildable_and_disjoint() {
+    for (seed, archetype) in [(3, MapArchetype::Classic), (17, MapArchetype::Maze), (29, MapArchetype::Islands)] {
+        let (grid, route) = generated_level(seed, archetype);
+        let zones = calculate_exposure_tower_zones(&grid, std::slice::from_ref(&route), ZONE_TOWER_RANGE, 50.0);
+        assert!(!zones.is_empty() && zones.len() <= MAX_EXPOSURE_ZONES);
+        assert!(zones[0].strategic_value > 0.0 && zones[0].strategic_value <= 1.0);
+        assert!(zones.windows(2).all(|pair| pair[0].strategic_value >= pair[1].strategic_value));
+
+        let mut claimed = vec![false; grid.width * grid.height];
+        for zone in &zones {
+            let (start, end) = zone.grid_bounds;
+            for y in start.y..=end.y {
+                for x in start.x..=end.x {
+                    let cell = grid.get_cell(GridPos::new(x, y));
+                    assert!(matches!(cell, Some(CellType::Empty) | Some(CellType::TowerZone)), "{:?} at ({}, {})", cell, x, y);
+                    assert!(!claimed[y * grid.width + x], "zones overlap at ({}, {})", x, y);
+                    claimed[y * grid.width + x] = true;
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_exposure_zones_include_the_maps_best_spot() {
+    for (seed, archetype) in [(5, MapArchetype::Classic), (11, MapArchetype::OpenField), (23, MapArchetype::Maze), (41, MapArchetype::Islands)] {
+        let (grid, route) = generated_level(seed, archetype);
+        let travel = TravelTimeField::from_route(&grid, &route, 50.0);
+        let exposure = ExposureField::new(&grid, &travel, ZONE_TOWER_RANGE);
+
+        let flow = calculate_exposure_tower_zones(&grid, std::slice::from_ref(&route), ZONE_TOWER_RANGE, 50.0);
+        assert!(!flow.is_empty());
+
+        // The zones always cover the map's best spot
+        let best = flow.iter().map(|zone| best_cell_exposure(zone, &exposure)).fold(0.0, f32::max);
+        assert_eq!(best, exposure.max(), "{}", archetype.get_name());
+    }
+}
+
+#[test]
+fn test_placement_estimate_is_dps_times_exposure() {
+    let stats = TowerStats::new(TowerType::Laser);
+    let estimate = PlacementEstimate::new(&stats, 4.0);
+    assert_eq!(estimate.dps, stats.damage * stats.fire_rate);
+    assert_eq!(estimate.damage_per_enemy, estimate.dps * 4.0);
+    assert!(estimate.label().starts_with(&format!("{:.0} dmg/enemy", estimate.damage_per_enemy)));
+
+    let out_of_range = PlacementEstimate::new(&stats, 0.0);
+    assert_eq!(out_of_range.damage_per_enemy, 0.0);
+}
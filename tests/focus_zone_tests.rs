@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::combat_system::{tower_targeting_system, FocusZone, Target, TargetingMode};
+use tower_defense_bevy::systems::focus_zone_system::*;
+use tower_defense_bevy::systems::input_system::MouseInputState;
+use tower_defense_bevy::systems::tower_ui::TowerSelectionState;
+
+fn spawn_enemy(world: &mut World, position: Vec2, progress: f32) -> Entity {
+    let mut path_progress = PathProgress::new();
+    path_progress.current = progress;
+    world
+        .spawn((Enemy::default(), Health::new(50.0), path_progress, Transform::from_translation(position.extend(0.0))))
+        .id()
+}
+
+/// World with a selected Basic tower at the origin, drawing its focus zone
+fn drawing_world() -> (World, Entity) {
+    let mut world = World::new();
+    let tower = world
+        .spawn((TowerStats::new(TowerType::Basic), Transform::default()))
+        .id();
+    let mut selection = TowerSelectionState::default();
+    selection.set_upgrade_mode(tower);
+    world.insert_resource(selection);
+    world.insert_resource(MouseInputState::default());
+    let mut drawing = FocusZoneDrawing::default();
+    drawing.start(tower);
+    world.insert_resource(drawing);
+    (world, tower)
+}
+
+/// Press the left button at `from`, then release it at `to`
+fn drag(world: &mut World, from: Vec2, to: Vec2) {
+    {
+        let mut mouse = world.resource_mut::<MouseInputState>();
+        mouse.world_position = from;
+        mouse.left_clicked = true;
+        mouse.left_held = true;
+        mouse.drag_start = Some(from);
+    }
+    world.run_system_once(focus_zone_draw_system).unwrap();
+    assert!(!world.resource::<MouseInputState>().left_clicked, "the press is kept from selecting towers");
+
+    {
+        let mut mouse = world.resource_mut::<MouseInputState>();
+        mouse.world_position = to;
+        mouse.left_clicked = false;
+        mouse.left_held = false;
+        mouse.left_released = true;
+    }
+    world.run_system_once(focus_zone_draw_system).unwrap();
+}
+
+#[test]
+fn test_focus_zone_is_clipped_to_the_range() {
+    let zone = FocusZone::from_drag(Vec2::ZERO, 80.0, Rect::new(20.0, -200.0, 300.0, 200.0)).unwrap();
+    assert_eq!(zone.rect, Rect::new(20.0, -80.0, 80.0, 80.0));
+    assert!(zone.contains(Vec2::new(50.0, 0.0)));
+    assert!(!zone.contains(Vec2::new(-50.0, 0.0)));
+
+    // Outside the range entirely, or only overlapping the corner of the range's square
+    assert_eq!(FocusZone::from_drag(Vec2::ZERO, 80.0, Rect::new(100.0, 0.0, 200.0, 50.0)), None);
+    assert_eq!(FocusZone::from_drag(Vec2::ZERO, 80.0, Rect::new(70.0, 70.0, 200.0, 200.0)), None);
+}
+
+#[test]
+fn test_targeting_ignores_enemies_outside_the_focus_zone() {
+    let mut world = World::new();
+    let tower = world
+        .spawn((
+            TowerStats::new(TowerType::Basic),
+            TargetingMode::First,
+            Target::default(),
+            Transform::default(),
+        ))
+        .id();
+    // The leader is furthest along but on the left; the follower is on the right
+    let leader = spawn_enemy(&mut world, Vec2::new(-40.0, 0.0), 0.8);
+    let follower = spawn_enemy(&mut world, Vec2::new(40.0, 0.0), 0.2);
+
+    world.run_system_once(tower_targeting_system).unwrap();
+    assert_eq!(world.get::<Target>(tower).unwrap().entity, Some(leader));
+
+    let zone = FocusZone::from_drag(Vec2::ZERO, 80.0, Rect::new(0.0, -80.0, 80.0, 80.0)).unwrap();
+    world.entity_mut(tower).insert(zone);
+    world.run_system_once(tower_targeting_system).unwrap();
+    assert_eq!(world.get::<Target>(tower).unwrap().entity, Some(follower));
+
+    // Nobody inside the zone: the tower holds fire
+    world.despawn(follower);
+    world.run_system_once(tower_targeting_system).unwrap();
+    assert_eq!(world.get::<Target>(tower).unwrap().entity, None);
+}
+
+#[test]
+fn test_dragging_sets_the_focus_zone() {
+    let (mut world, tower) = drawing_world();
+    drag(&mut world, Vec2::new(10.0, -30.0), Vec2::new(60.0, 30.0));
+
+    assert_eq!(world.get::<FocusZone>(tower).unwrap().rect, Rect::new(10.0, -30.0, 60.0, 30.0));
+    assert!(!world.resource::<FocusZoneDrawing>().is_active());
+    assert!(!world.resource::<MouseInputState>().left_released, "band selection doesn't see the drag");
+}
+
+#[test]
+fn test_click_clears_the_focus_zone() {
+    let (mut world, tower) = drawing_world();
+    let zone = FocusZone::from_drag(Vec2::ZERO, 80.0, Rect::new(0.0, 0.0, 40.0, 40.0)).unwrap();
+    world.entity_mut(tower).insert(zone);
+
+    drag(&mut world, Vec2::new(20.0, 20.0), Vec2::new(22.0, 21.0));
+    assert!(world.get::<FocusZone>(tower).is_none());
+    assert!(!world.resource::<FocusZoneDrawing>().is_active());
+}
+
+#[test]
+fn test_drawing_stops_when_the_tower_is_deselected() {
+    let (mut world, tower) = drawing_world();
+    world.resource_mut::<TowerSelectionState>().clear_selection();
+    world.run_system_once(focus_zone_draw_system).unwrap();
+    assert!(!world.resource::<FocusZoneDrawing>().is_active());
+    assert!(world.get::<FocusZone>(tower).is_none());
+}
@@ -0,0 +1,66 @@
+use std::time::Duration;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::winit::{UpdateMode, WinitSettings};
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::frame_pacing_system::*;
+use tower_defense_bevy::systems::settings_menu::{GameSettings, PerformanceToggle};
+
+const SETTINGS_V2: &str = include_str!("fixtures/settings_v2.json");
+
+#[test]
+fn test_frame_limits_cycle_through_every_cap() {
+    let mut limit = FrameLimit::default();
+    assert_eq!(limit, FrameLimit::Unlimited, "VSync alone paces frames by default");
+    let mut names = Vec::new();
+    for _ in 0..FrameLimit::ALL.len() {
+        limit = limit.next();
+        names.push(limit.get_name());
+    }
+    assert_eq!(names, vec!["30 FPS", "60 FPS", "120 FPS", "Unlimited"]);
+}
+
+#[test]
+fn test_limiter_waits_out_the_rest_of_the_frame() {
+    let frame = FrameLimit::Fps60.frame_duration().unwrap();
+    assert_eq!(FrameLimit::Fps60.remaining_wait(Duration::ZERO), frame);
+    assert_eq!(FrameLimit::Fps60.remaining_wait(Duration::from_millis(10)), frame - Duration::from_millis(10));
+    assert_eq!(FrameLimit::Fps30.remaining_wait(Duration::from_millis(50)), Duration::ZERO, "slow frames are not held back");
+    assert_eq!(FrameLimit::Unlimited.frame_duration(), None);
+    assert_eq!(FrameLimit::Unlimited.remaining_wait(Duration::ZERO), Duration::ZERO);
+}
+
+#[test]
+fn test_power_saving_throttles_the_unfocused_window() {
+    assert!(matches!(unfocused_update_mode(false), UpdateMode::Continuous));
+    assert!(matches!(
+        unfocused_update_mode(true),
+        UpdateMode::Reactive { wait, .. } if wait == Duration::from_secs_f64(1.0 / POWER_SAVING_FPS)
+    ));
+
+    let mut settings = GameSettings::default();
+    settings.cycle_performance(PerformanceToggle::PowerSaving);
+    let mut world = World::new();
+    world.insert_resource(settings);
+    world.insert_resource(WinitSettings::game());
+    world.run_system_once(apply_power_saving_system).unwrap();
+    assert!(matches!(world.resource::<WinitSettings>().unfocused_mode, UpdateMode::Reactive { .. }));
+    assert!(matches!(world.resource::<WinitSettings>().focused_mode, UpdateMode::Continuous));
+}
+
+#[test]
+fn test_performance_settings_persist() {
+    let older = GameSettings::from_json(SETTINGS_V2).unwrap();
+    assert_eq!(older.frame_limit, FrameLimit::Unlimited);
+    assert!(!older.power_saving);
+
+    let mut settings = GameSettings::default();
+    settings.cycle_performance(PerformanceToggle::FrameLimit);
+    settings.cycle_performance(PerformanceToggle::PowerSaving);
+    assert_eq!(settings.performance_label(PerformanceToggle::FrameLimit), "30 FPS");
+    assert_eq!(settings.performance_label(PerformanceToggle::PowerSaving), "ON");
+
+    let reloaded = GameSettings::from_json(&settings.to_json().unwrap()).unwrap();
+    assert_eq!(reloaded.frame_limit, FrameLimit::Fps30);
+    assert!(reloaded.power_saving);
+}
@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::combat_system::{game_state_system, WaveStatus};
+use tower_defense_bevy::systems::results_screen::*;
+use tower_defense_bevy::systems::tower_ui::TowerSelectionState;
+
+fn entry(waves: u32, score: u32) -> LeaderboardEntry {
+    LeaderboardEntry {
+        waves,
+        score,
+        date: "2026-10-16".to_string(),
+        seed: 7,
+        mode: LeaderboardMode::FreePlay,
+        prestige: PrestigeSet::default(),
+    }
+}
+
+/// World just after the campaign was won on wave 3
+fn won_world() -> World {
+    let mut world = World::new();
+    let mut score = Score::new();
+    score.current = 500;
+    let results = RunResults::capture(RunOutcome::Victory, &score, 3, 0, 7);
+
+    world.insert_resource(WavePlan { current_wave: 3, ..default() });
+    world.insert_resource(WaveRuntime::new());
+    world.insert_resource(score);
+    world.insert_resource(results);
+    world.insert_resource(GameState::Victory);
+    world.insert_resource(WaveStatus { wave_complete: true, ..default() });
+    world.init_resource::<FreePlayRun>();
+    world.init_resource::<TowerSelectionState>();
+    world.init_resource::<Events<StartFreePlayEvent>>();
+    world.spawn(ResultsOverlay);
+    world
+}
+
+#[test]
+fn test_leaderboard_ranks_by_waves_then_score() {
+    let mut leaderboard = RunLeaderboard::default();
+    assert_eq!(leaderboard.record(entry(4, 900)), Some(1));
+    assert_eq!(leaderboard.record(entry(6, 100)), Some(1), "more waves beat more score");
+    assert_eq!(leaderboard.record(entry(4, 1200)), Some(2));
+    assert_eq!(leaderboard.record(entry(4, 900)), Some(4), "ties stay behind the earlier run");
+    assert_eq!(leaderboard.best(LeaderboardMode::FreePlay), Some(&entry(6, 100)));
+
+    for _ in 0..LEADERBOARD_SIZE {
+        leaderboard.record(entry(10, 0));
+    }
+    assert_eq!(leaderboard.entries.len(), LEADERBOARD_SIZE);
+    assert_eq!(leaderboard.record(entry(1, 0)), None, "a full board keeps out weaker runs");
+
+    let json = leaderboard.to_json().unwrap();
+    assert_eq!(RunLeaderboard::from_json(&json).unwrap(), leaderboard);
+}
+
+#[test]
+fn test_free_play_counts_only_what_it_added() {
+    let mut score = Score::new();
+    score.current = 500;
+    let mut run = FreePlayRun::default();
+    run.start(3, &score, None);
+
+    score.current += 250;
+    assert_eq!(run.score_gained(&score), 250);
+    assert_eq!(run.waves_survived(4), 0, "the first free play wave is still being fought");
+    assert_eq!(run.waves_survived(7), 3);
+}
+
+#[test]
+fn test_continuing_hands_the_won_board_back_to_play() {
+    let mut world = won_world();
+    let tower = world.spawn(TowerStats::new(TowerType::Basic)).id();
+    world.send_event(StartFreePlayEvent);
+    world.run_system_once(start_free_play_system).unwrap();
+
+    assert_eq!(*world.resource::<GameState>(), GameState::Playing);
+    assert!(world.get_resource::<RunResults>().is_none(), "the results screen is dismissed");
+    assert!(world.query::<&ResultsOverlay>().iter(&world).next().is_none());
+    assert!(world.get_entity(tower).is_ok(), "towers are kept");
+    assert_eq!(world.resource::<WavePlan>().current_wave, 3, "waves carry on from the campaign");
+
+    let free_play = world.resource::<FreePlayRun>();
+    assert!(free_play.active);
+    assert_eq!(free_play.start_wave, 3);
+    assert_eq!(free_play.start_score, 500);
+    assert_eq!(free_play.campaign.as_ref().map(|results| results.outcome), Some(RunOutcome::Victory));
+
+    // The cleared final wave no longer counts as a win
+    world.run_system_once(game_state_system).unwrap();
+    assert_eq!(*world.resource::<GameState>(), GameState::Playing);
+}
+
+#[test]
+fn test_continuing_needs_a_won_campaign() {
+    let mut world = won_world();
+    world.insert_resource(GameState::GameOver);
+    world.send_event(StartFreePlayEvent);
+    world.run_system_once(start_free_play_system).unwrap();
+
+    assert_eq!(*world.resource::<GameState>(), GameState::GameOver);
+    assert!(!world.resource::<FreePlayRun>().active);
+}
+
+#[test]
+fn test_standing_line_names_rank_and_campaign_wave() {
+    let placed = FreePlayResult { entry: entry(5, 800), rank: Some(2), campaign_wave: 3 };
+    assert_eq!(free_play_standing(&placed), "Free play leaderboard: #2 (campaign won on wave 3)");
+
+    let unplaced = FreePlayResult { rank: None, ..placed };
+    assert!(free_play_standing(&unplaced).contains("not placed"));
+}
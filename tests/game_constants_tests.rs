@@ -0,0 +1,56 @@
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::path_generation::PathGrid;
+use tower_defense_bevy::systems::unified_grid::UnifiedGridSystem;
+
+#[test]
+fn test_grids_are_sized_from_cell_constant() {
+    let unified = UnifiedGridSystem::default();
+    assert_eq!(unified.cell_size, GRID_CELL_SIZE);
+    assert_eq!((unified.grid_width, unified.grid_height), (GRID_WIDTH, GRID_HEIGHT));
+    assert_eq!(unified.grid_area_size(), bevy::math::Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT));
+
+    let path_grid = PathGrid::new_unified();
+    assert_eq!((path_grid.width, path_grid.height), (GRID_WIDTH, GRID_HEIGHT));
+    assert_eq!(path_grid.cell_size, GRID_CELL_SIZE);
+}
+
+#[test]
+fn test_later_layers_override_earlier_ones() {
+    let balance = r#"{ "tower_click_radius": 32.0, "tower_footprint": 36.0 }"#;
+    let theme = r#"{ "tower_click_radius": 48.0, "selection_ring_size": 60.0, "palette": "dusk" }"#;
+
+    let constants = GameConstants::layered([balance, theme]);
+    assert_eq!(constants.tower_footprint, 36.0);
+    assert_eq!(constants.tower_click_radius, 48.0);
+    assert_eq!(constants.selection_ring_size, 60.0);
+
+    // Untouched values keep their defaults
+    assert_eq!(constants.group_selection_ring_size, GROUP_SELECTION_RING_SIZE);
+    assert_eq!(constants.stat_popup_size(), STAT_POPUP_SIZE);
+}
+
+#[test]
+fn test_broken_layer_is_skipped() {
+    let good = r#"{ "tooltip_left": 80.0 }"#;
+    let wrong_type = r#"{ "tooltip_top": "high", "tooltip_stagger": 10.0 }"#;
+
+    let constants = GameConstants::layered([good, "{ not json", wrong_type, "[1, 2]"]);
+    assert_eq!(constants.tooltip_left, 80.0);
+    // A layer that fails applies none of its values
+    assert_eq!(constants.tooltip_top, TOOLTIP_TOP);
+    assert_eq!(constants.tooltip_stagger, TOOLTIP_STAGGER);
+
+    let mut direct = GameConstants::default();
+    assert!(direct.apply_layer(wrong_type).is_err());
+    assert_eq!(direct, GameConstants::default());
+}
+
+#[test]
+fn test_tooltips_stagger_by_tower_type() {
+    let constants = GameConstants::default();
+    let first = constants.tooltip_position(TowerType::Basic);
+    let second = constants.tooltip_position(TowerType::Advanced);
+
+    assert_eq!(first.x, TOOLTIP_LEFT);
+    assert_eq!(second.y - first.y, TOOLTIP_STAGGER);
+}
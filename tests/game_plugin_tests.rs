@@ -0,0 +1,96 @@
+use bevy::audio::AudioSource;
+use bevy::gizmos::GizmoPlugin;
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use tower_defense_bevy::game::*;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::debug_toggle::DebugToggle;
+use tower_defense_bevy::systems::debug_ui::DebugUIState;
+use tower_defense_bevy::systems::path_generation::FixedLevelPath;
+use tower_defense_bevy::systems::stress_test_system::StartStressTestEvent;
+use tower_defense_bevy::systems::ui_feedback::{UiFeedbackEvent, UiSoundHandles};
+
+// Builder configurations are usable in const context
+const EMBEDDED: TowerDefensePlugin = TowerDefensePluginBuilder::new()
+    .without_debug_tools()
+    .without_security()
+    .build();
+
+/// Game app on the engine plugins it needs, without a renderer, window backend or audio device
+fn headless_app(plugin: TowerDefensePlugin) -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin, InputPlugin, WindowPlugin::default(), AssetPlugin::default()))
+        .init_asset::<Shader>() // GizmoPlugin loads its line shaders into this
+        .add_plugins(GizmoPlugin)
+        .init_asset::<AudioSource>()
+        .add_plugins(plugin);
+    app
+}
+
+/// Every combination of the optional subsystems
+fn all_configurations() -> Vec<TowerDefensePlugin> {
+    (0..16u8)
+        .map(|bits| {
+            let mut builder = TowerDefensePluginBuilder::new();
+            if bits & 1 != 0 {
+                builder = builder.without_debug_tools();
+            }
+            if bits & 2 != 0 {
+                builder = builder.without_audio();
+            }
+            if bits & 4 != 0 {
+                builder = builder.without_procedural_generation();
+            }
+            if bits & 8 != 0 {
+                builder = builder.without_security();
+            }
+            builder.build()
+        })
+        .collect()
+}
+
+#[test]
+fn test_builder_defaults_enable_every_subsystem() {
+    assert_eq!(TowerDefensePlugin::default(), TowerDefensePlugin::DEFAULT);
+    assert_eq!(TowerDefensePluginBuilder::default().build(), TowerDefensePlugin::DEFAULT);
+    assert!(!EMBEDDED.debug_tools && !EMBEDDED.security);
+    assert!(EMBEDDED.audio && EMBEDDED.procedural_generation);
+}
+
+#[test]
+fn test_every_subsystem_combination_builds_a_runnable_app() {
+    let configurations = all_configurations();
+    assert_eq!(configurations.len(), 16);
+
+    for plugin in configurations {
+        let mut app = headless_app(plugin);
+        app.update();
+        app.update();
+
+        let world = app.world();
+        assert!(world.contains_resource::<WavePlan>(), "{:?}", plugin);
+        assert!(world.contains_resource::<EnemyPath>(), "{:?}", plugin);
+        assert!(world.contains_resource::<Events<UiFeedbackEvent>>(), "{:?}", plugin);
+        assert_eq!(world.contains_resource::<DebugUIState>(), plugin.debug_tools, "{:?}", plugin);
+        assert_eq!(world.contains_resource::<Events<StartStressTestEvent>>(), plugin.debug_tools, "{:?}", plugin);
+        assert_eq!(world.contains_resource::<UiSoundHandles>(), plugin.audio, "{:?}", plugin);
+        assert_eq!(world.contains_resource::<FixedLevelPath>(), !plugin.procedural_generation, "{:?}", plugin);
+        assert_eq!(world.contains_resource::<DebugToggle>(), plugin.security, "{:?}", plugin);
+    }
+}
+
+#[test]
+fn test_supplied_path_is_used_without_procedural_generation() {
+    let waypoints = vec![Vec2::new(-300.0, 100.0), Vec2::new(0.0, -100.0), Vec2::new(300.0, 100.0)];
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin, InputPlugin, WindowPlugin::default(), AssetPlugin::default()))
+        .init_asset::<Shader>() // GizmoPlugin loads its line shaders into this
+        .add_plugins(GizmoPlugin)
+        .insert_resource(EnemyPath::new(waypoints.clone()))
+        .add_plugins(TowerDefensePluginBuilder::new().without_audio().without_procedural_generation().build());
+    app.update();
+    app.update();
+
+    assert_eq!(app.world().resource::<EnemyPath>().waypoints, waypoints);
+}
@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::game_snapshot::*;
+use tower_defense_bevy::systems::smart_enemy_system::SmartEnemy;
+
+fn game_world() -> World {
+    let mut world = World::new();
+    world.insert_resource(Economy::new(120, 4, 3, 20));
+    world.insert_resource(Score::new());
+    world.insert_resource(WavePlan::default());
+    world.insert_resource(WaveRuntime::new());
+    world.insert_resource(GameState::Playing);
+    world.insert_resource(EnemyPath::new(vec![Vec2::new(-400.0, 0.0), Vec2::new(0.0, 100.0), Vec2::new(400.0, 0.0)]));
+    world.spawn((Base, Health::new(250.0)));
+    world
+}
+
+#[test]
+fn test_snapshot_of_an_empty_world_is_default() {
+    assert_eq!(GameSnapshot::capture(&World::new()), GameSnapshot::default());
+}
+
+#[test]
+fn test_snapshot_captures_resources_towers_and_enemies() {
+    let mut world = game_world();
+    world.resource_scope(|world, mut wave_plan: Mut<WavePlan>| start_wave(&mut wave_plan, &mut world.resource_mut::<WaveRuntime>(), 6));
+    world.resource_mut::<Score>().enemies_killed = 2;
+
+    let mut stats = TowerStats::new(TowerType::Laser);
+    stats.upgrade();
+    let tower = world.spawn((stats.clone(), Transform::from_xyz(40.0, -80.0, 0.0))).id();
+
+    let mut health = Health::new(30.0);
+    health.take_damage(12.0);
+    let enemy = world
+        .spawn((Enemy::default(), health, PathProgress { current: 0.25 }, Transform::from_xyz(-10.0, 5.0, 1.0)))
+        .id();
+    let smart = world.spawn((Enemy::default(), SmartEnemy, Transform::from_xyz(0.0, 0.0, 1.0))).id();
+
+    let snapshot = GameSnapshot::capture(&world);
+    assert_eq!(snapshot.game_state, "Playing");
+    assert_eq!(snapshot.wave, WaveSnapshot { current: 1, enemies_spawned: 0, enemies_in_wave: 6, complete: false });
+    assert_eq!(snapshot.economy, EconomySnapshot { money: 120, research_points: 4, materials: 3, energy: 20 });
+    assert_eq!(snapshot.score.enemies_killed, 2);
+    assert_eq!(snapshot.base, Some(HealthSnapshot { current: 250.0, max: 250.0 }));
+    assert_eq!(snapshot.path, vec![[-400.0, 0.0], [0.0, 100.0], [400.0, 0.0]]);
+
+    assert_eq!(snapshot.towers.len(), 1);
+    let tower_state = &snapshot.towers[0];
+    assert_eq!(tower_state.entity, tower.to_bits());
+    assert_eq!(tower_state.tower_type, TowerType::Laser);
+    assert_eq!(tower_state.position, [40.0, -80.0]);
+    assert_eq!(tower_state.upgrade_level, stats.upgrade_level);
+    assert_eq!(tower_state.damage, stats.damage);
+
+    assert_eq!(snapshot.enemies.len(), 2);
+    let walker = snapshot.enemies.iter().find(|state| state.entity == enemy.to_bits()).unwrap();
+    assert_eq!(walker.position, [-10.0, 5.0]);
+    assert_eq!(walker.health, Some(HealthSnapshot { current: 18.0, max: 30.0 }));
+    assert_eq!(walker.progress, 0.25);
+    assert!(!walker.smart);
+    let smart_state = snapshot.enemies.iter().find(|state| state.entity == smart.to_bits()).unwrap();
+    assert!(smart_state.smart);
+    assert_eq!(smart_state.health, None);
+}
+
+#[test]
+fn test_snapshot_round_trips_through_json() {
+    let mut world = game_world();
+    world.spawn((TowerStats::new(TowerType::Basic), Transform::from_xyz(1.0, 2.0, 0.0)));
+    world.spawn((Enemy::default(), Health::new(10.0), Transform::default()));
+
+    let snapshot = GameSnapshot::capture(&world);
+    let json = serde_json::to_string(&snapshot).unwrap();
+    assert_eq!(serde_json::from_str::<GameSnapshot>(&json).unwrap(), snapshot);
+}
+
+#[test]
+fn test_remote_method_answers_with_the_snapshot() {
+    let mut world = game_world();
+    world.spawn((TowerStats::new(TowerType::Tesla), Transform::default()));
+
+    let expected = serde_json::to_value(GameSnapshot::capture(&world)).unwrap();
+    let response = world.run_system_once_with(process_snapshot_request, None).unwrap().unwrap();
+    assert_eq!(response, expected);
+    assert_eq!(response["towers"][0]["tower_type"], "Tesla");
+}
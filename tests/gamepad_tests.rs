@@ -0,0 +1,81 @@
+use bevy::prelude::*;
+use std::sync::Arc;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::enemy_system::StartWaveEvent;
+use tower_defense_bevy::systems::input::gamepad::process_gamepad_input;
+use tower_defense_bevy::systems::input::{
+    stick_direction, InputMappingRegistry, StartWaveHandler, StickRepeat, START_WAVE_KEY,
+};
+use tower_defense_bevy::systems::ui_feedback::UiFeedbackEvent;
+
+fn gamepad_world() -> World {
+    let mut world = World::new();
+    world.insert_resource(State::new(AppState::Playing));
+    world.init_resource::<WavePlan>();
+    world.init_resource::<WaveRuntime>();
+    world.init_resource::<Events<StartWaveEvent>>();
+    world.init_resource::<Events<UiFeedbackEvent>>();
+    let mut registry = InputMappingRegistry::new();
+    registry.register_handler(Arc::new(StartWaveHandler)).unwrap();
+    world.insert_resource(registry);
+    world
+}
+
+fn press_north(world: &mut World) {
+    let mut gamepad = Gamepad::default();
+    gamepad.digital_mut().press(GamepadButton::North);
+    world.spawn(gamepad);
+    process_gamepad_input(world);
+}
+
+fn waves_started(world: &mut World) -> usize {
+    world.resource_mut::<Events<StartWaveEvent>>().drain().count()
+}
+
+#[test]
+fn test_stick_direction_ignores_the_deadzone() {
+    assert_eq!(stick_direction(Vec2::new(0.2, -0.3)), IVec2::ZERO);
+    assert_eq!(stick_direction(Vec2::new(0.9, 0.1)), IVec2::new(1, 0));
+    assert_eq!(stick_direction(Vec2::new(-0.7, 0.7)), IVec2::new(-1, 1));
+}
+
+#[test]
+fn test_held_stick_steps_once_then_repeats() {
+    let mut repeat = StickRepeat::default();
+    let right = IVec2::new(1, 0);
+
+    assert_eq!(repeat.update(right, 0.016), right, "pushing the stick steps at once");
+    assert_eq!(repeat.update(right, 0.1), IVec2::ZERO);
+    assert_eq!(repeat.update(right, 0.3), right, "repeats after the delay");
+    assert_eq!(repeat.update(right, 0.05), IVec2::ZERO);
+    assert_eq!(repeat.update(right, 0.1), right);
+
+    assert_eq!(repeat.update(IVec2::ZERO, 0.016), IVec2::ZERO);
+    assert_eq!(repeat.update(right, 0.016), right, "letting go and pushing again steps at once");
+}
+
+#[test]
+fn test_north_button_starts_the_wave_through_the_registry() {
+    let mut world = gamepad_world();
+    press_north(&mut world);
+    assert_eq!(waves_started(&mut world), 1);
+}
+
+#[test]
+fn test_gamepad_follows_rebound_handler_key() {
+    let mut world = gamepad_world();
+    world
+        .resource_mut::<InputMappingRegistry>()
+        .rebind("start_wave", START_WAVE_KEY, KeyCode::KeyB)
+        .unwrap();
+    press_north(&mut world);
+    assert_eq!(waves_started(&mut world), 1);
+}
+
+#[test]
+fn test_start_wave_ignored_outside_play() {
+    let mut world = gamepad_world();
+    world.insert_resource(State::new(AppState::MainMenu));
+    press_north(&mut world);
+    assert_eq!(waves_started(&mut world), 0);
+}
@@ -0,0 +1,149 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use std::time::Duration;
+use tower_defense_bevy::components::{Base, EffectCategory, Enemy, Health};
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::enemy_system::{auto_start_wave_system, StartWaveEvent, AUTO_START_DELAY};
+use tower_defense_bevy::systems::screen_shake::{base_hit_shake_system, shake_offset, ScreenShake};
+use tower_defense_bevy::systems::settings_menu::{
+    apply_effect_settings_system, GameSettings, GameplayOption, AUTOSAVE_INTERVALS,
+};
+
+const SETTINGS_V2: &str = include_str!("fixtures/settings_v2.json");
+
+#[test]
+fn test_gameplay_options_default_and_load_from_older_files() {
+    let settings = GameSettings::default();
+    assert_eq!(settings.autosave_interval_secs, 60);
+    assert!(settings.confirm_before_sell);
+    assert!(!settings.auto_start_waves);
+    assert!(settings.assist_overlay_enabled);
+    assert!(settings.damage_numbers_enabled);
+    assert_eq!(settings.screen_shake_intensity, 1.0);
+
+    let loaded = GameSettings::from_json(SETTINGS_V2).unwrap();
+    assert_eq!(loaded.autosave_interval_secs, settings.autosave_interval_secs);
+    assert_eq!(loaded.confirm_before_sell, settings.confirm_before_sell);
+    assert_eq!(loaded.screen_shake_intensity, settings.screen_shake_intensity);
+}
+
+#[test]
+fn test_gameplay_options_cycle_and_label() {
+    let mut settings = GameSettings::default();
+    assert_eq!(settings.gameplay_option_label(GameplayOption::AutosaveInterval), "1 MIN");
+
+    // Autosave steps through every interval and wraps around to off
+    let mut seen = vec![settings.autosave_interval_secs];
+    for _ in 1..AUTOSAVE_INTERVALS.len() {
+        settings.cycle_gameplay_option(GameplayOption::AutosaveInterval);
+        seen.push(settings.autosave_interval_secs);
+    }
+    seen.sort();
+    assert_eq!(seen, AUTOSAVE_INTERVALS.to_vec());
+    settings.autosave_interval_secs = 0;
+    assert_eq!(settings.gameplay_option_label(GameplayOption::AutosaveInterval), "OFF");
+    settings.autosave_interval_secs = 30;
+    assert_eq!(settings.gameplay_option_label(GameplayOption::AutosaveInterval), "30s");
+
+    settings.cycle_gameplay_option(GameplayOption::ConfirmSell);
+    assert!(!settings.confirm_before_sell);
+    assert_eq!(settings.gameplay_option_label(GameplayOption::ConfirmSell), "OFF");
+
+    settings.cycle_gameplay_option(GameplayOption::ScreenShake);
+    assert_eq!(settings.gameplay_option_label(GameplayOption::ScreenShake), "OFF");
+    settings.cycle_gameplay_option(GameplayOption::ScreenShake);
+    assert_eq!(settings.gameplay_option_label(GameplayOption::ScreenShake), "50%");
+}
+
+#[test]
+fn test_damage_numbers_setting_caps_the_effect_budget() {
+    let mut world = World::new();
+    let mut settings = GameSettings::default();
+    settings.damage_numbers_enabled = false;
+    world.insert_resource(settings);
+    world.init_resource::<EffectBudget>();
+    world.run_system_once(apply_effect_settings_system).unwrap();
+
+    let mut budget = world.resource_mut::<EffectBudget>();
+    assert_eq!(budget.cap(EffectCategory::DamageNumber), 0);
+    assert!(!budget.admit(EffectCategory::DamageNumber));
+    assert!(budget.admit(EffectCategory::Particle), "other effects are untouched");
+
+    world.resource_mut::<GameSettings>().damage_numbers_enabled = true;
+    world.run_system_once(apply_effect_settings_system).unwrap();
+    assert_eq!(
+        world.resource::<EffectBudget>().cap(EffectCategory::DamageNumber),
+        EffectBudget::default().cap(EffectCategory::DamageNumber)
+    );
+}
+
+fn auto_start_world(auto_start: bool) -> World {
+    let mut world = World::new();
+    let mut settings = GameSettings::default();
+    settings.auto_start_waves = auto_start;
+    world.insert_resource(settings);
+    world.insert_resource(GameState::Playing);
+    world.insert_resource(Time::<()>::default());
+    world.init_resource::<Events<StartWaveEvent>>();
+
+    // Wave 1 has finished spawning
+    let mut wave_plan = WavePlan::default();
+    let mut wave_runtime = WaveRuntime::new();
+    wave_plan.current_wave = 1;
+    wave_runtime.enemies_spawned = wave_plan.enemies_in_wave();
+    world.insert_resource(wave_plan);
+    world.insert_resource(wave_runtime);
+    world
+}
+
+/// Advance time and run the auto-start system, which keeps its countdown between runs
+fn run_auto_start_for(world: &mut World, system: bevy::ecs::system::SystemId, seconds: f32) -> usize {
+    world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(seconds));
+    world.run_system(system).unwrap();
+    world.resource::<Events<StartWaveEvent>>().len()
+}
+
+#[test]
+fn test_waves_auto_start_once_the_board_is_clear() {
+    let mut world = auto_start_world(true);
+    let system = world.register_system(auto_start_wave_system);
+    assert_eq!(run_auto_start_for(&mut world, system, AUTO_START_DELAY * 0.5), 0);
+
+    // An enemy still on the board holds the countdown back
+    let straggler = world.spawn(Enemy::default()).id();
+    assert_eq!(run_auto_start_for(&mut world, system, AUTO_START_DELAY), 0);
+    world.despawn(straggler);
+
+    assert_eq!(run_auto_start_for(&mut world, system, AUTO_START_DELAY * 0.5), 0);
+    assert_eq!(run_auto_start_for(&mut world, system, AUTO_START_DELAY * 0.6), 1);
+}
+
+#[test]
+fn test_waves_wait_for_the_player_without_auto_start() {
+    let mut world = auto_start_world(false);
+    let system = world.register_system(auto_start_wave_system);
+    assert_eq!(run_auto_start_for(&mut world, system, AUTO_START_DELAY * 2.0), 0);
+}
+
+#[test]
+fn test_screen_shake_scales_with_intensity() {
+    assert_eq!(shake_offset(1.0, 0.0, 0.37), Vec2::ZERO, "intensity 0 turns shake off");
+    assert_eq!(shake_offset(0.0, 1.0, 0.37), Vec2::ZERO);
+    let full = shake_offset(1.0, 1.0, 0.37).length();
+    let half = shake_offset(1.0, 0.5, 0.37).length();
+    assert!((half - full * 0.5).abs() < 1e-4);
+}
+
+#[test]
+fn test_base_hits_add_trauma() {
+    let mut world = World::new();
+    world.init_resource::<ScreenShake>();
+    let base = world.spawn((Base, Health::new(100.0))).id();
+    let system = world.register_system(base_hit_shake_system);
+    world.run_system(system).unwrap();
+    assert_eq!(world.resource::<ScreenShake>().trauma, 0.0, "spawning the base is not a hit");
+
+    world.get_mut::<Health>(base).unwrap().take_damage(10.0);
+    world.run_system(system).unwrap();
+    assert!(world.resource::<ScreenShake>().trauma > 0.0);
+}
@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+use proptest::prelude::*;
+use tower_defense_bevy::systems::path_generation::*;
+
+#[test]
+fn test_try_new_rejects_zero_dimensions() {
+    assert_eq!(
+        PathGrid::try_new(0, 18).unwrap_err(),
+        GridError::InvalidDimensions { width: 0, height: 18 }
+    );
+    assert!(PathGrid::try_new(32, 0).is_err());
+    assert!(PathGrid::try_new(32, 18).is_ok());
+}
+
+#[test]
+fn test_out_of_bounds_errors_are_typed() {
+    let mut grid = PathGrid::new_unified();
+    let outside = GridPos::new(32, 5);
+    let expected = GridError::OutOfBounds { pos: outside, width: 32, height: 18 };
+
+    assert_eq!(grid.try_get_cell(outside), Err(expected));
+    assert_eq!(grid.try_set_cell(outside, CellType::Blocked), Err(expected));
+    assert_eq!(grid.try_grid_to_world(outside), Err(expected));
+    assert!(grid.try_world_to_grid(Vec2::new(10_000.0, 0.0)).is_err());
+    assert!(grid.try_world_to_grid(Vec2::new(f32::NAN, 0.0)).is_err());
+    assert_eq!(grid.try_to_enemy_path(&[]).unwrap_err(), GridError::EmptyPath);
+}
+
+#[test]
+fn test_undersized_grid_falls_back_to_direct_route() {
+    let grid = PathGrid::new(6, 4);
+    assert!(validate_generation_grid(&grid).is_err());
+    assert!(try_generate_random_strategic_path(1, &grid).is_err());
+
+    let path = generate_random_strategic_path(1, &grid);
+    assert_eq!(path.first(), Some(&grid.entry_point));
+    assert_eq!(path.last(), Some(&grid.exit_point));
+}
+
+proptest! {
+    #[test]
+    fn prop_cell_access_never_panics(x in any::<usize>(), y in any::<usize>()) {
+        let mut grid = PathGrid::new_unified();
+        let pos = GridPos::new(x, y);
+        let inside = x < grid.width && y < grid.height;
+
+        prop_assert_eq!(grid.try_get_cell(pos).is_ok(), inside);
+        prop_assert_eq!(grid.get_cell(pos).is_some(), inside);
+        prop_assert_eq!(grid.try_set_cell(pos, CellType::Blocked).is_ok(), inside);
+        prop_assert_eq!(grid.try_grid_to_world(pos).is_ok(), inside);
+    }
+
+    #[test]
+    fn prop_world_round_trip(x in 0_usize..32, y in 0_usize..18) {
+        let grid = PathGrid::new_unified();
+        let pos = GridPos::new(x, y);
+        let world_pos = grid.try_grid_to_world(pos).unwrap();
+        prop_assert_eq!(grid.try_world_to_grid(world_pos), Ok(pos));
+    }
+
+    #[test]
+    fn prop_world_to_grid_never_panics(x in any::<f32>(), y in any::<f32>()) {
+        let grid = PathGrid::new_unified();
+        if let Ok(pos) = grid.try_world_to_grid(Vec2::new(x, y)) {
+            prop_assert!(grid.contains(pos));
+        }
+    }
+
+    #[test]
+    fn prop_generation_never_panics(
+        width in 0_usize..40,
+        height in 0_usize..24,
+        entry in (0_usize..48, 0_usize..32),
+        exit in (0_usize..48, 0_usize..32),
+        seed in any::<u64>(),
+    ) {
+        let mut grid = PathGrid::new(width, height);
+        grid.entry_point = GridPos::new(entry.0, entry.1);
+        grid.exit_point = GridPos::new(exit.0, exit.1);
+
+        let path = generate_random_strategic_path(seed, &grid);
+        prop_assert!(path.iter().all(|&pos| grid.contains(pos)));
+
+        if let Ok(enemy_path) = grid.try_to_enemy_path(&path) {
+            prop_assert_eq!(enemy_path.waypoints.len(), path.len());
+        }
+    }
+}
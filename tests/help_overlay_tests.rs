@@ -0,0 +1,61 @@
+use std::sync::Arc;
+use bevy::prelude::*;
+use tower_defense_bevy::systems::help_overlay::*;
+use tower_defense_bevy::systems::input::*;
+
+fn registry_with_help() -> InputMappingRegistry {
+    let mut registry = InputMappingRegistry::new();
+    for handler in create_standard_fkey_handlers() {
+        registry.register_handler(handler).unwrap();
+    }
+    registry.register_handler(Arc::new(HelpOverlayHandler)).unwrap();
+    registry
+}
+
+#[test]
+fn test_key_names_are_short() {
+    assert_eq!(key_name(KeyCode::F9), "F9");
+    assert_eq!(key_name(KeyCode::KeyO), "O");
+    assert_eq!(key_name(KeyCode::Digit3), "3");
+    assert_eq!(key_name(KeyCode::Escape), "Esc");
+}
+
+#[test]
+fn test_describe_key_prefers_handlers_over_hints() {
+    let mut registry = registry_with_help();
+    assert_eq!(registry.describe_key(KeyCode::KeyO), None);
+
+    registry.register_hint(KeyCode::KeyO, "Overclock", InputContext::Game);
+    registry.register_hint(KeyCode::KeyO, "Overclock the selected tower", InputContext::Game);
+    assert_eq!(registry.get_hints().len(), 1, "re-registering a hint replaces it");
+    assert_eq!(registry.describe_key(KeyCode::KeyO).as_deref(), Some("Overclock the selected tower"));
+
+    registry.register_hint(KeyCode::F9, "Stale text", InputContext::Admin);
+    assert_eq!(registry.describe_key(KeyCode::F9).as_deref(), Some("Toggle cheat menu visibility"));
+}
+
+#[test]
+fn test_callouts_list_registered_hotkeys() {
+    let mut registry = registry_with_help();
+    let hud = callout_text(HelpRegion::Hud, &registry);
+    assert!(hud.starts_with("HUD"));
+    assert!(hud.contains("[H] Toggle this help overlay"));
+    assert!(hud.contains("[F9] Toggle cheat menu visibility"));
+    assert!(!hud.contains("[Esc]"), "unregistered keys are left out");
+
+    registry.register_hint(KeyCode::Escape, "Pause menu", InputContext::System);
+    assert!(callout_text(HelpRegion::Hud, &registry).contains("[Esc] Pause menu"));
+    assert!(!callout_text(HelpRegion::Resources, &registry).contains('['));
+}
+
+#[test]
+fn test_help_key_toggles_overlay() {
+    let mut world = World::new();
+    world.init_resource::<HelpOverlayState>();
+    let registry = registry_with_help();
+
+    assert!(registry.process_input(&mut world, HELP_KEY));
+    assert!(world.resource::<HelpOverlayState>().visible);
+    assert!(registry.process_input(&mut world, HELP_KEY));
+    assert!(!world.resource::<HelpOverlayState>().visible);
+}
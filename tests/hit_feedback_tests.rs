@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::combat_system::{collision_system, DamageKind, EnemyDamagedEvent, EnemyKilledEvent, WaveStatus};
+use tower_defense_bevy::systems::hit_feedback_system::*;
+use tower_defense_bevy::systems::tween::ColorTween;
+
+const ENEMY_COLOR: Color = Color::srgb(1.0, 0.2, 0.2);
+
+/// World with a straight 1000px path
+fn feedback_world() -> World {
+    let mut world = World::new();
+    world.insert_resource(EnemyPath::new(vec![Vec2::new(-500.0, 0.0), Vec2::new(500.0, 0.0)]));
+    world.init_resource::<SimulationClock>();
+    world.init_resource::<Events<EnemyDamagedEvent>>();
+    world
+}
+
+fn spawn_enemy(world: &mut World, max_health: f32) -> Entity {
+    world
+        .spawn((
+            Enemy::default(),
+            Health::new(max_health),
+            PathProgress { current: 0.5 },
+            KnockbackLimiter::default(),
+            Sprite::from_color(ENEMY_COLOR, Vec2::splat(20.0)),
+        ))
+        .id()
+}
+
+fn hit(world: &mut World, enemy: Entity, tower_type: TowerType) {
+    world.send_event(EnemyDamagedEvent { enemy, position: Vec2::ZERO, amount: 10.0, tower_type, kind: DamageKind::Direct });
+    world.run_system_once(hit_feedback_system).unwrap();
+    // A fresh reader each run, so drop the hit once it has been read
+    world.resource_mut::<Events<EnemyDamagedEvent>>().clear();
+}
+
+fn progress(world: &World, enemy: Entity) -> f32 {
+    world.get::<PathProgress>(enemy).unwrap().current
+}
+
+#[test]
+fn test_knockback_is_capped_per_second() {
+    let mut limiter = KnockbackLimiter::default();
+    assert_eq!(limiter.allow(12.0, 24.0, 0.0), 12.0);
+    assert_eq!(limiter.allow(12.0, 24.0, 0.5), 12.0);
+    assert_eq!(limiter.allow(12.0, 24.0, 0.9), 0.0, "the cap is spent for this second");
+    assert_eq!(limiter.allow(12.0, 24.0, 1.0), 12.0, "a new second starts a new window");
+}
+
+#[test]
+fn test_every_hit_flashes_the_enemy_white() {
+    let mut world = feedback_world();
+    let enemy = spawn_enemy(&mut world, 300.0);
+    hit(&mut world, enemy, TowerType::Basic);
+
+    let flash = world.get::<ColorTween>(enemy).expect("hit flash");
+    assert_eq!(flash.from, Color::WHITE);
+    assert_eq!(flash.to, ENEMY_COLOR, "fades back to the enemy's own colour");
+    assert_eq!(progress(&world, enemy), 0.5, "a basic shot doesn't push");
+}
+
+#[test]
+fn test_missiles_push_light_enemies_back_along_the_path() {
+    let mut world = feedback_world();
+    let light = spawn_enemy(&mut world, 100.0);
+    let heavy = spawn_enemy(&mut world, LIGHT_ENEMY_MAX_HEALTH + 1.0);
+
+    hit(&mut world, light, TowerType::Missile);
+    hit(&mut world, heavy, TowerType::Missile);
+    let pushed = 0.5 - KNOCKBACK_DISTANCE / 1000.0;
+    assert!((progress(&world, light) - pushed).abs() < 1e-5);
+    assert_eq!(progress(&world, heavy), 0.5, "heavy enemies shrug missiles off");
+
+    for _ in 0..5 {
+        hit(&mut world, light, TowerType::Missile);
+    }
+    let capped = 0.5 - MAX_KNOCKBACK_PER_SECOND / 1000.0;
+    assert!((progress(&world, light) - capped).abs() < 1e-5, "no more than the per-second cap");
+}
+
+#[test]
+fn test_collisions_report_the_damage_dealt() {
+    let mut world = feedback_world();
+    world.init_resource::<Economy>();
+    world.init_resource::<Score>();
+    world.init_resource::<WaveStatus>();
+    world.init_resource::<Events<EnemyKilledEvent>>();
+    let enemy = world.spawn((Enemy::default(), Health::new(30.0), Transform::default())).id();
+    world.spawn((Projectile::new(50.0, 300.0, enemy, Vec2::ZERO, TowerType::Missile), Transform::default()));
+
+    world.run_system_once(collision_system).unwrap();
+    let events: Vec<EnemyDamagedEvent> = world.resource_mut::<Events<EnemyDamagedEvent>>().drain().collect();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].enemy, enemy);
+    assert_eq!(events[0].amount, 30.0, "overkill is not counted");
+    assert_eq!(events[0].tower_type, TowerType::Missile);
+
+    // The enemy died, so there is nothing left to flash
+    world.send_event(events[0]);
+    world.run_system_once(hit_feedback_system).unwrap();
+}
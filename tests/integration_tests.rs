@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use tower_defense_bevy::{components::*, resources::*, systems::*};
-use tower_defense_bevy::systems::combat_system::WaveStatus;
+use tower_defense_bevy::systems::combat_system::{EnemyDamagedEvent, EnemyKilledEvent, WaveStatus};
+use tower_defense_bevy::systems::enemy_system::EnemyEscapedEvent;
 use bevy::ecs::system::RunSystemOnce;
 use std::time::Duration;
 
@@ -11,7 +12,8 @@ fn create_test_world() -> World {
     // Initialize resources
     world.insert_resource(Economy::default());
     world.insert_resource(Score::default());
-    world.insert_resource(WaveManager::new());
+    world.insert_resource(WavePlan::default());
+    world.insert_resource(WaveRuntime::new());
     world.insert_resource(EnemyPath::new(vec![
         Vec2::new(50.0, 100.0),
         Vec2::new(200.0, 100.0),
@@ -19,9 +21,14 @@ fn create_test_world() -> World {
         Vec2::new(400.0, 200.0),
     ]));
     world.insert_resource(Time::<()>::default());
+    world.insert_resource(SimulationClock::default());
     
     // Add WaveStatus resource needed by collision system
     world.insert_resource(WaveStatus::default());
+    world.init_resource::<Events<EnemyKilledEvent>>();
+    world.init_resource::<Events<TowerFiredEvent>>();
+    world.init_resource::<Events<EnemyDamagedEvent>>();
+    world.init_resource::<Events<EnemyEscapedEvent>>();
     
     world
 }
@@ -122,35 +129,34 @@ fn test_complete_combat_cycle() {
 fn test_wave_progression_cycle() {
     let mut world = create_test_world();
     
-    // Start with wave 0 (WaveManager starts at 0)
-    let initial_wave = world.resource::<WaveManager>().current_wave;
+    // Start with wave 0 (WavePlan starts at 0)
+    let initial_wave = world.resource::<WavePlan>().current_wave;
     assert_eq!(initial_wave, 0, "Should start at wave 0");
     
     // Start a new wave with specific enemy count
-    world.resource_mut::<WaveManager>().start_wave(2);
+    world.resource_scope(|world, mut wave_plan: Mut<WavePlan>| start_wave(&mut wave_plan, &mut world.resource_mut::<WaveRuntime>(), 2));
     
     // Advance time and tick the spawn timer to allow spawning
     advance_time(&mut world, 1.2); // More than spawn interval
-    world.resource_mut::<WaveManager>().spawn_timer.tick(std::time::Duration::from_secs_f32(1.2));
+    world.resource_mut::<WaveRuntime>().spawn_timer.tick(std::time::Duration::from_secs_f32(1.2));
     
     // Try to spawn enemies
     let _ = world.run_system_once(enemy_spawning_system);
     
     let _enemy_count_after_spawn = world.query::<&Enemy>().iter(&world).count();
     // Note: enemy spawning might not work in isolated test, so we'll test the wave manager state instead
-    let wave_manager = world.resource::<WaveManager>();
-    assert_eq!(wave_manager.current_wave, 1, "Wave should have started");
+    assert_eq!(world.resource::<WavePlan>().current_wave, 1, "Wave should have started");
     
     // Manually simulate all enemies being spawned and defeated
     // Set enemies spawned to match enemies in wave to simulate completion
-    world.resource_mut::<WaveManager>().enemies_spawned = world.resource::<WaveManager>().enemies_in_wave;
+    world.resource_mut::<WaveRuntime>().enemies_spawned = world.resource::<WavePlan>().enemies_in_wave();
     
     // Run game state system
     let _ = world.run_system_once(game_state_system);
     
     // Check wave state - wave manager should handle the completion
-    let current_wave = world.resource::<WaveManager>().current_wave;
-    let wave_complete = world.resource::<WaveManager>().wave_complete();
+    let current_wave = world.resource::<WavePlan>().current_wave;
+    let wave_complete = world.resource::<WaveRuntime>().wave_complete(world.resource::<WavePlan>());
     assert!(wave_complete, "Wave should be marked as complete when all enemies spawned");
     assert!(current_wave >= initial_wave, "Wave system should handle wave completion");
 }
@@ -324,8 +330,8 @@ fn test_all_enemies_defeated_scenario() {
     let mut world = create_test_world();
     
     // Set up a scenario where all enemies are defeated (start a wave first)
-    world.resource_mut::<WaveManager>().start_wave(3);
-    world.resource_mut::<WaveManager>().enemies_spawned = 3;
+    world.resource_scope(|world, mut wave_plan: Mut<WavePlan>| start_wave(&mut wave_plan, &mut world.resource_mut::<WaveRuntime>(), 3));
+    world.resource_mut::<WaveRuntime>().enemies_spawned = 3;
     
     // Don't spawn any actual enemies (simulating all defeated)
     let enemy_count = world.query::<&Enemy>().iter(&world).count();
@@ -335,10 +341,10 @@ fn test_all_enemies_defeated_scenario() {
     let _ = world.run_system_once(game_state_system);
     
     // Wave system should handle the empty enemy scenario gracefully
-    let wave_manager = world.resource::<WaveManager>();
+    let (wave_plan, wave_runtime) = (world.resource::<WavePlan>(), world.resource::<WaveRuntime>());
     // The wave should be marked complete and current_wave should be valid
-    assert!(wave_manager.wave_complete(), "Wave should be complete when all enemies spawned/defeated");
-    assert!(wave_manager.current_wave >= 1, "Wave number should remain valid");
+    assert!(wave_runtime.wave_complete(wave_plan), "Wave should be complete when all enemies spawned/defeated");
+    assert!(wave_plan.current_wave >= 1, "Wave number should remain valid");
 }
 
 /// Integration test for tower upgrade cycle
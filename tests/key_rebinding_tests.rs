@@ -0,0 +1,152 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use std::sync::Arc;
+use tower_defense_bevy::systems::controls_menu::{conflict_note, key_capture_system, saved_rebinds};
+use tower_defense_bevy::systems::input::{
+    parse_key_code, InputContext, InputHandler, InputMappingRegistry, KeyCapture, KeyRebind,
+};
+use tower_defense_bevy::systems::settings_menu::GameSettings;
+
+#[derive(Resource, Default)]
+struct Presses(Vec<KeyCode>);
+
+struct TestHandler {
+    id: &'static str,
+    key: KeyCode,
+}
+
+impl InputHandler for TestHandler {
+    fn handle_input(&self, world: &mut World, key: KeyCode) -> bool {
+        world.resource_mut::<Presses>().0.push(key);
+        true
+    }
+
+    fn get_description(&self) -> &str {
+        "Test action"
+    }
+
+    fn get_id(&self) -> &str {
+        self.id
+    }
+
+    fn handles_key(&self, key: KeyCode) -> bool {
+        key == self.key
+    }
+
+    fn get_handled_keys(&self) -> Vec<KeyCode> {
+        vec![self.key]
+    }
+
+    fn get_context(&self) -> InputContext {
+        InputContext::Debug
+    }
+}
+
+fn registry_with(handlers: &[(&'static str, KeyCode)]) -> InputMappingRegistry {
+    let mut registry = InputMappingRegistry::new();
+    for &(id, key) in handlers {
+        registry.register_handler(Arc::new(TestHandler { id, key })).unwrap();
+    }
+    registry
+}
+
+#[test]
+fn test_rebound_handler_fires_on_new_key_only() {
+    let mut registry = registry_with(&[("debug", KeyCode::F1)]);
+    registry.rebind("debug", KeyCode::F1, KeyCode::KeyG).unwrap();
+
+    let mut world = World::new();
+    world.init_resource::<Presses>();
+    assert!(!registry.process_input(&mut world, KeyCode::F1));
+    assert!(registry.process_input(&mut world, KeyCode::KeyG));
+
+    // The handler still sees the key it was written for
+    assert_eq!(world.resource::<Presses>().0, vec![KeyCode::F1]);
+    assert_eq!(registry.get_key_bindings()[0].key, KeyCode::KeyG);
+}
+
+#[test]
+fn test_rebinding_to_original_key_removes_rebind() {
+    let mut registry = registry_with(&[("debug", KeyCode::F1)]);
+    registry.rebind("debug", KeyCode::F1, KeyCode::KeyG).unwrap();
+    registry.rebind("debug", KeyCode::F1, KeyCode::F1).unwrap();
+
+    assert!(registry.get_rebinds().is_empty());
+    assert!(registry.rebind("missing", KeyCode::F1, KeyCode::KeyG).is_err());
+    assert!(registry.rebind("debug", KeyCode::F2, KeyCode::KeyG).is_err());
+}
+
+#[test]
+fn test_rebinding_onto_used_key_reports_conflict() {
+    let mut registry = registry_with(&[("debug", KeyCode::F1), ("grid", KeyCode::F3)]);
+    registry.rebind("debug", KeyCode::F1, KeyCode::F3).unwrap();
+
+    let binding = registry
+        .get_key_bindings()
+        .into_iter()
+        .find(|binding| binding.handler_id == "debug")
+        .unwrap();
+    assert_eq!(registry.get_conflicts_for("debug", KeyCode::F3).len(), 1);
+    assert_eq!(conflict_note(&registry, &binding), Some("Also on F3: grid".to_string()));
+
+    registry.rebind("debug", KeyCode::F1, KeyCode::F1).unwrap();
+    assert!(registry.get_conflicts().is_empty());
+}
+
+#[test]
+fn test_saved_rebinds_apply_to_handlers_registered_later() {
+    let mut registry = InputMappingRegistry::new();
+    registry.set_rebinds([("debug".to_string(), KeyCode::F1, KeyCode::KeyG)]);
+    registry.register_handler(Arc::new(TestHandler { id: "debug", key: KeyCode::F1 })).unwrap();
+
+    assert!(registry.get_primary_handler(KeyCode::F1).is_none());
+    assert!(registry.get_primary_handler(KeyCode::KeyG).is_some());
+    assert_eq!(saved_rebinds(&registry), vec![KeyRebind::new("debug", KeyCode::F1, KeyCode::KeyG)]);
+}
+
+#[test]
+fn test_key_rebind_round_trips_through_json() {
+    let rebind = KeyRebind::new("debug", KeyCode::F1, KeyCode::KeyG);
+    let json = serde_json::to_string(&rebind).unwrap();
+    let loaded: KeyRebind = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(loaded.parse(), Some(("debug".to_string(), KeyCode::F1, KeyCode::KeyG)));
+    assert_eq!(parse_key_code("Escape"), Some(KeyCode::Escape));
+    assert_eq!(parse_key_code("NotAKey"), None);
+}
+
+fn capture_world(registry: InputMappingRegistry, pressed: KeyCode) -> World {
+    let mut world = World::new();
+    world.insert_resource(registry);
+    world.insert_resource(GameSettings::default());
+    world.insert_resource(KeyCapture {
+        target: Some(("debug".to_string(), KeyCode::F1)),
+    });
+    let mut input = ButtonInput::<KeyCode>::default();
+    input.press(pressed);
+    world.insert_resource(input);
+    world
+}
+
+#[test]
+fn test_key_capture_binds_next_key_and_saves_it() {
+    let mut world = capture_world(registry_with(&[("debug", KeyCode::F1)]), KeyCode::KeyG);
+    world.run_system_once(key_capture_system).unwrap();
+
+    assert!(!world.resource::<KeyCapture>().is_active());
+    assert_eq!(world.resource::<InputMappingRegistry>().bound_key("debug", KeyCode::F1), KeyCode::KeyG);
+    assert_eq!(
+        world.resource::<GameSettings>().key_bindings,
+        vec![KeyRebind::new("debug", KeyCode::F1, KeyCode::KeyG)]
+    );
+}
+
+#[test]
+fn test_escape_cancels_key_capture() {
+    let mut world = capture_world(registry_with(&[("debug", KeyCode::F1)]), KeyCode::Escape);
+    world.run_system_once(key_capture_system).unwrap();
+
+    assert!(!world.resource::<KeyCapture>().is_active());
+    assert_eq!(world.resource::<InputMappingRegistry>().bound_key("debug", KeyCode::F1), KeyCode::F1);
+    assert!(world.resource::<GameSettings>().key_bindings.is_empty());
+}
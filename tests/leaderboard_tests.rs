@@ -0,0 +1,92 @@
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::leaderboard_page::leaderboard_lines;
+use tower_defense_bevy::systems::results_screen::leaderboard_standing;
+
+fn entry(waves: u32, score: u32) -> LeaderboardEntry {
+    LeaderboardEntry {
+        waves,
+        score,
+        date: "2026-10-16".to_string(),
+        seed: 42,
+        mode: LeaderboardMode::Campaign,
+        prestige: PrestigeSet::default(),
+    }
+}
+
+#[test]
+fn test_runs_rank_by_waves_then_score() {
+    let mut leaderboard = RunLeaderboard::default();
+    assert_eq!(leaderboard.record(entry(5, 900)), Some(1));
+    assert_eq!(leaderboard.record(entry(8, 100)), Some(1), "more waves beat more score");
+    assert_eq!(leaderboard.record(entry(5, 1_200)), Some(2));
+    assert_eq!(leaderboard.record(entry(5, 1_200)), Some(3), "ties keep the earlier run ahead");
+    assert_eq!(leaderboard.best(LeaderboardMode::Campaign), Some(&entry(8, 100)));
+
+    for _ in 0..LEADERBOARD_SIZE {
+        leaderboard.record(entry(20, 0));
+    }
+    assert_eq!(leaderboard.entries.len(), LEADERBOARD_SIZE);
+    assert_eq!(leaderboard.record(entry(1, 0)), None);
+}
+
+#[test]
+fn test_each_mode_is_ranked_on_its_own() {
+    let mut leaderboard = RunLeaderboard::default();
+    for _ in 0..LEADERBOARD_SIZE {
+        leaderboard.record(entry(20, 0));
+    }
+    let free_play = |waves, score| LeaderboardEntry { mode: LeaderboardMode::FreePlay, ..entry(waves, score) };
+    assert_eq!(leaderboard.record(free_play(2, 300)), Some(1), "a full campaign board doesn't crowd out free play");
+    assert_eq!(leaderboard.record(free_play(4, 100)), Some(1));
+    assert_eq!(leaderboard.record(entry(25, 0)), Some(1));
+
+    assert_eq!(leaderboard.entries_for(LeaderboardMode::Campaign).count(), LEADERBOARD_SIZE);
+    let free_play_waves: Vec<u32> = leaderboard.entries_for(LeaderboardMode::FreePlay).map(|entry| entry.waves).collect();
+    assert_eq!(free_play_waves, [4, 2]);
+    assert_eq!(leaderboard.best(LeaderboardMode::Endless), None);
+}
+
+#[test]
+fn test_leaderboard_round_trips_through_json() {
+    let mut leaderboard = RunLeaderboard::default();
+    leaderboard.record(entry(3, 250));
+    leaderboard.record(LeaderboardEntry { mode: LeaderboardMode::Endless, ..entry(14, 4_000) });
+    let json = leaderboard.to_json().unwrap();
+    assert_eq!(RunLeaderboard::from_json(&json).unwrap(), leaderboard);
+
+    // Entries written before run modes were recorded load as campaign runs
+    let old = r#"{ "entries": [ { "waves_reached": 3, "score": 10, "date": "2025-01-02", "seed": 7 } ] }"#;
+    assert_eq!(RunLeaderboard::from_json(old).unwrap().entries[0].mode, LeaderboardMode::Campaign);
+    let endless = r#"{ "entries": [ { "waves_reached": 3, "score": 10, "date": "2025-01-02", "seed": 7, "run_mode": "Endless" } ] }"#;
+    assert_eq!(RunLeaderboard::from_json(endless).unwrap().entries[0].mode, LeaderboardMode::Endless);
+}
+
+#[test]
+fn test_dates_are_calendar_days() {
+    assert_eq!(format_date(0), "1970-01-01");
+    assert_eq!(format_date(951_782_400), "2000-02-29");
+    assert_eq!(format_date(1_790_000_000), "2026-09-21");
+    assert_eq!(today().len(), "YYYY-MM-DD".len());
+}
+
+#[test]
+fn test_leaderboard_rows_and_standing() {
+    let formatter = NumberFormatter::default();
+    assert_eq!(leaderboard_lines(&RunLeaderboard::default(), &formatter), ["No runs recorded yet"]);
+
+    let mut leaderboard = RunLeaderboard::default();
+    leaderboard.record(LeaderboardEntry { mode: LeaderboardMode::Endless, ..entry(12, 450) });
+    leaderboard.record(LeaderboardEntry { mode: LeaderboardMode::FreePlay, ..entry(3, 200) });
+    leaderboard.record(entry(5, 900));
+    assert_eq!(
+        leaderboard_lines(&leaderboard, &formatter),
+        [
+            "#1  Wave 5  900 pts  2026-10-16  seed 42",
+            "#1  Wave 12  450 pts  2026-10-16  seed 42  endless",
+            "#1  +3 waves  200 pts  2026-10-16  seed 42  free play",
+        ]
+    );
+
+    assert_eq!(leaderboard_standing(Some(2)), "Leaderboard: #2");
+    assert_eq!(leaderboard_standing(None), "Leaderboard: not placed");
+}
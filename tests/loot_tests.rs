@@ -0,0 +1,140 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::combat_system::{collision_system, EnemyDamagedEvent, EnemyKilledEvent, Target, WaveStatus};
+use tower_defense_bevy::systems::input_system::MouseInputState;
+use tower_defense_bevy::systems::loot_system::loot_collection_system;
+
+fn always_drops() -> LootTable {
+    LootTable {
+        drop_chance: 1.0,
+        ..LootTable::for_enemy(EnemyType::Basic, 1)
+    }
+}
+
+#[test]
+fn test_loot_table_roll() {
+    let table = LootTable::for_enemy(EnemyType::Basic, 1);
+
+    // Drop chance gate
+    assert_eq!(table.roll(table.drop_chance, 0.0), None);
+    assert_eq!(table.roll(0.99, 0.0), None);
+
+    // Weighted pick: cash 6, fire rate 3, cooldown 1 (of 10)
+    assert_eq!(table.roll(0.0, 0.0), Some(PickupKind::CashBundle));
+    assert_eq!(table.roll(0.0, 0.59), Some(PickupKind::CashBundle));
+    assert_eq!(table.roll(0.0, 0.61), Some(PickupKind::FireRateBoost));
+    assert_eq!(table.roll(0.0, 0.95), Some(PickupKind::CooldownReset));
+    assert_eq!(table.roll(0.0, 1.0), Some(PickupKind::CooldownReset));
+
+    let empty = LootTable { entries: Vec::new(), ..always_drops() };
+    assert_eq!(empty.roll(0.0, 0.5), None);
+}
+
+#[test]
+fn test_loot_tables_follow_the_enemy_type() {
+    let basic = LootTable::for_enemy(EnemyType::Basic, 4);
+    let tank = LootTable::for_enemy(EnemyType::Tank, 4);
+    let boss = LootTable::for_enemy(EnemyType::Boss, 4);
+    assert!(tank.drop_chance > basic.drop_chance);
+    assert_eq!(boss.drop_chance, 1.0, "bosses always drop");
+    assert_eq!(tank.cash_amount, basic.cash_amount * EnemyType::Tank.reward_multiplier());
+
+    // Flyers favour the cooldown reset
+    let flying = LootTable::for_enemy(EnemyType::Flying, 4);
+    assert_eq!(flying.roll(0.0, 0.99), Some(PickupKind::CooldownReset));
+    assert_eq!(flying.roll(0.0, 0.55), Some(PickupKind::CooldownReset));
+}
+
+#[test]
+fn test_fire_rate_boost_expires() {
+    let mut buffs = ActiveBuffs::default();
+    assert_eq!(buffs.fire_rate_multiplier(), 1.0);
+
+    buffs.activate_fire_rate_boost();
+    assert_eq!(buffs.fire_rate_multiplier(), FIRE_RATE_BOOST_MULTIPLIER);
+
+    buffs.tick(FIRE_RATE_BOOST_DURATION + 0.1);
+    assert!(!buffs.is_fire_rate_boosted());
+    assert_eq!(buffs.fire_rate_multiplier(), 1.0);
+}
+
+#[test]
+fn test_killed_enemy_drops_loot() {
+    let mut world = World::new();
+    world.insert_resource(Economy::default());
+    world.insert_resource(Score::default());
+    world.insert_resource(WaveStatus::default());
+    world.init_resource::<Events<EnemyKilledEvent>>();
+    world.init_resource::<Events<EnemyDamagedEvent>>();
+
+    let enemy = world.spawn((
+        Enemy::default(),
+        Health::new(5.0),
+        always_drops(),
+        Transform::from_xyz(100.0, 50.0, 0.0),
+    )).id();
+    world.spawn((
+        Transform::from_xyz(100.0, 50.0, 0.0),
+        Projectile::new(10.0, 300.0, enemy, Vec2::new(100.0, 50.0), TowerType::Basic),
+    ));
+
+    let _ = world.run_system_once(collision_system);
+
+    let pickups: Vec<(&LootPickup, &Transform)> = world
+        .query::<(&LootPickup, &Transform)>()
+        .iter(&world)
+        .collect();
+    assert_eq!(pickups.len(), 1);
+    assert_eq!(pickups[0].1.translation.truncate(), Vec2::new(100.0, 50.0));
+}
+
+#[test]
+fn test_click_collects_cash_and_consumes_click() {
+    let mut world = World::new();
+    world.insert_resource(Economy::default());
+    world.insert_resource(ActiveBuffs::default());
+    world.insert_resource(MouseInputState {
+        world_position: Vec2::new(205.0, -3.0),
+        left_clicked: true,
+        ..default()
+    });
+
+    let pickup = world.spawn((
+        LootPickup::new(PickupKind::CashBundle, 40, 6.0),
+        Transform::from_xyz(200.0, 0.0, 0.0),
+    )).id();
+    let initial_money = world.resource::<Economy>().money;
+
+    let _ = world.run_system_once(loot_collection_system);
+
+    assert_eq!(world.resource::<Economy>().money, initial_money + 40);
+    assert!(world.get_entity(pickup).is_err());
+    assert!(!world.resource::<MouseInputState>().left_clicked, "click should not fall through to placement");
+}
+
+#[test]
+fn test_cooldown_reset_pickup_readies_towers() {
+    let mut world = World::new();
+    world.insert_resource(Economy::default());
+    world.insert_resource(ActiveBuffs::default());
+    world.insert_resource(MouseInputState {
+        world_position: Vec2::ZERO,
+        left_clicked: true,
+        ..default()
+    });
+
+    let tower = world.spawn((
+        TowerStats::new(TowerType::Basic),
+        Target { entity: None, last_shot_time: 12.0 },
+    )).id();
+    world.spawn((
+        LootPickup::new(PickupKind::CooldownReset, 0, 6.0),
+        Transform::default(),
+    ));
+
+    let _ = world.run_system_once(loot_collection_system);
+
+    let target = world.entity(tower).get::<Target>().unwrap();
+    assert_eq!(target.last_shot_time, f32::NEG_INFINITY);
+}
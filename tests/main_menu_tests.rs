@@ -0,0 +1,80 @@
+//! Main menu: picking the save Continue resumes, and the menu gating gameplay
+
+mod common;
+
+use std::time::{Duration, SystemTime};
+use bevy::prelude::*;
+use common::UiTestApp;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::main_menu::*;
+use tower_defense_bevy::systems::map_select::{MapSelectAction, MapSelectButton};
+use tower_defense_bevy::systems::pause_system::{PauseButton, PauseMenuAction};
+use tower_defense_bevy::systems::tower_ui::TowerTypeButton;
+
+/// A free cell above the test path, which runs along y = 0
+const BUILD_SPOT: Vec2 = Vec2::new(-120.0, 120.0);
+
+fn main_menu_shown(ui: &mut UiTestApp) -> bool {
+    let world = ui.app.world_mut();
+    world.query_filtered::<(), With<MainMenuOverlay>>().iter(world).count() == 1
+}
+
+#[test]
+fn test_continue_picks_the_newest_save() {
+    let earlier = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000);
+    let later = earlier + Duration::from_secs(60);
+
+    assert_eq!(newest_save(None, None), None);
+    assert_eq!(newest_save(Some(earlier), None), Some(SaveSlot::Suspended));
+    assert_eq!(newest_save(None, Some(earlier)), Some(SaveSlot::SaveState));
+    assert_eq!(newest_save(Some(earlier), Some(later)), Some(SaveSlot::SaveState));
+    assert_eq!(newest_save(Some(later), Some(earlier)), Some(SaveSlot::Suspended));
+}
+
+#[test]
+fn test_main_menu_holds_gameplay_until_a_new_game() {
+    let mut ui = UiTestApp::at_main_menu();
+    assert_eq!(ui.app_state(), AppState::MainMenu);
+    assert!(main_menu_shown(&mut ui));
+
+    // Nothing is placed behind the menu, and Escape doesn't open the pause menu
+    ui.click_button_where::<TowerTypeButton>(|button| button.tower_type == TowerType::Basic);
+    ui.click_world(BUILD_SPOT);
+    assert!(ui.towers().is_empty());
+    ui.tap_key(KeyCode::Escape);
+    assert_eq!(ui.app_state(), AppState::MainMenu);
+
+    ui.click_button_where::<MainMenuButton>(|button| button.action == MainMenuAction::NewGame);
+    assert_eq!(ui.app_state(), AppState::MainMenu, "a map is picked first");
+    ui.click_button_where::<MapSelectButton>(|button| button.action == MapSelectAction::Pick(0));
+    assert_eq!(ui.app_state(), AppState::Playing);
+    assert!(!main_menu_shown(&mut ui));
+    assert!(ui.towers().is_empty());
+
+    ui.click_button_where::<TowerTypeButton>(|button| button.tower_type == TowerType::Basic);
+    ui.click_world(BUILD_SPOT);
+    assert_eq!(ui.towers().len(), 1, "placement works once a game is started");
+}
+
+#[test]
+fn test_settings_return_to_the_menu_that_opened_them() {
+    let mut ui = UiTestApp::at_main_menu();
+    ui.click_button_where::<MainMenuButton>(|button| button.action == MainMenuAction::Settings);
+    assert_eq!(ui.app_state(), AppState::Settings);
+    assert!(!main_menu_shown(&mut ui));
+
+    ui.tap_key(KeyCode::Escape);
+    assert_eq!(ui.app_state(), AppState::MainMenu);
+    assert!(main_menu_shown(&mut ui), "the menu is rebuilt on returning to it");
+    assert_eq!(ui.app.world().resource::<SettingsReturnState>().0, AppState::MainMenu);
+
+    // Once playing, settings opened from the pause menu go back to it
+    ui.click_button_where::<MainMenuButton>(|button| button.action == MainMenuAction::NewGame);
+    ui.click_button_where::<MapSelectButton>(|button| button.action == MapSelectAction::Pick(0));
+    ui.tap_key(KeyCode::Escape);
+    assert_eq!(ui.app_state(), AppState::Paused);
+    ui.click_button_where::<PauseButton>(|button| matches!(button.action, PauseMenuAction::Settings));
+    assert_eq!(ui.app_state(), AppState::Settings);
+    ui.tap_key(KeyCode::Escape);
+    assert_eq!(ui.app_state(), AppState::Paused);
+}
@@ -0,0 +1,77 @@
+use tower_defense_bevy::systems::path_generation::*;
+
+#[test]
+fn test_every_archetype_keeps_a_path() {
+    for archetype in MapArchetype::ALL {
+        for seed in [1_u64, 42, 9001] {
+            let grid = generate_procedural_map_with_archetype(seed, 0.5, archetype);
+            assert!(
+                find_path(&grid, grid.entry_point, grid.exit_point).is_some(),
+                "{} map with seed {} has no path",
+                archetype.get_name(),
+                seed
+            );
+        }
+    }
+}
+
+#[test]
+fn test_maze_is_denser_than_open_field() {
+    for seed in [7_u64, 123, 4567] {
+        let open = generate_procedural_map_with_archetype(seed, 0.5, MapArchetype::OpenField);
+        let maze = generate_procedural_map_with_archetype(seed, 0.5, MapArchetype::Maze);
+
+        let open_analysis = analyze_map(&open, MapArchetype::OpenField);
+        let maze_analysis = analyze_map(&maze, MapArchetype::Maze);
+
+        assert!(open_analysis.obstacle_coverage < 0.08);
+        assert!(maze_analysis.obstacle_coverage > open_analysis.obstacle_coverage);
+    }
+}
+
+#[test]
+fn test_islands_split_build_space() {
+    for seed in [5_u64, 99, 2024] {
+        let grid = generate_procedural_map_with_archetype(seed, 0.5, MapArchetype::Islands);
+        let analysis = analyze_map(&grid, MapArchetype::Islands);
+        assert!(analysis.buildable_regions > 1, "seed {} produced no islands", seed);
+    }
+}
+
+#[test]
+fn test_archetype_generation_is_deterministic() {
+    for archetype in MapArchetype::ALL {
+        let first = generate_procedural_map_with_archetype(77, 0.3, archetype);
+        let second = generate_procedural_map_with_archetype(77, 0.3, archetype);
+        assert_eq!(first.cells, second.cells);
+        assert_eq!(first.entry_point, second.entry_point);
+        assert_eq!(first.exit_point, second.exit_point);
+    }
+}
+
+#[test]
+fn test_analyze_map_counts_build_regions() {
+    let mut grid = PathGrid::new_unified();
+    assert_eq!(analyze_map(&grid, MapArchetype::OpenField).buildable_regions, 1);
+
+    // A full-height wall splits the map into two build areas
+    for y in 0..grid.height {
+        grid.set_cell(GridPos::new(10, y), CellType::Blocked);
+    }
+    let analysis = analyze_map(&grid, MapArchetype::Islands);
+
+    assert_eq!(analysis.archetype, MapArchetype::Islands);
+    assert_eq!(analysis.buildable_regions, 2);
+    assert_eq!(analysis.largest_region, (grid.width - 11) * grid.height);
+    assert!((analysis.obstacle_coverage - 1.0 / grid.width as f32).abs() < 1e-6);
+}
+
+#[test]
+fn test_archetype_cycle_visits_all() {
+    let mut archetype = MapArchetype::default();
+    for expected in MapArchetype::ALL.iter().cycle().skip(1).take(MapArchetype::ALL.len()) {
+        archetype = archetype.next();
+        assert_eq!(archetype, *expected);
+    }
+    assert_eq!(archetype, MapArchetype::Classic);
+}
@@ -0,0 +1,111 @@
+//! Map editor: painting rules, custom map files and opening the editor from the map select screen
+
+mod common;
+
+use bevy::prelude::*;
+use common::UiTestApp;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::main_menu::{MainMenuAction, MainMenuButton};
+use tower_defense_bevy::systems::map_editor::*;
+use tower_defense_bevy::systems::map_select::{custom_map_for_play, MapSelectAction, MapSelectButton};
+use tower_defense_bevy::systems::path_generation::*;
+
+/// Editor with a wall across the board, leaving a gap in the top row
+fn walled_editor() -> MapEditor {
+    let mut editor = MapEditor::default();
+    for y in 0..GRID_HEIGHT - 1 {
+        editor.paint(GridPos::new(GRID_WIDTH / 2, y));
+    }
+    editor
+}
+
+#[test]
+fn test_blocks_never_cover_the_entry_or_exit() {
+    let mut editor = MapEditor::default();
+    let entry = editor.grid.entry_point;
+    assert!(!editor.paint(entry));
+    assert_eq!(editor.grid.get_cell(entry), Some(CellType::Empty));
+
+    let cell = GridPos::new(4, 4);
+    assert!(editor.paint(cell));
+    assert!(!editor.paint(cell), "painting a blocked cell again changes nothing");
+
+    // Moving the entry onto a blocked cell clears it
+    editor.tool = EditorTool::Entry;
+    assert!(editor.paint(cell));
+    assert_eq!(editor.grid.entry_point, cell);
+    assert_eq!(editor.grid.get_cell(cell), Some(CellType::Empty));
+    assert!(!editor.paint(editor.grid.exit_point), "entry and exit stay apart");
+}
+
+#[test]
+fn test_validation_finds_the_route_and_edits_clear_it() {
+    let mut editor = walled_editor();
+    assert!(editor.validate().is_ok());
+    assert!(editor.route.contains(&GridPos::new(GRID_WIDTH / 2, GRID_HEIGHT - 1)), "the route squeezes through the gap");
+
+    editor.paint(GridPos::new(GRID_WIDTH / 2, GRID_HEIGHT - 1));
+    assert!(editor.route.is_empty());
+    assert_eq!(editor.validate(), Err(CustomMapError::NoRoute));
+    assert!(editor.status.starts_with("Invalid"));
+}
+
+#[test]
+fn test_custom_maps_round_trip_through_ron() {
+    let editor = walled_editor();
+    let map = editor.to_custom_map("Great Wall");
+    assert_eq!(map.blocked.len(), GRID_HEIGHT - 1);
+
+    let loaded = CustomMap::from_ron(&map.to_ron().unwrap()).unwrap();
+    assert_eq!(loaded, map);
+    assert_eq!(loaded.to_grid().unwrap().cells, editor.grid.cells);
+    assert!(CustomMap::from_ron("(name: \"broken\")").is_err());
+
+    let shared = custom_map_for_play(&map).unwrap();
+    assert_eq!(shared.route.first(), Some(&editor.grid.entry_point));
+    let small = CustomMap { width: 10, height: 10, entry: (0, 5), exit: (9, 5), ..map };
+    assert!(custom_map_for_play(&small).is_err(), "custom maps fill the board");
+}
+
+#[test]
+fn test_saves_pick_a_free_name_and_are_listed() {
+    let dir = std::env::temp_dir().join(format!("td_custom_maps_{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    assert_eq!(map_file_stem("Custom Map 1"), "custom_map_1");
+    assert_eq!(map_file_stem("  !! "), "custom_map");
+
+    let mut blocked = walled_editor();
+    blocked.paint(GridPos::new(GRID_WIDTH / 2, GRID_HEIGHT - 1));
+    assert!(blocked.save(&dir).is_err(), "maps without a route aren't saved");
+    assert!(!dir.join("custom_map_1.ron").exists());
+
+    let mut editor = walled_editor();
+    assert_eq!(editor.save(&dir).unwrap(), dir.join("custom_map_1.ron"));
+    assert_eq!(editor.save(&dir).unwrap(), dir.join("custom_map_2.ron"));
+    std::fs::write(dir.join("notes.ron"), "not a map").unwrap();
+
+    let (maps, failures) = load_custom_maps(&dir);
+    let names: Vec<&str> = maps.iter().map(|map| map.name.as_str()).collect();
+    assert_eq!(names, ["Custom Map 1", "Custom Map 2"]);
+    assert_eq!(failures.len(), 1);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_editor_opens_from_map_select_and_exits_to_the_menu() {
+    let mut ui = UiTestApp::at_main_menu();
+    ui.click_button_where::<MainMenuButton>(|button| button.action == MainMenuAction::NewGame);
+    ui.click_button_where::<MapSelectButton>(|button| button.action == MapSelectAction::Editor);
+    assert_eq!(ui.app_state(), AppState::Editor);
+
+    let world = ui.app.world_mut();
+    assert_eq!(world.query::<&EditorCell>().iter(world).count(), GRID_WIDTH * GRID_HEIGHT);
+
+    ui.click_button_where::<EditorButton>(|button| button.action == EditorAction::Tool(EditorTool::Erase));
+    assert_eq!(ui.app.world().resource::<MapEditor>().tool, EditorTool::Erase);
+
+    ui.click_button_where::<EditorButton>(|button| button.action == EditorAction::Exit);
+    assert_eq!(ui.app_state(), AppState::MainMenu);
+    let world = ui.app.world_mut();
+    assert_eq!(world.query::<&EditorCell>().iter(world).count(), 0);
+}
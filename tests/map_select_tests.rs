@@ -0,0 +1,96 @@
+//! Map select: registered maps, framed boards and starting a game on a picked map
+
+mod common;
+
+use bevy::prelude::*;
+use common::UiTestApp;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::main_menu::{MainMenuAction, MainMenuButton};
+use tower_defense_bevy::systems::map_select::*;
+use tower_defense_bevy::systems::obstacle_rendering::ObstacleGrid;
+use tower_defense_bevy::systems::path_generation::*;
+
+fn map_select_shown(ui: &mut UiTestApp) -> bool {
+    let world = ui.app.world_mut();
+    world.query_filtered::<(), With<MapSelectPage>>().iter(world).count() == 1
+}
+
+#[test]
+fn test_every_registered_map_keeps_a_path() {
+    for map in MapRegistry::default().maps {
+        for seed in [1_u64, 42, 9001] {
+            let grid = generate_framed_map(seed, map.archetype, map.obstacle_density, map.width, map.height);
+            assert_eq!((grid.width, grid.height), (GRID_WIDTH, GRID_HEIGHT), "{} fits the board", map.name);
+            assert!(
+                find_path(&grid, grid.entry_point, grid.exit_point).is_some(),
+                "{} with seed {} has no path",
+                map.name,
+                seed
+            );
+        }
+    }
+}
+
+#[test]
+fn test_small_maps_are_framed_in_the_middle_of_the_board() {
+    let grid = generate_framed_map(7, MapArchetype::Classic, 0.1, 20, 10);
+    let (offset_x, offset_y) = ((GRID_WIDTH - 20) / 2, (GRID_HEIGHT - 10) / 2);
+
+    for y in 0..grid.height {
+        for x in 0..grid.width {
+            let inside = (offset_x..offset_x + 20).contains(&x) && (offset_y..offset_y + 10).contains(&y);
+            if !inside {
+                assert_eq!(grid.get_cell(GridPos::new(x, y)), Some(CellType::Blocked), "({x}, {y}) is walled off");
+            }
+        }
+    }
+    for point in [grid.entry_point, grid.exit_point] {
+        assert!((offset_x..offset_x + 20).contains(&point.x) && (offset_y..offset_y + 10).contains(&point.y));
+    }
+}
+
+#[test]
+fn test_selecting_a_missing_map_keeps_the_current_pick() {
+    let mut registry = MapRegistry::default();
+    assert!(registry.selected_map().is_none());
+    assert_eq!(registry.select(1).map(|map| map.name), Some("Open Plains"));
+    assert!(registry.select(registry.maps.len()).is_none());
+    assert_eq!(registry.selected, Some(1));
+}
+
+#[test]
+fn test_back_returns_to_the_main_menu() {
+    let mut ui = UiTestApp::at_main_menu();
+    ui.click_button_where::<MainMenuButton>(|button| button.action == MainMenuAction::NewGame);
+    assert!(map_select_shown(&mut ui));
+
+    ui.click_button_where::<MapSelectButton>(|button| button.action == MapSelectAction::Back);
+    assert!(!map_select_shown(&mut ui));
+    assert_eq!(ui.app_state(), AppState::MainMenu);
+    assert!(ui.app.world().resource::<MapRegistry>().selected.is_none());
+}
+
+#[test]
+fn test_picking_a_map_regenerates_the_level() {
+    let mut ui = UiTestApp::at_main_menu();
+    ui.app.world_mut().remove_resource::<FixedLevelPath>();
+    let test_path = ui.app.world().resource::<EnemyPath>().clone();
+
+    let pit = MapRegistry::default().maps.iter().position(|map| map.name == "The Pit").unwrap();
+    ui.click_button_where::<MainMenuButton>(|button| button.action == MainMenuAction::NewGame);
+    ui.click_button_where::<MapSelectButton>(|button| button.action == MapSelectAction::Pick(pit));
+    assert_eq!(ui.app_state(), AppState::Playing);
+    assert!(!map_select_shown(&mut ui));
+
+    let world = ui.app.world_mut();
+    let map = world.resource::<MapRegistry>().selected_map().cloned().unwrap();
+    assert_eq!(map.name, "The Pit");
+    assert_eq!(current_level_seed(), 77);
+    assert_eq!(world.resource::<LevelConfig>().layout, Some(map.layout()));
+    assert_ne!(world.resource::<EnemyPath>().waypoints, test_path.waypoints, "the path is generated for the map");
+
+    let grid = world.resource::<ObstacleGrid>().grid.clone();
+    assert_eq!(grid.get_cell(GridPos::new(0, 0)), Some(CellType::Blocked), "the board is walled down to the map's size");
+    let obstacles = world.query::<&Obstacle>().iter(world).count();
+    assert!(obstacles >= GRID_WIDTH * GRID_HEIGHT - map.width * map.height);
+}
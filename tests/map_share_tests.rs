@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::resources::EnemyPath;
+use tower_defense_bevy::systems::map_share_system::*;
+use tower_defense_bevy::systems::obstacle_rendering::ObstacleGrid;
+use tower_defense_bevy::systems::path_generation::*;
+
+fn generated_map(seed: u64, archetype: MapArchetype) -> SharedMap {
+    let grid = generate_procedural_map_with_archetype(seed, 0.5, archetype);
+    let route = generate_random_strategic_path(seed.wrapping_add(1000), &grid);
+    SharedMap { grid, route, extra_routes: Vec::new(), archetype }
+}
+
+#[test]
+fn test_generated_maps_round_trip_through_codes() {
+    for (seed, archetype) in MapArchetype::ALL.iter().enumerate().map(|(i, a)| (i as u64 * 7 + 3, *a)) {
+        let map = generated_map(seed, archetype);
+        let code = map.to_code();
+        assert!(code.starts_with(MAP_CODE_PREFIX));
+        assert!(!code.contains('\n'));
+        assert_eq!(SharedMap::from_code(&code), Ok(map.clone()), "{}", archetype.get_name());
+    }
+}
+
+#[test]
+fn test_route_with_jumps_round_trips() {
+    let mut grid = PathGrid::new(6, 4);
+    grid.set_cell(GridPos::new(3, 3), CellType::Blocked);
+    let route = vec![GridPos::new(0, 1), GridPos::new(1, 1), GridPos::new(1, 2), GridPos::new(4, 0), GridPos::new(5, 0)];
+    let map = SharedMap { grid, route, extra_routes: Vec::new(), archetype: MapArchetype::Maze };
+
+    let code = map.to_code();
+    assert!(code.ends_with(";0,1RU@4,0R"), "{}", code);
+    assert_eq!(SharedMap::from_code(&code), Ok(map));
+}
+
+#[test]
+fn test_extra_routes_round_trip() {
+    let grid = PathGrid::new(6, 4);
+    let route = vec![GridPos::new(0, 1), GridPos::new(1, 1), GridPos::new(2, 1)];
+    let extra_routes = vec![vec![GridPos::new(2, 3), GridPos::new(2, 2), GridPos::new(2, 1)]];
+    let map = SharedMap { grid, route, extra_routes, archetype: MapArchetype::OpenField };
+
+    let code = map.to_code();
+    assert!(code.ends_with(";0,1RR|2,3DD"), "{}", code);
+    assert_eq!(SharedMap::from_code(&code), Ok(map.clone()));
+
+    let mut grid = map.grid.clone();
+    grid.set_cell(GridPos::new(2, 2), CellType::Blocked);
+    let blocked = SharedMap { grid, ..map }.to_code();
+    assert_eq!(SharedMap::from_code(&blocked), Err(MapCodeError::RouteBlocked(GridPos::new(2, 2))));
+}
+
+#[test]
+fn test_invalid_codes_are_rejected() {
+    let code = generated_map(11, MapArchetype::Classic).to_code();
+    let fields: Vec<&str> = code.split(';').collect();
+    let with_field = |index: usize, value: &str| {
+        let mut changed = fields.clone();
+        changed[index] = value;
+        changed.join(";")
+    };
+
+    assert!(matches!(SharedMap::from_code("hello"), Err(MapCodeError::Format(_))));
+    assert!(matches!(SharedMap::from_code(&code.replace(MAP_CODE_PREFIX, "TDMAP9")), Err(MapCodeError::Format(_))));
+    assert!(matches!(SharedMap::from_code(&fields[..7].join(";")), Err(MapCodeError::Format(_))));
+    assert!(matches!(SharedMap::from_code(&with_field(1, "9")), Err(MapCodeError::Format(_))));
+    assert!(matches!(SharedMap::from_code(&with_field(2, "0x18")), Err(MapCodeError::Grid(_))));
+    assert!(matches!(SharedMap::from_code(&with_field(2, "100000x100000")), Err(MapCodeError::Format(_))));
+    assert!(matches!(SharedMap::from_code(&with_field(4, "500,1")), Err(MapCodeError::Grid(_))));
+    assert!(matches!(SharedMap::from_code(&with_field(6, "3.")), Err(MapCodeError::Format(_))));
+    assert!(matches!(SharedMap::from_code(&with_field(7, "0,0LLL")), Err(MapCodeError::Format(_))));
+
+    // A route through an obstacle isn't playable
+    let mut grid = PathGrid::new(4, 1);
+    grid.set_cell(GridPos::new(2, 0), CellType::Blocked);
+    let route = vec![GridPos::new(0, 0), GridPos::new(1, 0), GridPos::new(2, 0), GridPos::new(3, 0)];
+    let blocked = SharedMap { grid, route, extra_routes: Vec::new(), archetype: MapArchetype::Classic }.to_code();
+    assert!(blocked.ends_with(";0,0RRR"), "{}", blocked);
+    assert_eq!(SharedMap::from_code(&blocked), Err(MapCodeError::RouteBlocked(GridPos::new(2, 0))));
+}
+
+#[test]
+fn test_current_map_is_read_back_from_the_enemy_path() {
+    let map = generated_map(5, MapArchetype::Islands);
+    let obstacle_grid = ObstacleGrid {
+        grid: map.grid.clone(),
+        wave_number: 1,
+        analysis: analyze_map(&map.grid, map.archetype),
+    };
+    let enemy_path = map.grid.to_enemy_path(map.route.clone());
+
+    let current = current_shared_map(&obstacle_grid, &enemy_path).unwrap();
+    assert_eq!(current.route, map.route);
+    assert!(current.extra_routes.is_empty());
+    assert_eq!(current.archetype, MapArchetype::Islands);
+    assert_eq!(current.grid, map.grid);
+
+    let off_grid = EnemyPath::new(vec![Vec2::new(1.0e6, 0.0)]);
+    assert!(current_shared_map(&obstacle_grid, &off_grid).is_none());
+}
+
+#[test]
+fn test_applying_a_shared_map_replaces_grid_path_and_obstacles() {
+    let map = generated_map(9, MapArchetype::Maze);
+    let blocked = map.grid.cells.iter().flatten().filter(|cell| **cell == CellType::Blocked).count();
+
+    let mut world = World::new();
+    world.init_resource::<ObstacleGrid>();
+    world.insert_resource(EnemyPath::new(vec![Vec2::ZERO]));
+    let applied = map.clone();
+    world
+        .run_system_once(move |mut commands: Commands, mut grid: ResMut<ObstacleGrid>, mut path: ResMut<EnemyPath>| {
+            apply_shared_map(&mut commands, &applied, &mut grid, &mut path);
+        })
+        .unwrap();
+
+    assert_eq!(world.resource::<ObstacleGrid>().grid, map.grid);
+    assert_eq!(world.resource::<ObstacleGrid>().analysis.archetype, MapArchetype::Maze);
+    assert_eq!(world.resource::<EnemyPath>().waypoints.len(), map.route.len());
+    assert_eq!(world.query::<&Obstacle>().iter(&world).count(), blocked);
+}
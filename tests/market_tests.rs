@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::market_system::market_system;
+use tower_defense_bevy::systems::path_generation::current_level_seed;
+use tower_defense_bevy::systems::settings_menu::GameSettings;
+use tower_defense_bevy::systems::tower_ui::tower_cost_label;
+
+fn open_market(seed: u64) -> MarketState {
+    let mut market = MarketState::new(seed);
+    market.enabled = true;
+    market
+}
+
+#[test]
+fn test_closed_market_charges_list_price() {
+    let mut market = MarketState::new(7);
+    market.advance_to(10);
+    for tower_type in TowerType::ALL {
+        assert_eq!(market.tower_cost(tower_type), tower_type.get_cost());
+        assert_eq!(market.trend(tower_type), PriceTrend::Steady);
+        assert_eq!(resolve_tower_cost(None, tower_type), tower_type.get_cost());
+    }
+}
+
+#[test]
+fn test_prices_stay_within_the_swing() {
+    let mut market = open_market(42);
+    for wave in 1..=200 {
+        market.advance_to(wave);
+        for tower_type in TowerType::ALL {
+            let multiplier = market.multiplier(tower_type);
+            assert!(
+                (1.0 - MARKET_MAX_SWING..=1.0 + MARKET_MAX_SWING).contains(&multiplier),
+                "wave {wave}: {tower_type:?} at {multiplier}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_same_seed_walks_the_same_prices() {
+    let mut first = open_market(99);
+    let mut second = open_market(99);
+    first.advance_to(12);
+    for wave in 1..=12 {
+        second.advance_to(wave);
+    }
+    assert_eq!(first, second, "walking all at once or wave by wave ends up the same");
+
+    let mut other_seed = open_market(100);
+    other_seed.advance_to(12);
+    assert_ne!(first, other_seed);
+}
+
+#[test]
+fn test_trend_and_label_follow_the_last_move() {
+    let mut market = open_market(5);
+    market.advance_to(3);
+    let formatter = NumberFormatter::default();
+    for tower_type in TowerType::ALL {
+        let label = tower_cost_label(tower_type, Some(&market), &formatter);
+        match market.trend(tower_type) {
+            PriceTrend::Rising => assert!(label.ends_with(" ^"), "{label}"),
+            PriceTrend::Falling => assert!(label.ends_with(" v"), "{label}"),
+            PriceTrend::Steady => assert!(!label.ends_with('^') && !label.ends_with('v'), "{label}"),
+        }
+        let expected = (tower_type.get_cost().money as f32 * market.multiplier(tower_type)) as u32;
+        assert_eq!(market.tower_cost(tower_type).money, expected);
+    }
+}
+
+#[test]
+fn test_market_system_moves_prices_per_wave_and_resets_on_new_run() {
+    let mut world = World::new();
+    world.insert_resource(GameSettings { market_mode_enabled: true, ..default() });
+    world.insert_resource(WavePlan::default());
+    world.insert_resource(WaveRuntime::new());
+    world.insert_resource(MarketState::new(current_level_seed()));
+
+    world.resource_mut::<WavePlan>().current_wave = 4;
+    let _ = world.run_system_once(market_system);
+    let market = world.resource::<MarketState>().clone();
+    assert!(market.enabled);
+    assert_eq!(market.wave, 4);
+
+    // A restarted run starts from list price again
+    world.resource_mut::<WavePlan>().current_wave = 0;
+    let _ = world.run_system_once(market_system);
+    let market = world.resource::<MarketState>();
+    assert_eq!(market.wave, 0);
+    for tower_type in TowerType::ALL {
+        assert_eq!(market.tower_cost(tower_type), tower_type.get_cost());
+    }
+}
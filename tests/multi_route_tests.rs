@@ -0,0 +1,113 @@
+//! Multi-route maps: converging routes from several entry points, spawning
+//! enemies down each route and keeping towers off every route
+
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use tower_defense_bevy::components::*;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::enemy_system::{enemy_spawning_system, route_color, route_for_spawn, SpawnRoute, ROUTE_COLORS};
+use tower_defense_bevy::systems::occupancy::OccupancyMap;
+use tower_defense_bevy::systems::path_generation::*;
+use tower_defense_bevy::systems::placement_validator::*;
+use tower_defense_bevy::systems::unified_grid::UnifiedGridSystem;
+
+/// Path with a main route along the middle and a second one coming down from the top
+fn two_route_path() -> EnemyPath {
+    EnemyPath::new(vec![Vec2::new(-300.0, 0.0), Vec2::new(300.0, 0.0)])
+        .with_extra_routes(vec![vec![Vec2::new(0.0, 200.0), Vec2::new(0.0, 0.0), Vec2::new(300.0, 0.0)], Vec::new()])
+}
+
+#[test]
+fn test_extra_routes_converge_on_the_exit_from_separate_entries() {
+    let grid = PathGrid::new_unified();
+    let route = find_path(&grid, grid.entry_point, grid.exit_point).unwrap();
+    let routes = converging_routes(9, &grid, &route, MAX_LEVEL_ROUTES - 1);
+    assert_eq!(routes.len(), 2);
+
+    let mut entries = vec![grid.entry_point];
+    for extra in &routes {
+        let entry = extra[0];
+        assert_eq!(extra.last(), Some(&grid.exit_point), "every route ends at the one exit");
+        assert!(entry.x == 0 || entry.y == 0 || entry.y == grid.height - 1, "{:?} lies on an edge", entry);
+        assert_ne!(entry.x, grid.width - 1, "no entry shares the exit's edge");
+        assert!(entries.iter().all(|other| other.manhattan_distance(&entry) >= 12.0), "entries are spread out");
+        entries.push(entry);
+    }
+    assert_eq!(converging_routes(9, &grid, &route, 2), routes, "the same seed picks the same entries");
+}
+
+#[test]
+fn test_level_paths_get_the_routes_the_map_asks_for() {
+    set_level_seed(4242);
+    set_level_archetype(MapArchetype::OpenField);
+    let mut level_config = LevelConfig {
+        layout: Some(LevelLayout { width: 32, height: 18, obstacle_density: 0.08 }),
+        ..LevelConfig::default()
+    };
+
+    level_config.set_routes(10);
+    assert_eq!(level_config.routes, MAX_LEVEL_ROUTES, "route counts are capped");
+    let path = generate_level_path_with_config(1, &level_config);
+    assert_eq!(path.route_count(), 3);
+    let exit = *path.waypoints.last().unwrap();
+    for route in path.routes() {
+        assert_eq!(route.last(), Some(&exit));
+    }
+
+    level_config.set_routes(0);
+    assert_eq!(level_config.routes, 1);
+    assert_eq!(generate_level_path_with_config(1, &level_config).route_count(), 1);
+}
+
+#[test]
+fn test_routes_are_dealt_in_turn() {
+    let path = two_route_path();
+    assert_eq!(path.route_count(), 2, "routes without waypoints are dropped");
+    assert_eq!(path.route(1).unwrap().waypoints[0], Vec2::new(0.0, 200.0));
+    assert!(path.route(1).unwrap().extra_routes.is_empty());
+    assert!(path.route(2).is_none());
+
+    let picks: Vec<usize> = (0..6).map(|index| route_for_spawn(index, 3)).collect();
+    assert_eq!(picks, [0, 1, 2, 0, 1, 2]);
+    assert_eq!(route_for_spawn(5, 0), 0);
+    assert_ne!(route_color(0), route_color(1));
+    assert_eq!(route_color(ROUTE_COLORS.len()), route_color(0));
+}
+
+#[test]
+fn test_spawned_enemies_start_at_their_route_entry() {
+    let mut wave_plan = WavePlan::default();
+    let mut wave_runtime = WaveRuntime::new();
+    wave_plan.current_wave = 1;
+    start_composed_wave(&mut wave_plan, &mut wave_runtime, WaveComposition::swarm(1, 4));
+    wave_runtime.pending_spawns = 2;
+
+    let mut world = World::new();
+    world.insert_resource(wave_plan);
+    world.insert_resource(wave_runtime);
+    world.insert_resource(two_route_path());
+    world.init_resource::<SimulationClock>();
+    world.run_system_once(enemy_spawning_system).unwrap();
+
+    let mut spawned: Vec<(usize, Vec2)> = world
+        .query::<(&SpawnRoute, &Transform)>()
+        .iter(&world)
+        .map(|(route, transform)| (route.index, transform.translation.truncate()))
+        .collect();
+    spawned.sort_by_key(|(index, _)| *index);
+    assert_eq!(spawned, [(0, Vec2::new(-300.0, 0.0)), (1, Vec2::new(0.0, 200.0))]);
+    assert_eq!(world.query::<&Enemy>().iter(&world).count(), 2);
+}
+
+#[test]
+fn test_towers_keep_off_every_route() {
+    let unified_grid = UnifiedGridSystem::default();
+    let path = two_route_path();
+    let on_second_route = Vec2::new(0.0, 120.0);
+
+    let occupancy = OccupancyMap::default();
+    let main_only = PlacementValidator::new(&unified_grid, None, &path.waypoints, &occupancy, TOWER_FOOTPRINT);
+    assert_eq!(main_only.check_position(on_second_route), PlacementVerdict::Buildable);
+    let every_route = main_only.with_extra_routes(&path.extra_routes);
+    assert_eq!(every_route.check_position(on_second_route), PlacementVerdict::OnPath);
+}
@@ -0,0 +1,194 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::combat_system::{tower_targeting_system, Target, TargetingMode};
+use tower_defense_bevy::systems::input_system::MouseInputState;
+use tower_defense_bevy::systems::multi_select_system::*;
+use tower_defense_bevy::systems::occupancy::OccupancyMap;
+use tower_defense_bevy::systems::tower_ui::{targeting_button_system, TargetingButton, TowerSelectionState};
+use tower_defense_bevy::systems::ui_feedback::UiFeedbackEvent;
+
+fn spawn_tower(world: &mut World, tower_type: TowerType, position: Vec2) -> Entity {
+    world.spawn((
+        TowerStats::new(tower_type),
+        TargetingMode::default(),
+        Target::default(),
+        Transform::from_translation(position.extend(0.0)),
+    )).id()
+}
+
+fn selection_world(money: u32) -> World {
+    let mut world = World::new();
+    world.insert_resource(Economy::new(money, 100, 100, 100));
+    world.insert_resource(TowerMultiSelection::default());
+    world.insert_resource(TowerSelectionState::default());
+    world.init_resource::<OccupancyMap>();
+    world.init_resource::<Events<GroupOperation>>();
+    world
+}
+
+fn run_operation(world: &mut World, operation: GroupOperation) {
+    world.send_event(operation);
+    let _ = world.run_system_once(group_operation_system);
+}
+
+#[test]
+fn test_sell_value_covers_upgrades() {
+    let mut stats = TowerStats::new(TowerType::Basic);
+    assert_eq!(stats.total_investment().money, 40);
+    assert_eq!(stats.sell_value().money, 28);
+
+    // Level 1 -> 2 costs 20, level 2 -> 3 costs 40
+    stats.upgrade();
+    stats.upgrade();
+    assert_eq!(stats.total_investment().money, 100);
+    assert_eq!(stats.sell_value().money, 70);
+}
+
+#[test]
+fn test_try_spend_is_all_or_nothing() {
+    let mut economy = Economy::new(50, 0, 0, 0);
+    assert!(!economy.try_spend(&ResourceCost::new(40, 1, 0, 0)));
+    assert_eq!(economy.money, 50);
+    assert!(economy.try_spend(&ResourceCost::money(40)));
+    assert_eq!(economy.money, 10);
+}
+
+#[test]
+fn test_upgrade_all_prefers_cheapest_upgrades() {
+    let mut world = selection_world(60);
+    let basic = spawn_tower(&mut world, TowerType::Basic, Vec2::ZERO);
+    let missile = spawn_tower(&mut world, TowerType::Missile, Vec2::new(80.0, 0.0));
+    let second_basic = spawn_tower(&mut world, TowerType::Basic, Vec2::new(160.0, 0.0));
+    world.resource_mut::<TowerMultiSelection>().towers.extend([basic, missile, second_basic]);
+
+    // Basic upgrades cost 20 each, missile costs 80: only the two basics fit
+    run_operation(&mut world, GroupOperation::UpgradeAll);
+
+    let level = |world: &World, entity| world.get::<TowerStats>(entity).unwrap().upgrade_level;
+    assert_eq!(level(&world, basic), 2);
+    assert_eq!(level(&world, second_basic), 2);
+    assert_eq!(level(&world, missile), 1);
+    assert_eq!(world.resource::<Economy>().money, 20);
+}
+
+#[test]
+fn test_sell_all_refunds_total_and_clears_selection() {
+    let mut world = selection_world(0);
+    let built = spawn_tower(&mut world, TowerType::Basic, Vec2::ZERO);
+    let building = spawn_tower(&mut world, TowerType::Basic, Vec2::new(80.0, 0.0));
+    world.entity_mut(building).insert(Constructing::new(2.0, TowerType::Basic.get_cost()));
+    let unselected = spawn_tower(&mut world, TowerType::Basic, Vec2::new(160.0, 0.0));
+    world.resource_mut::<TowerMultiSelection>().towers.extend([built, building]);
+
+    run_operation(&mut world, GroupOperation::SellAll);
+
+    // 70% back for the finished tower, full refund for the construction site
+    assert_eq!(world.resource::<Economy>().money, 28 + 40);
+    assert!(world.get_entity(built).is_err());
+    assert!(world.get_entity(building).is_err());
+    assert!(world.get_entity(unselected).is_ok());
+    assert!(!world.resource::<TowerMultiSelection>().is_active());
+}
+
+#[test]
+fn test_set_targeting_applies_to_group() {
+    let mut world = selection_world(0);
+    let selected = spawn_tower(&mut world, TowerType::Basic, Vec2::ZERO);
+    let unselected = spawn_tower(&mut world, TowerType::Basic, Vec2::new(80.0, 0.0));
+    world.resource_mut::<TowerMultiSelection>().towers.insert(selected);
+
+    run_operation(&mut world, GroupOperation::SetTargeting(TargetingMode::Strongest));
+
+    assert_eq!(*world.get::<TargetingMode>(selected).unwrap(), TargetingMode::Strongest);
+    assert_eq!(*world.get::<TargetingMode>(unselected).unwrap(), TargetingMode::First);
+}
+
+#[test]
+fn test_group_targeting_is_read_from_its_towers() {
+    use TargetingMode::*;
+    assert_eq!(TargetingMode::shared([Strongest, Strongest]), Some(Strongest));
+    assert_eq!(TargetingMode::shared([Strongest, Closest]), None, "mixed modes have no shared mode");
+    assert_eq!(TargetingMode::shared([]), None);
+}
+
+#[test]
+fn test_band_select_picks_towers_inside_rectangle() {
+    let mut world = selection_world(0);
+    world.insert_resource(ButtonInput::<KeyCode>::default());
+    let inside_a = spawn_tower(&mut world, TowerType::Basic, Vec2::new(10.0, 10.0));
+    let inside_b = spawn_tower(&mut world, TowerType::Laser, Vec2::new(90.0, 50.0));
+    let outside = spawn_tower(&mut world, TowerType::Basic, Vec2::new(200.0, 10.0));
+    world.insert_resource(MouseInputState {
+        world_position: Vec2::new(100.0, 60.0),
+        drag_start: Some(Vec2::new(0.0, 0.0)),
+        left_released: true,
+        ..default()
+    });
+
+    let _ = world.run_system_once(band_select_system);
+
+    let selection = world.resource::<TowerMultiSelection>();
+    assert!(selection.towers.contains(&inside_a));
+    assert!(selection.towers.contains(&inside_b));
+    assert!(!selection.towers.contains(&outside));
+}
+
+#[test]
+fn test_targeting_mode_changes_chosen_enemy() {
+    let mut world = World::new();
+    let tower = spawn_tower(&mut world, TowerType::Basic, Vec2::ZERO);
+
+    let mut leader_progress = PathProgress::new();
+    leader_progress.current = 0.8;
+    let leader = world.spawn((
+        Enemy::default(),
+        Health::new(10.0),
+        leader_progress,
+        Transform::from_xyz(40.0, 0.0, 0.0),
+    )).id();
+    let mut tank_progress = PathProgress::new();
+    tank_progress.current = 0.3;
+    let tank = world.spawn((
+        Enemy::default(),
+        Health::new(200.0),
+        tank_progress,
+        Transform::from_xyz(20.0, 0.0, 0.0),
+    )).id();
+
+    let _ = world.run_system_once(tower_targeting_system);
+    assert_eq!(world.get::<Target>(tower).unwrap().entity, Some(leader));
+
+    *world.get_mut::<TargetingMode>(tower).unwrap() = TargetingMode::Strongest;
+    let _ = world.run_system_once(tower_targeting_system);
+    assert_eq!(world.get::<Target>(tower).unwrap().entity, Some(tank));
+
+    *world.get_mut::<TargetingMode>(tower).unwrap() = TargetingMode::Closest;
+    let _ = world.run_system_once(tower_targeting_system);
+    assert_eq!(world.get::<Target>(tower).unwrap().entity, Some(tank));
+
+    *world.get_mut::<TargetingMode>(tower).unwrap() = TargetingMode::Weakest;
+    let _ = world.run_system_once(tower_targeting_system);
+    assert_eq!(world.get::<Target>(tower).unwrap().entity, Some(leader));
+
+    *world.get_mut::<TargetingMode>(tower).unwrap() = TargetingMode::Last;
+    let _ = world.run_system_once(tower_targeting_system);
+    assert_eq!(world.get::<Target>(tower).unwrap().entity, Some(tank));
+}
+
+#[test]
+fn test_targeting_button_cycles_the_selected_tower() {
+    let mut world = selection_world(0);
+    world.insert_resource(MouseInputState::default());
+    world.init_resource::<Events<UiFeedbackEvent>>();
+    let tower = spawn_tower(&mut world, TowerType::Basic, Vec2::ZERO);
+    world.resource_mut::<TowerSelectionState>().set_upgrade_mode(tower);
+    world.spawn((Button, Interaction::Pressed, BackgroundColor::default(), TargetingButton));
+
+    let _ = world.run_system_once(targeting_button_system);
+    assert_eq!(*world.get::<TargetingMode>(tower).unwrap(), TargetingMode::Last);
+
+    let names: Vec<&str> = TargetingMode::ALL.iter().map(|mode| mode.get_name()).collect();
+    assert_eq!(names, vec!["First", "Last", "Strongest", "Weakest", "Closest"]);
+    assert_eq!(TargetingMode::Closest.next(), TargetingMode::First, "the cycle wraps around");
+}
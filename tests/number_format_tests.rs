@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::settings_menu::{apply_number_format_system, GameSettings, NumberFormatToggle};
+
+#[test]
+fn test_counts_use_the_locale_separators() {
+    let english = NumberFormatter::default();
+    assert_eq!(english.count(0u32), "0");
+    assert_eq!(english.count(999u32), "999");
+    assert_eq!(english.count(1_204u32), "1,204");
+    assert_eq!(english.count(1_234_567u64), "1,234,567");
+
+    assert_eq!(NumberFormatter::new(NumberLocale::German, false).count(1_234_567u64), "1.234.567");
+    assert_eq!(NumberFormatter::new(NumberLocale::French, false).count(1_234_567u64), "1 234 567");
+}
+
+#[test]
+fn test_money_puts_the_symbol_where_the_locale_does() {
+    assert_eq!(NumberFormatter::default().money(1_250u32), "$1,250");
+    assert_eq!(NumberFormatter::new(NumberLocale::German, false).money(1_250u32), "1.250 $");
+    assert_eq!(NumberFormatter::new(NumberLocale::French, false).money(40u32), "40 $");
+}
+
+#[test]
+fn test_compact_notation_shortens_large_values_only() {
+    let compact = NumberFormatter::new(NumberLocale::English, true);
+    assert_eq!(compact.count(COMPACT_THRESHOLD - 1), "9,999", "everyday amounts stay exact");
+    assert_eq!(compact.count(12_500u32), "12.5k");
+    assert_eq!(compact.count(12_000u32), "12k");
+    assert_eq!(compact.count(250_000u32), "250k");
+    assert_eq!(compact.count(3_400_000u32), "3.4M");
+    assert_eq!(compact.money(12_599u32), "$12.5k", "tenths round down");
+    assert_eq!(compact.count(999_999u32), "999k", "never rounds up into the next unit");
+
+    let german = NumberFormatter::new(NumberLocale::German, true);
+    assert_eq!(german.money(12_500u32), "12,5k $");
+}
+
+#[test]
+fn test_settings_drive_the_shared_formatter() {
+    let mut settings = GameSettings::default();
+    settings.cycle_number_format(NumberFormatToggle::Locale);
+    settings.cycle_number_format(NumberFormatToggle::Compact);
+    assert_eq!(settings.number_formatter(), NumberFormatter::new(NumberLocale::German, true));
+
+    let mut world = World::new();
+    world.insert_resource(settings.clone());
+    world.init_resource::<NumberFormatter>();
+    world.run_system_once(apply_number_format_system).unwrap();
+    assert_eq!(*world.resource::<NumberFormatter>(), settings.number_formatter());
+
+    let reloaded = GameSettings::from_json(&settings.to_json().unwrap()).unwrap();
+    assert_eq!(reloaded.number_locale, NumberLocale::German);
+    assert!(reloaded.compact_numbers);
+}
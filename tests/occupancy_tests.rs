@@ -0,0 +1,120 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::occupancy::*;
+use tower_defense_bevy::systems::path_generation::grid::GridPos;
+use tower_defense_bevy::systems::path_generation::obstacles::{Obstacle, ObstacleType};
+use tower_defense_bevy::systems::placement_validator::*;
+use tower_defense_bevy::systems::unified_grid::{grid_to_world, world_to_grid, UnifiedGridSystem};
+
+/// World with the occupancy observers registered, as `OccupancyPlugin` does
+fn occupancy_world() -> World {
+    let mut world = World::new();
+    world.init_resource::<UnifiedGridSystem>();
+    world.init_resource::<OccupancyMap>();
+    world.add_observer(record_tower_observer);
+    world.add_observer(record_obstacle_observer);
+    world.add_observer(vacate_observer::<TowerStats>);
+    world.add_observer(vacate_observer::<Obstacle>);
+    world
+}
+
+fn spawn_tower(world: &mut World, cell: GridPos) -> Entity {
+    let position = grid_to_world(cell, world.resource::<UnifiedGridSystem>());
+    world
+        .spawn((TowerStats::new(TowerType::Basic), Transform::from_translation(position.extend(0.0))))
+        .id()
+}
+
+fn towers_and_obstacles(world: &mut World) -> (Vec<(Entity, GridPos)>, Vec<(Entity, GridPos)>) {
+    let unified_grid = UnifiedGridSystem::default();
+    let towers = world
+        .query_filtered::<(Entity, &Transform), With<TowerStats>>()
+        .iter(world)
+        .map(|(entity, transform)| (entity, world_to_grid(transform.translation.truncate(), &unified_grid).unwrap()))
+        .collect();
+    let obstacles = world
+        .query::<(Entity, &Obstacle)>()
+        .iter(world)
+        .map(|(entity, obstacle)| (entity, obstacle.position))
+        .collect();
+    (towers, obstacles)
+}
+
+#[test]
+fn test_spawning_and_despawning_keeps_the_map_current() {
+    let mut world = occupancy_world();
+    let tower = spawn_tower(&mut world, GridPos::new(6, 4));
+    let obstacle = world
+        .spawn(Obstacle { position: GridPos::new(2, 2), obstacle_type: ObstacleType::Rock })
+        .id();
+
+    let occupancy = world.resource::<OccupancyMap>();
+    assert_eq!(occupancy.get(GridPos::new(6, 4)), Some(Occupant::Tower(tower)));
+    assert_eq!(occupancy.tower_at(GridPos::new(6, 4)), Some(tower));
+    assert_eq!(occupancy.get(GridPos::new(2, 2)), Some(Occupant::Obstacle(obstacle)));
+    assert_eq!(occupancy.len(), 2);
+
+    // Selling the tower and clearing the map both go through despawning
+    world.despawn(tower);
+    world.despawn(obstacle);
+    assert!(world.resource::<OccupancyMap>().is_empty());
+}
+
+#[test]
+fn test_a_shared_cell_passes_to_the_remaining_entity() {
+    let mut world = occupancy_world();
+    let first = spawn_tower(&mut world, GridPos::new(3, 3));
+    let second = spawn_tower(&mut world, GridPos::new(3, 3));
+    assert_eq!(world.resource::<OccupancyMap>().tower_at(GridPos::new(3, 3)), Some(second));
+
+    world.despawn(second);
+    let occupancy = world.resource::<OccupancyMap>();
+    assert_eq!(occupancy.tower_at(GridPos::new(3, 3)), Some(first));
+    assert_eq!(occupancy.cell_of(second), None);
+
+    let (towers, obstacles) = towers_and_obstacles(&mut world);
+    assert!(occupancy_mismatches(world.resource::<OccupancyMap>(), towers, obstacles).is_empty());
+}
+
+#[test]
+fn test_moved_towers_take_their_new_cell() {
+    let mut world = occupancy_world();
+    let tower = spawn_tower(&mut world, GridPos::new(4, 4));
+    let moved_to = grid_to_world(GridPos::new(9, 4), world.resource::<UnifiedGridSystem>());
+    world.get_mut::<Transform>(tower).unwrap().translation = moved_to.extend(0.0);
+    world.run_system_once(track_moved_towers_system).unwrap();
+
+    let occupancy = world.resource::<OccupancyMap>();
+    assert_eq!(occupancy.tower_at(GridPos::new(9, 4)), Some(tower));
+    assert!(!occupancy.is_occupied(GridPos::new(4, 4)));
+}
+
+#[test]
+fn test_mismatches_are_reported() {
+    let mut world = occupancy_world();
+    let tower = spawn_tower(&mut world, GridPos::new(8, 8));
+
+    // A tower the map never heard of, and an entry for a despawned one
+    let mut stale = OccupancyMap::default();
+    stale.occupy(GridPos::new(1, 1), Occupant::Tower(Entity::from_raw(999)));
+    let mismatches = occupancy_mismatches(&stale, [(tower, GridPos::new(8, 8))], []);
+    assert_eq!(mismatches.len(), 2, "{:?}", mismatches);
+
+    let (towers, obstacles) = towers_and_obstacles(&mut world);
+    assert!(occupancy_mismatches(world.resource::<OccupancyMap>(), towers, obstacles).is_empty());
+}
+
+#[test]
+fn test_validator_refuses_occupied_cells() {
+    let unified_grid = UnifiedGridSystem::default();
+    let path = vec![Vec2::new(-600.0, 10.0), Vec2::new(600.0, 10.0)];
+    let mut occupancy = OccupancyMap::default();
+    occupancy.occupy(GridPos::new(5, 5), Occupant::Obstacle(Entity::from_raw(1)));
+    occupancy.occupy(GridPos::new(6, 5), Occupant::Tower(Entity::from_raw(2)));
+
+    let validator = PlacementValidator::new(&unified_grid, None, &path, &occupancy, TOWER_FOOTPRINT);
+    assert_eq!(validator.is_cell_buildable(GridPos::new(5, 5)), PlacementVerdict::Blocked);
+    assert_eq!(validator.is_cell_buildable(GridPos::new(6, 5)), PlacementVerdict::Occupied);
+    assert_eq!(validator.is_cell_buildable(GridPos::new(5, 15)), PlacementVerdict::Buildable);
+}
@@ -0,0 +1,134 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use std::time::Duration;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::combat_system::{projectile_spawning_system, Target, TowerFiredEvent};
+use tower_defense_bevy::systems::input::{InputHandler, InputMappingRegistry};
+use tower_defense_bevy::systems::overclock_system::{OverclockHandler, OVERCLOCK_KEY};
+use tower_defense_bevy::systems::tower_ui::TowerSelectionState;
+
+/// Simulate `seconds` of heat in 0.1s steps, returning how many ticks overheated
+fn run_heat(heat: &mut Heat, seconds: f32) -> usize {
+    let steps = (seconds / 0.1).round() as usize;
+    (0..steps).filter(|_| heat.tick(0.1)).count()
+}
+
+#[test]
+fn test_overclock_builds_heat_until_shutdown() {
+    let mut heat = Heat::default();
+    assert_eq!(heat.fire_rate_multiplier(), 1.0);
+
+    assert!(heat.toggle_overclock());
+    assert_eq!(heat.fire_rate_multiplier(), OVERCLOCK_FIRE_RATE_MULTIPLIER);
+
+    run_heat(&mut heat, 3.0);
+    assert!(heat.current > 0.0 && heat.current < MAX_HEAT);
+    assert!(!heat.is_shut_down());
+
+    // Keep running until it overheats exactly once
+    assert_eq!(run_heat(&mut heat, 4.0), 1);
+    assert!(heat.is_shut_down());
+    assert!(!heat.overclocked);
+    assert_eq!(heat.fire_rate_multiplier(), 1.0);
+    assert!(!heat.toggle_overclock(), "overheated tower should refuse overclock");
+
+    run_heat(&mut heat, OVERHEAT_SHUTDOWN_SECONDS + 0.1);
+    assert!(!heat.is_shut_down());
+    assert!(heat.toggle_overclock());
+}
+
+#[test]
+fn test_heat_dissipates_when_not_overclocked() {
+    let mut heat = Heat { current: 50.0, ..default() };
+    run_heat(&mut heat, 1.0);
+    assert!((heat.current - (50.0 - HEAT_DISSIPATION_PER_SECOND)).abs() < 0.01);
+
+    run_heat(&mut heat, 10.0);
+    assert_eq!(heat.current, 0.0);
+}
+
+#[test]
+fn test_vent_restores_overheated_tower() {
+    let mut heat = Heat::default();
+    heat.toggle_overclock();
+    run_heat(&mut heat, 7.0);
+    assert!(heat.is_shut_down());
+
+    heat.vent();
+    assert!(!heat.is_shut_down());
+    assert_eq!(heat.fraction(), 0.0);
+}
+
+/// World with one tower whose last shot was `since_last_shot` seconds ago
+fn tower_world(heat: Heat, since_last_shot: f32) -> World {
+    let mut world = World::new();
+    let mut time = Time::<()>::default();
+    time.advance_by(Duration::from_secs(10));
+    world.insert_resource(time);
+    world.init_resource::<Events<TowerFiredEvent>>();
+
+    let enemy = world.spawn((Enemy::default(), Transform::from_xyz(50.0, 0.0, 0.0))).id();
+    world.spawn((
+        TowerStats::new(TowerType::Basic),
+        Transform::default(),
+        Target { entity: Some(enemy), last_shot_time: 10.0 - since_last_shot },
+        heat,
+    ));
+    world
+}
+
+fn projectile_count(world: &mut World) -> usize {
+    world.query::<&Projectile>().iter(world).count()
+}
+
+#[test]
+fn test_overclocked_tower_fires_faster() {
+    let cooldown = 1.0 / TowerStats::new(TowerType::Basic).fire_rate;
+    // Too soon for a normal tower, but past the overclocked cooldown
+    let since_last_shot = cooldown / 1.25;
+
+    let mut normal = tower_world(Heat::default(), since_last_shot);
+    let _ = normal.run_system_once(projectile_spawning_system);
+    assert_eq!(projectile_count(&mut normal), 0);
+
+    let mut overclocked_heat = Heat::default();
+    overclocked_heat.toggle_overclock();
+    let mut overclocked = tower_world(overclocked_heat, since_last_shot);
+    let _ = overclocked.run_system_once(projectile_spawning_system);
+    assert_eq!(projectile_count(&mut overclocked), 1);
+}
+
+#[test]
+fn test_overheated_tower_holds_fire() {
+    let mut heat = Heat::default();
+    heat.toggle_overclock();
+    run_heat(&mut heat, 7.0);
+    assert!(heat.is_shut_down());
+
+    let mut world = tower_world(heat, 100.0);
+    let _ = world.run_system_once(projectile_spawning_system);
+    assert_eq!(projectile_count(&mut world), 0);
+}
+
+#[test]
+fn test_overclock_key_is_a_rebindable_handler() {
+    let mut world = World::new();
+    world.insert_resource(State::new(AppState::Playing));
+    let tower = world.spawn((TowerStats::new(TowerType::Basic), Heat::default())).id();
+    let mut selection = TowerSelectionState::default();
+    selection.set_upgrade_mode(tower);
+    world.insert_resource(selection);
+
+    let mut registry = InputMappingRegistry::new();
+    registry.register_handler(std::sync::Arc::new(OverclockHandler)).unwrap();
+    registry.rebind(OverclockHandler.get_id(), OVERCLOCK_KEY, KeyCode::KeyP).unwrap();
+
+    assert!(!registry.process_input(&mut world, OVERCLOCK_KEY), "the old key is free once rebound");
+    assert!(registry.process_input(&mut world, KeyCode::KeyP));
+    assert!(world.get::<Heat>(tower).unwrap().overclocked);
+
+    // Towers under construction refuse, but the key press is still taken
+    world.entity_mut(tower).insert(Constructing::new(1.0, ResourceCost::money(40)));
+    assert!(registry.process_input(&mut world, KeyCode::KeyP));
+    assert!(world.get::<Heat>(tower).unwrap().overclocked);
+}
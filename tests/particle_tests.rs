@@ -0,0 +1,90 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use std::time::Duration;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::combat_system::{collision_system, EnemyDamagedEvent, EnemyKilledEvent, WaveStatus};
+use tower_defense_bevy::systems::particles::*;
+
+fn particle_world() -> World {
+    let mut world = World::new();
+    world.init_resource::<Time>();
+    world.init_resource::<EffectBudget>();
+    world
+}
+
+fn particles(world: &mut World) -> Vec<Particle> {
+    world.query::<&Particle>().iter(world).copied().collect()
+}
+
+fn emitters(world: &mut World) -> usize {
+    world.query::<&ParticleEmitter>().iter(world).count()
+}
+
+#[test]
+fn test_velocity_stays_within_the_spread() {
+    let mut emitter = ParticleEmitter::impact(Color::WHITE);
+    emitter.direction = Vec2::X;
+    emitter.angle_spread = 1.0;
+
+    assert_eq!(emitter.velocity(0.5, 0.0), Vec2::X * emitter.min_speed);
+    let widest = emitter.velocity(1.0, 1.0);
+    assert!((widest.to_angle() - 0.5).abs() < 1e-5);
+    assert!((widest.length() - emitter.max_speed).abs() < 1e-3);
+}
+
+#[test]
+fn test_emitter_bursts_once_and_particles_fade_out() {
+    let mut world = particle_world();
+    let emitter = ParticleEmitter::dust();
+    world.run_system_once(move |mut commands: Commands| spawn_particles(&mut commands, Vec2::new(40.0, 0.0), emitter)).unwrap();
+
+    world.run_system_once(particle_emitter_system).unwrap();
+    assert_eq!(emitters(&mut world), 0, "emitters burst once");
+    assert_eq!(particles(&mut world).len(), emitter.burst_count as usize);
+
+    world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(emitter.lifetime / 2.0));
+    world.run_system_once(particle_system).unwrap();
+    let mut sprites = world.query_filtered::<(&Sprite, &Transform), With<Particle>>();
+    for (sprite, transform) in sprites.iter(&world) {
+        assert!(sprite.color.alpha() < emitter.start_color.alpha(), "fades along the gradient");
+        assert_ne!(transform.translation.truncate(), Vec2::new(40.0, 0.0), "particles spread out");
+    }
+
+    world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(emitter.lifetime));
+    world.run_system_once(particle_system).unwrap();
+    assert!(particles(&mut world).is_empty());
+}
+
+#[test]
+fn test_particle_cap_cuts_bursts_short() {
+    let mut world = particle_world();
+    world.resource_mut::<EffectBudget>().set_cap(EffectCategory::Particle, 5);
+    world
+        .run_system_once(|mut commands: Commands| {
+            spawn_particles(&mut commands, Vec2::ZERO, ParticleEmitter::explosion(40.0));
+            spawn_particles(&mut commands, Vec2::ZERO, ParticleEmitter::explosion(40.0));
+        })
+        .unwrap();
+
+    world.run_system_once(particle_emitter_system).unwrap();
+    assert_eq!(particles(&mut world).len(), 5);
+    assert_eq!(emitters(&mut world), 0);
+}
+
+#[test]
+fn test_projectile_impacts_emit_sparks() {
+    let mut world = particle_world();
+    world.init_resource::<Economy>();
+    world.init_resource::<Score>();
+    world.init_resource::<WaveStatus>();
+    world.init_resource::<Events<EnemyKilledEvent>>();
+    world.init_resource::<Events<EnemyDamagedEvent>>();
+    let enemy = world.spawn((Enemy::default(), Health::new(100.0), Transform::default())).id();
+    world.spawn((
+        Transform::default(),
+        Projectile::new(40.0, 200.0, enemy, Vec2::ZERO, TowerType::Missile).with_splash(40.0),
+    ));
+
+    world.run_system_once(collision_system).unwrap();
+    assert_eq!(emitters(&mut world), 2, "impact sparks and an explosion");
+}
@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::enemy_system::*;
+use tower_defense_bevy::systems::settings_menu::GameSettings;
+
+#[test]
+fn test_segment_coverage_scales_with_towers_in_range() {
+    let start = Vec2::ZERO;
+    let end = Vec2::new(200.0, 0.0);
+
+    assert_eq!(segment_coverage(start, end, &[]), 0.0);
+    assert_eq!(segment_coverage(start, end, &[(Vec2::new(100.0, 500.0), 80.0)]), 0.0);
+
+    let one_tower = [(Vec2::new(100.0, 0.0), 500.0)];
+    assert!((segment_coverage(start, end, &one_tower) - 1.0 / FULL_COVERAGE_TOWERS).abs() < 1e-6);
+
+    let saturated = [(Vec2::new(100.0, 0.0), 500.0); 5];
+    assert_eq!(segment_coverage(start, end, &saturated), 1.0);
+
+    // A tower reaching only part of the segment covers only part of it
+    let partial = segment_coverage(start, end, &[(Vec2::ZERO, 100.0); 3]);
+    assert!(partial > 0.3 && partial < 0.7, "partial coverage was {partial}");
+}
+
+#[test]
+fn test_coverage_color_runs_red_to_green() {
+    let red = coverage_color(0.0).to_srgba();
+    let green = coverage_color(1.0).to_srgba();
+    assert!(red.red > red.green);
+    assert!(green.green > green.red);
+    assert_eq!(coverage_color(-1.0), coverage_color(0.0));
+    assert_eq!(coverage_color(2.0), coverage_color(1.0));
+}
+
+fn segment_colors(world: &mut World) -> Vec<(Vec2, Color)> {
+    let mut colors: Vec<(Vec2, Color)> = world
+        .query::<(&PathSegment, &Sprite)>()
+        .iter(world)
+        .map(|(segment, sprite)| (segment.start, sprite.color))
+        .collect();
+    colors.sort_by(|a, b| a.0.x.total_cmp(&b.0.x));
+    colors
+}
+
+#[test]
+fn test_path_segments_are_tinted_when_towers_change() {
+    let mut world = World::new();
+    world.insert_resource(EnemyPath::new(vec![
+        Vec2::new(-400.0, 0.0),
+        Vec2::new(0.0, 0.0),
+        Vec2::new(400.0, 0.0),
+    ]));
+    let mut schedule = Schedule::default();
+    schedule.add_systems((path_visualization_system, path_coverage_tint_system).chain());
+
+    schedule.run(&mut world);
+    let colors = segment_colors(&mut world);
+    assert_eq!(colors.len(), 2);
+    assert!(colors.iter().all(|(_, color)| *color == coverage_color(0.0)), "no towers, all red");
+
+    // A tower over the second segment turns it towards green
+    let tower = world
+        .spawn((TowerStats::new(TowerType::Basic), Transform::from_xyz(200.0, 40.0, 0.0)))
+        .id();
+    schedule.run(&mut world);
+    let colors = segment_colors(&mut world);
+    assert_eq!(colors[0].1, coverage_color(0.0));
+    assert_ne!(colors[1].1, coverage_color(0.0));
+
+    // Towers still under construction don't count
+    world.entity_mut(tower).insert(Constructing::new(1.0, ResourceCost::zero()));
+    schedule.run(&mut world);
+    assert_eq!(segment_colors(&mut world)[1].1, coverage_color(0.0));
+}
+
+fn mark_count(world: &mut World) -> usize {
+    world.query_filtered::<(), With<PathMark>>().iter(world).count()
+}
+
+#[test]
+fn test_path_style_change_rebuilds_marks() {
+    let mut world = World::new();
+    world.insert_resource(EnemyPath::new(vec![Vec2::new(-200.0, 0.0), Vec2::new(200.0, 0.0)]));
+    world.insert_resource(GameSettings { path_style: PathStyle::Line, ..default() });
+    let mut schedule = Schedule::default();
+    schedule.add_systems((path_visualization_system, path_coverage_tint_system).chain());
+
+    schedule.run(&mut world);
+    assert_eq!(mark_count(&mut world), 0, "the plain line draws no marks");
+
+    world.resource_mut::<GameSettings>().path_style = PathStyle::Dots;
+    schedule.run(&mut world);
+    let dots = mark_count(&mut world);
+    assert_eq!(dots, (400.0 / PathStyle::Dots.mark_spacing().unwrap()) as usize);
+    assert_eq!(world.query::<&PathSegment>().iter(&world).count(), 1, "old segments are cleaned up");
+    let tinted = world
+        .query_filtered::<&Sprite, With<PathMark>>()
+        .iter(&world)
+        .all(|sprite| sprite.color == coverage_color(0.0));
+    assert!(tinted, "marks take their segment's coverage tint");
+
+    // Chevrons draw two arms per mark, and switching again leaves no stale dots behind
+    world.resource_mut::<GameSettings>().path_style = PathStyle::Chevrons;
+    schedule.run(&mut world);
+    assert_eq!(mark_count(&mut world), 2 * (400.0 / PathStyle::Chevrons.mark_spacing().unwrap()) as usize);
+}
+
+#[test]
+fn test_flow_dashes_wrap_along_their_segment() {
+    let dash = PathFlowDash { start: -100.0, cycle: 200.0 };
+    assert_eq!(flow_dash_position(-50.0, 30.0, &dash), -20.0);
+    assert_eq!(flow_dash_position(90.0, 30.0, &dash), -80.0, "dashes re-enter at the segment start");
+    assert_eq!(flow_dash_position(10.0, 0.0, &PathFlowDash { start: 0.0, cycle: 0.0 }), 10.0);
+}
+
+#[test]
+fn test_path_style_cycles_through_every_style() {
+    let mut style = PathStyle::Line;
+    for _ in 0..PathStyle::ALL.len() {
+        style = style.next();
+    }
+    assert_eq!(style, PathStyle::Line);
+    assert_eq!(PathStyle::Line.mark_spacing(), None);
+}
@@ -124,14 +124,15 @@ fn test_deterministic_path_generation() {
 #[test]
 fn test_wave_manager_integration() {
     // Create a wave manager
-    let mut wave_manager = WaveManager::new();
+    let mut wave_plan = WavePlan::default();
+    let mut wave_runtime = WaveRuntime::new();
     
     // Test path generation for progressive waves
     let mut previous_paths = Vec::new();
     
     for wave_num in 1..=5 {
         // Start a new wave
-        wave_manager.start_wave(5);
+        start_wave(&mut wave_plan, &mut wave_runtime, 5);
         
         // Generate path for this wave
         let current_path = generate_level_path(wave_num);
@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::input_system::{tower_placement_preview_system, MouseInputState, PlacementGhost, PlacementPreview};
+use tower_defense_bevy::systems::obstacle_rendering::ObstacleGrid;
+use tower_defense_bevy::systems::occupancy::OccupancyMap;
+use tower_defense_bevy::systems::path_generation::grid::GridPos;
+use tower_defense_bevy::systems::tower_rendering::{setup_range_ring_assets, tower_pattern_color, RangeRingAssets};
+use tower_defense_bevy::systems::tower_ui::TowerSelectionState;
+use tower_defense_bevy::systems::unified_grid::{grid_to_world, UnifiedGridSystem};
+
+fn preview_world(tower_type: TowerType, cursor: Vec2) -> World {
+    let mut world = World::new();
+    world.init_resource::<UnifiedGridSystem>();
+    world.init_resource::<ObstacleGrid>();
+    world.init_resource::<OccupancyMap>();
+    world.insert_resource(GameConstants::default());
+    world.insert_resource(EnemyPath::new(vec![Vec2::new(-600.0, 10.0), Vec2::new(600.0, 10.0)]));
+    world.insert_resource(Economy::new(1000, 100, 100, 100));
+    world.init_resource::<Assets<Mesh>>();
+    world.init_resource::<Assets<ColorMaterial>>();
+    world.insert_resource(MouseInputState { world_position: cursor, ..default() });
+    let mut selection = TowerSelectionState::default();
+    selection.set_placement_mode(Some(tower_type));
+    world.insert_resource(selection);
+
+    world.run_system_once(setup_range_ring_assets).unwrap();
+    world.run_system_once(tower_placement_preview_system).unwrap();
+    world
+}
+
+/// Material and radius of the previewed range ring
+fn range_ring(world: &mut World) -> (Handle<ColorMaterial>, f32) {
+    let mut rings = world.query_filtered::<(&MeshMaterial2d<ColorMaterial>, &Transform), With<PlacementPreview>>();
+    let rings: Vec<_> = rings.iter(world).map(|(material, transform)| (material.0.clone(), transform.scale.x)).collect();
+    assert_eq!(rings.len(), 1);
+    rings[0].clone()
+}
+
+#[test]
+fn test_ghost_is_snapped_to_the_hovered_cell() {
+    let cell_centre = grid_to_world(GridPos::new(5, 5), &UnifiedGridSystem::default());
+    let mut world = preview_world(TowerType::Laser, cell_centre + Vec2::new(3.0, -4.0));
+
+    let mut ghosts = world.query_filtered::<(&Sprite, &Transform), With<PlacementGhost>>();
+    let (sprite, transform) = ghosts.single(&world).unwrap();
+    assert_eq!(transform.translation.truncate(), cell_centre);
+    assert_eq!(sprite.color, tower_pattern_color(TowerType::Laser).with_alpha(0.5));
+}
+
+#[test]
+fn test_range_ring_matches_range_and_is_green_on_a_free_cell() {
+    let cell_centre = grid_to_world(GridPos::new(5, 5), &UnifiedGridSystem::default());
+    let mut world = preview_world(TowerType::Missile, cell_centre);
+
+    let (material, radius) = range_ring(&mut world);
+    assert_eq!(material, world.resource::<RangeRingAssets>().valid);
+    assert_eq!(radius, TowerStats::new(TowerType::Missile).range);
+}
+
+#[test]
+fn test_range_ring_is_red_on_the_path() {
+    let mut world = preview_world(TowerType::Basic, Vec2::new(0.0, 10.0));
+
+    let (material, _) = range_ring(&mut world);
+    assert_eq!(material, world.resource::<RangeRingAssets>().invalid);
+}
+
+#[test]
+fn test_no_preview_without_a_selected_type() {
+    let mut world = preview_world(TowerType::Basic, Vec2::ZERO);
+    world.resource_mut::<TowerSelectionState>().clear_selection();
+    world.run_system_once(tower_placement_preview_system).unwrap();
+
+    let mut previews = world.query_filtered::<(), With<PlacementPreview>>();
+    assert_eq!(previews.iter(&world).count(), 0);
+}
@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::obstacle_rendering::ObstacleGrid;
+use tower_defense_bevy::systems::occupancy::{record_tower_observer, Occupant, OccupancyMap};
+use tower_defense_bevy::systems::path_generation::grid::{CellType, GridPos, PathGrid};
+use tower_defense_bevy::systems::placement_validator::*;
+use tower_defense_bevy::systems::unified_grid::{grid_to_world, UnifiedGridSystem};
+
+/// A cell well clear of the test path
+const FREE_CELL: GridPos = GridPos { x: 5, y: 5 };
+
+fn test_path() -> Vec<Vec2> {
+    vec![Vec2::new(-600.0, 10.0), Vec2::new(600.0, 10.0)]
+}
+
+#[test]
+fn test_verdict_reasons() {
+    let unified_grid = UnifiedGridSystem::default();
+    let mut path_grid = PathGrid::new_unified();
+    path_grid.set_cell(GridPos::new(3, 3), CellType::Blocked);
+    path_grid.set_cell(GridPos::new(4, 3), CellType::Path);
+    let path = test_path();
+    let tower_at = grid_to_world(GridPos::new(20, 4), &unified_grid);
+    let mut occupancy = OccupancyMap::default();
+    occupancy.occupy(GridPos::new(20, 4), Occupant::Tower(Entity::from_raw(1)));
+    let validator = PlacementValidator::new(&unified_grid, Some(&path_grid), &path, &occupancy, TOWER_FOOTPRINT);
+
+    assert_eq!(validator.is_cell_buildable(FREE_CELL), PlacementVerdict::Buildable);
+    assert_eq!(validator.is_cell_buildable(GridPos::new(3, 3)), PlacementVerdict::Blocked);
+    assert_eq!(validator.is_cell_buildable(GridPos::new(4, 3)), PlacementVerdict::OnPath);
+    assert_eq!(validator.is_cell_buildable(GridPos::new(20, 4)), PlacementVerdict::Occupied);
+    assert_eq!(validator.is_cell_buildable(GridPos::new(21, 4)), PlacementVerdict::Buildable, "neighbouring cells are free");
+    assert_eq!(validator.check_position(tower_at + Vec2::new(15.0, 0.0)), PlacementVerdict::Occupied);
+    assert_eq!(validator.check_position(Vec2::new(5000.0, 0.0)), PlacementVerdict::OutOfZone);
+    assert_eq!(validator.is_cell_buildable(GridPos::new(500, 0)), PlacementVerdict::OutOfZone);
+}
+
+#[test]
+fn test_path_line_blocks_cells_not_marked_as_path() {
+    let unified_grid = UnifiedGridSystem::default();
+    let path = test_path();
+    let occupancy = OccupancyMap::default();
+    let validator = PlacementValidator::new(&unified_grid, None, &path, &occupancy, TOWER_FOOTPRINT);
+
+    // The row just above y = 0 has its centre 10px from the path line
+    assert_eq!(validator.is_cell_buildable(GridPos::new(10, 9)), PlacementVerdict::OnPath);
+    assert_eq!(validator.is_cell_buildable(GridPos::new(10, 11)), PlacementVerdict::Buildable);
+}
+
+#[test]
+fn test_funds_are_checked_after_the_site() {
+    let unified_grid = UnifiedGridSystem::default();
+    let path = test_path();
+    let broke = Economy::new(10, 0, 0, 0);
+    let cost = TowerType::Basic.get_cost();
+
+    let occupancy = OccupancyMap::default();
+    let validator = PlacementValidator::new(&unified_grid, None, &path, &occupancy, TOWER_FOOTPRINT).with_funds(&broke, &cost);
+    assert_eq!(validator.is_cell_buildable(FREE_CELL), PlacementVerdict::InsufficientFunds);
+    // A bad site is reported ahead of the missing money
+    assert_eq!(validator.check_position(Vec2::new(0.0, 10.0)), PlacementVerdict::OnPath);
+
+    let rich = Economy::new(1000, 0, 0, 0);
+    let validator = PlacementValidator::new(&unified_grid, None, &path, &occupancy, TOWER_FOOTPRINT).with_funds(&rich, &cost);
+    assert!(validator.is_cell_buildable(FREE_CELL).is_buildable());
+}
+
+#[test]
+fn test_placement_context_reads_world_state() {
+    let mut world = World::new();
+    world.init_resource::<UnifiedGridSystem>();
+    world.init_resource::<ObstacleGrid>();
+    world.insert_resource(GameConstants::default());
+    world.insert_resource(EnemyPath::new(test_path()));
+    world.init_resource::<OccupancyMap>();
+    world.add_observer(record_tower_observer);
+    let tower_at = grid_to_world(FREE_CELL, &UnifiedGridSystem::default());
+    world.spawn((TowerStats::new(TowerType::Basic), Transform::from_translation(tower_at.extend(0.0))));
+
+    let verdicts = world
+        .run_system_once(|placement: PlacementContext| {
+            let validator = placement.validator();
+            (validator.is_cell_buildable(FREE_CELL), validator.is_cell_buildable(GridPos::new(10, 9)))
+        })
+        .unwrap();
+    assert_eq!(verdicts, (PlacementVerdict::Occupied, PlacementVerdict::OnPath));
+}
@@ -0,0 +1,96 @@
+use bevy::prelude::*;
+use tower_defense_bevy::resources::{PLAY_AREA_HEIGHT, PLAY_AREA_WIDTH};
+use tower_defense_bevy::systems::popup_layout::*;
+use tower_defense_bevy::systems::spawn_preview::{entry_label_position, EntryPreview};
+
+const VIEWPORT: Vec2 = Vec2::new(800.0, 600.0);
+
+fn on_screen(rect: Rect, viewport: Vec2) -> bool {
+    rect.min.x >= 0.0 && rect.min.y >= 0.0 && rect.max.x <= viewport.x && rect.max.y <= viewport.y
+}
+
+#[test]
+fn test_popup_uses_preferred_side_when_it_fits() {
+    let anchor = Rect::new(500.0, 100.0, 700.0, 140.0);
+    let size = Vec2::new(200.0, 150.0);
+
+    let placement = place_popup(anchor, size, VIEWPORT, PopupSide::Left);
+    assert_eq!(placement.side, PopupSide::Left);
+    assert_eq!(placement.position, Vec2::new(500.0 - POPUP_GAP - 200.0, 100.0));
+    assert!(placement.rect(size).intersect(anchor).is_empty());
+}
+
+#[test]
+fn test_popup_flips_away_from_screen_edges() {
+    let size = Vec2::new(200.0, 150.0);
+
+    let near_left = Rect::new(20.0, 100.0, 120.0, 140.0);
+    let placement = place_popup(near_left, size, VIEWPORT, PopupSide::Left);
+    assert_eq!(placement.side, PopupSide::Right);
+    assert_eq!(placement.position.x, 120.0 + POPUP_GAP);
+
+    let near_top = Rect::new(100.0, 10.0, 200.0, 40.0);
+    let placement = place_popup(near_top, size, VIEWPORT, PopupSide::Above);
+    assert_eq!(placement.side, PopupSide::Below);
+    assert_eq!(placement.position.y, 40.0 + POPUP_GAP);
+    assert!(placement.rect(size).intersect(near_top).is_empty());
+}
+
+#[test]
+fn test_popup_is_clamped_on_screen() {
+    // Lined up with an anchor near the bottom, the popup would run off screen
+    let size = Vec2::new(200.0, 150.0);
+    let near_bottom = Rect::new(500.0, 560.0, 700.0, 590.0);
+    let placement = place_popup(near_bottom, size, VIEWPORT, PopupSide::Left);
+    assert_eq!(placement.position.y, VIEWPORT.y - size.y - VIEWPORT_MARGIN);
+    assert!(on_screen(placement.rect(size), VIEWPORT));
+
+    // No room on either side at a small resolution
+    let small = Vec2::new(300.0, 200.0);
+    let placement = place_popup(Rect::new(100.0, 10.0, 200.0, 40.0), Vec2::new(150.0, 50.0), small, PopupSide::Left);
+    assert!(on_screen(placement.rect(Vec2::new(150.0, 50.0)), small));
+
+    // Larger than the screen: pinned to the top-left margin
+    let huge = Vec2::new(1000.0, 900.0);
+    let placement = place_popup(Rect::new(300.0, 300.0, 320.0, 320.0), huge, VIEWPORT, PopupSide::Right);
+    assert_eq!(placement.position, Vec2::splat(VIEWPORT_MARGIN));
+}
+
+#[test]
+fn test_flip_prefers_the_roomier_side_when_neither_fits() {
+    let size = Vec2::new(500.0, 50.0);
+    let anchor = Rect::new(150.0, 100.0, 250.0, 140.0);
+    let placement = place_popup(anchor, size, VIEWPORT, PopupSide::Left);
+    assert_eq!(placement.side, PopupSide::Right);
+    assert!(on_screen(placement.rect(size), VIEWPORT));
+}
+
+#[test]
+fn test_world_and_ui_coordinates_round_trip() {
+    let area = Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT);
+    assert_eq!(world_to_ui(Vec2::ZERO, area), area / 2.0);
+    assert_eq!(world_to_ui(Vec2::new(-640.0, 360.0), area), Vec2::ZERO);
+    let point = Vec2::new(123.0, -45.0);
+    assert_eq!(ui_to_world(world_to_ui(point, area), area), point);
+}
+
+#[test]
+fn test_wave_preview_labels_stay_inside_the_play_area() {
+    let half_area = Vec2::new(PLAY_AREA_WIDTH, PLAY_AREA_HEIGHT) / 2.0;
+    let label = "Wave 12: 30 (100%)\nArmored, Swarm";
+    let size = estimated_text_size(label, 14.0);
+
+    for (position, direction) in [
+        (Vec2::new(-616.0, 0.0), Vec2::X),
+        (Vec2::new(616.0, -336.0), Vec2::NEG_X),
+        (Vec2::new(600.0, 336.0), Vec2::NEG_Y),
+        (Vec2::new(-616.0, -336.0), Vec2::Y),
+    ] {
+        let entry = EntryPreview { position, direction, enemies: 30, share: 1.0 };
+        let center = entry_label_position(&entry, label);
+        let rect = Rect::from_center_size(center, size);
+        assert!(rect.min.cmpge(-half_area).all() && rect.max.cmple(half_area).all(), "{:?} at {:?}", rect, position);
+        // The label doesn't cover the arrow's base
+        assert!(!rect.contains(position), "{:?} at {:?}", rect, position);
+    }
+}
@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::combat_system::{collision_system, kill_reward, EnemyDamagedEvent, EnemyKilledEvent, WaveStatus};
+use tower_defense_bevy::systems::results_screen::*;
+
+fn both() -> PrestigeSet {
+    let mut set = PrestigeSet::default();
+    set.insert(PrestigeModifier::ToughEnemies);
+    set.insert(PrestigeModifier::LeanStart);
+    set
+}
+
+#[test]
+fn test_stacked_modifiers_multiply_the_score() {
+    let none = PrestigeSet::default();
+    assert_eq!(none.score_multiplier(), 1.0);
+    assert_eq!(none.label(), "None");
+    assert_eq!(none.starting_money(155), 155);
+
+    let set = both();
+    assert!((set.score_multiplier() - 1.5).abs() < 1e-6);
+    assert_eq!(set.label(), "Tough Enemies + Lean Start");
+    assert!((set.enemy_health_multiplier() - 1.2).abs() < 1e-6);
+    assert_eq!(set.starting_money(155), 116);
+    assert_eq!(set.scale_points(10), 15);
+}
+
+#[test]
+fn test_modifiers_stay_locked_until_a_win() {
+    let mut profile = PrestigeProfile::default();
+    assert!(!profile.toggle(PrestigeModifier::ToughEnemies));
+    assert!(profile.selected.is_empty());
+
+    assert!(profile.unlock());
+    assert!(!profile.unlock(), "only the first win unlocks");
+    assert!(profile.toggle(PrestigeModifier::ToughEnemies));
+    assert!(profile.toggle(PrestigeModifier::LeanStart));
+    assert!(!profile.toggle(PrestigeModifier::LeanStart));
+    assert!(profile.selected.contains(PrestigeModifier::ToughEnemies));
+
+    let json = profile.to_json().unwrap();
+    assert_eq!(PrestigeProfile::from_json(&json).unwrap(), profile);
+}
+
+#[test]
+fn test_leaderboard_entries_remember_their_prestige() {
+    let mut leaderboard = RunLeaderboard::default();
+    leaderboard.record(LeaderboardEntry {
+        waves: 3,
+        score: 400,
+        date: "2026-10-16".to_string(),
+        seed: 1,
+        mode: LeaderboardMode::FreePlay,
+        prestige: both(),
+    });
+    let json = leaderboard.to_json().unwrap();
+    assert_eq!(RunLeaderboard::from_json(&json).unwrap(), leaderboard);
+    assert_eq!(
+        leaderboard_row(1, &leaderboard.entries[0], "400"),
+        "#1  +3 waves  400 pts  2026-10-16  seed 1  free play  prestige: Tough Enemies + Lean Start"
+    );
+
+    // Boards saved before prestige existed still load
+    let old = r#"{ "entries": [ { "waves_reached": 2, "score": 100, "date": "2025-01-02", "seed": 9 } ] }"#;
+    let loaded = RunLeaderboard::from_json(old).unwrap();
+    assert!(loaded.entries[0].prestige.is_empty());
+
+    let result = FreePlayResult { entry: leaderboard.entries[0].clone(), rank: Some(1), campaign_wave: 10 };
+    assert_eq!(
+        free_play_standing(&result),
+        "Free play leaderboard: #1 (campaign won on wave 10, prestige: Tough Enemies + Lean Start)"
+    );
+}
+
+#[test]
+fn test_selection_labels_follow_the_pick() {
+    let mut selected = PrestigeSet::default();
+    assert_eq!(
+        prestige_option_label(PrestigeModifier::ToughEnemies, &selected),
+        "[ ] Tough Enemies (+20% enemy HP)"
+    );
+    selected.toggle(PrestigeModifier::ToughEnemies);
+    assert_eq!(
+        prestige_option_label(PrestigeModifier::ToughEnemies, &selected),
+        "[x] Tough Enemies (+20% enemy HP)"
+    );
+    assert_eq!(prestige_summary(&selected), "Next run score: x1.25");
+}
+
+#[test]
+fn test_kills_score_with_the_run_prestige() {
+    let mut world = World::new();
+    world.init_resource::<Economy>();
+    world.init_resource::<Score>();
+    world.init_resource::<WaveStatus>();
+    world.init_resource::<Events<EnemyKilledEvent>>();
+    world.init_resource::<Events<EnemyDamagedEvent>>();
+    world.insert_resource(RunPrestige { modifiers: both() });
+    let enemy = world.spawn((Enemy::default(), Health::new(10.0), Transform::default())).id();
+    world.spawn((Projectile::new(50.0, 300.0, enemy, Vec2::ZERO, TowerType::Laser), Transform::default()));
+
+    world.run_system_once(collision_system).unwrap();
+    let reward = kill_reward(TowerType::Laser);
+    assert_eq!(world.resource::<Score>().current, both().scale_points(reward));
+    assert_eq!(world.resource::<Score>().money_earned, reward, "money is not multiplied");
+}
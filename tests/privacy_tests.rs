@@ -0,0 +1,67 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use tower_defense_bevy::components::EnemyType;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::privacy_system::*;
+use tower_defense_bevy::systems::settings_menu::{GameSettings, PrivacyToggle};
+
+const SETTINGS_V2: &str = include_str!("fixtures/settings_v2.json");
+
+#[test]
+fn test_privacy_settings_default_on_and_load_from_older_files() {
+    let settings = GameSettings::default();
+    assert!(settings.privacy_enabled(PrivacyToggle::Statistics));
+    assert!(settings.privacy_enabled(PrivacyToggle::SaveRecords));
+
+    let loaded = GameSettings::from_json(SETTINGS_V2).unwrap();
+    assert!(loaded.statistics_enabled && loaded.save_records, "files from before the privacy settings keep recording");
+}
+
+#[test]
+fn test_opted_out_codex_records_nothing() {
+    let mut world = World::new();
+    let mut settings = GameSettings::default();
+    settings.toggle_privacy(PrivacyToggle::Statistics);
+    world.insert_resource(settings);
+    world.init_resource::<EnemyCodex>();
+    world.run_system_once(apply_statistics_opt_out_system).unwrap();
+
+    let mut codex = world.resource_mut::<EnemyCodex>();
+    assert!(codex.opted_out);
+    assert_eq!(codex.record_kill(EnemyType::Basic), None);
+    codex.record_leak(EnemyType::Basic);
+    codex.record_damage(EnemyType::Basic, 10.0);
+    assert_eq!(codex.stats(EnemyType::Basic), EnemyTypeStats::default());
+
+    world.resource_mut::<GameSettings>().toggle_privacy(PrivacyToggle::Statistics);
+    world.run_system_once(apply_statistics_opt_out_system).unwrap();
+    world.resource_mut::<EnemyCodex>().record_leak(EnemyType::Basic);
+    assert_eq!(world.resource::<EnemyCodex>().stats(EnemyType::Basic).leaks, 1, "opting back in records again");
+}
+
+#[test]
+fn test_opt_out_is_not_written_to_the_codex_file() {
+    let codex = EnemyCodex { opted_out: true, ..default() };
+    let loaded = EnemyCodex::from_json(&codex.to_json().unwrap()).unwrap();
+    assert!(!loaded.opted_out, "the setting decides, not the profile");
+}
+
+#[test]
+fn test_wipe_removes_local_data_and_keeps_settings() {
+    let root = std::env::temp_dir().join(format!("td_privacy_wipe_{}", std::process::id()));
+    std::fs::create_dir_all(root.join(LOCAL_DATA_DIRS[0])).unwrap();
+    std::fs::write(root.join(LOCAL_DATA_DIRS[0]).join("timeline-1.json"), "{}").unwrap();
+    std::fs::write(root.join(ENEMY_CODEX_FILE), "{}").unwrap();
+    std::fs::write(root.join(LEADERBOARD_FILE), "{}").unwrap();
+    std::fs::write(root.join("settings.json"), "{}").unwrap();
+
+    let report = wipe_local_data_in(&root);
+    assert!(report.failed.is_empty(), "{:?}", report.failed);
+    assert_eq!(report.removed.len(), 3, "missing files are skipped: {:?}", report.removed);
+    assert!(!root.join(ENEMY_CODEX_FILE).exists());
+    assert!(!root.join(LOCAL_DATA_DIRS[0]).exists());
+    assert!(root.join("settings.json").exists());
+
+    assert_eq!(wipe_local_data_in(&root), WipeReport::default(), "a second wipe finds nothing");
+    std::fs::remove_dir_all(&root).unwrap();
+}
@@ -1,6 +1,14 @@
-use tower_defense_bevy::components::Projectile;
-use tower_defense_bevy::resources::TowerType;
-use bevy::prelude::{Entity, Vec2};
+use std::time::Duration;
+use tower_defense_bevy::components::{
+    arc_position, steer, Enemy, Explosion, Health, Projectile, ProjectileKind, MISSILE_TURN_RATE, SPLASH_EDGE_DAMAGE,
+};
+use tower_defense_bevy::resources::{Economy, Score, TowerStats, TowerType, HEAVY_TURRET_LEVEL};
+use tower_defense_bevy::systems::combat_system::{
+    collision_system, muzzle_position, projectile_movement_system, projectile_spawning_system, BarrelCycle, EnemyDamagedEvent, EnemyKilledEvent,
+    Target, TowerFiredEvent, WaveStatus,
+};
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
 
 #[test]
 fn test_projectile_creation() {
@@ -59,4 +67,216 @@ fn test_projectile_damage_variations() {
     assert!(weak_projectile.damage < strong_projectile.damage);
     assert_eq!(weak_projectile.tower_type, TowerType::Basic);
     assert_eq!(strong_projectile.tower_type, TowerType::Tesla);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_muzzle_offset_turns_toward_target() {
+    let tower = Vec2::new(100.0, 100.0);
+    let offset = Vec2::new(18.0, 6.0);
+
+    // Facing +X the offset is used as is
+    assert_eq!(muzzle_position(tower, Vec2::new(300.0, 100.0), offset), tower + offset);
+
+    // Facing +Y it turns a quarter anticlockwise
+    let up = muzzle_position(tower, Vec2::new(100.0, 300.0), offset);
+    assert!(up.distance(tower + Vec2::new(-6.0, 18.0)) < 1e-4, "got {up:?}");
+
+    // A target on top of the tower leaves the offset unrotated
+    assert_eq!(muzzle_position(tower, tower, offset), tower + offset);
+}
+
+#[test]
+fn test_multi_barrel_towers_alternate() {
+    let muzzles = TowerType::Advanced.get_muzzle_offsets(1);
+    assert_eq!(muzzles.len(), 2);
+
+    let mut barrels = BarrelCycle::default();
+    let shots: Vec<Vec2> = (0..4).map(|_| barrels.fire(muzzles)).collect();
+    assert_eq!(shots, vec![muzzles[0], muzzles[1], muzzles[0], muzzles[1]]);
+
+    // The heavy turret adds barrels; a shorter list after a swap still wraps
+    assert!(TowerType::Advanced.get_muzzle_offsets(HEAVY_TURRET_LEVEL).len() > muzzles.len());
+    let mut barrels = BarrelCycle { next: 3 };
+    assert_eq!(barrels.fire(muzzles), muzzles[1]);
+    assert_eq!(barrels.fire(&[]), Vec2::ZERO);
+}
+
+#[test]
+fn test_projectiles_leave_from_the_muzzle() {
+    let mut world = World::new();
+    let mut time = Time::<()>::default();
+    time.advance_by(Duration::from_secs(10));
+    world.insert_resource(time);
+    world.init_resource::<Events<TowerFiredEvent>>();
+
+    let enemy = world.spawn((Enemy::default(), Transform::from_xyz(0.0, 200.0, 0.0))).id();
+    world.spawn((
+        TowerStats::new(TowerType::Missile),
+        Transform::default(),
+        Target { entity: Some(enemy), last_shot_time: 0.0 },
+        BarrelCycle::default(),
+    ));
+    let _ = world.run_system_once(projectile_spawning_system);
+
+    let first_muzzle = TowerType::Missile.get_muzzle_offsets(1)[0];
+    let expected = muzzle_position(Vec2::ZERO, Vec2::new(0.0, 200.0), first_muzzle);
+    let spawned: Vec<Vec2> = world
+        .query_filtered::<&Transform, With<Projectile>>()
+        .iter(&world)
+        .map(|transform| transform.translation.truncate())
+        .collect();
+    assert_eq!(spawned.len(), 1);
+    assert!(spawned[0].distance(expected) < 1e-4, "projectile spawned at {:?}", spawned[0]);
+    assert!(spawned[0] != Vec2::ZERO, "projectiles no longer start at the tower centre");
+}
+
+#[test]
+fn test_splash_damage_falls_off_with_distance() {
+    let projectile = Projectile::new(40.0, 200.0, Entity::from_raw(1), Vec2::ZERO, TowerType::Missile).with_splash(50.0);
+
+    assert_eq!(projectile.splash_damage_at(0.0), Some(40.0));
+    let halfway = projectile.splash_damage_at(25.0).unwrap();
+    assert!(halfway < 40.0 && halfway > 40.0 * SPLASH_EDGE_DAMAGE);
+    assert!((projectile.splash_damage_at(50.0).unwrap() - 40.0 * SPLASH_EDGE_DAMAGE).abs() < 1e-4);
+    assert_eq!(projectile.splash_damage_at(50.1), None);
+
+    // Single-target shots have no blast at all
+    let bullet = Projectile::new(40.0, 200.0, Entity::from_raw(1), Vec2::ZERO, TowerType::Basic);
+    assert!(!bullet.has_splash());
+    assert_eq!(bullet.splash_damage_at(0.0), None);
+}
+
+#[test]
+fn test_only_missile_towers_have_a_blast_radius() {
+    let mut missile = TowerStats::new(TowerType::Missile);
+    assert!(missile.splash_radius > 0.0);
+    let base_radius = missile.splash_radius;
+    missile.upgrade();
+    assert!(missile.splash_radius > base_radius, "upgrades widen the blast");
+
+    for tower_type in [TowerType::Basic, TowerType::Advanced, TowerType::Laser, TowerType::Tesla] {
+        assert_eq!(TowerStats::new(tower_type).splash_radius, 0.0, "{:?}", tower_type);
+    }
+}
+
+#[test]
+fn test_splash_projectile_damages_the_group() {
+    let mut world = World::new();
+    world.insert_resource(Economy::default());
+    world.insert_resource(Score::default());
+    world.insert_resource(WaveStatus::default());
+    world.init_resource::<Events<EnemyKilledEvent>>();
+    world.init_resource::<Events<EnemyDamagedEvent>>();
+
+    let spawn_enemy = |world: &mut World, x: f32| {
+        world.spawn((Enemy::default(), Health::new(100.0), Transform::from_xyz(x, 0.0, 0.0))).id()
+    };
+    let struck = spawn_enemy(&mut world, 0.0);
+    let nearby = spawn_enemy(&mut world, 20.0);
+    let outside = spawn_enemy(&mut world, 80.0);
+
+    world.spawn((
+        Transform::from_xyz(0.0, 0.0, 0.0),
+        Projectile::new(40.0, 200.0, struck, Vec2::ZERO, TowerType::Missile).with_splash(40.0),
+    ));
+    world.run_system_once(collision_system).unwrap();
+
+    let health = |world: &World, enemy: Entity| world.get::<Health>(enemy).unwrap().current;
+    assert_eq!(health(&world, struck), 60.0, "the enemy struck takes full damage");
+    let nearby_health = health(&world, nearby);
+    assert!(nearby_health < 100.0 && nearby_health > 60.0, "splash damage falls off, got {nearby_health}");
+    assert_eq!(health(&world, outside), 100.0, "enemies outside the blast are untouched");
+
+    assert_eq!(world.query::<&Projectile>().iter(&world).count(), 0);
+    assert_eq!(world.query::<&Explosion>().iter(&world).count(), 1);
+    assert_eq!(world.resource::<Events<EnemyDamagedEvent>>().len(), 2);
+}
+
+fn combat_world() -> World {
+    let mut world = World::new();
+    world.init_resource::<Time>();
+    world.insert_resource(Economy::default());
+    world.insert_resource(Score::default());
+    world.insert_resource(WaveStatus::default());
+    world.init_resource::<Events<EnemyKilledEvent>>();
+    world.init_resource::<Events<EnemyDamagedEvent>>();
+    world
+}
+
+/// Fire a tower type's projectile from the origin at `enemy`
+fn fire(world: &mut World, tower_type: TowerType, enemy: Entity) -> Entity {
+    let target = world.get::<Transform>(enemy).unwrap().translation.truncate();
+    world
+        .spawn((
+            Transform::default(),
+            ProjectileKind::for_tower(tower_type, Vec2::ZERO, target),
+            Projectile::new(10.0, 200.0, enemy, target, tower_type),
+        ))
+        .id()
+}
+
+fn step(world: &mut World) {
+    world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(0.016));
+    world.run_system_once(projectile_movement_system).unwrap();
+    world.run_system_once(collision_system).unwrap();
+}
+
+#[test]
+fn test_each_tower_fires_its_own_kind_of_projectile() {
+    let kind = |tower_type| ProjectileKind::for_tower(tower_type, Vec2::ZERO, Vec2::new(100.0, 0.0));
+    assert_eq!(kind(TowerType::Basic), ProjectileKind::Bullet);
+    assert_eq!(kind(TowerType::Laser), ProjectileKind::Beam { fired: false });
+    assert!(matches!(kind(TowerType::Missile), ProjectileKind::Homing { .. }));
+    assert!(matches!(kind(TowerType::Tesla), ProjectileKind::Arc { .. }));
+    assert_eq!(kind(TowerType::Laser).sprite_size(), None, "beams are drawn as a line");
+}
+
+#[test]
+fn test_laser_beam_hits_the_frame_it_fires() {
+    let mut world = combat_world();
+    let enemy = world.spawn((Enemy::default(), Health::new(100.0), Transform::from_xyz(300.0, 0.0, 0.0))).id();
+    fire(&mut world, TowerType::Laser, enemy);
+
+    step(&mut world);
+    assert_eq!(world.get::<Health>(enemy).unwrap().current, 90.0);
+    assert_eq!(world.query::<&Projectile>().iter(&world).count(), 0);
+}
+
+#[test]
+fn test_missiles_turn_at_a_limited_rate() {
+    let turned = steer(Vec2::X, Vec2::Y, 0.1);
+    assert!((turned.to_angle() - 0.1).abs() < 1e-5);
+    let close = Vec2::new(1.0, 0.01).normalize();
+    assert!(steer(Vec2::X, close, MISSILE_TURN_RATE * 0.016).distance(close) < 1e-5, "small corrections are made at once");
+}
+
+#[test]
+fn test_missiles_and_tesla_bolts_reach_moving_targets() {
+    for tower_type in [TowerType::Missile, TowerType::Tesla] {
+        let mut world = combat_world();
+        let enemy = world.spawn((Enemy::default(), Health::new(100.0), Transform::from_xyz(150.0, 40.0, 0.0))).id();
+        let projectile = fire(&mut world, tower_type, enemy);
+
+        let mut off_line = false;
+        for _ in 0..200 {
+            world.get_mut::<Transform>(enemy).unwrap().translation.y -= 0.5;
+            step(&mut world);
+            let Some(transform) = world.get::<Transform>(projectile) else {
+                break;
+            };
+            let position = transform.translation.truncate();
+            let enemy_position = world.get::<Transform>(enemy).unwrap().translation.truncate();
+            off_line |= position.normalize_or_zero().perp_dot(enemy_position.normalize_or_zero()).abs() > 0.05;
+        }
+        assert_eq!(world.get::<Health>(enemy).unwrap().current, 90.0, "{tower_type:?} projectile hit");
+        assert!(off_line, "{tower_type:?} projectile curves rather than flying straight");
+    }
+}
+
+#[test]
+fn test_tesla_arc_starts_and_ends_on_the_line() {
+    let target = Vec2::new(200.0, 0.0);
+    assert_eq!(arc_position(Vec2::ZERO, target, 0.0), Vec2::ZERO);
+    assert!(arc_position(Vec2::ZERO, target, 1.0).distance(target) < 1e-3);
+    assert!(arc_position(Vec2::ZERO, target, 0.5).y.abs() > 10.0, "bows out halfway");
+}
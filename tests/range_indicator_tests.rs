@@ -0,0 +1,89 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use std::time::Duration;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::input_system::MouseInputState;
+use tower_defense_bevy::systems::tower_rendering::{setup_range_ring_assets, RangeRingAssets};
+use tower_defense_bevy::systems::tower_ui::{range_indicator_system, range_indicator_target, RangeIndicator, TowerSelectionState, UpgradeButton};
+
+fn indicator_world() -> (World, Entity) {
+    let mut world = World::new();
+    world.init_resource::<Time>();
+    world.init_resource::<TowerSelectionState>();
+    world.init_resource::<MouseInputState>();
+    world.insert_resource(GameConstants::default());
+    world.init_resource::<Assets<Mesh>>();
+    world.init_resource::<Assets<ColorMaterial>>();
+    world.run_system_once(setup_range_ring_assets).unwrap();
+    let tower = world
+        .spawn((TowerStats::new(TowerType::Basic), Transform::from_xyz(100.0, 50.0, 0.0)))
+        .id();
+    (world, tower)
+}
+
+fn indicator(world: &mut World) -> Option<(RangeIndicator, Handle<ColorMaterial>)> {
+    let mut query = world.query::<(&RangeIndicator, &MeshMaterial2d<ColorMaterial>)>();
+    query.iter(world).next().map(|(indicator, material)| (*indicator, material.0.clone()))
+}
+
+fn run(world: &mut World, seconds: f32) {
+    world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(seconds));
+    world.run_system_once(range_indicator_system).unwrap();
+}
+
+#[test]
+fn test_upgrade_preview_uses_next_level_range() {
+    let stats = TowerStats::new(TowerType::Advanced);
+    let mut upgraded = stats.clone();
+    upgraded.upgrade();
+
+    assert_eq!(range_indicator_target(&stats, false), stats.range);
+    assert_eq!(range_indicator_target(&stats, true), upgraded.range);
+}
+
+#[test]
+fn test_selected_tower_shows_its_range() {
+    let (mut world, tower) = indicator_world();
+    world.resource_mut::<TowerSelectionState>().set_upgrade_mode(tower);
+    run(&mut world, 0.0);
+
+    let (ring, material) = indicator(&mut world).expect("a ring around the selected tower");
+    assert_eq!(ring.tower, tower);
+    assert_eq!(ring.radius, TowerStats::new(TowerType::Basic).range);
+    assert_eq!(material, world.resource::<RangeRingAssets>().selected);
+
+    world.resource_mut::<TowerSelectionState>().clear_selection();
+    run(&mut world, 0.0);
+    assert!(indicator(&mut world).is_none(), "ring removed once nothing is selected or hovered");
+}
+
+#[test]
+fn test_hovering_upgrade_button_grows_ring_to_upgraded_range() {
+    let (mut world, tower) = indicator_world();
+    world.resource_mut::<TowerSelectionState>().set_upgrade_mode(tower);
+    run(&mut world, 0.0);
+    world.spawn((UpgradeButton, Interaction::Hovered));
+
+    let current = TowerStats::new(TowerType::Basic).range;
+    let upgraded = range_indicator_target(&TowerStats::new(TowerType::Basic), true);
+    run(&mut world, 0.05);
+    let (growing, material) = indicator(&mut world).unwrap();
+    assert!(growing.radius > current && growing.radius < upgraded, "eases toward the upgraded range");
+    assert_eq!(material, world.resource::<RangeRingAssets>().upgrade);
+
+    run(&mut world, 2.0);
+    assert!((indicator(&mut world).unwrap().0.radius - upgraded).abs() < 0.01);
+}
+
+#[test]
+fn test_hovered_tower_shows_its_range() {
+    let (mut world, tower) = indicator_world();
+    world.resource_mut::<MouseInputState>().world_position = Vec2::new(104.0, 48.0);
+    run(&mut world, 0.0);
+    assert_eq!(indicator(&mut world).unwrap().0.tower, tower);
+
+    // Picking a tower type to place hands the ring over to the placement preview
+    world.resource_mut::<TowerSelectionState>().set_placement_mode(Some(TowerType::Laser));
+    run(&mut world, 0.0);
+    assert!(indicator(&mut world).is_none());
+}
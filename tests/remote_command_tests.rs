@@ -0,0 +1,186 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use serde_json::json;
+use tower_defense_bevy::components::PlacementGrace;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::enemy_system::StartWaveEvent;
+use tower_defense_bevy::systems::obstacle_rendering::ObstacleGrid;
+use tower_defense_bevy::systems::occupancy::OccupancyMap;
+use tower_defense_bevy::systems::remote_commands::*;
+use tower_defense_bevy::systems::tower_ui::TowerSelectionState;
+use tower_defense_bevy::systems::unified_grid::UnifiedGridSystem;
+
+/// A free spot above the test path, which runs along y = 20 through the middle of a row of cells
+const BUILD_SPOT: [f32; 2] = [-120.0, 120.0];
+
+fn command_world(money: u32) -> World {
+    let mut world = World::new();
+    world.insert_resource(Economy::new(money, 100, 100, 100));
+    world.insert_resource(WavePlan::default());
+    world.insert_resource(WaveRuntime::new());
+    world.insert_resource(EnemyPath::new(vec![Vec2::new(-400.0, 20.0), Vec2::new(400.0, 20.0)]));
+    world.init_resource::<UnifiedGridSystem>();
+    world.init_resource::<ObstacleGrid>();
+    world.init_resource::<TowerSelectionState>();
+    world.init_resource::<GameConstants>();
+    world.init_resource::<OccupancyMap>();
+    world.init_resource::<RemoteCommandQueue>();
+    world.init_resource::<Events<StartWaveEvent>>();
+    world
+}
+
+fn push(world: &mut World, sequence: Option<u64>, command: RemoteCommand) -> u64 {
+    world
+        .resource_mut::<RemoteCommandQueue>()
+        .push(RemoteCommandRequest { sequence, command })
+        .unwrap()
+}
+
+fn outcome(world: &World, ticket: u64) -> CommandOutcome {
+    world.resource::<RemoteCommandQueue>().outcome(ticket).unwrap()
+}
+
+fn tower_count(world: &mut World) -> usize {
+    world.query::<&TowerStats>().iter(world).count()
+}
+
+#[test]
+fn test_numbered_commands_apply_in_order_and_late_ones_are_refused() {
+    let mut queue = RemoteCommandQueue::default();
+    let request = |sequence| RemoteCommandRequest { sequence, command: RemoteCommand::StartWave };
+    let third = queue.push(request(Some(3))).unwrap();
+    let unnumbered = queue.push(request(None)).unwrap();
+    let first = queue.push(request(Some(1))).unwrap();
+    assert!(queue.push(request(Some(1))).is_err(), "duplicate sequence");
+
+    let order: Vec<u64> = queue.drain().iter().map(|queued| queued.ticket).collect();
+    assert_eq!(order, vec![first, unnumbered, third]);
+    assert_eq!(queue.pending_len(), 0);
+
+    // Sequence 2 was overtaken by 3, which has been applied
+    assert!(queue.push(request(Some(2))).is_err());
+    assert!(queue.push(request(Some(4))).is_ok());
+}
+
+#[test]
+fn test_malformed_commands_are_refused_before_queuing() {
+    let mut queue = RemoteCommandQueue::default();
+    let place = RemoteCommand::PlaceTower { tower_type: TowerType::Basic, position: [f32::NAN, 0.0] };
+    assert!(queue.push(RemoteCommandRequest { sequence: None, command: place }).is_err());
+    let sell = RemoteCommand::SellTower { entity: 0 };
+    assert!(queue.push(RemoteCommandRequest { sequence: None, command: sell }).is_err());
+
+    for _ in 0..MAX_PENDING_COMMANDS {
+        queue.push(RemoteCommandRequest { sequence: None, command: RemoteCommand::StartWave }).unwrap();
+    }
+    assert!(queue.push(RemoteCommandRequest { sequence: None, command: RemoteCommand::StartWave }).is_err());
+}
+
+#[test]
+fn test_commands_wait_for_the_apply_system() {
+    let mut world = command_world(1000);
+    let ticket = push(&mut world, None, RemoteCommand::PlaceTower { tower_type: TowerType::Basic, position: BUILD_SPOT });
+    assert_eq!(outcome(&world, ticket), CommandOutcome::Pending);
+    assert_eq!(tower_count(&mut world), 0, "nothing changes until commands are applied");
+
+    world.run_system_once(apply_remote_commands_system).unwrap();
+    assert!(matches!(outcome(&world, ticket), CommandOutcome::Applied(_)));
+    assert_eq!(tower_count(&mut world), 1);
+    assert_eq!(world.resource::<Economy>().money, 1000 - TowerType::Basic.get_cost().money);
+}
+
+#[test]
+fn test_commands_are_checked_against_the_game_when_applied() {
+    let mut world = command_world(1000);
+    let place = RemoteCommand::PlaceTower { tower_type: TowerType::Basic, position: BUILD_SPOT };
+    let placed = push(&mut world, None, place.clone());
+    let same_spot = push(&mut world, None, place);
+    let on_path = push(&mut world, None, RemoteCommand::PlaceTower { tower_type: TowerType::Basic, position: [0.0, 20.0] });
+    let missing = push(&mut world, None, RemoteCommand::UpgradeTower { entity: Entity::from_raw(999).to_bits() });
+    let first_wave = push(&mut world, None, RemoteCommand::StartWave);
+    let second_wave = push(&mut world, None, RemoteCommand::StartWave);
+    world.run_system_once(apply_remote_commands_system).unwrap();
+
+    assert!(matches!(outcome(&world, placed), CommandOutcome::Applied(_)));
+    assert!(matches!(outcome(&world, same_spot), CommandOutcome::Rejected(_)));
+    assert!(matches!(outcome(&world, on_path), CommandOutcome::Rejected(_)));
+    assert!(matches!(outcome(&world, missing), CommandOutcome::Rejected(_)));
+    assert!(matches!(outcome(&world, first_wave), CommandOutcome::Applied(_)));
+    assert!(matches!(outcome(&world, second_wave), CommandOutcome::Rejected(_)));
+    assert_eq!(tower_count(&mut world), 1);
+    assert_eq!(world.resource::<Events<StartWaveEvent>>().len(), 1);
+}
+
+#[test]
+fn test_a_tower_is_sold_once() {
+    let mut world = command_world(0);
+    let tower = world.spawn((TowerStats::new(TowerType::Basic), Transform::default())).id();
+    let refund = TowerStats::new(TowerType::Basic).sell_value();
+    let sale = push(&mut world, None, RemoteCommand::SellTower { entity: tower.to_bits() });
+    let resale = push(&mut world, None, RemoteCommand::SellTower { entity: tower.to_bits() });
+    world.run_system_once(apply_remote_commands_system).unwrap();
+
+    assert!(matches!(outcome(&world, sale), CommandOutcome::Applied(_)));
+    assert!(matches!(outcome(&world, resale), CommandOutcome::Rejected(_)));
+    assert!(world.get_entity(tower).is_err());
+    assert_eq!(world.resource::<Economy>().money, refund.money);
+}
+
+#[test]
+fn test_a_just_placed_tower_sells_for_its_full_price() {
+    let mut world = command_world(0);
+    let paid = TowerType::Basic.get_cost();
+    let tower = world
+        .spawn((TowerStats::new(TowerType::Basic), PlacementGrace::new(paid.clone()), Transform::default()))
+        .id();
+    let sale = push(&mut world, None, RemoteCommand::SellTower { entity: tower.to_bits() });
+    world.run_system_once(apply_remote_commands_system).unwrap();
+
+    assert!(matches!(outcome(&world, sale), CommandOutcome::Applied(_)));
+    assert_eq!(world.resource::<Economy>().money, paid.money);
+}
+
+#[test]
+fn test_remote_methods_queue_and_report() {
+    let mut world = command_world(1000);
+    let response = world
+        .run_system_once_with(
+            process_command_request,
+            Some(json!({ "type": "place_tower", "tower_type": "Basic", "position": BUILD_SPOT, "sequence": 7 })),
+        )
+        .unwrap()
+        .unwrap();
+    let ticket = json!({ "ticket": response["ticket"] });
+
+    let pending = world.run_system_once_with(process_command_result_request, Some(ticket.clone())).unwrap().unwrap();
+    assert_eq!(pending, json!({ "status": "pending" }));
+
+    world.run_system_once(apply_remote_commands_system).unwrap();
+    let applied = world.run_system_once_with(process_command_result_request, Some(ticket)).unwrap().unwrap();
+    assert_eq!(applied["status"], "applied");
+
+    let unknown = world.run_system_once_with(process_command_request, Some(json!({ "type": "launch_nukes" }))).unwrap();
+    assert!(unknown.is_err());
+    let expired = world.run_system_once_with(process_command_result_request, Some(json!({ "ticket": 1234 }))).unwrap();
+    assert!(expired.is_err());
+}
+
+#[test]
+fn test_remote_placements_are_rate_limited() {
+    let mut world = command_world(10_000);
+    world.init_resource::<RunIntegrity>();
+    let tickets: Vec<u64> = (0..=MAX_PLACEMENTS_PER_WINDOW)
+        .map(|index| {
+            let position = [-360.0 + index as f32 * 120.0, 160.0];
+            push(&mut world, None, RemoteCommand::PlaceTower { tower_type: TowerType::Basic, position })
+        })
+        .collect();
+    world.run_system_once(apply_remote_commands_system).unwrap();
+
+    let (last, allowed) = tickets.split_last().unwrap();
+    for ticket in allowed {
+        assert!(matches!(outcome(&world, *ticket), CommandOutcome::Applied(_)));
+    }
+    assert_eq!(outcome(&world, *last), CommandOutcome::Rejected("placement rate limit reached".to_string()));
+    assert!(!world.resource::<RunIntegrity>().is_clean());
+}
@@ -0,0 +1,117 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::boss_arena_system::BossArenaSettings;
+use tower_defense_bevy::systems::reward_chest_system::*;
+use tower_defense_bevy::systems::shop_system::shop_open_system;
+use tower_defense_bevy::systems::ui_feedback::UiFeedbackEvent;
+
+fn chest_world() -> World {
+    let mut world = World::new();
+    world.init_resource::<WavePlan>();
+    world.init_resource::<WaveRuntime>();
+    world.init_resource::<GameState>();
+    world.init_resource::<Economy>();
+    world.init_resource::<RunPerks>();
+    world.init_resource::<PerkShop>();
+    world.init_resource::<RewardChest>();
+    world.init_resource::<BossArenaSettings>();
+    world.insert_resource(GameRng::from_seed(4));
+    world.init_resource::<Events<UiFeedbackEvent>>();
+    world
+}
+
+fn finish_waves(world: &mut World, waves: u32) {
+    for _ in 0..waves {
+        world.resource_scope(|world, mut wave_plan: Mut<WavePlan>| start_wave(&mut wave_plan, &mut world.resource_mut::<WaveRuntime>(), 0));
+    }
+}
+
+fn press_reward(world: &mut World, index: usize) {
+    world.spawn((Button, Interaction::Pressed, BackgroundColor::default(), BorderColor::default(), RewardChestButton { index }));
+    world.run_system_once(reward_chest_button_system).unwrap();
+}
+
+#[test]
+fn test_chest_offers_cash_research_and_a_seeded_perk() {
+    let rewards = roll_chest_rewards(10, &mut GameRng::from_seed(7));
+    assert_eq!(rewards.len(), 3);
+    assert_eq!(rewards[0], ChestReward::Cash(CHEST_CASH_BASE + CHEST_CASH_PER_WAVE * 10));
+    assert_eq!(rewards[1], ChestReward::Research(CHEST_RESEARCH_BASE + CHEST_RESEARCH_PER_WAVE * 10));
+    assert!(matches!(rewards[2], ChestReward::Perk(_)));
+    assert_eq!(rewards, roll_chest_rewards(10, &mut GameRng::from_seed(7)), "same seed, same perk");
+}
+
+#[test]
+fn test_claiming_gives_one_reward_and_closes_the_chest() {
+    let mut chest = RewardChest::default();
+    chest.open_after(5, &mut GameRng::from_seed(1));
+    chest.rewards[2] = ChestReward::Perk(Perk::FieldResearch);
+    let mut economy = Economy::new(0, 0, 0, 0);
+    let mut perks = RunPerks::default();
+    let research_generation = economy.research_generation;
+
+    assert_eq!(chest.try_claim(2, &mut economy, &mut perks), Some(ChestReward::Perk(Perk::FieldResearch)));
+    assert_eq!(perks.stacks(Perk::FieldResearch), 1);
+    assert!((economy.research_generation - research_generation - RESEARCH_PERK_GENERATION).abs() < 1e-6);
+    assert_eq!(economy.money, 0, "the perk is free");
+    assert!(!chest.open);
+
+    // Only one pick per chest
+    assert_eq!(chest.try_claim(0, &mut economy, &mut perks), None);
+    assert_eq!(economy.money, 0);
+}
+
+#[test]
+fn test_chest_opens_after_cleared_boss_wave_and_is_forfeited_on_next_wave() {
+    let mut world = chest_world();
+    finish_waves(&mut world, 4);
+    world.run_system_once(reward_chest_open_system).unwrap();
+    assert!(!world.resource::<RewardChest>().open, "wave 4 is not a boss wave");
+
+    finish_waves(&mut world, 1);
+    let enemy = world.spawn(Enemy::default()).id();
+    world.run_system_once(reward_chest_open_system).unwrap();
+    assert!(!world.resource::<RewardChest>().open, "the boss wave is still being fought");
+
+    world.despawn(enemy);
+    world.run_system_once(reward_chest_open_system).unwrap();
+    let chest = world.resource::<RewardChest>();
+    assert!(chest.open);
+    assert_eq!(chest.wave, 5);
+
+    world.resource_scope(|world, mut wave_plan: Mut<WavePlan>| start_wave(&mut wave_plan, &mut world.resource_mut::<WaveRuntime>(), 4));
+    world.run_system_once(reward_chest_open_system).unwrap();
+    assert!(!world.resource::<RewardChest>().open);
+}
+
+#[test]
+fn test_shop_waits_for_the_chest() {
+    let mut world = chest_world();
+    finish_waves(&mut world, 5);
+    world.run_system_once(reward_chest_open_system).unwrap();
+    world.run_system_once(shop_open_system).unwrap();
+    assert!(world.resource::<RewardChest>().open);
+    assert!(!world.resource::<PerkShop>().open);
+
+    let money = world.resource::<Economy>().money;
+    press_reward(&mut world, 0);
+    assert_eq!(world.resource::<Economy>().money, money + CHEST_CASH_BASE + CHEST_CASH_PER_WAVE * 5);
+
+    world.run_system_once(shop_open_system).unwrap();
+    assert!(world.resource::<PerkShop>().open);
+}
+
+#[test]
+fn test_reinforcements_from_the_chest_raise_base_health() {
+    let mut world = chest_world();
+    world.resource_mut::<RewardChest>().open_after(5, &mut GameRng::from_seed(0));
+    world.resource_mut::<RewardChest>().rewards[2] = ChestReward::Perk(Perk::Reinforcements);
+    let base = world.spawn((Base, Health { current: 50.0, max: BASE_MAX_HEALTH })).id();
+
+    press_reward(&mut world, 2);
+    let extra_health = LIVES_PER_PERK as f32 * ENEMY_BASE_DAMAGE;
+    let health = world.get::<Health>(base).unwrap();
+    assert_eq!(health.max, BASE_MAX_HEALTH + extra_health);
+    assert_eq!(health.current, 50.0 + extra_health);
+}
@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::debug_ui::{CheatMenuState, CheatMultipliers};
+use tower_defense_bevy::systems::results_screen::RestartRunEvent;
+use tower_defense_bevy::systems::security::*;
+
+fn integrity_world() -> World {
+    let mut world = World::new();
+    world.init_resource::<Time<Real>>();
+    world.init_resource::<RunIntegrity>();
+    world.init_resource::<CheatMenuState>();
+    world.init_resource::<CheatMultipliers>();
+    world.init_resource::<Events<RestartRunEvent>>();
+    world
+}
+
+#[test]
+fn test_placements_beyond_the_rate_are_refused_and_flagged() {
+    let mut integrity = RunIntegrity::default();
+    for _ in 0..MAX_PLACEMENTS_PER_WINDOW {
+        assert!(integrity.try_record_placement());
+    }
+    assert!(integrity.is_clean());
+
+    assert!(!integrity.try_record_placement());
+    assert_eq!(integrity.reasons(), &[TamperReason::PlacementRate]);
+
+    // Once the window has passed placing is allowed again, but the run stays flagged
+    integrity.tick(PLACEMENT_RATE_WINDOW_SECS);
+    assert!(integrity.try_record_placement());
+    assert!(!integrity.is_clean());
+}
+
+#[test]
+fn test_steady_placement_stays_within_the_rate() {
+    let mut integrity = RunIntegrity::default();
+    for _ in 0..50 {
+        assert!(integrity.try_record_placement());
+        integrity.tick(PLACEMENT_RATE_WINDOW_SECS / MAX_PLACEMENTS_PER_WINDOW as f64);
+    }
+    assert!(integrity.is_clean());
+}
+
+#[test]
+fn test_only_placed_towers_count_against_the_rate() {
+    let mut integrity = RunIntegrity::default();
+    // Checked placements that were then refused for other reasons
+    for _ in 0..MAX_PLACEMENTS_PER_WINDOW * 2 {
+        assert!(integrity.allows_placement());
+    }
+    for _ in 0..MAX_PLACEMENTS_PER_WINDOW {
+        integrity.record_placement();
+    }
+    assert!(integrity.is_clean());
+    assert!(!integrity.allows_placement());
+}
+
+#[test]
+fn test_competitive_integrity_needs_a_clean_run_without_admin() {
+    let mut security = SecurityContext::default();
+    let mut integrity = RunIntegrity::default();
+    assert!(security.has_competitive_integrity(&integrity));
+
+    security.admin_privileges = true;
+    assert!(!security.has_competitive_integrity(&integrity));
+
+    security.admin_privileges = false;
+    integrity.flag(TamperReason::CheatMenu);
+    integrity.flag(TamperReason::CheatMenu);
+    assert_eq!(integrity.reasons().len(), 1, "each reason is recorded once");
+    assert!(!security.has_competitive_integrity(&integrity));
+}
+
+#[test]
+fn test_cheat_menu_taints_the_run_until_a_new_one_starts() {
+    let mut world = integrity_world();
+    let system = world.register_system(run_integrity_system);
+    world.run_system(system).unwrap();
+    assert!(world.resource::<RunIntegrity>().is_clean());
+
+    world.resource_mut::<CheatMultipliers>().tower_damage = 3.0;
+    world.run_system(system).unwrap();
+    assert_eq!(world.resource::<RunIntegrity>().reasons(), &[TamperReason::CheatMenu]);
+
+    // Putting the multiplier back does not clean the run
+    world.resource_mut::<CheatMultipliers>().tower_damage = 1.0;
+    world.run_system(system).unwrap();
+    assert!(!world.resource::<RunIntegrity>().is_clean());
+
+    world.send_event(RestartRunEvent { new_seed: false });
+    world.run_system(system).unwrap();
+    assert!(world.resource::<RunIntegrity>().is_clean());
+}
+
+#[test]
+fn test_admin_privileges_taint_the_run() {
+    let mut world = integrity_world();
+    world.insert_resource(SecurityContext { admin_privileges: true, ..default() });
+    let system = world.register_system(run_integrity_system);
+    world.run_system(system).unwrap();
+    assert_eq!(world.resource::<RunIntegrity>().reasons(), &[TamperReason::AdminMode]);
+}
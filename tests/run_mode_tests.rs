@@ -0,0 +1,71 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::combat_system::{game_state_system, WaveStatus};
+use tower_defense_bevy::systems::main_menu::run_mode_label;
+use tower_defense_bevy::systems::settings_menu::{GameSettings, GameplayOption};
+
+/// World with `wave` just cleared under the given settings
+fn cleared_wave_world(wave: u32, settings: GameSettings) -> World {
+    let mut world = World::new();
+    world.insert_resource(WavePlan { current_wave: wave, ..default() });
+    world.insert_resource(WaveRuntime::new());
+    world.insert_resource(WaveStatus { wave_complete: true, ..default() });
+    world.insert_resource(settings);
+    world.init_resource::<GameState>();
+    world
+}
+
+#[test]
+fn test_campaign_is_won_on_the_configured_wave() {
+    let settings = GameSettings { victory_waves: 5, ..default() };
+    let mut world = cleared_wave_world(3, settings.clone());
+    world.run_system_once(game_state_system).unwrap();
+    assert_eq!(*world.resource::<GameState>(), GameState::Playing, "wave 3 is no longer the last");
+    assert_eq!(world.resource::<WavePlan>().current_wave, 4);
+
+    let mut world = cleared_wave_world(5, settings);
+    world.run_system_once(game_state_system).unwrap();
+    assert_eq!(*world.resource::<GameState>(), GameState::Victory);
+}
+
+#[test]
+fn test_endless_runs_have_no_victory() {
+    let settings = GameSettings { run_mode: RunMode::Endless, ..default() };
+    let mut world = cleared_wave_world(40, settings);
+    world.run_system_once(game_state_system).unwrap();
+    assert_eq!(*world.resource::<GameState>(), GameState::Playing);
+    assert_eq!(world.resource::<WavePlan>().current_wave, 41);
+}
+
+#[test]
+fn test_endless_scaling_grows_every_wave_and_caps_speed() {
+    let scaling = EndlessScaling::default();
+    assert_eq!(scaling.health_multiplier(1), 1.0);
+    assert_eq!(scaling.speed_multiplier(1), 1.0);
+    assert!((scaling.health_multiplier(3) - (1.0 + scaling.health_growth).powi(2)).abs() < 1e-5);
+    assert!(scaling.health_multiplier(20) > scaling.health_multiplier(19));
+    assert_eq!(scaling.speed_multiplier(1_000), scaling.max_speed_multiplier);
+
+    // Tuned from the balance file, missing fields keep their defaults
+    let tuned: BalanceConfig = serde_json::from_str(r#"{ "endless": { "health_growth": 0.5 } }"#).unwrap();
+    assert_eq!(tuned.endless.health_growth, 0.5);
+    assert_eq!(tuned.endless.speed_growth, scaling.speed_growth);
+}
+
+#[test]
+fn test_victory_waves_cycle_and_mode_label() {
+    let mut settings = GameSettings::default();
+    assert_eq!(settings.victory_waves, DEFAULT_VICTORY_WAVES);
+    assert_eq!(run_mode_label(&settings), "MODE: CAMPAIGN (3 WAVES)");
+
+    settings.cycle_gameplay_option(GameplayOption::VictoryWaves);
+    assert_eq!(settings.victory_waves, VICTORY_WAVE_CHOICES[1]);
+    for _ in 1..VICTORY_WAVE_CHOICES.len() {
+        settings.cycle_gameplay_option(GameplayOption::VictoryWaves);
+    }
+    assert_eq!(settings.victory_waves, VICTORY_WAVE_CHOICES[0]);
+
+    settings.run_mode = settings.run_mode.next();
+    assert_eq!(run_mode_label(&settings), "MODE: ENDLESS");
+}
@@ -0,0 +1,49 @@
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::results_screen::{CounterFormat, RollingCounter};
+
+#[test]
+fn test_outcome_only_for_terminal_states() {
+    assert_eq!(RunOutcome::from_game_state(&GameState::Playing), None);
+    assert_eq!(RunOutcome::from_game_state(&GameState::Victory), Some(RunOutcome::Victory));
+    assert_eq!(RunOutcome::from_game_state(&GameState::GameOver), Some(RunOutcome::Defeat));
+}
+
+#[test]
+fn test_results_capture_score_snapshot() {
+    let mut score = Score::new();
+    score.record_damage(120.5);
+    score.enemy_killed(8);
+    score.record_money_earned(8);
+    score.enemy_killed(5);
+    score.record_money_earned(5);
+
+    let results = RunResults::capture(RunOutcome::Defeat, &score, 3, 10, 42);
+
+    assert_eq!(results.outcome, RunOutcome::Defeat);
+    assert_eq!(results.waves_reached, 3);
+    assert_eq!(results.enemies_killed, 2);
+    assert_eq!(results.enemies_escaped, 10);
+    assert_eq!(results.damage_dealt, 120.5);
+    assert_eq!(results.money_earned, 13);
+    assert_eq!(results.score, 13);
+    assert_eq!(results.seed, 42);
+}
+
+#[test]
+fn test_rolling_counter_rolls_up_after_delay() {
+    let mut counter = RollingCounter::new("Money Earned", 200.0, CounterFormat::Money, 0.5);
+    assert_eq!(counter.display_text(&NumberFormatter::default()), "Money Earned: $0");
+
+    // Still waiting on the delay
+    counter.progress.tick(0.4);
+    assert_eq!(counter.current_value(), 0.0);
+
+    // Part way through the roll-up
+    counter.progress.tick(0.1 + counter.progress.duration / 2.0);
+    let midway = counter.current_value();
+    assert!(midway > 100.0 && midway < 200.0, "ease-out should be past half way, got {}", midway);
+
+    // Settled on the target
+    counter.progress.tick(counter.progress.duration);
+    assert_eq!(counter.display_text(&NumberFormatter::default()), "Money Earned: $200");
+}
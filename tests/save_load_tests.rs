@@ -0,0 +1,115 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use tower_defense_bevy::components::*;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::combat_system::WaveStatus;
+use tower_defense_bevy::systems::obstacle_rendering::ObstacleGrid;
+use tower_defense_bevy::systems::path_generation::*;
+use tower_defense_bevy::systems::save_load::*;
+use tower_defense_bevy::systems::suspend_system::*;
+use tower_defense_bevy::systems::tower_ui::TowerSelectionState;
+
+/// World between waves 2 and 3 on an open 10x5 map, with one upgraded tower
+fn between_waves_world() -> World {
+    let mut grid = PathGrid::new(10, 5);
+    grid.entry_point = GridPos::new(0, 2);
+    grid.exit_point = GridPos::new(9, 2);
+    let route = find_path(&grid, grid.entry_point, grid.exit_point).unwrap();
+    let path = grid.to_enemy_path(route);
+    let analysis = analyze_map(&grid, MapArchetype::Classic);
+
+    let mut world = World::new();
+    world.insert_resource(ObstacleGrid { grid, wave_number: 1, analysis });
+    world.insert_resource(path);
+    let mut wave_plan = WavePlan::default();
+    let mut wave_runtime = WaveRuntime::new();
+    wave_plan.current_wave = 1;
+    start_wave(&mut wave_plan, &mut wave_runtime, 4);
+    wave_runtime.enemies_spawned = 4;
+    world.insert_resource(wave_plan);
+    world.insert_resource(wave_runtime);
+    world.insert_resource(WaveStatus::default());
+    world.insert_resource(GameState::Playing);
+    world.insert_resource(Economy::default());
+    world.insert_resource(Score::new());
+    world.init_resource::<TowerSelectionState>();
+    let mut stats = TowerStats::new(TowerType::Advanced);
+    stats.upgrade();
+    world.spawn((stats, Transform::from_xyz(40.0, 10.0, 0.0)));
+    world.resource_mut::<Economy>().money = 275;
+    world.resource_mut::<Score>().current = 480;
+    world
+}
+
+#[test]
+fn test_save_state_works_between_waves() {
+    let world = between_waves_world();
+    assert_eq!(SuspendedRun::capture(&world), None, "only mid-wave runs are suspended on quit");
+
+    let stem = std::env::temp_dir().join(format!("td_save_state_{}", std::process::id()));
+    let stem = stem.to_str().unwrap();
+    let saved = save_game_state(&world, stem, SaveFormat::Json).unwrap();
+    assert_eq!(saved.wave.current, 2);
+    assert_eq!(saved.economy.money, 275);
+    assert_eq!(saved.towers.len(), 1);
+    assert_eq!(saved.seed, current_level_seed());
+
+    let loaded = load_game_state(stem).unwrap();
+    std::fs::remove_file(SaveFormat::Json.file_name(stem)).unwrap();
+    assert_eq!(loaded, saved);
+    assert!(load_game_state(stem).is_err(), "nothing left to load");
+}
+
+#[test]
+fn test_saves_follow_the_picked_format() {
+    let world = between_waves_world();
+    let stem = std::env::temp_dir().join(format!("td_save_format_{}", std::process::id()));
+    let stem = stem.to_str().unwrap();
+
+    let saved = save_game_state(&world, stem, SaveFormat::Binary).unwrap();
+    let binary = std::fs::read(format!("{}.sav", stem)).unwrap();
+    assert_eq!(SaveFormat::detect(&binary), SaveFormat::Binary);
+    assert_eq!(load_game_state(stem).unwrap(), saved);
+
+    // Switching back replaces the binary file rather than leaving it to be read
+    save_game_state(&world, stem, SaveFormat::Json).unwrap();
+    assert_eq!(existing_save_file(stem), Some(format!("{}.json", stem)));
+    assert!(!std::path::Path::new(&format!("{}.sav", stem)).exists());
+    assert_eq!(load_game_state(stem).unwrap(), saved);
+
+    remove_save(stem).unwrap();
+    assert_eq!(existing_save_file(stem), None);
+}
+
+#[test]
+fn test_loading_restores_a_saved_state_mid_session() {
+    let saved = SuspendedRun::snapshot(&between_waves_world()).unwrap();
+
+    // The session carried on after the save: more money, another tower, wave 3 under way
+    let mut world = between_waves_world();
+    world.resource_mut::<Economy>().money = 20;
+    world.resource_scope(|world, mut wave_plan: Mut<WavePlan>| start_wave(&mut wave_plan, &mut world.resource_mut::<WaveRuntime>(), 6));
+    world.spawn((TowerStats::new(TowerType::Basic), Transform::from_xyz(-40.0, 0.0, 0.0)));
+
+    let run = saved.clone();
+    let restored = world
+        .run_system_once(move |mut restorer: RunRestorer| restorer.restore(&run))
+        .unwrap();
+    assert_eq!(restored, Ok(()));
+    assert_eq!(SuspendedRun::snapshot(&world), Some(saved));
+}
+
+#[test]
+fn test_restoring_a_run_with_a_broken_map_leaves_the_board() {
+    let mut world = between_waves_world();
+    let mut run = SuspendedRun::snapshot(&world).unwrap();
+    run.map_code = "not a map".to_string();
+    run.economy.money = 1;
+
+    let restored = world
+        .run_system_once(move |mut restorer: RunRestorer| restorer.restore(&run))
+        .unwrap();
+    assert!(restored.is_err());
+    assert_eq!(world.resource::<Economy>().money, 275);
+    assert_eq!(world.query::<&TowerStats>().iter(&world).count(), 1);
+}
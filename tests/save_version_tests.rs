@@ -0,0 +1,167 @@
+use serde_json::{json, Value};
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::settings_menu::*;
+
+const SETTINGS_V1: &str = include_str!("fixtures/settings_v1.json");
+const SETTINGS_V2: &str = include_str!("fixtures/settings_v2.json");
+
+fn add_field(save: &mut serde_json::Map<String, Value>) -> Result<(), String> {
+    save.insert("added".to_string(), json!(true));
+    Ok(())
+}
+
+fn rename_field(save: &mut serde_json::Map<String, Value>) -> Result<(), String> {
+    let old = save.remove("old_name").ok_or("old_name missing")?;
+    save.insert("new_name".to_string(), old);
+    Ok(())
+}
+
+#[test]
+fn test_migrations_run_in_order() {
+    let registry = MigrationRegistry::new(3)
+        .register(2, rename_field)
+        .register(1, add_field);
+
+    let migrated = registry.migrate(json!({ "old_name": 5 })).unwrap();
+    assert_eq!(migrated, json!({ "added": true, "new_name": 5, "version": 3 }));
+
+    // Already current: nothing to do
+    let current = json!({ "new_name": 1, "version": 3 });
+    assert_eq!(registry.migrate(current.clone()).unwrap(), current);
+}
+
+#[test]
+fn test_migration_errors() {
+    let registry = MigrationRegistry::new(3).register(1, add_field);
+
+    assert_eq!(
+        registry.migrate(json!({ "version": 7 })),
+        Err(SaveError::TooNew { found: 7, supported: 3 })
+    );
+    assert_eq!(registry.migrate(json!({})), Err(SaveError::MissingMigration { from: 2 }));
+    assert!(matches!(
+        MigrationRegistry::new(3).register(1, add_field).register(2, rename_field).migrate(json!({})),
+        Err(SaveError::Migration { from: 2, .. })
+    ));
+    assert!(matches!(registry.migrate(json!({ "version": "two" })), Err(SaveError::Parse(_))));
+}
+
+#[test]
+fn test_loads_unversioned_v1_settings() {
+    let settings = GameSettings::from_json(SETTINGS_V1).unwrap();
+    assert_eq!(settings.current_resolution, ResolutionOption::Res1280x720);
+    assert!(settings.fullscreen_enabled);
+    assert_eq!(settings.master_volume, 0.5);
+
+    // Settings added after v1 get their defaults
+    let defaults = GameSettings::default();
+    assert_eq!(settings.difficulty, defaults.difficulty);
+    assert_eq!(settings.advisor_enabled, defaults.advisor_enabled);
+}
+
+#[test]
+fn test_loads_v2_settings() {
+    let settings = GameSettings::from_json(SETTINGS_V2).unwrap();
+    assert_eq!(settings.difficulty, Difficulty::Hard);
+    assert!(!settings.ui_sounds_enabled);
+    assert!(!settings.advisor_enabled);
+}
+
+#[test]
+fn test_saved_settings_carry_current_version() {
+    let mut settings = GameSettings::default();
+    settings.difficulty = Difficulty::Easy;
+
+    let json = settings.to_json().unwrap();
+    let value: Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(MigrationRegistry::version_of(&value), Ok(SETTINGS_VERSION));
+
+    let reloaded = GameSettings::from_json(&json).unwrap();
+    assert_eq!(reloaded.difficulty, Difficulty::Easy);
+}
+
+#[test]
+fn test_newer_settings_are_reported_and_kept() {
+    let mut newer: Value = serde_json::from_str(SETTINGS_V2).unwrap();
+    newer["version"] = json!(SETTINGS_VERSION + 1);
+
+    let error = GameSettings::from_json(&newer.to_string()).unwrap_err();
+    assert_eq!(
+        error,
+        SaveError::TooNew {
+            found: SETTINGS_VERSION + 1,
+            supported: SETTINGS_VERSION
+        }
+    );
+
+    let load_error = SettingsLoadError(Some(error));
+    assert!(load_error.blocks_saving(), "a newer file must not be overwritten");
+    assert!(load_error.message().unwrap().contains("newer version"));
+
+    // Broken files are reported but may be replaced
+    let broken = SettingsLoadError(GameSettings::from_json("{ not json").err());
+    assert!(!broken.blocks_saving());
+    assert!(broken.message().is_some());
+    assert!(SettingsLoadError::default().message().is_none());
+}
+
+#[test]
+fn test_binary_settings_round_trip_matches_json() {
+    let mut settings = GameSettings::default();
+    settings.difficulty = Difficulty::Hard;
+    settings.master_volume = 0.25;
+    settings.advisor_enabled = false;
+    let registry = GameSettings::migrations();
+
+    let binary = registry.save_bytes(&settings, SaveFormat::Binary).unwrap();
+    let json = registry.save_bytes(&settings, SaveFormat::Json).unwrap();
+    assert!(binary.starts_with(&BINARY_SAVE_MAGIC));
+    assert_eq!(SaveFormat::detect(&binary), SaveFormat::Binary);
+    assert_eq!(SaveFormat::detect(&json), SaveFormat::Json);
+
+    // Both formats decode to the same versioned document
+    let from_binary: Value = registry.load_bytes(&binary).unwrap();
+    let from_json: Value = registry.load_bytes(&json).unwrap();
+    assert_eq!(from_binary, from_json);
+    assert_eq!(MigrationRegistry::version_of(&from_binary), Ok(SETTINGS_VERSION));
+
+    let reloaded = GameSettings::from_bytes(&binary).unwrap();
+    assert_eq!(reloaded.difficulty, Difficulty::Hard);
+    assert_eq!(reloaded.master_volume, 0.25);
+    assert!(!reloaded.advisor_enabled);
+}
+
+#[test]
+fn test_settings_save_in_their_selected_format() {
+    let mut settings = GameSettings::default();
+    assert_eq!(SaveFormat::detect(&settings.to_bytes().unwrap()), SaveFormat::Json);
+
+    settings.save_format = SaveFormat::Binary;
+    let bytes = settings.to_bytes().unwrap();
+    assert_eq!(SaveFormat::detect(&bytes), SaveFormat::Binary);
+    assert_eq!(GameSettings::from_bytes(&bytes).unwrap().save_format, SaveFormat::Binary);
+
+    // Older files predate the option and keep writing JSON
+    assert_eq!(GameSettings::from_bytes(SETTINGS_V2.as_bytes()).unwrap().save_format, SaveFormat::Json);
+}
+
+#[test]
+fn test_binary_saves_are_migrated_and_compressed() {
+    let registry = MigrationRegistry::new(3)
+        .register(2, rename_field)
+        .register(1, add_field);
+    let old = MigrationRegistry::new(1);
+
+    let padding = "x".repeat(4096);
+    let binary = old.save_bytes(&json!({ "old_name": 5, "padding": padding }), SaveFormat::Binary).unwrap();
+    let json = old.save_bytes(&json!({ "old_name": 5, "padding": padding }), SaveFormat::Json).unwrap();
+    assert!(binary.len() < json.len() / 4, "binary saves should compress");
+
+    let migrated: Value = registry.load_bytes(&binary).unwrap();
+    assert_eq!(migrated["new_name"], json!(5));
+    assert_eq!(migrated["version"], json!(3));
+
+    // A truncated binary save is reported rather than half-loaded
+    let truncated = &binary[..binary.len() / 2];
+    assert!(matches!(registry.load_bytes::<Value>(truncated), Err(SaveError::Parse(_))));
+}
@@ -0,0 +1,128 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use tower_defense_bevy::components::{Enemy, Health};
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::seasonal_event_system::*;
+
+fn winter() -> SeasonalEventDefinition {
+    SeasonalEvents::default().get("winter").cloned().expect("built-in winter event")
+}
+
+/// World with the golden enemy observers registered, as `SeasonalEventPlugin` does
+fn event_world(event: Option<SeasonalEventDefinition>) -> World {
+    let mut world = World::new();
+    world.insert_resource(ActiveSeasonalEvent { event, enemies_spawned: 0 });
+    world.init_resource::<Economy>();
+    world.init_resource::<Score>();
+    world.add_observer(golden_enemy_observer);
+    world.add_observer(golden_bounty_observer);
+    world
+}
+
+fn spawn_enemies(world: &mut World, count: usize) -> Vec<Entity> {
+    (0..count)
+        .map(|_| world.spawn((Enemy::default(), Health::new(10.0), Sprite::default())).id())
+        .collect()
+}
+
+#[test]
+fn test_dates_convert_from_days_since_epoch() {
+    assert_eq!(MonthDay::from_days_since_epoch(0), MonthDay::new(1, 1));
+    assert_eq!(MonthDay::from_days_since_epoch(31), MonthDay::new(2, 1));
+    // 2024-02-29 and 2024-12-25
+    assert_eq!(MonthDay::from_days_since_epoch(19_782), MonthDay::new(2, 29));
+    assert_eq!(MonthDay::from_days_since_epoch(20_082), MonthDay::new(12, 25));
+}
+
+#[test]
+fn test_event_windows_include_both_ends_and_wrap_over_new_year() {
+    let december = winter();
+    assert!(december.is_running_on(MonthDay::new(12, 1)));
+    assert!(december.is_running_on(MonthDay::new(12, 31)));
+    assert!(!december.is_running_on(MonthDay::new(11, 30)));
+    assert!(!december.is_running_on(MonthDay::new(1, 1)));
+
+    let new_year = SeasonalEventDefinition {
+        start: MonthDay::new(12, 28),
+        end: MonthDay::new(1, 3),
+        ..winter()
+    };
+    assert!(new_year.is_running_on(MonthDay::new(12, 30)));
+    assert!(new_year.is_running_on(MonthDay::new(1, 2)));
+    assert!(!new_year.is_running_on(MonthDay::new(1, 4)));
+    assert!(!new_year.is_running_on(MonthDay::new(6, 15)));
+}
+
+#[test]
+fn test_override_picks_the_event_regardless_of_date() {
+    let events = SeasonalEvents::default();
+    let summer = MonthDay::new(7, 1);
+    let christmas = MonthDay::new(12, 25);
+
+    assert_eq!(events.active(summer, &SeasonalEventOverride::Auto), None);
+    assert_eq!(events.active(christmas, &SeasonalEventOverride::Auto).map(|event| event.id.as_str()), Some("winter"));
+    assert_eq!(events.active(christmas, &SeasonalEventOverride::Off), None);
+    let forced = SeasonalEventOverride::Force("winter".to_string());
+    assert_eq!(events.active(summer, &forced).map(|event| event.id.as_str()), Some("winter"));
+    assert_eq!(events.active(summer, &SeasonalEventOverride::Force("missing".to_string())), None);
+
+    // The settings button cycles through every choice and back
+    assert_eq!(events.next_override(&SeasonalEventOverride::Auto), SeasonalEventOverride::Off);
+    assert_eq!(events.next_override(&SeasonalEventOverride::Off), forced);
+    assert_eq!(events.next_override(&forced), SeasonalEventOverride::Auto);
+    assert_eq!(events.override_label(&forced), "Winter Festival");
+}
+
+#[test]
+fn test_events_load_from_data() {
+    let json = r#"[{
+        "id": "harvest",
+        "name": "Harvest Moon",
+        "start": { "month": 10, "day": 20 },
+        "end": { "month": 11, "day": 2 },
+        "golden_enemy": { "every": 10, "bonus_bounty": 30, "color": [1.0, 0.6, 0.1] }
+    }]"#;
+    let events = SeasonalEvents::from_json(json).unwrap();
+    let harvest = events.active(MonthDay::new(10, 31), &SeasonalEventOverride::Auto).unwrap();
+    assert_eq!(harvest.name, "Harvest Moon");
+    assert_eq!(harvest.tint(), None, "the tint is optional");
+    assert_eq!(harvest.golden_enemy.as_ref().map(|golden| golden.bonus_bounty), Some(30));
+
+    assert!(SeasonalEvents::from_json("{ not json").is_err());
+}
+
+#[test]
+fn test_every_nth_enemy_is_golden_and_pays_a_bonus_when_killed() {
+    let mut world = event_world(Some(winter()));
+    let every = winter().golden_enemy.unwrap().every as usize;
+    let enemies = spawn_enemies(&mut world, every * 2);
+
+    let golden: Vec<Entity> = enemies.iter().copied().filter(|enemy| world.get::<GoldenEnemy>(*enemy).is_some()).collect();
+    assert_eq!(golden, vec![enemies[every - 1], enemies[every * 2 - 1]]);
+    assert_ne!(world.get::<Sprite>(golden[0]).unwrap().color, Sprite::default().color);
+
+    // A leak pays nothing, a kill pays the bonus
+    let money = world.resource::<Economy>().money;
+    world.despawn(golden[0]);
+    assert_eq!(world.resource::<Economy>().money, money);
+
+    world.get_mut::<Health>(golden[1]).unwrap().take_damage(10.0);
+    world.despawn(golden[1]);
+    assert_eq!(world.resource::<Economy>().money, money + 50);
+}
+
+#[test]
+fn test_no_event_leaves_enemies_and_background_alone() {
+    let mut world = event_world(None);
+    let enemies = spawn_enemies(&mut world, 60);
+    assert!(enemies.iter().all(|enemy| world.get::<GoldenEnemy>(*enemy).is_none()));
+    assert!(enemies.iter().all(|enemy| world.get::<Sprite>(*enemy).unwrap().color == Sprite::default().color));
+
+    world.insert_resource(ClearColor(Color::WHITE));
+    world.run_system_once(apply_biome_tint_system).unwrap();
+    assert_eq!(world.resource::<ClearColor>().0, ClearColor::default().0);
+
+    world.resource_mut::<ActiveSeasonalEvent>().event = Some(winter());
+    world.run_system_once(apply_biome_tint_system).unwrap();
+    assert_eq!(Some(world.resource::<ClearColor>().0), winter().tint());
+}
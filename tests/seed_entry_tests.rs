@@ -0,0 +1,64 @@
+//! Seeded runs: the --seed argument, the seed field and the HUD seed label
+
+mod common;
+
+use bevy::prelude::*;
+use common::UiTestApp;
+use tower_defense_bevy::resources::AppState;
+use tower_defense_bevy::systems::main_menu::{MainMenuAction, MainMenuButton};
+use tower_defense_bevy::systems::map_select::{MapRegistry, MapSelectAction, MapSelectButton};
+use tower_defense_bevy::systems::path_generation::current_level_seed;
+use tower_defense_bevy::systems::seed_entry::*;
+
+fn args(args: &[&str]) -> Vec<String> {
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+#[test]
+fn test_seed_comes_from_either_argument_form() {
+    assert_eq!(seed_from_args(args(&[])), Ok(None));
+    assert_eq!(seed_from_args(args(&["--fullscreen"])), Ok(None));
+    assert_eq!(seed_from_args(args(&["--seed", "1234"])), Ok(Some(1234)));
+    assert_eq!(seed_from_args(args(&["--seed=99"])), Ok(Some(99)));
+    assert!(seed_from_args(args(&["--seed"])).is_err());
+    assert!(seed_from_args(args(&["--seed", "banana"])).is_err());
+}
+
+#[test]
+fn test_seed_entry_only_takes_digits_that_fit_a_seed() {
+    let mut entry = SeedEntry::default();
+    assert_eq!(entry.seed(), None);
+    assert_eq!(entry.label(), "Map default");
+
+    assert!(entry.push('4'));
+    assert!(!entry.push('x'));
+    assert!(entry.push('2'));
+    assert_eq!(entry.seed(), Some(42));
+    entry.pop();
+    assert_eq!(entry.seed(), Some(4));
+
+    let mut entry = SeedEntry::from_seed(u64::MAX);
+    assert!(!entry.push('0'), "would overflow a u64");
+    entry.focused = true;
+    assert_eq!(entry.label(), format!("{}_", u64::MAX));
+}
+
+#[test]
+fn test_typed_seed_starts_the_game_and_shows_on_the_hud() {
+    let mut ui = UiTestApp::at_main_menu();
+    ui.click_button_where::<MainMenuButton>(|button| button.action == MainMenuAction::NewGame);
+    ui.click_button::<SeedField>();
+    ui.type_text("4242");
+    assert_eq!(ui.app.world().resource::<SeedEntry>().seed(), Some(4242));
+
+    // The first map rolls a fresh seed unless one is typed
+    assert_eq!(MapRegistry::default().maps[0].seed, None);
+    ui.click_button_where::<MapSelectButton>(|button| button.action == MapSelectAction::Pick(0));
+    assert_eq!(ui.app_state(), AppState::Playing);
+    assert_eq!(current_level_seed(), 4242);
+
+    ui.app.update();
+    let world = ui.app.world_mut();
+    let hud = world.query_filtered::<&Text, With<SeedHud>>().single(world).unwrap();
+    assert_eq!(hud.0, seed_label(4242));
+}
@@ -0,0 +1,155 @@
+mod common;
+
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use common::UiTestApp;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::shop_system::*;
+use tower_defense_bevy::systems::tower_ui::TowerTypeButton;
+use tower_defense_bevy::systems::ui_feedback::UiFeedbackEvent;
+
+fn open_shop(seed: u64) -> PerkShop {
+    let mut shop = PerkShop::default();
+    shop.open_after(5, &mut GameRng::from_seed(seed));
+    shop
+}
+
+fn shop_world() -> World {
+    let mut world = World::new();
+    world.init_resource::<WavePlan>();
+    world.init_resource::<WaveRuntime>();
+    world.init_resource::<GameState>();
+    world.init_resource::<Economy>();
+    world.init_resource::<RunPerks>();
+    world.init_resource::<PerkShop>();
+    world.insert_resource(GameRng::from_seed(3));
+    world.init_resource::<Events<UiFeedbackEvent>>();
+    world
+}
+
+#[test]
+fn test_shop_opens_every_fifth_wave() {
+    assert!(!is_shop_wave(0));
+    assert!(!is_shop_wave(4));
+    assert!(is_shop_wave(5));
+    assert!(is_shop_wave(10));
+}
+
+#[test]
+fn test_offers_are_distinct_and_seeded() {
+    let shop = open_shop(7);
+    assert_eq!(shop.offers.len(), SHOP_OFFER_COUNT);
+    for (index, perk) in shop.offers.iter().enumerate() {
+        assert!(!shop.offers[index + 1..].contains(perk), "{perk:?} offered twice");
+    }
+    assert_eq!(shop.offers, open_shop(7).offers, "same seed, same offers");
+}
+
+#[test]
+fn test_buying_spends_and_stacks() {
+    let mut shop = open_shop(1);
+    shop.offers = vec![Perk::SharpenedRounds, Perk::FieldResearch, Perk::FreeTower];
+    let mut economy = Economy::new(200, 0, 0, 0);
+    let mut perks = RunPerks::default();
+    let research_generation = economy.research_generation;
+
+    assert_eq!(shop.try_buy(0, &mut economy, &mut perks), Some(Perk::SharpenedRounds));
+    assert_eq!(economy.money, 200 - Perk::SharpenedRounds.get_cost().money);
+    assert!((perks.damage_multiplier() - (1.0 + DAMAGE_PERK_BONUS)).abs() < 1e-6);
+    assert_eq!(shop.offers, vec![Perk::FieldResearch, Perk::FreeTower], "bought offers leave the shop");
+
+    assert_eq!(shop.try_buy(0, &mut economy, &mut perks), Some(Perk::FieldResearch));
+    assert!((economy.research_generation - research_generation - RESEARCH_PERK_GENERATION).abs() < 1e-6);
+
+    // Free Tower is priced in research, which this economy lacks
+    assert_eq!(shop.try_buy(0, &mut economy, &mut perks), None);
+    assert_eq!(perks.free_towers, 0);
+
+    perks.add(Perk::SharpenedRounds);
+    assert_eq!(perks.stacks(Perk::SharpenedRounds), 2);
+    assert!((perks.damage_multiplier() - (1.0 + 2.0 * DAMAGE_PERK_BONUS)).abs() < 1e-6);
+}
+
+#[test]
+fn test_reroll_cost_rises_within_a_visit() {
+    let mut shop = open_shop(2);
+    let mut rng = GameRng::from_seed(9);
+    let mut economy = Economy::new(SHOP_REROLL_COST * 3, 0, 0, 0);
+
+    assert!(shop.try_reroll(&mut economy, &mut rng));
+    assert_eq!(economy.money, SHOP_REROLL_COST * 2);
+    assert_eq!(shop.reroll_cost(), ResourceCost::money(SHOP_REROLL_COST * 2));
+    assert!(shop.try_reroll(&mut economy, &mut rng));
+    assert!(!shop.try_reroll(&mut economy, &mut rng), "third reroll is unaffordable");
+    assert_eq!(shop.offers.len(), SHOP_OFFER_COUNT);
+
+    // The next visit starts cheap again
+    shop.close();
+    shop.open_after(10, &mut rng);
+    assert_eq!(shop.reroll_cost(), ResourceCost::money(SHOP_REROLL_COST));
+}
+
+#[test]
+fn test_shop_opens_after_cleared_shop_wave_and_closes_on_next_wave() {
+    let mut world = shop_world();
+    for _ in 0..5 {
+        world.resource_scope(|world, mut wave_plan: Mut<WavePlan>| start_wave(&mut wave_plan, &mut world.resource_mut::<WaveRuntime>(), 0));
+    }
+    assert_eq!(world.resource::<WavePlan>().current_wave, 5);
+
+    // Still fighting
+    let enemy = world.spawn(Enemy::default()).id();
+    world.run_system_once(shop_open_system).unwrap();
+    assert!(!world.resource::<PerkShop>().open);
+
+    world.despawn(enemy);
+    world.run_system_once(shop_open_system).unwrap();
+    let shop = world.resource::<PerkShop>();
+    assert!(shop.open);
+    assert_eq!(shop.visited_wave, 5);
+
+    // Leaving the shop doesn't reopen it for the same wave
+    world.resource_mut::<PerkShop>().close();
+    world.run_system_once(shop_open_system).unwrap();
+    assert!(!world.resource::<PerkShop>().open);
+
+    // Starting the next wave closes a shop left open
+    world.resource_mut::<PerkShop>().open = true;
+    world.resource_scope(|world, mut wave_plan: Mut<WavePlan>| start_wave(&mut wave_plan, &mut world.resource_mut::<WaveRuntime>(), 4));
+    world.run_system_once(shop_open_system).unwrap();
+    assert!(!world.resource::<PerkShop>().open);
+}
+
+#[test]
+fn test_reinforcements_raise_base_health() {
+    let mut world = shop_world();
+    world.resource_mut::<PerkShop>().open_after(5, &mut GameRng::from_seed(0));
+    world.resource_mut::<PerkShop>().offers = vec![Perk::Reinforcements];
+    let base = world.spawn((Base, Health { current: 50.0, max: BASE_MAX_HEALTH })).id();
+    world.spawn((Button, Interaction::Pressed, BackgroundColor::default(), BorderColor::default(), ShopButton { action: ShopAction::Buy(0) }));
+
+    world.run_system_once(shop_button_system).unwrap();
+
+    let extra = LIVES_PER_PERK as f32 * ENEMY_BASE_DAMAGE;
+    let health = world.get::<Health>(base).unwrap();
+    assert_eq!(health.max, BASE_MAX_HEALTH + extra);
+    assert_eq!(health.current, 50.0 + extra);
+    assert_eq!(world.resource::<RunPerks>().stacks(Perk::Reinforcements), 1);
+}
+
+#[test]
+fn test_free_tower_perk_covers_next_placement() {
+    let mut ui = UiTestApp::new();
+    ui.app.insert_resource(RunPerks { purchased: vec![Perk::FreeTower], free_towers: 1 });
+    let money = ui.app.world().resource::<Economy>().money;
+
+    ui.click_button_where::<TowerTypeButton>(|button| button.tower_type == TowerType::Basic);
+    ui.click_world(Vec2::new(-120.0, 120.0));
+    assert_eq!(ui.towers().len(), 1);
+    assert_eq!(ui.app.world().resource::<Economy>().money, money, "the free tower costs nothing");
+    assert_eq!(ui.app.world().resource::<RunPerks>().free_towers, 0);
+
+    ui.click_world(Vec2::new(120.0, 120.0));
+    assert_eq!(ui.towers().len(), 2);
+    assert_eq!(ui.app.world().resource::<Economy>().money, money - 40, "the perk is used up");
+}
@@ -0,0 +1,189 @@
+use bevy::input::InputPlugin;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::input_system::MouseInputState;
+use tower_defense_bevy::systems::simulation_clock_system::{GameSpeedButton, SimulationClockPlugin};
+
+const FRAME: Duration = Duration::from_millis(100);
+
+/// App running the simulation clock and passive income, ten frames per second
+fn clock_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin, InputPlugin))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+        .init_state::<AppState>()
+        .init_resource::<GameState>()
+        .init_resource::<Economy>()
+        .init_resource::<MouseInputState>()
+        .configure_sets(Update, (GameSystemSet::Input, GameSystemSet::UI, GameSystemSet::Gameplay).chain())
+        .add_plugins(SimulationClockPlugin);
+    app.update();
+    app
+}
+
+fn run_frames(app: &mut App, frames: u32) {
+    for _ in 0..frames {
+        app.update();
+    }
+}
+
+fn set_app_state(app: &mut App, state: AppState) {
+    app.world_mut().resource_mut::<NextState<AppState>>().set(state);
+    app.update();
+}
+
+fn money(app: &App) -> u32 {
+    app.world().resource::<Economy>().money
+}
+
+#[test]
+fn test_clock_scales_with_speed_and_stands_still_when_frozen() {
+    let mut clock = SimulationClock::default();
+    clock.advance(Duration::from_millis(100), true);
+    assert!((clock.delta_secs() - 0.1).abs() < 1e-6);
+
+    clock.set_speed(2.0);
+    clock.advance(Duration::from_millis(100), true);
+    assert!((clock.delta_secs() - 0.2).abs() < 1e-6);
+    assert!((clock.elapsed_secs() - 0.3).abs() < 1e-6);
+
+    clock.advance(Duration::from_millis(100), false);
+    assert_eq!(clock.delta(), Duration::ZERO);
+    assert!(clock.is_frozen());
+    assert!((clock.elapsed_secs() - 0.3).abs() < 1e-6);
+
+    // A long hitch only counts as one capped step
+    clock.set_speed(1.0);
+    clock.advance(Duration::from_secs(5), true);
+    assert_eq!(clock.delta(), MAX_CLOCK_STEP);
+}
+
+#[test]
+fn test_slow_motion_scales_the_game_speed_without_replacing_it() {
+    let mut clock = SimulationClock::default();
+    clock.set_speed(2.0);
+    clock.set_time_scale(0.5);
+    assert_eq!(clock.speed(), 2.0, "the player's chosen speed is kept");
+    assert_eq!(clock.effective_speed(), 1.0);
+    clock.advance(Duration::from_millis(100), true);
+    assert!((clock.delta_secs() - 0.1).abs() < 1e-6);
+
+    clock.set_time_scale(1.0);
+    assert_eq!(clock.effective_speed(), 2.0);
+}
+
+#[test]
+fn test_game_speed_cycles_and_is_clamped() {
+    let mut clock = SimulationClock::default();
+    clock.cycle_speed();
+    assert_eq!(clock.speed(), 2.0);
+    clock.cycle_speed();
+    assert_eq!(clock.speed(), 4.0);
+    clock.cycle_speed();
+    assert_eq!(clock.speed(), 1.0);
+
+    clock.set_speed(100.0);
+    assert_eq!(clock.speed(), MAX_GAME_SPEED);
+    clock.set_speed(0.0);
+    assert_eq!(clock.speed(), MIN_GAME_SPEED);
+}
+
+#[test]
+fn test_fractional_income_adds_up() {
+    let mut economy = Economy::new(0, 0, 0, 0);
+    let mut remainder = IncomeRemainder::default();
+    // No quarter second earns a whole unit of anything on its own
+    for _ in 0..20 {
+        economy.accrue_passive_income(0.25, &mut remainder);
+    }
+    assert_eq!(economy.money, 2);
+    assert_eq!(economy.research_points, 1);
+    assert_eq!(economy.energy, 10);
+}
+
+#[test]
+fn test_income_accrues_while_playing() {
+    let mut app = clock_app();
+    let start = money(&app);
+    run_frames(&mut app, 45);
+    assert_eq!(money(&app), start + 2);
+}
+
+#[test]
+fn test_no_income_accrues_in_pause_or_settings() {
+    let mut app = clock_app();
+    for state in [AppState::Paused, AppState::Settings] {
+        set_app_state(&mut app, state);
+        let before = app.world().resource::<Economy>().clone();
+        run_frames(&mut app, 100);
+
+        let after = app.world().resource::<Economy>();
+        assert_eq!(after.money, before.money, "no money while {:?}", state);
+        assert_eq!(after.research_points, before.research_points);
+        assert_eq!(after.energy, before.energy);
+        assert!(app.world().resource::<SimulationClock>().is_frozen());
+        assert_eq!(app.world().resource::<SimulationClock>().delta(), Duration::ZERO);
+    }
+
+    set_app_state(&mut app, AppState::Playing);
+    assert!(!app.world().resource::<SimulationClock>().is_frozen());
+}
+
+#[test]
+fn test_clock_freezes_once_the_run_ends() {
+    let mut app = clock_app();
+    *app.world_mut().resource_mut::<GameState>() = GameState::GameOver;
+    let elapsed = app.world().resource::<SimulationClock>().elapsed_secs();
+    let start = money(&app);
+    run_frames(&mut app, 50);
+    assert_eq!(money(&app), start);
+    assert_eq!(app.world().resource::<SimulationClock>().elapsed_secs(), elapsed);
+}
+
+#[test]
+fn test_double_speed_doubles_income_and_virtual_time() {
+    let mut app = clock_app();
+    app.world_mut().resource_mut::<SimulationClock>().set_speed(2.0);
+    let start = money(&app);
+    run_frames(&mut app, 25);
+    assert_eq!(money(&app), start + 2);
+    assert_eq!(app.world().resource::<Time<Virtual>>().relative_speed(), 2.0);
+}
+
+#[test]
+fn test_speed_buttons_hold_and_set_the_clock() {
+    let mut clock = SimulationClock::default();
+    GameSpeedButton::Hold.apply(&mut clock);
+    assert!(clock.is_held() && clock.is_frozen());
+    assert_eq!(clock.effective_speed(), 0.0);
+    clock.advance(Duration::from_millis(100), true);
+    assert_eq!(clock.delta(), Duration::ZERO);
+    assert!(GameSpeedButton::Hold.is_active(&clock));
+
+    // Picking a speed lets go of the hold
+    GameSpeedButton::Speed(4.0).apply(&mut clock);
+    assert!(!clock.is_held());
+    assert!(GameSpeedButton::Speed(4.0).is_active(&clock));
+    assert!(!GameSpeedButton::Speed(1.0).is_active(&clock));
+    clock.advance(Duration::from_millis(100), true);
+    assert!((clock.delta_secs() - 0.4).abs() < 1e-6);
+}
+
+#[test]
+fn test_holding_stops_the_game_without_the_pause_menu() {
+    let mut app = clock_app();
+    app.world_mut().resource_mut::<SimulationClock>().set_held(true);
+    let before = money(&app);
+    run_frames(&mut app, 30);
+
+    assert_eq!(money(&app), before);
+    assert_eq!(app.world().resource::<Time<Virtual>>().relative_speed(), 0.0);
+    assert_eq!(*app.world().resource::<State<AppState>>().get(), AppState::Playing);
+
+    app.world_mut().resource_mut::<SimulationClock>().set_held(false);
+    run_frames(&mut app, 1);
+    assert_eq!(app.world().resource::<Time<Virtual>>().relative_speed(), 1.0);
+}
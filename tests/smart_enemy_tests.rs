@@ -0,0 +1,151 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use std::time::Duration;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::obstacle_rendering::ObstacleGrid;
+use tower_defense_bevy::systems::path_generation::*;
+use tower_defense_bevy::systems::smart_enemy_system::*;
+
+/// 9x5 grid with a wall along the middle row, leaving a corridor above and below it
+fn two_corridor_grid() -> PathGrid {
+    let mut grid = PathGrid::new(9, 5);
+    for x in 1..8 {
+        grid.set_cell(GridPos::new(x, 2), CellType::Blocked);
+    }
+    grid
+}
+
+fn route_cells(grid: &PathGrid, path: &EnemyPath) -> Vec<GridPos> {
+    path.waypoints.iter().filter_map(|point| grid.world_to_grid(*point)).collect()
+}
+
+#[test]
+fn test_costly_cells_are_avoided() {
+    let grid = PathGrid::new(5, 3);
+    let start = GridPos::new(0, 1);
+    let goal = GridPos::new(4, 1);
+
+    // No cost layer: straight along the middle row
+    assert_eq!(find_path(&grid, start, goal).unwrap().len(), 5);
+
+    // An expensive cell in the way is worth a two-step detour
+    let expensive = GridPos::new(2, 1);
+    let path = find_path_with_costs(&grid, start, goal, |pos| if pos == expensive { 5.0 } else { 0.0 }).unwrap();
+    assert!(!path.contains(&expensive));
+    assert_eq!(path.len(), 7);
+
+    // A cheap one isn't
+    let path = find_path_with_costs(&grid, start, goal, |pos| if pos == expensive { 1.0 } else { 0.0 }).unwrap();
+    assert!(path.contains(&expensive));
+
+    // Infinite cost blocks the cell outright
+    let wall = |pos: GridPos| if pos.x == 2 { f32::INFINITY } else { 0.0 };
+    assert!(find_path_with_costs(&grid, start, goal, wall).is_none());
+}
+
+#[test]
+fn test_danger_counts_covering_towers() {
+    let grid = PathGrid::new(9, 5);
+    let a = grid.grid_to_world(GridPos::new(2, 2));
+    let b = grid.grid_to_world(GridPos::new(4, 2));
+
+    let field = DangerField::from_towers(&grid, &[(a, 90.0), (b, 90.0)]);
+    assert_eq!(field.cost(GridPos::new(3, 2)), 2.0 * DANGER_COST_PER_TOWER);
+    assert_eq!(field.cost(GridPos::new(0, 2)), DANGER_COST_PER_TOWER);
+    assert_eq!(field.cost(GridPos::new(8, 0)), 0.0);
+
+    // Tower cells can't be walked through
+    assert!(field.cost(GridPos::new(2, 2)).is_infinite());
+    // Outside the field costs nothing
+    assert_eq!(field.cost(GridPos::new(20, 20)), 0.0);
+}
+
+#[test]
+fn test_smart_spawns_only_on_maze_maps() {
+    let settings = SmartEnemySettings::default();
+    let smart: Vec<u32> = (0..12).filter(|&index| settings.spawns_smart(index, MapArchetype::Maze)).collect();
+    assert_eq!(smart, vec![3, 7, 11]);
+    assert!(!(0..12).any(|index| settings.spawns_smart(index, MapArchetype::Classic)));
+
+    let everywhere = SmartEnemySettings {
+        maze_only: false,
+        ..default()
+    };
+    assert!(everywhere.spawns_smart(3, MapArchetype::Classic));
+
+    let disabled = SmartEnemySettings {
+        enabled: false,
+        ..default()
+    };
+    assert!(!disabled.spawns_smart(3, MapArchetype::Maze));
+}
+
+#[test]
+fn test_route_takes_less_defended_corridor() {
+    let grid = two_corridor_grid();
+    let start = grid.grid_to_world(GridPos::new(0, 2));
+    let goal = grid.grid_to_world(GridPos::new(8, 2));
+    let tower = grid.grid_to_world(GridPos::new(4, 0));
+
+    let danger = DangerField::from_towers(&grid, &[(tower, 70.0)]);
+    let path = smart_route(&grid, &danger, start, goal).unwrap();
+
+    assert_eq!(path.waypoints[0], start, "route starts where the enemy is");
+    assert_eq!(*path.waypoints.last().unwrap(), goal);
+    let cells = route_cells(&grid, &path);
+    assert!(cells.iter().all(|cell| cell.y >= 2), "went through the defended corridor: {:?}", cells);
+}
+
+#[test]
+fn test_reroute_system_gives_smart_enemies_a_route() {
+    let grid = two_corridor_grid();
+    let start = grid.grid_to_world(GridPos::new(0, 2));
+    let goal = grid.grid_to_world(GridPos::new(8, 2));
+
+    let mut world = World::new();
+    world.insert_resource(ObstacleGrid {
+        grid: grid.clone(),
+        ..default()
+    });
+    world.insert_resource(EnemyPath::new(vec![start, goal]));
+    world.init_resource::<SmartEnemySettings>();
+    world.init_resource::<DangerMap>();
+    world.init_resource::<Time>();
+
+    world.spawn((TowerStats::new(TowerType::Basic), Transform::from_translation(grid.grid_to_world(GridPos::new(4, 4)).extend(0.0))));
+    let mut progress = PathProgress::new();
+    progress.current = 0.3;
+    let smart = world.spawn((Enemy::default(), SmartEnemy, progress, Transform::from_translation(start.extend(0.0)))).id();
+    let plain = world.spawn((Enemy::default(), PathProgress::new(), Transform::from_translation(start.extend(0.0)))).id();
+
+    // Nothing happens until the reroute interval passes
+    world.run_system_once(smart_reroute_system).unwrap();
+    assert!(world.get::<SmartRoute>(smart).is_none());
+
+    world.resource_mut::<Time>().advance_by(Duration::from_secs(1));
+    world.run_system_once(smart_reroute_system).unwrap();
+
+    let route = world.get::<SmartRoute>(smart).expect("smart enemy was routed");
+    let cells = route_cells(&grid, &route.path);
+    assert!(cells.iter().all(|cell| cell.y <= 2), "went past the tower: {:?}", cells);
+    assert_eq!(world.get::<PathProgress>(smart).unwrap().current, 0.0);
+    assert!(world.get::<SmartRoute>(plain).is_none());
+}
+
+#[test]
+fn test_rerouting_keeps_the_progress_an_enemy_made() {
+    let travelled = EnemyPath::new(vec![Vec2::new(0.0, 0.0), Vec2::new(100.0, 0.0), Vec2::new(100.0, 100.0), Vec2::new(200.0, 100.0)]);
+    assert_eq!(travelled.progress_nearest(Vec2::new(100.0, 50.0)), 0.5);
+    assert_eq!(travelled.progress_nearest(Vec2::new(-50.0, 10.0)), 0.0);
+    assert_eq!(travelled.progress_nearest(Vec2::new(500.0, 100.0)), 1.0);
+
+    // Half way up the second segment, turning off to the left
+    let position = travelled.get_position_at_progress(0.5);
+    let route = EnemyPath::new(vec![position, Vec2::new(0.0, 50.0), Vec2::new(0.0, 200.0)]);
+    let (path, progress) = continue_route(&travelled, 0.5, route);
+
+    assert_eq!(path.waypoints[..2], travelled.waypoints[..2], "the walked stretch is kept");
+    assert_eq!(path.waypoints.last(), Some(&Vec2::new(0.0, 200.0)));
+    assert_eq!(progress, 0.5);
+    assert_eq!(path.get_position_at_progress(progress), position);
+}
@@ -0,0 +1,92 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::enemy_system::calculate_enemies_for_wave;
+use tower_defense_bevy::systems::spawn_preview::*;
+
+fn preview_world() -> World {
+    let mut world = World::new();
+    world.insert_resource(WavePlan::default());
+    world.insert_resource(WaveRuntime::new());
+    world.insert_resource(EnemyPath::new(vec![Vec2::new(-700.0, 0.0), Vec2::new(400.0, 0.0)]));
+    world.init_resource::<SpawnPreview>();
+    world.init_resource::<NumberFormatter>();
+    world
+}
+
+#[test]
+fn test_wave_is_split_across_entries() {
+    let left = EnemyPath::new(vec![Vec2::new(-600.0, 0.0), Vec2::new(0.0, 0.0)]);
+    let top = EnemyPath::new(vec![Vec2::new(0.0, 340.0), Vec2::new(0.0, 0.0)]);
+
+    let entries = entry_previews(&[&left, &top], 7);
+    assert_eq!(entries.len(), 2);
+    assert_eq!((entries[0].enemies, entries[1].enemies), (4, 3));
+    assert!((entries[0].share + entries[1].share - 1.0).abs() < 1e-6);
+
+    // Arrows point the way enemies walk in
+    assert!(entries[0].direction.abs_diff_eq(Vec2::X, 1e-4));
+    assert!(entries[1].direction.abs_diff_eq(Vec2::NEG_Y, 1e-4));
+
+    assert!(entry_previews(&[&left], 0).is_empty());
+}
+
+#[test]
+fn test_off_screen_entries_are_pulled_into_view() {
+    let path = EnemyPath::new(vec![Vec2::new(-900.0, 500.0), Vec2::new(0.0, 0.0)]);
+
+    let entry = entry_previews(&[&path], 5)[0];
+    assert!(entry.position.x > -PLAY_AREA_WIDTH / 2.0);
+    assert!(entry.position.y < PLAY_AREA_HEIGHT / 2.0);
+    assert_eq!(entry.enemies, 5);
+    assert_eq!(entry.share, 1.0);
+}
+
+#[test]
+fn test_preview_shows_between_waves_only() {
+    let mut world = preview_world();
+
+    world.run_system_once(update_spawn_preview_system).unwrap();
+    let preview = world.resource::<SpawnPreview>();
+    assert_eq!(preview.wave, 1);
+    assert_eq!(preview.entries.len(), 1);
+    assert_eq!(preview.entries[0].enemies, calculate_enemies_for_wave(1));
+
+    // Cleared once the wave is underway
+    world.resource_scope(|world, mut wave_plan: Mut<WavePlan>| start_wave(&mut wave_plan, &mut world.resource_mut::<WaveRuntime>(), calculate_enemies_for_wave(1)));
+    world.spawn(Enemy::default());
+    world.run_system_once(update_spawn_preview_system).unwrap();
+    assert!(world.resource::<SpawnPreview>().entries.is_empty());
+
+    // Back once the whole wave has spawned and the field is clear, now for the following wave
+    for _ in 0..calculate_enemies_for_wave(1) {
+        world.resource_mut::<WaveRuntime>().enemy_spawned();
+    }
+    let enemies: Vec<Entity> = world.query_filtered::<Entity, With<Enemy>>().iter(&world).collect();
+    for enemy in enemies {
+        world.despawn(enemy);
+    }
+    world.run_system_once(update_spawn_preview_system).unwrap();
+    let preview = world.resource::<SpawnPreview>();
+    assert_eq!(preview.wave, 2);
+    assert_eq!(preview.entries[0].enemies, calculate_enemies_for_wave(2));
+}
+
+#[test]
+fn test_arrows_follow_the_preview() {
+    let mut world = preview_world();
+    let mut schedule = Schedule::default();
+    schedule.add_systems((update_spawn_preview_system, spawn_preview_visual_system).chain());
+
+    schedule.run(&mut world);
+    let arrow_parts = world.query_filtered::<(), With<SpawnPreviewArrow>>().iter(&world).count();
+    assert!(arrow_parts > 0);
+
+    // Nothing changed: the same arrows stay
+    schedule.run(&mut world);
+    assert_eq!(world.query_filtered::<(), With<SpawnPreviewArrow>>().iter(&world).count(), arrow_parts);
+
+    world.resource_scope(|world, mut wave_plan: Mut<WavePlan>| start_wave(&mut wave_plan, &mut world.resource_mut::<WaveRuntime>(), 5));
+    schedule.run(&mut world);
+    assert_eq!(world.query_filtered::<(), With<SpawnPreviewArrow>>().iter(&world).count(), 0);
+}
@@ -0,0 +1,154 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use std::time::Duration;
+use tower_defense_bevy::components::*;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::combat_system::{collision_system, EnemyDamagedEvent, EnemyKilledEvent, WaveStatus};
+use tower_defense_bevy::systems::enemy_system::enemy_movement_system;
+use tower_defense_bevy::systems::status_effect_system::status_effect_system;
+
+fn combat_world() -> World {
+    let mut world = World::new();
+    world.insert_resource(Economy::default());
+    world.insert_resource(Score::default());
+    world.insert_resource(WaveStatus::default());
+    world.insert_resource(Time::<()>::default());
+    world.init_resource::<Events<EnemyKilledEvent>>();
+    world.init_resource::<Events<EnemyDamagedEvent>>();
+    world
+}
+
+fn spawn_enemy(world: &mut World, position: Vec2, health: f32) -> Entity {
+    world
+        .spawn((Enemy::default(), Health::new(health), Transform::from_translation(position.extend(0.0))))
+        .id()
+}
+
+fn advance_time(world: &mut World, seconds: f32) {
+    world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(seconds));
+}
+
+#[test]
+fn test_slow_wears_off_and_is_capped() {
+    let mut slow = Slow::new(0.4, 1.0);
+    assert!((slow.speed_multiplier() - 0.6).abs() < 1e-6);
+    assert!(!slow.tick(0.5));
+    assert!(slow.tick(0.6));
+    assert_eq!(Slow::new(5.0, 1.0).amount, MAX_SLOW);
+}
+
+#[test]
+fn test_burn_deals_its_full_damage_in_ticks() {
+    let mut burn = Burn::new(10.0, BURN_DURATION, TowerType::Laser);
+    let mut total = 0.0;
+    let mut ticks = 0;
+    while !burn.is_finished() {
+        let damage = burn.tick(0.1);
+        if damage > 0.0 {
+            ticks += 1;
+        }
+        total += damage;
+    }
+    assert!((total - 10.0 * BURN_DURATION).abs() < 1e-3, "dealt {total}");
+    assert!(ticks >= (BURN_DURATION / BURN_TICK_INTERVAL) as usize - 1, "damage arrives in ticks, not every frame");
+}
+
+#[test]
+fn test_chain_jumps_to_nearest_enemies_in_range() {
+    let candidates = [Vec2::new(50.0, 0.0), Vec2::new(20.0, 0.0), Vec2::new(500.0, 0.0), Vec2::new(100.0, 0.0)];
+    let jumps = chain_jumps(Vec2::ZERO, &candidates, 5, 100.0);
+    let order: Vec<usize> = jumps.iter().map(|(index, _)| *index).collect();
+    assert_eq!(order, vec![1, 0, 3], "nearest first, never out of range");
+    assert!(jumps.windows(2).all(|pair| pair[1].1 < pair[0].1), "each jump hits softer");
+
+    assert_eq!(chain_jumps(Vec2::ZERO, &candidates, 1, 100.0).len(), 1);
+    assert!(chain_jumps(Vec2::ZERO, &candidates, 0, 100.0).is_empty());
+}
+
+#[test]
+fn test_towers_carry_their_status_effects() {
+    let tesla = TowerStats::new(TowerType::Tesla);
+    assert!(tesla.chain_targets > 0);
+    let laser = TowerStats::new(TowerType::Laser);
+    assert!(laser.burn_dps > 0.0);
+
+    // Basic towers learn to slow through upgrades
+    let mut basic = TowerStats::new(TowerType::Basic);
+    assert_eq!(basic.slow, 0.0);
+    while basic.upgrade_level < SLOW_UPGRADE_LEVEL {
+        basic.upgrade();
+    }
+    assert!(basic.slow > 0.0);
+
+    for tower_type in [TowerType::Advanced, TowerType::Missile] {
+        let stats = TowerStats::new(tower_type);
+        assert_eq!((stats.chain_targets, stats.burn_dps, stats.slow), (0, 0.0, 0.0), "{:?}", tower_type);
+    }
+}
+
+#[test]
+fn test_tesla_hit_chains_to_nearby_enemies() {
+    let mut world = combat_world();
+    let struck = spawn_enemy(&mut world, Vec2::ZERO, 100.0);
+    let near = spawn_enemy(&mut world, Vec2::new(40.0, 0.0), 100.0);
+    let far = spawn_enemy(&mut world, Vec2::new(400.0, 0.0), 100.0);
+    world.spawn((
+        Transform::default(),
+        Projectile::new(20.0, 600.0, struck, Vec2::ZERO, TowerType::Tesla).with_status_effects(2, 0.0, 0.0),
+    ));
+    world.run_system_once(collision_system).unwrap();
+
+    let health = |world: &World, enemy: Entity| world.get::<Health>(enemy).unwrap().current;
+    assert_eq!(health(&world, struck), 80.0);
+    assert!((health(&world, near) - (100.0 - 20.0 * CHAIN_DAMAGE_FALLOFF)).abs() < 1e-4);
+    assert_eq!(health(&world, far), 100.0);
+    assert!(world.get::<Chained>(struck).is_some() && world.get::<Chained>(near).is_some());
+}
+
+#[test]
+fn test_hits_apply_slow_and_burn_to_survivors() {
+    let mut world = combat_world();
+    let enemy = spawn_enemy(&mut world, Vec2::ZERO, 100.0);
+    world.spawn((
+        Transform::default(),
+        Projectile::new(10.0, 300.0, enemy, Vec2::ZERO, TowerType::Laser).with_status_effects(0, 5.0, 0.3),
+    ));
+    world.run_system_once(collision_system).unwrap();
+
+    assert_eq!(world.get::<Slow>(enemy).unwrap().amount, 0.3);
+    assert_eq!(world.get::<Burn>(enemy).unwrap().damage_per_second, 5.0);
+}
+
+#[test]
+fn test_burn_kills_pay_out_and_effects_expire() {
+    let mut world = combat_world();
+    let burning = spawn_enemy(&mut world, Vec2::ZERO, 4.0);
+    world.entity_mut(burning).insert(Burn::new(10.0, BURN_DURATION, TowerType::Laser));
+    let slowed = spawn_enemy(&mut world, Vec2::new(200.0, 0.0), 50.0);
+    world.entity_mut(slowed).insert((Slow::new(0.5, 0.2), Chained::default()));
+    let money_before = world.resource::<Economy>().money;
+
+    advance_time(&mut world, BURN_TICK_INTERVAL);
+    world.run_system_once(status_effect_system).unwrap();
+
+    assert!(world.get::<DyingEnemy>(burning).is_some(), "the burn finished the enemy off");
+    assert!(world.get::<Enemy>(burning).is_none());
+    assert!(world.resource::<Economy>().money > money_before);
+    assert_eq!(world.resource::<WaveStatus>().enemies_killed, 1);
+    assert!(world.get::<Slow>(slowed).is_none());
+    assert!(world.get::<Chained>(slowed).is_none());
+}
+
+#[test]
+fn test_slowed_enemies_move_less() {
+    let mut world = combat_world();
+    world.insert_resource(EnemyPath::new(vec![Vec2::ZERO, Vec2::new(1000.0, 0.0)]));
+    let normal = world.spawn((Enemy::default(), PathProgress::new(), Transform::default())).id();
+    let slowed = world.spawn((Enemy::default(), PathProgress::new(), Transform::default(), Slow::new(0.5, 5.0))).id();
+
+    advance_time(&mut world, 1.0);
+    world.run_system_once(enemy_movement_system).unwrap();
+
+    let progress = |world: &World, enemy: Entity| world.get::<PathProgress>(enemy).unwrap().current;
+    assert!((progress(&world, slowed) - progress(&world, normal) * 0.5).abs() < 1e-4);
+}
@@ -0,0 +1,73 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::stress_test_system::*;
+
+fn count<T: Component>(world: &mut World) -> usize {
+    world.query_filtered::<(), With<T>>().iter(world).count()
+}
+
+#[test]
+fn test_run_records_until_duration_and_summarises() {
+    let mut run = StressTestRun::default();
+    assert!(!run.record(0.016, 1, 1, 1), "inactive runs record nothing");
+
+    run.start(StressTestConfig { duration: 0.05, ..default() });
+    assert!(!run.record(0.01, 10, 2, 5));
+    assert!(!run.record(0.03, 10, 2, 5));
+    assert!(run.record(0.02, 10, 2, 4));
+
+    let summary = run.summary();
+    assert_eq!(summary.frames, 3);
+    assert!((summary.average_ms - 20.0).abs() < 1e-3);
+    assert!((summary.max_ms - 30.0).abs() < 1e-3);
+    assert_eq!(summary.p95_ms, summary.max_ms);
+
+    let csv = run.to_csv();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert!(lines[0].starts_with("# enemies=200"));
+    assert_eq!(lines[1], "time_s,frame_time_ms,enemies,towers,projectiles");
+    assert_eq!(lines.len(), 5);
+    assert!(lines[4].ends_with(",10,2,4"));
+}
+
+#[test]
+fn test_tower_positions_are_spread_and_distinct() {
+    assert!(stress_tower_positions(0).is_empty());
+    let positions = stress_tower_positions(40);
+    assert_eq!(positions.len(), 40);
+    for (index, position) in positions.iter().enumerate() {
+        assert!(position.x.abs() <= 500.0 && position.y.abs() <= 250.0);
+        assert!(positions[..index].iter().all(|other| other.distance(*position) > 1.0));
+    }
+}
+
+#[test]
+fn test_start_replaces_board_with_synthetic_load() {
+    let mut world = World::new();
+    world.insert_resource(EnemyPath::new(vec![Vec2::new(-600.0, 0.0), Vec2::new(600.0, 0.0)]));
+    world.insert_resource(StressTestConfig { enemies: 12, towers: 5, projectiles: 20, duration: 1.0 });
+    world.init_resource::<StressTestRun>();
+    world.init_resource::<Events<StartStressTestEvent>>();
+    world.spawn(Enemy::for_wave(3));
+
+    world.run_system_once(start_stress_test_system).unwrap();
+    assert_eq!(count::<Enemy>(&mut world), 1, "nothing happens without the event");
+
+    world.send_event(StartStressTestEvent);
+    world.run_system_once(start_stress_test_system).unwrap();
+    assert_eq!(count::<Enemy>(&mut world), 12);
+    assert_eq!(count::<TowerStats>(&mut world), 5);
+    assert_eq!(count::<Projectile>(&mut world), 20);
+    assert!(world.resource::<StressTestRun>().active);
+
+    // Lost projectiles are replaced and enemies near the end loop back
+    let projectile = world.query_filtered::<Entity, With<Projectile>>().iter(&world).next().unwrap();
+    world.despawn(projectile);
+    for mut progress in world.query::<&mut PathProgress>().iter_mut(&mut world) {
+        progress.current = 0.99;
+    }
+    world.run_system_once(maintain_stress_load_system).unwrap();
+    assert_eq!(count::<Projectile>(&mut world), 20);
+    assert!(world.query::<&PathProgress>().iter(&world).all(|progress| progress.current == 0.0));
+}
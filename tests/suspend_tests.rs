@@ -0,0 +1,190 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use tower_defense_bevy::components::*;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::combat_system::{FocusZone, TargetingMode, WaveStatus};
+use tower_defense_bevy::systems::obstacle_rendering::ObstacleGrid;
+use tower_defense_bevy::systems::path_generation::*;
+use tower_defense_bevy::systems::smart_enemy_system::SmartEnemy;
+use tower_defense_bevy::systems::suspend_system::*;
+use tower_defense_bevy::systems::tower_ui::TowerSelectionState;
+
+/// World with an open 10x5 map, its path, and wave 2 under way
+fn run_world() -> World {
+    let mut grid = PathGrid::new(10, 5);
+    grid.entry_point = GridPos::new(0, 2);
+    grid.exit_point = GridPos::new(9, 2);
+    let route = find_path(&grid, grid.entry_point, grid.exit_point).unwrap();
+    let path = grid.to_enemy_path(route);
+    let analysis = analyze_map(&grid, MapArchetype::Classic);
+
+    let mut world = World::new();
+    world.insert_resource(ObstacleGrid { grid, wave_number: 1, analysis });
+    world.insert_resource(path);
+    let mut wave_plan = WavePlan::default();
+    let mut wave_runtime = WaveRuntime::new();
+    wave_plan.current_wave = 1;
+    start_wave(&mut wave_plan, &mut wave_runtime, 6);
+    wave_runtime.enemies_spawned = 2;
+    world.insert_resource(wave_plan);
+    world.insert_resource(wave_runtime);
+    let mut wave_status = WaveStatus::default();
+    wave_status.initialize_wave(6);
+    world.insert_resource(wave_status);
+    world.insert_resource(GameState::Playing);
+    world.insert_resource(Economy::default());
+    world.insert_resource(Score::new());
+    world
+}
+
+fn spawn_enemy(world: &mut World, progress: f32) -> Entity {
+    world
+        .spawn((
+            Enemy { speed: 65.0, reward: 12, ..default() },
+            Health { current: 30.0, max: 40.0 },
+            PathProgress { current: progress },
+            SwarmOffset { lateral: 4.0 },
+            Transform::from_xyz(10.0, 20.0, 0.0),
+        ))
+        .id()
+}
+
+#[test]
+fn test_only_runs_quit_mid_wave_are_suspended() {
+    let mut wave_plan = WavePlan::default();
+    let mut wave_runtime = WaveRuntime::new();
+    assert!(!is_mid_wave(&wave_plan, &wave_runtime, GameState::Playing, 0), "no wave started yet");
+
+    start_wave(&mut wave_plan, &mut wave_runtime, 5);
+    assert!(is_mid_wave(&wave_plan, &wave_runtime, GameState::Playing, 0), "still spawning");
+    wave_runtime.enemies_spawned = 5;
+    assert!(is_mid_wave(&wave_plan, &wave_runtime, GameState::Playing, 2), "enemies left on the board");
+    assert!(!is_mid_wave(&wave_plan, &wave_runtime, GameState::Playing, 0), "between waves");
+    assert!(!is_mid_wave(&wave_plan, &wave_runtime, GameState::GameOver, 2), "the run is over");
+
+    let mut world = run_world();
+    world.resource_mut::<WaveRuntime>().enemies_spawned = 6;
+    assert_eq!(SuspendedRun::capture(&world), None);
+}
+
+#[test]
+fn test_capture_keeps_the_board_and_wave_progress() {
+    let mut world = run_world();
+    let enemy = spawn_enemy(&mut world, 0.3);
+    world.entity_mut(enemy).insert(SmartEnemy);
+    spawn_enemy(&mut world, 0.1);
+    let mut stats = TowerStats::new(TowerType::Laser);
+    stats.upgrade();
+    world.spawn((stats, Transform::from_xyz(50.0, -20.0, 0.0)));
+    world.spawn((Base, Health { current: 70.0, max: 100.0 }));
+    world.resource_mut::<Economy>().money = 345;
+
+    let run = SuspendedRun::capture(&world).expect("wave 2 is under way");
+    assert_eq!(run.wave.current, 2);
+    assert_eq!(run.wave.enemies_spawned, 2);
+    assert_eq!(run.wave.enemies_remaining, 6);
+    assert_eq!(run.economy.money, 345);
+    assert_eq!(run.base.map(|base| base.current), Some(70.0));
+    assert_eq!(run.towers.len(), 1);
+    assert_eq!(run.towers[0].upgrade_level, 2);
+    assert_eq!(run.enemies.len(), 2);
+    assert_eq!(run.enemies.iter().filter(|enemy| enemy.smart).count(), 1);
+    assert!(run.enemies.iter().all(|enemy| enemy.lateral == Some(4.0) && enemy.health.current == 30.0));
+    assert!(SharedMap::from_code(&run.map_code).is_ok());
+
+    let json = run.to_json().unwrap();
+    assert!(json.contains("\"version\""));
+    assert_eq!(SuspendedRun::from_json(&json).unwrap(), run);
+}
+
+#[test]
+fn test_resuming_puts_the_run_back_exactly() {
+    let mut source = run_world();
+    spawn_enemy(&mut source, 0.45);
+    // Overheated and part way through its shutdown
+    let mut heat = Heat { current: MAX_HEAT, overclocked: true, ..default() };
+    heat.tick(0.1);
+    heat.tick(1.5);
+    source.spawn((
+        TowerStats::new(TowerType::Tesla),
+        Transform::from_xyz(-30.0, 15.0, 0.0),
+        TargetingMode::Strongest,
+        FocusZone { rect: Rect::new(-60.0, 0.0, 10.0, 40.0) },
+        heat,
+    ));
+    source.resource_mut::<Economy>().money = 512;
+    source.resource_mut::<Score>().current = 900;
+    let mut integrity = RunIntegrity::default();
+    integrity.flag(TamperReason::CheatMenu);
+    source.insert_resource(integrity);
+    // A chosen map with two routes
+    let level_config = LevelConfig {
+        layout: Some(LevelLayout { width: 10, height: 5, obstacle_density: 0.0 }),
+        routes: 2,
+        void_lake: false,
+    };
+    source.insert_resource(level_config);
+    let run = SuspendedRun::capture(&source).unwrap();
+    assert_eq!(run.level, level_config);
+    assert_eq!(run.towers[0].targeting, TargetingMode::Strongest);
+    assert_eq!(run.towers[0].focus_zone, Some([[-60.0, 0.0], [10.0, 40.0]]));
+    assert_eq!(run.towers[0].heat.shutdown_elapsed, Some(1.5));
+
+    // A fresh launch: wave 0 and an empty board
+    let mut world = World::new();
+    world.insert_resource(ObstacleGrid::default());
+    world.insert_resource(EnemyPath::new(vec![Vec2::ZERO, Vec2::X]));
+    world.insert_resource(WavePlan::default());
+    world.insert_resource(WaveRuntime::new());
+    world.insert_resource(WaveStatus::default());
+    world.insert_resource(GameState::Playing);
+    world.insert_resource(Economy::default());
+    world.insert_resource(Score::new());
+    world.init_resource::<TowerSelectionState>();
+    world.init_resource::<RunIntegrity>();
+    world.init_resource::<LevelConfig>();
+    world.init_resource::<Events<SuspendedRunChoice>>();
+    world.insert_resource(PendingSuspendedRun(Some(run.clone())));
+    world.send_event(SuspendedRunChoice::Resume);
+    world.run_system_once(resume_suspended_run_system).unwrap();
+
+    assert!(world.resource::<PendingSuspendedRun>().0.is_none(), "a run resumes only once");
+    assert_eq!(world.resource::<RunIntegrity>().reasons(), &[TamperReason::CheatMenu], "suspending doesn't clear cheats");
+    assert_eq!(*world.resource::<LevelConfig>(), level_config, "a restart regenerates the same map");
+    assert_eq!(SuspendedRun::capture(&world), Some(run));
+}
+
+#[test]
+fn test_discarding_drops_the_suspended_run() {
+    let mut source = run_world();
+    spawn_enemy(&mut source, 0.45);
+    let run = SuspendedRun::capture(&source).unwrap();
+
+    let mut world = run_world();
+    world.init_resource::<TowerSelectionState>();
+    world.init_resource::<Events<SuspendedRunChoice>>();
+    world.insert_resource(PendingSuspendedRun(Some(run)));
+    world.send_event(SuspendedRunChoice::Discard);
+    world.run_system_once(resume_suspended_run_system).unwrap();
+
+    assert!(world.resource::<PendingSuspendedRun>().0.is_none());
+    assert_eq!(world.query::<&Enemy>().iter(&world).count(), 0);
+}
+
+#[test]
+fn test_a_lost_run_leaves_nothing_to_resume() {
+    let mut world = run_world();
+    spawn_enemy(&mut world, 0.45);
+    let run = SuspendedRun::capture(&world).unwrap();
+    // An autosave taken mid-wave, before the defeat
+    run.save(SaveFormat::default()).unwrap();
+    world.insert_resource(PendingSuspendedRun(Some(run)));
+
+    world.run_system_once(clear_suspended_run_system).unwrap();
+    assert!(world.resource::<PendingSuspendedRun>().0.is_some(), "the run is still going");
+
+    *world.resource_mut::<GameState>() = GameState::GameOver;
+    world.run_system_once(clear_suspended_run_system).unwrap();
+    assert!(world.resource::<PendingSuspendedRun>().0.is_none());
+    assert!(SuspendedRun::load().is_none(), "Continue has no run to offer");
+}
@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+use tower_defense_bevy::resources::{CombatSet, EnemySet};
+use tower_defense_bevy::systems::system_order::*;
+
+fn enemy_movement_system() {}
+fn tower_targeting_system() {}
+fn projectile_spawning_system() {}
+fn collision_system() {}
+
+fn names(list: &[&str]) -> Vec<String> {
+    list.iter().map(|name| name.to_string()).collect()
+}
+
+#[test]
+fn test_find_order_violations() {
+    let expected = ["a", "b", "c"];
+
+    assert!(find_order_violations(&names(&["a", "x", "b", "c"]), &expected).is_empty());
+    assert_eq!(
+        find_order_violations(&names(&["b", "a", "c"]), &expected),
+        vec![SystemOrderViolation::OutOfOrder { first: "a".to_string(), second: "b".to_string() }]
+    );
+    assert_eq!(
+        find_order_violations(&names(&["a", "c"]), &expected),
+        vec![SystemOrderViolation::Missing("b".to_string())]
+    );
+}
+
+#[test]
+fn test_sets_resolve_in_declared_order() {
+    let mut app = App::new();
+    app.add_plugins(SystemOrderPlugin);
+
+    // Registered in reverse so only the set ordering can put them right
+    app.add_systems(Update, (
+        collision_system.in_set(CombatSet::Collision),
+        projectile_spawning_system.in_set(CombatSet::Firing),
+        tower_targeting_system.in_set(CombatSet::Targeting),
+        enemy_movement_system.in_set(EnemySet::Movement),
+    ));
+
+    let schedules = app.world().resource::<Schedules>();
+    assert!(resolved_system_names(schedules.get(Update).unwrap()).is_none());
+
+    app.update();
+
+    let schedules = app.world().resource::<Schedules>();
+    let resolved = resolved_system_names(schedules.get(Update).unwrap()).unwrap();
+    let expected = [
+        "enemy_movement_system",
+        "tower_targeting_system",
+        "projectile_spawning_system",
+        "collision_system",
+    ];
+    assert!(find_order_violations(&resolved, &expected).is_empty(), "resolved order: {:?}", resolved);
+}
@@ -0,0 +1,100 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::results_screen::{RestartRunEvent, RestoreCheckpointEvent};
+use tower_defense_bevy::systems::threat_alert_system::*;
+use tower_defense_bevy::systems::ui_feedback::{UiCue, UiFeedbackEvent};
+
+fn alert_world(wave: u32) -> World {
+    let mut world = World::new();
+    world.insert_resource(WavePlan { current_wave: wave, ..default() });
+    world.insert_resource(WaveRuntime::new());
+    world.init_resource::<PathMilestoneState>();
+    world.init_resource::<Events<PathMilestoneEvent>>();
+    world.init_resource::<Events<UiFeedbackEvent>>();
+    world.init_resource::<Events<RestartRunEvent>>();
+    world.init_resource::<Events<RestoreCheckpointEvent>>();
+    world
+}
+
+fn spawn_enemy_at(world: &mut World, progress: f32) {
+    world.spawn((Enemy::for_wave(1), PathProgress { current: progress }));
+}
+
+fn milestones_sent(world: &World) -> Vec<PathMilestone> {
+    let events = world.resource::<Events<PathMilestoneEvent>>();
+    events.iter_current_update_events().map(|event| event.milestone).collect()
+}
+
+fn alert_cues(world: &World) -> usize {
+    let events = world.resource::<Events<UiFeedbackEvent>>();
+    events.iter_current_update_events().filter(|event| event.cue == UiCue::Alert).count()
+}
+
+#[test]
+fn test_milestones_fire_once_per_wave() {
+    let mut state = PathMilestoneState::default();
+
+    assert!(state.cross(1, 0.3).is_empty());
+    assert_eq!(state.cross(1, 0.55), vec![PathMilestone::Halfway]);
+    assert!(state.cross(1, 0.6).is_empty(), "halfway already reported this wave");
+    assert_eq!(state.cross(1, 0.85), vec![PathMilestone::NearBase]);
+    assert!(state.cross(1, 0.95).is_empty());
+
+    assert_eq!(state.cross(2, 0.5), vec![PathMilestone::Halfway], "a new wave resets the milestones");
+}
+
+#[test]
+fn test_jump_past_both_milestones() {
+    let mut state = PathMilestoneState::default();
+    assert_eq!(state.cross(3, 0.9), vec![PathMilestone::Halfway, PathMilestone::NearBase]);
+}
+
+#[test]
+fn test_monitor_follows_lead_enemy() {
+    let mut world = alert_world(2);
+    spawn_enemy_at(&mut world, 0.2);
+    spawn_enemy_at(&mut world, 0.6);
+
+    world.run_system_once(path_progress_monitor_system).unwrap();
+    assert_eq!(milestones_sent(&world), vec![PathMilestone::Halfway]);
+    assert_eq!(alert_cues(&world), 1);
+
+    world.resource_mut::<Events<PathMilestoneEvent>>().update();
+    world.resource_mut::<Events<UiFeedbackEvent>>().update();
+    world.run_system_once(path_progress_monitor_system).unwrap();
+    assert!(milestones_sent(&world).is_empty(), "no repeat while the lead enemy stays put");
+    assert_eq!(alert_cues(&world), 0);
+}
+
+#[test]
+fn test_simultaneous_milestones_share_one_cue() {
+    let mut world = alert_world(4);
+    spawn_enemy_at(&mut world, 0.9);
+
+    world.run_system_once(path_progress_monitor_system).unwrap();
+    assert_eq!(milestones_sent(&world), vec![PathMilestone::Halfway, PathMilestone::NearBase]);
+    assert_eq!(alert_cues(&world), 1);
+}
+
+#[test]
+fn test_restart_rearms_milestones() {
+    let mut world = alert_world(1);
+    spawn_enemy_at(&mut world, 0.6);
+    world.run_system_once(path_progress_monitor_system).unwrap();
+
+    world.resource_mut::<Events<PathMilestoneEvent>>().update();
+    world.send_event(RestartRunEvent { new_seed: false });
+    world.run_system_once(path_progress_monitor_system).unwrap();
+    assert_eq!(milestones_sent(&world), vec![PathMilestone::Halfway], "replayed wave alerts again");
+}
+
+#[test]
+fn test_alert_spawns_banner_and_flash() {
+    let mut world = alert_world(1);
+    world.send_event(PathMilestoneEvent { milestone: PathMilestone::NearBase, wave: 1 });
+
+    world.run_system_once(threat_alert_ui_system).unwrap();
+    let mut query = world.query_filtered::<(), With<ThreatAlertUi>>();
+    assert_eq!(query.iter(&world).count(), 2);
+}
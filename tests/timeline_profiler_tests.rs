@@ -0,0 +1,74 @@
+use tower_defense_bevy::systems::debug_ui::profiler::*;
+
+fn span(span: ProfiledSpan, start_us: f64, duration_us: f64) -> SpanTiming {
+    SpanTiming { span, start_us, duration_us }
+}
+
+#[test]
+fn test_spans_are_only_recorded_during_a_capture() {
+    let mut profiler = TimelineProfiler::default();
+    profiler.begin(ProfiledSpan::Collision);
+    profiler.end(ProfiledSpan::Collision);
+    assert!(!profiler.is_recording());
+    assert_eq!(profiler.status_text(), "idle (F10 to record)");
+
+    assert!(profiler.start());
+    assert!(!profiler.start(), "one capture at a time");
+    // A span still open from before the capture is dropped
+    profiler.end(ProfiledSpan::Frame);
+    profiler.begin(ProfiledSpan::Collision);
+    profiler.end(ProfiledSpan::Collision);
+
+    let capture = profiler.capture.as_ref().unwrap();
+    assert_eq!(capture.spans.len(), 1);
+    assert_eq!(capture.spans[0].span, ProfiledSpan::Collision);
+    assert!(capture.spans[0].duration_us >= 0.0);
+    assert!(profiler.status_text().starts_with("recording"));
+}
+
+#[test]
+fn test_chrome_trace_has_complete_counter_and_row_name_events() {
+    let spans = [span(ProfiledSpan::Frame, 0.0, 16_000.0), span(ProfiledSpan::Spawning, 120.0, 35.5)];
+    let samples = [MetricSample { time_us: 16_000.0, fps: 60.0, entity_count: 420, live_enemies: 12 }];
+    let trace = chrome_trace(&spans, &samples);
+
+    let events = trace["traceEvents"].as_array().unwrap();
+    assert_eq!(events.len(), ProfiledSpan::ALL.len() + spans.len() + samples.len());
+    assert_eq!(trace["displayTimeUnit"], "ms");
+
+    let names: Vec<&serde_json::Value> = events.iter().filter(|event| event["ph"] == "M").collect();
+    assert_eq!(names.len(), ProfiledSpan::ALL.len());
+
+    let spawning = events.iter().find(|event| event["name"] == "enemy_spawning").unwrap();
+    assert_eq!(spawning["ph"], "X");
+    assert_eq!(spawning["cat"], "enemy");
+    assert_eq!(spawning["ts"], 120.0);
+    assert_eq!(spawning["dur"], 35.5);
+    assert_eq!(spawning["tid"], ProfiledSpan::Spawning.track());
+
+    let counter = events.iter().find(|event| event["ph"] == "C").unwrap();
+    assert_eq!(counter["args"]["entities"], 420);
+    assert_eq!(counter["args"]["enemies"], 12);
+}
+
+#[test]
+fn test_every_span_has_its_own_row() {
+    let mut tracks: Vec<usize> = ProfiledSpan::ALL.iter().map(|span| span.track()).collect();
+    tracks.dedup();
+    assert_eq!(tracks.len(), ProfiledSpan::ALL.len());
+}
+
+#[test]
+fn test_export_writes_a_loadable_trace() {
+    let mut profiler = TimelineProfiler::default();
+    profiler.start();
+    profiler.begin(ProfiledSpan::UI);
+    profiler.end(ProfiledSpan::UI);
+
+    let dir = std::env::temp_dir().join("td_timeline_profiler_test");
+    let path = export_chrome_trace(&dir, profiler.capture.as_ref().unwrap()).unwrap();
+    let contents = std::fs::read_to_string(&path).unwrap();
+    let trace: serde_json::Value = serde_json::from_str(&contents).unwrap();
+    assert!(trace["traceEvents"].as_array().unwrap().iter().any(|event| event["name"] == "ui"));
+    std::fs::remove_file(path).unwrap();
+}
@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use std::f32::consts::FRAC_PI_2;
+use std::time::Duration;
+use tower_defense_bevy::components::Enemy;
+use tower_defense_bevy::resources::{TowerStats, TowerType};
+use tower_defense_bevy::systems::combat_system::{projectile_spawning_system, Target, TowerFiredEvent};
+use tower_defense_bevy::systems::tower_animation::*;
+use tower_defense_bevy::systems::tower_rendering::TowerVisualPart;
+
+#[test]
+fn test_pose_turns_the_short_way_round() {
+    let mut pose = TowerPose { facing: 3.0, ..default() };
+    pose.fire(Vec2::from_angle(-3.0));
+    pose.update(0.01);
+    assert!(pose.facing > 3.0, "crosses PI rather than swinging back through zero");
+
+    pose.update(1.0);
+    assert!((Vec2::from_angle(pose.facing) - Vec2::from_angle(-3.0)).length() < 1e-4);
+    assert!(!pose.is_animating());
+}
+
+#[test]
+fn test_pulse_pops_and_settles() {
+    let mut pose = TowerPose::default();
+    pose.fire(Vec2::X);
+    assert!((pose.scale() - FIRING_PULSE_SCALE).abs() < 1e-5);
+    pose.update(FIRING_PULSE_DURATION / 2.0);
+    assert!(pose.scale() > 1.0 && pose.scale() < FIRING_PULSE_SCALE);
+    pose.update(FIRING_PULSE_DURATION);
+    assert_eq!(pose.scale(), 1.0);
+}
+
+#[test]
+fn test_firing_sends_an_event() {
+    let mut world = World::new();
+    let mut time = Time::<()>::default();
+    time.advance_by(Duration::from_secs(10));
+    world.insert_resource(time);
+    world.init_resource::<Events<TowerFiredEvent>>();
+    let enemy = world.spawn((Enemy::default(), Transform::from_xyz(0.0, 100.0, 0.0))).id();
+    let tower = world
+        .spawn((TowerStats::new(TowerType::Basic), Transform::default(), Target { entity: Some(enemy), last_shot_time: 0.0 }))
+        .id();
+
+    world.run_system_once(projectile_spawning_system).unwrap();
+    let events: Vec<TowerFiredEvent> = world.resource_mut::<Events<TowerFiredEvent>>().drain().collect();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].tower, tower);
+    assert_eq!(events[0].target, Vec2::new(0.0, 100.0));
+}
+
+#[test]
+fn test_tower_parts_face_the_target_and_flash() {
+    let mut world = World::new();
+    world.init_resource::<Time>();
+    world.init_resource::<Events<TowerFiredEvent>>();
+    let tower = world.spawn((Transform::from_xyz(100.0, 0.0, 0.0), Visibility::Hidden)).id();
+    let barrel = world
+        .spawn((Transform::from_xyz(112.0, 0.0, 0.2), TowerVisualPart { parent_tower: tower }))
+        .id();
+
+    world.send_event(TowerFiredEvent { tower, muzzle: Vec2::new(100.0, 12.0), target: Vec2::new(100.0, 200.0) });
+    world.run_system_once(tower_fired_system).unwrap();
+    world.resource_mut::<Time>().advance_by(Duration::from_secs(1));
+    world.run_system_once(tower_animation_system).unwrap();
+    world.run_system_once(tower_animation_system).unwrap();
+
+    let pose = *world.get::<TowerPose>(tower).unwrap();
+    assert!((pose.facing - FRAC_PI_2).abs() < 1e-5, "turned to face straight up");
+    let barrel_transform = world.get::<Transform>(barrel).unwrap();
+    assert!(barrel_transform.translation.truncate().distance(Vec2::new(100.0, 12.0)) < 1e-3, "barrel swings round the centre");
+    assert_eq!(barrel_transform.translation.z, 0.2);
+
+    let mut flashes = world.query_filtered::<(&ChildOf, &Visibility), With<MuzzleFlash>>();
+    let (parent, visibility) = flashes.single(&world).unwrap();
+    assert_eq!(parent.parent(), tower);
+    assert_eq!(*visibility, Visibility::Visible, "shown despite the hidden tower base");
+}
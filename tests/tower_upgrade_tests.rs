@@ -1,5 +1,6 @@
 use tower_defense_bevy::components::*;
 use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::tower_ui::{try_upgrade_tower, UpgradeRefusal};
 
 // ============================================================================
 // COMPONENT TESTS - Test upgrade level and stat progression
@@ -168,7 +169,7 @@ fn test_upgrade_cost_different_levels() {
 
 #[test]
 fn test_can_afford_basic_tower_upgrade() {
-    let mut economy = Economy::new(100, 10, 10, 50);
+    let economy = Economy::new(100, 10, 10, 50);
     let tower = TowerStats::new(TowerType::Basic);
     let upgrade_cost = tower.get_upgrade_cost();
     
@@ -177,7 +178,7 @@ fn test_can_afford_basic_tower_upgrade() {
 
 #[test]
 fn test_cannot_afford_expensive_upgrade() {
-    let mut economy = Economy::new(10, 0, 0, 5);
+    let economy = Economy::new(10, 0, 0, 5);
     let tower = TowerStats::new(TowerType::Tesla);
     let upgrade_cost = tower.get_upgrade_cost();
     
@@ -288,4 +289,102 @@ fn test_tower_type_upgrade_balance() {
         assert!(improvement_ratio > 1.15, "Tower type {:?} upgrade too weak", tower_type);
         assert!(improvement_ratio < 2.50, "Tower type {:?} upgrade too strong", tower_type); // Adjusted for current system balance
     }
-}
\ No newline at end of file
+}
+// ============================================================================
+// BRANCH TESTS - Specializations chosen at BRANCH_LEVEL
+// ============================================================================
+
+fn tower_at_level(tower_type: TowerType, level: u32) -> TowerStats {
+    let mut tower = TowerStats::new(tower_type);
+    while tower.upgrade_level < level {
+        tower.upgrade();
+    }
+    tower
+}
+
+#[test]
+fn test_specialization_opens_at_branch_level() {
+    let mut tower = tower_at_level(TowerType::Basic, BRANCH_LEVEL - 1);
+    assert!(!tower.can_specialize());
+    assert!(!tower.specialize(UpgradeBranch::Sniper));
+
+    tower.upgrade();
+    assert!(tower.can_specialize());
+    assert!(!tower.specialize(UpgradeBranch::Storm), "Storm belongs to Tesla");
+    assert!(tower.specialize(UpgradeBranch::Sniper));
+    assert!(!tower.can_specialize(), "the choice is permanent");
+    assert!(!tower.specialize(UpgradeBranch::Rapid));
+    assert_eq!(tower.branch, Some(UpgradeBranch::Sniper));
+}
+
+#[test]
+fn test_branches_pull_stats_in_different_directions() {
+    let base = tower_at_level(TowerType::Basic, BRANCH_LEVEL);
+    let mut sniper = base.clone();
+    sniper.specialize(UpgradeBranch::Sniper);
+    let mut rapid = base.clone();
+    rapid.specialize(UpgradeBranch::Rapid);
+
+    assert!(sniper.range > base.range && sniper.damage > base.damage && sniper.fire_rate < base.fire_rate);
+    assert!(rapid.fire_rate > base.fire_rate && rapid.damage < base.damage);
+}
+
+#[test]
+fn test_branches_change_tower_behavior() {
+    let mut hunter = tower_at_level(TowerType::Missile, BRANCH_LEVEL);
+    hunter.specialize(UpgradeBranch::Hunter);
+    assert_eq!(hunter.splash_radius, 0.0, "hunter warheads hit one target");
+
+    let mut overload = tower_at_level(TowerType::Tesla, BRANCH_LEVEL);
+    overload.specialize(UpgradeBranch::Overload);
+    assert_eq!(overload.chain_targets, 0);
+    assert!(overload.slow > 0.0);
+
+    let mut lens = tower_at_level(TowerType::Laser, BRANCH_LEVEL);
+    lens.specialize(UpgradeBranch::Lens);
+    assert_eq!(lens.burn_dps, 0.0);
+}
+
+#[test]
+fn test_specialization_survives_further_upgrades() {
+    let mut storm = tower_at_level(TowerType::Tesla, BRANCH_LEVEL);
+    let unbranched_chain = storm.chain_targets;
+    storm.specialize(UpgradeBranch::Storm);
+    assert_eq!(storm.chain_targets, unbranched_chain + 2);
+
+    storm.upgrade();
+    let unbranched = tower_at_level(TowerType::Tesla, BRANCH_LEVEL + 1);
+    assert_eq!(storm.chain_targets, unbranched.chain_targets + 2);
+    assert_eq!(storm.branch, Some(UpgradeBranch::Storm));
+}
+
+#[test]
+fn test_every_tower_type_has_two_distinct_branches() {
+    for tower_type in TowerType::ALL {
+        let path = UpgradePath::for_tower(tower_type);
+        assert_ne!(path.branches[0], path.branches[1]);
+        for branch in path.branches {
+            let mut tower = tower_at_level(tower_type, BRANCH_LEVEL);
+            assert!(tower.specialize(branch), "{:?} should offer {:?}", tower_type, branch);
+        }
+    }
+}
+
+#[test]
+fn test_try_upgrade_tower_pays_or_says_why_not() {
+    let mut tower = TowerStats::new(TowerType::Basic);
+    let cost = tower.get_upgrade_cost();
+
+    let mut broke = Economy::new(cost.money - 1, 100, 100, 100);
+    assert_eq!(try_upgrade_tower(&mut tower, false, &mut broke), Err(UpgradeRefusal::CannotAfford));
+    assert_eq!(try_upgrade_tower(&mut tower, true, &mut broke), Err(UpgradeRefusal::UnderConstruction));
+    assert_eq!(tower.upgrade_level, 1);
+
+    let mut economy = Economy::new(cost.money, 100, 100, 100);
+    assert_eq!(try_upgrade_tower(&mut tower, false, &mut economy), Ok(()));
+    assert_eq!(tower.upgrade_level, 2);
+    assert_eq!(economy.money, 0);
+
+    let mut maxed = tower_at_level(TowerType::Basic, 5);
+    assert_eq!(try_upgrade_tower(&mut maxed, false, &mut economy), Err(UpgradeRefusal::MaxLevel));
+}
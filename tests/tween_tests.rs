@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use std::time::Duration;
+use tower_defense_bevy::systems::tween::*;
+
+fn create_tween_world() -> World {
+    let mut world = World::new();
+    world.insert_resource(Time::<()>::default());
+    world.init_resource::<Events<TweenCompleted>>();
+    world
+}
+
+#[test]
+fn test_easing_endpoints() {
+    let curves = [
+        Easing::Linear,
+        Easing::QuadIn,
+        Easing::QuadOut,
+        Easing::QuadInOut,
+        Easing::CubicIn,
+        Easing::CubicOut,
+        Easing::CubicInOut,
+        Easing::SmoothStep,
+        Easing::BackOut,
+    ];
+
+    for easing in curves {
+        assert!(easing.apply(0.0).abs() < 0.0001, "{:?} should start at 0", easing);
+        assert!((easing.apply(1.0) - 1.0).abs() < 0.0001, "{:?} should end at 1", easing);
+        // Out-of-range input is clamped
+        assert_eq!(easing.apply(-1.0), easing.apply(0.0));
+        assert_eq!(easing.apply(2.0), easing.apply(1.0));
+    }
+
+    assert!(Easing::QuadOut.apply(0.5) > Easing::Linear.apply(0.5));
+    assert!(Easing::QuadIn.apply(0.5) < Easing::Linear.apply(0.5));
+}
+
+#[test]
+fn test_tween_progress_respects_delay() {
+    let mut progress = TweenProgress::new(1.0, Easing::Linear).with_delay(0.5);
+
+    progress.tick(0.5);
+    assert_eq!(progress.fraction(), 0.0);
+    assert!(!progress.is_finished());
+
+    progress.tick(0.5);
+    assert!((progress.fraction() - 0.5).abs() < 0.0001);
+
+    progress.tick(10.0);
+    assert_eq!(progress.fraction(), 1.0);
+    assert!(progress.is_finished());
+}
+
+#[test]
+fn test_translation_tween_moves_and_removes_itself() {
+    let mut world = create_tween_world();
+    let entity = world.spawn((
+        Transform::default(),
+        TranslationTween::new(Vec3::ZERO, Vec3::new(100.0, 0.0, 0.0), TweenProgress::new(1.0, Easing::Linear)),
+    )).id();
+
+    world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(0.5));
+    let _ = world.run_system_once(translation_tween_system);
+    let x = world.get::<Transform>(entity).unwrap().translation.x;
+    assert!((x - 50.0).abs() < 0.01, "Expected half way, got {}", x);
+
+    world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(0.6));
+    let _ = world.run_system_once(translation_tween_system);
+    assert_eq!(world.get::<Transform>(entity).unwrap().translation.x, 100.0);
+    assert!(world.get::<TranslationTween>(entity).is_none(), "Finished tween should be removed");
+
+    let completed: Vec<TweenCompleted> = world.resource_mut::<Events<TweenCompleted>>().drain().collect();
+    assert_eq!(completed.len(), 1);
+    assert_eq!(completed[0].entity, entity);
+    assert_eq!(completed[0].kind, TweenKind::Translation);
+}
+
+#[test]
+fn test_alpha_tween_can_despawn_on_complete() {
+    let mut world = create_tween_world();
+    let entity = world.spawn((
+        Sprite::default(),
+        AlphaTween::new(1.0, 0.0, TweenProgress::new(0.2, Easing::Linear).despawn_on_complete()),
+    )).id();
+
+    world.resource_mut::<Time>().advance_by(Duration::from_secs_f32(0.5));
+    let _ = world.run_system_once(alpha_tween_system);
+
+    assert!(world.get_entity(entity).is_err(), "Faded entity should be despawned");
+}
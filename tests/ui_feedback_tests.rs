@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+use bevy::input::gamepad::{Gamepad, GamepadRumbleRequest};
+use tower_defense_bevy::systems::settings_menu::GameSettings;
+use tower_defense_bevy::systems::ui_feedback::*;
+
+fn feedback_world() -> World {
+    let mut world = World::new();
+    world.init_resource::<Events<UiFeedbackEvent>>();
+    world.init_resource::<Events<GamepadRumbleRequest>>();
+    world
+}
+
+fn drain_cues(world: &mut World) -> Vec<UiCue> {
+    world
+        .resource_mut::<Events<UiFeedbackEvent>>()
+        .drain()
+        .map(|event| event.cue)
+        .collect()
+}
+
+fn rumble_count(world: &mut World) -> usize {
+    world.resource_mut::<Events<GamepadRumbleRequest>>().drain().count()
+}
+
+#[test]
+fn test_cue_mapping() {
+    assert!(UiCue::Hover.rumble().is_none(), "hovering should never rumble");
+    for cue in [UiCue::Click, UiCue::Confirm, UiCue::Error] {
+        assert!(cue.rumble().is_some());
+    }
+    assert!(UiCue::Hover.volume() < UiCue::Click.volume());
+
+    let paths: Vec<&str> = UiCue::ALL.iter().map(|cue| cue.sound_path()).collect();
+    for (index, path) in paths.iter().enumerate() {
+        assert!(!paths[index + 1..].contains(path), "each cue has its own sound");
+    }
+}
+
+#[test]
+fn test_buttons_report_hover_and_click() {
+    let mut world = feedback_world();
+    let mut schedule = Schedule::default();
+    schedule.add_systems(button_feedback_system);
+
+    let button = world.spawn((Button, Interaction::Hovered)).id();
+    world.spawn((Button, Interaction::Hovered, NoUiFeedback));
+    schedule.run(&mut world);
+    assert_eq!(drain_cues(&mut world), vec![UiCue::Hover]);
+
+    *world.get_mut::<Interaction>(button).unwrap() = Interaction::Pressed;
+    schedule.run(&mut world);
+    assert_eq!(drain_cues(&mut world), vec![UiCue::Click]);
+
+    // Releasing the click is not a fresh hover
+    *world.get_mut::<Interaction>(button).unwrap() = Interaction::Hovered;
+    schedule.run(&mut world);
+    assert!(drain_cues(&mut world).is_empty());
+
+    *world.get_mut::<Interaction>(button).unwrap() = Interaction::None;
+    schedule.run(&mut world);
+    *world.get_mut::<Interaction>(button).unwrap() = Interaction::Hovered;
+    schedule.run(&mut world);
+    assert_eq!(drain_cues(&mut world), vec![UiCue::Hover]);
+}
+
+#[test]
+fn test_rumble_follows_settings() {
+    let mut world = feedback_world();
+    world.insert_resource(GameSettings::default());
+    world.spawn(Gamepad::default());
+    let mut schedule = Schedule::default();
+    schedule.add_systems(ui_feedback_system);
+
+    // Repeated cues in one frame rumble once; hover doesn't rumble at all
+    for cue in [UiCue::Error, UiCue::Error, UiCue::Hover] {
+        world.send_event(UiFeedbackEvent { cue });
+    }
+    schedule.run(&mut world);
+    assert_eq!(rumble_count(&mut world), 1);
+
+    world.resource_mut::<GameSettings>().ui_haptics_enabled = false;
+    world.send_event(UiFeedbackEvent { cue: UiCue::Click });
+    schedule.run(&mut world);
+    assert_eq!(rumble_count(&mut world), 0);
+}
+
+#[test]
+fn test_feedback_toggles() {
+    use tower_defense_bevy::systems::settings_menu::FeedbackToggle;
+
+    let mut settings = GameSettings::default();
+    assert!(settings.feedback_enabled(FeedbackToggle::UiSounds));
+    assert!(settings.feedback_enabled(FeedbackToggle::Haptics));
+
+    settings.toggle_feedback(FeedbackToggle::UiSounds);
+    assert!(!settings.ui_sounds_enabled);
+    assert!(settings.ui_haptics_enabled);
+}
@@ -0,0 +1,136 @@
+//! End-to-end UI flows driven by synthetic mouse and keyboard input
+
+mod common;
+
+use bevy::prelude::*;
+use common::{RecordedInput, UiTestApp};
+use tower_defense_bevy::components::{Constructing, Enemy};
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::pause_system::{PauseButton, PauseMenuAction, PauseMenuOverlay};
+use tower_defense_bevy::systems::tower_ui::{StartWaveButton, TowerSelectionState, TowerTypeButton, UpgradeButton};
+use tower_defense_bevy::systems::unified_grid::{snap_to_grid, UnifiedGridSystem};
+
+/// A free cell above the test path, which runs along y = 0
+const BUILD_SPOT: Vec2 = Vec2::new(-120.0, 120.0);
+
+fn place_basic_tower(ui: &mut UiTestApp) -> Entity {
+    ui.click_button_where::<TowerTypeButton>(|button| button.tower_type == TowerType::Basic);
+    ui.click_world(BUILD_SPOT);
+    let towers = ui.towers();
+    assert_eq!(towers.len(), 1, "clicking a free cell places exactly one tower");
+    towers[0].0
+}
+
+fn overlay_visibility(ui: &mut UiTestApp) -> Visibility {
+    let world = ui.app.world_mut();
+    *world
+        .query_filtered::<&Visibility, With<PauseMenuOverlay>>()
+        .single(world)
+        .unwrap()
+}
+
+#[test]
+fn test_placement_flow() {
+    let mut ui = UiTestApp::new();
+    let starting_money = ui.app.world().resource::<Economy>().money;
+
+    ui.click_button_where::<TowerTypeButton>(|button| button.tower_type == TowerType::Basic);
+    assert_eq!(
+        ui.app.world().resource::<TowerSelectionState>().selected_placement_type,
+        Some(TowerType::Basic)
+    );
+    assert!(ui.towers().is_empty(), "selecting a tower type must not place one");
+
+    // The same clicks, replayed from a recording
+    ui.play(&[
+        RecordedInput::CursorTo(BUILD_SPOT),
+        RecordedInput::MousePress(MouseButton::Left),
+        RecordedInput::MouseRelease(MouseButton::Left),
+    ]);
+
+    let towers = ui.towers();
+    assert_eq!(towers.len(), 1);
+    let (tower, position, tower_type) = towers[0];
+    assert_eq!(tower_type, TowerType::Basic);
+    assert_eq!(position, snap_to_grid(BUILD_SPOT, &UnifiedGridSystem::default()));
+    assert!(ui.app.world().get::<Constructing>(tower).is_some(), "new towers start under construction");
+    assert_eq!(ui.app.world().resource::<Economy>().money, starting_money - 40);
+
+    // Starting funds hold no research points, so an Advanced tower is refused and costs nothing
+    ui.click_button_where::<TowerTypeButton>(|button| button.tower_type == TowerType::Advanced);
+    ui.click_world(Vec2::new(200.0, 120.0));
+    assert_eq!(ui.towers().len(), 1);
+    assert_eq!(ui.app.world().resource::<Economy>().money, starting_money - 40);
+}
+
+#[test]
+fn test_upgrade_flow() {
+    let mut ui = UiTestApp::new();
+    let tower = place_basic_tower(&mut ui);
+    let tower_position = ui.towers()[0].1;
+
+    // Basic towers take two seconds to build
+    ui.play(&[RecordedInput::Wait(50)]);
+    assert!(ui.app.world().get::<Constructing>(tower).is_none());
+
+    ui.click_world(tower_position);
+    let selection = ui.app.world().resource::<TowerSelectionState>();
+    assert!(selection.is_upgrade_mode());
+    assert_eq!(selection.selected_tower_entity, Some(tower));
+
+    let money_before = ui.app.world().resource::<Economy>().money;
+    ui.click_button::<UpgradeButton>();
+    assert_eq!(ui.app.world().get::<TowerStats>(tower).unwrap().upgrade_level, 2);
+    assert_eq!(ui.app.world().resource::<Economy>().money, money_before - 20);
+
+    // Right-click drops the selection
+    ui.play(&[
+        RecordedInput::CursorTo(Vec2::new(300.0, -200.0)),
+        RecordedInput::MousePress(MouseButton::Right),
+        RecordedInput::MouseRelease(MouseButton::Right),
+    ]);
+    assert_eq!(ui.app.world().resource::<TowerSelectionState>().selected_tower_entity, None);
+}
+
+#[test]
+fn test_pause_menu_flow() {
+    let mut ui = UiTestApp::new();
+    ui.click_button_where::<TowerTypeButton>(|button| button.tower_type == TowerType::Basic);
+    assert_eq!(overlay_visibility(&mut ui), Visibility::Hidden);
+
+    ui.tap_key(KeyCode::Escape);
+    assert_eq!(ui.app_state(), AppState::Paused);
+    assert_eq!(overlay_visibility(&mut ui), Visibility::Visible);
+
+    // Gameplay input is ignored while paused
+    ui.click_world(BUILD_SPOT);
+    assert!(ui.towers().is_empty());
+
+    ui.click_button_where::<PauseButton>(|button| matches!(button.action, PauseMenuAction::Resume));
+    assert_eq!(ui.app_state(), AppState::Playing);
+    assert_eq!(overlay_visibility(&mut ui), Visibility::Hidden);
+
+    // Escape pauses again, and a second press resumes
+    ui.tap_key(KeyCode::Escape);
+    assert_eq!(ui.app_state(), AppState::Paused);
+    ui.tap_key(KeyCode::Escape);
+    assert_eq!(ui.app_state(), AppState::Playing);
+
+    ui.click_world(BUILD_SPOT);
+    assert_eq!(ui.towers().len(), 1, "placement works again after resuming");
+}
+
+#[test]
+fn test_start_wave_button_starts_wave() {
+    let mut ui = UiTestApp::new();
+    assert_eq!(ui.app.world().resource::<WavePlan>().current_wave, 0);
+
+    ui.click_button::<StartWaveButton>();
+    assert_eq!(ui.app.world().resource::<WavePlan>().current_wave, 1);
+    assert!(ui.towers().is_empty(), "the button click must not fall through to placement");
+
+    ui.frames(40);
+    let world = ui.app.world_mut();
+    let enemies = world.query_filtered::<(), With<Enemy>>().iter(world).count();
+    assert!(enemies > 0, "the started wave spawns enemies");
+}
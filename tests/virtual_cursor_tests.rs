@@ -0,0 +1,120 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::input_system::{get_placement_position, MouseInputState, PlacementMode};
+use tower_defense_bevy::systems::path_generation::grid::GridPos;
+use tower_defense_bevy::systems::tower_ui::TowerSelectionState;
+use tower_defense_bevy::systems::unified_grid::{grid_to_world, world_to_grid, UnifiedGridSystem};
+use tower_defense_bevy::systems::virtual_cursor::*;
+
+fn cursor_world() -> World {
+    let mut world = World::new();
+    world.init_resource::<Time>();
+    world.init_resource::<ButtonInput<KeyCode>>();
+    world.init_resource::<VirtualCursor>();
+    world.init_resource::<MouseInputState>();
+    world.init_resource::<TowerSelectionState>();
+    world.init_resource::<UnifiedGridSystem>();
+    world
+}
+
+/// Tap `keys` for one run of the cursor system
+fn tap(world: &mut World, keys: &[KeyCode]) {
+    {
+        let mut keyboard = world.resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.clear();
+        keyboard.release_all();
+        for key in keys {
+            keyboard.press(*key);
+        }
+    }
+    {
+        // Clicks last one frame, as `mouse_input_system` clears them each frame
+        let mut mouse = world.resource_mut::<MouseInputState>();
+        mouse.left_clicked = false;
+        mouse.right_clicked = false;
+        mouse.left_released = false;
+    }
+    world.run_system_once(virtual_cursor_system).unwrap();
+}
+
+fn cell_under_cursor(world: &World) -> Option<GridPos> {
+    world_to_grid(world.resource::<MouseInputState>().world_position, world.resource::<UnifiedGridSystem>())
+}
+
+#[test]
+fn test_cursor_steps_cell_by_cell_and_stays_on_the_grid() {
+    let mut cursor = VirtualCursor::default();
+    cursor.take_over(CursorSource::Keyboard, GridPos::new(1, 1), Vec2::ZERO);
+    cursor.step(IVec2::new(1, -1), 4, 3);
+    assert_eq!(cursor.cell, GridPos::new(2, 0));
+
+    cursor.step(IVec2::new(5, -5), 4, 3);
+    assert_eq!(cursor.cell, GridPos::new(3, 0), "clamped to the last column and first row");
+}
+
+#[test]
+fn test_cursor_point_snaps_onto_its_own_cell() {
+    let grid = UnifiedGridSystem::default();
+    for cell in [GridPos::new(0, 0), GridPos::new(13, 12), GridPos::new(grid.grid_width - 1, grid.grid_height - 1)] {
+        let point = cursor_world_position(cell, &grid);
+        assert_eq!(world_to_grid(point, &grid), Some(cell));
+        assert_eq!(get_placement_position(point, PlacementMode::Hybrid, &grid), grid_to_world(cell, &grid));
+    }
+}
+
+#[test]
+fn test_tower_type_cycles_in_both_directions() {
+    assert_eq!(cycle_tower_type(None, 1), TowerType::ALL[0]);
+    assert_eq!(cycle_tower_type(None, -1), TowerType::ALL[TowerType::ALL.len() - 1]);
+    assert_eq!(cycle_tower_type(Some(TowerType::ALL[0]), 1), TowerType::ALL[1]);
+    assert_eq!(cycle_tower_type(Some(TowerType::ALL[0]), -1), TowerType::ALL[TowerType::ALL.len() - 1]);
+}
+
+#[test]
+fn test_keyboard_mode_moves_the_placement_point_and_places_with_enter() {
+    let mut world = cursor_world();
+    let start = GridPos::new(10, 5);
+    world.resource_mut::<MouseInputState>().world_position =
+        cursor_world_position(start, world.resource::<UnifiedGridSystem>());
+
+    tap(&mut world, &[KeyCode::KeyG]);
+    assert_eq!(world.resource::<VirtualCursor>().source, CursorSource::Keyboard);
+    assert_eq!(world.resource::<VirtualCursor>().cell, start, "the cursor starts under the mouse");
+
+    tap(&mut world, &[KeyCode::KeyD]);
+    tap(&mut world, &[KeyCode::ArrowUp]);
+    assert_eq!(cell_under_cursor(&world), Some(GridPos::new(11, 6)));
+
+    tap(&mut world, &[KeyCode::KeyE]);
+    let selection = world.resource::<TowerSelectionState>();
+    assert!(selection.is_placement_mode());
+    assert_eq!(selection.selected_placement_type, Some(TowerType::ALL[0]));
+    assert!(!world.resource::<MouseInputState>().left_clicked);
+
+    tap(&mut world, &[KeyCode::Enter]);
+    let mouse = world.resource::<MouseInputState>();
+    assert!(mouse.left_clicked);
+    assert_eq!(mouse.drag_rect().map(|rect| rect.size()), Some(Vec2::ZERO), "a tap, not a drag");
+
+    tap(&mut world, &[KeyCode::Backspace]);
+    assert_eq!(world.resource::<TowerSelectionState>().selected_placement_type, None);
+}
+
+#[test]
+fn test_moving_the_mouse_hands_control_back() {
+    let mut world = cursor_world();
+    tap(&mut world, &[KeyCode::KeyG]);
+    let cell = world.resource::<VirtualCursor>().cell;
+    assert_eq!(cell_under_cursor(&world), Some(cell));
+
+    world.resource_mut::<MouseInputState>().current_position = Vec2::new(300.0, 200.0);
+    tap(&mut world, &[KeyCode::KeyD]);
+    assert_eq!(world.resource::<VirtualCursor>().source, CursorSource::Mouse);
+    assert_eq!(cell_under_cursor(&world), Some(cell), "arrows no longer move the placement point");
+
+    // G toggles keyboard mode off again
+    tap(&mut world, &[KeyCode::KeyG]);
+    tap(&mut world, &[KeyCode::KeyG]);
+    assert_eq!(world.resource::<VirtualCursor>().source, CursorSource::Mouse);
+}
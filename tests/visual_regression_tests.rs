@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+use std::path::PathBuf;
+use tower_defense_bevy::systems::settings_menu::ResolutionOption;
+use tower_defense_bevy::systems::visual_regression::*;
+
+fn solid_frame(size: UVec2, color: [u8; 4]) -> Frame {
+    Frame::new(size, color.repeat((size.x * size.y) as usize))
+}
+
+#[test]
+fn test_plan_covers_every_screen_at_every_resolution() {
+    let plan = capture_plan();
+    assert_eq!(plan.len(), VisualScreen::ALL.len() * CAPTURE_RESOLUTIONS.len());
+    for screen in VisualScreen::ALL {
+        for resolution in CAPTURE_RESOLUTIONS {
+            assert!(plan.contains(&CaptureShot { screen, resolution }));
+        }
+    }
+
+    let shot = CaptureShot { screen: VisualScreen::UpgradePanel, resolution: ResolutionOption::Res1280x720 };
+    assert_eq!(shot.file_name(), "upgrade_panel_1280x720.png");
+}
+
+#[test]
+fn test_small_channel_noise_stays_within_tolerance() {
+    let size = UVec2::new(20, 10);
+    let baseline = solid_frame(size, [40, 40, 40, 255]);
+    let tolerance = DiffTolerance::default();
+
+    let noisy = solid_frame(size, [44, 36, 40, 255]);
+    let diff = compare_frames(&baseline, &noisy, &tolerance).unwrap();
+    assert_eq!(diff.mismatched_pixels, 0);
+    assert_eq!(diff.max_channel_delta, 4);
+    assert!(diff.within(&tolerance));
+}
+
+#[test]
+fn test_moved_panel_is_reported_as_a_regression() {
+    let size = UVec2::new(20, 10);
+    let baseline = solid_frame(size, [0, 0, 0, 255]);
+    let mut shifted = baseline.clone();
+    // A 4x2 block of panel pixels where the baseline had background
+    for y in 0..2 {
+        for x in 0..4 {
+            let offset = ((y * size.x + x) * 4) as usize;
+            shifted.rgba[offset..offset + 4].copy_from_slice(&[200, 120, 40, 255]);
+        }
+    }
+
+    let tolerance = DiffTolerance::default();
+    let diff = compare_frames(&baseline, &shifted, &tolerance).unwrap();
+    assert_eq!(diff.mismatched_pixels, 8);
+    assert!((diff.mismatched_share() - 0.04).abs() < 1e-6);
+    assert!(!diff.within(&tolerance));
+    assert!(diff.within(&DiffTolerance { channel_delta: 8, max_mismatched_share: 0.05 }));
+
+    let resized = solid_frame(UVec2::new(10, 10), [0, 0, 0, 255]);
+    assert!(compare_frames(&baseline, &resized, &tolerance).is_err(), "sizes must match");
+}
+
+#[test]
+fn test_only_partly_overlapping_panels_are_flagged() {
+    let panels = vec![
+        ("backdrop".to_string(), Rect::new(0.0, 0.0, 1280.0, 720.0)),
+        ("placement".to_string(), Rect::new(0.0, 620.0, 600.0, 720.0)),
+        ("upgrade".to_string(), Rect::new(500.0, 600.0, 800.0, 720.0)),
+        ("minimap".to_string(), Rect::new(1100.0, 0.0, 1280.0, 180.0)),
+        // Touching edges is not an overlap
+        ("status".to_string(), Rect::new(900.0, 0.0, 1100.0, 40.0)),
+    ];
+
+    assert_eq!(
+        overlapping_panels(&panels),
+        vec![("placement".to_string(), "upgrade".to_string())]
+    );
+}
+
+#[test]
+fn test_capture_without_a_baseline_fails_unless_updating() {
+    let mut capture = VisualCapture::new(PathBuf::from("target/visual"), false);
+    capture.baseline_dir = PathBuf::from("tests/visual_baselines/does-not-exist");
+    let shot = capture.current_shot().unwrap();
+
+    let frame = solid_frame(UVec2::new(2, 2), [0, 0, 0, 255]);
+    let outcome = capture.judge(&shot, &frame);
+    assert!(outcome.is_failure(), "a missing baseline must not pass: {:?}", outcome);
+    assert!(matches!(&outcome, ShotOutcome::Failed(e) if e.contains(VISUAL_UPDATE_ENV)));
+
+    capture.update_baselines = true;
+    assert_eq!(capture.judge(&shot, &frame), ShotOutcome::NewBaseline);
+
+    capture.outcomes.push((shot.clone(), ShotOutcome::NewBaseline));
+    assert!(!capture.has_failures());
+    capture.overlaps.push((shot, "a".to_string(), "b".to_string()));
+    assert!(capture.has_failures());
+    assert!(capture.report().contains("OVERLAP between a and b"));
+}
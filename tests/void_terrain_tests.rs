@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::components::*;
+use tower_defense_bevy::resources::TowerType;
+use tower_defense_bevy::systems::obstacle_rendering::ObstacleGrid;
+use tower_defense_bevy::systems::path_generation::*;
+use tower_defense_bevy::systems::placement_validator::{terrain_verdict, PlacementVerdict};
+use tower_defense_bevy::systems::void_terrain_system::*;
+
+/// 10x5 grid with a void wall at x = 5 that only leaves the top row open
+fn lake_grid() -> PathGrid {
+    let mut grid = PathGrid::new(10, 5);
+    grid.entry_point = GridPos::new(0, 0);
+    grid.exit_point = GridPos::new(9, 0);
+    for y in 0..4 {
+        grid.set_cell(GridPos::new(5, y), CellType::Void);
+    }
+    grid
+}
+
+fn obstacle_grid(grid: PathGrid) -> ObstacleGrid {
+    let analysis = analyze_map(&grid, MapArchetype::Classic);
+    ObstacleGrid { grid, wave_number: 1, analysis }
+}
+
+#[test]
+fn test_ground_enemies_path_around_void_but_flyers_cross_it() {
+    let grid = lake_grid();
+    let ground = find_path(&grid, grid.entry_point, grid.exit_point).unwrap();
+    assert!(ground.iter().all(|pos| !grid.is_void(*pos)));
+    assert!(ground.contains(&GridPos::new(5, 4)), "the only gap in the wall");
+
+    let flight = find_flight_path(&grid, grid.entry_point, grid.exit_point).unwrap();
+    assert!(flight.iter().any(|pos| grid.is_void(*pos)));
+    assert!(flight.len() < ground.len());
+}
+
+#[test]
+fn test_flight_path_only_exists_as_a_shortcut_over_void() {
+    let grid = lake_grid();
+    let ground = grid.to_enemy_path(find_path(&grid, grid.entry_point, grid.exit_point).unwrap());
+    let shortcut = flight_path(&grid, &ground).expect("flyers cut across the void");
+    assert!(shortcut.total_length() < ground.total_length());
+
+    let dry = PathGrid::new(10, 5);
+    assert!(flight_path(&dry, &ground).is_none());
+
+    let flight = FlightPath { path: Some(shortcut) };
+    let flyers: Vec<u32> = (0..9).filter(|index| flight.spawns_flying(*index)).collect();
+    assert_eq!(flyers, vec![1, 4, 7]);
+    assert!(!FlightPath::default().spawns_flying(1));
+}
+
+#[test]
+fn test_towers_cannot_be_built_over_void() {
+    let grid = lake_grid();
+    let verdict = terrain_verdict(Some(&grid), GridPos::new(5, 0));
+    assert_eq!(verdict, PlacementVerdict::OverVoid);
+    assert!(!verdict.is_buildable());
+    assert!(!grid.is_traversable(GridPos::new(5, 0)));
+    assert!(grid.is_flyable(GridPos::new(5, 0)));
+}
+
+#[test]
+fn test_void_lakes_keep_the_ground_route_open() {
+    let mut carved = 0;
+    for seed in 0..10 {
+        let mut grid = generate_procedural_map_with_archetype(seed, 0.5, MapArchetype::Maze);
+        let before = grid.clone();
+        let lake = carve_void_lake(&mut grid, seed);
+        let void_cells = grid.cells.iter().flatten().filter(|cell| **cell == CellType::Void).count();
+        assert_eq!(lake, void_cells);
+        assert!(find_path(&grid, grid.entry_point, grid.exit_point).is_some(), "seed {}", seed);
+        if lake == 0 {
+            assert_eq!(grid, before, "a dropped lake leaves the map as it was");
+        }
+        carved += lake;
+    }
+    assert!(carved > 0);
+}
+
+#[test]
+fn test_void_survives_map_codes() {
+    let grid = lake_grid();
+    let route = find_path(&grid, grid.entry_point, grid.exit_point).unwrap();
+    let map = SharedMap { grid, route, extra_routes: Vec::new(), archetype: MapArchetype::Classic };
+    let code = map.to_code();
+    assert!(code.contains('~'));
+    assert_eq!(SharedMap::from_code(&code), Ok(map));
+}
+
+#[test]
+fn test_projectiles_without_a_target_are_lost_over_void() {
+    let grid = lake_grid();
+    let over_void = grid.grid_to_world(GridPos::new(5, 2)).extend(0.0);
+    let over_ground = grid.grid_to_world(GridPos::new(2, 2)).extend(0.0);
+    let mut world = World::new();
+    world.insert_resource(obstacle_grid(grid));
+
+    let enemy = world.spawn((Enemy::default(), Transform::default())).id();
+    let gone = world.spawn_empty().id();
+    world.despawn(gone);
+    let mut projectile = |target: Entity, at: Vec3| {
+        world
+            .spawn((Projectile::new(10.0, 300.0, target, Vec2::ZERO, TowerType::Basic), Transform::from_translation(at)))
+            .id()
+    };
+    let homing = projectile(enemy, over_void);
+    let stray = projectile(gone, over_void);
+    let landed = projectile(gone, over_ground);
+
+    world.run_system_once(void_projectile_cleanup_system).unwrap();
+    assert!(world.get_entity(homing).is_ok(), "still chasing a live enemy");
+    assert!(world.get_entity(stray).is_err());
+    assert!(world.get_entity(landed).is_ok());
+}
@@ -0,0 +1,73 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use tower_defense_bevy::systems::settings_menu::*;
+
+fn slider_world() -> World {
+    let mut world = World::new();
+    world.insert_resource(GameSettings::default());
+    world
+}
+
+fn press_track(world: &mut World, setting_type: SettingsType, normalized_x: f32) -> Entity {
+    world
+        .spawn((
+            Interaction::Pressed,
+            RelativeCursorPosition { normalized: Some(Vec2::new(normalized_x, 0.0)), ..default() },
+            SettingsSliderTrack(setting_type),
+        ))
+        .id()
+}
+
+#[test]
+fn test_track_position_maps_to_whole_percent() {
+    assert_eq!(slider_value_at(-0.5), 0.0);
+    assert_eq!(slider_value_at(0.0), 0.5);
+    assert_eq!(slider_value_at(0.5), 1.0);
+    assert_eq!(slider_value_at(0.123), 0.62);
+    // Dragging past the ends pins the value
+    assert_eq!(slider_value_at(-3.0), 0.0);
+    assert_eq!(slider_value_at(2.0), 1.0);
+}
+
+#[test]
+fn test_volume_setters_only_touch_volumes() {
+    let mut settings = GameSettings::default();
+    settings.set_volume(SettingsType::SFXVolume, 1.5);
+    assert_eq!(settings.sfx_volume, 1.0);
+    settings.set_volume(SettingsType::MusicVolume, 0.25);
+    assert_eq!(settings.volume(SettingsType::MusicVolume), Some(0.25));
+    assert_eq!(settings.volume(SettingsType::VSync), None);
+}
+
+#[test]
+fn test_dragging_a_slider_sets_its_volume() {
+    let mut world = slider_world();
+    let track = press_track(&mut world, SettingsType::SFXVolume, -0.25);
+    world.run_system_once(settings_slider_drag_system).unwrap();
+    let settings = world.resource::<GameSettings>();
+    assert_eq!(settings.sfx_volume, 0.25);
+    assert_eq!(settings.master_volume, GameSettings::default().master_volume, "other sliders are untouched");
+
+    // Hovering without the button held does nothing
+    world.entity_mut(track).insert((Interaction::Hovered, RelativeCursorPosition { normalized: Some(Vec2::ZERO), ..default() }));
+    world.run_system_once(settings_slider_drag_system).unwrap();
+    assert_eq!(world.resource::<GameSettings>().sfx_volume, 0.25);
+}
+
+#[test]
+fn test_slider_visuals_follow_the_settings() {
+    let mut world = slider_world();
+    let fill = world
+        .spawn((Node::default(), SettingsSlider { setting_type: SettingsType::MusicVolume, value: 0.0 }))
+        .id();
+    let handle = world.spawn((Node::default(), SettingsSliderHandle(SettingsType::MusicVolume))).id();
+    let text = world.spawn((Text::new(""), SettingsSliderText(SettingsType::MusicVolume))).id();
+    world.resource_mut::<GameSettings>().music_volume = 0.4;
+
+    world.run_system_once(settings_slider_display_system).unwrap();
+    assert_eq!(world.get::<SettingsSlider>(fill).unwrap().value, 0.4);
+    assert_eq!(world.get::<Node>(fill).unwrap().width, Val::Percent(40.0));
+    assert_eq!(world.get::<Node>(handle).unwrap().left, Val::Percent(39.0));
+    assert_eq!(world.get::<Text>(text).unwrap().0, "40%");
+}
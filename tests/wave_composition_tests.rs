@@ -0,0 +1,351 @@
+use std::time::Duration;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::enemy_system::{calculate_enemies_for_wave, compose_wave};
+use tower_defense_bevy::systems::path_generation::MapArchetype;
+use tower_defense_bevy::systems::smart_enemy_system::SmartEnemySettings;
+use tower_defense_bevy::systems::combat_system::{kill_reward_range, WaveStatus};
+use tower_defense_bevy::systems::debug_ui::cheat_menu::CheatMultipliers;
+use tower_defense_bevy::systems::debug_ui::wave_jump::*;
+
+#[test]
+fn test_swarm_wave_has_one_stream_group() {
+    let composition = WaveComposition::swarm(3, 8);
+    assert_eq!(composition.groups.len(), 1);
+    assert_eq!(composition.total_enemies(), 8);
+    assert_eq!(composition.count_of(EnemyKind::Smart), 0);
+    assert_eq!(composition.groups[0].health, Enemy::health_for_wave(3));
+    assert_eq!(composition.estimated_total_health(), 8.0 * Enemy::health_for_wave(3));
+    assert_eq!(composition.spawn_rate, spawn_rate_for_wave(3));
+}
+
+#[test]
+fn test_interleaved_smart_enemies_take_every_nth_slot() {
+    let composition = WaveComposition::standard(4, 12, Some(4));
+    assert_eq!(composition.count_of(EnemyKind::Smart), 3);
+    assert_eq!(composition.count_of(EnemyKind::Swarm), 9);
+
+    let smart: Vec<u32> = (0..12)
+        .filter(|&index| composition.group_at(index).unwrap().kind == EnemyKind::Smart)
+        .collect();
+    assert_eq!(smart, vec![3, 7, 11]);
+    assert!(composition.group_at(12).is_none());
+}
+
+#[test]
+fn test_uneven_interleave_leaves_tail_to_stream() {
+    // 10 enemies with every 4th smart: slots 3 and 7 are smart, the tail is swarm
+    let composition = WaveComposition::standard(1, 10, Some(4));
+    let kinds: Vec<EnemyKind> = (0..10).map(|index| composition.group_at(index).unwrap().kind).collect();
+    assert_eq!(kinds.iter().filter(|kind| **kind == EnemyKind::Smart).count(), 2);
+    assert_eq!(kinds[9], EnemyKind::Swarm);
+}
+
+#[test]
+fn test_tags_follow_wave_traits() {
+    assert!(WaveComposition::swarm(1, 3).tags().is_empty());
+
+    let late = WaveComposition::standard(8, 20, Some(4));
+    assert_eq!(late.tags(), vec![WaveTag::Swarm, WaveTag::Fast, WaveTag::Tanky, WaveTag::SmartHeavy]);
+    assert!(late.summary().starts_with("20 enemies"));
+    assert!(late.summary().contains("[swarm, fast, tanky, smart-heavy]"));
+}
+
+#[test]
+fn test_compose_wave_mixes_in_smart_enemies_on_maze_maps_only() {
+    let settings = SmartEnemySettings::default();
+    assert_eq!(settings.smart_every(MapArchetype::Maze), Some(settings.spawn_every));
+    assert_eq!(settings.smart_every(MapArchetype::Classic), None);
+
+    let plain = compose_wave(6, None, None);
+    assert_eq!(plain.total_enemies(), calculate_enemies_for_wave(6));
+    assert_eq!(plain.count_of(EnemyKind::Smart), 0);
+}
+
+#[test]
+fn test_enemy_types_scale_the_wave_baseline() {
+    let tank = EnemyGroup::of_type(EnemyKind::Swarm, EnemyType::Tank, 4, 2, SpawnPattern::Stream);
+    assert_eq!(tank.health, Enemy::health_for_wave(4) * EnemyType::Tank.health_multiplier());
+    assert_eq!(tank.speed, Enemy::for_wave(4).speed * EnemyType::Tank.speed_multiplier());
+    assert_eq!(EnemyGroup::for_wave(EnemyKind::Swarm, 4, 2, SpawnPattern::Stream).enemy_type, EnemyType::Basic);
+
+    let fast = EnemyType::Fast;
+    assert!(fast.speed_multiplier() > 1.0 && fast.health_multiplier() < 1.0);
+    assert!(EnemyType::Boss.health_multiplier() > EnemyType::Tank.health_multiplier());
+    for enemy_type in EnemyType::ALL {
+        assert!(enemy_type.reward_multiplier() >= 1, "{} pays nothing", enemy_type.get_name());
+    }
+    let colors: std::collections::HashSet<String> =
+        EnemyType::ALL.iter().map(|enemy_type| format!("{:?}", enemy_type.color())).collect();
+    assert_eq!(colors.len(), EnemyType::ALL.len(), "every type has its own color");
+}
+
+#[test]
+fn test_mixed_waves_add_types_as_waves_go_on() {
+    let first = compose_wave(1, None, None);
+    assert_eq!(first.groups.len(), 1, "the first wave is all basic enemies");
+    assert_eq!(first.count_of_type(EnemyType::Basic), first.total_enemies());
+
+    let mut seen = Vec::new();
+    for wave in 1..=12 {
+        let composition = compose_wave(wave, None, None);
+        assert_eq!(composition.total_enemies(), calculate_enemies_for_wave(wave), "wave {}", wave);
+        assert_eq!(composition.count_of_type(EnemyType::Boss), u32::from(wave % BOSS_WAVE_INTERVAL == 0));
+        for enemy_type in EnemyType::ALL {
+            if composition.count_of_type(enemy_type) > 0 && !seen.contains(&enemy_type) {
+                seen.push(enemy_type);
+            }
+        }
+    }
+    assert_eq!(
+        seen,
+        vec![EnemyType::Basic, EnemyType::Fast, EnemyType::Tank, EnemyType::Boss, EnemyType::Shielded, EnemyType::Flying]
+    );
+
+    // Specialists grow with the wave, and the boss closes out its wave
+    let late = compose_wave(10, None, None);
+    assert!(late.count_of_type(EnemyType::Fast) > compose_wave(3, None, None).count_of_type(EnemyType::Fast));
+    assert_eq!(late.group_at(late.total_enemies() - 1).unwrap().enemy_type, EnemyType::Boss);
+    assert!(late.tags().contains(&WaveTag::Boss));
+    assert!(late.bounty(5) > late.total_enemies() * 5, "tougher types pay more");
+}
+
+#[test]
+fn test_spawned_enemies_carry_their_type() {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::*;
+    use tower_defense_bevy::systems::enemy_system::{enemy_color, enemy_spawning_system};
+
+    let mut composition = WaveComposition::swarm(3, 1);
+    composition.groups.push(EnemyGroup::of_type(EnemyKind::Swarm, EnemyType::Tank, 3, 1, SpawnPattern::Stream));
+    let mut wave_plan = WavePlan::default();
+    let mut wave_runtime = WaveRuntime::new();
+    wave_plan.current_wave = 2;
+    start_composed_wave(&mut wave_plan, &mut wave_runtime, composition);
+    wave_runtime.pending_spawns = 2;
+
+    let mut world = World::new();
+    world.insert_resource(wave_plan);
+    world.insert_resource(wave_runtime);
+    world.insert_resource(EnemyPath::new(vec![Vec2::new(-300.0, 0.0), Vec2::new(300.0, 0.0)]));
+    world.init_resource::<SimulationClock>();
+    world.run_system_once(enemy_spawning_system).unwrap();
+
+    let mut enemies: Vec<(EnemyType, u32, f32, Color)> = world
+        .query::<(&EnemyType, &Enemy, &Health, &Sprite)>()
+        .iter(&world)
+        .map(|(enemy_type, enemy, health, sprite)| (*enemy_type, enemy.reward, health.max, sprite.color))
+        .collect();
+    enemies.sort_by_key(|(_, reward, _, _)| *reward);
+    assert_eq!(enemies.len(), 2);
+    let (basic, tank) = (enemies[0], enemies[1]);
+    assert_eq!(basic.0, EnemyType::Basic);
+    assert_eq!(tank.0, EnemyType::Tank);
+    assert_eq!(tank.1, basic.1 * EnemyType::Tank.reward_multiplier());
+    assert_eq!(tank.2, Enemy::health_for_wave(3) * EnemyType::Tank.health_multiplier());
+    assert_eq!(tank.3, enemy_color(EnemyKind::Swarm, EnemyType::Tank));
+}
+
+#[test]
+fn test_mixed_waves_keep_smart_enemies_interleaved() {
+    let composition = WaveComposition::mixed(8, 20, Some(4));
+    assert_eq!(composition.count_of(EnemyKind::Smart), 5);
+    assert_eq!(composition.total_enemies(), 20);
+    for index in [3, 7, 11, 15, 19] {
+        assert_eq!(composition.group_at(index).unwrap().kind, EnemyKind::Smart);
+    }
+
+    let mut wave_plan = WavePlan::default();
+    let mut wave_runtime = WaveRuntime::new();
+    start_composed_wave(&mut wave_plan, &mut wave_runtime, composition);
+    let schedule = wave_runtime.spawn_schedule(&wave_plan);
+    let flyers = schedule.iter().filter(|spawn| spawn.enemy_type == EnemyType::Flying).count() as u32;
+    assert!(flyers > 0);
+    assert_eq!(wave_runtime.remaining_to_spawn_of_type(&wave_plan, EnemyType::Flying), flyers);
+}
+
+#[test]
+fn test_start_composed_wave_uses_composition() {
+    let mut wave_plan = WavePlan::default();
+    let mut wave_runtime = WaveRuntime::new();
+    start_composed_wave(&mut wave_plan, &mut wave_runtime, WaveComposition::standard(1, 8, Some(4)));
+
+    assert_eq!(wave_plan.current_wave, 1);
+    assert_eq!(wave_plan.composition.wave, 1);
+    assert_eq!(wave_plan.enemies_in_wave(), 8);
+    assert_eq!(wave_runtime.enemies_remaining_to_spawn(&wave_plan), 8);
+
+    // The legacy count-only entry point builds a plain swarm wave
+    start_wave(&mut wave_plan, &mut wave_runtime, 5);
+    assert_eq!(wave_plan.current_wave, 2);
+    assert_eq!(wave_plan.composition, WaveComposition::swarm(2, 5));
+}
+
+#[test]
+fn test_spawn_schedule_lists_each_enemy_at_its_interval() {
+    let mut wave_plan = WavePlan::default();
+    let mut wave_runtime = WaveRuntime::new();
+    start_composed_wave(&mut wave_plan, &mut wave_runtime, WaveComposition::standard(1, 8, Some(4)));
+    wave_runtime.set_spawn_rate(2.0);
+
+    let schedule = wave_runtime.spawn_schedule(&wave_plan);
+    assert_eq!(schedule.len(), 8);
+    assert!((schedule[0].time - 0.5).abs() < 1e-5);
+    assert!((schedule[7].time - 4.0).abs() < 1e-5);
+    assert_eq!(schedule[3].kind, EnemyKind::Smart);
+    assert_eq!(schedule[4].kind, EnemyKind::Swarm);
+}
+
+#[test]
+fn test_paused_spawning_holds_schedule_until_jumped() {
+    let mut wave_plan = WavePlan::default();
+    let mut wave_runtime = WaveRuntime::new();
+    start_composed_wave(&mut wave_plan, &mut wave_runtime, WaveComposition::swarm(1, 5));
+    wave_runtime.set_spawn_rate(1.0);
+    wave_runtime.spawning_paused = true;
+
+    wave_runtime.tick_spawn_timer(&wave_plan, Duration::from_secs(3));
+    assert_eq!(wave_runtime.pending_spawns, 0);
+    assert_eq!(wave_runtime.schedule_elapsed(&wave_plan), 0.0);
+
+    // Jumping works while paused, and only ever moves forward
+    wave_runtime.jump_to_next_spawn(&wave_plan);
+    assert_eq!(wave_runtime.pending_spawns, 1);
+    wave_runtime.jump_to_spawn(&wave_plan, 3);
+    assert_eq!(wave_runtime.pending_spawns, 4);
+    wave_runtime.jump_to_spawn(&wave_plan, 1);
+    assert_eq!(wave_runtime.pending_spawns, 4);
+    assert!((wave_runtime.schedule_elapsed(&wave_plan) - 4.0).abs() < 1e-4);
+
+    wave_runtime.advance_spawn_schedule(&wave_plan, Duration::from_secs(10));
+    assert_eq!(wave_runtime.pending_spawns, 5, "never queues past the wave");
+
+    wave_runtime.spawning_paused = false;
+    wave_runtime.enemy_spawned();
+    wave_runtime.tick_spawn_timer(&wave_plan, Duration::from_secs(1));
+    assert_eq!(wave_runtime.pending_spawns, 4);
+}
+
+#[test]
+fn test_remaining_enemy_counts_include_live_and_unspawned() {
+    use tower_defense_bevy::systems::enemy_count_hud::remaining_enemy_counts;
+
+    let mut wave_plan = WavePlan::default();
+    let mut wave_runtime = WaveRuntime::new();
+    start_composed_wave(&mut wave_plan, &mut wave_runtime, WaveComposition::standard(2, 8, Some(4)));
+    assert_eq!(wave_runtime.remaining_to_spawn_of(&wave_plan, EnemyKind::Smart), 2);
+
+    // Four spawned, one of them smart; one already died, and a tank is still about
+    for _ in 0..4 {
+        wave_runtime.enemy_spawned();
+    }
+    assert_eq!(wave_runtime.remaining_to_spawn_of(&wave_plan, EnemyKind::Swarm), 3);
+    assert_eq!(wave_runtime.remaining_to_spawn_of_type(&wave_plan, EnemyType::Basic), 4);
+    let counts = remaining_enemy_counts(&wave_plan, &wave_runtime, [EnemyType::Basic, EnemyType::Basic, EnemyType::Tank]);
+    assert_eq!(counts.len(), EnemyType::ALL.len());
+    assert_eq!(counts[0], (EnemyType::Basic, 6));
+    assert_eq!(counts.iter().find(|(enemy_type, _)| *enemy_type == EnemyType::Tank), Some(&(EnemyType::Tank, 1)));
+    assert_eq!(counts.iter().map(|(_, count)| count).sum::<u32>(), 7);
+}
+
+#[test]
+fn test_wave_preview_applies_balance_and_cheat_scaling() {
+    let composition = compose_wave(6, None, None);
+    let mut balance = BalanceConfig::default();
+    balance.enemies.health_per_wave *= 2.0;
+    let multipliers = CheatMultipliers { enemy_speed: 1.5, ..Default::default() };
+
+    let (health_factor, speed_factor) = balance.enemies.factors_for_wave(6);
+    let preview = preview_wave_groups(&composition, &balance, &multipliers);
+    assert_eq!(preview.len(), composition.groups.len());
+    assert_eq!(preview.iter().map(|group| group.count).sum::<u32>(), calculate_enemies_for_wave(6));
+    assert_eq!(preview[0].enemy_type, EnemyType::Basic);
+    assert!((preview[0].health - Enemy::health_for_wave(6) * health_factor).abs() < 1e-3);
+    assert!((preview[0].speed - Enemy::for_wave(6).speed * speed_factor * 1.5).abs() < 1e-3);
+
+    let (min_reward, max_reward) = kill_reward_range();
+    assert!(min_reward < max_reward);
+    let text = wave_preview_text(&composition, &balance, &multipliers, &NumberFormatter::default());
+    assert!(text.starts_with(&format!("Wave 6: {} enemies", composition.total_enemies())));
+    assert!(text.contains("\nFast x"), "specialist groups are listed by type: {}", text);
+    assert!(text.contains(&format!("${}-${} per kill", min_reward, max_reward)));
+}
+
+#[test]
+fn test_wave_jump_starts_the_composed_wave() {
+    let mut wave_plan = WavePlan::default();
+    let mut wave_runtime = WaveRuntime::new();
+    let mut wave_status = WaveStatus::default();
+    start_composed_wave(&mut wave_plan, &mut wave_runtime, compose_wave(1, None, None));
+    wave_runtime.enemies_spawned = 3;
+
+    apply_wave_jump(&mut wave_plan, &mut wave_runtime, &mut wave_status, compose_wave(5, None, None));
+    assert_eq!(wave_plan.current_wave, 5);
+    assert_eq!(wave_plan.composition.wave, 5);
+    assert_eq!(wave_runtime.enemies_spawned, 0);
+    assert_eq!(wave_plan.enemies_in_wave(), calculate_enemies_for_wave(5));
+    assert_eq!(wave_plan.composition.groups[0].health, Enemy::health_for_wave(5));
+    assert_eq!(wave_status.enemies_remaining, calculate_enemies_for_wave(5));
+    assert!(!wave_status.wave_complete);
+}
+
+#[test]
+fn test_pacing_profile_interpolates_between_keys() {
+    assert_eq!(PacingProfile::steady().rate_at(0.5), 1.0);
+    assert!(PacingProfile::steady().is_steady());
+
+    let tension = PacingProfile::tension();
+    assert!(!tension.is_steady());
+    assert!((tension.rate_at(0.0) - 0.6).abs() < 1e-5, "calm start");
+    assert!((tension.rate_at(0.4) - 1.2).abs() < 1e-5, "halfway up to the spike");
+    assert!(tension.rate_at(0.5) > tension.rate_at(0.65), "a breather after the spike");
+    assert!((tension.rate_at(2.0) - 2.0).abs() < 1e-5, "progress is clamped");
+    assert_eq!(tension.peak_rate(), 2.0);
+
+    assert_eq!(WaveComposition::swarm(1, 5).pacing, PacingProfile::steady());
+    assert_eq!(compose_wave(6, None, None).pacing, PacingProfile::tension());
+}
+
+#[test]
+fn test_paced_schedule_matches_the_running_timer() {
+    let mut wave_plan = WavePlan::default();
+    let mut wave_runtime = WaveRuntime::new();
+    let mut composition = WaveComposition::swarm(1, 10);
+    composition.pacing = PacingProfile::tension();
+    start_composed_wave(&mut wave_plan, &mut wave_runtime, composition);
+    wave_runtime.set_spawn_rate(1.0);
+
+    let schedule = wave_runtime.spawn_schedule(&wave_plan);
+    assert!((schedule[0].time - 1.0 / 0.6).abs() < 1e-4, "the first gap is stretched");
+    let early_gap = schedule[1].time - schedule[0].time;
+    let late_gap = schedule[9].time - schedule[8].time;
+    assert!(late_gap < early_gap, "the final rush packs enemies closer together");
+    assert!((wave_runtime.schedule_length(&wave_plan) - schedule[9].time).abs() < 1e-4);
+
+    // Ticking in small steps releases each enemy when the schedule says
+    let step = Duration::from_millis(10);
+    let mut elapsed = 0.0;
+    for spawn in &schedule {
+        while wave_runtime.pending_spawns <= spawn.index {
+            wave_runtime.tick_spawn_timer(&wave_plan, step);
+            elapsed += step.as_secs_f32();
+        }
+        assert!((elapsed - spawn.time).abs() < 0.02, "enemy {} due at {} not {}", spawn.index, spawn.time, elapsed);
+    }
+    assert!((wave_runtime.schedule_elapsed(&wave_plan) - wave_runtime.schedule_length(&wave_plan)).abs() < 1e-4);
+}
+
+#[test]
+fn test_jumping_a_paced_schedule_lands_on_the_next_spawn() {
+    let mut wave_plan = WavePlan::default();
+    let mut wave_runtime = WaveRuntime::new();
+    let mut composition = WaveComposition::swarm(1, 4);
+    composition.pacing = PacingProfile::tension();
+    start_composed_wave(&mut wave_plan, &mut wave_runtime, composition);
+
+    wave_runtime.advance_spawn_schedule(&wave_plan, Duration::from_millis(500));
+    assert_eq!(wave_runtime.pending_spawns, 0);
+    wave_runtime.jump_to_next_spawn(&wave_plan);
+    assert_eq!(wave_runtime.pending_spawns, 1);
+    let first = wave_runtime.spawn_schedule(&wave_plan)[0].time;
+    assert!((wave_runtime.schedule_elapsed(&wave_plan) - first).abs() < 1e-4);
+}
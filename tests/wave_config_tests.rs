@@ -0,0 +1,104 @@
+use std::path::Path;
+use std::time::Duration;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::enemy_system::{calculate_enemies_for_wave, compose_configured_wave, compose_wave};
+
+const AMBUSH: &str = r#"{
+    "wave": 4,
+    "groups": [
+        { "count": 3, "spawn_interval": 1.0 },
+        { "enemy_type": "Tank", "count": 2, "spawn_interval": 0.5, "delay": 2.0 },
+        { "enemy_type": "Fast", "kind": "Smart", "count": 1, "spawn_interval": 0.25 }
+    ]
+}"#;
+
+fn definition(json: &str) -> WaveDefinition {
+    WaveDefinition::from_json(json).unwrap()
+}
+
+#[test]
+fn test_definitions_describe_groups_in_spawn_order() {
+    let composition = definition(AMBUSH).composition();
+    assert_eq!(composition.wave, 4);
+    assert_eq!(composition.total_enemies(), 6);
+    assert_eq!(composition.count_of_type(EnemyType::Tank), 2);
+    assert_eq!(composition.count_of(EnemyKind::Smart), 1);
+    assert_eq!(composition.group_at(2).unwrap().enemy_type, EnemyType::Basic);
+    assert_eq!(composition.group_at(3).unwrap().enemy_type, EnemyType::Tank);
+    assert_eq!(composition.group_at(5).unwrap().kind, EnemyKind::Smart);
+
+    // Stats still follow the wave and type
+    let tanks = composition.groups.iter().find(|group| group.enemy_type == EnemyType::Tank).unwrap();
+    assert_eq!(tanks, &EnemyGroup::of_type(EnemyKind::Swarm, EnemyType::Tank, 4, 2, SpawnPattern::Stream));
+}
+
+#[test]
+fn test_intervals_and_delays_set_the_spawn_schedule() {
+    let mut wave_plan = WavePlan::default();
+    let mut wave_runtime = WaveRuntime::new();
+    wave_plan.current_wave = 3;
+    start_composed_wave(&mut wave_plan, &mut wave_runtime, definition(AMBUSH).composition());
+
+    let times: Vec<f32> = wave_runtime.spawn_schedule(&wave_plan).iter().map(|spawn| spawn.time).collect();
+    let expected = [1.0, 2.0, 3.0, 5.5, 6.0, 6.25];
+    for (time, expected) in times.iter().zip(expected) {
+        assert!((time - expected).abs() < 1e-4, "got {:?}", times);
+    }
+    assert!((wave_runtime.schedule_length(&wave_plan) - 6.25).abs() < 1e-4);
+
+    // The running timer releases enemies when the schedule says
+    let step = Duration::from_millis(10);
+    let mut elapsed = 0.0;
+    for (index, due) in expected.iter().enumerate() {
+        while wave_runtime.pending_spawns <= index as u32 {
+            wave_runtime.tick_spawn_timer(&wave_plan, step);
+            elapsed += step.as_secs_f32();
+        }
+        assert!((elapsed - due).abs() < 0.02, "enemy {} due at {} not {}", index, due, elapsed);
+    }
+}
+
+#[test]
+fn test_unplayable_definitions_are_refused() {
+    assert!(WaveDefinition::from_json(r#"{ "wave": 0, "groups": [{ "count": 1, "spawn_interval": 1.0 }] }"#).is_err());
+    assert!(WaveDefinition::from_json(r#"{ "wave": 2, "groups": [] }"#).is_err());
+    assert!(WaveDefinition::from_json(r#"{ "wave": 2, "groups": [{ "count": 1, "spawn_interval": 0.0 }] }"#).is_err());
+    assert!(WaveDefinition::from_json(r#"{ "wave": 2, "groups": [{ "count": 1, "spawn_interval": 1.0, "delay": -1.0 }] }"#).is_err());
+    assert!(WaveDefinition::from_json(r#"{ "wave": 2, "groups": [{ "enemy_type": "Dragon", "count": 1, "spawn_interval": 1.0 }] }"#).is_err());
+
+    let twice = WaveConfig::from_definitions([definition(AMBUSH), definition(AMBUSH)]);
+    assert!(twice.is_err());
+}
+
+#[test]
+fn test_undefined_waves_stay_procedural() {
+    let config = WaveConfig::from_definitions([definition(AMBUSH)]).unwrap();
+    assert_eq!(compose_configured_wave(4, Some(&config), None, None), definition(AMBUSH).composition());
+    assert_eq!(compose_configured_wave(5, Some(&config), None, None), compose_wave(5, None, None));
+    assert_eq!(compose_configured_wave(4, None, None, None), compose_wave(4, None, None));
+}
+
+#[test]
+fn test_broken_files_are_skipped() {
+    let dir = std::env::temp_dir().join(format!("td_wave_config_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("a_ambush.json"), AMBUSH).unwrap();
+    std::fs::write(dir.join("b_duplicate.json"), AMBUSH).unwrap();
+    std::fs::write(dir.join("c_broken.json"), "{ not json").unwrap();
+    std::fs::write(dir.join("notes.txt"), "not a wave").unwrap();
+
+    let config = WaveConfig::load_from(&dir);
+    std::fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(config.waves().collect::<Vec<_>>(), vec![4]);
+    assert!(WaveConfig::load_from(&dir).is_empty(), "a missing directory defines nothing");
+}
+
+#[test]
+fn test_shipped_waves_load_and_keep_the_usual_sizes() {
+    let config = WaveConfig::load_from(Path::new(WAVE_DEFINITIONS_DIR));
+    assert!(!config.is_empty());
+    for wave in config.waves() {
+        let definition = config.get(wave).unwrap();
+        assert_eq!(definition.total_enemies(), calculate_enemies_for_wave(wave), "wave {}", wave);
+    }
+}
@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::{components::*, resources::*};
+use tower_defense_bevy::systems::spawn_preview::{EntryPreview, SpawnPreview};
+use tower_defense_bevy::systems::wave_preview_panel::*;
+
+#[test]
+fn test_rows_add_up_groups_of_the_same_enemy() {
+    let mut composition = WaveComposition::standard(4, 12, Some(4));
+    composition.groups.push(EnemyGroup::for_wave(EnemyKind::Swarm, 4, 2, SpawnPattern::Stream));
+    composition.groups.push(EnemyGroup::of_type(EnemyKind::Swarm, EnemyType::Tank, 4, 0, SpawnPattern::Stream));
+
+    let rows: Vec<(&str, u32)> = wave_preview_rows(&composition).iter().map(|row| (row.name, row.count)).collect();
+    assert_eq!(rows, vec![("Swarm", 11), ("Smart", 3)], "empty groups are left out");
+}
+
+#[test]
+fn test_boss_waves_are_flagged() {
+    let boss_wave = WaveComposition::mixed(BOSS_WAVE_INTERVAL, 20, None);
+    assert!(wave_has_boss(&boss_wave));
+    assert!(wave_preview_rows(&boss_wave).iter().any(|row| row.enemy_type == EnemyType::Boss));
+    assert!(!wave_has_boss(&WaveComposition::swarm(1, 20)));
+}
+
+fn panel_texts(world: &mut World) -> Vec<String> {
+    let mut texts = world.query::<&Text>();
+    texts.iter(world).map(|text| text.0.clone()).collect()
+}
+
+#[test]
+fn test_panel_lists_next_wave_between_waves_only() {
+    let mut world = World::new();
+    world.run_system_once(setup_wave_preview_panel).unwrap();
+    world.insert_resource(SpawnPreview {
+        wave: BOSS_WAVE_INTERVAL,
+        composition: WaveComposition::mixed(BOSS_WAVE_INTERVAL, 20, None),
+        entries: vec![EntryPreview { position: Vec2::ZERO, direction: Vec2::X, enemies: 20, share: 1.0 }],
+        arena: None,
+    });
+    world.run_system_once(wave_preview_panel_system).unwrap();
+
+    let texts = panel_texts(&mut world);
+    assert!(texts.contains(&format!("NEXT: WAVE {}", BOSS_WAVE_INTERVAL)));
+    assert!(texts.contains(&"BOSS WAVE".to_string()));
+    assert!(texts.iter().any(|text| text.starts_with("Boss x")));
+
+    // The wave starts: the spawn preview drops its entries and the panel hides
+    world.resource_mut::<SpawnPreview>().entries.clear();
+    world.run_system_once(wave_preview_panel_system).unwrap();
+    let mut panels = world.query_filtered::<&Node, With<WavePreviewPanel>>();
+    assert_eq!(panels.single(&world).unwrap().display, Display::None);
+    assert!(panel_texts(&mut world).is_empty());
+}
@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use tower_defense_bevy::resources::*;
+use tower_defense_bevy::systems::settings_menu::GameSettings;
+use tower_defense_bevy::systems::whats_new::*;
+
+fn changelog() -> Changelog {
+    Changelog::from_json(
+        r#"[
+            { "version": "0.3.0", "notes": ["Tanks are slower"] },
+            { "version": "0.2.1", "notes": ["Fixed a crash"] },
+            { "version": "0.2.0", "notes": ["Added endless mode"] }
+        ]"#,
+    )
+    .unwrap()
+}
+
+fn versions(entries: &[&ChangelogEntry]) -> Vec<String> {
+    entries.iter().map(|entry| entry.version.clone()).collect()
+}
+
+fn overlay_count(world: &mut World) -> usize {
+    world.query_filtered::<(), With<WhatsNewOverlay>>().iter(world).count()
+}
+
+#[test]
+fn test_versions_compare_numerically() {
+    assert_eq!(parse_version("1.10.2"), Some((1, 10, 2)));
+    assert_eq!(parse_version("2"), Some((2, 0, 0)));
+    assert_eq!(parse_version("not a version"), None);
+    assert!(is_newer_version("0.10.0", "0.9.9"));
+    assert!(!is_newer_version("0.2.0", "0.2.0"));
+    assert!(!is_newer_version("0.1.0", "0.2.0"));
+    assert!(is_newer_version("0.1.0", ""), "a player who never saw any notes is behind");
+}
+
+#[test]
+fn test_notes_since_the_last_seen_version() {
+    let changelog = changelog();
+    let settings = GameSettings { last_seen_version: "0.2.0".to_string(), ..default() };
+    assert_eq!(versions(&whats_new_entries(&changelog, &settings, "0.3.0")), ["0.3.0", "0.2.1"]);
+    assert_eq!(versions(&whats_new_entries(&changelog, &settings, "0.2.1")), ["0.2.1"], "unreleased notes are left out");
+    assert!(whats_new_entries(&changelog, &settings, "0.2.0").is_empty());
+
+    // Without a last-seen version only the current release is shown
+    let fresh = GameSettings::default();
+    assert_eq!(versions(&whats_new_entries(&changelog, &fresh, "0.3.0")), ["0.3.0"]);
+
+    let opted_out = GameSettings { whats_new_enabled: false, ..settings };
+    assert!(whats_new_entries(&changelog, &opted_out, "0.3.0").is_empty());
+}
+
+#[test]
+fn test_embedded_changelog_covers_this_build() {
+    let changelog = Changelog::embedded();
+    assert!(changelog.entries.iter().any(|entry| entry.version == GAME_VERSION));
+    assert!(changelog.entries.iter().all(|entry| parse_version(&entry.version).is_some() && !entry.notes.is_empty()));
+}
+
+#[test]
+fn test_overlay_shows_once_and_dont_show_again_sticks() {
+    let mut world = World::new();
+    world.insert_resource(Changelog::embedded());
+    world.insert_resource(GameSettings::default());
+    world.run_system_once(show_whats_new_on_update_system).unwrap();
+    assert_eq!(overlay_count(&mut world), 1);
+
+    world.spawn((Interaction::Pressed, BackgroundColor::default(), WhatsNewButton(WhatsNewAction::DontShowAgain)));
+    world.run_system_once(whats_new_button_system).unwrap();
+    assert_eq!(overlay_count(&mut world), 0);
+    let settings = world.resource::<GameSettings>();
+    assert_eq!(settings.last_seen_version, GAME_VERSION);
+    assert!(!settings.whats_new_enabled);
+
+    world.run_system_once(show_whats_new_on_update_system).unwrap();
+    assert_eq!(overlay_count(&mut world), 0, "seen notes are not shown again");
+}